@@ -0,0 +1,486 @@
+use soroban_sdk::{Address, Bytes, Env, Map, String, contractimpl, panic_with_error};
+
+use crate::{
+    ConvictionTrait, Governance, GovernanceArgs, GovernanceClient, errors, events, types,
+    validation,
+};
+
+const CONVICTION_SCALE: u128 = 1_000_000_000;
+const TOTAL_WEIGHT_BPS: u128 = 10_000;
+const MAX_SUPPORTERS: u32 = 1000; // DoS protection
+const MIN_CONVICTION: u128 = types::Badge::Community as u128; // weight of a community member
+
+#[contractimpl]
+impl ConvictionTrait for Governance {
+    /// Create a funding proposal for a project.
+    ///
+    /// Instead of voting before a deadline, members continuously support
+    /// funding proposals with their weight. The conviction of a proposal
+    /// follows its support over ledgers, moving halfway to it every
+    /// `conviction_half_life` ledgers. The proposal can be executed once its
+    /// conviction crosses a threshold growing with the share of the treasury
+    /// requested, see `get_required_conviction`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `proposer` - Address of the proposal creator
+    /// * `project_key` - The project key identifier
+    /// * `title` - Title of the proposal
+    /// * `ipfs` - IPFS content identifier describing the proposal
+    /// * `token` - The token contract requested from the treasury
+    /// * `recipient` - The address receiving the funds
+    /// * `amount` - The amount requested
+    ///
+    /// # Returns
+    /// * `u32` - The ID of the funding proposal
+    ///
+    /// # Panics
+    /// * If the project doesn't exist, is archived or paused
+    /// * If conviction voting is disabled for the project
+    /// * If the title is too long for the governance configuration
+    /// * If the IPFS CID is malformed
+    /// * If the amount is not positive or exceeds the treasury
+    /// * If the proposer created a proposal less than the cooldown ago
+    #[allow(clippy::too_many_arguments)]
+    fn create_funding_proposal(
+        env: Env,
+        proposer: Address,
+        project_key: Bytes,
+        title: String,
+        ipfs: String,
+        token: Address,
+        recipient: Address,
+        amount: i128,
+    ) -> u32 {
+        crate::require_not_paused(&env);
+        crate::require_not_archived(&env, &project_key);
+        crate::require_project_not_paused(&env, &project_key);
+        proposer.require_auth();
+
+        let tansu = crate::tansu(&env);
+        let governance = tansu.get_governance_config(&project_key);
+        if governance.conviction_half_life == 0 {
+            panic_with_error!(&env, &errors::ExtendedErrors::ConvictionDisabled);
+        }
+        if !(types::MIN_TITLE_LENGTH..=governance.max_title_length).contains(&title.len()) {
+            panic_with_error!(&env, &errors::ContractErrors::ProposalInputValidation);
+        }
+        validation::validate_cid(&env, &ipfs);
+        if amount <= 0 {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidAmount);
+        }
+        let balance = tansu
+            .get_treasury(&project_key)
+            .get(token.clone())
+            .unwrap_or(0);
+        if amount > balance {
+            panic_with_error!(&env, &errors::ExtendedErrors::InsufficientTreasury);
+        }
+        tansu.use_proposal_cooldown(&project_key, &proposer);
+
+        let total_key = types::FundingKey::Total(project_key.clone());
+        let proposal_id: u32 = env.storage().persistent().get(&total_key).unwrap_or(0);
+        let proposal = types::FundingProposal {
+            id: proposal_id,
+            title,
+            proposer: proposer.clone(),
+            ipfs,
+            token: token.clone(),
+            recipient,
+            amount,
+            support: 0,
+            conviction: 0,
+            updated_at: env.ledger().sequence(),
+            status: types::ProposalStatus::Active,
+        };
+        env.storage().persistent().set(
+            &types::FundingKey::Proposal(project_key.clone(), proposal_id),
+            &proposal,
+        );
+        env.storage()
+            .persistent()
+            .set(&total_key, &(proposal_id + 1));
+
+        events::FundingProposalCreated {
+            project_key,
+            proposal_id,
+            proposer,
+            token,
+            amount,
+        }
+        .publish(&env);
+
+        proposal_id
+    }
+
+    /// Set the weight supporting a funding proposal.
+    ///
+    /// The weight of a member is split among the funding proposals it
+    /// supports, a weight of zero withdraws the support. The conviction
+    /// accumulated so far is kept. Only members with a badge, or holding the
+    /// voting token of the project, have weight, see `VotingWeight`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `member` - The address of the supporting member
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the funding proposal
+    /// * `weight` - The weight supporting the proposal
+    ///
+    /// # Panics
+    /// * If the project is archived or paused
+    /// * If the funding proposal doesn't exist or is not active anymore
+    /// * If the member has no voting weight in the project
+    /// * If the weight of the member on all proposals exceeds its voting
+    ///   weight
+    /// * If the proposal has too many supporters
+    fn support_funding_proposal(
+        env: Env,
+        member: Address,
+        project_key: Bytes,
+        proposal_id: u32,
+        weight: u32,
+    ) {
+        crate::require_not_paused(&env);
+        crate::require_not_archived(&env, &project_key);
+        crate::require_project_not_paused(&env, &project_key);
+        member.require_auth();
+
+        let mut proposal =
+            Self::get_funding_proposal(env.clone(), project_key.clone(), proposal_id);
+        if proposal.status != types::ProposalStatus::Active {
+            panic_with_error!(&env, &errors::ContractErrors::ProposalActive);
+        }
+
+        let max_weight = crate::tansu(&env).get_member_weight(&project_key, &member);
+        if max_weight == 0 {
+            panic_with_error!(&env, &errors::ContractErrors::UnknownMember);
+        }
+
+        // stakes on proposals which are not active anymore are released
+        let stakes_key = types::FundingKey::Stakes(project_key.clone(), member.clone());
+        let stakes: Map<u32, u32> = env
+            .storage()
+            .persistent()
+            .get(&stakes_key)
+            .unwrap_or(Map::new(&env));
+        let mut active_stakes = Map::new(&env);
+        for (id, stake) in stakes {
+            if id != proposal_id && is_active(&env, &project_key, id) {
+                active_stakes.set(id, stake);
+            }
+        }
+        let staked: u64 = active_stakes
+            .values()
+            .iter()
+            .map(|stake| stake as u64)
+            .sum();
+        if staked + weight as u64 > max_weight as u64 {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidSupport);
+        }
+
+        let support_key = types::FundingKey::Support(project_key.clone(), proposal_id);
+        let mut supporters: Map<Address, u32> = env
+            .storage()
+            .persistent()
+            .get(&support_key)
+            .unwrap_or(Map::new(&env));
+        let previous = supporters.get(member.clone()).unwrap_or(0);
+        if weight == 0 {
+            supporters.remove(member.clone());
+        } else {
+            supporters.set(member.clone(), weight);
+            active_stakes.set(proposal_id, weight);
+        }
+        if supporters.len() > MAX_SUPPORTERS {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidSupport);
+        }
+        env.storage().persistent().set(&support_key, &supporters);
+        env.storage().persistent().set(&stakes_key, &active_stakes);
+
+        proposal.support = proposal.support - previous as u128 + weight as u128;
+        env.storage().persistent().set(
+            &types::FundingKey::Proposal(project_key.clone(), proposal_id),
+            &proposal,
+        );
+
+        events::FundingSupportChanged {
+            project_key,
+            member,
+            proposal_id,
+            weight,
+        }
+        .publish(&env);
+    }
+
+    /// Execute a funding proposal which accumulated enough conviction.
+    ///
+    /// Anyone can execute the proposal, the requested amount is transferred
+    /// from the treasury to the recipient. Supporters count with at most
+    /// their current weight. If their weight dropped, the conviction is
+    /// capped to the remaining support as the drop may be old.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `executor` - The address executing the proposal
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the funding proposal
+    ///
+    /// # Returns
+    /// * `u128` - The conviction of the proposal
+    ///
+    /// # Panics
+    /// * If the funding proposal doesn't exist or is not active anymore
+    /// * If the conviction is below the required conviction
+    /// * If the treasury does not hold the amount anymore
+    fn execute_funding_proposal(
+        env: Env,
+        executor: Address,
+        project_key: Bytes,
+        proposal_id: u32,
+    ) -> u128 {
+        crate::require_not_paused(&env);
+        executor.require_auth();
+
+        let mut proposal =
+            Self::get_funding_proposal(env.clone(), project_key.clone(), proposal_id);
+        if proposal.status != types::ProposalStatus::Active {
+            panic_with_error!(&env, &errors::ContractErrors::ProposalActive);
+        }
+        reweigh_support(&env, &project_key, &mut proposal);
+        if proposal.conviction < required_conviction(&env, &project_key, &proposal) {
+            panic_with_error!(&env, &errors::ExtendedErrors::ConvictionTooLow);
+        }
+
+        proposal.status = types::ProposalStatus::Approved;
+        env.storage().persistent().set(
+            &types::FundingKey::Proposal(project_key.clone(), proposal_id),
+            &proposal,
+        );
+        env.storage()
+            .persistent()
+            .remove(&types::FundingKey::Support(
+                project_key.clone(),
+                proposal_id,
+            ));
+
+        crate::tansu(&env).spend_treasury(
+            &project_key,
+            &executor,
+            &proposal.token,
+            &proposal.recipient,
+            &proposal.amount,
+        );
+
+        events::FundingProposalExecuted {
+            project_key,
+            proposal_id,
+            conviction: proposal.conviction,
+        }
+        .publish(&env);
+
+        proposal.conviction
+    }
+
+    /// Get a funding proposal.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the funding proposal
+    ///
+    /// # Returns
+    /// * `types::FundingProposal` - The proposal, with its conviction as of
+    ///   the current ledger if it is active
+    ///
+    /// # Panics
+    /// * If the funding proposal doesn't exist
+    fn get_funding_proposal(
+        env: Env,
+        project_key: Bytes,
+        proposal_id: u32,
+    ) -> types::FundingProposal {
+        let mut proposal: types::FundingProposal = env
+            .storage()
+            .persistent()
+            .get(&types::FundingKey::Proposal(
+                project_key.clone(),
+                proposal_id,
+            ))
+            .unwrap_or_else(|| {
+                panic_with_error!(&env, &errors::ContractErrors::NoProposalorPageFound)
+            });
+
+        if proposal.status == types::ProposalStatus::Active {
+            let governance = crate::tansu(&env).get_governance_config(&project_key);
+            let ledger = env.ledger().sequence();
+            let decay = decay(
+                ledger.saturating_sub(proposal.updated_at),
+                governance.conviction_half_life,
+            );
+            proposal.conviction = if proposal.conviction >= proposal.support {
+                proposal.support
+                    + (proposal.conviction - proposal.support) * decay / CONVICTION_SCALE
+            } else {
+                proposal.support
+                    - (proposal.support - proposal.conviction) * decay / CONVICTION_SCALE
+            };
+            proposal.updated_at = ledger;
+        }
+        proposal
+    }
+
+    /// Get the conviction required to execute a funding proposal.
+    ///
+    /// The required conviction is a share of the total weight of the
+    /// project, from `conviction_min_support_bps` for a negligible amount up
+    /// to all the weight for the whole treasury balance of the token, and at
+    /// least the weight of a community member.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the funding proposal
+    ///
+    /// # Returns
+    /// * `u128` - The required conviction
+    ///
+    /// # Panics
+    /// * If the funding proposal doesn't exist
+    fn get_required_conviction(env: Env, project_key: Bytes, proposal_id: u32) -> u128 {
+        let proposal = Self::get_funding_proposal(env.clone(), project_key.clone(), proposal_id);
+        required_conviction(&env, &project_key, &proposal)
+    }
+}
+
+/// Whether a funding proposal exists and is active.
+fn is_active(env: &Env, project_key: &Bytes, proposal_id: u32) -> bool {
+    env.storage()
+        .persistent()
+        .get::<_, types::FundingProposal>(&types::FundingKey::Proposal(
+            project_key.clone(),
+            proposal_id,
+        ))
+        .is_some_and(|proposal| proposal.status == types::ProposalStatus::Active)
+}
+
+/// Count the supporters of a funding proposal with at most their current
+/// weight.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `proposal` - The funding proposal, with its conviction as of the
+///   current ledger
+fn reweigh_support(env: &Env, project_key: &Bytes, proposal: &mut types::FundingProposal) {
+    let supporters: Map<Address, u32> = env
+        .storage()
+        .persistent()
+        .get(&types::FundingKey::Support(
+            project_key.clone(),
+            proposal.id,
+        ))
+        .unwrap_or(Map::new(env));
+    let tansu = crate::tansu(env);
+    let support: u128 = supporters
+        .iter()
+        .map(|(member, weight)| weight.min(tansu.get_member_weight(project_key, &member)) as u128)
+        .sum();
+    if support < proposal.support {
+        proposal.support = support;
+        proposal.conviction = proposal.conviction.min(support);
+    }
+}
+
+/// Share of the distance between the conviction and the support remaining
+/// after some ledgers.
+///
+/// The distance halves every half-life, and decreases linearly in between.
+/// Reputation decays the same way in Tansu.
+///
+/// # Arguments
+/// * `elapsed` - Ledgers since the last update of the conviction
+/// * `half_life` - Ledgers for the conviction to move halfway to the support
+///
+/// # Returns
+/// * `u128` - The remaining share, scaled by `CONVICTION_SCALE`
+fn decay(elapsed: u32, half_life: u32) -> u128 {
+    if half_life == 0 || elapsed / half_life >= u128::BITS {
+        return 0;
+    }
+    let remainder = (elapsed % half_life) as u128;
+    (CONVICTION_SCALE - CONVICTION_SCALE * remainder / (2 * half_life as u128))
+        >> (elapsed / half_life)
+}
+
+/// Conviction required to execute a funding proposal.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `proposal` - The funding proposal
+///
+/// The required conviction is at least `MIN_CONVICTION` so that projects
+/// with few badge holders cannot be drained.
+///
+/// # Returns
+/// * `u128` - The required conviction, unreachable if the treasury does not
+///   hold the amount
+fn required_conviction(env: &Env, project_key: &Bytes, proposal: &types::FundingProposal) -> u128 {
+    let tansu = crate::tansu(env);
+    let governance = tansu.get_governance_config(project_key);
+    let total_weight = total_weight(env, project_key);
+    let balance = tansu
+        .get_treasury(project_key)
+        .get(proposal.token.clone())
+        .unwrap_or(0);
+    if proposal.amount > balance {
+        return u128::MAX;
+    }
+
+    let min_support = governance.conviction_min_support_bps as u128;
+    let share_bps =
+        min_support + (TOTAL_WEIGHT_BPS - min_support) * proposal.amount as u128 / balance as u128;
+    (total_weight * share_bps / TOTAL_WEIGHT_BPS).max(MIN_CONVICTION)
+}
+
+/// Weight of all the badges of a project.
+fn total_weight(env: &Env, project_key: &Bytes) -> u128 {
+    let badges = crate::tansu(env).get_badges(project_key);
+
+    [
+        (badges.developer, types::Badge::Developer),
+        (badges.triage, types::Badge::Triage),
+        (badges.community, types::Badge::Community),
+        (badges.verified, types::Badge::Verified),
+        (badges.reviewer, types::Badge::Reviewer),
+    ]
+    .into_iter()
+    .map(|(members, badge)| members.len() as u128 * badge as u128)
+    .sum()
+}
+
+/// Remove the funding proposals of a deregistered project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+pub fn remove_funding_proposals(env: &Env, project_key: &Bytes) {
+    let total_key = types::FundingKey::Total(project_key.clone());
+    let total: u32 = env.storage().persistent().get(&total_key).unwrap_or(0);
+    for proposal_id in 0..total {
+        env.storage()
+            .persistent()
+            .remove(&types::FundingKey::Proposal(
+                project_key.clone(),
+                proposal_id,
+            ));
+        env.storage()
+            .persistent()
+            .remove(&types::FundingKey::Support(
+                project_key.clone(),
+                proposal_id,
+            ));
+    }
+    env.storage().persistent().remove(&total_key);
+}
@@ -0,0 +1,88 @@
+use soroban_sdk::{Address, Bytes, Env, Vec, contractimpl, panic_with_error};
+
+use crate::{CouncilTrait, Governance, GovernanceArgs, GovernanceClient, errors, events, types};
+
+#[contractimpl]
+impl CouncilTrait for Governance {
+    /// Apply an action on behalf of the DAO with the signatures of its council.
+    ///
+    /// The council is elected by approved proposals, see
+    /// `types::DaoAction::SetCouncil`. Councils can only transfer funds from
+    /// the treasury, up to their spend limit.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `signers` - The council members signing the action
+    /// * `project_key` - The project key identifier
+    /// * `action` - The action to apply
+    ///
+    /// # Panics
+    /// * If the project has no council
+    /// * If a signer is not authorized, not a council member or duplicated
+    /// * If there are less signers than the threshold of the council
+    /// * If the action is not a transfer within the spend limit
+    /// * If the project is archived or paused
+    fn council_execute(
+        env: Env,
+        signers: Vec<Address>,
+        project_key: Bytes,
+        action: types::DaoAction,
+    ) {
+        crate::require_not_paused(&env);
+        crate::require_not_archived(&env, &project_key);
+        crate::require_project_not_paused(&env, &project_key);
+
+        let Some(council) = Self::get_council(env.clone(), project_key.clone()) else {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidCouncil);
+        };
+        for (index, signer) in signers.iter().enumerate() {
+            if !council.members.contains(&signer)
+                || signers.first_index_of(&signer) != Some(index as u32)
+            {
+                panic_with_error!(&env, &errors::ContractErrors::UnauthorizedSigner);
+            }
+            signer.require_auth();
+        }
+        if signers.len() < council.threshold {
+            panic_with_error!(&env, &errors::ContractErrors::UnauthorizedSigner);
+        }
+
+        let types::DaoAction::Transfer(token, recipient, amount) = action else {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidDaoAction);
+        };
+        if amount <= 0 {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidAmount);
+        }
+        if amount > council.spend_limit {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidDaoAction);
+        }
+        crate::tansu(&env).spend_treasury(
+            &project_key,
+            &signers.get_unchecked(0),
+            &token,
+            &recipient,
+            &amount,
+        );
+
+        events::CouncilActed {
+            project_key,
+            signers,
+        }
+        .publish(&env);
+    }
+
+    /// Get the council of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Option<types::Council>` - The council, if one is elected
+    fn get_council(env: Env, project_key: Bytes) -> Option<types::Council> {
+        env.storage()
+            .persistent()
+            .get::<_, types::Council>(&types::CouncilKey::Council(project_key))
+            .filter(|council| !council.members.is_empty())
+    }
+}
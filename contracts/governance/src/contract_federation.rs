@@ -0,0 +1,274 @@
+use soroban_sdk::{Address, Bytes, Env, Map, String, Vec, contractimpl, panic_with_error};
+
+use crate::{
+    FederationTrait, Governance, GovernanceArgs, GovernanceClient, errors, events, types,
+    validation,
+};
+
+#[contractimpl]
+impl FederationTrait for Governance {
+    /// Create a proposal spanning several projects, e.g. an organization-wide
+    /// policy.
+    ///
+    /// Each project votes on its own proposal, linked with
+    /// `link_federated_proposal`, and the federated proposal is approved once
+    /// the proposals of enough projects are approved. Projects have
+    /// `FEDERATION_PERIOD` to link and vote on their proposal.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `proposer` - Address of the proposal creator
+    /// * `title` - Title of the proposal
+    /// * `ipfs` - IPFS content identifier describing the proposal
+    /// * `project_keys` - The projects voting on the proposal
+    /// * `required_approvals` - The number of projects which must approve
+    ///
+    /// # Returns
+    /// * `u32` - The ID of the federated proposal
+    ///
+    /// # Panics
+    /// * If the title is empty or too long, or the IPFS CID is malformed
+    /// * If there are less than two or too many projects, or a duplicate
+    /// * If a project doesn't exist
+    /// * If the required approvals are not between one and the number of projects
+    fn create_federated_proposal(
+        env: Env,
+        proposer: Address,
+        title: String,
+        ipfs: String,
+        project_keys: Vec<Bytes>,
+        required_approvals: u32,
+    ) -> u32 {
+        crate::require_not_paused(&env);
+        proposer.require_auth();
+
+        if !(types::MIN_TITLE_LENGTH..=types::MAX_TITLE_LENGTH).contains(&title.len()) {
+            panic_with_error!(&env, &errors::ContractErrors::ProposalInputValidation);
+        }
+        validation::validate_cid(&env, &ipfs);
+        if !(2..=types::MAX_FEDERATED_PROJECTS).contains(&project_keys.len())
+            || project_keys.iter().enumerate().any(|(index, project_key)| {
+                project_keys.first_index_of(&project_key) != Some(index as u32)
+            })
+            || !(1..=project_keys.len()).contains(&required_approvals)
+        {
+            panic_with_error!(&env, &errors::AdditionalErrors::InvalidFederation);
+        }
+        for project_key in project_keys.iter() {
+            crate::tansu(&env).get_project(&project_key);
+        }
+
+        let federated_id = env
+            .storage()
+            .persistent()
+            .get(&types::FederationKey::Count)
+            .unwrap_or(0);
+        let federated_proposal = types::FederatedProposal {
+            id: federated_id,
+            title,
+            ipfs,
+            proposer: proposer.clone(),
+            project_keys: project_keys.clone(),
+            required_approvals,
+            proposals: Map::new(&env),
+            status: types::ProposalStatus::Active,
+            expires_at: env.ledger().timestamp() + types::FEDERATION_PERIOD,
+        };
+        env.storage()
+            .persistent()
+            .set(&types::FederationKey::Count, &(federated_id + 1));
+        env.storage().persistent().set(
+            &types::FederationKey::Proposal(federated_id),
+            &federated_proposal,
+        );
+
+        events::FederatedProposalCreated {
+            federated_id,
+            proposer,
+            project_keys,
+            required_approvals,
+        }
+        .publish(&env);
+
+        federated_id
+    }
+
+    /// Link the proposal voted by a project to a federated proposal.
+    ///
+    /// The project proposal must describe the federated proposal, with the
+    /// same IPFS CID, and close before the federated proposal expires.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of a maintainer of the project
+    /// * `federated_id` - The ID of the federated proposal
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal of the project
+    ///
+    /// # Panics
+    /// * If the federated proposal doesn't exist, is resolved or expired
+    /// * If the project is not part of it or already linked a proposal
+    /// * If the address is not a maintainer of the project
+    /// * If the project proposal is not active anymore
+    /// * If the project proposal does not match the federated proposal or
+    ///   closes after its expiry
+    fn link_federated_proposal(
+        env: Env,
+        maintainer: Address,
+        federated_id: u32,
+        project_key: Bytes,
+        proposal_id: u32,
+    ) {
+        crate::require_not_paused(&env);
+        crate::auth_maintainers(&env, &maintainer, &project_key);
+
+        let mut federated_proposal = Self::get_federated_proposal(env.clone(), federated_id);
+        if federated_proposal.status != types::ProposalStatus::Active {
+            panic_with_error!(&env, &errors::ContractErrors::ProposalActive);
+        }
+        if env.ledger().timestamp() >= federated_proposal.expires_at {
+            panic_with_error!(&env, &errors::ContractErrors::ProposalVotingTime);
+        }
+        if !federated_proposal.project_keys.contains(&project_key)
+            || federated_proposal
+                .proposals
+                .contains_key(project_key.clone())
+        {
+            panic_with_error!(&env, &errors::AdditionalErrors::InvalidFederation);
+        }
+
+        let proposal = crate::tansu(&env).get_proposal(&project_key, &proposal_id);
+        if proposal.status != types::ProposalStatus::Active {
+            panic_with_error!(&env, &errors::ContractErrors::ProposalActive);
+        }
+        if proposal.ipfs != federated_proposal.ipfs
+            || crate::vote_closes_at(&proposal) > federated_proposal.expires_at
+        {
+            panic_with_error!(&env, &errors::AdditionalErrors::InvalidFederation);
+        }
+
+        federated_proposal
+            .proposals
+            .set(project_key.clone(), proposal_id);
+        env.storage().persistent().set(
+            &types::FederationKey::Proposal(federated_id),
+            &federated_proposal,
+        );
+
+        events::FederatedProposalLinked {
+            federated_id,
+            project_key,
+            proposal_id,
+        }
+        .publish(&env);
+    }
+
+    /// Resolve a federated proposal from the outcome of the project proposals.
+    ///
+    /// The federated proposal is approved once enough project proposals are
+    /// approved, and rejected once too few can still be. Proposals of
+    /// deregistered projects count as rejected, as do the projects which did
+    /// not link a proposal before the expiry. Anyone can resolve a federated
+    /// proposal.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `federated_id` - The ID of the federated proposal
+    ///
+    /// # Returns
+    /// * `types::ProposalStatus` - Approved or Rejected
+    ///
+    /// # Panics
+    /// * If the federated proposal doesn't exist or is already resolved
+    /// * If the outcome still depends on pending project proposals
+    fn resolve_federated_proposal(env: Env, federated_id: u32) -> types::ProposalStatus {
+        crate::require_not_paused(&env);
+
+        let mut federated_proposal = Self::get_federated_proposal(env.clone(), federated_id);
+        if federated_proposal.status != types::ProposalStatus::Active {
+            panic_with_error!(&env, &errors::ContractErrors::ProposalActive);
+        }
+
+        let expired = env.ledger().timestamp() >= federated_proposal.expires_at;
+        let mut approvals = 0;
+        let mut pending = 0;
+        for project_key in federated_proposal.project_keys.iter() {
+            match federated_proposal.proposals.get(project_key.clone()) {
+                Some(proposal_id) => {
+                    match linked_status(&env, &federated_proposal, &project_key, proposal_id) {
+                        Some(types::ProposalStatus::Approved) => approvals += 1,
+                        Some(types::ProposalStatus::Active) if !expired => pending += 1,
+                        _ => (),
+                    }
+                }
+                // projects can still link a proposal
+                None if !expired => pending += 1,
+                None => (),
+            }
+        }
+
+        federated_proposal.status = if approvals >= federated_proposal.required_approvals {
+            types::ProposalStatus::Approved
+        } else if approvals + pending < federated_proposal.required_approvals {
+            types::ProposalStatus::Rejected
+        } else {
+            panic_with_error!(&env, &errors::ContractErrors::ProposalVotingTime)
+        };
+        env.storage().persistent().set(
+            &types::FederationKey::Proposal(federated_id),
+            &federated_proposal,
+        );
+
+        events::FederatedProposalResolved {
+            federated_id,
+            status: federated_proposal.status.clone(),
+            approvals,
+        }
+        .publish(&env);
+
+        federated_proposal.status
+    }
+
+    /// Get a federated proposal.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `federated_id` - The ID of the federated proposal
+    ///
+    /// # Returns
+    /// * `types::FederatedProposal` - The federated proposal
+    ///
+    /// # Panics
+    /// * If the federated proposal doesn't exist
+    fn get_federated_proposal(env: Env, federated_id: u32) -> types::FederatedProposal {
+        env.storage()
+            .persistent()
+            .get(&types::FederationKey::Proposal(federated_id))
+            .unwrap_or_else(|| {
+                panic_with_error!(&env, &errors::AdditionalErrors::UnknownFederation)
+            })
+    }
+}
+
+/// Status of a proposal linked to a federated proposal.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `federated_proposal` - The federated proposal
+/// * `project_key` - The project key identifier
+/// * `proposal_id` - The ID of the linked proposal
+///
+/// # Returns
+/// * `Option<types::ProposalStatus>` - The status of the proposal, None if
+///   the project was deregistered or the proposal is gone
+fn linked_status(
+    env: &Env,
+    federated_proposal: &types::FederatedProposal,
+    project_key: &Bytes,
+    proposal_id: u32,
+) -> Option<types::ProposalStatus> {
+    match crate::tansu(env).try_get_proposal(project_key, &proposal_id) {
+        Ok(Ok(proposal)) if proposal.ipfs == federated_proposal.ipfs => Some(proposal.status),
+        _ => None,
+    }
+}
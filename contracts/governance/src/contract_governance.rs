@@ -0,0 +1,212 @@
+use soroban_sdk::{Address, Bytes, Env, Map, Vec, contractimpl};
+
+use crate::{
+    Governance, GovernanceArgs, GovernanceClient, GovernanceTrait, RevealTrait, SponsorshipTrait,
+    contract_conviction, contract_rewards, contract_sponsorship, events, types,
+};
+
+#[contractimpl]
+impl GovernanceTrait for Governance {
+    /// Initialize the governance contract with the Tansu contract the
+    /// projects are registered with.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `tansu` - The address of the Tansu contract
+    fn __constructor(env: Env, tansu: Address) {
+        env.storage().instance().set(&types::DataKey::Tansu, &tansu);
+    }
+
+    /// Get the Tansu contract the projects are registered with.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    ///
+    /// # Returns
+    /// * `Address` - The address of the Tansu contract
+    fn get_tansu(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&types::DataKey::Tansu)
+            .unwrap()
+    }
+
+    /// Record that a new proposal needs sponsors before it opens for voting.
+    ///
+    /// Only Tansu can call this function, when the proposal is created.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal
+    /// * `required` - The sponsors needed, nothing is recorded for 0
+    ///
+    /// # Panics
+    /// * If Tansu did not authorize the call
+    fn open_sponsorship(env: Env, project_key: Bytes, proposal_id: u32, required: u32) {
+        Self::get_tansu(env.clone()).require_auth();
+
+        contract_sponsorship::open_sponsorship(&env, &project_key, proposal_id, required);
+    }
+
+    /// Whether a proposal is open for voting, enough badge holders
+    /// sponsoring it if it needs sponsors.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal
+    ///
+    /// # Returns
+    /// * `bool` - False if the proposal still needs sponsors
+    fn is_sponsored(env: Env, project_key: Bytes, proposal_id: u32) -> bool {
+        Self::get_sponsorship(env, project_key, proposal_id)
+            .is_none_or(|sponsorship| sponsorship.sponsors.len() >= sponsorship.required)
+    }
+
+    /// Remove the sponsors and the hashed votes of a proposal which is not
+    /// active anymore.
+    ///
+    /// Only Tansu can call this function, when the proposal is closed. Tansu
+    /// holds the collaterals of the hashed votes and settles them.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal
+    ///
+    /// # Returns
+    /// * `Vec<Address>` - The voters whose hashed vote was not revealed
+    ///
+    /// # Panics
+    /// * If Tansu did not authorize the call
+    fn close_proposal(env: Env, project_key: Bytes, proposal_id: u32) -> Vec<Address> {
+        Self::get_tansu(env.clone()).require_auth();
+
+        let commitments = Self::get_vote_commitments(env.clone(), project_key.clone(), proposal_id);
+        env.storage()
+            .persistent()
+            .remove(&types::SponsorKey::Sponsorship(
+                project_key.clone(),
+                proposal_id,
+            ));
+        env.storage()
+            .persistent()
+            .remove(&types::RevealKey::Commitments(project_key, proposal_id));
+        commitments.keys()
+    }
+
+    /// Add tokens taken from the treasury to the reward pool of a proposal.
+    ///
+    /// Only Tansu can call this function, applying `DaoAction::FundReward`
+    /// once the DAO approved it. Tansu transfers the tokens beforehand.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the rewarded proposal
+    /// * `token` - The token contract of the reward
+    /// * `amount` - The amount taken from the treasury
+    /// * `closes_at` - The end of the vote on the rewarded proposal
+    ///
+    /// # Panics
+    /// * If Tansu did not authorize the call
+    /// * If the pool holds another token
+    fn fund_reward_pool(
+        env: Env,
+        project_key: Bytes,
+        proposal_id: u32,
+        token: Address,
+        amount: i128,
+        closes_at: u64,
+    ) {
+        Self::get_tansu(env.clone()).require_auth();
+
+        contract_rewards::fund_reward_pool(
+            &env,
+            &project_key,
+            proposal_id,
+            token,
+            amount,
+            closes_at,
+        );
+    }
+
+    /// Return the reward pool of a proposal closed before its end.
+    ///
+    /// Only Tansu can call this function, when the proposal is revoked or
+    /// cancelled. The tokens are transferred back to Tansu, which credits
+    /// them to the treasury of the project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal
+    ///
+    /// # Returns
+    /// * `Option<types::RewardPool>` - The pool returned, if any
+    ///
+    /// # Panics
+    /// * If Tansu did not authorize the call
+    fn refund_reward_pool(
+        env: Env,
+        project_key: Bytes,
+        proposal_id: u32,
+    ) -> Option<types::RewardPool> {
+        Self::get_tansu(env.clone()).require_auth();
+
+        contract_rewards::take_reward_pool(&env, &project_key, proposal_id)
+    }
+
+    /// Elect the council of a project.
+    ///
+    /// Only Tansu can call this function, applying `DaoAction::SetCouncil`
+    /// once the DAO approved it. Tansu validates the council.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `council` - The council, without members to dissolve it
+    ///
+    /// # Panics
+    /// * If Tansu did not authorize the call
+    fn set_council(env: Env, project_key: Bytes, council: types::Council) {
+        Self::get_tansu(env.clone()).require_auth();
+
+        env.storage()
+            .persistent()
+            .set(&types::CouncilKey::Council(project_key.clone()), &council);
+
+        events::CouncilElected {
+            project_key,
+            council,
+        }
+        .publish(&env);
+    }
+
+    /// Remove the governance data of a deregistered project.
+    ///
+    /// Only Tansu can call this function, when the project is deregistered.
+    /// The open reward pools are transferred back to Tansu, which credits
+    /// them to the treasury of the project before handing it over.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Map<Address, i128>` - The amount returned for each token
+    ///
+    /// # Panics
+    /// * If Tansu did not authorize the call
+    fn remove_project(env: Env, project_key: Bytes) -> Map<Address, i128> {
+        Self::get_tansu(env.clone()).require_auth();
+
+        let refunds = contract_rewards::remove_reward_pools(&env, &project_key);
+        contract_conviction::remove_funding_proposals(&env, &project_key);
+        env.storage()
+            .persistent()
+            .remove(&types::CouncilKey::Council(project_key));
+        refunds
+    }
+}
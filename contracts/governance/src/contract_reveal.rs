@@ -0,0 +1,196 @@
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, Bytes, BytesN, Env, Map, contractimpl, panic_with_error};
+
+use crate::{
+    Governance, GovernanceArgs, GovernanceClient, RevealTrait, contract_sponsorship, errors,
+    events, types,
+};
+
+#[contractimpl]
+impl RevealTrait for Governance {
+    /// Commit to a vote on a proposal taking hashed votes.
+    ///
+    /// When the governance configuration sets a reveal period, public
+    /// proposals only accept the sha256 of the XDR of the `PublicVote`
+    /// followed by a salt during their voting period. Choices stay hidden
+    /// until the vote ends, then voters reveal them with `reveal_vote`.
+    /// Votes which are not revealed before the end of the reveal period are
+    /// not counted. Committing again replaces the commitment.
+    ///
+    /// The first commitment takes the collateral of a vote. It is kept for
+    /// the vote when revealed, and slashed to the treasury of the project if
+    /// the vote is not revealed in time.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `voter` - The address of the voter
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal
+    /// * `commitment` - The sha256 of the XDR of the vote followed by the salt
+    ///
+    /// # Panics
+    /// * If the proposal doesn't exist, is not active or needs sponsors
+    /// * If the voting period has ended
+    /// * If the proposal does not take hashed votes
+    /// * If the voter already voted or delegated its weight
+    /// * If the voter is not a member of the project
+    /// * If the vote limit of the proposal is reached
+    /// * If the collateral cannot be taken
+    /// * If the project is archived
+    /// * If the project is paused
+    fn commit_vote(
+        env: Env,
+        voter: Address,
+        project_key: Bytes,
+        proposal_id: u32,
+        commitment: BytesN<32>,
+    ) {
+        crate::require_not_paused(&env);
+        crate::require_not_archived(&env, &project_key);
+        crate::require_project_not_paused(&env, &project_key);
+
+        voter.require_auth();
+
+        let tansu = crate::tansu(&env);
+        let proposal = tansu.get_proposal(&project_key, &proposal_id);
+        if proposal.status != types::ProposalStatus::Active {
+            panic_with_error!(&env, &errors::ContractErrors::ProposalActive);
+        }
+        contract_sponsorship::require_sponsored(&env, &project_key, proposal_id);
+        if env.ledger().timestamp() >= proposal.vote_data.voting_ends_at {
+            panic_with_error!(&env, &errors::ContractErrors::ProposalVotingTime);
+        }
+        if !crate::takes_hashed_votes(&proposal) {
+            panic_with_error!(&env, &errors::ContractErrors::WrongVoteType);
+        }
+
+        let mut commitments =
+            Self::get_vote_commitments(env.clone(), project_key.clone(), proposal_id);
+        if has_voted(&proposal, &voter) {
+            panic_with_error!(&env, &errors::ContractErrors::AlreadyVoted);
+        }
+        if tansu
+            .get_delegations(&project_key)
+            .contains_key(voter.clone())
+        {
+            panic_with_error!(&env, &errors::ExtendedErrors::VoteDelegated);
+        }
+        if tansu.get_voting_weight(&project_key, &proposal_id, &voter) == 0 {
+            panic_with_error!(&env, &errors::ContractErrors::UnknownMember);
+        }
+        if !commitments.contains_key(voter.clone())
+            && commitments.len() + proposal.vote_data.votes.len() >= types::MAX_VOTES_PER_PROPOSAL
+        {
+            panic_with_error!(&env, &errors::ContractErrors::VoteLimitExceeded);
+        }
+
+        if !commitments.contains_key(voter.clone()) {
+            tansu.take_vote_collateral(&voter);
+        }
+        commitments.set(voter.clone(), commitment);
+        env.storage().persistent().set(
+            &types::RevealKey::Commitments(project_key.clone(), proposal_id),
+            &commitments,
+        );
+
+        events::VoteCommitted {
+            project_key,
+            proposal_id,
+            voter,
+        }
+        .publish(&env);
+    }
+
+    /// Reveal a vote committed with `commit_vote`.
+    ///
+    /// Votes are revealed between the end of the voting period and the end
+    /// of the reveal period. The revealed vote is then recorded as with
+    /// `vote`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `voter` - The address of the voter
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal
+    /// * `vote` - The committed vote
+    /// * `salt` - The salt of the commitment
+    ///
+    /// # Panics
+    /// * If the proposal is not in its reveal period
+    /// * If the voter did not commit to a vote
+    /// * If the vote and salt do not match the commitment
+    /// * If the vote could not be recorded, see `vote`
+    /// * If the project is archived
+    /// * If the project is paused
+    fn reveal_vote(
+        env: Env,
+        voter: Address,
+        project_key: Bytes,
+        proposal_id: u32,
+        vote: types::PublicVote,
+        salt: Bytes,
+    ) {
+        crate::require_not_paused(&env);
+        crate::require_not_archived(&env, &project_key);
+        crate::require_project_not_paused(&env, &project_key);
+
+        voter.require_auth();
+
+        let proposal = crate::tansu(&env).get_proposal(&project_key, &proposal_id);
+        if !crate::takes_hashed_votes(&proposal) {
+            panic_with_error!(&env, &errors::ContractErrors::WrongVoteType);
+        }
+        let reveal_ends_at = crate::vote_closes_at(&proposal);
+        if !(proposal.vote_data.voting_ends_at..reveal_ends_at).contains(&env.ledger().timestamp())
+        {
+            panic_with_error!(&env, &errors::ContractErrors::ProposalVotingTime);
+        }
+
+        let mut commitments =
+            Self::get_vote_commitments(env.clone(), project_key.clone(), proposal_id);
+        let commitment = commitments
+            .get(voter.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, &errors::AdditionalErrors::InvalidReveal));
+        let mut preimage = vote.clone().to_xdr(&env);
+        preimage.append(&salt);
+        if env.crypto().sha256(&preimage).to_bytes() != commitment {
+            panic_with_error!(&env, &errors::AdditionalErrors::InvalidReveal);
+        }
+
+        commitments.remove(voter.clone());
+        env.storage().persistent().set(
+            &types::RevealKey::Commitments(project_key.clone(), proposal_id),
+            &commitments,
+        );
+
+        crate::tansu(&env).cast_revealed_vote(&voter, &project_key, &proposal_id, &vote);
+    }
+
+    /// Get the hashed votes of a proposal which are not revealed yet.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal
+    ///
+    /// # Returns
+    /// * `Map<Address, BytesN<32>>` - The commitment of each voter
+    fn get_vote_commitments(
+        env: Env,
+        project_key: Bytes,
+        proposal_id: u32,
+    ) -> Map<Address, BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&types::RevealKey::Commitments(project_key, proposal_id))
+            .unwrap_or(Map::new(&env))
+    }
+}
+
+/// Whether an address voted on a proposal, publicly or anonymously.
+fn has_voted(proposal: &types::Proposal, address: &Address) -> bool {
+    proposal.vote_data.votes.iter().any(|vote_| match vote_ {
+        types::Vote::PublicVote(vote_choice) => vote_choice.address == *address,
+        types::Vote::AnonymousVote(vote_choice) => vote_choice.address == *address,
+    })
+}
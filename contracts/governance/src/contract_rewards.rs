@@ -0,0 +1,360 @@
+use soroban_sdk::{Address, Bytes, Env, Map, Vec, contractimpl, panic_with_error, token};
+
+use crate::{Governance, GovernanceArgs, GovernanceClient, RewardsTrait, errors, events, types};
+
+const REWARD_CLAIM_PERIOD: u64 = 30 * 24 * 3600; // 30 days in seconds
+
+#[contractimpl]
+impl RewardsTrait for Governance {
+    /// Claim the share of a voter in the reward pool of a proposal.
+    ///
+    /// The share is proportional to the weight of the vote among the
+    /// revealed votes, the abstain vote of the proposer excluded. Anonymous
+    /// votes are not rewarded as their choice is never revealed. Delegates
+    /// receive the share of the weight delegated to them. The last claim
+    /// returns the rounding dust to the treasury.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `voter` - The address of the voter
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal
+    ///
+    /// # Returns
+    /// * `i128` - The amount transferred to the voter
+    ///
+    /// # Panics
+    /// * If the proposal has no reward pool
+    /// * If the proposal is still active
+    /// * If the claim period of the pool is over
+    /// * If the address did not cast a revealed vote
+    /// * If the voter already claimed its share
+    fn claim_proposal_reward(
+        env: Env,
+        voter: Address,
+        project_key: Bytes,
+        proposal_id: u32,
+    ) -> i128 {
+        crate::require_not_paused(&env);
+        voter.require_auth();
+
+        let Some(mut pool) =
+            Self::get_proposal_reward(env.clone(), project_key.clone(), proposal_id)
+        else {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidRewardPool);
+        };
+        let proposal = crate::tansu(&env).get_proposal(&project_key, &proposal_id);
+        if proposal.status == types::ProposalStatus::Active {
+            panic_with_error!(&env, &errors::ContractErrors::ProposalActive);
+        }
+        if env.ledger().timestamp() >= pool.claim_ends_at {
+            panic_with_error!(&env, &errors::AdditionalErrors::RewardClaimPeriod);
+        }
+
+        let epoch = epoch(&env, &project_key);
+        let claimed_key =
+            types::RewardKey::Claimed(project_key.clone(), proposal_id, voter.clone());
+        if env.storage().persistent().get(&claimed_key) == Some(epoch) {
+            panic_with_error!(&env, &errors::ExtendedErrors::RewardAlreadyClaimed);
+        }
+
+        let mut voter_weight: u128 = 0;
+        let mut total_weight: u128 = 0;
+        let mut voters: u32 = 0;
+        for vote_ in proposal.vote_data.votes.iter() {
+            let types::Vote::PublicVote(vote_choice) = vote_ else {
+                continue;
+            };
+            if vote_choice.address == proposal.proposer {
+                continue;
+            }
+            if vote_choice.address == voter {
+                voter_weight = vote_choice.weight as u128;
+            }
+            total_weight += vote_choice.weight as u128;
+            voters += 1;
+        }
+        if voter_weight == 0 {
+            panic_with_error!(&env, &errors::ExtendedErrors::UnknownVote);
+        }
+
+        let amount = (pool.amount as u128 * voter_weight / total_weight) as i128;
+        pool.claimed += amount;
+        pool.claims += 1;
+        env.storage().persistent().set(&claimed_key, &epoch);
+        env.storage().persistent().set(
+            &types::RewardKey::Pool(project_key.clone(), proposal_id),
+            &pool,
+        );
+        token::TokenClient::new(&env, &pool.token).transfer(
+            &env.current_contract_address(),
+            &voter,
+            &amount,
+        );
+
+        events::ProposalRewardClaimed {
+            project_key: project_key.clone(),
+            voter,
+            proposal_id,
+            amount,
+        }
+        .publish(&env);
+
+        if pool.claims == voters {
+            refund_reward_pool(&env, &project_key, proposal_id, &proposal);
+        }
+
+        amount
+    }
+
+    /// Return the unclaimed part of a reward pool to the treasury.
+    ///
+    /// Anyone can close a pool once its claim period is over.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal
+    ///
+    /// # Panics
+    /// * If the proposal has no reward pool
+    /// * If the proposal is still active
+    /// * If the claim period of the pool is not over
+    fn close_proposal_reward(env: Env, project_key: Bytes, proposal_id: u32) {
+        crate::require_not_paused(&env);
+
+        let Some(pool) = Self::get_proposal_reward(env.clone(), project_key.clone(), proposal_id)
+        else {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidRewardPool);
+        };
+        let proposal = crate::tansu(&env).get_proposal(&project_key, &proposal_id);
+        if proposal.status == types::ProposalStatus::Active {
+            panic_with_error!(&env, &errors::ContractErrors::ProposalActive);
+        }
+        if env.ledger().timestamp() < pool.claim_ends_at {
+            panic_with_error!(&env, &errors::AdditionalErrors::RewardClaimPeriod);
+        }
+
+        refund_reward_pool(&env, &project_key, proposal_id, &proposal);
+    }
+
+    /// Get the reward pool of a proposal.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal
+    ///
+    /// # Returns
+    /// * `Option<types::RewardPool>` - The reward pool, if any
+    fn get_proposal_reward(
+        env: Env,
+        project_key: Bytes,
+        proposal_id: u32,
+    ) -> Option<types::RewardPool> {
+        env.storage()
+            .persistent()
+            .get(&types::RewardKey::Pool(project_key, proposal_id))
+    }
+}
+
+/// Add tokens to the reward pool of a proposal.
+///
+/// Voters can claim their share until `REWARD_CLAIM_PERIOD` after the end of
+/// the vote. Funding a pool again adds to it and extends its claim period.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `proposal_id` - The ID of the rewarded proposal
+/// * `token` - The token contract of the reward
+/// * `amount` - The amount taken from the treasury
+/// * `closes_at` - The end of the vote on the rewarded proposal
+///
+/// # Panics
+/// * If the pool holds another token
+pub fn fund_reward_pool(
+    env: &Env,
+    project_key: &Bytes,
+    proposal_id: u32,
+    token: Address,
+    amount: i128,
+    closes_at: u64,
+) {
+    let claim_ends_at = env.ledger().timestamp().max(closes_at) + REWARD_CLAIM_PERIOD;
+
+    let pool_key = types::RewardKey::Pool(project_key.clone(), proposal_id);
+    let mut pool = env
+        .storage()
+        .persistent()
+        .get::<_, types::RewardPool>(&pool_key)
+        .unwrap_or_else(|| {
+            let pools_key = types::RewardKey::Pools(project_key.clone());
+            let mut pools: Vec<u32> = env
+                .storage()
+                .persistent()
+                .get(&pools_key)
+                .unwrap_or(Vec::new(env));
+            pools.push_back(proposal_id);
+            env.storage().persistent().set(&pools_key, &pools);
+
+            types::RewardPool {
+                token: token.clone(),
+                amount: 0,
+                claimed: 0,
+                claims: 0,
+                claim_ends_at,
+            }
+        });
+    if pool.token != token {
+        panic_with_error!(env, &errors::ExtendedErrors::InvalidRewardPool);
+    }
+
+    pool.amount += amount;
+    pool.claim_ends_at = claim_ends_at;
+    env.storage().persistent().set(&pool_key, &pool);
+
+    events::ProposalRewardFunded {
+        project_key: project_key.clone(),
+        proposal_id,
+        token,
+        amount,
+    }
+    .publish(env);
+}
+
+/// Return the unclaimed part of the reward pool of a proposal to the
+/// treasury of its project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `proposal_id` - The ID of the proposal
+/// * `proposal` - The proposal, whose voters may have claimed their share
+fn refund_reward_pool(
+    env: &Env,
+    project_key: &Bytes,
+    proposal_id: u32,
+    proposal: &types::Proposal,
+) {
+    let Some(pool) = take_reward_pool(env, project_key, proposal_id) else {
+        return;
+    };
+
+    let amount = pool.amount - pool.claimed;
+    if amount > 0 {
+        crate::tansu(env).refund_treasury(project_key, &pool.token, &amount);
+    }
+    if pool.claims > 0 {
+        for vote_ in proposal.vote_data.votes.iter() {
+            if let types::Vote::PublicVote(vote_choice) = vote_ {
+                env.storage()
+                    .persistent()
+                    .remove(&types::RewardKey::Claimed(
+                        project_key.clone(),
+                        proposal_id,
+                        vote_choice.address,
+                    ));
+            }
+        }
+    }
+}
+
+/// Remove the reward pool of a proposal and transfer its unclaimed part
+/// back to Tansu.
+///
+/// Tansu credits the returned tokens to the treasury of the project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `proposal_id` - The ID of the proposal
+///
+/// # Returns
+/// * `Option<types::RewardPool>` - The pool removed, if any
+pub fn take_reward_pool(
+    env: &Env,
+    project_key: &Bytes,
+    proposal_id: u32,
+) -> Option<types::RewardPool> {
+    let pool_key = types::RewardKey::Pool(project_key.clone(), proposal_id);
+    let pool = env
+        .storage()
+        .persistent()
+        .get::<_, types::RewardPool>(&pool_key)?;
+
+    let amount = pool.amount - pool.claimed;
+    if amount > 0 {
+        token::TokenClient::new(env, &pool.token).transfer(
+            &env.current_contract_address(),
+            &crate::tansu(env).address,
+            &amount,
+        );
+    }
+    env.storage().persistent().remove(&pool_key);
+
+    let pools_key = types::RewardKey::Pools(project_key.clone());
+    let mut pools: Vec<u32> = env
+        .storage()
+        .persistent()
+        .get(&pools_key)
+        .unwrap_or(Vec::new(env));
+    if let Some(index) = pools.first_index_of(proposal_id) {
+        pools.remove(index);
+    }
+    if pools.is_empty() {
+        env.storage().persistent().remove(&pools_key);
+    } else {
+        env.storage().persistent().set(&pools_key, &pools);
+    }
+
+    events::ProposalRewardRefunded {
+        project_key: project_key.clone(),
+        proposal_id,
+        amount,
+    }
+    .publish(env);
+
+    Some(pool)
+}
+
+/// Return the open reward pools of a deregistered project to Tansu.
+///
+/// The claims of the pools are left behind, a new registration of the
+/// project key ignores them.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+///
+/// # Returns
+/// * `Map<Address, i128>` - The amount returned for each token
+pub fn remove_reward_pools(env: &Env, project_key: &Bytes) -> Map<Address, i128> {
+    let pools: Vec<u32> = env
+        .storage()
+        .persistent()
+        .get(&types::RewardKey::Pools(project_key.clone()))
+        .unwrap_or(Vec::new(env));
+    let mut refunds = Map::new(env);
+    for proposal_id in pools.iter() {
+        if let Some(pool) = take_reward_pool(env, project_key, proposal_id)
+            && pool.amount > pool.claimed
+        {
+            let refunded = refunds.get(pool.token.clone()).unwrap_or(0);
+            refunds.set(pool.token, refunded + pool.amount - pool.claimed);
+        }
+    }
+    env.storage().persistent().set(
+        &types::RewardKey::Epoch(project_key.clone()),
+        &(epoch(env, project_key) + 1),
+    );
+    refunds
+}
+
+/// Registration of a project key, see `RewardKey::Epoch`.
+fn epoch(env: &Env, project_key: &Bytes) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&types::RewardKey::Epoch(project_key.clone()))
+        .unwrap_or(0)
+}
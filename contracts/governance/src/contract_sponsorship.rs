@@ -0,0 +1,122 @@
+use soroban_sdk::{Address, Bytes, Env, Vec, contractimpl, panic_with_error};
+
+use crate::{
+    Governance, GovernanceArgs, GovernanceClient, GovernanceTrait, SponsorshipTrait, errors,
+    events, types,
+};
+
+#[contractimpl]
+impl SponsorshipTrait for Governance {
+    /// Sponsor a proposal so that it opens for voting.
+    ///
+    /// When the governance configuration requires sponsors, proposals only
+    /// accept votes once enough distinct badge holders sponsored them. The
+    /// voting period is not extended by the sponsorship.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `sponsor` - The address of the badge holder
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal
+    ///
+    /// # Panics
+    /// * If the proposal doesn't exist, is not active or its vote is over
+    /// * If the proposal doesn't need sponsors anymore
+    /// * If the sponsor is the proposer, holds no badge or already sponsored it
+    fn sponsor(env: Env, sponsor: Address, project_key: Bytes, proposal_id: u32) {
+        crate::require_not_paused(&env);
+        sponsor.require_auth();
+
+        let proposal = crate::tansu(&env).get_proposal(&project_key, &proposal_id);
+        if proposal.status != types::ProposalStatus::Active {
+            panic_with_error!(&env, &errors::ContractErrors::ProposalActive);
+        }
+        if env.ledger().timestamp() >= proposal.vote_data.voting_ends_at {
+            panic_with_error!(&env, &errors::ContractErrors::ProposalVotingTime);
+        }
+
+        let mut sponsorship =
+            match Self::get_sponsorship(env.clone(), project_key.clone(), proposal_id) {
+                Some(sponsorship) if sponsorship.sponsors.len() < sponsorship.required => {
+                    sponsorship
+                }
+                _ => panic_with_error!(&env, &errors::AdditionalErrors::InvalidSponsor),
+            };
+        if sponsor == proposal.proposer
+            || sponsorship.sponsors.contains(&sponsor)
+            || crate::tansu(&env).get_max_weight(&project_key, &sponsor)
+                <= types::Badge::Default as u32
+        {
+            panic_with_error!(&env, &errors::AdditionalErrors::InvalidSponsor);
+        }
+
+        sponsorship.sponsors.push_back(sponsor.clone());
+        env.storage().persistent().set(
+            &types::SponsorKey::Sponsorship(project_key.clone(), proposal_id),
+            &sponsorship,
+        );
+
+        events::ProposalSponsored {
+            project_key,
+            proposal_id,
+            sponsor,
+            sponsors: sponsorship.sponsors.len(),
+            required: sponsorship.required,
+        }
+        .publish(&env);
+    }
+
+    /// Get the sponsors of an active proposal.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal
+    ///
+    /// # Returns
+    /// * `Option<types::Sponsorship>` - The sponsors, if the proposal needs some
+    fn get_sponsorship(
+        env: Env,
+        project_key: Bytes,
+        proposal_id: u32,
+    ) -> Option<types::Sponsorship> {
+        env.storage()
+            .persistent()
+            .get(&types::SponsorKey::Sponsorship(project_key, proposal_id))
+    }
+}
+
+/// Record that a new proposal needs sponsors before it opens for voting.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `proposal_id` - The ID of the proposal
+/// * `required` - The sponsors needed, nothing is recorded for 0
+pub fn open_sponsorship(env: &Env, project_key: &Bytes, proposal_id: u32, required: u32) {
+    if required == 0 {
+        return;
+    }
+    env.storage().persistent().set(
+        &types::SponsorKey::Sponsorship(project_key.clone(), proposal_id),
+        &types::Sponsorship {
+            required,
+            sponsors: Vec::new(env),
+        },
+    );
+}
+
+/// Check that a proposal is open for voting.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `proposal_id` - The ID of the proposal
+///
+/// # Panics
+/// * If the proposal still needs sponsors
+pub fn require_sponsored(env: &Env, project_key: &Bytes, proposal_id: u32) {
+    if !Governance::is_sponsored(env.clone(), project_key.clone(), proposal_id) {
+        panic_with_error!(env, &errors::AdditionalErrors::ProposalNotSponsored);
+    }
+}
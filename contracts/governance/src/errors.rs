@@ -0,0 +1,72 @@
+//! Errors of the governance contract.
+//!
+//! Codes are shared with the Tansu contract so that clients handle the
+//! errors of both contracts alike. Only the errors raised by the governance
+//! contract are declared.
+
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractErrors {
+    // Versioning
+    UnauthorizedSigner = 3,
+    // DAO
+    ProposalInputValidation = 7,
+    NoProposalorPageFound = 8,
+    AlreadyVoted = 9,
+    ProposalVotingTime = 10,
+    ProposalActive = 11,
+    // Private voting
+    WrongVoteType = 12,
+    // Membership
+    UnknownMember = 18,
+    VoteLimitExceeded = 21,
+    // Project lifecycle
+    ProjectArchived = 26,
+    // DAO control
+    InvalidDaoAction = 39,
+    // Incident response
+    ProjectPaused = 40,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ExtendedErrors {
+    // IPFS
+    InvalidCid = 70,
+    // Treasury
+    InvalidAmount = 74,
+    InsufficientTreasury = 75,
+    // Delegation
+    VoteDelegated = 78,
+    // DAO
+    UnknownVote = 79,
+    // Conviction voting
+    ConvictionDisabled = 89,
+    ConvictionTooLow = 90,
+    InvalidSupport = 91,
+    // Participation rewards
+    InvalidRewardPool = 95,
+    RewardAlreadyClaimed = 96,
+    // Council
+    InvalidCouncil = 99,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AdditionalErrors {
+    // Federated proposals
+    InvalidFederation = 100,
+    UnknownFederation = 101,
+    // Sponsorship
+    ProposalNotSponsored = 102,
+    InvalidSponsor = 103,
+    // Commit-reveal voting
+    InvalidReveal = 104,
+    // Rewards
+    RewardClaimPeriod = 105,
+}
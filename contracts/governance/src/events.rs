@@ -0,0 +1,132 @@
+//! Event definitions for the governance contract.
+
+use crate::types::{Council, ProposalStatus};
+use soroban_sdk::{Address, Bytes, Vec, contractevent};
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundingProposalCreated {
+    #[topic]
+    pub project_key: Bytes,
+    pub proposal_id: u32,
+    pub proposer: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundingSupportChanged {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub member: Address,
+    pub proposal_id: u32,
+    pub weight: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundingProposalExecuted {
+    #[topic]
+    pub project_key: Bytes,
+    pub proposal_id: u32,
+    pub conviction: u128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalRewardFunded {
+    #[topic]
+    pub project_key: Bytes,
+    pub proposal_id: u32,
+    pub token: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalRewardClaimed {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub voter: Address,
+    pub proposal_id: u32,
+    pub amount: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalRewardRefunded {
+    #[topic]
+    pub project_key: Bytes,
+    pub proposal_id: u32,
+    pub amount: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CouncilElected {
+    #[topic]
+    pub project_key: Bytes,
+    pub council: Council,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CouncilActed {
+    #[topic]
+    pub project_key: Bytes,
+    pub signers: Vec<Address>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FederatedProposalCreated {
+    #[topic]
+    pub federated_id: u32,
+    pub proposer: Address,
+    pub project_keys: Vec<Bytes>,
+    pub required_approvals: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FederatedProposalLinked {
+    #[topic]
+    pub federated_id: u32,
+    #[topic]
+    pub project_key: Bytes,
+    pub proposal_id: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FederatedProposalResolved {
+    #[topic]
+    pub federated_id: u32,
+    pub status: ProposalStatus,
+    pub approvals: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalSponsored {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub proposal_id: u32,
+    pub sponsor: Address,
+    pub sponsors: u32,
+    pub required: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteCommitted {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub proposal_id: u32,
+    pub voter: Address,
+}
@@ -0,0 +1,246 @@
+#![no_std]
+
+use soroban_sdk::contractmeta;
+use soroban_sdk::{Address, Bytes, BytesN, Env, Map, String, Vec, contract, panic_with_error};
+
+mod contract_conviction;
+mod contract_council;
+mod contract_federation;
+mod contract_governance;
+mod contract_reveal;
+mod contract_rewards;
+mod contract_sponsorship;
+pub mod errors;
+mod events;
+mod tansu;
+pub mod types;
+mod validation;
+
+contractmeta!(key = "Description", val = "Tansu - Governance extensions");
+
+#[contract]
+pub struct Governance;
+
+pub trait GovernanceTrait {
+    fn __constructor(env: Env, tansu: Address);
+
+    fn get_tansu(env: Env) -> Address;
+
+    fn open_sponsorship(env: Env, project_key: Bytes, proposal_id: u32, required: u32);
+
+    fn is_sponsored(env: Env, project_key: Bytes, proposal_id: u32) -> bool;
+
+    fn close_proposal(env: Env, project_key: Bytes, proposal_id: u32) -> Vec<Address>;
+
+    fn fund_reward_pool(
+        env: Env,
+        project_key: Bytes,
+        proposal_id: u32,
+        token: Address,
+        amount: i128,
+        closes_at: u64,
+    );
+
+    fn refund_reward_pool(
+        env: Env,
+        project_key: Bytes,
+        proposal_id: u32,
+    ) -> Option<types::RewardPool>;
+
+    fn set_council(env: Env, project_key: Bytes, council: types::Council);
+
+    fn remove_project(env: Env, project_key: Bytes) -> Map<Address, i128>;
+}
+
+pub trait ConvictionTrait {
+    #[allow(clippy::too_many_arguments)]
+    fn create_funding_proposal(
+        env: Env,
+        proposer: Address,
+        project_key: Bytes,
+        title: String,
+        ipfs: String,
+        token: Address,
+        recipient: Address,
+        amount: i128,
+    ) -> u32;
+
+    fn support_funding_proposal(
+        env: Env,
+        member: Address,
+        project_key: Bytes,
+        proposal_id: u32,
+        weight: u32,
+    );
+
+    fn execute_funding_proposal(
+        env: Env,
+        executor: Address,
+        project_key: Bytes,
+        proposal_id: u32,
+    ) -> u128;
+
+    fn get_funding_proposal(
+        env: Env,
+        project_key: Bytes,
+        proposal_id: u32,
+    ) -> types::FundingProposal;
+
+    fn get_required_conviction(env: Env, project_key: Bytes, proposal_id: u32) -> u128;
+}
+
+pub trait RewardsTrait {
+    fn claim_proposal_reward(
+        env: Env,
+        voter: Address,
+        project_key: Bytes,
+        proposal_id: u32,
+    ) -> i128;
+
+    fn close_proposal_reward(env: Env, project_key: Bytes, proposal_id: u32);
+
+    fn get_proposal_reward(
+        env: Env,
+        project_key: Bytes,
+        proposal_id: u32,
+    ) -> Option<types::RewardPool>;
+}
+
+pub trait CouncilTrait {
+    fn council_execute(
+        env: Env,
+        signers: Vec<Address>,
+        project_key: Bytes,
+        action: types::DaoAction,
+    );
+
+    fn get_council(env: Env, project_key: Bytes) -> Option<types::Council>;
+}
+
+pub trait FederationTrait {
+    fn create_federated_proposal(
+        env: Env,
+        proposer: Address,
+        title: String,
+        ipfs: String,
+        project_keys: Vec<Bytes>,
+        required_approvals: u32,
+    ) -> u32;
+
+    fn link_federated_proposal(
+        env: Env,
+        maintainer: Address,
+        federated_id: u32,
+        project_key: Bytes,
+        proposal_id: u32,
+    );
+
+    fn resolve_federated_proposal(env: Env, federated_id: u32) -> types::ProposalStatus;
+
+    fn get_federated_proposal(env: Env, federated_id: u32) -> types::FederatedProposal;
+}
+
+pub trait RevealTrait {
+    fn commit_vote(
+        env: Env,
+        voter: Address,
+        project_key: Bytes,
+        proposal_id: u32,
+        commitment: BytesN<32>,
+    );
+
+    fn reveal_vote(
+        env: Env,
+        voter: Address,
+        project_key: Bytes,
+        proposal_id: u32,
+        vote: types::PublicVote,
+        salt: Bytes,
+    );
+
+    fn get_vote_commitments(
+        env: Env,
+        project_key: Bytes,
+        proposal_id: u32,
+    ) -> Map<Address, BytesN<32>>;
+}
+
+pub trait SponsorshipTrait {
+    fn sponsor(env: Env, sponsor: Address, project_key: Bytes, proposal_id: u32);
+
+    fn get_sponsorship(
+        env: Env,
+        project_key: Bytes,
+        proposal_id: u32,
+    ) -> Option<types::Sponsorship>;
+}
+
+/// Get a client of the Tansu contract the projects are registered with.
+fn tansu(env: &Env) -> tansu::TansuClient<'_> {
+    let address = <Governance as GovernanceTrait>::get_tansu(env.clone());
+    tansu::TansuClient::new(env, &address)
+}
+
+/// Require that the Tansu contract is not paused, panic if it is.
+fn require_not_paused(env: &Env) {
+    tansu(env).require_not_paused();
+}
+
+/// Authenticate a maintainer of a project.
+///
+/// # Panics
+/// * If the project doesn't exist
+/// * If the maintainer is not authorized
+fn auth_maintainers(env: &Env, maintainer: &Address, project_key: &Bytes) {
+    maintainer.require_auth();
+    tansu(env).get_maintainer_role(project_key, maintainer);
+}
+
+/// Require that the project is not archived, panic if it is.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+///
+/// # Panics
+/// * If the project is archived
+fn require_not_archived(env: &Env, project_key: &Bytes) {
+    if tansu(env).is_archived(project_key) {
+        panic_with_error!(&env, &errors::ContractErrors::ProjectArchived);
+    }
+}
+
+/// Require that the project is not paused, panic if it is.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+///
+/// # Panics
+/// * If the project is paused
+fn require_project_not_paused(env: &Env, project_key: &Bytes) {
+    if tansu(env).is_project_paused(project_key) {
+        panic_with_error!(&env, &errors::ContractErrors::ProjectPaused);
+    }
+}
+
+/// Whether a proposal takes hashed votes, see `commit_vote`.
+fn takes_hashed_votes(proposal: &types::Proposal) -> bool {
+    proposal.vote_data.public_voting && proposal.reveal_ends_at.is_some()
+}
+
+/// End of the vote on a proposal.
+///
+/// Proposals taking hashed votes close at the end of their reveal period.
+///
+/// # Arguments
+/// * `proposal` - The proposal
+///
+/// # Returns
+/// * `u64` - UNIX timestamp after which no vote can be counted anymore
+fn vote_closes_at(proposal: &types::Proposal) -> u64 {
+    match (takes_hashed_votes(proposal), proposal.reveal_ends_at) {
+        (true, Some(reveal_ends_at)) => reveal_ends_at,
+        _ => proposal.vote_data.voting_ends_at,
+    }
+}
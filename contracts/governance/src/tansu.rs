@@ -0,0 +1,92 @@
+//! Tansu contract the projects are registered with.
+//!
+//! Projects, members, proposals and treasuries live in Tansu, the governance
+//! contract only stores the state of the governance extensions. Treasury
+//! transfers and votes go through Tansu, which only accepts them from the
+//! governance contract.
+
+use soroban_sdk::{Address, Bytes, Env, Map, Val, contractclient};
+
+use crate::types;
+
+/// Functions of the Tansu contract used by the governance contract.
+///
+/// The governance contract only uses the generated `TansuClient`.
+#[allow(dead_code)]
+#[contractclient(name = "TansuClient")]
+pub trait TansuInterface {
+    /// Panic if the contract is paused.
+    fn require_not_paused(env: Env);
+
+    /// Badges of the members of a project.
+    fn get_badges(env: Env, key: Bytes) -> types::Badges;
+
+    /// Sum of the badge weights of a member, 1 without badges.
+    fn get_max_weight(env: Env, key: Bytes, member_address: Address) -> u32;
+
+    /// Role of a maintainer, panics if the address is not a maintainer.
+    fn get_maintainer_role(env: Env, key: Bytes, maintainer: Address) -> types::MaintainerRole;
+
+    /// Whether a project is archived.
+    fn is_archived(env: Env, key: Bytes) -> bool;
+
+    /// Whether a project is paused.
+    fn is_project_paused(env: Env, key: Bytes) -> bool;
+
+    /// Project, panics if it doesn't exist. The governance contract only
+    /// checks that the project exists.
+    fn get_project(env: Env, project_key: Bytes) -> Val;
+
+    /// Voting rules of the DAO of a project.
+    fn get_governance_config(env: Env, project_key: Bytes) -> types::GovernanceConfig;
+
+    /// Balance of each token in the treasury of a project.
+    fn get_treasury(env: Env, project_key: Bytes) -> Map<Address, i128>;
+
+    /// Delegate of each member of a project.
+    fn get_delegations(env: Env, project_key: Bytes) -> Map<Address, Address>;
+
+    /// Weight of a voter on a proposal.
+    fn get_voting_weight(
+        env: Env,
+        project_key: Bytes,
+        proposal_id: u32,
+        member_address: Address,
+    ) -> u32;
+
+    /// Current voting weight of a member, as counted for new proposals.
+    fn get_member_weight(env: Env, project_key: Bytes, member: Address) -> u32;
+
+    /// Proposal of the DAO of a project, panics if it doesn't exist.
+    fn get_proposal(env: Env, project_key: Bytes, proposal_id: u32) -> types::Proposal;
+
+    /// Transfer tokens from the treasury of a project, authorized by the
+    /// governance contract.
+    fn spend_treasury(
+        env: Env,
+        project_key: Bytes,
+        executor: Address,
+        token: Address,
+        recipient: Address,
+        amount: i128,
+    );
+
+    /// Credit tokens returned by the governance contract to the treasury of
+    /// a project.
+    fn refund_treasury(env: Env, project_key: Bytes, token: Address, amount: i128);
+
+    /// Record a new proposal of an address, panics within the cooldown.
+    fn use_proposal_cooldown(env: Env, project_key: Bytes, proposer: Address);
+
+    /// Take the collateral of a hashed vote from the voter.
+    fn take_vote_collateral(env: Env, voter: Address);
+
+    /// Record a hashed vote revealed after the voting period.
+    fn cast_revealed_vote(
+        env: Env,
+        voter: Address,
+        project_key: Bytes,
+        proposal_id: u32,
+        vote: types::PublicVote,
+    );
+}
@@ -0,0 +1,303 @@
+use soroban_sdk::{Address, Bytes, BytesN, Map, String, Symbol, Val, Vec, contracttype};
+
+// Constants
+pub const MIN_CID_LENGTH: u32 = 46;
+pub const MAX_CID_LENGTH: u32 = 128;
+pub const MIN_TITLE_LENGTH: u32 = 10;
+pub const MAX_TITLE_LENGTH: u32 = 256;
+pub const MAX_VOTES_PER_PROPOSAL: u32 = 1000; // DoS protection
+pub const MAX_FEDERATED_PROJECTS: u32 = 10;
+pub const FEDERATION_PERIOD: u64 = 60 * 24 * 3600; // 60 days in seconds
+
+#[contracttype]
+pub enum DataKey {
+    Tansu, // Tansu contract the projects are registered with
+}
+
+#[contracttype]
+pub enum FundingKey {
+    Proposal(Bytes, u32),   // funding proposal using conviction voting
+    Total(Bytes),           // number of funding proposals of the project
+    Support(Bytes, u32),    // weight of each supporter of a funding proposal
+    Stakes(Bytes, Address), // weight of a member on each funding proposal
+}
+
+#[contracttype]
+pub enum RewardKey {
+    Pool(Bytes, u32), // tokens from the treasury split among the voters of a proposal
+    Pools(Bytes),     // proposals of a project with an open reward pool
+    Claimed(Bytes, u32, Address), // voter claimed its share of a reward pool, in this registration
+    Epoch(Bytes),     // registration of a project key, claims of removed projects are ignored
+}
+
+#[contracttype]
+pub enum CouncilKey {
+    Council(Bytes), // council elected by the DAO of a project
+}
+
+#[contracttype]
+pub enum FederationKey {
+    Count,         // number of federated proposals
+    Proposal(u32), // federated proposal, id
+}
+
+#[contracttype]
+pub enum SponsorKey {
+    Sponsorship(Bytes, u32), // sponsors of a proposal which needs some to open for voting
+}
+
+#[contracttype]
+pub enum RevealKey {
+    Commitments(Bytes, u32), // hashed votes of a proposal which are not revealed yet
+}
+
+/// Funding request of the treasury passing once enough conviction
+/// accumulated, see `create_funding_proposal`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FundingProposal {
+    pub id: u32,
+    pub title: String,
+    pub proposer: Address,
+    pub ipfs: String,
+    pub token: Address,
+    pub recipient: Address,
+    pub amount: i128,
+    pub support: u128,    // weight currently supporting the proposal
+    pub conviction: u128, // support smoothed over ledgers
+    pub updated_at: u32,  // ledger of the last conviction update
+    pub status: ProposalStatus,
+}
+
+/// Reward split among the voters of a proposal, see `DaoAction::FundReward`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RewardPool {
+    pub token: Address,
+    pub amount: i128,
+    pub claimed: i128,      // amount already paid to voters
+    pub claims: u32,        // number of voters who claimed their share
+    pub claim_ends_at: u64, // unclaimed rewards return to the treasury afterwards
+}
+
+/// Members elected to act for the DAO of a project, see `council_execute`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Council {
+    pub members: Vec<Address>, // empty to dissolve the council
+    pub threshold: u32,        // signatures of members needed to act
+    pub spend_limit: i128,     // largest treasury transfer the council can make
+}
+
+/// Proposal voted by the DAO of several projects, see
+/// `create_federated_proposal`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FederatedProposal {
+    pub id: u32,
+    pub title: String,
+    pub ipfs: String,
+    pub proposer: Address,
+    pub project_keys: Vec<Bytes>,
+    pub required_approvals: u32,    // approved project proposals needed
+    pub proposals: Map<Bytes, u32>, // proposal voted by each project which linked one
+    pub status: ProposalStatus,
+    pub expires_at: u64, // UNIX timestamp after which projects cannot link proposals
+}
+
+/// Sponsors of a proposal, see `sponsor`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Sponsorship {
+    pub required: u32, // sponsors needed for the proposal to open for voting
+    pub sponsors: Vec<Address>,
+}
+
+// Types of the Tansu contract, see `tansu::TansuInterface`.
+
+#[contracttype(export = false)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Badges {
+    pub developer: Vec<Address>,
+    pub triage: Vec<Address>,
+    pub community: Vec<Address>,
+    pub verified: Vec<Address>,
+    pub reviewer: Vec<Address>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Badge {
+    Developer = 10_000_000,
+    Triage = 5_000_000,
+    Reviewer = 2_000_000, // can attest code reviews
+    Community = 1_000_000,
+    Verified = 500_000, // have a soroban domain
+    Default = 1,
+}
+
+/// Permissions of a maintainer on a project, each role includes the previous ones.
+#[contracttype(export = false)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum MaintainerRole {
+    Committer = 0, // can commit
+    Releaser = 1,  // can commit and release
+    Admin = 2,     // can also execute proposals
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProposalStatus {
+    Active,
+    Approved,
+    Rejected,
+    Cancelled,
+    Malicious,
+    Vetoed,
+    Expired, // approved but executed after the execution window, or anonymous tallies not revealed in time
+}
+
+#[contracttype(export = false)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Vote {
+    PublicVote(PublicVote),
+    AnonymousVote(AnonymousVote),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum VoteChoice {
+    Approve,
+    Reject,
+    Abstain,
+    Choice(u32), // index of an option of a multiple-choice proposal
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PublicVote {
+    pub address: Address,
+    pub weight: u32,
+    pub vote_choice: VoteChoice,
+}
+
+#[contracttype(export = false)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct AnonymousVote {
+    pub address: Address,
+    pub weight: u32,
+    pub encrypted_seeds: Vec<String>,
+    pub encrypted_votes: Vec<String>,
+    pub commitments: Vec<BytesN<96>>,
+}
+
+#[contracttype(export = false)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct VoteData {
+    pub voting_ends_at: u64,
+    pub public_voting: bool,
+    pub votes: Vec<Vote>,
+    pub eligible_weight: u128, // weight of the badges of the project when the proposal was created
+}
+
+/// Kind of a proposal, deciding what is applied when it is approved.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProposalKind {
+    Text,             // advisory, nothing is applied
+    ConfigChange,     // configuration, governance rules, license or badges
+    Treasury,         // spending from the treasury of the project
+    Upgrade,          // new commit or release promotion
+    MaintainerChange, // maintainers of a DAO controlled project
+    ContractCall,     // call of another contract
+}
+
+#[contracttype(export = false)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Proposal {
+    pub id: u32,
+    pub title: String,
+    pub proposer: Address,
+    pub ipfs: String,
+    pub vote_data: VoteData,
+    pub status: ProposalStatus,
+    pub outcomes_contract: Option<Address>,
+    pub kind: ProposalKind,
+    pub created_at: u64,             // UNIX timestamp
+    pub deposit: i128,               // bond of the proposer in collateral asset
+    pub depends_on: Option<u32>,     // proposal which must be approved first
+    pub options: Vec<String>,        // labels of a multiple-choice proposal, empty otherwise
+    pub winning_option: Option<u32>, // option with the most weight once approved
+    pub cosigner: Option<Address>,   // maintainer co-signing an emergency proposal
+    pub reveal_ends_at: Option<u64>, // end of the reveal of hashed public votes or anonymous tallies, None without deadline
+}
+
+/// Channel of a release, releases are promoted from one channel to the next.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ReleaseChannel {
+    Nightly = 0,
+    Rc = 1,
+    Stable = 2,
+}
+
+/// Source of the voting weight of the members of a project.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VotingWeight {
+    Badges,
+    Token(Address),          // whole tokens held, badges only grant membership
+    BadgesAndToken(Address), // sum of the badges and of the whole tokens held
+}
+
+/// Change applied to a DAO controlled project when a proposal is approved.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DaoAction {
+    Commit(String),                 // new commit hash
+    UpdateConfig(String, String),   // url, ipfs
+    SetBadges(Address, Vec<Badge>), // member, badges
+    SetGovernanceConfig(GovernanceConfig),
+    SetLicense(String),                     // SPDX license identifier
+    PromoteRelease(String, ReleaseChannel), // tag, channel
+    YankRelease(String, String),            // tag, CID of the reason
+    ArchiveRelease(String),                 // tag
+    // tag, commit hash, artifact CID, changelog CID, channel
+    CreateRelease(String, String, String, String, ReleaseChannel),
+    Transfer(Address, Address, i128), // token, recipient, amount from the treasury
+    SetMaintainers(Vec<Address>),
+    Invoke(Address, Symbol, Vec<Val>), // contract, function, arguments
+    SetCouncil(Council),
+    FundReward(u32, Address, i128), // proposal id, token, amount from the treasury
+}
+
+/// Voting rules of the DAO of a project.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceConfig {
+    pub min_voting_period: u64, // in seconds
+    pub max_voting_period: u64, // in seconds
+    pub max_title_length: u32,
+    pub quorum: u32, // minimal total weight cast, otherwise the proposal is cancelled
+    pub approval_thresholds: Map<ProposalKind, u32>, // share of the weight cast in basis points, simple majority if not set
+    pub abstain_in_quorum: bool,                     // abstain weight counts toward the quorum
+    pub abstain_in_approval: bool,                   // abstain weight counts in the approval ratio
+    pub veto_period: u64, // in seconds after the vote, maintainers can veto approved proposals, 0 to disable
+    pub proposal_deposit: i128, // bond of proposers in collateral asset, 0 to disable
+    pub spam_quorum_bps: u32, // share of the quorum in basis points under which the deposit is slashed
+    pub proposal_cooldown: u32, // ledgers between two proposals of an address, 0 to disable
+    pub quadratic_voting: bool, // public votes weigh the square root of their weight
+    pub conviction_half_life: u32, // ledgers for conviction to move halfway to the support, 0 to disable
+    pub conviction_min_support_bps: u32, // share of the total weight funding a negligible amount
+    pub voting_weight: VotingWeight, // read for each member holding a badge when a proposal is created
+    pub unbonding_period: u64,       // in seconds before unstaked tokens can be withdrawn
+    pub reputation_half_life: u32,   // ledgers for reputation to halve, 0 to disable
+    pub reputation_weight: u32, // voting weight per reputation point, 0 to not count reputation
+    pub execution_window: u64, // in seconds after the vote and veto period to execute, 0 to disable
+    pub emergency_voting_period: u64, // minimal voting period of emergency proposals in seconds, 0 to disable
+    pub emergency_threshold: u32,     // approval share of emergency proposals in basis points
+    pub sponsors_required: u32, // badge holders sponsoring a proposal before it opens for voting, 0 to disable
+    pub max_voter_weight_bps: u32, // share of the eligible weight each voter or delegator casts at most, 0 to disable
+    pub reveal_period: u64, // in seconds after the vote to reveal hashed public votes, 0 to vote directly
+    pub tally_reveal_period: u64, // in seconds after the vote to reveal anonymous tallies, 0 for no deadline
+}
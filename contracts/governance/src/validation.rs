@@ -0,0 +1,52 @@
+//! Validation of user inputs.
+
+use soroban_sdk::{Env, String, panic_with_error};
+
+use crate::{errors, types};
+
+/// Validate an IPFS CID.
+///
+/// CIDv0 are 46 base58btc characters starting with `Qm`. CIDv1 are
+/// multibase encoded, the first character being the base: `b` for
+/// lowercase base32, `k` for base36 or `z` for base58btc.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `cid` - The CID to validate
+///
+/// # Panics
+/// * If the CID is shorter than `MIN_CID_LENGTH` or longer than
+///   `MAX_CID_LENGTH`
+/// * If the multibase prefix is unknown or the CID contains characters
+///   outside of its base alphabet
+pub fn validate_cid(env: &Env, cid: &String) {
+    let str_len = cid.len() as usize;
+    if str_len < types::MIN_CID_LENGTH as usize || str_len > types::MAX_CID_LENGTH as usize {
+        panic_with_error!(&env, &errors::ExtendedErrors::InvalidCid);
+    }
+
+    let mut slice = [0u8; types::MAX_CID_LENGTH as usize];
+    cid.copy_into_slice(&mut slice[..str_len]);
+    let cid_b = &slice[..str_len];
+
+    let valid = match cid_b {
+        [b'Q', b'm', ..] if str_len == 46 => cid_b.iter().all(is_base58),
+        [b'b', encoded @ ..] => encoded
+            .iter()
+            .all(|c| c.is_ascii_lowercase() || (b'2'..=b'7').contains(c)),
+        [b'k', encoded @ ..] => encoded
+            .iter()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()),
+        [b'z', encoded @ ..] => encoded.iter().all(is_base58),
+        _ => false,
+    };
+    if !valid {
+        panic_with_error!(&env, &errors::ExtendedErrors::InvalidCid);
+    }
+}
+
+/// Whether a character is in the base58btc alphabet, which excludes `0`,
+/// `O`, `I` and `l`.
+fn is_base58(c: &u8) -> bool {
+    c.is_ascii_alphanumeric() && !matches!(c, b'0' | b'O' | b'I' | b'l')
+}
@@ -0,0 +1,287 @@
+use soroban_sdk::{Address, Bytes, Env, String, Vec, contractimpl, panic_with_error};
+
+use crate::{
+    AdvisoriesTrait, Registry, RegistryArgs, RegistryClient, errors, events, types, validation,
+};
+
+#[contractimpl]
+impl AdvisoriesTrait for Registry {
+    /// Publish a security advisory for releases of a project.
+    ///
+    /// Advisories stay open until resolved, so consumers can check the
+    /// releases they depend on, like a RUSTSEC-style database.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `key` - The project key identifier
+    /// * `id` - The advisory id, e.g. `RUSTSEC-2025-0001`
+    /// * `severity` - The severity of the vulnerability
+    /// * `affected_releases` - The affected release tags
+    /// * `cid` - CID of the advisory details
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the id is invalid or already published
+    /// * If the CID is malformed
+    /// * If an affected release is listed twice
+    /// * If an affected release doesn't exist
+    /// * If the maximum number of advisories is reached
+    fn publish_advisory(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        id: String,
+        severity: types::Severity,
+        affected_releases: Vec<String>,
+        cid: String,
+    ) {
+        crate::require_not_paused(&env);
+
+        crate::auth_maintainers(&env, &maintainer, &key);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        validation::validate_advisory_id(&env, &id);
+        validate_advisory(&env, &key, &affected_releases, &cid);
+        let mut ids = Self::get_advisories(env.clone(), key.clone());
+        if ids.contains(&id) || ids.len() >= types::MAX_ADVISORIES {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidAdvisory);
+        }
+        ids.push_back(id.clone());
+
+        env.storage()
+            .persistent()
+            .set(&types::AdvisoryKey::Ids(key.clone()), &ids);
+        env.storage().persistent().set(
+            &types::AdvisoryKey::Advisory(key.clone(), id.clone()),
+            &types::Advisory {
+                id: id.clone(),
+                severity,
+                affected_releases: affected_releases.clone(),
+                cid: cid.clone(),
+                open: true,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        events::AdvisoryPublished {
+            project_key: key,
+            id,
+            maintainer,
+            severity,
+            affected_releases,
+            cid,
+        }
+        .publish(&env);
+    }
+
+    /// Amend a security advisory of a project.
+    ///
+    /// The severity, affected releases and details can change as the
+    /// investigation goes on. Resolved advisories stay resolved.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `key` - The project key identifier
+    /// * `id` - The advisory id
+    /// * `severity` - The new severity
+    /// * `affected_releases` - The new affected release tags
+    /// * `cid` - CID of the new advisory details
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the advisory doesn't exist
+    /// * If the CID is malformed
+    /// * If an affected release is listed twice
+    /// * If an affected release doesn't exist
+    fn amend_advisory(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        id: String,
+        severity: types::Severity,
+        affected_releases: Vec<String>,
+        cid: String,
+    ) {
+        crate::require_not_paused(&env);
+
+        crate::auth_maintainers(&env, &maintainer, &key);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        let mut advisory = Self::get_advisory(env.clone(), key.clone(), id.clone());
+        validate_advisory(&env, &key, &affected_releases, &cid);
+        advisory.severity = severity;
+        advisory.affected_releases = affected_releases.clone();
+        advisory.cid = cid.clone();
+        advisory.timestamp = env.ledger().timestamp();
+        env.storage().persistent().set(
+            &types::AdvisoryKey::Advisory(key.clone(), id.clone()),
+            &advisory,
+        );
+
+        events::AdvisoryAmended {
+            project_key: key,
+            id,
+            maintainer,
+            severity,
+            affected_releases,
+            cid,
+        }
+        .publish(&env);
+    }
+
+    /// Resolve a security advisory of a project, e.g. once fixed releases
+    /// are out.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `key` - The project key identifier
+    /// * `id` - The advisory id
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the advisory doesn't exist or is already resolved
+    fn resolve_advisory(env: Env, maintainer: Address, key: Bytes, id: String) {
+        crate::require_not_paused(&env);
+
+        crate::auth_maintainers(&env, &maintainer, &key);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        let mut advisory = Self::get_advisory(env.clone(), key.clone(), id.clone());
+        if !advisory.open {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidAdvisory);
+        }
+        advisory.open = false;
+        advisory.timestamp = env.ledger().timestamp();
+        env.storage().persistent().set(
+            &types::AdvisoryKey::Advisory(key.clone(), id.clone()),
+            &advisory,
+        );
+
+        events::AdvisoryResolved {
+            project_key: key,
+            id,
+            maintainer,
+        }
+        .publish(&env);
+    }
+
+    /// Get a security advisory of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    /// * `id` - The advisory id
+    ///
+    /// # Returns
+    /// * `types::Advisory` - The advisory
+    ///
+    /// # Panics
+    /// * If the advisory doesn't exist
+    fn get_advisory(env: Env, key: Bytes, id: String) -> types::Advisory {
+        env.storage()
+            .persistent()
+            .get(&types::AdvisoryKey::Advisory(key, id))
+            .unwrap_or_else(|| panic_with_error!(&env, &errors::ExtendedErrors::UnknownAdvisory))
+    }
+
+    /// Get the ids of the security advisories of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Vec<String>` - The advisory ids, oldest first
+    fn get_advisories(env: Env, key: Bytes) -> Vec<String> {
+        env.storage()
+            .persistent()
+            .get(&types::AdvisoryKey::Ids(key))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get the open security advisories of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Vec<types::Advisory>` - The advisories not resolved yet, oldest
+    ///   first
+    fn get_open_advisories(env: Env, key: Bytes) -> Vec<types::Advisory> {
+        let mut advisories = Vec::new(&env);
+        for id in Self::get_advisories(env.clone(), key.clone()).iter() {
+            let advisory = Self::get_advisory(env.clone(), key.clone(), id);
+            if advisory.open {
+                advisories.push_back(advisory);
+            }
+        }
+        advisories
+    }
+}
+
+/// Validate the details of an advisory.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+/// * `affected_releases` - The affected release tags
+/// * `cid` - CID of the advisory details
+///
+/// # Panics
+/// * If the CID is malformed
+/// * If an affected release is listed twice
+/// * If an affected release doesn't exist
+fn validate_advisory(env: &Env, key: &Bytes, affected_releases: &Vec<String>, cid: &String) {
+    validation::validate_cid(env, cid);
+    if affected_releases.len() > types::MAX_RELEASES {
+        panic_with_error!(env, &errors::ExtendedErrors::InvalidAdvisory);
+    }
+    for (i, tag) in affected_releases.iter().enumerate() {
+        if affected_releases.first_index_of(&tag) != Some(i as u32) {
+            panic_with_error!(env, &errors::ExtendedErrors::InvalidAdvisory);
+        }
+        if !env
+            .storage()
+            .persistent()
+            .has(&types::ReleaseKey::Release(key.clone(), tag))
+        {
+            panic_with_error!(env, &errors::ContractErrors::UnknownRelease);
+        }
+    }
+}
+
+/// Remove all advisories of a project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+pub fn remove_advisories(env: &Env, key: &Bytes) {
+    let ids_key = types::AdvisoryKey::Ids(key.clone());
+    let ids: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&ids_key)
+        .unwrap_or(Vec::new(env));
+    for id in ids.iter() {
+        env.storage()
+            .persistent()
+            .remove(&types::AdvisoryKey::Advisory(key.clone(), id));
+    }
+    env.storage().persistent().remove(&ids_key);
+}
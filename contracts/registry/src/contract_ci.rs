@@ -0,0 +1,167 @@
+use soroban_sdk::{Address, Bytes, Env, String, Vec, contractimpl, panic_with_error};
+
+use crate::{CiTrait, Registry, RegistryArgs, RegistryClient, errors, events, types, validation};
+
+#[contractimpl]
+impl CiTrait for Registry {
+    /// Post the status of a CI run for a commit of a project.
+    ///
+    /// Statuses are reported by registered attestors, see `set_attestor`, so
+    /// other contracts can check that the tests passed for a commit without
+    /// trusting the maintainers alone. An attestor has one run per commit,
+    /// updated as the run goes on. Only the last `MAX_CI_COMMITS` commits
+    /// with runs are kept.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `attestor` - The address of the attestor calling this function
+    /// * `key` - The project key identifier
+    /// * `commit_hash` - The commit hash tested
+    /// * `status` - The status of the run
+    /// * `run_cid` - CID of the run logs and reports
+    ///
+    /// # Panics
+    /// * If the attestor is not registered
+    /// * If the project doesn't exist
+    /// * If the commit hash does not match the hash algorithm of the project
+    /// * If the run CID is malformed
+    /// * If the maximum number of runs of the commit is reached
+    fn ci_status(
+        env: Env,
+        attestor: Address,
+        key: Bytes,
+        commit_hash: String,
+        status: types::CiStatus,
+        run_cid: String,
+    ) {
+        crate::require_not_paused(&env);
+
+        attestor.require_auth();
+        if !crate::tansu(&env).is_attestor(&attestor) {
+            panic_with_error!(&env, &errors::ExtendedErrors::NotAttestor);
+        }
+
+        crate::tansu(&env).get_project(&key);
+        let algorithm = crate::tansu(&env).get_hash_algorithm(&key);
+        validation::validate_commit_hash(&env, &commit_hash, algorithm);
+        validation::validate_cid(&env, &run_cid);
+
+        let run = types::CiRun {
+            attestor: attestor.clone(),
+            status,
+            run_cid: run_cid.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        let mut runs = Self::get_ci_runs(env.clone(), key.clone(), commit_hash.clone());
+        match runs.iter().position(|run| run.attestor == attestor) {
+            Some(index) => runs.set(index as u32, run),
+            None => {
+                if runs.len() >= types::MAX_CI_RUNS {
+                    panic_with_error!(&env, &errors::ExtendedErrors::InvalidAttestation);
+                }
+                if runs.is_empty() {
+                    track_ci_commit(&env, &key, &commit_hash);
+                }
+                runs.push_back(run);
+            }
+        }
+        env.storage()
+            .persistent()
+            .set(&types::CiKey::Runs(key.clone(), commit_hash.clone()), &runs);
+
+        events::CiStatusPosted {
+            project_key: key,
+            commit_hash,
+            attestor,
+            status,
+            run_cid,
+        }
+        .publish(&env);
+    }
+
+    /// Get the CI runs of a commit of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    /// * `commit_hash` - The commit hash
+    ///
+    /// # Returns
+    /// * `Vec<types::CiRun>` - The runs, one per attestor
+    fn get_ci_runs(env: Env, key: Bytes, commit_hash: String) -> Vec<types::CiRun> {
+        env.storage()
+            .persistent()
+            .get(&types::CiKey::Runs(key, commit_hash))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get the CI run of a commit reported by an attestor.
+    ///
+    /// Contracts gating on CI pick the attestors they trust and check that
+    /// the status is `Passed`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    /// * `commit_hash` - The commit hash
+    /// * `attestor` - The address of the attestor
+    ///
+    /// # Returns
+    /// * `Option<types::CiRun>` - The run, `None` if the attestor reported
+    ///   no status for the commit
+    fn get_ci_status(
+        env: Env,
+        key: Bytes,
+        commit_hash: String,
+        attestor: Address,
+    ) -> Option<types::CiRun> {
+        Self::get_ci_runs(env, key, commit_hash)
+            .iter()
+            .find(|run| run.attestor == attestor)
+    }
+}
+
+/// Track a commit with CI runs, dropping the runs of the oldest commit
+/// beyond `MAX_CI_COMMITS`.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+/// * `commit_hash` - The commit hash
+fn track_ci_commit(env: &Env, key: &Bytes, commit_hash: &String) {
+    let commits_key = types::CiKey::Commits(key.clone());
+    let mut commits: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&commits_key)
+        .unwrap_or(Vec::new(env));
+    if commits.len() >= types::MAX_CI_COMMITS
+        && let Some(oldest) = commits.pop_front()
+    {
+        env.storage()
+            .persistent()
+            .remove(&types::CiKey::Runs(key.clone(), oldest));
+    }
+    commits.push_back(commit_hash.clone());
+    env.storage().persistent().set(&commits_key, &commits);
+}
+
+/// Remove all CI runs of a project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+pub fn remove_ci_runs(env: &Env, key: &Bytes) {
+    let commits_key = types::CiKey::Commits(key.clone());
+    let commits: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&commits_key)
+        .unwrap_or(Vec::new(env));
+    for commit_hash in commits.iter() {
+        env.storage()
+            .persistent()
+            .remove(&types::CiKey::Runs(key.clone(), commit_hash));
+    }
+    env.storage().persistent().remove(&commits_key);
+}
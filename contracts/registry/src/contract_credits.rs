@@ -0,0 +1,156 @@
+use soroban_sdk::{Address, Bytes, Env, Map, Vec, contractimpl, panic_with_error};
+
+use crate::{
+    CreditsTrait, Registry, RegistryArgs, RegistryClient, ReleasesTrait, errors, events, types,
+    validation,
+};
+
+#[contractimpl]
+impl CreditsTrait for Registry {
+    /// Credit a contributor for a commit or a release of a project.
+    ///
+    /// Shares are in basis points and the shares of a commit or release
+    /// cannot exceed `TOTAL_SHARE_BPS`, so they can later be used to split
+    /// rewards. Crediting a contributor again replaces their share, a share
+    /// of zero removes the credit.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `key` - The project key identifier
+    /// * `target` - The commit or release credited
+    /// * `contributor` - The address of the contributor
+    /// * `share_bps` - The share of the contributor in basis points
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the commit hash does not match the hash algorithm of the project
+    /// * If the release doesn't exist
+    /// * If the shares of the target would exceed `TOTAL_SHARE_BPS`
+    /// * If removing the credit of a contributor who has none
+    /// * If the maximum number of contributors or credited targets is reached
+    fn credit(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        target: types::CreditTarget,
+        contributor: Address,
+        share_bps: u32,
+    ) {
+        crate::require_not_paused(&env);
+
+        crate::auth_maintainers(&env, &maintainer, &key);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        match &target {
+            types::CreditTarget::Commit(hash) => {
+                let algorithm = crate::tansu(&env).get_hash_algorithm(&key);
+                validation::validate_commit_hash(&env, hash, algorithm);
+            }
+            types::CreditTarget::Release(tag) => {
+                <Registry as ReleasesTrait>::get_release(env.clone(), key.clone(), tag.clone());
+            }
+        }
+
+        let mut credits = Self::get_credits(env.clone(), key.clone(), target.clone());
+        let previous = credits.get(contributor.clone());
+        let others = credits.values().iter().sum::<u32>() - previous.unwrap_or(0);
+        if others + share_bps > types::TOTAL_SHARE_BPS || (share_bps == 0 && previous.is_none()) {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidCredit);
+        }
+
+        let credits_key = types::CreditKey::Credits(key.clone(), target.clone());
+        if share_bps == 0 {
+            credits.remove(contributor.clone());
+        } else {
+            if previous.is_none() && credits.len() >= types::MAX_CONTRIBUTORS_PER_TARGET {
+                panic_with_error!(&env, &errors::ExtendedErrors::InvalidCredit);
+            }
+            credits.set(contributor.clone(), share_bps);
+        }
+
+        let mut targets = Self::get_credited_targets(env.clone(), key.clone());
+        let targets_key = types::CreditKey::Targets(key.clone());
+        if credits.is_empty() {
+            env.storage().persistent().remove(&credits_key);
+            if let Some(index) = targets.first_index_of(&target) {
+                targets.remove(index);
+                env.storage().persistent().set(&targets_key, &targets);
+            }
+        } else {
+            env.storage().persistent().set(&credits_key, &credits);
+            if !targets.contains(&target) {
+                if targets.len() >= types::MAX_CREDITED_TARGETS {
+                    panic_with_error!(&env, &errors::ExtendedErrors::InvalidCredit);
+                }
+                targets.push_back(target.clone());
+                env.storage().persistent().set(&targets_key, &targets);
+            }
+        }
+
+        events::ContributorCredited {
+            project_key: key,
+            contributor,
+            maintainer,
+            target,
+            share_bps,
+        }
+        .publish(&env);
+    }
+
+    /// Get the contributors credited for a commit or a release.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    /// * `target` - The commit or release
+    ///
+    /// # Returns
+    /// * `Map<Address, u32>` - The share of each contributor in basis points
+    fn get_credits(env: Env, key: Bytes, target: types::CreditTarget) -> Map<Address, u32> {
+        env.storage()
+            .persistent()
+            .get(&types::CreditKey::Credits(key, target))
+            .unwrap_or(Map::new(&env))
+    }
+
+    /// Get the commits and releases of a project with credited contributors.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Vec<types::CreditTarget>` - The credited commits and releases, in
+    ///   the order they were first credited
+    fn get_credited_targets(env: Env, key: Bytes) -> Vec<types::CreditTarget> {
+        env.storage()
+            .persistent()
+            .get(&types::CreditKey::Targets(key))
+            .unwrap_or(Vec::new(&env))
+    }
+}
+
+/// Remove all credits of a project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+pub fn remove_credits(env: &Env, key: &Bytes) {
+    let targets_key = types::CreditKey::Targets(key.clone());
+    let targets: Vec<types::CreditTarget> = env
+        .storage()
+        .persistent()
+        .get(&targets_key)
+        .unwrap_or(Vec::new(env));
+    for target in targets.iter() {
+        env.storage()
+            .persistent()
+            .remove(&types::CreditKey::Credits(key.clone(), target));
+    }
+    env.storage().persistent().remove(&targets_key);
+}
@@ -0,0 +1,348 @@
+use soroban_sdk::{Address, Bytes, Env, Map, String, Vec, contractimpl, panic_with_error};
+
+use crate::{
+    AdvisoriesTrait, DependenciesTrait, Registry, RegistryArgs, RegistryClient, ReleasesTrait,
+    errors, events, semver, types,
+};
+
+#[contractimpl]
+impl DependenciesTrait for Registry {
+    /// Declare a dependency of a project on another registered project.
+    ///
+    /// Declaring an existing dependency again updates its version range.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `key` - The project key identifier
+    /// * `dependency` - The project key of the dependency
+    /// * `range` - The version range of the dependency, e.g. `^1.2`
+    ///
+    /// # Panics
+    /// * If the project or the dependency doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the project depends on itself
+    /// * If the range is malformed
+    /// * If the maximum number of dependencies is reached
+    fn declare_dependency(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        dependency: Bytes,
+        range: String,
+    ) {
+        crate::require_not_paused(&env);
+
+        crate::auth_maintainers(&env, &maintainer, &key);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        crate::tansu(&env).get_project(&dependency);
+        if dependency == key {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidDependency);
+        }
+        semver::validate_range(&env, &range);
+
+        let mut dependencies = Self::get_dependencies(env.clone(), key.clone());
+        if !dependencies.contains_key(dependency.clone()) {
+            if dependencies.len() >= types::MAX_DEPENDENCIES {
+                panic_with_error!(&env, &errors::ExtendedErrors::InvalidDependency);
+            }
+            index_dependent(&env, &dependency, &key);
+        }
+        dependencies.set(dependency.clone(), range.clone());
+        env.storage().persistent().set(
+            &types::DependencyKey::Dependencies(key.clone()),
+            &dependencies,
+        );
+
+        events::DependencyUpdated {
+            project_key: key,
+            dependency,
+            maintainer,
+            range: Some(range),
+        }
+        .publish(&env);
+    }
+
+    /// Remove a dependency of a project, with its pinned release.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `key` - The project key identifier
+    /// * `dependency` - The project key of the dependency
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the dependency is not declared
+    fn remove_dependency(env: Env, maintainer: Address, key: Bytes, dependency: Bytes) {
+        crate::require_not_paused(&env);
+
+        crate::auth_maintainers(&env, &maintainer, &key);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        let mut dependencies = Self::get_dependencies(env.clone(), key.clone());
+        if dependencies.remove(dependency.clone()).is_none() {
+            panic_with_error!(&env, &errors::ExtendedErrors::UnknownDependency);
+        }
+        unindex_dependent(&env, &dependency, &key);
+        let mut pins = Self::get_pins(env.clone(), key.clone());
+        if pins.remove(dependency.clone()).is_some() {
+            env.storage()
+                .persistent()
+                .set(&types::DependencyKey::Pins(key.clone()), &pins);
+        }
+        env.storage().persistent().set(
+            &types::DependencyKey::Dependencies(key.clone()),
+            &dependencies,
+        );
+
+        events::DependencyUpdated {
+            project_key: key,
+            dependency,
+            maintainer,
+            range: None,
+        }
+        .publish(&env);
+    }
+
+    /// Get the dependencies of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Map<Bytes, String>` - The version range of each dependency, by
+    ///   project key
+    fn get_dependencies(env: Env, key: Bytes) -> Map<Bytes, String> {
+        env.storage()
+            .persistent()
+            .get(&types::DependencyKey::Dependencies(key))
+            .unwrap_or(Map::new(&env))
+    }
+
+    /// List the keys of the projects depending on a project.
+    ///
+    /// Projects are listed by pages of `MAX_PROJECTS_PER_PAGE` keys, like
+    /// `list_projects`, e.g. to find the projects affected by an advisory.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    /// * `page` - The page number to retrieve
+    ///
+    /// # Returns
+    /// * `Vec<Bytes>` - The project keys of the page, empty past the last page
+    fn get_dependents(env: Env, key: Bytes, page: u32) -> Vec<Bytes> {
+        env.storage()
+            .persistent()
+            .get(&types::DependencyKey::Dependents(key, page))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Attest the release of a dependency shipped by a project.
+    ///
+    /// Pinning again replaces the previous release. Pins are checked
+    /// against the advisories of the dependencies, see
+    /// `get_vulnerable_pins`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `key` - The project key identifier
+    /// * `dependency` - The project key of the dependency
+    /// * `tag` - The release tag of the dependency
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the dependency is not declared
+    /// * If the dependency has no such release
+    fn attest_pin(env: Env, maintainer: Address, key: Bytes, dependency: Bytes, tag: String) {
+        crate::require_not_paused(&env);
+
+        crate::auth_maintainers(&env, &maintainer, &key);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        if !Self::get_dependencies(env.clone(), key.clone()).contains_key(dependency.clone()) {
+            panic_with_error!(&env, &errors::ExtendedErrors::UnknownDependency);
+        }
+        <Registry as ReleasesTrait>::get_release(env.clone(), dependency.clone(), tag.clone());
+
+        let mut pins = Self::get_pins(env.clone(), key.clone());
+        pins.set(dependency.clone(), tag.clone());
+        env.storage()
+            .persistent()
+            .set(&types::DependencyKey::Pins(key.clone()), &pins);
+
+        events::PinAttested {
+            project_key: key,
+            dependency,
+            maintainer,
+            tag,
+        }
+        .publish(&env);
+    }
+
+    /// Get the releases of its dependencies shipped by a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Map<Bytes, String>` - The pinned release tag of each dependency,
+    ///   by project key
+    fn get_pins(env: Env, key: Bytes) -> Map<Bytes, String> {
+        env.storage()
+            .persistent()
+            .get(&types::DependencyKey::Pins(key))
+            .unwrap_or(Map::new(&env))
+    }
+
+    /// Get the pinned releases of a project affected by open advisories of
+    /// its dependencies.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Vec<types::VulnerablePin>` - One entry per pin and open advisory
+    ///   affecting it, empty if no pin is vulnerable
+    fn get_vulnerable_pins(env: Env, key: Bytes) -> Vec<types::VulnerablePin> {
+        let mut vulnerable = Vec::new(&env);
+        for (dependency, tag) in Self::get_pins(env.clone(), key).iter() {
+            for advisory in
+                <Registry as AdvisoriesTrait>::get_open_advisories(env.clone(), dependency.clone())
+                    .iter()
+            {
+                if advisory.affected_releases.contains(&tag) {
+                    vulnerable.push_back(types::VulnerablePin {
+                        dependency: dependency.clone(),
+                        tag: tag.clone(),
+                        advisory_id: advisory.id,
+                        severity: advisory.severity,
+                    });
+                }
+            }
+        }
+        vulnerable
+    }
+}
+
+/// Add a project to the listing of the dependents of a dependency.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `dependency` - The project key of the dependency
+/// * `key` - The project key of the dependent
+fn index_dependent(env: &Env, dependency: &Bytes, key: &Bytes) {
+    let count_key = types::DependencyKey::DependentCount(dependency.clone());
+    let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+
+    let page_key =
+        types::DependencyKey::Dependents(dependency.clone(), count / types::MAX_PROJECTS_PER_PAGE);
+    let mut page: Vec<Bytes> = env
+        .storage()
+        .persistent()
+        .get(&page_key)
+        .unwrap_or(Vec::new(env));
+    page.push_back(key.clone());
+
+    env.storage().persistent().set(&page_key, &page);
+    env.storage().persistent().set(&count_key, &(count + 1));
+    env.storage().persistent().set(
+        &types::DependencyKey::DependentIndex(key.clone(), dependency.clone()),
+        &count,
+    );
+}
+
+/// Remove a project from the listing of the dependents of a dependency.
+///
+/// The last project of the listing takes the place of the removed one so
+/// that pages stay contiguous.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `dependency` - The project key of the dependency
+/// * `key` - The project key of the dependent
+fn unindex_dependent(env: &Env, dependency: &Bytes, key: &Bytes) {
+    let index_key = types::DependencyKey::DependentIndex(key.clone(), dependency.clone());
+    let Some(index) = env.storage().persistent().get::<_, u32>(&index_key) else {
+        return;
+    };
+    let count_key = types::DependencyKey::DependentCount(dependency.clone());
+    let last = env
+        .storage()
+        .persistent()
+        .get::<_, u32>(&count_key)
+        .unwrap()
+        - 1;
+    if last == 0 {
+        env.storage().persistent().remove(&count_key);
+    } else {
+        env.storage().persistent().set(&count_key, &last);
+    }
+
+    let last_page_key =
+        types::DependencyKey::Dependents(dependency.clone(), last / types::MAX_PROJECTS_PER_PAGE);
+    let mut last_page: Vec<Bytes> = env.storage().persistent().get(&last_page_key).unwrap();
+    let last_key = last_page.pop_back().unwrap();
+    if last_page.is_empty() {
+        env.storage().persistent().remove(&last_page_key);
+    } else {
+        env.storage().persistent().set(&last_page_key, &last_page);
+    }
+
+    if index != last {
+        let page_key = types::DependencyKey::Dependents(
+            dependency.clone(),
+            index / types::MAX_PROJECTS_PER_PAGE,
+        );
+        let mut page: Vec<Bytes> = env.storage().persistent().get(&page_key).unwrap();
+        page.set(index % types::MAX_PROJECTS_PER_PAGE, last_key.clone());
+        env.storage().persistent().set(&page_key, &page);
+        env.storage().persistent().set(
+            &types::DependencyKey::DependentIndex(last_key, dependency.clone()),
+            &index,
+        );
+    }
+
+    env.storage().persistent().remove(&index_key);
+}
+
+/// Remove all dependencies of a project.
+///
+/// The project leaves the dependents of its dependencies. Projects
+/// depending on it keep their declarations.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+pub fn remove_dependencies(env: &Env, key: &Bytes) {
+    let dependencies_key = types::DependencyKey::Dependencies(key.clone());
+    let dependencies: Map<Bytes, String> = env
+        .storage()
+        .persistent()
+        .get(&dependencies_key)
+        .unwrap_or(Map::new(env));
+    for dependency in dependencies.keys().iter() {
+        unindex_dependent(env, &dependency, key);
+    }
+    env.storage().persistent().remove(&dependencies_key);
+    env.storage()
+        .persistent()
+        .remove(&types::DependencyKey::Pins(key.clone()));
+}
@@ -0,0 +1,209 @@
+use soroban_sdk::{Address, Bytes, BytesN, Env, String, Vec, contractimpl, panic_with_error};
+
+use crate::{ProofsTrait, Registry, RegistryArgs, RegistryClient, errors, events, types};
+
+#[contractimpl]
+impl ProofsTrait for Registry {
+    /// Anchor the Merkle root of the file tree of the latest commit.
+    ///
+    /// The root lets anyone later prove that a file was part of the commit.
+    /// A commit can only be anchored once and only the last
+    /// `MAX_TREE_ANCHORS` anchors are kept.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `key` - The project key identifier
+    /// * `merkle_root` - The Merkle root of the file tree
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the project is paused
+    /// * If the project has no commit
+    /// * If the commit or the root is already anchored
+    fn anchor_tree(env: Env, maintainer: Address, key: Bytes, merkle_root: BytesN<32>) {
+        crate::require_not_paused(&env);
+
+        crate::auth_maintainers_role(&env, &maintainer, &key, types::MaintainerRole::Committer);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+        crate::require_project_not_paused(&env, &key);
+
+        let commit_hash = crate::tansu(&env).get_commit(&key);
+        let commit_key = types::AnchorKey::Commit(key.clone(), commit_hash.clone());
+        let root_key = types::AnchorKey::Root(key.clone(), merkle_root.clone());
+        if env.storage().persistent().has(&commit_key) || env.storage().persistent().has(&root_key)
+        {
+            panic_with_error!(&env, &errors::ExtendedErrors::TreeAlreadyAnchored);
+        }
+
+        let roots_key = types::AnchorKey::Roots(key.clone());
+        let mut roots: Vec<BytesN<32>> = env
+            .storage()
+            .persistent()
+            .get(&roots_key)
+            .unwrap_or(Vec::new(&env));
+        if roots.len() >= types::MAX_TREE_ANCHORS
+            && let Some(oldest) = roots.pop_front()
+        {
+            remove_tree_anchor(&env, &key, &oldest);
+        }
+        roots.push_back(merkle_root.clone());
+        env.storage().persistent().set(&roots_key, &roots);
+
+        env.storage().persistent().set(&commit_key, &merkle_root);
+        env.storage().persistent().set(
+            &root_key,
+            &types::TreeAnchor {
+                root: merkle_root.clone(),
+                commit_hash: commit_hash.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        events::TreeAnchored {
+            project_key: key,
+            commit_hash,
+            root: merkle_root,
+        }
+        .publish(&env);
+    }
+
+    /// Get the Merkle root anchored for a commit.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    /// * `commit_hash` - The commit hash
+    ///
+    /// # Returns
+    /// * `Option<BytesN<32>>` - The Merkle root, `None` if not anchored
+    fn get_tree_root(env: Env, key: Bytes, commit_hash: String) -> Option<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&types::AnchorKey::Commit(key, commit_hash))
+    }
+
+    /// Get the anchor of a Merkle root.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    /// * `merkle_root` - The Merkle root
+    ///
+    /// # Returns
+    /// * `Option<types::TreeAnchor>` - The anchor with its commit hash,
+    ///   `None` if the root is not anchored
+    fn get_tree_anchor(env: Env, key: Bytes, merkle_root: BytesN<32>) -> Option<types::TreeAnchor> {
+        env.storage()
+            .persistent()
+            .get(&types::AnchorKey::Root(key, merkle_root))
+    }
+
+    /// Verify that a file is part of an anchored file tree.
+    ///
+    /// Leaves are the sha256 of the file path followed by the file content.
+    /// Each node is the sha256 of its two children, the smaller one first,
+    /// so proofs are only the list of sibling hashes from the leaf up.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    /// * `commit_or_root` - The anchored tree, by commit, root or release
+    /// * `path_hash` - The leaf of the file
+    /// * `proof` - The sibling hashes from the leaf to the root
+    ///
+    /// # Returns
+    /// * `bool` - True if the proof leads to the anchored root, false if it
+    ///   does not, is longer than `MAX_PROOF_DEPTH` or the tree is not anchored
+    fn verify_file(
+        env: Env,
+        key: Bytes,
+        commit_or_root: types::TreeRef,
+        path_hash: BytesN<32>,
+        proof: Vec<BytesN<32>>,
+    ) -> bool {
+        if proof.len() > types::MAX_PROOF_DEPTH {
+            return false;
+        }
+
+        let root = match commit_or_root {
+            types::TreeRef::Commit(commit_hash) => {
+                Self::get_tree_root(env.clone(), key, commit_hash)
+            }
+            types::TreeRef::Root(root) => {
+                Self::get_tree_anchor(env.clone(), key, root.clone()).map(|anchor| anchor.root)
+            }
+            types::TreeRef::Release(tag) => {
+                let Some(release) = env
+                    .storage()
+                    .persistent()
+                    .get::<types::ReleaseKey, types::Release>(&types::ReleaseKey::Release(
+                        key.clone(),
+                        tag,
+                    ))
+                else {
+                    return false;
+                };
+                Self::get_tree_root(env.clone(), key, release.commit_hash)
+            }
+        };
+        let Some(root) = root else {
+            return false;
+        };
+
+        let mut node = path_hash;
+        for sibling in proof.iter() {
+            let (left, right) = if node <= sibling {
+                (node, sibling)
+            } else {
+                (sibling, node)
+            };
+            let mut pair = Bytes::from(left);
+            pair.append(&Bytes::from(right));
+            node = env.crypto().sha256(&pair).to_bytes();
+        }
+        node == root
+    }
+}
+
+/// Remove an anchored Merkle root of a project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+/// * `root` - The Merkle root
+fn remove_tree_anchor(env: &Env, key: &Bytes, root: &BytesN<32>) {
+    let root_key = types::AnchorKey::Root(key.clone(), root.clone());
+    if let Some(anchor) = env
+        .storage()
+        .persistent()
+        .get::<types::AnchorKey, types::TreeAnchor>(&root_key)
+    {
+        env.storage()
+            .persistent()
+            .remove(&types::AnchorKey::Commit(key.clone(), anchor.commit_hash));
+    }
+    env.storage().persistent().remove(&root_key);
+}
+
+/// Remove all anchored Merkle roots of a project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+pub fn remove_tree_anchors(env: &Env, key: &Bytes) {
+    let roots_key = types::AnchorKey::Roots(key.clone());
+    let roots: Vec<BytesN<32>> = env
+        .storage()
+        .persistent()
+        .get(&roots_key)
+        .unwrap_or(Vec::new(env));
+    for root in roots.iter() {
+        remove_tree_anchor(env, key, &root);
+    }
+    env.storage().persistent().remove(&roots_key);
+}
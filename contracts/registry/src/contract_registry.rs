@@ -0,0 +1,159 @@
+use soroban_sdk::{Address, Bytes, Env, contractimpl};
+
+use crate::{
+    Registry, RegistryArgs, RegistryClient, RegistryTrait, ReleasesTrait, contract_advisories,
+    contract_ci, contract_credits, contract_dependencies, contract_proofs, contract_releases,
+    contract_reviews, errors, types,
+};
+
+#[contractimpl]
+impl RegistryTrait for Registry {
+    /// Initialize the registry with the Tansu contract the projects are
+    /// registered with.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `tansu` - The address of the Tansu contract
+    fn __constructor(env: Env, tansu: Address) {
+        env.storage().instance().set(&types::DataKey::Tansu, &tansu);
+    }
+
+    /// Get the Tansu contract the projects are registered with.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    ///
+    /// # Returns
+    /// * `Address` - The address of the Tansu contract
+    fn get_tansu(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&types::DataKey::Tansu)
+            .unwrap()
+    }
+
+    /// Check that a release action of an approved proposal can be applied
+    /// without failing.
+    ///
+    /// Used by Tansu to skip the scheduled proposals which cannot be
+    /// executed anymore.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    /// * `action` - The release action of the proposal
+    ///
+    /// # Returns
+    /// * `Option<u32>` - The code of the error applying the action would
+    ///   fail with
+    fn check_release_action(env: Env, key: Bytes, action: types::ReleaseAction) -> Option<u32> {
+        match action {
+            types::ReleaseAction::Promote(tag, channel) => {
+                match env
+                    .storage()
+                    .persistent()
+                    .get::<_, types::Release>(&types::ReleaseKey::Release(key, tag))
+                {
+                    Some(release) if release.yanked.is_none() && channel > release.channel => None,
+                    _ => Some(errors::ContractErrors::InvalidRelease as u32),
+                }
+            }
+            types::ReleaseAction::Create(tag, ..) => {
+                if contract_releases::can_release(&env, &key, &tag) {
+                    None
+                } else {
+                    Some(errors::ContractErrors::InvalidRelease as u32)
+                }
+            }
+            types::ReleaseAction::Archive(tag) => {
+                if <Registry as ReleasesTrait>::get_releases(env.clone(), key).contains(&tag) {
+                    None
+                } else {
+                    Some(errors::ContractErrors::UnknownRelease as u32)
+                }
+            }
+            types::ReleaseAction::Yank(tag, _) => {
+                match env
+                    .storage()
+                    .persistent()
+                    .get::<_, types::Release>(&types::ReleaseKey::Release(key, tag))
+                {
+                    Some(release) if release.yanked.is_none() => None,
+                    _ => Some(errors::ContractErrors::InvalidRelease as u32),
+                }
+            }
+        }
+    }
+
+    /// Apply a release action of an approved proposal of the DAO of a
+    /// project.
+    ///
+    /// Only Tansu can call this function, once the proposal is executed. The
+    /// action was validated when the proposal was created.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    /// * `executor` - The address executing the proposal
+    /// * `action` - The release action of the proposal
+    ///
+    /// # Panics
+    /// * If Tansu did not authorize the call
+    /// * If the action cannot be applied, see `check_release_action`
+    fn apply_release_action(env: Env, key: Bytes, executor: Address, action: types::ReleaseAction) {
+        Self::get_tansu(env.clone()).require_auth();
+
+        match action {
+            types::ReleaseAction::Create(
+                tag,
+                commit_hash,
+                artifact_cid,
+                changelog_cid,
+                channel,
+            ) => {
+                contract_releases::create(
+                    &env,
+                    &key,
+                    &executor,
+                    &tag,
+                    &commit_hash,
+                    &artifact_cid,
+                    &changelog_cid,
+                    channel,
+                    None,
+                );
+            }
+            types::ReleaseAction::Promote(tag, channel) => {
+                contract_releases::promote(&env, &key, &executor, &tag, channel, None);
+            }
+            types::ReleaseAction::Yank(tag, reason_cid) => {
+                contract_releases::yank(&env, &key, &executor, &tag, &reason_cid);
+            }
+            types::ReleaseAction::Archive(tag) => {
+                contract_releases::archive(&env, &key, &executor, &tag);
+            }
+        }
+    }
+
+    /// Remove the releases and attestations of a deregistered project.
+    ///
+    /// Only Tansu can call this function, when the project is deregistered.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    ///
+    /// # Panics
+    /// * If Tansu did not authorize the call
+    fn remove_project(env: Env, key: Bytes) {
+        Self::get_tansu(env.clone()).require_auth();
+
+        contract_releases::remove_releases(&env, &key);
+        contract_proofs::remove_tree_anchors(&env, &key);
+        contract_advisories::remove_advisories(&env, &key);
+        contract_ci::remove_ci_runs(&env, &key);
+        contract_reviews::remove_reviews(&env, &key);
+        contract_credits::remove_credits(&env, &key);
+        contract_dependencies::remove_dependencies(&env, &key);
+    }
+}
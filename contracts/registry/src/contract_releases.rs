@@ -0,0 +1,1280 @@
+use soroban_sdk::{Address, Bytes, BytesN, Env, Map, String, Vec, contractimpl, panic_with_error};
+
+use crate::{
+    Registry, RegistryArgs, RegistryClient, ReleasesTrait, errors, events, semver, types,
+    validation,
+};
+
+#[contractimpl]
+impl ReleasesTrait for Registry {
+    /// Create a tagged release of a project.
+    ///
+    /// Releases map a tag to a commit hash, independently of the latest
+    /// commit of the project. Tags are semantic versions, optionally
+    /// prefixed with `v`. A tag cannot be released twice.
+    ///
+    /// If the project requires it, see `set_release_approval`, the release
+    /// must reference an approved proposal of the DAO. A proposal approves a
+    /// single release.
+    ///
+    /// The release becomes the head of its channel, and can later be
+    /// promoted to the next channels, see `promote_release`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the releaser calling this function
+    /// * `key` - The project key identifier
+    /// * `tag` - The release tag
+    /// * `commit_hash` - The commit hash the tag points to
+    /// * `artifact_cid` - CID of the release artifacts
+    /// * `changelog_cid` - CID of the release notes
+    /// * `channel` - The channel of the release, e.g. `Rc`
+    /// * `proposal_id` - The approved proposal of the DAO for the release
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not a releaser
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the tag is not a semantic version
+    /// * If the commit hash does not match the hash algorithm of the project
+    /// * If the artifact or changelog CID is malformed
+    /// * If the tag is already released, even if the release was archived
+    /// * If the maximum number of releases is reached, see `archive_release`
+    /// * If a proposal is required but missing
+    /// * If the proposal doesn't exist, is not approved or already released
+    #[allow(clippy::too_many_arguments)]
+    fn create_release(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        tag: String,
+        commit_hash: String,
+        artifact_cid: String,
+        changelog_cid: String,
+        channel: types::ReleaseChannel,
+        proposal_id: Option<u32>,
+    ) {
+        crate::require_not_paused(&env);
+
+        crate::auth_maintainers_role(&env, &maintainer, &key, types::MaintainerRole::Releaser);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        validate_release(
+            &env,
+            &key,
+            &tag,
+            &commit_hash,
+            &artifact_cid,
+            &changelog_cid,
+        );
+        use_release_proposal(&env, &key, &tag, proposal_id, true);
+        create(
+            &env,
+            &key,
+            &maintainer,
+            &tag,
+            &commit_hash,
+            &artifact_cid,
+            &changelog_cid,
+            channel,
+            proposal_id,
+        );
+    }
+
+    /// Promote a release of a project to a later channel.
+    ///
+    /// Releases only move forward, from `Nightly` to `Rc` to `Stable`, and
+    /// become the head of the new channel. If the project requires releases
+    /// to be approved, see `set_release_approval`, promoting to `Stable` must
+    /// reference an approved proposal of the DAO. Projects controlled by
+    /// their DAO promote releases with a `PromoteRelease` action.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the releaser calling this function
+    /// * `key` - The project key identifier
+    /// * `tag` - The release tag
+    /// * `channel` - The channel to promote the release to
+    /// * `proposal_id` - The approved proposal of the DAO for the promotion
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not a releaser
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the release doesn't exist or is yanked
+    /// * If the channel does not come after the channel of the release
+    /// * If a proposal is required but missing
+    /// * If the proposal doesn't exist, is not approved or already used
+    fn promote_release(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        tag: String,
+        channel: types::ReleaseChannel,
+        proposal_id: Option<u32>,
+    ) {
+        crate::require_not_paused(&env);
+
+        crate::auth_maintainers_role(&env, &maintainer, &key, types::MaintainerRole::Releaser);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        promote(&env, &key, &maintainer, &tag, channel, proposal_id);
+        let required = channel == types::ReleaseChannel::Stable;
+        use_release_proposal(&env, &key, &tag, proposal_id, required);
+    }
+
+    /// Get the head of a release channel of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    /// * `channel` - The release channel
+    ///
+    /// # Returns
+    /// * `Option<String>` - The tag last released or promoted on the
+    ///   channel, `None` if there is none or it was yanked
+    fn get_channel_head(env: Env, key: Bytes, channel: types::ReleaseChannel) -> Option<String> {
+        env.storage()
+            .persistent()
+            .get(&types::ReleaseKey::ChannelHead(key, channel))
+    }
+
+    /// Require releases of a project to be approved by its DAO.
+    ///
+    /// When required, `create_release` only succeeds with an approved
+    /// proposal, so releases go through a community vote.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the admin calling this function
+    /// * `key` - The project key identifier
+    /// * `required` - Whether releases need an approved proposal
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not an admin
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    fn set_release_approval(env: Env, maintainer: Address, key: Bytes, required: bool) {
+        crate::require_not_paused(&env);
+
+        crate::auth_maintainers_role(&env, &maintainer, &key, types::MaintainerRole::Admin);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        let approval_key = types::ReleaseKey::ApprovalRequired(key.clone());
+        if required {
+            env.storage().persistent().set(&approval_key, &true);
+        } else {
+            env.storage().persistent().remove(&approval_key);
+        }
+        events::ReleaseApprovalUpdated {
+            project_key: key,
+            maintainer,
+            required,
+        }
+        .publish(&env);
+    }
+
+    /// Check if releases of a project must be approved by its DAO.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `bool` - True if releases need an approved proposal
+    fn get_release_approval(env: Env, key: Bytes) -> bool {
+        env.storage()
+            .persistent()
+            .has(&types::ReleaseKey::ApprovalRequired(key))
+    }
+
+    /// Register an ed25519 key signing the releases of a project.
+    ///
+    /// Off-chain verifiers check release signatures against the active keys
+    /// of the project. Revoked keys stay in the history, see
+    /// `get_release_keys`, until `MAX_RELEASE_SIGNING_KEYS` is reached and
+    /// the oldest revoked key is dropped.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the admin calling this function
+    /// * `key` - The project key identifier
+    /// * `public_key` - The ed25519 public key
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not an admin
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the key was already registered
+    /// * If the maximum number of active keys is reached
+    fn add_release_key(env: Env, maintainer: Address, key: Bytes, public_key: BytesN<32>) {
+        crate::require_not_paused(&env);
+
+        crate::auth_maintainers_role(&env, &maintainer, &key, types::MaintainerRole::Admin);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        let mut keys = Self::get_release_keys(env.clone(), key.clone());
+        push_release_key(&env, &mut keys, &public_key);
+        store_release_keys(&env, &key, &keys);
+
+        events::ReleaseKeyUpdated {
+            project_key: key,
+            public_key,
+            maintainer,
+            revoked: false,
+        }
+        .publish(&env);
+    }
+
+    /// Revoke an ed25519 key signing the releases of a project.
+    ///
+    /// The key is kept in the history with the time of its revocation so
+    /// signatures made before can still be checked.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the admin calling this function
+    /// * `key` - The project key identifier
+    /// * `public_key` - The ed25519 public key
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not an admin
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the key is not an active key of the project
+    fn revoke_release_key(env: Env, maintainer: Address, key: Bytes, public_key: BytesN<32>) {
+        crate::require_not_paused(&env);
+
+        crate::auth_maintainers_role(&env, &maintainer, &key, types::MaintainerRole::Admin);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        let mut keys = Self::get_release_keys(env.clone(), key.clone());
+        revoke_key(&env, &mut keys, &public_key);
+        store_release_keys(&env, &key, &keys);
+
+        events::ReleaseKeyUpdated {
+            project_key: key,
+            public_key,
+            maintainer,
+            revoked: true,
+        }
+        .publish(&env);
+    }
+
+    /// Replace an ed25519 key signing the releases of a project.
+    ///
+    /// The old key is revoked and the new one registered at once, so the
+    /// project always has a valid key.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the admin calling this function
+    /// * `key` - The project key identifier
+    /// * `old_key` - The ed25519 public key to revoke
+    /// * `new_key` - The ed25519 public key to register
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not an admin
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the old key is not an active key of the project
+    /// * If the new key was already registered
+    fn rotate_release_key(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        old_key: BytesN<32>,
+        new_key: BytesN<32>,
+    ) {
+        crate::require_not_paused(&env);
+
+        crate::auth_maintainers_role(&env, &maintainer, &key, types::MaintainerRole::Admin);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        let mut keys = Self::get_release_keys(env.clone(), key.clone());
+        revoke_key(&env, &mut keys, &old_key);
+        push_release_key(&env, &mut keys, &new_key);
+        store_release_keys(&env, &key, &keys);
+
+        events::ReleaseKeyUpdated {
+            project_key: key.clone(),
+            public_key: old_key,
+            maintainer: maintainer.clone(),
+            revoked: true,
+        }
+        .publish(&env);
+        events::ReleaseKeyUpdated {
+            project_key: key,
+            public_key: new_key,
+            maintainer,
+            revoked: false,
+        }
+        .publish(&env);
+    }
+
+    /// Get the history of the keys signing the releases of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Vec<types::ReleaseSigningKey>` - The active and revoked keys,
+    ///   oldest first
+    fn get_release_keys(env: Env, key: Bytes) -> Vec<types::ReleaseSigningKey> {
+        env.storage()
+            .persistent()
+            .get(&types::ReleaseKey::SigningKeys(key))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get the keys currently valid to sign the releases of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Vec<BytesN<32>>` - The ed25519 public keys which are not revoked
+    fn get_active_release_keys(env: Env, key: Bytes) -> Vec<BytesN<32>> {
+        let mut active = Vec::new(&env);
+        for signing_key in Self::get_release_keys(env.clone(), key).iter() {
+            if signing_key.revoked.is_none() {
+                active.push_back(signing_key.public_key);
+            }
+        }
+        active
+    }
+
+    /// Yank a release of a project.
+    ///
+    /// Like on crates.io, the release is kept but marked as withdrawn so
+    /// consumers stop picking it. Yanked releases are skipped by
+    /// `latest_release` and are no longer the head of their channels.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the releaser calling this function
+    /// * `key` - The project key identifier
+    /// * `tag` - The release tag
+    /// * `reason_cid` - CID of the document explaining the yank
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not a releaser
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the release doesn't exist
+    /// * If the release is already yanked
+    /// * If the reason CID is malformed
+    fn yank_release(env: Env, maintainer: Address, key: Bytes, tag: String, reason_cid: String) {
+        crate::require_not_paused(&env);
+
+        crate::auth_maintainers_role(&env, &maintainer, &key, types::MaintainerRole::Releaser);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        yank(&env, &key, &maintainer, &tag, &reason_cid);
+    }
+
+    /// Archive a release of a project.
+    ///
+    /// Projects keep at most `MAX_RELEASES` releases, so old releases are
+    /// archived to make room for new ones. The release is kept and can still
+    /// be read with `get_release`, but it is no longer listed by
+    /// `get_releases`, considered by `latest_release` nor the head of its
+    /// channels. Its tag cannot be released again. Projects controlled by
+    /// their DAO archive releases with an `ArchiveRelease` action.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the releaser calling this function
+    /// * `key` - The project key identifier
+    /// * `tag` - The release tag
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not a releaser
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the release doesn't exist or is already archived
+    fn archive_release(env: Env, maintainer: Address, key: Bytes, tag: String) {
+        crate::require_not_paused(&env);
+
+        crate::auth_maintainers_role(&env, &maintainer, &key, types::MaintainerRole::Releaser);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        archive(&env, &key, &maintainer, &tag);
+    }
+
+    /// Add a binary artifact to a release of a project.
+    ///
+    /// Each platform has at most one artifact per release, so downloaders
+    /// can verify the binary for their target.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the releaser calling this function
+    /// * `key` - The project key identifier
+    /// * `tag` - The release tag
+    /// * `platform` - The target triple of the artifact
+    /// * `sha256` - The sha256 hash of the artifact
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not a releaser
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the release doesn't exist or is yanked
+    /// * If the platform is invalid or already has an artifact
+    /// * If the maximum number of artifacts is reached
+    fn add_artifact(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        tag: String,
+        platform: String,
+        sha256: BytesN<32>,
+    ) {
+        crate::require_not_paused(&env);
+
+        crate::auth_maintainers_role(&env, &maintainer, &key, types::MaintainerRole::Releaser);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        let release = Self::get_release(env.clone(), key.clone(), tag.clone());
+        if release.yanked.is_some() {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidRelease);
+        }
+        validation::validate_platform(&env, &platform);
+
+        let mut artifacts = Self::get_artifacts(env.clone(), key.clone(), tag.clone());
+        if artifacts.len() >= types::MAX_ARTIFACTS
+            || artifacts
+                .iter()
+                .any(|artifact| artifact.platform == platform)
+        {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidArtifact);
+        }
+        artifacts.push_back(types::Artifact {
+            platform: platform.clone(),
+            sha256: sha256.clone(),
+        });
+        env.storage().persistent().set(
+            &types::ReleaseKey::Artifacts(key.clone(), tag.clone()),
+            &artifacts,
+        );
+
+        events::ArtifactAdded {
+            project_key: key,
+            tag,
+            platform,
+            sha256,
+        }
+        .publish(&env);
+    }
+
+    /// Get the binary artifacts of a release of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    /// * `tag` - The release tag
+    ///
+    /// # Returns
+    /// * `Vec<types::Artifact>` - The artifacts with their platform and hash
+    fn get_artifacts(env: Env, key: Bytes, tag: String) -> Vec<types::Artifact> {
+        env.storage()
+            .persistent()
+            .get(&types::ReleaseKey::Artifacts(key, tag))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Attach a software bill of materials to a release of a project.
+    ///
+    /// Supply-chain tooling can verify installed dependencies against the
+    /// CycloneDX or SPDX document. A release has at most one SBOM per format.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the releaser calling this function
+    /// * `key` - The project key identifier
+    /// * `tag` - The release tag
+    /// * `format` - The format of the SBOM
+    /// * `sha256` - The sha256 hash of the SBOM document
+    /// * `cid` - CID of the SBOM document
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not a releaser
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the release doesn't exist or is yanked
+    /// * If the release already has an SBOM of this format
+    /// * If the CID is malformed
+    #[allow(clippy::too_many_arguments)]
+    fn attach_sbom(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        tag: String,
+        format: types::SbomFormat,
+        sha256: BytesN<32>,
+        cid: String,
+    ) {
+        crate::require_not_paused(&env);
+
+        crate::auth_maintainers_role(&env, &maintainer, &key, types::MaintainerRole::Releaser);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        let mut release = Self::get_release(env.clone(), key.clone(), tag.clone());
+        if release.yanked.is_some() {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidRelease);
+        }
+        if release.sboms.iter().any(|sbom| sbom.format == format) {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidArtifact);
+        }
+        validation::validate_cid(&env, &cid);
+        release.sboms.push_back(types::Sbom {
+            format,
+            sha256: sha256.clone(),
+            cid: cid.clone(),
+        });
+        env.storage().persistent().set(
+            &types::ReleaseKey::Release(key.clone(), tag.clone()),
+            &release,
+        );
+
+        events::SbomAttached {
+            project_key: key,
+            tag,
+            format,
+            sha256,
+            cid,
+        }
+        .publish(&env);
+    }
+
+    /// Record the toolchain which built a release of a project.
+    ///
+    /// The toolchain maps each build tool to its version, e.g. `rustc` to
+    /// `1.89.0` and `soroban-sdk` to `23.0.2`, so reproducible builds can be
+    /// checked from on-chain data alone. It can only be recorded once.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the releaser calling this function
+    /// * `key` - The project key identifier
+    /// * `tag` - The release tag
+    /// * `toolchain` - The version of each build tool
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not a releaser
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the release doesn't exist or is yanked
+    /// * If the toolchain of the release was already recorded
+    /// * If the toolchain is empty, too large or has malformed entries
+    fn set_release_toolchain(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        tag: String,
+        toolchain: Map<String, String>,
+    ) {
+        crate::require_not_paused(&env);
+
+        crate::auth_maintainers_role(&env, &maintainer, &key, types::MaintainerRole::Releaser);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        let mut release = Self::get_release(env.clone(), key.clone(), tag.clone());
+        if release.yanked.is_some() {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidRelease);
+        }
+        if !release.toolchain.is_empty() {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidToolchain);
+        }
+        validation::validate_toolchain(&env, &toolchain);
+
+        release.toolchain = toolchain.clone();
+        env.storage().persistent().set(
+            &types::ReleaseKey::Release(key.clone(), tag.clone()),
+            &release,
+        );
+
+        events::ToolchainRecorded {
+            project_key: key,
+            tag,
+            maintainer,
+            toolchain,
+        }
+        .publish(&env);
+    }
+
+    /// Attest a reproducible build of a release of a project.
+    ///
+    /// Registered attestors rebuild the release independently and record the
+    /// hash they obtained. Each attestor attests a release once, so consumers
+    /// can require N-of-M matching hashes.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    /// * `tag` - The release tag
+    /// * `builder` - The address of the attestor calling this function
+    /// * `hash` - The hash of the wasm or binary built
+    ///
+    /// # Panics
+    /// * If the builder is not a registered attestor
+    /// * If the release doesn't exist or is yanked
+    /// * If the builder already attested the release
+    /// * If the maximum number of attestations is reached
+    fn attest_build(env: Env, key: Bytes, tag: String, builder: Address, hash: BytesN<32>) {
+        crate::require_not_paused(&env);
+
+        builder.require_auth();
+        if !crate::tansu(&env).is_attestor(&builder) {
+            panic_with_error!(&env, &errors::ExtendedErrors::NotAttestor);
+        }
+
+        let release = Self::get_release(env.clone(), key.clone(), tag.clone());
+        if release.yanked.is_some() {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidRelease);
+        }
+
+        let mut attestations = Self::get_attestations(env.clone(), key.clone(), tag.clone());
+        if attestations.len() >= types::MAX_ATTESTATIONS
+            || attestations
+                .iter()
+                .any(|attestation| attestation.builder == builder)
+        {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidAttestation);
+        }
+        attestations.push_back(types::Attestation {
+            builder: builder.clone(),
+            hash: hash.clone(),
+            timestamp: env.ledger().timestamp(),
+        });
+        env.storage().persistent().set(
+            &types::ReleaseKey::Attestations(key.clone(), tag.clone()),
+            &attestations,
+        );
+
+        events::BuildAttested {
+            project_key: key,
+            tag,
+            builder,
+            hash,
+        }
+        .publish(&env);
+    }
+
+    /// Get the build attestations of a release of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    /// * `tag` - The release tag
+    ///
+    /// # Returns
+    /// * `Vec<types::Attestation>` - The attestations, oldest first
+    fn get_attestations(env: Env, key: Bytes, tag: String) -> Vec<types::Attestation> {
+        env.storage()
+            .persistent()
+            .get(&types::ReleaseKey::Attestations(key, tag))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Count the attestors who obtained a given hash for a release.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    /// * `tag` - The release tag
+    /// * `hash` - The hash of the wasm or binary
+    ///
+    /// # Returns
+    /// * `u32` - The number of attestations matching the hash
+    fn get_attestation_count(env: Env, key: Bytes, tag: String, hash: BytesN<32>) -> u32 {
+        Self::get_attestations(env, key, tag)
+            .iter()
+            .filter(|attestation| attestation.hash == hash)
+            .count() as u32
+    }
+
+    /// Attach a security audit report to a release of a project.
+    ///
+    /// Audits are published by registered attestors, see `set_attestor`.
+    /// Each auditor attaches one report per release.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    /// * `tag` - The release tag
+    /// * `auditor` - The address of the attestor calling this function
+    /// * `report_cid` - CID of the audit report
+    /// * `verdict` - The outcome of the audit
+    ///
+    /// # Panics
+    /// * If the auditor is not a registered attestor
+    /// * If the release doesn't exist or is yanked
+    /// * If the report CID is malformed
+    /// * If the auditor already audited the release
+    /// * If the maximum number of audits is reached
+    fn attach_audit(
+        env: Env,
+        key: Bytes,
+        tag: String,
+        auditor: Address,
+        report_cid: String,
+        verdict: types::AuditVerdict,
+    ) {
+        crate::require_not_paused(&env);
+
+        auditor.require_auth();
+        if !crate::tansu(&env).is_attestor(&auditor) {
+            panic_with_error!(&env, &errors::ExtendedErrors::NotAttestor);
+        }
+
+        let release = Self::get_release(env.clone(), key.clone(), tag.clone());
+        if release.yanked.is_some() {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidRelease);
+        }
+
+        validation::validate_cid(&env, &report_cid);
+        let mut audits = Self::get_audits(env.clone(), key.clone(), tag.clone());
+        if audits.len() >= types::MAX_AUDITS || audits.iter().any(|audit| audit.auditor == auditor)
+        {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidAudit);
+        }
+        audits.push_back(types::Audit {
+            auditor: auditor.clone(),
+            report_cid: report_cid.clone(),
+            verdict,
+            timestamp: env.ledger().timestamp(),
+        });
+        env.storage().persistent().set(
+            &types::ReleaseKey::Audits(key.clone(), tag.clone()),
+            &audits,
+        );
+
+        events::AuditAttached {
+            project_key: key,
+            tag,
+            auditor,
+            report_cid,
+            verdict,
+        }
+        .publish(&env);
+    }
+
+    /// Get the security audits of a release of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    /// * `tag` - The release tag
+    ///
+    /// # Returns
+    /// * `Vec<types::Audit>` - The audits, oldest first
+    fn get_audits(env: Env, key: Bytes, tag: String) -> Vec<types::Audit> {
+        env.storage()
+            .persistent()
+            .get(&types::ReleaseKey::Audits(key, tag))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get a release of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    /// * `tag` - The release tag
+    ///
+    /// # Returns
+    /// * `types::Release` - The release with its SBOMs, and the reason if it
+    ///   was yanked
+    ///
+    /// # Panics
+    /// * If the release doesn't exist
+    fn get_release(env: Env, key: Bytes, tag: String) -> types::Release {
+        env.storage()
+            .persistent()
+            .get(&types::ReleaseKey::Release(key, tag))
+            .unwrap_or_else(|| {
+                panic_with_error!(&env, &errors::ContractErrors::UnknownRelease);
+            })
+    }
+
+    /// Get the release tags of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Vec<String>` - The tags, in creation order
+    fn get_releases(env: Env, key: Bytes) -> Vec<String> {
+        env.storage()
+            .persistent()
+            .get(&types::ReleaseKey::Tags(key))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get the latest release of a project by semantic version ordering.
+    ///
+    /// Yanked releases are skipped and pre-releases are only considered if
+    /// the project has no release.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Option<types::Release>` - The release with the highest version,
+    ///   `None` if the project has no release which is not yanked
+    fn latest_release(env: Env, key: Bytes) -> Option<types::Release> {
+        let mut latest_buffer = [0u8; types::MAX_RELEASE_TAG_LENGTH as usize];
+        let mut latest_len = 0;
+        let mut latest_release: Option<types::Release> = None;
+
+        for tag in Self::get_releases(env.clone(), key.clone()).iter() {
+            let mut buffer = [0u8; types::MAX_RELEASE_TAG_LENGTH as usize];
+            let Some(version) =
+                semver::tag_bytes(&tag, &mut buffer).and_then(semver::Version::parse)
+            else {
+                continue;
+            };
+            let latest = latest_release
+                .as_ref()
+                .and_then(|_| semver::Version::parse(&latest_buffer[..latest_len]));
+            // releases take precedence over pre-releases
+            let is_latest = match latest {
+                Some(latest) => {
+                    (!version.is_prerelease(), &version) > (!latest.is_prerelease(), &latest)
+                }
+                None => true,
+            };
+            if !is_latest {
+                continue;
+            }
+            let release = Self::get_release(env.clone(), key.clone(), tag.clone());
+            if release.yanked.is_none() {
+                latest_len = tag.len() as usize;
+                tag.copy_into_slice(&mut latest_buffer[..latest_len]);
+                latest_release = Some(release);
+            }
+        }
+
+        latest_release
+    }
+}
+
+/// Remove all releases of a project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+pub fn remove_releases(env: &Env, key: &Bytes) {
+    let tags_key = types::ReleaseKey::Tags(key.clone());
+    let tags: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&tags_key)
+        .unwrap_or(Vec::new(env));
+    for tag in tags.iter() {
+        let release: Option<types::Release> = env
+            .storage()
+            .persistent()
+            .get(&types::ReleaseKey::Release(key.clone(), tag.clone()));
+        if let Some(proposal_id) = release.and_then(|release| release.proposal_id) {
+            env.storage()
+                .persistent()
+                .remove(&types::ReleaseKey::Proposal(key.clone(), proposal_id));
+        }
+        env.storage()
+            .persistent()
+            .remove(&types::ReleaseKey::Release(key.clone(), tag.clone()));
+        env.storage()
+            .persistent()
+            .remove(&types::ReleaseKey::Artifacts(key.clone(), tag.clone()));
+        env.storage()
+            .persistent()
+            .remove(&types::ReleaseKey::Attestations(key.clone(), tag.clone()));
+        env.storage()
+            .persistent()
+            .remove(&types::ReleaseKey::Audits(key.clone(), tag));
+    }
+    env.storage().persistent().remove(&tags_key);
+    env.storage()
+        .persistent()
+        .remove(&types::ReleaseKey::ApprovalRequired(key.clone()));
+    env.storage()
+        .persistent()
+        .remove(&types::ReleaseKey::SigningKeys(key.clone()));
+    for channel in [
+        types::ReleaseChannel::Nightly,
+        types::ReleaseChannel::Rc,
+        types::ReleaseChannel::Stable,
+    ] {
+        env.storage()
+            .persistent()
+            .remove(&types::ReleaseKey::ChannelHead(key.clone(), channel));
+    }
+}
+
+/// Check and use the approved proposal of the DAO for a release.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+/// * `tag` - The release tag
+/// * `proposal_id` - The approved proposal of the DAO
+/// * `required` - Whether the proposal is required if releases need approval
+///
+/// # Panics
+/// * If a proposal is required but missing
+/// * If the proposal doesn't exist, is not approved or already used
+fn use_release_proposal(
+    env: &Env,
+    key: &Bytes,
+    tag: &String,
+    proposal_id: Option<u32>,
+    required: bool,
+) {
+    if let Some(proposal_id) = proposal_id {
+        let proposal_key = types::ReleaseKey::Proposal(key.clone(), proposal_id);
+        let status = crate::tansu(env).get_proposal_status(key, &proposal_id);
+        if status != types::ProposalStatus::Approved
+            || env.storage().persistent().has(&proposal_key)
+        {
+            panic_with_error!(env, &errors::ExtendedErrors::ReleaseNotApproved);
+        }
+        env.storage().persistent().set(&proposal_key, tag);
+    } else if required
+        && <Registry as ReleasesTrait>::get_release_approval(env.clone(), key.clone())
+    {
+        panic_with_error!(env, &errors::ExtendedErrors::ReleaseNotApproved);
+    }
+}
+
+/// Check a release before it is created.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+/// * `tag` - The release tag
+/// * `commit_hash` - The commit hash the tag points to
+/// * `artifact_cid` - CID of the release artifacts
+/// * `changelog_cid` - CID of the release notes
+///
+/// # Panics
+/// * If the tag is not a semantic version
+/// * If the commit hash does not match the hash algorithm of the project
+/// * If the artifact or changelog CID is malformed
+fn validate_release(
+    env: &Env,
+    key: &Bytes,
+    tag: &String,
+    commit_hash: &String,
+    artifact_cid: &String,
+    changelog_cid: &String,
+) {
+    semver::validate(env, tag);
+    let algorithm = crate::tansu(env).get_hash_algorithm(key);
+    validation::validate_commit_hash(env, commit_hash, algorithm);
+    validation::validate_cid(env, artifact_cid);
+    validation::validate_cid(env, changelog_cid);
+}
+
+/// Whether a tag can be released, see `create`.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+/// * `tag` - The release tag
+///
+/// # Returns
+/// * `bool` - True if the tag was never released, archived releases
+///   included, and the maximum number of releases is not reached
+pub fn can_release(env: &Env, key: &Bytes, tag: &String) -> bool {
+    let tags = <Registry as ReleasesTrait>::get_releases(env.clone(), key.clone());
+    tags.len() < types::MAX_RELEASES
+        && !env
+            .storage()
+            .persistent()
+            .has(&types::ReleaseKey::Release(key.clone(), tag.clone()))
+}
+
+/// Create a release, making it the head of its channel.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+/// * `maintainer` - The address creating the release
+/// * `tag` - The release tag
+/// * `commit_hash` - The commit hash the tag points to
+/// * `artifact_cid` - CID of the release artifacts
+/// * `changelog_cid` - CID of the release notes
+/// * `channel` - The channel of the release
+/// * `proposal_id` - The approved proposal of the DAO for the release
+///
+/// # Panics
+/// * If the tag is already released
+/// * If the maximum number of releases is reached
+#[allow(clippy::too_many_arguments)]
+pub fn create(
+    env: &Env,
+    key: &Bytes,
+    maintainer: &Address,
+    tag: &String,
+    commit_hash: &String,
+    artifact_cid: &String,
+    changelog_cid: &String,
+    channel: types::ReleaseChannel,
+    proposal_id: Option<u32>,
+) {
+    if !can_release(env, key, tag) {
+        panic_with_error!(env, &errors::ContractErrors::InvalidRelease);
+    }
+    let mut tags = <Registry as ReleasesTrait>::get_releases(env.clone(), key.clone());
+    tags.push_back(tag.clone());
+
+    env.storage()
+        .persistent()
+        .set(&types::ReleaseKey::Tags(key.clone()), &tags);
+    env.storage().persistent().set(
+        &types::ReleaseKey::Release(key.clone(), tag.clone()),
+        &types::Release {
+            tag: tag.clone(),
+            commit_hash: commit_hash.clone(),
+            artifact_cid: artifact_cid.clone(),
+            changelog_cid: changelog_cid.clone(),
+            timestamp: env.ledger().timestamp(),
+            yanked: None,
+            sboms: Vec::new(env),
+            proposal_id,
+            toolchain: Map::new(env),
+            channel,
+        },
+    );
+    env.storage()
+        .persistent()
+        .set(&types::ReleaseKey::ChannelHead(key.clone(), channel), tag);
+
+    events::ReleaseCreated {
+        project_key: key.clone(),
+        tag: tag.clone(),
+        maintainer: maintainer.clone(),
+        commit_hash: commit_hash.clone(),
+        artifact_cid: artifact_cid.clone(),
+        changelog_cid: changelog_cid.clone(),
+        channel,
+        proposal_id,
+    }
+    .publish(env);
+}
+
+/// Archive a release, freeing its slot among the `MAX_RELEASES` releases of
+/// the project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+/// * `maintainer` - The address archiving the release
+/// * `tag` - The release tag
+///
+/// # Panics
+/// * If the release doesn't exist or is already archived
+pub fn archive(env: &Env, key: &Bytes, maintainer: &Address, tag: &String) {
+    let mut tags = <Registry as ReleasesTrait>::get_releases(env.clone(), key.clone());
+    let Some(index) = tags.first_index_of(tag) else {
+        panic_with_error!(env, &errors::ContractErrors::UnknownRelease);
+    };
+    tags.remove(index);
+    env.storage()
+        .persistent()
+        .set(&types::ReleaseKey::Tags(key.clone()), &tags);
+    remove_channel_heads(env, key, tag);
+
+    events::ReleaseArchived {
+        project_key: key.clone(),
+        tag: tag.clone(),
+        maintainer: maintainer.clone(),
+    }
+    .publish(env);
+}
+
+/// Remove a tag from the head of the channels it leads.
+fn remove_channel_heads(env: &Env, key: &Bytes, tag: &String) {
+    for channel in [
+        types::ReleaseChannel::Nightly,
+        types::ReleaseChannel::Rc,
+        types::ReleaseChannel::Stable,
+    ] {
+        if <Registry as ReleasesTrait>::get_channel_head(env.clone(), key.clone(), channel).as_ref()
+            == Some(tag)
+        {
+            env.storage()
+                .persistent()
+                .remove(&types::ReleaseKey::ChannelHead(key.clone(), channel));
+        }
+    }
+}
+
+/// Promote a release to a later channel.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+/// * `maintainer` - The address promoting the release
+/// * `tag` - The release tag
+/// * `channel` - The channel to promote the release to
+/// * `proposal_id` - The approved proposal of the DAO for the promotion
+///
+/// # Panics
+/// * If the release doesn't exist or is yanked
+/// * If the channel does not come after the channel of the release
+pub fn promote(
+    env: &Env,
+    key: &Bytes,
+    maintainer: &Address,
+    tag: &String,
+    channel: types::ReleaseChannel,
+    proposal_id: Option<u32>,
+) {
+    let release_key = types::ReleaseKey::Release(key.clone(), tag.clone());
+    let mut release =
+        <Registry as ReleasesTrait>::get_release(env.clone(), key.clone(), tag.clone());
+    if release.yanked.is_some() || channel <= release.channel {
+        panic_with_error!(env, &errors::ContractErrors::InvalidRelease);
+    }
+    release.channel = channel;
+    env.storage().persistent().set(&release_key, &release);
+    env.storage()
+        .persistent()
+        .set(&types::ReleaseKey::ChannelHead(key.clone(), channel), tag);
+
+    events::ReleasePromoted {
+        project_key: key.clone(),
+        tag: tag.clone(),
+        maintainer: maintainer.clone(),
+        channel,
+        proposal_id,
+    }
+    .publish(env);
+}
+
+/// Yank a release, removing it from the head of its channels.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+/// * `maintainer` - The address yanking the release
+/// * `tag` - The release tag
+/// * `reason_cid` - CID of the document explaining the yank
+///
+/// # Panics
+/// * If the release doesn't exist or is already yanked
+/// * If the reason CID is malformed
+pub fn yank(env: &Env, key: &Bytes, maintainer: &Address, tag: &String, reason_cid: &String) {
+    let mut release =
+        <Registry as ReleasesTrait>::get_release(env.clone(), key.clone(), tag.clone());
+    if release.yanked.is_some() {
+        panic_with_error!(env, &errors::ContractErrors::InvalidRelease);
+    }
+    validation::validate_cid(env, reason_cid);
+    release.yanked = Some(reason_cid.clone());
+    env.storage().persistent().set(
+        &types::ReleaseKey::Release(key.clone(), tag.clone()),
+        &release,
+    );
+    remove_channel_heads(env, key, tag);
+
+    events::ReleaseYanked {
+        project_key: key.clone(),
+        tag: tag.clone(),
+        maintainer: maintainer.clone(),
+        reason_cid: reason_cid.clone(),
+    }
+    .publish(env);
+}
+
+/// Register a release signing key, dropping the oldest revoked key once
+/// `MAX_RELEASE_SIGNING_KEYS` is reached.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `keys` - The signing keys of the project
+/// * `public_key` - The ed25519 public key
+///
+/// # Panics
+/// * If the key was already registered
+/// * If all the keys are active and the maximum is reached
+fn push_release_key(env: &Env, keys: &mut Vec<types::ReleaseSigningKey>, public_key: &BytesN<32>) {
+    if keys
+        .iter()
+        .any(|signing_key| signing_key.public_key == *public_key)
+    {
+        panic_with_error!(env, &errors::ExtendedErrors::InvalidSigningKey);
+    }
+    if keys.len() >= types::MAX_RELEASE_SIGNING_KEYS {
+        match keys
+            .iter()
+            .position(|signing_key| signing_key.revoked.is_some())
+        {
+            Some(index) => {
+                keys.remove(index as u32);
+            }
+            None => panic_with_error!(env, &errors::ExtendedErrors::InvalidSigningKey),
+        }
+    }
+    keys.push_back(types::ReleaseSigningKey {
+        public_key: public_key.clone(),
+        added: env.ledger().timestamp(),
+        revoked: None,
+    });
+}
+
+/// Mark an active release signing key as revoked.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `keys` - The signing keys of the project
+/// * `public_key` - The ed25519 public key
+///
+/// # Panics
+/// * If the key is not an active key of the project
+fn revoke_key(env: &Env, keys: &mut Vec<types::ReleaseSigningKey>, public_key: &BytesN<32>) {
+    let Some(index) = keys.iter().position(|signing_key| {
+        signing_key.public_key == *public_key && signing_key.revoked.is_none()
+    }) else {
+        panic_with_error!(env, &errors::ExtendedErrors::UnknownSigningKey);
+    };
+    let mut signing_key = keys.get_unchecked(index as u32);
+    signing_key.revoked = Some(env.ledger().timestamp());
+    keys.set(index as u32, signing_key);
+}
+
+/// Store the release signing keys of a project.
+fn store_release_keys(env: &Env, key: &Bytes, keys: &Vec<types::ReleaseSigningKey>) {
+    env.storage()
+        .persistent()
+        .set(&types::ReleaseKey::SigningKeys(key.clone()), keys);
+}
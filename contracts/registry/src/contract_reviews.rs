@@ -0,0 +1,172 @@
+use soroban_sdk::{Address, Bytes, Env, String, Vec, contractimpl, panic_with_error};
+
+use crate::{
+    Registry, RegistryArgs, RegistryClient, ReviewsTrait, errors, events, types, validation,
+};
+
+#[contractimpl]
+impl ReviewsTrait for Registry {
+    /// Attest the code review of a commit of a project.
+    ///
+    /// Reviews are attested by members holding the Reviewer badge on the
+    /// project, see `set_badges`, so N-of-M review policies can be checked
+    /// on-chain before a release. A reviewer has one review per commit,
+    /// reviewing again replaces the verdict. Only the last
+    /// `MAX_REVIEWED_COMMITS` commits with reviews are kept.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `reviewer` - The address of the reviewer calling this function
+    /// * `key` - The project key identifier
+    /// * `commit_hash` - The commit hash reviewed
+    /// * `verdict` - The verdict of the review
+    /// * `notes_cid` - CID of the review notes
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the reviewer does not hold the Reviewer badge on the project
+    /// * If the commit hash does not match the hash algorithm of the project
+    /// * If the notes CID is malformed
+    /// * If the maximum number of reviews of the commit is reached
+    fn attest_review(
+        env: Env,
+        reviewer: Address,
+        key: Bytes,
+        commit_hash: String,
+        verdict: types::ReviewVerdict,
+        notes_cid: String,
+    ) {
+        crate::require_not_paused(&env);
+
+        reviewer.require_auth();
+        crate::tansu(&env).get_project(&key);
+        let badges = crate::tansu(&env).get_badges(&key);
+        if !badges.reviewer.contains(&reviewer) {
+            panic_with_error!(&env, &errors::ExtendedErrors::NotReviewer);
+        }
+
+        let algorithm = crate::tansu(&env).get_hash_algorithm(&key);
+        validation::validate_commit_hash(&env, &commit_hash, algorithm);
+        validation::validate_cid(&env, &notes_cid);
+
+        let review = types::Review {
+            reviewer: reviewer.clone(),
+            verdict,
+            notes_cid: notes_cid.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        let mut reviews = Self::get_reviews(env.clone(), key.clone(), commit_hash.clone());
+        match reviews
+            .iter()
+            .position(|review| review.reviewer == reviewer)
+        {
+            Some(index) => reviews.set(index as u32, review),
+            None => {
+                if reviews.len() >= types::MAX_REVIEWS {
+                    panic_with_error!(&env, &errors::ExtendedErrors::InvalidReview);
+                }
+                if reviews.is_empty() {
+                    track_reviewed_commit(&env, &key, &commit_hash);
+                }
+                reviews.push_back(review);
+            }
+        }
+        env.storage().persistent().set(
+            &types::ReviewKey::Reviews(key.clone(), commit_hash.clone()),
+            &reviews,
+        );
+
+        events::ReviewAttested {
+            project_key: key,
+            commit_hash,
+            reviewer,
+            verdict,
+            notes_cid,
+        }
+        .publish(&env);
+    }
+
+    /// Get the reviews of a commit of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    /// * `commit_hash` - The commit hash
+    ///
+    /// # Returns
+    /// * `Vec<types::Review>` - The reviews, one per reviewer
+    fn get_reviews(env: Env, key: Bytes, commit_hash: String) -> Vec<types::Review> {
+        env.storage()
+            .persistent()
+            .get(&types::ReviewKey::Reviews(key, commit_hash))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get the number of approving reviews of a commit.
+    ///
+    /// Only reviewers still holding the Reviewer badge are counted, so
+    /// revoking the badge of a reviewer withdraws their approvals.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    /// * `commit_hash` - The commit hash
+    ///
+    /// # Returns
+    /// * `u32` - The number of `Approved` verdicts
+    fn get_approval_count(env: Env, key: Bytes, commit_hash: String) -> u32 {
+        let reviewers = crate::tansu(&env).get_badges(&key).reviewer;
+        Self::get_reviews(env, key, commit_hash)
+            .iter()
+            .filter(|review| {
+                review.verdict == types::ReviewVerdict::Approved
+                    && reviewers.contains(&review.reviewer)
+            })
+            .count() as u32
+    }
+}
+
+/// Track a commit with reviews, dropping the reviews of the oldest commit
+/// beyond `MAX_REVIEWED_COMMITS`.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+/// * `commit_hash` - The commit hash
+fn track_reviewed_commit(env: &Env, key: &Bytes, commit_hash: &String) {
+    let commits_key = types::ReviewKey::Commits(key.clone());
+    let mut commits: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&commits_key)
+        .unwrap_or(Vec::new(env));
+    if commits.len() >= types::MAX_REVIEWED_COMMITS
+        && let Some(oldest) = commits.pop_front()
+    {
+        env.storage()
+            .persistent()
+            .remove(&types::ReviewKey::Reviews(key.clone(), oldest));
+    }
+    commits.push_back(commit_hash.clone());
+    env.storage().persistent().set(&commits_key, &commits);
+}
+
+/// Remove all reviews of a project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+pub fn remove_reviews(env: &Env, key: &Bytes) {
+    let commits_key = types::ReviewKey::Commits(key.clone());
+    let commits: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&commits_key)
+        .unwrap_or(Vec::new(env));
+    for commit_hash in commits.iter() {
+        env.storage()
+            .persistent()
+            .remove(&types::ReviewKey::Reviews(key.clone(), commit_hash));
+    }
+    env.storage().persistent().remove(&commits_key);
+}
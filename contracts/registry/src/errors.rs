@@ -0,0 +1,58 @@
+//! Errors of the registry contract.
+//!
+//! Codes are shared with the Tansu contract so that clients handle the
+//! errors of both contracts alike. Only the errors raised by the registry
+//! are declared.
+
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractErrors {
+    // Project lifecycle
+    ProjectArchived = 26,
+    InsufficientRole = 32,
+    // DAO control
+    DaoControlled = 38,
+    // Incident response
+    ProjectPaused = 40,
+    // Releases
+    InvalidRelease = 48,
+    UnknownRelease = 49,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ExtendedErrors {
+    // Commits
+    InvalidCommitHash = 52,
+    // Proofs
+    TreeAlreadyAnchored = 54,
+    // Releases
+    InvalidArtifact = 55,
+    // Attestations
+    NotAttestor = 56,
+    InvalidAttestation = 57,
+    InvalidAudit = 58,
+    // Advisories
+    InvalidAdvisory = 59,
+    UnknownAdvisory = 60,
+    // Releases
+    ReleaseNotApproved = 61,
+    // Dependencies
+    InvalidDependency = 65,
+    UnknownDependency = 66,
+    // Reviews
+    NotReviewer = 67,
+    InvalidReview = 68,
+    // Credits
+    InvalidCredit = 69,
+    // IPFS
+    InvalidCid = 70,
+    // Releases
+    InvalidSigningKey = 71,
+    UnknownSigningKey = 72,
+    InvalidToolchain = 73,
+}
@@ -0,0 +1,235 @@
+//! Event definitions for the registry contract.
+
+use crate::types::{
+    AuditVerdict, CiStatus, CreditTarget, ReleaseChannel, ReviewVerdict, SbomFormat, Severity,
+};
+use soroban_sdk::{Address, Bytes, BytesN, Map, String, Vec, contractevent};
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseApprovalUpdated {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub maintainer: Address,
+    pub required: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseKeyUpdated {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub public_key: BytesN<32>,
+    pub maintainer: Address,
+    pub revoked: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdvisoryPublished {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub id: String,
+    pub maintainer: Address,
+    pub severity: Severity,
+    pub affected_releases: Vec<String>,
+    pub cid: String,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdvisoryAmended {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub id: String,
+    pub maintainer: Address,
+    pub severity: Severity,
+    pub affected_releases: Vec<String>,
+    pub cid: String,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdvisoryResolved {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub id: String,
+    pub maintainer: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CiStatusPosted {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub commit_hash: String,
+    pub attestor: Address,
+    pub status: CiStatus,
+    pub run_cid: String,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewAttested {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub commit_hash: String,
+    pub reviewer: Address,
+    pub verdict: ReviewVerdict,
+    pub notes_cid: String,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributorCredited {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub contributor: Address,
+    pub maintainer: Address,
+    pub target: CreditTarget,
+    pub share_bps: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DependencyUpdated {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub dependency: Bytes,
+    pub maintainer: Address,
+    pub range: Option<String>, // None once the dependency is removed
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PinAttested {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub dependency: Bytes,
+    pub maintainer: Address,
+    pub tag: String,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreeAnchored {
+    #[topic]
+    pub project_key: Bytes,
+    pub commit_hash: String,
+    pub root: BytesN<32>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseCreated {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub tag: String,
+    pub maintainer: Address,
+    pub commit_hash: String,
+    pub artifact_cid: String,
+    pub changelog_cid: String,
+    pub channel: ReleaseChannel,
+    pub proposal_id: Option<u32>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleasePromoted {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub tag: String,
+    pub maintainer: Address,
+    pub channel: ReleaseChannel,
+    pub proposal_id: Option<u32>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseYanked {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub tag: String,
+    pub maintainer: Address,
+    pub reason_cid: String,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseArchived {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub tag: String,
+    pub maintainer: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArtifactAdded {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub tag: String,
+    pub platform: String,
+    pub sha256: BytesN<32>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BuildAttested {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub tag: String,
+    pub builder: Address,
+    pub hash: BytesN<32>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuditAttached {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub tag: String,
+    pub auditor: Address,
+    pub report_cid: String,
+    pub verdict: AuditVerdict,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SbomAttached {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub tag: String,
+    pub format: SbomFormat,
+    pub sha256: BytesN<32>,
+    pub cid: String,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ToolchainRecorded {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub tag: String,
+    pub maintainer: Address,
+    pub toolchain: Map<String, String>,
+}
@@ -0,0 +1,359 @@
+#![no_std]
+
+use soroban_sdk::contractmeta;
+use soroban_sdk::{Address, Bytes, BytesN, Env, Map, String, Vec, contract, panic_with_error};
+
+mod contract_advisories;
+mod contract_ci;
+mod contract_credits;
+mod contract_dependencies;
+mod contract_proofs;
+mod contract_registry;
+// functions with too many arguments allow it themselves, but `#[contractimpl]`
+// generates `RegistryArgs::create_release` next to the impl without its attributes
+#[allow(clippy::too_many_arguments)]
+mod contract_releases;
+mod contract_reviews;
+pub mod errors;
+mod events;
+mod semver;
+mod tansu;
+pub mod types;
+mod validation;
+
+contractmeta!(
+    key = "Description",
+    val = "Tansu - Registry of releases and attestations"
+);
+
+#[contract]
+pub struct Registry;
+
+pub trait RegistryTrait {
+    fn __constructor(env: Env, tansu: Address);
+
+    fn get_tansu(env: Env) -> Address;
+
+    fn check_release_action(env: Env, key: Bytes, action: types::ReleaseAction) -> Option<u32>;
+
+    fn apply_release_action(env: Env, key: Bytes, executor: Address, action: types::ReleaseAction);
+
+    fn remove_project(env: Env, key: Bytes);
+}
+
+pub trait AdvisoriesTrait {
+    #[allow(clippy::too_many_arguments)]
+    fn publish_advisory(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        id: String,
+        severity: types::Severity,
+        affected_releases: Vec<String>,
+        cid: String,
+    );
+
+    #[allow(clippy::too_many_arguments)]
+    fn amend_advisory(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        id: String,
+        severity: types::Severity,
+        affected_releases: Vec<String>,
+        cid: String,
+    );
+
+    fn resolve_advisory(env: Env, maintainer: Address, key: Bytes, id: String);
+
+    fn get_advisory(env: Env, key: Bytes, id: String) -> types::Advisory;
+
+    fn get_advisories(env: Env, key: Bytes) -> Vec<String>;
+
+    fn get_open_advisories(env: Env, key: Bytes) -> Vec<types::Advisory>;
+}
+
+pub trait CiTrait {
+    fn ci_status(
+        env: Env,
+        attestor: Address,
+        key: Bytes,
+        commit_hash: String,
+        status: types::CiStatus,
+        run_cid: String,
+    );
+
+    fn get_ci_runs(env: Env, key: Bytes, commit_hash: String) -> Vec<types::CiRun>;
+
+    fn get_ci_status(
+        env: Env,
+        key: Bytes,
+        commit_hash: String,
+        attestor: Address,
+    ) -> Option<types::CiRun>;
+}
+
+pub trait ReviewsTrait {
+    fn attest_review(
+        env: Env,
+        reviewer: Address,
+        key: Bytes,
+        commit_hash: String,
+        verdict: types::ReviewVerdict,
+        notes_cid: String,
+    );
+
+    fn get_reviews(env: Env, key: Bytes, commit_hash: String) -> Vec<types::Review>;
+
+    fn get_approval_count(env: Env, key: Bytes, commit_hash: String) -> u32;
+}
+
+pub trait CreditsTrait {
+    fn credit(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        target: types::CreditTarget,
+        contributor: Address,
+        share_bps: u32,
+    );
+
+    fn get_credits(env: Env, key: Bytes, target: types::CreditTarget) -> Map<Address, u32>;
+
+    fn get_credited_targets(env: Env, key: Bytes) -> Vec<types::CreditTarget>;
+}
+
+pub trait DependenciesTrait {
+    fn declare_dependency(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        dependency: Bytes,
+        range: String,
+    );
+
+    fn remove_dependency(env: Env, maintainer: Address, key: Bytes, dependency: Bytes);
+
+    fn get_dependencies(env: Env, key: Bytes) -> Map<Bytes, String>;
+
+    fn get_dependents(env: Env, key: Bytes, page: u32) -> Vec<Bytes>;
+
+    fn attest_pin(env: Env, maintainer: Address, key: Bytes, dependency: Bytes, tag: String);
+
+    fn get_pins(env: Env, key: Bytes) -> Map<Bytes, String>;
+
+    fn get_vulnerable_pins(env: Env, key: Bytes) -> Vec<types::VulnerablePin>;
+}
+
+pub trait ProofsTrait {
+    fn anchor_tree(env: Env, maintainer: Address, key: Bytes, merkle_root: BytesN<32>);
+
+    fn get_tree_root(env: Env, key: Bytes, commit_hash: String) -> Option<BytesN<32>>;
+
+    fn get_tree_anchor(env: Env, key: Bytes, merkle_root: BytesN<32>) -> Option<types::TreeAnchor>;
+
+    fn verify_file(
+        env: Env,
+        key: Bytes,
+        commit_or_root: types::TreeRef,
+        path_hash: BytesN<32>,
+        proof: Vec<BytesN<32>>,
+    ) -> bool;
+}
+
+pub trait ReleasesTrait {
+    #[allow(clippy::too_many_arguments)]
+    fn create_release(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        tag: String,
+        commit_hash: String,
+        artifact_cid: String,
+        changelog_cid: String,
+        channel: types::ReleaseChannel,
+        proposal_id: Option<u32>,
+    );
+
+    fn promote_release(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        tag: String,
+        channel: types::ReleaseChannel,
+        proposal_id: Option<u32>,
+    );
+
+    fn get_channel_head(env: Env, key: Bytes, channel: types::ReleaseChannel) -> Option<String>;
+
+    fn set_release_approval(env: Env, maintainer: Address, key: Bytes, required: bool);
+
+    fn get_release_approval(env: Env, key: Bytes) -> bool;
+
+    fn add_release_key(env: Env, maintainer: Address, key: Bytes, public_key: BytesN<32>);
+
+    fn revoke_release_key(env: Env, maintainer: Address, key: Bytes, public_key: BytesN<32>);
+
+    fn rotate_release_key(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        old_key: BytesN<32>,
+        new_key: BytesN<32>,
+    );
+
+    fn get_release_keys(env: Env, key: Bytes) -> Vec<types::ReleaseSigningKey>;
+
+    fn get_active_release_keys(env: Env, key: Bytes) -> Vec<BytesN<32>>;
+
+    fn yank_release(env: Env, maintainer: Address, key: Bytes, tag: String, reason_cid: String);
+
+    fn archive_release(env: Env, maintainer: Address, key: Bytes, tag: String);
+
+    fn add_artifact(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        tag: String,
+        platform: String,
+        sha256: BytesN<32>,
+    );
+
+    fn get_artifacts(env: Env, key: Bytes, tag: String) -> Vec<types::Artifact>;
+
+    #[allow(clippy::too_many_arguments)]
+    fn attach_sbom(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        tag: String,
+        format: types::SbomFormat,
+        sha256: BytesN<32>,
+        cid: String,
+    );
+
+    fn set_release_toolchain(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        tag: String,
+        toolchain: Map<String, String>,
+    );
+
+    fn attest_build(env: Env, key: Bytes, tag: String, builder: Address, hash: BytesN<32>);
+
+    fn get_attestations(env: Env, key: Bytes, tag: String) -> Vec<types::Attestation>;
+
+    fn get_attestation_count(env: Env, key: Bytes, tag: String, hash: BytesN<32>) -> u32;
+
+    fn attach_audit(
+        env: Env,
+        key: Bytes,
+        tag: String,
+        auditor: Address,
+        report_cid: String,
+        verdict: types::AuditVerdict,
+    );
+
+    fn get_audits(env: Env, key: Bytes, tag: String) -> Vec<types::Audit>;
+
+    fn get_release(env: Env, key: Bytes, tag: String) -> types::Release;
+
+    fn get_releases(env: Env, key: Bytes) -> Vec<String>;
+
+    fn latest_release(env: Env, key: Bytes) -> Option<types::Release>;
+}
+
+/// Client of the Tansu contract the projects are registered with.
+fn tansu(env: &Env) -> tansu::TansuClient<'_> {
+    let address = <Registry as RegistryTrait>::get_tansu(env.clone());
+    tansu::TansuClient::new(env, &address)
+}
+
+/// Require that the Tansu contract is not paused, panic if it is.
+fn require_not_paused(env: &Env) {
+    tansu(env).require_not_paused();
+}
+
+/// Authenticate a maintainer of a project.
+///
+/// # Panics
+/// * If the project doesn't exist
+/// * If the maintainer is not authorized
+fn auth_maintainers(env: &Env, maintainer: &Address, project_key: &Bytes) {
+    auth_maintainers_role(
+        env,
+        maintainer,
+        project_key,
+        types::MaintainerRole::Committer,
+    );
+}
+
+/// Authenticate a maintainer and check that it holds at least the given role.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `maintainer` - The address of the maintainer
+/// * `project_key` - The project key identifier
+/// * `role` - The minimal role required
+///
+/// # Panics
+/// * If the project doesn't exist
+/// * If the maintainer is not authorized
+/// * If the maintainer's role is not sufficient
+fn auth_maintainers_role(
+    env: &Env,
+    maintainer: &Address,
+    project_key: &Bytes,
+    role: types::MaintainerRole,
+) {
+    maintainer.require_auth();
+    if tansu(env).get_maintainer_role(project_key, maintainer) < role {
+        panic_with_error!(&env, &errors::ContractErrors::InsufficientRole);
+    }
+}
+
+/// Require that the project is not archived, panic if it is.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+///
+/// # Panics
+/// * If the project is archived
+fn require_not_archived(env: &Env, project_key: &Bytes) {
+    if tansu(env).is_archived(project_key) {
+        panic_with_error!(&env, &errors::ContractErrors::ProjectArchived);
+    }
+}
+
+/// Require that the project is not paused, panic if it is.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+///
+/// # Panics
+/// * If the project is paused
+fn require_project_not_paused(env: &Env, project_key: &Bytes) {
+    if tansu(env).is_project_paused(project_key) {
+        panic_with_error!(&env, &errors::ContractErrors::ProjectPaused);
+    }
+}
+
+/// Require that the project is not controlled by its DAO, panic if it is.
+///
+/// Once the maintainers renounced to the project, releases are only
+/// changed through approved proposals, see `apply_release_action`.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+///
+/// # Panics
+/// * If the project is controlled by its DAO
+fn require_not_dao_controlled(env: &Env, project_key: &Bytes) {
+    if tansu(env).is_dao_controlled(project_key) {
+        panic_with_error!(&env, &errors::ContractErrors::DaoControlled);
+    }
+}
@@ -0,0 +1,236 @@
+//! Semantic versions of release tags, see https://semver.org.
+//!
+//! Tags are versions optionally prefixed with `v`, e.g. `v1.2.3-rc.1`.
+//! Build metadata (`+build.5`) is accepted but ignored for the ordering.
+
+use core::cmp::Ordering;
+
+use soroban_sdk::{Env, String, panic_with_error};
+
+use crate::{errors, types};
+
+/// Version parsed from a release tag, borrowing the tag bytes.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Version<'a> {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    pub pre: &'a [u8], // pre-release identifiers, empty for a release
+}
+
+impl<'a> Version<'a> {
+    /// Parse a release tag.
+    ///
+    /// # Arguments
+    /// * `tag` - The release tag
+    ///
+    /// # Returns
+    /// * `Option<Version>` - The version, `None` if the tag is malformed
+    pub fn parse(tag: &'a [u8]) -> Option<Self> {
+        let tag = tag.strip_prefix(b"v").unwrap_or(tag);
+        let (version, build) = split_once(tag, b'+');
+        if let Some(build) = build
+            && !valid_identifiers(build, false)
+        {
+            return None;
+        }
+        let (core, pre) = split_once(version, b'-');
+        let pre = match pre {
+            Some(pre) if valid_identifiers(pre, true) => pre,
+            Some(_) => return None,
+            None => &[],
+        };
+
+        let mut numbers = core.split(|c| *c == b'.');
+        let major = parse_number(numbers.next()?)?;
+        let minor = parse_number(numbers.next()?)?;
+        let patch = parse_number(numbers.next()?)?;
+        if numbers.next().is_some() {
+            return None;
+        }
+
+        Some(Version {
+            major,
+            minor,
+            patch,
+            pre,
+        })
+    }
+
+    /// Whether the version is a pre-release.
+    pub fn is_prerelease(&self) -> bool {
+        !self.pre.is_empty()
+    }
+}
+
+impl Ord for Version<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.major
+            .cmp(&other.major)
+            .then(self.minor.cmp(&other.minor))
+            .then(self.patch.cmp(&other.patch))
+            .then_with(|| compare_pre(self.pre, other.pre))
+    }
+}
+
+impl PartialOrd for Version<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Copy a release tag into a buffer.
+///
+/// # Arguments
+/// * `tag` - The release tag
+/// * `buffer` - Buffer large enough for `MAX_RELEASE_TAG_LENGTH` bytes
+///
+/// # Returns
+/// * `Option<&[u8]>` - The tag bytes, `None` if the tag is too long
+pub fn tag_bytes<'a>(
+    tag: &String,
+    buffer: &'a mut [u8; types::MAX_RELEASE_TAG_LENGTH as usize],
+) -> Option<&'a [u8]> {
+    let len = tag.len() as usize;
+    if len > buffer.len() {
+        return None;
+    }
+    let bytes = &mut buffer[..len];
+    tag.copy_into_slice(bytes);
+    Some(bytes)
+}
+
+/// Validate a release tag.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `tag` - The release tag
+///
+/// # Panics
+/// * If the tag is longer than `MAX_RELEASE_TAG_LENGTH` or is not a
+///   semantic version
+pub fn validate(env: &Env, tag: &String) {
+    let mut buffer = [0u8; types::MAX_RELEASE_TAG_LENGTH as usize];
+    if tag_bytes(tag, &mut buffer)
+        .and_then(Version::parse)
+        .is_none()
+    {
+        panic_with_error!(env, &errors::ContractErrors::InvalidRelease);
+    }
+}
+
+/// Validate a version range, e.g. `^1.2`, `>=1.0.0, <2.0.0` or `*`.
+///
+/// Ranges are comma separated comparators. A comparator is an optional
+/// operator (`^`, `~`, `=`, `>`, `>=`, `<`, `<=`, caret if omitted) and a
+/// version whose minor and patch numbers may be omitted.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `range` - The version range
+///
+/// # Panics
+/// * If the range is empty, longer than `MAX_VERSION_RANGE_LENGTH` or
+///   malformed
+pub fn validate_range(env: &Env, range: &String) {
+    let len = range.len() as usize;
+    let mut buffer = [0u8; types::MAX_VERSION_RANGE_LENGTH as usize];
+    if len == 0 || len > buffer.len() {
+        panic_with_error!(env, &errors::ExtendedErrors::InvalidDependency);
+    }
+    let bytes = &mut buffer[..len];
+    range.copy_into_slice(bytes);
+
+    let valid = bytes == b"*"
+        || bytes
+            .split(|c| *c == b',')
+            .all(|comparator| valid_comparator(comparator.trim_ascii()));
+    if !valid {
+        panic_with_error!(env, &errors::ExtendedErrors::InvalidDependency);
+    }
+}
+
+/// Validate a comparator of a version range.
+fn valid_comparator(comparator: &[u8]) -> bool {
+    let version = [b">=".as_slice(), b"<=", b"^", b"~", b"=", b">", b"<"]
+        .iter()
+        .find_map(|operator| comparator.strip_prefix(*operator))
+        .unwrap_or(comparator)
+        .trim_ascii_start();
+
+    let (version, pre) = split_once(version, b'-');
+    let mut numbers = version.split(|c| *c == b'.');
+    let count = numbers.clone().count();
+    (1..=3).contains(&count)
+        && numbers.all(|number| parse_number(number).is_some())
+        && pre.is_none_or(|pre| count == 3 && valid_identifiers(pre, true))
+}
+
+/// Split a slice at the first occurrence of a separator.
+fn split_once(bytes: &[u8], separator: u8) -> (&[u8], Option<&[u8]>) {
+    match bytes.iter().position(|c| *c == separator) {
+        Some(index) => (&bytes[..index], Some(&bytes[index + 1..])),
+        None => (bytes, None),
+    }
+}
+
+/// Parse a numeric identifier, leading zeros are not allowed.
+fn parse_number(bytes: &[u8]) -> Option<u64> {
+    if bytes.is_empty() || (bytes.len() > 1 && bytes[0] == b'0') {
+        return None;
+    }
+    bytes.iter().try_fold(0u64, |number, c| {
+        if !c.is_ascii_digit() {
+            return None;
+        }
+        number.checked_mul(10)?.checked_add((c - b'0') as u64)
+    })
+}
+
+/// Validate dot separated identifiers made of ASCII alphanumerics and `-`.
+///
+/// Numeric identifiers of pre-releases cannot have leading zeros.
+fn valid_identifiers(bytes: &[u8], pre: bool) -> bool {
+    bytes.split(|c| *c == b'.').all(|identifier| {
+        !identifier.is_empty()
+            && identifier
+                .iter()
+                .all(|c| c.is_ascii_alphanumeric() || *c == b'-')
+            && !(pre && is_numeric(identifier) && identifier.len() > 1 && identifier[0] == b'0')
+    })
+}
+
+fn is_numeric(identifier: &[u8]) -> bool {
+    identifier.iter().all(u8::is_ascii_digit)
+}
+
+/// Compare pre-release identifiers, a release has precedence over its
+/// pre-releases.
+fn compare_pre(a: &[u8], b: &[u8]) -> Ordering {
+    match (a.is_empty(), b.is_empty()) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Greater,
+        (false, true) => return Ordering::Less,
+        (false, false) => {}
+    }
+
+    let mut a = a.split(|c| *c == b'.');
+    let mut b = b.split(|c| *c == b'.');
+    loop {
+        let ordering = match (a.next(), b.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a), Some(b)) => match (is_numeric(a), is_numeric(b)) {
+                // without leading zeros, longer numbers are greater
+                (true, true) => a.len().cmp(&b.len()).then(a.cmp(b)),
+                (true, false) => Ordering::Less,
+                (false, true) => Ordering::Greater,
+                (false, false) => a.cmp(b),
+            },
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
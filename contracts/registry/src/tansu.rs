@@ -0,0 +1,50 @@
+//! Tansu contract the projects are registered with.
+//!
+//! Projects, maintainers, badges and proposals live in Tansu, the registry
+//! only stores the releases and attestations of the projects.
+
+use soroban_sdk::{Address, Bytes, Env, String, Val, contractclient};
+
+use crate::types;
+
+/// Functions of the Tansu contract used by the registry.
+///
+/// The registry only uses the generated `TansuClient`.
+#[allow(dead_code)]
+#[contractclient(name = "TansuClient")]
+pub trait TansuInterface {
+    /// Panic if the contract is paused.
+    fn require_not_paused(env: Env);
+
+    /// Whether an address is a registered build attestor.
+    fn is_attestor(env: Env, attestor: Address) -> bool;
+
+    /// Badges of the members of a project.
+    fn get_badges(env: Env, key: Bytes) -> types::Badges;
+
+    /// Role of a maintainer, panics if the address is not a maintainer.
+    fn get_maintainer_role(env: Env, key: Bytes, maintainer: Address) -> types::MaintainerRole;
+
+    /// Whether a project is archived.
+    fn is_archived(env: Env, key: Bytes) -> bool;
+
+    /// Whether a project is paused.
+    fn is_project_paused(env: Env, key: Bytes) -> bool;
+
+    /// Whether a project is controlled by its DAO.
+    fn is_dao_controlled(env: Env, key: Bytes) -> bool;
+
+    /// Latest commit hash of a project.
+    fn get_commit(env: Env, project_key: Bytes) -> String;
+
+    /// Object format of the git repository of a project.
+    fn get_hash_algorithm(env: Env, key: Bytes) -> types::HashAlgorithm;
+
+    /// Project, panics if it doesn't exist. The registry only checks that
+    /// the project exists.
+    fn get_project(env: Env, project_key: Bytes) -> Val;
+
+    /// Status of a proposal of the DAO of a project.
+    fn get_proposal_status(env: Env, project_key: Bytes, proposal_id: u32)
+    -> types::ProposalStatus;
+}
@@ -0,0 +1,326 @@
+use soroban_sdk::{Address, Bytes, BytesN, Map, String, Vec, contracttype};
+
+// Constants
+pub const MAX_PROJECTS_PER_PAGE: u32 = 20;
+pub const MIN_CID_LENGTH: u32 = 46;
+pub const MAX_CID_LENGTH: u32 = 128;
+pub const MAX_RELEASES: u32 = 100;
+pub const MAX_RELEASE_TAG_LENGTH: u32 = 64;
+pub const MAX_ARTIFACTS: u32 = 20;
+pub const MAX_PLATFORM_LENGTH: u32 = 64;
+pub const MAX_TOOLCHAIN_COMPONENTS: u32 = 10;
+pub const MAX_TOOLCHAIN_NAME_LENGTH: u32 = 32;
+pub const MAX_TOOLCHAIN_VERSION_LENGTH: u32 = 64;
+pub const MAX_ATTESTATIONS: u32 = 20;
+pub const MAX_AUDITS: u32 = 20;
+pub const MAX_ADVISORIES: u32 = 100;
+pub const MAX_ADVISORY_ID_LENGTH: u32 = 64;
+pub const MAX_CI_COMMITS: u32 = 50;
+pub const MAX_CI_RUNS: u32 = 20;
+pub const MAX_DEPENDENCIES: u32 = 50;
+pub const MAX_RELEASE_SIGNING_KEYS: u32 = 20;
+pub const MAX_REVIEWED_COMMITS: u32 = 50;
+pub const MAX_REVIEWS: u32 = 20;
+pub const MAX_CREDITED_TARGETS: u32 = 200;
+pub const MAX_CONTRIBUTORS_PER_TARGET: u32 = 50;
+pub const TOTAL_SHARE_BPS: u32 = 10_000; // shares are in basis points
+pub const MAX_VERSION_RANGE_LENGTH: u32 = 64;
+pub const MAX_TREE_ANCHORS: u32 = 50;
+pub const MAX_PROOF_DEPTH: u32 = 64;
+
+#[contracttype]
+pub enum ReleaseKey {
+    Release(Bytes, String),             // release of a project, tag
+    Tags(Bytes),                        // tags released by a project
+    Artifacts(Bytes, String),           // artifacts of a release, tag
+    Attestations(Bytes, String),        // build attestations of a release, tag
+    Audits(Bytes, String),              // audit reports of a release, tag
+    ApprovalRequired(Bytes),            // releases need an approved proposal
+    Proposal(Bytes, u32),               // tag released with an approved proposal
+    SigningKeys(Bytes),                 // ed25519 keys signing the releases, oldest first
+    ChannelHead(Bytes, ReleaseChannel), // tag last released on a channel
+}
+
+#[contracttype]
+pub enum AdvisoryKey {
+    Ids(Bytes),              // advisory ids of a project, oldest first
+    Advisory(Bytes, String), // advisory of a project, id
+}
+
+#[contracttype]
+pub enum DependencyKey {
+    Dependencies(Bytes),          // version ranges of the dependencies of a project
+    DependentCount(Bytes),        // number of projects depending on a project
+    Dependents(Bytes, u32),       // page of projects depending on a project
+    DependentIndex(Bytes, Bytes), // position of a dependent in the listing, dependency
+    Pins(Bytes),                  // release shipped of each dependency of a project
+}
+
+#[contracttype]
+pub enum CiKey {
+    Commits(Bytes),      // commits of a project with CI runs, oldest first
+    Runs(Bytes, String), // CI runs of a commit, commit hash
+}
+
+#[contracttype]
+pub enum ReviewKey {
+    Commits(Bytes),         // commits of a project with reviews, oldest first
+    Reviews(Bytes, String), // reviews of a commit, commit hash
+}
+
+#[contracttype]
+pub enum CreditKey {
+    Targets(Bytes),               // commits and releases of a project with credits
+    Credits(Bytes, CreditTarget), // share of each contributor of a commit or release
+}
+
+#[contracttype]
+pub enum AnchorKey {
+    Roots(Bytes),            // anchored Merkle roots of a project, oldest first
+    Root(Bytes, BytesN<32>), // anchor of a Merkle root
+    Commit(Bytes, String),   // Merkle root anchored for a commit hash
+}
+
+/// Merkle root of the file tree of a commit, see `anchor_tree`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreeAnchor {
+    pub root: BytesN<32>,
+    pub commit_hash: String,
+    pub timestamp: u64, // ledger timestamp of the anchoring
+}
+
+/// Binary artifact of a release, see `add_artifact`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Artifact {
+    pub platform: String,   // target triple, e.g. x86_64-unknown-linux-gnu
+    pub sha256: BytesN<32>, // hash of the artifact
+}
+
+/// Reproducible build of a release by an attestor, see `attest_build`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Attestation {
+    pub builder: Address, // attestor who rebuilt the release
+    pub hash: BytesN<32>, // hash of the wasm or binary they obtained
+    pub timestamp: u64,   // ledger timestamp of the attestation
+}
+
+/// Outcome of a security audit.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AuditVerdict {
+    Passed,
+    PassedWithFindings,
+    Failed,
+}
+
+/// Security audit of a release by an attestor, see `attach_audit`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Audit {
+    pub auditor: Address,
+    pub report_cid: String, // CID of the audit report
+    pub verdict: AuditVerdict,
+    pub timestamp: u64, // ledger timestamp of the audit
+}
+
+/// Severity of a security advisory.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Severity {
+    Low,
+    Medium,
+    High,
+    Critical,
+}
+
+/// Security advisory of a project, see `publish_advisory`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Advisory {
+    pub id: String, // e.g. RUSTSEC-2025-0001 or GHSA-xxxx-xxxx-xxxx
+    pub severity: Severity,
+    pub affected_releases: Vec<String>, // affected release tags
+    pub cid: String,                    // CID of the advisory details
+    pub open: bool,                     // false once resolved
+    pub timestamp: u64,                 // ledger timestamp of the last change
+}
+
+/// Outcome of a CI run.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CiStatus {
+    Pending,
+    Passed,
+    Failed,
+}
+
+/// CI run of a commit reported by an attestor, see `ci_status`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CiRun {
+    pub attestor: Address,
+    pub status: CiStatus,
+    pub run_cid: String, // CID of the run logs and reports
+    pub timestamp: u64,  // ledger timestamp of the last status
+}
+
+/// Commit or release contributors are credited on, see `credit`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CreditTarget {
+    Commit(String),  // commit hash
+    Release(String), // release tag
+}
+
+/// Verdict of a code review.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ReviewVerdict {
+    Approved,
+    ChangesRequested,
+    Commented,
+}
+
+/// Code review of a commit by a member holding the Reviewer badge, see
+/// `attest_review`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Review {
+    pub reviewer: Address,
+    pub verdict: ReviewVerdict,
+    pub notes_cid: String, // CID of the review notes
+    pub timestamp: u64,    // ledger timestamp of the last verdict
+}
+
+/// Pinned release of a dependency affected by an open advisory, see
+/// `get_vulnerable_pins`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VulnerablePin {
+    pub dependency: Bytes,
+    pub tag: String,
+    pub advisory_id: String,
+    pub severity: Severity,
+}
+
+/// Anchored file tree to verify a proof against, see `verify_file`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TreeRef {
+    Commit(String),   // tree anchored for a commit hash
+    Root(BytesN<32>), // anchored Merkle root
+    Release(String),  // tree anchored for the commit of a release tag
+}
+
+/// Tagged release of a project, see `create_release`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Release {
+    pub tag: String,
+    pub commit_hash: String,            // commit the tag points to
+    pub artifact_cid: String,           // CID of the release artifacts
+    pub changelog_cid: String,          // CID of the release notes
+    pub timestamp: u64,                 // ledger timestamp of the release
+    pub yanked: Option<String>,         // CID of the reason, set once the release is withdrawn
+    pub sboms: Vec<Sbom>,               // software bills of materials, one per format
+    pub proposal_id: Option<u32>,       // approved proposal of the DAO for the release
+    pub toolchain: Map<String, String>, // version of each build tool, e.g. rustc
+    pub channel: ReleaseChannel,        // channel the release was promoted to
+}
+
+/// Channel of a release, releases are promoted from one channel to the next.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ReleaseChannel {
+    Nightly = 0,
+    Rc = 1,
+    Stable = 2,
+}
+
+/// ed25519 key signing the releases of a project, see `add_release_key`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseSigningKey {
+    pub public_key: BytesN<32>,
+    pub added: u64,           // ledger timestamp of the registration
+    pub revoked: Option<u64>, // ledger timestamp of the revocation
+}
+
+/// Format of a software bill of materials.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SbomFormat {
+    CycloneDx,
+    Spdx,
+}
+
+/// Software bill of materials of a release, see `attach_sbom`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sbom {
+    pub format: SbomFormat,
+    pub sha256: BytesN<32>, // hash of the SBOM document
+    pub cid: String,        // CID of the SBOM document
+}
+
+/// Release change applied by an approved proposal of the DAO of a project,
+/// see `apply_release_action`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReleaseAction {
+    // tag, commit hash, artifact CID, changelog CID, channel
+    Create(String, String, String, String, ReleaseChannel),
+    Promote(String, ReleaseChannel), // tag, channel
+    Yank(String, String),            // tag, reason CID
+    Archive(String),                 // tag
+}
+
+#[contracttype]
+pub enum DataKey {
+    Tansu, // Tansu contract the projects are registered with
+}
+
+// Types of the Tansu contract, see `tansu::TansuInterface`.
+
+/// Permissions of a maintainer on a project, each role includes the previous ones.
+#[contracttype(export = false)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum MaintainerRole {
+    Committer = 0, // can commit
+    Releaser = 1,  // can commit and release
+    Admin = 2,     // can also execute proposals
+}
+
+/// Object format of the git repository of a project.
+#[contracttype(export = false)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum HashAlgorithm {
+    #[default]
+    Sha1, // 40 hexadecimal characters, git's default
+    Sha256, // 64 hexadecimal characters
+}
+
+#[contracttype(export = false)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Badges {
+    pub developer: Vec<Address>,
+    pub triage: Vec<Address>,
+    pub community: Vec<Address>,
+    pub verified: Vec<Address>,
+    pub reviewer: Vec<Address>,
+}
+
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProposalStatus {
+    Active,
+    Approved,
+    Rejected,
+    Cancelled,
+    Malicious,
+    Vetoed,
+    Expired,
+}
@@ -0,0 +1,188 @@
+//! Validation of user inputs.
+
+use soroban_sdk::{Env, Map, String, panic_with_error};
+
+use crate::{errors, types};
+
+/// Validate the platform of an artifact, a target triple such as
+/// `x86_64-unknown-linux-gnu` or `wasm32v1-none`.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `platform` - The platform
+///
+/// # Panics
+/// * If the platform is empty or longer than `MAX_PLATFORM_LENGTH`
+/// * If the platform contains other characters than lowercase ASCII
+///   letters, digits, `-`, `_` and `.`
+pub fn validate_platform(env: &Env, platform: &String) {
+    let str_len = platform.len() as usize;
+    if str_len == 0 || str_len > types::MAX_PLATFORM_LENGTH as usize {
+        panic_with_error!(&env, &errors::ExtendedErrors::InvalidArtifact);
+    }
+
+    let mut slice = [0u8; types::MAX_PLATFORM_LENGTH as usize];
+    let platform_slice = &mut slice[..str_len];
+    platform.copy_into_slice(platform_slice);
+
+    if !platform_slice
+        .iter()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || b"-_.".contains(c))
+    {
+        panic_with_error!(&env, &errors::ExtendedErrors::InvalidArtifact);
+    }
+}
+
+/// Validate the toolchain of a release, e.g. `rustc` at `1.89.0`.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `toolchain` - The version of each build tool
+///
+/// # Panics
+/// * If the toolchain is empty or has more than `MAX_TOOLCHAIN_COMPONENTS`
+///   tools
+/// * If a tool name is empty, longer than `MAX_TOOLCHAIN_NAME_LENGTH` or
+///   contains other characters than lowercase ASCII letters, digits, `-`
+///   and `_`
+/// * If a version is empty, longer than `MAX_TOOLCHAIN_VERSION_LENGTH` or
+///   contains whitespace or non-printable characters
+pub fn validate_toolchain(env: &Env, toolchain: &Map<String, String>) {
+    if toolchain.is_empty() || toolchain.len() > types::MAX_TOOLCHAIN_COMPONENTS {
+        panic_with_error!(&env, &errors::ExtendedErrors::InvalidToolchain);
+    }
+
+    let mut slice = [0u8; types::MAX_TOOLCHAIN_VERSION_LENGTH as usize];
+    for (name, version) in toolchain.iter() {
+        let name_len = name.len() as usize;
+        let version_len = version.len() as usize;
+        if name_len == 0
+            || name_len > types::MAX_TOOLCHAIN_NAME_LENGTH as usize
+            || version_len == 0
+            || version_len > types::MAX_TOOLCHAIN_VERSION_LENGTH as usize
+        {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidToolchain);
+        }
+
+        let name_slice = &mut slice[..name_len];
+        name.copy_into_slice(name_slice);
+        if !name_slice
+            .iter()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || b"-_".contains(c))
+        {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidToolchain);
+        }
+
+        let version_slice = &mut slice[..version_len];
+        version.copy_into_slice(version_slice);
+        if !version_slice.iter().all(|c| c.is_ascii_graphic()) {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidToolchain);
+        }
+    }
+}
+
+/// Validate the id of a security advisory, e.g. `RUSTSEC-2025-0001`.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `id` - The advisory id
+///
+/// # Panics
+/// * If the id is empty or longer than `MAX_ADVISORY_ID_LENGTH`
+/// * If the id contains other characters than ASCII alphanumerics and `-`
+pub fn validate_advisory_id(env: &Env, id: &String) {
+    let str_len = id.len() as usize;
+    if str_len == 0 || str_len > types::MAX_ADVISORY_ID_LENGTH as usize {
+        panic_with_error!(&env, &errors::ExtendedErrors::InvalidAdvisory);
+    }
+
+    let mut slice = [0u8; types::MAX_ADVISORY_ID_LENGTH as usize];
+    let id_slice = &mut slice[..str_len];
+    id.copy_into_slice(id_slice);
+
+    if !id_slice
+        .iter()
+        .all(|c| c.is_ascii_alphanumeric() || *c == b'-')
+    {
+        panic_with_error!(&env, &errors::ExtendedErrors::InvalidAdvisory);
+    }
+}
+
+/// Validate a commit hash.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `hash` - The commit hash
+/// * `algorithm` - The object format of the repository
+///
+/// # Panics
+/// * If the hash is not a 40 (SHA-1) or 64 (SHA-256) characters
+///   hexadecimal string, depending on the algorithm
+pub fn validate_commit_hash(env: &Env, hash: &String, algorithm: types::HashAlgorithm) {
+    if !is_git_hash(hash, algorithm) {
+        panic_with_error!(&env, &errors::ExtendedErrors::InvalidCommitHash);
+    }
+}
+
+/// Whether a string is a git object hash of the algorithm in hexadecimal.
+fn is_git_hash(hash: &String, algorithm: types::HashAlgorithm) -> bool {
+    let str_len = hash.len() as usize;
+    let expected_len = match algorithm {
+        types::HashAlgorithm::Sha1 => 40,
+        types::HashAlgorithm::Sha256 => 64,
+    };
+    if str_len != expected_len {
+        return false;
+    }
+    let mut slice = [0u8; 64];
+    let hash_slice = &mut slice[..str_len];
+    hash.copy_into_slice(hash_slice);
+    hash_slice.iter().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Validate an IPFS CID.
+///
+/// CIDv0 are 46 base58btc characters starting with `Qm`. CIDv1 are
+/// multibase encoded, the first character being the base: `b` for
+/// lowercase base32, `k` for base36 or `z` for base58btc.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `cid` - The CID to validate
+///
+/// # Panics
+/// * If the CID is shorter than `MIN_CID_LENGTH` or longer than
+///   `MAX_CID_LENGTH`
+/// * If the multibase prefix is unknown or the CID contains characters
+///   outside of its base alphabet
+pub fn validate_cid(env: &Env, cid: &String) {
+    let str_len = cid.len() as usize;
+    if str_len < types::MIN_CID_LENGTH as usize || str_len > types::MAX_CID_LENGTH as usize {
+        panic_with_error!(&env, &errors::ExtendedErrors::InvalidCid);
+    }
+
+    let mut slice = [0u8; types::MAX_CID_LENGTH as usize];
+    cid.copy_into_slice(&mut slice[..str_len]);
+    let cid_b = &slice[..str_len];
+
+    let valid = match cid_b {
+        [b'Q', b'm', ..] if str_len == 46 => cid_b.iter().all(is_base58),
+        [b'b', encoded @ ..] => encoded
+            .iter()
+            .all(|c| c.is_ascii_lowercase() || (b'2'..=b'7').contains(c)),
+        [b'k', encoded @ ..] => encoded
+            .iter()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()),
+        [b'z', encoded @ ..] => encoded.iter().all(is_base58),
+        _ => false,
+    };
+    if !valid {
+        panic_with_error!(&env, &errors::ExtendedErrors::InvalidCid);
+    }
+}
+
+/// Whether a character is in the base58btc alphabet, which excludes `0`,
+/// `O`, `I` and `l`.
+fn is_base58(c: &u8) -> bool {
+    c.is_ascii_alphanumeric() && !matches!(c, b'0' | b'O' | b'I' | b'l')
+}
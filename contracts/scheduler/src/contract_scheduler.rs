@@ -0,0 +1,184 @@
+use soroban_sdk::{Address, Bytes, Env, Map, contractimpl, panic_with_error};
+
+use crate::{Scheduler, SchedulerArgs, SchedulerClient, SchedulerTrait, errors, events, types};
+
+#[contractimpl]
+impl SchedulerTrait for Scheduler {
+    /// Initialize the scheduler with the Tansu contract the projects are
+    /// registered with.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `tansu` - The address of the Tansu contract
+    fn __constructor(env: Env, tansu: Address) {
+        env.storage().instance().set(&types::DataKey::Tansu, &tansu);
+    }
+
+    /// Get the Tansu contract the projects are registered with.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    ///
+    /// # Returns
+    /// * `Address` - The address of the Tansu contract
+    fn get_tansu(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&types::DataKey::Tansu)
+            .unwrap()
+    }
+
+    /// Schedule the execution of an approved public proposal.
+    ///
+    /// From the given ledger on, anyone can execute the proposal with
+    /// `run_due`. Scheduling a proposal again replaces its ledger. Proposals
+    /// leave the queue once executed, revoked, cancelled or vetoed.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal
+    /// * `ledger` - The ledger sequence from which the proposal is executed
+    ///
+    /// # Panics
+    /// * If the maintainer could not execute the proposal, see `execute`
+    /// * If the proposal doesn't exist or is not active anymore
+    /// * If the votes are anonymous
+    /// * If the voting period hasn't ended
+    /// * If the votes do not approve the proposal
+    /// * If the queue of the project is full
+    fn schedule_execution(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        proposal_id: u32,
+        ledger: u32,
+    ) {
+        crate::tansu(&env).require_not_paused();
+        crate::auth_executor(&env, &maintainer, &project_key);
+
+        if crate::tansu(&env).get_outcome(&project_key, &proposal_id)
+            != types::ProposalStatus::Approved
+        {
+            panic_with_error!(&env, &errors::ExtendedErrors::ProposalNotApproved);
+        }
+
+        let mut queue = Self::get_schedule(env.clone(), project_key.clone());
+        if !queue.contains_key(proposal_id) && queue.len() >= types::MAX_SCHEDULED_PROPOSALS {
+            panic_with_error!(&env, &errors::ExtendedErrors::ScheduleFull);
+        }
+        queue.set(proposal_id, ledger);
+        store_queue(&env, &project_key, &queue);
+
+        events::ProposalScheduled {
+            project_key,
+            proposal_id,
+            ledger,
+        }
+        .publish(&env);
+    }
+
+    /// Execute the scheduled proposals of a project whose ledger is reached.
+    ///
+    /// Anyone can run the queue, proposals are executed by Tansu. Proposals
+    /// still in their veto period or waiting on their dependency stay
+    /// scheduled. Proposals which cannot be executed anymore, see
+    /// `run_scheduled` of Tansu, are unscheduled without stopping the run. Neither
+    /// does a failing outcomes contract, whose success is published with
+    /// `OutcomesContractInvoked`. A result is published for each proposal due.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Map<u32, types::ScheduledRun>` - The result of each proposal due
+    fn run_due(env: Env, project_key: Bytes) -> Map<u32, types::ScheduledRun> {
+        crate::tansu(&env).require_not_paused();
+
+        let mut queue = Self::get_schedule(env.clone(), project_key.clone());
+        let mut results = Map::new(&env);
+        for (proposal_id, ledger) in queue.clone().iter() {
+            if ledger > env.ledger().sequence() {
+                continue;
+            }
+
+            let result = crate::tansu(&env).run_scheduled(&project_key, &proposal_id);
+            if result != types::ScheduledRun::Pending {
+                queue.remove(proposal_id);
+            }
+            results.set(proposal_id, result.clone());
+
+            events::ScheduledProposalRun {
+                project_key: project_key.clone(),
+                proposal_id,
+                result,
+            }
+            .publish(&env);
+        }
+        store_queue(&env, &project_key, &queue);
+        results
+    }
+
+    /// Get the proposals of a project scheduled for execution.
+    ///
+    /// Proposals which are not active anymore are left out.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Map<u32, u32>` - The ledger from which each proposal is executed
+    fn get_schedule(env: Env, project_key: Bytes) -> Map<u32, u32> {
+        let queue: Map<u32, u32> = env
+            .storage()
+            .persistent()
+            .get(&types::SchedulerKey::Queue(project_key.clone()))
+            .unwrap_or(Map::new(&env));
+        let mut active = Map::new(&env);
+        for (proposal_id, ledger) in queue.iter() {
+            // revoked, cancelled and vetoed proposals leave the queue lazily
+            if let Ok(Ok(types::ProposalStatus::Active)) =
+                crate::tansu(&env).try_get_proposal_status(&project_key, &proposal_id)
+            {
+                active.set(proposal_id, ledger);
+            }
+        }
+        active
+    }
+
+    /// Remove the queue of a deregistered project.
+    ///
+    /// Only Tansu can call this function, when the project is deregistered.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Panics
+    /// * If Tansu did not authorize the call
+    fn remove_project(env: Env, project_key: Bytes) {
+        Self::get_tansu(env.clone()).require_auth();
+
+        env.storage()
+            .persistent()
+            .remove(&types::SchedulerKey::Queue(project_key));
+    }
+}
+
+/// Store the queue of a project, removed once empty.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `queue` - The ledger from which each proposal is executed
+fn store_queue(env: &Env, project_key: &Bytes, queue: &Map<u32, u32>) {
+    let queue_key = types::SchedulerKey::Queue(project_key.clone());
+    if queue.is_empty() {
+        env.storage().persistent().remove(&queue_key);
+    } else {
+        env.storage().persistent().set(&queue_key, queue);
+    }
+}
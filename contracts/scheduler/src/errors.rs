@@ -0,0 +1,25 @@
+//! Errors of the scheduler contract.
+//!
+//! Codes are shared with the Tansu contract so that clients handle the
+//! errors of both contracts alike. Only the errors raised by the scheduler
+//! are declared.
+
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractErrors {
+    // Project lifecycle
+    InsufficientRole = 32,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ExtendedErrors {
+    // Commits
+    ProposalNotApproved = 64,
+    // Scheduler
+    ScheduleFull = 97,
+}
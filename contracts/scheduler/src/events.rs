@@ -0,0 +1,25 @@
+//! Event definitions for the scheduler contract.
+
+use soroban_sdk::{Bytes, contractevent};
+
+use crate::types::ScheduledRun;
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalScheduled {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub proposal_id: u32,
+    pub ledger: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduledProposalRun {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub proposal_id: u32,
+    pub result: ScheduledRun,
+}
@@ -0,0 +1,67 @@
+#![no_std]
+
+use soroban_sdk::contractmeta;
+use soroban_sdk::{Address, Bytes, Env, Map, contract, panic_with_error};
+
+mod contract_scheduler;
+pub mod errors;
+mod events;
+mod tansu;
+pub mod types;
+
+contractmeta!(
+    key = "Description",
+    val = "Tansu - Scheduled execution of proposals"
+);
+
+#[contract]
+pub struct Scheduler;
+
+pub trait SchedulerTrait {
+    fn __constructor(env: Env, tansu: Address);
+
+    fn get_tansu(env: Env) -> Address;
+
+    fn schedule_execution(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        proposal_id: u32,
+        ledger: u32,
+    );
+
+    fn run_due(env: Env, project_key: Bytes) -> Map<u32, types::ScheduledRun>;
+
+    fn get_schedule(env: Env, project_key: Bytes) -> Map<u32, u32>;
+
+    fn remove_project(env: Env, project_key: Bytes);
+}
+
+/// Get a client of the Tansu contract the projects are registered with.
+fn tansu(env: &Env) -> tansu::TansuClient<'_> {
+    let address = <Scheduler as SchedulerTrait>::get_tansu(env.clone());
+    tansu::TansuClient::new(env, &address)
+}
+
+/// Authorize an address to execute the proposals of a project.
+///
+/// Anyone can execute the proposals of DAO controlled projects, only admins
+/// otherwise, as in Tansu.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `executor` - The address executing proposals
+/// * `project_key` - The project key identifier
+///
+/// # Panics
+/// * If the executor is not authorized
+/// * If the executor is not an admin of a project not controlled by its DAO
+fn auth_executor(env: &Env, executor: &Address, project_key: &Bytes) {
+    executor.require_auth();
+    let tansu = tansu(env);
+    if !tansu.is_dao_controlled(project_key)
+        && tansu.get_maintainer_role(project_key, executor) < types::MaintainerRole::Admin
+    {
+        panic_with_error!(&env, &errors::ContractErrors::InsufficientRole);
+    }
+}
@@ -0,0 +1,36 @@
+//! Tansu contract the projects are registered with.
+//!
+//! Proposals and their execution live in Tansu, the scheduler only holds
+//! the queue of the proposals scheduled for execution. Tansu executes the
+//! proposals due on behalf of the scheduler, see `run_scheduled`.
+
+use soroban_sdk::{Address, Bytes, Env, contractclient};
+
+use crate::types;
+
+/// Functions of the Tansu contract used by the scheduler.
+///
+/// The scheduler only uses the generated `TansuClient`.
+#[allow(dead_code)]
+#[contractclient(name = "TansuClient")]
+pub trait TansuInterface {
+    /// Panic if the contract is paused.
+    fn require_not_paused(env: Env);
+
+    /// Role of a maintainer, panics if the address is not a maintainer.
+    fn get_maintainer_role(env: Env, key: Bytes, maintainer: Address) -> types::MaintainerRole;
+
+    /// Whether a project is controlled by its DAO.
+    fn is_dao_controlled(env: Env, key: Bytes) -> bool;
+
+    /// Status of a proposal of the DAO of a project.
+    fn get_proposal_status(env: Env, project_key: Bytes, proposal_id: u32)
+    -> types::ProposalStatus;
+
+    /// Status a public proposal gets from its votes, panics if its vote is
+    /// not closed.
+    fn get_outcome(env: Env, project_key: Bytes, proposal_id: u32) -> types::ProposalStatus;
+
+    /// Execute a scheduled proposal, authorized by the scheduler.
+    fn run_scheduled(env: Env, project_key: Bytes, proposal_id: u32) -> types::ScheduledRun;
+}
@@ -0,0 +1,45 @@
+use soroban_sdk::{Bytes, contracttype};
+
+pub const MAX_SCHEDULED_PROPOSALS: u32 = 20;
+
+#[contracttype]
+pub enum DataKey {
+    Tansu, // Tansu contract the projects are registered with
+}
+
+#[contracttype]
+pub enum SchedulerKey {
+    Queue(Bytes), // ledger from which each scheduled proposal of a project can be executed
+}
+
+/// Result of a scheduled proposal due, see `run_due`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ScheduledRun {
+    Pending,                  // not ready, stays scheduled
+    Executed(ProposalStatus), // executed with this status
+    Failed(u32),              // code of the error preventing the execution, unscheduled
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProposalStatus {
+    Active,
+    Approved,
+    Rejected,
+    Cancelled,
+    Malicious,
+    Vetoed,
+    Expired, // approved but executed after the execution window, or anonymous tallies not revealed in time
+}
+
+// Types of the Tansu contract, see `tansu::TansuInterface`.
+
+/// Permissions of a maintainer on a project, each role includes the previous ones.
+#[contracttype(export = false)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum MaintainerRole {
+    Committer = 0, // can commit
+    Releaser = 1,  // can commit and release
+    Admin = 2,     // can also execute proposals
+}
@@ -0,0 +1,331 @@
+use soroban_sdk::{Address, Bytes, Env, contractimpl, panic_with_error, token};
+
+use crate::{Staking, StakingArgs, StakingClient, StakingTrait, errors, events, types};
+
+#[contractimpl]
+impl StakingTrait for Staking {
+    /// Initialize the staking contract with the Tansu contract the projects
+    /// are registered with.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `tansu` - The address of the Tansu contract
+    fn __constructor(env: Env, tansu: Address) {
+        env.storage().instance().set(&types::DataKey::Tansu, &tansu);
+    }
+
+    /// Get the Tansu contract the projects are registered with.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    ///
+    /// # Returns
+    /// * `Address` - The address of the Tansu contract
+    fn get_tansu(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&types::DataKey::Tansu)
+            .unwrap()
+    }
+
+    /// Lock tokens of the voting token of a project to gain voting weight.
+    ///
+    /// Staked tokens count as held by the member when proposals are created,
+    /// even for members without a badge, see `VotingWeight`. Members without
+    /// a badge only count their staked tokens, on the proposals created
+    /// after their last stake. A stake is at least one whole token.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `member` - The address staking tokens
+    /// * `project_key` - The project key identifier
+    /// * `amount` - The amount of tokens to stake
+    ///
+    /// # Panics
+    /// * If the project is archived or paused
+    /// * If the project has no voting token
+    /// * If the amount is not positive or the stake below one whole token
+    /// * If the member staked another token, or in a removed project, and
+    ///   did not withdraw it
+    fn stake(env: Env, member: Address, project_key: Bytes, amount: i128) {
+        crate::require_not_paused(&env);
+        crate::require_not_archived(&env, &project_key);
+        crate::require_project_not_paused(&env, &project_key);
+        member.require_auth();
+
+        let governance = crate::tansu(&env).get_governance_config(&project_key);
+        let Some(token_address) = voting_token(&governance.voting_weight) else {
+            panic_with_error!(&env, &errors::ExtendedErrors::StakingDisabled);
+        };
+        let token_client = token::TokenClient::new(&env, &token_address);
+        let epoch = epoch(&env, &project_key);
+        let mut stake = Self::get_stake(env.clone(), project_key.clone(), member.clone())
+            .unwrap_or(types::Stake {
+                token: token_address.clone(),
+                amount: 0,
+                unbonding: 0,
+                unbonding_ends_at: 0,
+                staked_at: 0,
+                epoch,
+            });
+        if amount <= 0 || stake.amount + amount < token_unit(&token_client) {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidAmount);
+        }
+        if stake.token != token_address || stake.epoch != epoch {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidStake);
+        }
+
+        token_client.transfer(&member, env.current_contract_address(), &amount);
+
+        stake.amount += amount;
+        stake.staked_at = env.ledger().timestamp();
+        store_stake(&env, &project_key, &member, &stake);
+        add_to_total(&env, &project_key, amount);
+
+        events::StakeChanged {
+            project_key,
+            member,
+            amount: stake.amount,
+            unbonding: stake.unbonding,
+        }
+        .publish(&env);
+    }
+
+    /// Start unbonding staked tokens.
+    ///
+    /// The tokens stop granting voting weight right away and can be
+    /// withdrawn once the unbonding period of the project has passed.
+    /// Unstaking again restarts the unbonding period of all unbonding tokens.
+    /// Tokens staked in a removed project can be withdrawn right away.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `member` - The address which staked tokens
+    /// * `project_key` - The project key identifier
+    /// * `amount` - The amount of tokens to unstake
+    ///
+    /// # Panics
+    /// * If the amount is not positive or exceeds the staked amount
+    /// * If the remaining stake is below one whole token
+    fn unstake(env: Env, member: Address, project_key: Bytes, amount: i128) {
+        crate::require_not_paused(&env);
+        member.require_auth();
+
+        let Some(mut stake) = Self::get_stake(env.clone(), project_key.clone(), member.clone())
+        else {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidStake);
+        };
+        let remaining = stake.amount - amount;
+        if amount <= 0
+            || remaining < 0
+            || (remaining > 0
+                && remaining < token_unit(&token::TokenClient::new(&env, &stake.token)))
+        {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidAmount);
+        }
+
+        stake.amount = remaining;
+        stake.unbonding += amount;
+        stake.unbonding_ends_at = if stake.epoch == epoch(&env, &project_key) {
+            let governance = crate::tansu(&env).get_governance_config(&project_key);
+            add_to_total(&env, &project_key, -amount);
+            env.ledger().timestamp() + governance.unbonding_period
+        } else {
+            env.ledger().timestamp()
+        };
+        store_stake(&env, &project_key, &member, &stake);
+
+        events::StakeChanged {
+            project_key,
+            member,
+            amount: stake.amount,
+            unbonding: stake.unbonding,
+        }
+        .publish(&env);
+    }
+
+    /// Withdraw the unbonded tokens of a member.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `member` - The address which unstaked tokens
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `i128` - The amount of tokens withdrawn
+    ///
+    /// # Panics
+    /// * If the member has no unbonding tokens
+    /// * If the unbonding period is not over
+    fn withdraw_stake(env: Env, member: Address, project_key: Bytes) -> i128 {
+        crate::require_not_paused(&env);
+        member.require_auth();
+
+        let Some(mut stake) = Self::get_stake(env.clone(), project_key.clone(), member.clone())
+        else {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidStake);
+        };
+        if stake.unbonding == 0 {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidStake);
+        }
+        if env.ledger().timestamp() < stake.unbonding_ends_at {
+            panic_with_error!(&env, &errors::ExtendedErrors::StakeUnbonding);
+        }
+
+        let amount = stake.unbonding;
+        stake.unbonding = 0;
+        store_stake(&env, &project_key, &member, &stake);
+
+        token::TokenClient::new(&env, &stake.token).transfer(
+            &env.current_contract_address(),
+            &member,
+            &amount,
+        );
+
+        events::StakeChanged {
+            project_key,
+            member,
+            amount: stake.amount,
+            unbonding: 0,
+        }
+        .publish(&env);
+
+        amount
+    }
+
+    /// Get the stake of a member in a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `member` - The address of the member
+    ///
+    /// # Returns
+    /// * `Option<types::Stake>` - The stake of the member, if any
+    fn get_stake(env: Env, project_key: Bytes, member: Address) -> Option<types::Stake> {
+        env.storage()
+            .persistent()
+            .get(&types::StakeKey::Stake(project_key, member))
+    }
+
+    /// Get the tokens staked in a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `i128` - The staked tokens, unbonding tokens excluded
+    fn get_total_stake(env: Env, project_key: Bytes) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&types::StakeKey::Total(project_key))
+            .unwrap_or(0)
+    }
+
+    /// Get the tokens of a member staked in the voting token of a project.
+    ///
+    /// Read by Tansu when weighing the votes of the member.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `member` - The address of the member
+    /// * `token` - The voting token of the project
+    /// * `since` - Only count stakes made at or before this UNIX timestamp
+    ///
+    /// # Returns
+    /// * `i128` - The staked tokens, 0 if staked later, in another token or
+    ///   in a removed project
+    fn get_voting_stake(
+        env: Env,
+        project_key: Bytes,
+        member: Address,
+        token: Address,
+        since: u64,
+    ) -> i128 {
+        match Self::get_stake(env.clone(), project_key.clone(), member) {
+            Some(stake)
+                if stake.token == token
+                    && stake.epoch == epoch(&env, &project_key)
+                    && stake.staked_at <= since =>
+            {
+                stake.amount
+            }
+            _ => 0,
+        }
+    }
+
+    /// Release the stakes of a removed project, authorized by Tansu.
+    ///
+    /// Stakes are stored per member and cannot be listed, so they stop
+    /// granting weight and members withdraw them without unbonding, see
+    /// `unstake`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Panics
+    /// * If the call is not authorized by Tansu
+    fn remove_project(env: Env, project_key: Bytes) {
+        Self::get_tansu(env.clone()).require_auth();
+
+        env.storage()
+            .persistent()
+            .remove(&types::StakeKey::Total(project_key.clone()));
+        env.storage().persistent().set(
+            &types::StakeKey::Epoch(project_key.clone()),
+            &(epoch(&env, &project_key) + 1),
+        );
+    }
+}
+
+/// Voting token of a project, if any.
+///
+/// # Arguments
+/// * `voting_weight` - Source of the voting weight of the project
+///
+/// # Returns
+/// * `Option<Address>` - The token contract whose balances grant voting weight
+fn voting_token(voting_weight: &types::VotingWeight) -> Option<Address> {
+    match voting_weight {
+        types::VotingWeight::Badges => None,
+        types::VotingWeight::Token(token_address)
+        | types::VotingWeight::BadgesAndToken(token_address) => Some(token_address.clone()),
+    }
+}
+
+/// Amount of a whole token.
+fn token_unit(token_client: &token::TokenClient) -> i128 {
+    10i128
+        .checked_pow(token_client.decimals())
+        .unwrap_or(i128::MAX)
+}
+
+/// Registration of a project key, incremented when the project is removed.
+fn epoch(env: &Env, project_key: &Bytes) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&types::StakeKey::Epoch(project_key.clone()))
+        .unwrap_or(0)
+}
+
+fn store_stake(env: &Env, project_key: &Bytes, member: &Address, stake: &types::Stake) {
+    let stake_key = types::StakeKey::Stake(project_key.clone(), member.clone());
+    if stake.amount == 0 && stake.unbonding == 0 {
+        env.storage().persistent().remove(&stake_key);
+    } else {
+        env.storage().persistent().set(&stake_key, stake);
+    }
+}
+
+fn add_to_total(env: &Env, project_key: &Bytes, amount: i128) {
+    let total_key = types::StakeKey::Total(project_key.clone());
+    let total = Staking::get_total_stake(env.clone(), project_key.clone()) + amount;
+    if total == 0 {
+        env.storage().persistent().remove(&total_key);
+    } else {
+        env.storage().persistent().set(&total_key, &total);
+    }
+}
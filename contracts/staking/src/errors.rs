@@ -0,0 +1,29 @@
+//! Errors of the staking contract.
+//!
+//! Codes are shared with the Tansu contract so that clients handle the
+//! errors of both contracts alike. Only the errors raised by the staking
+//! contract are declared.
+
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContractErrors {
+    // Project lifecycle
+    ProjectArchived = 26,
+    // Incident response
+    ProjectPaused = 40,
+}
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ExtendedErrors {
+    // Treasury
+    InvalidAmount = 74,
+    // Staking
+    StakingDisabled = 92,
+    InvalidStake = 93,
+    StakeUnbonding = 94,
+}
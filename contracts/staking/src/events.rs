@@ -0,0 +1,14 @@
+//! Event definitions for the staking contract.
+
+use soroban_sdk::{Address, Bytes, contractevent};
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakeChanged {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub member: Address,
+    pub amount: i128,
+    pub unbonding: i128,
+}
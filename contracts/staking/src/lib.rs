@@ -0,0 +1,83 @@
+#![no_std]
+
+use soroban_sdk::contractmeta;
+use soroban_sdk::{Address, Bytes, Env, contract, panic_with_error};
+
+mod contract_staking;
+pub mod errors;
+mod events;
+mod tansu;
+pub mod types;
+
+contractmeta!(
+    key = "Description",
+    val = "Tansu - Staking of voting tokens"
+);
+
+#[contract]
+pub struct Staking;
+
+pub trait StakingTrait {
+    fn __constructor(env: Env, tansu: Address);
+
+    fn get_tansu(env: Env) -> Address;
+
+    fn stake(env: Env, member: Address, project_key: Bytes, amount: i128);
+
+    fn unstake(env: Env, member: Address, project_key: Bytes, amount: i128);
+
+    fn withdraw_stake(env: Env, member: Address, project_key: Bytes) -> i128;
+
+    fn get_stake(env: Env, project_key: Bytes, member: Address) -> Option<types::Stake>;
+
+    fn get_total_stake(env: Env, project_key: Bytes) -> i128;
+
+    fn get_voting_stake(
+        env: Env,
+        project_key: Bytes,
+        member: Address,
+        token: Address,
+        since: u64,
+    ) -> i128;
+
+    fn remove_project(env: Env, project_key: Bytes);
+}
+
+/// Get a client of the Tansu contract the projects are registered with.
+fn tansu(env: &Env) -> tansu::TansuClient<'_> {
+    let address = <Staking as StakingTrait>::get_tansu(env.clone());
+    tansu::TansuClient::new(env, &address)
+}
+
+/// Require that the Tansu contract is not paused, panic if it is.
+fn require_not_paused(env: &Env) {
+    tansu(env).require_not_paused();
+}
+
+/// Require that the project is not archived, panic if it is.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+///
+/// # Panics
+/// * If the project is archived
+fn require_not_archived(env: &Env, project_key: &Bytes) {
+    if tansu(env).is_archived(project_key) {
+        panic_with_error!(&env, &errors::ContractErrors::ProjectArchived);
+    }
+}
+
+/// Require that the project is not paused, panic if it is.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+///
+/// # Panics
+/// * If the project is paused
+fn require_project_not_paused(env: &Env, project_key: &Bytes) {
+    if tansu(env).is_project_paused(project_key) {
+        panic_with_error!(&env, &errors::ContractErrors::ProjectPaused);
+    }
+}
@@ -0,0 +1,28 @@
+//! Tansu contract the projects are registered with.
+//!
+//! Projects and their governance configuration live in Tansu, the staking
+//! contract only holds the staked tokens. Tansu reads the stakes when
+//! weighing the votes.
+
+use soroban_sdk::{Bytes, Env, contractclient};
+
+use crate::types;
+
+/// Functions of the Tansu contract used by the staking contract.
+///
+/// The staking contract only uses the generated `TansuClient`.
+#[allow(dead_code)]
+#[contractclient(name = "TansuClient")]
+pub trait TansuInterface {
+    /// Panic if the contract is paused.
+    fn require_not_paused(env: Env);
+
+    /// Whether a project is archived.
+    fn is_archived(env: Env, key: Bytes) -> bool;
+
+    /// Whether a project is paused.
+    fn is_project_paused(env: Env, key: Bytes) -> bool;
+
+    /// Voting rules of the DAO of a project.
+    fn get_governance_config(env: Env, project_key: Bytes) -> types::GovernanceConfig;
+}
@@ -0,0 +1,78 @@
+use soroban_sdk::{Address, Bytes, Map, contracttype};
+
+#[contracttype]
+pub enum DataKey {
+    Tansu, // Tansu contract the projects are registered with
+}
+
+#[contracttype]
+pub enum StakeKey {
+    Stake(Bytes, Address), // tokens locked by a member to gain voting weight
+    Total(Bytes),          // tokens staked in a project, unbonding tokens excluded
+    Epoch(Bytes), // registration of a project key, stakes of removed projects grant no weight
+}
+
+/// Tokens locked by a member to gain voting weight, see `stake`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stake {
+    pub token: Address,
+    pub amount: i128,           // staked tokens granting voting weight
+    pub unbonding: i128,        // unstaked tokens not withdrawn yet
+    pub unbonding_ends_at: u64, // UNIX timestamp from which unbonding tokens can be withdrawn
+    pub staked_at: u64,         // UNIX timestamp of the last stake, later proposals count it
+    pub epoch: u32,             // registration of the project the tokens are staked in
+}
+
+// Types of the Tansu contract, see `tansu::TansuInterface`.
+
+/// Kind of a proposal, deciding what is applied when it is approved.
+#[contracttype(export = false)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProposalKind {
+    Text,             // advisory, nothing is applied
+    ConfigChange,     // configuration, governance rules, license or badges
+    Treasury,         // spending from the treasury of the project
+    Upgrade,          // new commit or release promotion
+    MaintainerChange, // maintainers of a DAO controlled project
+    ContractCall,     // call of another contract
+}
+
+/// Source of the voting weight of the members of a project.
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VotingWeight {
+    Badges,
+    Token(Address),          // whole tokens held, badges only grant membership
+    BadgesAndToken(Address), // sum of the badges and of the whole tokens held
+}
+
+#[contracttype(export = false)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct GovernanceConfig {
+    pub min_voting_period: u64, // in seconds
+    pub max_voting_period: u64, // in seconds
+    pub max_title_length: u32,
+    pub quorum: u32, // minimal total weight cast, otherwise the proposal is cancelled
+    pub approval_thresholds: Map<ProposalKind, u32>, // share of the weight cast in basis points, simple majority if not set
+    pub abstain_in_quorum: bool,                     // abstain weight counts toward the quorum
+    pub abstain_in_approval: bool,                   // abstain weight counts in the approval ratio
+    pub veto_period: u64, // in seconds after the vote, maintainers can veto approved proposals, 0 to disable
+    pub proposal_deposit: i128, // bond of proposers in collateral asset, 0 to disable
+    pub spam_quorum_bps: u32, // share of the quorum in basis points under which the deposit is slashed
+    pub proposal_cooldown: u32, // ledgers between two proposals of an address, 0 to disable
+    pub quadratic_voting: bool, // public votes weigh the square root of their weight
+    pub conviction_half_life: u32, // ledgers for conviction to move halfway to the support, 0 to disable
+    pub conviction_min_support_bps: u32, // share of the total weight funding a negligible amount
+    pub voting_weight: VotingWeight, // read for each member holding a badge when a proposal is created
+    pub unbonding_period: u64,       // in seconds before unstaked tokens can be withdrawn
+    pub reputation_half_life: u32,   // ledgers for reputation to halve, 0 to disable
+    pub reputation_weight: u32, // voting weight per reputation point, 0 to not count reputation
+    pub execution_window: u64, // in seconds after the vote and veto period to execute, 0 to disable
+    pub emergency_voting_period: u64, // minimal voting period of emergency proposals in seconds, 0 to disable
+    pub emergency_threshold: u32,     // approval share of emergency proposals in basis points
+    pub sponsors_required: u32, // badge holders sponsoring a proposal before it opens for voting, 0 to disable
+    pub max_voter_weight_bps: u32, // share of the eligible weight each voter or delegator casts at most, 0 to disable
+    pub reveal_period: u64, // in seconds after the vote to reveal hashed public votes, 0 to vote directly
+    pub tally_reveal_period: u64, // in seconds after the vote to reveal anonymous tallies, 0 for no deadline
+}
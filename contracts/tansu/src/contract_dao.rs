@@ -1,35 +1,59 @@
 use crate::{
-    DaoTrait, MembershipTrait, Tansu, TansuArgs, TansuClient, TansuTrait, errors, events,
-    outcomes_contract, types,
+    DaoTrait, MembershipTrait, Tansu, TansuArgs, TansuClient, TansuTrait, VersioningTrait,
+    contract_membership, errors, events, governance, outcomes_contract, registry, semver, types,
+    validation,
 };
 use soroban_sdk::crypto::bls12_381::G1Affine;
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, String, U256, Vec, contractimpl, panic_with_error, token, vec,
+    Address, Bytes, BytesN, Env, InvokeError, Map, String, Symbol, U256, Val, Vec, contractimpl,
+    panic_with_error, token, vec,
 };
 
 const PROPOSAL_COLLATERAL: i128 = 100 * 10_000_000;
-const VOTE_COLLATERAL: i128 = 10 * 10_000_000;
-const MAX_TITLE_LENGTH: u32 = 256;
-const MAX_PROPOSALS_PER_PAGE: u32 = 9;
+pub const VOTE_COLLATERAL: i128 = 10 * 10_000_000;
+pub const MIN_TITLE_LENGTH: u32 = 10;
+pub const MAX_TITLE_LENGTH: u32 = 256;
+pub const MAX_PROPOSALS_PER_PAGE: u32 = 9;
 const MAX_PAGES: u32 = 1000;
 const MIN_VOTING_PERIOD: u64 = 24 * 3600; // 1 day in seconds
 const MAX_VOTING_PERIOD: u64 = 30 * 24 * 3600; // 30 days in seconds
-const MAX_VOTES_PER_PROPOSAL: u32 = 1000; // DoS protection
+const MAX_GOVERNANCE_VOTING_PERIOD: u64 = 365 * 24 * 3600; // 1 year in seconds
+const MAX_GOVERNANCE_TITLE_LENGTH: u32 = 1024;
+const MAX_SPONSORS_REQUIRED: u32 = 10;
+pub const MAX_VOTES_PER_PROPOSAL: u32 = 1000; // DoS protection
+const TOTAL_WEIGHT_BPS: u32 = 10_000;
+const MAX_INVOKE_ARGS: u32 = 10;
+const SIMPLE_MAJORITY_BPS: u32 = 5_000; // approve needs more than half of the weight cast
+const MAX_DELEGATIONS: u32 = 100;
+const CANCELLATION_GRACE_PERIOD: u64 = 3600; // 1 hour in seconds
+const MAX_VETO_REASON_LENGTH: u32 = 256;
+const AMENDMENT_WINDOW: u64 = 3600; // 1 hour in seconds
+const MAX_REVISIONS: u32 = 10;
+const MAX_TEMPLATES: u32 = 10;
+const MIN_TEMPLATE_INTERVAL: u64 = 24 * 3600; // 1 day in seconds
+const MAX_OPTIONS: u32 = 10;
+const MAX_OPTION_LABEL_LENGTH: u32 = 64;
+const ANONYMOUS_KEY_ACTIVATION_DELAY: u64 = 24 * 3600; // 1 day in seconds
+const MAX_ANONYMOUS_KEYS: u32 = 20;
 
 #[contractimpl]
 impl DaoTrait for Tansu {
     /// Setup anonymous voting for a project.
     ///
     /// Configures BLS12-381 cryptographic primitives for anonymous voting.
-    /// Only the contract admin can call this function.
+    /// Any maintainer of the project can set it up once, the public key is
+    /// active right away. Later changes of the key are approved by the
+    /// maintainers with `rotate_anonymous_key`.
     ///
     /// # Arguments
     /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer
     /// * `project_key` - Unique identifier for the project
     /// * `public_key` - Asymmetric public key to be used for vote encryption
     ///
     /// # Panics
-    /// * If the caller is not the contract admin
+    /// * If the caller is not a maintainer of the project
+    /// * If anonymous voting is already set up for the project
     fn anonymous_voting_setup(
         env: Env,
         maintainer: Address,
@@ -37,6 +61,13 @@ impl DaoTrait for Tansu {
         public_key: String,
     ) {
         crate::auth_maintainers(&env, &maintainer, &project_key);
+        if env
+            .storage()
+            .instance()
+            .has(&types::ProjectKey::AnonymousVoteConfig(project_key.clone()))
+        {
+            panic_with_error!(&env, &errors::AdditionalErrors::AnonymousVotingAlreadySetup);
+        }
 
         // generators
         let bls12_381 = env.crypto().bls12_381();
@@ -55,10 +86,22 @@ impl DaoTrait for Tansu {
             public_key: public_key.clone(),
         };
 
+        let history = vec![
+            &env,
+            types::AnonymousKey {
+                public_key: public_key.clone(),
+                active_from: env.ledger().timestamp(),
+            },
+        ];
+
         env.storage().instance().set(
             &types::ProjectKey::AnonymousVoteConfig(project_key.clone()),
             &vote_config,
         );
+        env.storage().persistent().set(
+            &types::AnonymousKeyKey::History(project_key.clone()),
+            &history,
+        );
 
         // Emit event for anonymous voting setup
         events::AnonymousVotingSetup {
@@ -71,6 +114,8 @@ impl DaoTrait for Tansu {
 
     /// Get the anonymous voting configuration for a project.
     ///
+    /// The public key is the one currently active, see `rotate_anonymous_key`.
+    ///
     /// # Arguments
     /// * `env` - The environment object
     /// * `project_key` - The project key identifier
@@ -81,14 +126,143 @@ impl DaoTrait for Tansu {
     /// # Panics
     /// * If no anonymous voting configuration exists for the project
     fn get_anonymous_voting_config(env: Env, project_key: Bytes) -> types::AnonymousVoteConfig {
-        env.storage()
+        let mut vote_config = env
+            .storage()
             .instance()
             .get::<types::ProjectKey, types::AnonymousVoteConfig>(
-                &types::ProjectKey::AnonymousVoteConfig(project_key),
+                &types::ProjectKey::AnonymousVoteConfig(project_key.clone()),
             )
             .unwrap_or_else(|| {
                 panic_with_error!(&env, &errors::ContractErrors::NoAnonymousVotingConfig);
-            })
+            });
+
+        let curr_timestamp = env.ledger().timestamp();
+        if let Some(key) = Self::get_anonymous_key_history(env, project_key)
+            .iter()
+            .rev()
+            .find(|key| key.active_from <= curr_timestamp)
+        {
+            vote_config.public_key = key.public_key;
+        }
+        vote_config
+    }
+
+    /// Replace the public key encrypting the anonymous votes of a project.
+    ///
+    /// Each maintainer calls this function to approve the rotation. Once the
+    /// maintainers threshold is reached, the new key becomes active after a
+    /// delay of one day, leaving time to voters to notice the rotation.
+    /// Rotating again before the activation replaces the pending key. The
+    /// generator points are kept so that proposals voted with the previous
+    /// key can still be tallied. The history keeps the last keys with their
+    /// activation time, and always the keys of active proposals, see
+    /// `get_proposal_anonymous_key`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer
+    /// * `project_key` - The project key identifier
+    /// * `public_key` - Asymmetric public key to be used for vote encryption
+    ///
+    /// # Returns
+    /// * `Option<u64>` - UNIX timestamp from which the key is active, `None`
+    ///   until the maintainers threshold is reached
+    ///
+    /// # Panics
+    /// * If the caller is not an admin maintainer of the project
+    /// * If no anonymous voting configuration exists for the project
+    /// * If the history is full of keys of active proposals
+    fn rotate_anonymous_key(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        public_key: String,
+    ) -> Option<u64> {
+        Tansu::require_not_paused(env.clone());
+        let project = crate::auth_maintainers(&env, &maintainer, &project_key);
+        Self::get_anonymous_voting_config(env.clone(), project_key.clone());
+
+        let action = types::MaintainersAction::RotateAnonymousKey(public_key.clone());
+        if !crate::approve_maintainers_action(&env, &maintainer, &project_key, &project, action) {
+            return None;
+        }
+
+        let mut history = anonymous_keys(&env, &project_key);
+        let curr_timestamp = env.ledger().timestamp();
+        if let Some(last) = history.last()
+            && last.active_from > curr_timestamp
+        {
+            history.pop_back();
+        }
+        let active_from = curr_timestamp + ANONYMOUS_KEY_ACTIVATION_DELAY;
+        history.push_back(types::AnonymousKey {
+            public_key: public_key.clone(),
+            active_from,
+        });
+        // the active and the new keys are never evicted
+        while history.len() > MAX_ANONYMOUS_KEYS {
+            let Some(index) = (0..history.len() - 2).find(|index| {
+                !is_key_referenced(
+                    &env,
+                    &project_key,
+                    &history.get_unchecked(*index).public_key,
+                )
+            }) else {
+                panic_with_error!(&env, &errors::AdditionalErrors::AnonymousKeysInUse);
+            };
+            history.remove(index);
+        }
+        env.storage().persistent().set(
+            &types::AnonymousKeyKey::History(project_key.clone()),
+            &history,
+        );
+
+        events::AnonymousKeyRotated {
+            project_key,
+            maintainer,
+            public_key,
+            active_from,
+        }
+        .publish(&env);
+
+        Some(active_from)
+    }
+
+    /// Get the public keys which encrypted the anonymous votes of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Vec<types::AnonymousKey>` - The keys with their activation time,
+    ///   oldest first. Empty for projects set up before keys were recorded
+    ///   and which never rotated their key
+    fn get_anonymous_key_history(env: Env, project_key: Bytes) -> Vec<types::AnonymousKey> {
+        env.storage()
+            .persistent()
+            .get(&types::AnonymousKeyKey::History(project_key))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get the public key which encrypts the anonymous votes of a proposal.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal
+    ///
+    /// # Returns
+    /// * `Option<String>` - The key active when the proposal was created,
+    ///   `None` for public proposals
+    fn get_proposal_anonymous_key(
+        env: Env,
+        project_key: Bytes,
+        proposal_id: u32,
+    ) -> Option<String> {
+        env.storage()
+            .persistent()
+            .get(&types::AnonymousKeyKey::Proposal(project_key, proposal_id))
     }
 
     /// Build vote commitments from votes and seeds for anonymous voting.
@@ -96,6 +270,12 @@ impl DaoTrait for Tansu {
     /// Creates BLS12-381 commitments for each vote using the formula:
     /// C = g^vote * h^seed where g and h are generator points.
     ///
+    /// These are Pedersen commitments: g and h are hashed to the curve so that
+    /// their discrete log relation is unknown, and commitments are additively
+    /// homomorphic. Summing the weighted commitments of all votes yields a
+    /// commitment to the tallies, which `proof` checks against the decoded
+    /// tallies and seeds without opening individual votes.
+    ///
     /// Note: This function does not consider voting weights, which are applied
     /// during the tallying phase. Calling this on the smart contract would reveal
     /// the votes and seeds, so it must be run either in simulation or client-side.
@@ -104,7 +284,8 @@ impl DaoTrait for Tansu {
     /// * `env` - The environment object
     /// * `project_key` - Unique identifier for the project
     /// * `votes` - Vector of vote choices (0=abstain, 1=approve, 2=reject)
-    /// * `seeds` - Vector of random seeds for each vote
+    /// * `seeds` - Vector of random seeds for each vote, scalars of the
+    ///   BLS12-381 field reduced modulo its order
     ///
     /// # Returns
     /// * `Vec<BytesN<96>>` - Vector of vote commitments (one per vote)
@@ -115,7 +296,7 @@ impl DaoTrait for Tansu {
         env: Env,
         project_key: Bytes,
         votes: Vec<u128>,
-        seeds: Vec<u128>,
+        seeds: Vec<U256>,
     ) -> Vec<BytesN<96>> {
         // Validate that votes and seeds have the same length
         if votes.len() != seeds.len() {
@@ -131,7 +312,6 @@ impl DaoTrait for Tansu {
         let mut commitments = Vec::new(&env);
         for (vote_, seed_) in votes.iter().zip(seeds.iter()) {
             let vote_: U256 = U256::from_u128(&env, vote_);
-            let seed_: U256 = U256::from_u128(&env, seed_);
             let seed_point_ = bls12_381.g1_mul(&seed_generator_point, &seed_.into());
             let vote_point_ = bls12_381.g1_mul(&vote_generator_point, &vote_.into());
 
@@ -145,7 +325,8 @@ impl DaoTrait for Tansu {
     /// The proposer is automatically added to the abstain group.
     /// By creating a proposal, the proposer incur a collateral which is
     /// repaid upon execution of the proposal unless the proposal is revoked.
-    /// This is a deterrent mechanism.
+    /// This is a deterrent mechanism. Projects can also require a deposit
+    /// which is slashed to their treasury if the proposal is spam.
     ///
     /// # Arguments
     /// * `env` - The environment object
@@ -161,9 +342,13 @@ impl DaoTrait for Tansu {
     /// * `u32` - The ID of the created proposal.
     ///
     /// # Panics
-    /// * If the title is too long
-    /// * If the voting period is invalid
+    /// * If the title is too long for the governance configuration
+    /// * If the voting period is invalid for the governance configuration
+    /// * If the IPFS CID is malformed
     /// * If the project doesn't exist
+    /// * If the project is archived
+    /// * If the project is paused
+    /// * If the proposer created a proposal less than the cooldown ago
     #[allow(clippy::too_many_arguments)]
     fn create_proposal(
         env: Env,
@@ -175,163 +360,359 @@ impl DaoTrait for Tansu {
         public_voting: bool,
         outcomes_contract: Option<Address>,
     ) -> u32 {
-        Tansu::require_not_paused(env.clone());
+        create(
+            &env,
+            proposer,
+            project_key,
+            title,
+            ipfs,
+            voting_ends_at,
+            public_voting,
+            outcomes_contract,
+            types::ProposalKind::Text,
+            Vec::new(&env),
+            None,
+        )
+    }
 
-        // Some input validations
-        let curr_timestamp = env.ledger().timestamp();
-        let min_voting_timestamp = curr_timestamp + MIN_VOTING_PERIOD;
-        let max_voting_timestamp = curr_timestamp + MAX_VOTING_PERIOD;
-        let ipfs_len = ipfs.len();
-        let title_len = title.len();
-
-        if !((min_voting_timestamp..=max_voting_timestamp).contains(&voting_ends_at)
-            && (10..=MAX_TITLE_LENGTH).contains(&title_len)
-            && (32..=64).contains(&ipfs_len))
+    /// Create a multiple-choice proposal for a project.
+    ///
+    /// Members vote for one of the labeled options, or abstain, and the
+    /// option with the most weight wins. Voting is public.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `proposer` - Address of the proposal creator
+    /// * `project_key` - Unique identifier for the project
+    /// * `title` - Title of the proposal
+    /// * `ipfs` - IPFS content identifier describing the proposal
+    /// * `voting_ends_at` - UNIX timestamp when voting ends
+    /// * `options` - Labels of the options
+    ///
+    /// # Returns
+    /// * `u32` - The ID of the created proposal.
+    ///
+    /// # Panics
+    /// * If there are less than two or too many options
+    /// * If a label is empty, too long or duplicated
+    /// * If the proposal cannot be created, see `create_proposal`
+    fn create_multiple_choice_proposal(
+        env: Env,
+        proposer: Address,
+        project_key: Bytes,
+        title: String,
+        ipfs: String,
+        voting_ends_at: u64,
+        options: Vec<String>,
+    ) -> u32 {
+        if !(2..=MAX_OPTIONS).contains(&options.len())
+            || options.iter().enumerate().any(|(index, label)| {
+                !(1..=MAX_OPTION_LABEL_LENGTH).contains(&label.len())
+                    || options.first_index_of(&label) != Some(index as u32)
+            })
         {
             panic_with_error!(&env, &errors::ContractErrors::ProposalInputValidation);
         }
 
-        // proposers deposit a collateral
-        proposer.require_auth();
-        let sac_contract = crate::retrieve_contract(&env, types::ContractKey::CollateralContract);
-        let token_stellar = token::StellarAssetClient::new(&env, &sac_contract.address);
-        token_stellar.transfer(
-            &proposer.clone(),
-            env.current_contract_address(),
-            &(PROPOSAL_COLLATERAL + VOTE_COLLATERAL),
-        );
-
-        let proposal_id = env
-            .storage()
-            .persistent()
-            .get(&types::ProjectKey::DaoTotalProposals(project_key.clone()))
-            .unwrap_or(0);
-
-        // proposer is automatically in the abstain group
-        // use the first level to not block a vote from proposer with
-        // a very high level of trust
-        let abstain_weight = types::Badge::Verified as u32;
-        let vote_ = match public_voting {
-            true => types::Vote::PublicVote(types::PublicVote {
-                address: proposer.clone(),
-                weight: abstain_weight,
-                vote_choice: types::VoteChoice::Abstain,
-            }),
-            false => types::Vote::AnonymousVote(types::AnonymousVote {
-                address: proposer.clone(),
-                weight: abstain_weight,
-                encrypted_seeds: vec![
-                    &env,
-                    String::from_str(&env, "0"),
-                    String::from_str(&env, "0"),
-                    String::from_str(&env, "0"),
-                ],
-                encrypted_votes: vec![
-                    &env,
-                    String::from_str(&env, "0"),
-                    String::from_str(&env, "0"),
-                    String::from_str(&env, "1"),
-                ],
-                commitments: Self::build_commitments_from_votes(
-                    env.clone(),
-                    project_key.clone(),
-                    vec![&env, 0u128, 0u128, 1u128],
-                    vec![&env, 0u128, 0u128, 0u128],
-                ),
-            }),
-        };
-
-        let votes = vec![&env, vote_];
-        let vote_data = types::VoteData {
-            voting_ends_at,
-            public_voting,
-            votes,
-        };
-        let proposal = types::Proposal {
-            id: proposal_id,
+        create(
+            &env,
+            proposer,
+            project_key,
             title,
-            proposer: proposer.clone(),
             ipfs,
-            vote_data,
-            status: types::ProposalStatus::Active,
-            outcomes_contract,
-        };
+            voting_ends_at,
+            true,
+            None,
+            types::ProposalKind::Text,
+            options,
+            None,
+        )
+    }
 
-        let next_id = proposal_id + 1;
-        let page = proposal_id / MAX_PROPOSALS_PER_PAGE;
+    /// Create a proposal applying a change to a project.
+    ///
+    /// The action is applied when the proposal is executed and approved, the
+    /// kind of the proposal follows from the action. Governance
+    /// configuration and license changes can be proposed for any project,
+    /// other actions only for projects controlled by their DAO. Treasury
+    /// transfers and council elections also need the project to require a
+    /// quorum.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `proposer` - Address of the proposal creator
+    /// * `project_key` - Unique identifier for the project
+    /// * `title` - Title of the proposal
+    /// * `ipfs` - IPFS content identifier describing the proposal
+    /// * `voting_ends_at` - UNIX timestamp when voting ends
+    /// * `public_voting` - Whether voting is public or anonymous
+    /// * `action` - The change to apply if the proposal is approved
+    ///
+    /// # Returns
+    /// * `u32` - The ID of the created proposal.
+    ///
+    /// # Panics
+    /// * If the action requires the project to be controlled by its DAO
+    /// * If the action spends the treasury and the project requires no quorum
+    /// * If the governance configuration is invalid
+    /// * If the license identifier is invalid
+    /// * If the CID of a new configuration is malformed
+    /// * If a new release or the reason of a yank is invalid
+    /// * If a transfer or reward amount is not positive
+    /// * If a reward targets a proposal which is not active
+    /// * If new maintainers are invalid
+    /// * If a call targets a function not allowed by the admins
+    /// * If the proposal cannot be created
+    #[allow(clippy::too_many_arguments)]
+    fn create_action_proposal(
+        env: Env,
+        proposer: Address,
+        project_key: Bytes,
+        title: String,
+        ipfs: String,
+        voting_ends_at: u64,
+        public_voting: bool,
+        action: types::DaoAction,
+    ) -> u32 {
+        validate_action(&env, &project_key, &action);
 
-        // Prevent exceeding maximum page limit
-        if page >= MAX_PAGES {
-            panic_with_error!(&env, &errors::ContractErrors::NoProposalorPageFound);
-        }
+        let proposal_id = create(
+            &env,
+            proposer,
+            project_key.clone(),
+            title,
+            ipfs,
+            voting_ends_at,
+            public_voting,
+            None,
+            proposal_kind(&action),
+            Vec::new(&env),
+            None,
+        );
 
         env.storage().persistent().set(
-            &types::ProjectKey::DaoTotalProposals(project_key.clone()),
-            &next_id,
+            &types::ProjectKey::ProposalAction(project_key, proposal_id),
+            &action,
         );
 
-        let mut dao_page = Self::get_dao(env.clone(), project_key.clone(), page);
-        dao_page.proposals.push_back(proposal.clone());
+        proposal_id
+    }
 
-        env.storage().persistent().set(
-            &types::ProjectKey::Dao(project_key.clone(), page),
-            &dao_page,
-        );
+    /// Create an emergency proposal for a project.
+    ///
+    /// Emergency proposals handle security incidents, like yanking a
+    /// compromised release. Their voting period can be as short as the
+    /// emergency voting period of the project, but they need a maintainer
+    /// other than the proposer to co-sign them and the approve weight must
+    /// exceed the emergency threshold. Voting is public. Only incident
+    /// actions can be applied, see `is_incident_action`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `proposer` - Address of the proposal creator
+    /// * `cosigner` - Address of the maintainer co-signing the proposal
+    /// * `project_key` - Unique identifier for the project
+    /// * `title` - Title of the proposal
+    /// * `ipfs` - IPFS content identifier describing the proposal
+    /// * `voting_ends_at` - UNIX timestamp when voting ends
+    /// * [`Option<action>`] - The change to apply if the proposal is approved
+    ///
+    /// # Returns
+    /// * `u32` - The ID of the created proposal.
+    ///
+    /// # Panics
+    /// * If the project has no emergency voting period
+    /// * If the cosigner is the proposer or not an admin of the project
+    /// * If the action is not an incident action
+    /// * If the action is invalid, see `create_action_proposal`
+    /// * If the proposal cannot be created, see `create_proposal`
+    #[allow(clippy::too_many_arguments)]
+    fn create_emergency_proposal(
+        env: Env,
+        proposer: Address,
+        cosigner: Address,
+        project_key: Bytes,
+        title: String,
+        ipfs: String,
+        voting_ends_at: u64,
+        action: Option<types::DaoAction>,
+    ) -> u32 {
+        if let Some(action) = &action {
+            if !is_incident_action(action) {
+                panic_with_error!(&env, &errors::ContractErrors::InvalidDaoAction);
+            }
+            validate_action(&env, &project_key, action);
+        }
 
-        events::ProposalCreated {
-            project_key,
-            proposal_id,
-            title: proposal.title,
+        let proposal_id = create(
+            &env,
             proposer,
+            project_key.clone(),
+            title,
+            ipfs,
             voting_ends_at,
-            public_voting,
+            true,
+            None,
+            action
+                .as_ref()
+                .map_or(types::ProposalKind::Text, proposal_kind),
+            Vec::new(&env),
+            Some(cosigner),
+        );
+
+        if let Some(action) = action {
+            env.storage().persistent().set(
+                &types::ProjectKey::ProposalAction(project_key, proposal_id),
+                &action,
+            );
         }
-        .publish(&env);
 
         proposal_id
     }
 
-    /// Revoke a proposal.
+    /// Get the governance configuration of a project.
     ///
-    /// Useful if there was some spam or bad intent. That will prevent the
-    /// collateral to be claimed back.
+    /// Projects registered before governance configurations existed use the
+    /// default configuration.
     ///
     /// # Arguments
     /// * `env` - The environment object
-    /// * `maintainer` - Address of the proposal creator
     /// * `project_key` - The project key identifier
-    /// * `proposal_id` - The ID of the proposal to vote on
+    ///
+    /// # Returns
+    /// * `types::GovernanceConfig` - The governance configuration
+    fn get_governance_config(env: Env, project_key: Bytes) -> types::GovernanceConfig {
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::GovernanceConfig(project_key))
+            .unwrap_or(default_governance_config(&env))
+    }
+
+    /// Fund the treasury of a project.
+    ///
+    /// The tokens are held by the contract and can only be spent through
+    /// approved treasury proposals of the project. They are sent to the
+    /// maintainers if the project is removed, see `deregister`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `funder` - The address sending the tokens
+    /// * `project_key` - The project key identifier
+    /// * `token` - The address of the token contract
+    /// * `amount` - The amount of tokens
     ///
     /// # Panics
-    /// * If the proposal is not active anymore
-    /// * If the maintainer is not authorized
-    fn revoke_proposal(env: Env, maintainer: Address, project_key: Bytes, proposal_id: u32) {
+    /// * If the project doesn't exist
+    /// * If the project is archived
+    /// * If the amount is not positive
+    /// * If the transfer fails
+    fn fund_treasury(env: Env, funder: Address, project_key: Bytes, token: Address, amount: i128) {
         Tansu::require_not_paused(env.clone());
-
-        let admins_config = Tansu::get_admins_config(env.clone());
-        if admins_config.admins.contains(maintainer.clone()) {
-            maintainer.require_auth();
-        } else {
-            crate::auth_maintainers(&env, &maintainer, &project_key);
+        <Tansu as VersioningTrait>::get_project(env.clone(), project_key.clone());
+        crate::require_not_archived(&env, &project_key);
+        if amount <= 0 {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidAmount);
         }
 
-        let page = proposal_id / MAX_PROPOSALS_PER_PAGE;
-        let sub_id = proposal_id % MAX_PROPOSALS_PER_PAGE;
-        let mut dao_page = Self::get_dao(env.clone(), project_key.clone(), page);
-        let mut proposal = match dao_page.proposals.try_get(sub_id) {
-            Ok(Some(proposal)) => proposal,
-            _ => panic_with_error!(&env, &errors::ContractErrors::NoProposalorPageFound),
-        };
+        funder.require_auth();
+        token::TokenClient::new(&env, &token).transfer(
+            &funder,
+            env.current_contract_address(),
+            &amount,
+        );
 
-        // only allow to execute once
-        if proposal.status != types::ProposalStatus::Active {
-            panic_with_error!(&env, &errors::ContractErrors::ProposalActive);
-        }
+        credit_treasury(&env, &project_key, &token, amount);
+
+        events::TreasuryFunded {
+            project_key,
+            funder,
+            token,
+            amount,
+        }
+        .publish(&env);
+    }
+
+    /// Get the treasury of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Map<Address, i128>` - The balance per token contract
+    fn get_treasury(env: Env, project_key: Bytes) -> Map<Address, i128> {
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::Treasury(project_key))
+            .unwrap_or(Map::new(&env))
+    }
+
+    /// Get the action attached to a proposal.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal
+    ///
+    /// # Returns
+    /// * `Option<types::DaoAction>` - The action, if any
+    fn get_proposal_action(
+        env: Env,
+        project_key: Bytes,
+        proposal_id: u32,
+    ) -> Option<types::DaoAction> {
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::ProposalAction(project_key, proposal_id))
+    }
+
+    /// Revoke a proposal.
+    ///
+    /// Useful if there was some spam or bad intent. That will prevent the
+    /// collateral to be claimed back and slash the deposit to the treasury.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - Address of the proposal creator
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal to vote on
+    ///
+    /// # Panics
+    /// * If the proposal is not active anymore
+    /// * If the maintainer is not authorized
+    /// * If the project is controlled by its DAO and the caller is not a
+    ///   contract admin
+    fn revoke_proposal(env: Env, maintainer: Address, project_key: Bytes, proposal_id: u32) {
+        Tansu::require_not_paused(env.clone());
+
+        let admins_config = Tansu::get_admins_config(env.clone());
+        if admins_config.admins.contains(maintainer.clone()) {
+            maintainer.require_auth();
+        } else {
+            crate::auth_maintainers(&env, &maintainer, &project_key);
+            crate::require_not_dao_controlled(&env, &project_key);
+        }
+
+        let page = proposal_id / MAX_PROPOSALS_PER_PAGE;
+        let sub_id = proposal_id % MAX_PROPOSALS_PER_PAGE;
+        let mut dao_page = Self::get_dao(env.clone(), project_key.clone(), page);
+        let mut proposal = match dao_page.proposals.try_get(sub_id) {
+            Ok(Some(proposal)) => proposal,
+            _ => panic_with_error!(&env, &errors::ContractErrors::NoProposalorPageFound),
+        };
+
+        // only allow to execute once
+        if proposal.status != types::ProposalStatus::Active {
+            panic_with_error!(&env, &errors::ContractErrors::ProposalActive);
+        }
 
         // we obfuscate the proposal to avoid any DMCA or else
         proposal.title = String::from_str(&env, "REDACTED");
         proposal.ipfs = String::from_str(&env, "NONE");
         proposal.status = types::ProposalStatus::Malicious;
+        settle_deposit(&env, &project_key, &proposal, true);
+        settle_commitments(&env, &project_key, proposal_id, false);
+        refund_reward_pool(&env, &project_key, proposal_id);
 
         dao_page.proposals.set(sub_id, proposal.clone());
 
@@ -339,6 +720,13 @@ impl DaoTrait for Tansu {
             &types::ProjectKey::Dao(project_key.clone(), page),
             &dao_page,
         );
+        clear_proposal_data(&env, &project_key, proposal_id);
+        env.storage()
+            .persistent()
+            .remove(&types::ProjectKey::Revisions(
+                project_key.clone(),
+                proposal_id,
+            ));
 
         events::ProposalExecuted {
             project_key: project_key.clone(),
@@ -349,31 +737,26 @@ impl DaoTrait for Tansu {
         .publish(&env);
     }
 
-    /// Cast a vote on a proposal.
+    /// Cancel a proposal by its proposer.
     ///
-    /// Allows a member to vote on a proposal.
-    /// The vote can be either public or anonymous depending on the proposal configuration.
-    /// For public votes, the choice and weight are visible. For anonymous votes, only
-    /// the weight is visible, and the choice is encrypted.
+    /// Possible until someone else votes, or during a grace period after
+    /// the creation of the proposal. The proposal is cancelled, its action
+    /// dropped and all collaterals and the deposit are refunded.
     ///
     /// # Arguments
     /// * `env` - The environment object
-    /// * `voter` - The address of the voter
+    /// * `proposer` - Address of the proposal creator
     /// * `project_key` - The project key identifier
-    /// * `proposal_id` - The ID of the proposal to vote on
-    /// * `vote` - The vote data (public or anonymous)
+    /// * `proposal_id` - The ID of the proposal to cancel
     ///
     /// # Panics
-    /// * If the voter has already voted
-    /// * If the voting period has ended
-    /// * If the proposal is not active anymore
     /// * If the proposal doesn't exist
-    /// * If the voter's weight exceeds their maximum allowed weight
-    /// * If the voter is not a member of the project
-    fn vote(env: Env, voter: Address, project_key: Bytes, proposal_id: u32, vote: types::Vote) {
+    /// * If the proposal is not active anymore
+    /// * If the caller is not the proposer
+    /// * If others voted and the grace period is over
+    fn cancel_proposal(env: Env, proposer: Address, project_key: Bytes, proposal_id: u32) {
         Tansu::require_not_paused(env.clone());
-
-        voter.require_auth();
+        proposer.require_auth();
 
         let page = proposal_id / MAX_PROPOSALS_PER_PAGE;
         let sub_id = proposal_id % MAX_PROPOSALS_PER_PAGE;
@@ -383,133 +766,182 @@ impl DaoTrait for Tansu {
             _ => panic_with_error!(&env, &errors::ContractErrors::NoProposalorPageFound),
         };
 
-        // Check that voting period has not ended
-        let curr_timestamp = env.ledger().timestamp();
-        if curr_timestamp >= proposal.vote_data.voting_ends_at {
-            panic_with_error!(&env, &errors::ContractErrors::ProposalVotingTime);
+        if proposal.status != types::ProposalStatus::Active {
+            panic_with_error!(&env, &errors::ContractErrors::ProposalActive);
         }
-
-        // Check vote limits for DoS protection
-        if proposal.vote_data.votes.len() >= MAX_VOTES_PER_PROPOSAL {
-            panic_with_error!(&env, &errors::ContractErrors::VoteLimitExceeded);
+        if proposal.proposer != proposer {
+            panic_with_error!(&env, &errors::ContractErrors::UnauthorizedSigner);
+        }
+        // the first vote is the abstain vote of the proposer
+        if proposal.vote_data.votes.len() > 1
+            && env.ledger().timestamp() >= proposal.created_at + CANCELLATION_GRACE_PERIOD
+        {
+            panic_with_error!(&env, &errors::ExtendedErrors::ProposalNotCancellable);
         }
 
-        // only allow to vote once per voter
-        let has_already_voted = proposal.vote_data.votes.iter().any(|vote_| match vote_ {
-            types::Vote::PublicVote(vote_choice) => vote_choice.address == voter,
-            types::Vote::AnonymousVote(vote_choice) => vote_choice.address == voter,
-        });
+        refund_collaterals(&env, &proposal);
+        settle_deposit(&env, &project_key, &proposal, false);
+        settle_commitments(&env, &project_key, proposal_id, false);
+        refund_reward_pool(&env, &project_key, proposal_id);
 
-        if has_already_voted {
-            panic_with_error!(&env, &errors::ContractErrors::AlreadyVoted);
-        }
+        proposal.status = types::ProposalStatus::Cancelled;
+        dao_page.proposals.set(sub_id, proposal.clone());
+        env.storage().persistent().set(
+            &types::ProjectKey::Dao(project_key.clone(), page),
+            &dao_page,
+        );
+        clear_proposal_data(&env, &project_key, proposal_id);
 
-        // proposals are either public or anonymous so only a single type of vote
-        // can be registered for a given proposal
-        let is_public_vote = matches!(vote, types::Vote::PublicVote(_));
-        if is_public_vote != proposal.vote_data.public_voting {
-            panic_with_error!(&env, &errors::ContractErrors::WrongVoteType);
+        events::ProposalCancelled {
+            project_key,
+            proposal_id,
+            proposer: proposer.clone(),
         }
+        .publish(&env);
 
-        // For anonymous votes, validate commitment structure
-        if !is_public_vote && let types::Vote::AnonymousVote(vote_choice) = &vote {
-            if vote_choice.commitments.len() != 3 {
-                panic_with_error!(&env, &errors::ContractErrors::BadCommitment)
-            }
-            for commitment in &vote_choice.commitments {
-                G1Affine::from_bytes(commitment);
-            }
+        if let Some(outcomes_contract) = &proposal.outcomes_contract {
+            outcomes_contract::Client::new(&env, outcomes_contract).abstain_outcome(&proposer);
         }
+    }
 
-        // can only vote for yourself so address must match
-        let vote_address = match &vote {
-            types::Vote::PublicVote(vote_choice) => &vote_choice.address,
-            types::Vote::AnonymousVote(vote_choice) => &vote_choice.address,
-        };
-        if vote_address != &voter {
-            panic_with_error!(&env, &errors::ContractErrors::WrongVoter);
-        }
+    /// Amend the description of a proposal by its proposer.
+    ///
+    /// Possible until someone else votes, or during a window after the
+    /// creation of the proposal. All the descriptions of the proposal are
+    /// kept so voters can see what changed.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `proposer` - Address of the proposal creator
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal to amend
+    /// * `ipfs` - IPFS content identifier of the amended description
+    ///
+    /// # Returns
+    /// * `u32` - The number of the revision, the original description being 0
+    ///
+    /// # Panics
+    /// * If the proposal doesn't exist
+    /// * If the proposal is not active anymore
+    /// * If the caller is not the proposer
+    /// * If the IPFS CID is malformed
+    /// * If others voted and the window is over
+    /// * If the description is unchanged or was amended too many times
+    fn amend_proposal(
+        env: Env,
+        proposer: Address,
+        project_key: Bytes,
+        proposal_id: u32,
+        ipfs: String,
+    ) -> u32 {
+        Tansu::require_not_paused(env.clone());
+        proposer.require_auth();
 
-        // Voter can use up to their max allowed voting weight
-        let vote_weight = match &vote {
-            types::Vote::PublicVote(vote_choice) => &vote_choice.weight,
-            types::Vote::AnonymousVote(vote_choice) => &vote_choice.weight,
+        let page = proposal_id / MAX_PROPOSALS_PER_PAGE;
+        let sub_id = proposal_id % MAX_PROPOSALS_PER_PAGE;
+        let mut dao_page = Self::get_dao(env.clone(), project_key.clone(), page);
+        let mut proposal = match dao_page.proposals.try_get(sub_id) {
+            Ok(Some(proposal)) => proposal,
+            _ => panic_with_error!(&env, &errors::ContractErrors::NoProposalorPageFound),
         };
 
-        let voter_max_weight = <Tansu as MembershipTrait>::get_max_weight(
-            env.clone(),
-            project_key.clone(),
-            vote_address.clone(),
-        );
-
-        if voter_max_weight == 0 {
-            panic_with_error!(&env, &errors::ContractErrors::UnknownMember);
+        if proposal.status != types::ProposalStatus::Active {
+            panic_with_error!(&env, &errors::ContractErrors::ProposalActive);
+        }
+        if proposal.proposer != proposer {
+            panic_with_error!(&env, &errors::ContractErrors::UnauthorizedSigner);
         }
+        validation::validate_cid(&env, &ipfs);
 
-        if vote_weight > &voter_max_weight {
-            panic_with_error!(&env, &errors::ContractErrors::VoterWeight);
+        let curr_timestamp = env.ledger().timestamp();
+        let mut revisions = Self::get_revisions(env.clone(), project_key.clone(), proposal_id);
+        if !is_amendable(&env, &proposal)
+            || proposal.ipfs == ipfs
+            || revisions.len() > MAX_REVISIONS
+        {
+            panic_with_error!(&env, &errors::ExtendedErrors::ProposalNotAmendable);
         }
 
-        let sac_contract = crate::retrieve_contract(&env, types::ContractKey::CollateralContract);
-        let token_stellar = token::StellarAssetClient::new(&env, &sac_contract.address);
-        match token_stellar.try_transfer(&voter, env.current_contract_address(), &VOTE_COLLATERAL) {
-            Ok(..) => (),
-            _ => panic_with_error!(&env, &errors::ContractErrors::CollateralError),
+        if revisions.is_empty() {
+            revisions.push_back(types::Revision {
+                ipfs: proposal.ipfs.clone(),
+                amended_at: proposal.created_at,
+            });
         }
-        // Record the vote
-        proposal.vote_data.votes.push_back(vote.clone());
+        revisions.push_back(types::Revision {
+            ipfs: ipfs.clone(),
+            amended_at: curr_timestamp,
+        });
+        env.storage().persistent().set(
+            &types::ProjectKey::Revisions(project_key.clone(), proposal_id),
+            &revisions,
+        );
 
+        proposal.ipfs = ipfs.clone();
         dao_page.proposals.set(sub_id, proposal);
-
         env.storage().persistent().set(
             &types::ProjectKey::Dao(project_key.clone(), page),
             &dao_page,
         );
 
-        events::VoteCast {
+        let revision = revisions.len() - 1;
+        events::ProposalAmended {
             project_key,
             proposal_id,
-            voter,
+            ipfs,
+            revision,
         }
         .publish(&env);
+
+        revision
     }
 
-    /// Execute a vote after the voting period ends.
-    ///
-    /// Processes the voting results and determines the final status of the proposal.
-    /// For public votes, the results are calculated directly from vote counts.
-    /// For anonymous votes, tallies and seeds are validated against vote commitments
-    /// to ensure the results are correct.
+    /// Get the descriptions of an amended proposal.
     ///
     /// # Arguments
     /// * `env` - The environment object
-    /// * `maintainer` - The address of the maintainer executing the proposal
     /// * `project_key` - The project key identifier
-    /// * `proposal_id` - The ID of the proposal to execute
-    /// * [`Option<tallies>`] - decoded tally values (scaled by weights), respectively Approve, reject and abstain
-    /// * [`Option<seeds>`] - decoded seed values (scaled by weights), respectively Approve, reject and abstain
+    /// * `proposal_id` - The ID of the proposal
     ///
     /// # Returns
-    /// * `types::ProposalStatus` - The final status of the proposal (Approved, Rejected, or Cancelled)
+    /// * `Vec<types::Revision>` - The descriptions oldest first, empty if the
+    ///   proposal was never amended
+    fn get_revisions(env: Env, project_key: Bytes, proposal_id: u32) -> Vec<types::Revision> {
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::Revisions(project_key, proposal_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Make a proposal depend on the approval of an earlier proposal.
+    ///
+    /// Multi-step programs are encoded by chaining proposals: the dependent
+    /// proposal cannot be approved before its dependency is, and it is
+    /// cancelled if its dependency is not approved. Linking is possible
+    /// while the proposal can be amended, see `amend_proposal`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `proposer` - Address of the proposal creator
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the dependent proposal
+    /// * `depends_on` - The ID of the proposal which must be approved first
     ///
     /// # Panics
-    /// * If the voting period hasn't ended
-    /// * If the proposal doesn't exist
+    /// * If a proposal doesn't exist
     /// * If the proposal is not active anymore
-    /// * If tallies/seeds are missing for anonymous votes
-    /// * If commitment validation fails for anonymous votes
-    /// * If the maintainer is not authorized
-    fn execute(
+    /// * If the caller is not the proposer
+    /// * If others voted and the amendment window is over
+    /// * If the dependency is not an earlier proposal, or it already failed
+    fn link_proposal(
         env: Env,
-        maintainer: Address,
+        proposer: Address,
         project_key: Bytes,
         proposal_id: u32,
-        tallies: Option<Vec<u128>>,
-        seeds: Option<Vec<u128>>,
-    ) -> types::ProposalStatus {
+        depends_on: u32,
+    ) {
         Tansu::require_not_paused(env.clone());
-
-        maintainer.require_auth();
+        proposer.require_auth();
 
         let page = proposal_id / MAX_PROPOSALS_PER_PAGE;
         let sub_id = proposal_id % MAX_PROPOSALS_PER_PAGE;
@@ -519,343 +951,2469 @@ impl DaoTrait for Tansu {
             _ => panic_with_error!(&env, &errors::ContractErrors::NoProposalorPageFound),
         };
 
-        let curr_timestamp = env.ledger().timestamp();
-
-        // only allow to execute once
         if proposal.status != types::ProposalStatus::Active {
             panic_with_error!(&env, &errors::ContractErrors::ProposalActive);
         }
-        if curr_timestamp < proposal.vote_data.voting_ends_at {
-            panic_with_error!(&env, &errors::ContractErrors::ProposalVotingTime);
+        if proposal.proposer != proposer {
+            panic_with_error!(&env, &errors::ContractErrors::UnauthorizedSigner);
         }
-
-        // proposers get its collateral back
-        let sac_contract = crate::retrieve_contract(&env, types::ContractKey::CollateralContract);
-        let token_stellar = token::StellarAssetClient::new(&env, &sac_contract.address);
-        match token_stellar.try_transfer(
-            &env.current_contract_address(),
-            &proposal.proposer,
-            &PROPOSAL_COLLATERAL,
-        ) {
-            Ok(..) => (),
-            _ => panic_with_error!(&env, &errors::ContractErrors::CollateralError),
+        if !is_amendable(&env, &proposal) {
+            panic_with_error!(&env, &errors::ExtendedErrors::ProposalNotAmendable);
         }
 
-        // all voters get their collateral back
-        for vote_ in &proposal.vote_data.votes {
-            let vote_address = match &vote_ {
-                types::Vote::PublicVote(vote_choice) => &vote_choice.address,
-                types::Vote::AnonymousVote(vote_choice) => &vote_choice.address,
-            };
-            match token_stellar.try_transfer(
-                &env.current_contract_address(),
-                vote_address,
-                &VOTE_COLLATERAL,
-            ) {
-                Ok(..) => (),
-                _ => panic_with_error!(&env, &errors::ContractErrors::CollateralError),
-            }
+        // earlier proposals only, which prevents cycles
+        if depends_on >= proposal_id
+            || !matches!(
+                Self::get_proposal(env.clone(), project_key.clone(), depends_on).status,
+                types::ProposalStatus::Active | types::ProposalStatus::Approved
+            )
+        {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidProposalDependency);
         }
 
-        // tally to results
-        proposal.status = match proposal.vote_data.public_voting {
-            true => {
-                if tallies.is_some() || seeds.is_some() {
-                    panic_with_error!(&env, &errors::ContractErrors::TallySeedError);
-                }
-                public_execute(&proposal)
-            }
-            false => {
-                let (tallies_, seeds_) = match (tallies, seeds) {
-                    (Some(t), Some(s)) => (t, s),
-                    _ => panic_with_error!(&env, &errors::ContractErrors::TallySeedError),
-                };
-
-                // Validate tallies and seeds have expected length (3: approve, reject, abstain)
-                if tallies_.len() != 3 || seeds_.len() != 3 {
-                    panic_with_error!(&env, &errors::ContractErrors::TallySeedError);
-                }
-
-                if !Self::proof(
-                    env.clone(),
-                    project_key.clone(),
-                    proposal.clone(),
-                    tallies_.clone(),
-                    seeds_,
-                ) {
-                    panic_with_error!(&env, &errors::ContractErrors::InvalidProof)
-                }
-                anonymous_execute(&tallies_)
-            }
-        };
-
-        dao_page.proposals.set(sub_id, proposal.clone());
-
+        proposal.depends_on = Some(depends_on);
+        dao_page.proposals.set(sub_id, proposal);
         env.storage().persistent().set(
             &types::ProjectKey::Dao(project_key.clone(), page),
             &dao_page,
         );
 
-        events::ProposalExecuted {
-            project_key: project_key.clone(),
+        events::ProposalLinked {
+            project_key,
             proposal_id,
-            status: match proposal.status {
-                types::ProposalStatus::Active => String::from_str(&env, "Active"),
-                types::ProposalStatus::Approved => String::from_str(&env, "Approved"),
-                types::ProposalStatus::Rejected => String::from_str(&env, "Rejected"),
-                types::ProposalStatus::Cancelled => String::from_str(&env, "Cancelled"),
-                types::ProposalStatus::Malicious => String::from_str(&env, "Malicious"),
-            },
-            maintainer: maintainer.clone(),
+            depends_on,
         }
         .publish(&env);
-
-        if (proposal.outcomes_contract).is_some() {
-            let client =
-                outcomes_contract::Client::new(&env, &(proposal.outcomes_contract).unwrap());
-
-            match proposal.status {
-                types::ProposalStatus::Approved => client.approve_outcome(&maintainer),
-                types::ProposalStatus::Rejected => client.reject_outcome(&maintainer),
-                types::ProposalStatus::Cancelled => client.abstain_outcome(&maintainer),
-                _ => (),
-            };
-        }
-
-        proposal.status
     }
 
-    /// Verify vote commitment proof for anonymous voting.
-    ///
-    /// Validates that the provided tallies and seeds match the vote commitments
-    /// without revealing individual votes. This ensures the integrity of anonymous
-    /// voting results.
-    ///
-    /// The commitment is:
+    /// Add a recurring proposal to a project.
     ///
-    /// C = g^v * h^r (in additive notation: g*v + h*r),
-    ///
-    /// where g, h are BLS12-381 generator points and v is the vote choice,
-    /// r is the seed. Voting weight is introduced during the tallying phase.
+    /// The proposal is created from the template on a schedule, e.g. a
+    /// monthly budget approval, when anyone calls `tick`.
     ///
     /// # Arguments
     /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
     /// * `project_key` - The project key identifier
-    /// * `proposal` - The proposal containing vote commitments
-    /// * `tallies` - Decoded tally values [approve, reject, abstain] (scaled by weights)
-    /// * `seeds` - Decoded seed values [approve, reject, abstain] (scaled by weights)
+    /// * `template` - The template of the proposal and its schedule
     ///
     /// # Returns
-    /// * `bool` - True if all commitments match the provided tallies and seeds
+    /// * `u32` - The ID of the template
     ///
     /// # Panics
-    /// * If no anonymous voting configuration exists for the project
-    fn proof(
+    /// * If the maintainer is not authorized or not an admin
+    /// * If the project is controlled by its DAO
+    /// * If the project has too many templates
+    /// * If the title, voting period or interval is out of bounds
+    /// * If the IPFS CID is malformed
+    /// * If the action is invalid
+    fn add_proposal_template(
         env: Env,
+        maintainer: Address,
         project_key: Bytes,
-        proposal: types::Proposal,
-        tallies: Vec<u128>,
-        seeds: Vec<u128>,
-    ) -> bool {
-        // Proof validation only applies to active proposals (before execution)
-        if proposal.status != types::ProposalStatus::Active {
-            panic_with_error!(&env, &errors::ContractErrors::ProposalActive);
-        }
+        template: types::ProposalTemplate,
+    ) -> u32 {
+        Tansu::require_not_paused(env.clone());
+        crate::auth_maintainers_role(
+            &env,
+            &maintainer,
+            &project_key,
+            types::MaintainerRole::Admin,
+        );
+        crate::require_not_dao_controlled(&env, &project_key);
 
-        // we can only proof anonymous votes
-        if proposal.vote_data.public_voting {
-            panic_with_error!(&env, &errors::ContractErrors::WrongVoteType);
+        let governance = Self::get_governance_config(env.clone(), project_key.clone());
+        let mut templates = Self::get_proposal_templates(env.clone(), project_key.clone());
+        if templates.len() >= MAX_TEMPLATES
+            || !(governance.min_voting_period..=governance.max_voting_period)
+                .contains(&template.voting_period)
+            || !(MIN_TITLE_LENGTH..=governance.max_title_length).contains(&template.title.len())
+            || template.interval < MIN_TEMPLATE_INTERVAL
+        {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidTemplate);
+        }
+        validation::validate_cid(&env, &template.ipfs);
+        if let types::TemplateAction::Action(action) = &template.action {
+            validate_action(&env, &project_key, action);
         }
 
-        let bls12_381 = env.crypto().bls12_381();
-
-        let vote_config: types::AnonymousVoteConfig = env
-            .storage()
-            .instance()
-            .get(&types::ProjectKey::AnonymousVoteConfig(project_key))
-            .unwrap_or_else(|| {
-                panic_with_error!(&env, &errors::ContractErrors::NoAnonymousVotingConfig);
-            });
-
-        let seed_generator_point = G1Affine::from_bytes(vote_config.seed_generator_point);
-        let vote_generator_point = G1Affine::from_bytes(vote_config.vote_generator_point);
+        let template_id = templates.keys().last().map_or(0, |id| id + 1);
+        templates.set(template_id, template);
+        env.storage().persistent().set(
+            &types::ProjectKey::ProposalTemplates(project_key.clone()),
+            &templates,
+        );
 
-        // calculate commitments from vote tally and seed tally
-        let mut commitment_checks = Vec::new(&env);
-        for it in tallies.iter().zip(seeds.iter()) {
-            let (tally_, seed_) = it;
-            let seed_: U256 = U256::from_u128(&env, seed_);
-            let tally_: U256 = U256::from_u128(&env, tally_);
-            let seed_point_ = bls12_381.g1_mul(&seed_generator_point, &seed_.into());
-            let tally_commitment_votes_ = bls12_381.g1_mul(&vote_generator_point, &tally_.into());
-            let commitment_check_ = bls12_381.g1_add(&tally_commitment_votes_, &seed_point_);
-            commitment_checks.push_back(commitment_check_);
+        events::ProposalTemplateUpdated {
+            project_key,
+            template_id,
+            maintainer,
+            active: true,
         }
+        .publish(&env);
 
-        // tally commitments from recorded votes (vote + seed)
-        let mut g1_identity = [0u8; 96];
-        g1_identity[0] = 0x40;
-        let tally_commitment_init_ = G1Affine::from_bytes(BytesN::from_array(&env, &g1_identity));
+        template_id
+    }
 
-        let mut tally_commitments = [
-            tally_commitment_init_.clone(),
-            tally_commitment_init_.clone(),
-            tally_commitment_init_.clone(),
-        ];
+    /// Remove a recurring proposal from a project.
+    ///
+    /// Proposals already created from the template are not affected.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `project_key` - The project key identifier
+    /// * `template_id` - The ID of the template
+    ///
+    /// # Panics
+    /// * If the maintainer is not authorized or not an admin
+    /// * If the project is controlled by its DAO
+    /// * If the template doesn't exist
+    fn remove_proposal_template(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        template_id: u32,
+    ) {
+        Tansu::require_not_paused(env.clone());
+        crate::auth_maintainers_role(
+            &env,
+            &maintainer,
+            &project_key,
+            types::MaintainerRole::Admin,
+        );
+        crate::require_not_dao_controlled(&env, &project_key);
 
-        for vote_ in proposal.vote_data.votes.iter() {
-            if let types::Vote::AnonymousVote(anonymous_vote) = &vote_ {
-                let weight_: U256 = U256::from_u32(&env, anonymous_vote.weight);
-                for (commitment, tally_commitment) in anonymous_vote
-                    .commitments
-                    .iter()
-                    .zip(tally_commitments.iter_mut())
-                {
-                    let commitment_ = G1Affine::from_bytes(commitment);
-                    // scale the commitment by the voter weight: g*v*weight + h*r*weight.
-                    let weighted_commitment =
-                        bls12_381.g1_mul(&commitment_, &weight_.clone().into());
-                    *tally_commitment = bls12_381.g1_add(tally_commitment, &weighted_commitment);
-                }
-            };
+        let mut templates = Self::get_proposal_templates(env.clone(), project_key.clone());
+        if templates.remove(template_id).is_none() {
+            panic_with_error!(&env, &errors::ExtendedErrors::UnknownTemplate);
         }
+        env.storage().persistent().set(
+            &types::ProjectKey::ProposalTemplates(project_key.clone()),
+            &templates,
+        );
 
-        // compare commitments
-        for (commitment_check, tally_commitment) in
-            commitment_checks.iter().zip(tally_commitments.iter())
-        {
-            if commitment_check != *tally_commitment {
-                return false;
-            }
+        events::ProposalTemplateUpdated {
+            project_key,
+            template_id,
+            maintainer,
+            active: false,
         }
+        .publish(&env);
+    }
 
-        true
+    /// Get the recurring proposals of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Map<u32, types::ProposalTemplate>` - The templates by ID
+    fn get_proposal_templates(env: Env, project_key: Bytes) -> Map<u32, types::ProposalTemplate> {
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::ProposalTemplates(project_key))
+            .unwrap_or(Map::new(&env))
     }
 
-    /// Returns a page of proposals (0 to MAX_PROPOSALS_PER_PAGE proposals per page).
+    /// Create the recurring proposals of a project which are due.
+    ///
+    /// Anyone can call this function. The caller becomes the proposer of the
+    /// proposals created from the templates and incurs their collaterals,
+    /// as with `create_proposal`. Occurrences missed while nobody called
+    /// this function are skipped.
     ///
     /// # Arguments
     /// * `env` - The environment object
+    /// * `proposer` - Address of the caller proposing the due proposals
     /// * `project_key` - The project key identifier
-    /// * `page` - The page number (0-based)
     ///
     /// # Returns
-    /// * `types::Dao` - The DAO object containing a page of proposals
+    /// * `Vec<u32>` - The IDs of the created proposals
     ///
     /// # Panics
-    /// * If the page number is out of bounds
-    fn get_dao(env: Env, project_key: Bytes, page: u32) -> types::Dao {
-        if page >= MAX_PAGES {
-            panic_with_error!(&env, &errors::ContractErrors::NoProposalorPageFound);
+    /// * If a proposal cannot be created, see `create_proposal` and
+    ///   `create_action_proposal`
+    fn tick(env: Env, proposer: Address, project_key: Bytes) -> Vec<u32> {
+        let mut templates = Self::get_proposal_templates(env.clone(), project_key.clone());
+        let curr_timestamp = env.ledger().timestamp();
+        let mut proposal_ids = Vec::new(&env);
+        for (template_id, mut template) in templates.clone() {
+            if template.next_at > curr_timestamp {
+                continue;
+            }
+            let voting_ends_at = curr_timestamp + template.voting_period;
+            let proposal_id = match template.action.clone() {
+                types::TemplateAction::Action(action) => Self::create_action_proposal(
+                    env.clone(),
+                    proposer.clone(),
+                    project_key.clone(),
+                    template.title.clone(),
+                    template.ipfs.clone(),
+                    voting_ends_at,
+                    template.public_voting,
+                    action,
+                ),
+                types::TemplateAction::Text => Self::create_proposal(
+                    env.clone(),
+                    proposer.clone(),
+                    project_key.clone(),
+                    template.title.clone(),
+                    template.ipfs.clone(),
+                    voting_ends_at,
+                    template.public_voting,
+                    None,
+                ),
+            };
+            proposal_ids.push_back(proposal_id);
+
+            let missed = (curr_timestamp - template.next_at) / template.interval;
+            template.next_at += (missed + 1) * template.interval;
+            templates.set(template_id, template);
         }
 
-        let key_ = types::ProjectKey::Key(project_key.clone());
-        if env
-            .storage()
-            .persistent()
-            .get::<types::ProjectKey, types::Project>(&key_)
-            .is_some()
-        {
-            env.storage()
-                .persistent()
-                .get(&types::ProjectKey::Dao(project_key, page))
-                .unwrap_or(types::Dao {
-                    proposals: Vec::new(&env),
-                })
-        } else {
-            panic_with_error!(&env, &errors::ContractErrors::InvalidKey);
+        if !proposal_ids.is_empty() {
+            env.storage().persistent().set(
+                &types::ProjectKey::ProposalTemplates(project_key),
+                &templates,
+            );
         }
+        proposal_ids
     }
 
-    /// Get a single proposal by ID.
+    /// Veto an approved proposal.
+    ///
+    /// During the veto period following the end of the voting period, each
+    /// maintainer calls this function to veto the proposal with a reason.
+    /// The proposal is vetoed once the maintainers threshold of the project
+    /// is reached, its action is dropped and all collaterals are refunded
+    /// while the deposit of the proposer is slashed to the treasury.
     ///
     /// # Arguments
     /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
     /// * `project_key` - The project key identifier
-    /// * `proposal_id` - The ID of the proposal to retrieve
+    /// * `proposal_id` - The ID of the proposal
+    /// * `reason` - Why the proposal is vetoed
     ///
     /// # Returns
-    /// * `types::Proposal` - The proposal object
+    /// * `bool` - True if the proposal was vetoed
     ///
     /// # Panics
+    /// * If the maintainer is not authorized or not an admin
+    /// * If the project is controlled by its DAO
     /// * If the proposal doesn't exist
-    fn get_proposal(env: Env, project_key: Bytes, proposal_id: u32) -> types::Proposal {
+    /// * If the proposal is not active anymore
+    /// * If the project has no veto period or it is not ongoing
+    /// * If the reason is empty or too long
+    /// * If the maintainer already vetoed the proposal
+    fn veto_proposal(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        proposal_id: u32,
+        reason: String,
+    ) -> bool {
+        Tansu::require_not_paused(env.clone());
+
+        let project = crate::auth_maintainers_role(
+            &env,
+            &maintainer,
+            &project_key,
+            types::MaintainerRole::Admin,
+        );
+        crate::require_not_dao_controlled(&env, &project_key);
+
         let page = proposal_id / MAX_PROPOSALS_PER_PAGE;
         let sub_id = proposal_id % MAX_PROPOSALS_PER_PAGE;
-        let dao_page = Self::get_dao(env.clone(), project_key, page);
-        let proposals = dao_page.proposals;
-        match proposals.try_get(sub_id) {
+        let mut dao_page = Self::get_dao(env.clone(), project_key.clone(), page);
+        let mut proposal = match dao_page.proposals.try_get(sub_id) {
             Ok(Some(proposal)) => proposal,
             _ => panic_with_error!(&env, &errors::ContractErrors::NoProposalorPageFound),
+        };
+
+        if proposal.status != types::ProposalStatus::Active {
+            panic_with_error!(&env, &errors::ContractErrors::ProposalActive);
+        }
+        let governance = Self::get_governance_config(env.clone(), project_key.clone());
+        let voting_ends_at = vote_closes_at(&proposal);
+        let curr_timestamp = env.ledger().timestamp();
+        if !(voting_ends_at..voting_ends_at + governance.veto_period).contains(&curr_timestamp) {
+            panic_with_error!(&env, &errors::ContractErrors::ProposalVotingTime);
+        }
+
+        let mut vetoes = Self::get_vetoes(env.clone(), project_key.clone(), proposal_id);
+        if !(1..=MAX_VETO_REASON_LENGTH).contains(&reason.len())
+            || vetoes.iter().any(|veto| veto.maintainer == maintainer)
+        {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidVeto);
+        }
+        vetoes.push_back(types::Veto {
+            maintainer: maintainer.clone(),
+            reason: reason.clone(),
+        });
+
+        events::VetoApproved {
+            project_key: project_key.clone(),
+            proposal_id,
+            maintainer: maintainer.clone(),
+            reason,
+            approvals_count: vetoes.len(),
+        }
+        .publish(&env);
+
+        let vetoes_key = types::ProjectKey::Vetoes(project_key.clone(), proposal_id);
+        if vetoes.len() < crate::maintainers_threshold(&project) {
+            env.storage().persistent().set(&vetoes_key, &vetoes);
+            return false;
+        }
+
+        refund_collaterals(&env, &proposal);
+        settle_deposit(&env, &project_key, &proposal, true);
+        settle_commitments(&env, &project_key, proposal_id, false);
+
+        proposal.status = types::ProposalStatus::Vetoed;
+        dao_page.proposals.set(sub_id, proposal.clone());
+        env.storage().persistent().set(
+            &types::ProjectKey::Dao(project_key.clone(), page),
+            &dao_page,
+        );
+        clear_proposal_data(&env, &project_key, proposal_id);
+        // the vetoes stay as the record of the decision
+        env.storage().persistent().set(&vetoes_key, &vetoes);
+
+        events::ProposalExecuted {
+            project_key,
+            proposal_id,
+            status: String::from_str(&env, "Vetoed"),
+            maintainer: maintainer.clone(),
+        }
+        .publish(&env);
+
+        if let Some(outcomes_contract) = &proposal.outcomes_contract {
+            outcomes_contract::Client::new(&env, outcomes_contract).reject_outcome(&maintainer);
+        }
+        true
+    }
+
+    /// Get the vetoes of the maintainers on a proposal.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal
+    ///
+    /// # Returns
+    /// * `Vec<types::Veto>` - The vetoes with their reasons
+    fn get_vetoes(env: Env, project_key: Bytes, proposal_id: u32) -> Vec<types::Veto> {
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::Vetoes(project_key, proposal_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get the max voting weight of a member on a proposal.
+    ///
+    /// The weight comes from the badges, and the voting token if any, held
+    /// when the proposal was created. Stakers without a badge get the weight
+    /// of the tokens they staked before the proposal was created. Members
+    /// without any weight at that time get the default weight. Proposals
+    /// created before snapshots existed use the current badges.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal
+    /// * `member_address` - The address of the member
+    ///
+    /// # Returns
+    /// * `u32` - The maximum voting weight of the member on the proposal
+    fn get_voting_weight(
+        env: Env,
+        project_key: Bytes,
+        proposal_id: u32,
+        member_address: Address,
+    ) -> u32 {
+        match env.storage().persistent().get::<_, Map<Address, u32>>(
+            &types::ProjectKey::WeightSnapshot(project_key.clone(), proposal_id),
+        ) {
+            Some(weights) => weights.get(member_address.clone()).unwrap_or_else(|| {
+                let proposal = Self::get_proposal(env.clone(), project_key.clone(), proposal_id);
+                let governance = Self::get_governance_config(env.clone(), project_key.clone());
+                match contract_membership::staker_weight(
+                    &env,
+                    &project_key,
+                    &governance,
+                    &member_address,
+                    proposal.created_at,
+                ) {
+                    0 => types::Badge::Default as u32,
+                    weight => weight,
+                }
+            }),
+            None => <Tansu as MembershipTrait>::get_max_weight(env, project_key, member_address),
         }
     }
+
+    /// Cast a vote on a proposal.
+    ///
+    /// Allows a member to vote on a proposal.
+    /// The vote can be either public or anonymous depending on the proposal configuration.
+    /// For public votes, the choice and weight are visible. For anonymous votes, only
+    /// the weight is visible, and the choice is encrypted.
+    ///
+    /// The maximum weight of the voter includes the weight of the members
+    /// delegating to it, except members who already voted. Their weight
+    /// cannot be used again on the proposal, even if the delegation is
+    /// revoked. Projects can cap the weight of the voter and of each
+    /// delegating member to a share of the eligible weight of the proposal,
+    /// whatever their badges.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `voter` - The address of the voter
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal to vote on
+    /// * `vote` - The vote data (public or anonymous)
+    ///
+    /// # Panics
+    /// * If the voter has already voted
+    /// * If the voter delegated its weight or its delegate already voted
+    /// * If the voting period has ended
+    /// * If the proposal is not active anymore
+    /// * If the proposal doesn't exist
+    /// * If the voter's weight exceeds their maximum allowed weight
+    /// * If the voter is not a member of the project
+    /// * If the choice is not an option of the proposal
+    /// * If the proposal takes hashed votes, see `commit_vote` of the
+    ///   governance contract
+    /// * If the project is archived
+    /// * If the project is paused
+    fn vote(env: Env, voter: Address, project_key: Bytes, proposal_id: u32, vote: types::Vote) {
+        Tansu::require_not_paused(env.clone());
+        crate::require_not_archived(&env, &project_key);
+        crate::require_project_not_paused(&env, &project_key);
+
+        voter.require_auth();
+
+        cast_vote(&env, voter, project_key, proposal_id, vote, false);
+    }
+
+    /// Replace the vote of a voter on a public proposal.
+    ///
+    /// The vote is updated in place until the voting period ends. The weight
+    /// cannot exceed the weight of the replaced vote and the proposer stays
+    /// in the abstain group.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `voter` - The address of the voter
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal
+    /// * `vote` - The new vote
+    ///
+    /// # Panics
+    /// * If the proposal doesn't exist
+    /// * If the proposal is not active anymore
+    /// * If the voting period has ended
+    /// * If the proposal uses anonymous voting or takes hashed votes
+    /// * If the vote is not from the voter
+    /// * If the voter did not vote or is the proposer
+    /// * If the weight exceeds the weight of the replaced vote
+    /// * If the choice is not an option of the proposal
+    /// * If the project is archived
+    /// * If the project is paused
+    fn change_vote(
+        env: Env,
+        voter: Address,
+        project_key: Bytes,
+        proposal_id: u32,
+        vote: types::PublicVote,
+    ) {
+        Tansu::require_not_paused(env.clone());
+        crate::require_not_archived(&env, &project_key);
+        crate::require_project_not_paused(&env, &project_key);
+
+        voter.require_auth();
+
+        let page = proposal_id / MAX_PROPOSALS_PER_PAGE;
+        let sub_id = proposal_id % MAX_PROPOSALS_PER_PAGE;
+        let mut dao_page = Self::get_dao(env.clone(), project_key.clone(), page);
+        let mut proposal = match dao_page.proposals.try_get(sub_id) {
+            Ok(Some(proposal)) => proposal,
+            _ => panic_with_error!(&env, &errors::ContractErrors::NoProposalorPageFound),
+        };
+
+        if proposal.status != types::ProposalStatus::Active {
+            panic_with_error!(&env, &errors::ContractErrors::ProposalActive);
+        }
+        if env.ledger().timestamp() >= proposal.vote_data.voting_ends_at {
+            panic_with_error!(&env, &errors::ContractErrors::ProposalVotingTime);
+        }
+        if !proposal.vote_data.public_voting || takes_hashed_votes(&proposal) {
+            panic_with_error!(&env, &errors::ContractErrors::WrongVoteType);
+        }
+        if vote.address != voter {
+            panic_with_error!(&env, &errors::ContractErrors::WrongVoter);
+        }
+        if voter == proposal.proposer {
+            panic_with_error!(&env, &errors::ExtendedErrors::UnknownVote);
+        }
+        validate_choice(&env, &proposal, &vote.vote_choice);
+
+        let index = proposal
+            .vote_data
+            .votes
+            .iter()
+            .position(|vote_| match vote_ {
+                types::Vote::PublicVote(vote_choice) => vote_choice.address == voter,
+                types::Vote::AnonymousVote(_) => false,
+            })
+            .unwrap_or_else(|| panic_with_error!(&env, &errors::ExtendedErrors::UnknownVote));
+        if let types::Vote::PublicVote(previous) =
+            proposal.vote_data.votes.get_unchecked(index as u32)
+            && vote.weight > previous.weight
+        {
+            panic_with_error!(&env, &errors::ContractErrors::VoterWeight);
+        }
+
+        proposal
+            .vote_data
+            .votes
+            .set(index as u32, types::Vote::PublicVote(vote));
+        dao_page.proposals.set(sub_id, proposal);
+        env.storage().persistent().set(
+            &types::ProjectKey::Dao(project_key.clone(), page),
+            &dao_page,
+        );
+
+        events::VoteChanged {
+            project_key,
+            proposal_id,
+            voter,
+        }
+        .publish(&env);
+    }
+
+    /// Delegate the voting weight of a member on a project.
+    ///
+    /// The delegate votes with the weight of the member until the
+    /// delegation is revoked, the member cannot vote meanwhile. Delegations
+    /// cannot be chained.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `member` - The address of the member delegating its weight
+    /// * `project_key` - The project key identifier
+    /// * `delegate` - The address voting in place of the member
+    ///
+    /// # Panics
+    /// * If the member or the delegate is not a member
+    /// * If the member delegates to itself
+    /// * If the delegate delegated its own weight
+    /// * If other members delegate to the member
+    /// * If the maximum number of delegations is reached
+    /// * If the project is archived
+    fn delegate(env: Env, member: Address, project_key: Bytes, delegate: Address) {
+        Tansu::require_not_paused(env.clone());
+        crate::require_not_archived(&env, &project_key);
+        member.require_auth();
+
+        for address in [&member, &delegate] {
+            if !env
+                .storage()
+                .persistent()
+                .has(&types::DataKey::Member(address.clone()))
+            {
+                panic_with_error!(&env, &errors::ContractErrors::UnknownMember);
+            }
+        }
+
+        let mut delegations = Self::get_delegations(env.clone(), project_key.clone());
+        if member == delegate
+            || delegations.contains_key(delegate.clone())
+            || delegations.values().contains(&member)
+            || (!delegations.contains_key(member.clone()) && delegations.len() >= MAX_DELEGATIONS)
+        {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidDelegation);
+        }
+
+        delegations.set(member.clone(), delegate.clone());
+        env.storage().persistent().set(
+            &types::ProjectKey::Delegations(project_key.clone()),
+            &delegations,
+        );
+
+        events::VoteDelegated {
+            project_key,
+            member,
+            delegate: Some(delegate),
+        }
+        .publish(&env);
+    }
+
+    /// Revoke the delegation of a member on a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `member` - The address of the member which delegated its weight
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Panics
+    /// * If the member did not delegate its weight
+    fn undelegate(env: Env, member: Address, project_key: Bytes) {
+        Tansu::require_not_paused(env.clone());
+        member.require_auth();
+
+        let mut delegations = Self::get_delegations(env.clone(), project_key.clone());
+        if delegations.remove(member.clone()).is_none() {
+            panic_with_error!(&env, &errors::ExtendedErrors::InvalidDelegation);
+        }
+
+        let delegations_key = types::ProjectKey::Delegations(project_key.clone());
+        if delegations.is_empty() {
+            env.storage().persistent().remove(&delegations_key);
+        } else {
+            env.storage()
+                .persistent()
+                .set(&delegations_key, &delegations);
+        }
+
+        events::VoteDelegated {
+            project_key,
+            member,
+            delegate: None,
+        }
+        .publish(&env);
+    }
+
+    /// Get the delegations of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Map<Address, Address>` - The delegate of each delegating member
+    fn get_delegations(env: Env, project_key: Bytes) -> Map<Address, Address> {
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::Delegations(project_key))
+            .unwrap_or(Map::new(&env))
+    }
+
+    /// Execute a vote after the voting period ends.
+    ///
+    /// Processes the voting results and determines the final status of the proposal.
+    /// For public votes, the results are calculated directly from vote counts.
+    /// For anonymous votes, tallies and seeds are validated against vote commitments
+    /// to ensure the results are correct.
+    ///
+    /// Proposals of a project controlled by its DAO can be executed by any
+    /// address and their action is applied if they are approved.
+    ///
+    /// A public proposal can be executed before the end of the voting period
+    /// if the weight not cast yet cannot change its outcome anymore. The
+    /// eligible weight is the weight of the badges of the project when the
+    /// proposal was created.
+    ///
+    /// The tallies of anonymous proposals are only accepted once the voting
+    /// period is over. If the project sets a tally reveal period, they must
+    /// be revealed before its end. Past it, the proposal expires without
+    /// tallies and its action is not applied.
+    ///
+    /// If the project has a veto period, approved proposals can only be
+    /// executed once it is over.
+    ///
+    /// With quadratic voting, public votes weigh the square root of their
+    /// weight and the quorum is compared to these tallies. Anonymous votes
+    /// are tallied linearly as their choices are hidden.
+    ///
+    /// Multiple-choice proposals are approved if an option has strictly more
+    /// weight than the others and the quorum is reached, they are cancelled
+    /// otherwise.
+    ///
+    /// Approved proposals depending on another proposal are only approved
+    /// once their dependency is approved, and cancelled if it is not.
+    ///
+    /// The deposit of the proposer is refunded, unless the weight cast did
+    /// not reach the spam share of the quorum in which case it is slashed to
+    /// the treasury of the project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer executing the proposal
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal to execute
+    /// * [`Option<tallies>`] - decoded tally values (scaled by weights), respectively Approve, reject and abstain
+    /// * [`Option<seeds>`] - decoded seed values (scaled by weights, modulo the BLS12-381 scalar field order), respectively Approve, reject and abstain
+    ///
+    /// # Returns
+    /// * `types::ProposalStatus` - The final status of the proposal (Approved, Rejected, or Cancelled)
+    ///
+    /// # Panics
+    /// * If the voting period hasn't ended and the outcome is not settled
+    /// * If anonymous tallies are given before the end of the voting period
+    ///   or after the end of the reveal period
+    /// * If the proposal doesn't exist
+    /// * If the proposal is not active anymore
+    /// * If tallies/seeds are missing for anonymous votes
+    /// * If commitment validation fails for anonymous votes
+    /// * If the maintainer is not authorized
+    /// * If the maintainer is not an admin
+    /// * If the proposal is approved while its dependency is still active
+    /// * If the outcomes contract of the proposal fails
+    fn execute(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        proposal_id: u32,
+        tallies: Option<Vec<u128>>,
+        seeds: Option<Vec<U256>>,
+    ) -> types::ProposalStatus {
+        Tansu::require_not_paused(env.clone());
+        auth_executor(&env, &maintainer, &project_key);
+
+        execute_proposal(
+            &env,
+            &maintainer,
+            &project_key,
+            proposal_id,
+            tallies,
+            seeds,
+            false,
+        )
+    }
+
+    /// Status a public proposal gets from its votes once its vote is closed.
+    ///
+    /// Used by the scheduler before scheduling the execution of a proposal.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal
+    ///
+    /// # Returns
+    /// * `types::ProposalStatus` - The status resulting from the votes
+    ///
+    /// # Panics
+    /// * If the proposal doesn't exist or is not active anymore
+    /// * If the votes are anonymous
+    /// * If the voting period hasn't ended
+    fn get_outcome(env: Env, project_key: Bytes, proposal_id: u32) -> types::ProposalStatus {
+        let proposal = Self::get_proposal(env.clone(), project_key.clone(), proposal_id);
+        if proposal.status != types::ProposalStatus::Active {
+            panic_with_error!(&env, &errors::ContractErrors::ProposalActive);
+        }
+        // anonymous proposals need their tallies to be executed
+        if !proposal.vote_data.public_voting {
+            panic_with_error!(&env, &errors::ContractErrors::TallySeedError);
+        }
+        if env.ledger().timestamp() < vote_closes_at(&proposal) {
+            panic_with_error!(&env, &errors::ContractErrors::ProposalVotingTime);
+        }
+        let governance = Self::get_governance_config(env, project_key);
+        public_outcome(&proposal, &governance)
+    }
+
+    /// Execute a proposal scheduled for execution, see `run_due` of the
+    /// scheduler.
+    ///
+    /// Only the scheduler can call this function, the proposal is executed
+    /// by the contract. Proposals still in their veto period or waiting on
+    /// their dependency are not executed. Neither are the proposals which
+    /// cannot be executed anymore, see `check_execution`. A failing outcomes
+    /// contract does not fail the execution, its success is published with
+    /// `OutcomesContractInvoked`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal
+    ///
+    /// # Returns
+    /// * `types::ScheduledRun` - The result of the execution
+    ///
+    /// # Panics
+    /// * If the scheduler did not authorize the call
+    fn run_scheduled(env: Env, project_key: Bytes, proposal_id: u32) -> types::ScheduledRun {
+        crate::retrieve_contract(&env, types::ContractKey::SchedulerContract)
+            .address
+            .require_auth();
+
+        let governance = Self::get_governance_config(env.clone(), project_key.clone());
+        if !is_ready(&env, &project_key, proposal_id, &governance) {
+            types::ScheduledRun::Pending
+        } else if let Some(error) = check_execution(&env, &project_key, proposal_id) {
+            types::ScheduledRun::Failed(error)
+        } else {
+            types::ScheduledRun::Executed(execute_proposal(
+                &env,
+                &env.current_contract_address(),
+                &project_key,
+                proposal_id,
+                None,
+                None,
+                true,
+            ))
+        }
+    }
+
+    /// Transfer tokens from the treasury of a project on behalf of the
+    /// governance contract.
+    ///
+    /// Used by councils and funding proposals, which the governance contract
+    /// authorizes. The transfer is published as with `DaoAction::Transfer`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `executor` - The address acting for the DAO
+    /// * `token` - The token contract
+    /// * `recipient` - The address receiving the tokens
+    /// * `amount` - The amount taken from the treasury
+    ///
+    /// # Panics
+    /// * If the governance contract did not authorize the call
+    /// * If the amount exceeds the treasury
+    fn spend_treasury(
+        env: Env,
+        project_key: Bytes,
+        executor: Address,
+        token: Address,
+        recipient: Address,
+        amount: i128,
+    ) {
+        crate::retrieve_contract(&env, types::ContractKey::GovernanceContract)
+            .address
+            .require_auth();
+
+        apply_action(
+            &env,
+            &project_key,
+            &executor,
+            types::DaoAction::Transfer(token, recipient, amount),
+        );
+    }
+
+    /// Credit tokens transferred back by the governance contract to the
+    /// treasury of a project, e.g. the unclaimed part of a reward pool.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `token` - The token contract
+    /// * `amount` - The amount transferred back
+    ///
+    /// # Panics
+    /// * If the governance contract did not authorize the call
+    fn refund_treasury(env: Env, project_key: Bytes, token: Address, amount: i128) {
+        crate::retrieve_contract(&env, types::ContractKey::GovernanceContract)
+            .address
+            .require_auth();
+
+        credit_treasury(&env, &project_key, &token, amount);
+    }
+
+    /// Record a new proposal of an address created with the governance
+    /// contract, e.g. a funding proposal.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `proposer` - Address of the proposal creator
+    ///
+    /// # Panics
+    /// * If the governance contract did not authorize the call
+    /// * If the proposer created a proposal less than the cooldown ago
+    fn use_proposal_cooldown(env: Env, project_key: Bytes, proposer: Address) {
+        crate::retrieve_contract(&env, types::ContractKey::GovernanceContract)
+            .address
+            .require_auth();
+
+        let governance = Self::get_governance_config(env.clone(), project_key.clone());
+        require_proposal_cooldown(&env, &project_key, &proposer, governance.proposal_cooldown);
+    }
+
+    /// Take the collateral of a hashed vote, see `commit_vote` of the
+    /// governance contract.
+    ///
+    /// The collateral is kept for the vote when revealed, see
+    /// `cast_revealed_vote`, and settled when the proposal is closed.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `voter` - The address of the voter
+    ///
+    /// # Panics
+    /// * If the governance contract did not authorize the call
+    /// * If the collateral cannot be taken
+    fn take_vote_collateral(env: Env, voter: Address) {
+        crate::retrieve_contract(&env, types::ContractKey::GovernanceContract)
+            .address
+            .require_auth();
+
+        take_vote_collateral(&env, &voter);
+    }
+
+    /// Record a hashed vote revealed after the voting period, see
+    /// `reveal_vote` of the governance contract.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `voter` - The address of the voter
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal
+    /// * `vote` - The revealed vote
+    ///
+    /// # Panics
+    /// * If the governance contract did not authorize the call
+    /// * If the vote could not be recorded, see `vote`
+    fn cast_revealed_vote(
+        env: Env,
+        voter: Address,
+        project_key: Bytes,
+        proposal_id: u32,
+        vote: types::PublicVote,
+    ) {
+        crate::retrieve_contract(&env, types::ContractKey::GovernanceContract)
+            .address
+            .require_auth();
+
+        cast_vote(
+            &env,
+            voter,
+            project_key,
+            proposal_id,
+            types::Vote::PublicVote(vote),
+            true,
+        );
+    }
+
+    /// Verify vote commitment proof for anonymous voting.
+    ///
+    /// Validates that the provided tallies and seeds match the vote commitments
+    /// without revealing individual votes. This ensures the integrity of anonymous
+    /// voting results.
+    ///
+    /// The commitment is:
+    ///
+    /// C = g^v * h^r (in additive notation: g*v + h*r),
+    ///
+    /// where g, h are BLS12-381 generator points and v is the vote choice,
+    /// r is the seed. Voting weight is introduced during the tallying phase.
+    /// Seeds are full scalars of the BLS12-381 field, so their weighted sums
+    /// are given modulo the order of the field.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `proposal` - The proposal containing vote commitments
+    /// * `tallies` - Decoded tally values [approve, reject, abstain] (scaled by weights)
+    /// * `seeds` - Decoded seed values [approve, reject, abstain] (scaled by weights, modulo the field order)
+    ///
+    /// # Returns
+    /// * `bool` - True if all commitments match the provided tallies and seeds
+    ///
+    /// # Panics
+    /// * If no anonymous voting configuration exists for the project
+    fn proof(
+        env: Env,
+        project_key: Bytes,
+        proposal: types::Proposal,
+        tallies: Vec<u128>,
+        seeds: Vec<U256>,
+    ) -> bool {
+        // Proof validation only applies to active proposals (before execution)
+        if proposal.status != types::ProposalStatus::Active {
+            panic_with_error!(&env, &errors::ContractErrors::ProposalActive);
+        }
+
+        // we can only proof anonymous votes
+        if proposal.vote_data.public_voting {
+            panic_with_error!(&env, &errors::ContractErrors::WrongVoteType);
+        }
+
+        let bls12_381 = env.crypto().bls12_381();
+
+        let vote_config: types::AnonymousVoteConfig = env
+            .storage()
+            .instance()
+            .get(&types::ProjectKey::AnonymousVoteConfig(project_key))
+            .unwrap_or_else(|| {
+                panic_with_error!(&env, &errors::ContractErrors::NoAnonymousVotingConfig);
+            });
+
+        let seed_generator_point = G1Affine::from_bytes(vote_config.seed_generator_point);
+        let vote_generator_point = G1Affine::from_bytes(vote_config.vote_generator_point);
+
+        // calculate commitments from vote tally and seed tally
+        let mut commitment_checks = Vec::new(&env);
+        for it in tallies.iter().zip(seeds.iter()) {
+            let (tally_, seed_) = it;
+            let tally_: U256 = U256::from_u128(&env, tally_);
+            let seed_point_ = bls12_381.g1_mul(&seed_generator_point, &seed_.into());
+            let tally_commitment_votes_ = bls12_381.g1_mul(&vote_generator_point, &tally_.into());
+            let commitment_check_ = bls12_381.g1_add(&tally_commitment_votes_, &seed_point_);
+            commitment_checks.push_back(commitment_check_);
+        }
+
+        // tally commitments from recorded votes (vote + seed)
+        let mut g1_identity = [0u8; 96];
+        g1_identity[0] = 0x40;
+        let tally_commitment_init_ = G1Affine::from_bytes(BytesN::from_array(&env, &g1_identity));
+
+        let mut tally_commitments = [
+            tally_commitment_init_.clone(),
+            tally_commitment_init_.clone(),
+            tally_commitment_init_.clone(),
+        ];
+
+        for vote_ in proposal.vote_data.votes.iter() {
+            if let types::Vote::AnonymousVote(anonymous_vote) = &vote_ {
+                let weight_: U256 = U256::from_u32(&env, anonymous_vote.weight);
+                for (commitment, tally_commitment) in anonymous_vote
+                    .commitments
+                    .iter()
+                    .zip(tally_commitments.iter_mut())
+                {
+                    let commitment_ = G1Affine::from_bytes(commitment);
+                    // scale the commitment by the voter weight: g*v*weight + h*r*weight.
+                    let weighted_commitment =
+                        bls12_381.g1_mul(&commitment_, &weight_.clone().into());
+                    *tally_commitment = bls12_381.g1_add(tally_commitment, &weighted_commitment);
+                }
+            };
+        }
+
+        // compare commitments
+        for (commitment_check, tally_commitment) in
+            commitment_checks.iter().zip(tally_commitments.iter())
+        {
+            if commitment_check != *tally_commitment {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Returns a page of proposals (0 to MAX_PROPOSALS_PER_PAGE proposals per page).
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `page` - The page number (0-based)
+    ///
+    /// # Returns
+    /// * `types::Dao` - The DAO object containing a page of proposals
+    ///
+    /// # Panics
+    /// * If the page number is out of bounds
+    fn get_dao(env: Env, project_key: Bytes, page: u32) -> types::Dao {
+        if page >= MAX_PAGES {
+            panic_with_error!(&env, &errors::ContractErrors::NoProposalorPageFound);
+        }
+
+        let key_ = types::ProjectKey::Key(project_key.clone());
+        if env
+            .storage()
+            .persistent()
+            .get::<types::ProjectKey, types::Project>(&key_)
+            .is_some()
+        {
+            env.storage()
+                .persistent()
+                .get(&types::ProjectKey::Dao(project_key, page))
+                .unwrap_or(types::Dao {
+                    proposals: Vec::new(&env),
+                })
+        } else {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidKey);
+        }
+    }
+
+    /// Get a single proposal by ID.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal to retrieve
+    ///
+    /// # Returns
+    /// * `types::Proposal` - The proposal object
+    ///
+    /// # Panics
+    /// * If the proposal doesn't exist
+    fn get_proposal(env: Env, project_key: Bytes, proposal_id: u32) -> types::Proposal {
+        let page = proposal_id / MAX_PROPOSALS_PER_PAGE;
+        let sub_id = proposal_id % MAX_PROPOSALS_PER_PAGE;
+        let dao_page = Self::get_dao(env.clone(), project_key, page);
+        let proposals = dao_page.proposals;
+        match proposals.try_get(sub_id) {
+            Ok(Some(proposal)) => proposal,
+            _ => panic_with_error!(&env, &errors::ContractErrors::NoProposalorPageFound),
+        }
+    }
+
+    /// Get the status of a proposal.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal
+    ///
+    /// # Returns
+    /// * `types::ProposalStatus` - The status of the proposal
+    ///
+    /// # Panics
+    /// * If the proposal doesn't exist
+    fn get_proposal_status(
+        env: Env,
+        project_key: Bytes,
+        proposal_id: u32,
+    ) -> types::ProposalStatus {
+        Self::get_proposal(env, project_key, proposal_id).status
+    }
+
+    /// Count the votes cast so far on a public proposal.
+    ///
+    /// The weights are counted like on execution, with the current
+    /// governance configuration of the project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal
+    ///
+    /// # Returns
+    /// * `types::Tally` - The approve, reject and abstain weights
+    ///
+    /// # Panics
+    /// * If the proposal doesn't exist
+    /// * If the proposal uses anonymous voting
+    fn tally(env: Env, project_key: Bytes, proposal_id: u32) -> types::Tally {
+        let proposal = Self::get_proposal(env.clone(), project_key.clone(), proposal_id);
+        if !proposal.vote_data.public_voting {
+            panic_with_error!(&env, &errors::ContractErrors::WrongVoteType);
+        }
+
+        let governance = Tansu::get_governance_config(env, project_key);
+        let (approve, reject, abstain) = public_tallies(&proposal, &governance);
+        types::Tally {
+            approve,
+            reject,
+            abstain,
+        }
+    }
+
+    /// Get the participation in the vote of a proposal.
+    ///
+    /// The turnout can exceed 100% when the weight of the members grew
+    /// after the creation of the proposal.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal
+    ///
+    /// # Returns
+    /// * `types::Turnout` - The weight cast, the eligible weight and their
+    ///   ratio
+    ///
+    /// # Panics
+    /// * If the proposal doesn't exist
+    fn get_turnout(env: Env, project_key: Bytes, proposal_id: u32) -> types::Turnout {
+        let proposal = Self::get_proposal(env, project_key, proposal_id);
+        let weight_cast = weight_cast(&proposal);
+        let eligible_weight = proposal.vote_data.eligible_weight;
+        let turnout_bps = match eligible_weight {
+            0 => 0,
+            _ => (weight_cast * TOTAL_WEIGHT_BPS as u128 / eligible_weight)
+                .try_into()
+                .unwrap_or(u32::MAX),
+        };
+        types::Turnout {
+            weight_cast,
+            eligible_weight,
+            turnout_bps,
+        }
+    }
+
+    /// Get the vote of an address on a proposal.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `proposal_id` - The ID of the proposal
+    /// * `voter` - The address of the voter
+    ///
+    /// # Returns
+    /// * `Option<types::Vote>` - The vote, with its commitments for anonymous
+    ///   voting, `None` if the address did not vote itself
+    ///
+    /// # Panics
+    /// * If the proposal doesn't exist
+    fn get_vote(
+        env: Env,
+        project_key: Bytes,
+        proposal_id: u32,
+        voter: Address,
+    ) -> Option<types::Vote> {
+        let proposal = Self::get_proposal(env, project_key, proposal_id);
+        proposal.vote_data.votes.iter().find(|vote_| match vote_ {
+            types::Vote::PublicVote(vote_choice) => vote_choice.address == voter,
+            types::Vote::AnonymousVote(vote_choice) => vote_choice.address == voter,
+        })
+    }
+
+    /// List the proposals of a project, most recent first.
+    ///
+    /// At most `MAX_PROPOSALS_SCANNED` proposals are scanned per call, so a
+    /// page can hold fewer proposals than the limit while a next cursor is
+    /// returned.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `status` - Only list proposals with this status, all if `None`
+    /// * `cursor` - ID of the first proposal to scan, `None` to start from
+    ///   the latest proposal
+    /// * `limit` - Maximum number of proposals, at most
+    ///   `MAX_PROPOSALS_PER_QUERY`
+    ///
+    /// # Returns
+    /// * `types::ProposalPage` - The proposals and the cursor of the next page
+    fn get_proposals(
+        env: Env,
+        project_key: Bytes,
+        status: Option<types::ProposalStatus>,
+        cursor: Option<u32>,
+        limit: u32,
+    ) -> types::ProposalPage {
+        let count: u32 = env
+            .storage()
+            .persistent()
+            .get(&types::ProjectKey::DaoTotalProposals(project_key.clone()))
+            .unwrap_or(0);
+        let limit = limit.min(types::MAX_PROPOSALS_PER_QUERY);
+
+        let mut proposals = Vec::new(&env);
+        if count == 0 || limit == 0 {
+            return types::ProposalPage {
+                proposals,
+                next_cursor: None,
+            };
+        }
+
+        let start = cursor.unwrap_or(u32::MAX).min(count - 1);
+        let last = (start + 1).saturating_sub(types::MAX_PROPOSALS_SCANNED);
+        let mut dao_page: Option<(u32, types::Dao)> = None;
+        let mut proposal_id = start;
+        loop {
+            let page = proposal_id / MAX_PROPOSALS_PER_PAGE;
+            if dao_page.as_ref().is_none_or(|(loaded, _)| *loaded != page) {
+                dao_page = Some((page, Self::get_dao(env.clone(), project_key.clone(), page)));
+            }
+            if let Some((_, dao)) = &dao_page
+                && let Some(proposal) = dao.proposals.get(proposal_id % MAX_PROPOSALS_PER_PAGE)
+                && status
+                    .as_ref()
+                    .is_none_or(|status| proposal.status == *status)
+            {
+                proposals.push_back(proposal);
+            }
+
+            if proposal_id == 0 {
+                return types::ProposalPage {
+                    proposals,
+                    next_cursor: None,
+                };
+            }
+            proposal_id -= 1;
+            if proposals.len() == limit || proposal_id < last {
+                return types::ProposalPage {
+                    proposals,
+                    next_cursor: Some(proposal_id),
+                };
+            }
+        }
+    }
+}
+
+/// Record a vote on a proposal, see `vote`.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `voter` - The address of the voter, already authorized
+/// * `project_key` - The project key identifier
+/// * `proposal_id` - The ID of the proposal to vote on
+/// * `vote` - The vote data (public or anonymous)
+/// * `revealed` - Whether the vote was committed during the voting period
+///   and is revealed now, see `cast_revealed_vote`. The collateral taken
+///   with the commitment is then kept for the vote
+///
+/// # Panics
+/// * Same as `vote`
+/// * If the proposal requires hashed votes and the vote is not revealed
+pub fn cast_vote(
+    env: &Env,
+    voter: Address,
+    project_key: Bytes,
+    proposal_id: u32,
+    vote: types::Vote,
+    revealed: bool,
+) {
+    let page = proposal_id / MAX_PROPOSALS_PER_PAGE;
+    let sub_id = proposal_id % MAX_PROPOSALS_PER_PAGE;
+    let mut dao_page = Tansu::get_dao(env.clone(), project_key.clone(), page);
+    let mut proposal = match dao_page.proposals.try_get(sub_id) {
+        Ok(Some(proposal)) => proposal,
+        _ => panic_with_error!(env, &errors::ContractErrors::NoProposalorPageFound),
+    };
+
+    if proposal.status != types::ProposalStatus::Active {
+        panic_with_error!(env, &errors::ContractErrors::ProposalActive);
+    }
+
+    // revealed votes were committed before the end of the voting period,
+    // once the proposal was sponsored
+    if !revealed {
+        if !governance::client(env).is_sponsored(&project_key, &proposal_id) {
+            panic_with_error!(env, &errors::AdditionalErrors::ProposalNotSponsored);
+        }
+        if takes_hashed_votes(&proposal) {
+            panic_with_error!(env, &errors::ContractErrors::WrongVoteType);
+        }
+        if env.ledger().timestamp() >= proposal.vote_data.voting_ends_at {
+            panic_with_error!(env, &errors::ContractErrors::ProposalVotingTime);
+        }
+    }
+
+    // Check vote limits for DoS protection
+    if proposal.vote_data.votes.len() >= MAX_VOTES_PER_PROPOSAL {
+        panic_with_error!(env, &errors::ContractErrors::VoteLimitExceeded);
+    }
+
+    // only allow to vote once per voter, including through a delegate
+    let delegated_votes_key = types::ProjectKey::DelegatedVotes(project_key.clone(), proposal_id);
+    let mut delegated_votes: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&delegated_votes_key)
+        .unwrap_or(Vec::new(env));
+    if has_voted(&proposal, &voter) || delegated_votes.contains(&voter) {
+        panic_with_error!(env, &errors::ContractErrors::AlreadyVoted);
+    }
+
+    let delegations = Tansu::get_delegations(env.clone(), project_key.clone());
+    if delegations.contains_key(voter.clone()) {
+        panic_with_error!(env, &errors::ExtendedErrors::VoteDelegated);
+    }
+
+    // proposals are either public or anonymous so only a single type of vote
+    // can be registered for a given proposal
+    let is_public_vote = matches!(vote, types::Vote::PublicVote(_));
+    if is_public_vote != proposal.vote_data.public_voting {
+        panic_with_error!(env, &errors::ContractErrors::WrongVoteType);
+    }
+
+    // For anonymous votes, validate commitment structure
+    if !is_public_vote && let types::Vote::AnonymousVote(vote_choice) = &vote {
+        if vote_choice.commitments.len() != 3 {
+            panic_with_error!(env, &errors::ContractErrors::BadCommitment)
+        }
+        for commitment in &vote_choice.commitments {
+            G1Affine::from_bytes(commitment);
+        }
+    }
+
+    // can only vote for yourself so address must match
+    let vote_address = match &vote {
+        types::Vote::PublicVote(vote_choice) => &vote_choice.address,
+        types::Vote::AnonymousVote(vote_choice) => &vote_choice.address,
+    };
+    if vote_address != &voter {
+        panic_with_error!(env, &errors::ContractErrors::WrongVoter);
+    }
+    if let types::Vote::PublicVote(vote_choice) = &vote {
+        validate_choice(env, &proposal, &vote_choice.vote_choice);
+    }
+
+    // Voter can use up to their max allowed voting weight
+    let vote_weight = match &vote {
+        types::Vote::PublicVote(vote_choice) => &vote_choice.weight,
+        types::Vote::AnonymousVote(vote_choice) => &vote_choice.weight,
+    };
+
+    // weights are taken from the snapshot of the proposal so that badge
+    // changes during the vote have no effect
+    let governance = Tansu::get_governance_config(env.clone(), project_key.clone());
+    let voter_max_weight = Tansu::get_voting_weight(
+        env.clone(),
+        project_key.clone(),
+        proposal_id,
+        vote_address.clone(),
+    );
+
+    if voter_max_weight == 0 {
+        panic_with_error!(env, &errors::ContractErrors::UnknownMember);
+    }
+
+    // delegations are resolved when voting
+    let delegated_count = delegated_votes.len();
+    let mut voter_max_weight = capped_weight(&proposal, voter_max_weight, &governance);
+    for (delegator, delegate) in delegations.iter() {
+        if delegate == voter && !has_voted(&proposal, &delegator) {
+            let weight = Tansu::get_voting_weight(
+                env.clone(),
+                project_key.clone(),
+                proposal_id,
+                delegator.clone(),
+            );
+            voter_max_weight += capped_weight(&proposal, weight, &governance);
+            delegated_votes.push_back(delegator);
+        }
+    }
+    if delegated_votes.len() > delegated_count {
+        env.storage()
+            .persistent()
+            .set(&delegated_votes_key, &delegated_votes);
+    }
+
+    if vote_weight > &voter_max_weight {
+        panic_with_error!(env, &errors::ContractErrors::VoterWeight);
+    }
+
+    if !revealed {
+        take_vote_collateral(env, &voter);
+    }
+    // Record the vote
+    proposal.vote_data.votes.push_back(vote.clone());
+
+    dao_page.proposals.set(sub_id, proposal);
+
+    env.storage().persistent().set(
+        &types::ProjectKey::Dao(project_key.clone(), page),
+        &dao_page,
+    );
+
+    contract_membership::add_reputation(
+        env,
+        &project_key,
+        &voter,
+        contract_membership::REPUTATION_PER_VOTE,
+    );
+
+    events::VoteCast {
+        project_key,
+        proposal_id,
+        voter,
+    }
+    .publish(env);
+}
+
+/// Whether a proposal takes hashed public votes, see `commit_vote` of the
+/// governance contract.
+pub fn takes_hashed_votes(proposal: &types::Proposal) -> bool {
+    proposal.vote_data.public_voting && proposal.reveal_ends_at.is_some()
+}
+
+/// End of the vote on a proposal.
+///
+/// Proposals taking hashed votes close at the end of their reveal period.
+/// The tallies of anonymous proposals are revealed once the vote is closed.
+///
+/// # Arguments
+/// * `proposal` - The proposal
+///
+/// # Returns
+/// * `u64` - UNIX timestamp after which no vote can be counted anymore
+pub fn vote_closes_at(proposal: &types::Proposal) -> u64 {
+    match (takes_hashed_votes(proposal), proposal.reveal_ends_at) {
+        (true, Some(reveal_ends_at)) => reveal_ends_at,
+        _ => proposal.vote_data.voting_ends_at,
+    }
+}
+
+/// Authorize an address to execute the proposals of a project.
+///
+/// Anyone can execute the proposals of DAO controlled projects, only admins
+/// otherwise.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `executor` - The address executing proposals
+/// * `project_key` - The project key identifier
+///
+/// # Panics
+/// * If the executor is not authorized
+/// * If the executor is not an admin of a project not controlled by its DAO
+pub fn auth_executor(env: &Env, executor: &Address, project_key: &Bytes) {
+    if <Tansu as VersioningTrait>::is_dao_controlled(env.clone(), project_key.clone()) {
+        executor.require_auth();
+    } else {
+        crate::auth_maintainers_role(env, executor, project_key, types::MaintainerRole::Admin);
+    }
+}
+
+/// Whether a scheduled proposal can be executed, its veto period over and
+/// its dependency resolved.
+fn is_ready(
+    env: &Env,
+    project_key: &Bytes,
+    proposal_id: u32,
+    governance: &types::GovernanceConfig,
+) -> bool {
+    let proposal = Tansu::get_proposal(env.clone(), project_key.clone(), proposal_id);
+    let vetoable = env.ledger().timestamp() < vote_closes_at(&proposal) + governance.veto_period;
+    let dependency_pending = proposal.depends_on.is_some_and(|depends_on| {
+        Tansu::get_proposal(env.clone(), project_key.clone(), depends_on).status
+            == types::ProposalStatus::Active
+    });
+    !vetoable && !dependency_pending
+}
+
+/// Execute a proposal once its executor is authorized, see `execute`.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `executor` - The address executing the proposal
+/// * `project_key` - The project key identifier
+/// * `proposal_id` - The ID of the proposal to execute
+/// * `tallies` - Decoded tally values of anonymous votes
+/// * `seeds` - Decoded seed values of anonymous votes
+/// * `best_effort_outcome` - Whether a failing outcomes contract is only
+///   reported by an event instead of failing the execution
+///
+/// # Returns
+/// * `types::ProposalStatus` - The final status of the proposal
+///
+/// # Panics
+/// * Same as `execute`, authorization aside
+pub fn execute_proposal(
+    env: &Env,
+    executor: &Address,
+    project_key: &Bytes,
+    proposal_id: u32,
+    tallies: Option<Vec<u128>>,
+    seeds: Option<Vec<U256>>,
+    best_effort_outcome: bool,
+) -> types::ProposalStatus {
+    let page = proposal_id / MAX_PROPOSALS_PER_PAGE;
+    let sub_id = proposal_id % MAX_PROPOSALS_PER_PAGE;
+    let mut dao_page = Tansu::get_dao(env.clone(), project_key.clone(), page);
+    let mut proposal = match dao_page.proposals.try_get(sub_id) {
+        Ok(Some(proposal)) => proposal,
+        _ => panic_with_error!(env, &errors::ContractErrors::NoProposalorPageFound),
+    };
+
+    let curr_timestamp = env.ledger().timestamp();
+
+    // only allow to execute once
+    if proposal.status != types::ProposalStatus::Active {
+        panic_with_error!(env, &errors::ContractErrors::ProposalActive);
+    }
+
+    refund_collaterals(env, &proposal);
+    settle_commitments(env, project_key, proposal_id, true);
+
+    // tally to results, anonymous tallies which were not revealed in time
+    // are not counted anymore
+    let governance = Tansu::get_governance_config(env.clone(), project_key.clone());
+    let unrevealed = !proposal.vote_data.public_voting
+        && proposal
+            .reveal_ends_at
+            .is_some_and(|reveal_ends_at| curr_timestamp >= reveal_ends_at);
+    let voted = match proposal.vote_data.public_voting {
+        true => {
+            if tallies.is_some() || seeds.is_some() {
+                panic_with_error!(env, &errors::ContractErrors::TallySeedError);
+            }
+            public_tallies(&proposal, &governance)
+        }
+        false => {
+            // tallies are revealed once the vote is over, until the end of
+            // the reveal period
+            if curr_timestamp < proposal.vote_data.voting_ends_at {
+                panic_with_error!(env, &errors::ContractErrors::ProposalVotingTime);
+            }
+            match (tallies, seeds) {
+                (None, None) if unrevealed => (0, 0, 0),
+                _ if unrevealed => {
+                    panic_with_error!(env, &errors::ContractErrors::ProposalVotingTime)
+                }
+                (Some(tallies_), Some(seeds_)) => {
+                    // Validate tallies and seeds have expected length (3: approve, reject, abstain)
+                    if tallies_.len() != 3 || seeds_.len() != 3 {
+                        panic_with_error!(env, &errors::ContractErrors::TallySeedError);
+                    }
+
+                    if !Tansu::proof(
+                        env.clone(),
+                        project_key.clone(),
+                        proposal.clone(),
+                        tallies_.clone(),
+                        seeds_,
+                    ) {
+                        panic_with_error!(env, &errors::ContractErrors::InvalidProof)
+                    }
+                    anonymous_tallies(&tallies_)
+                }
+                _ => panic_with_error!(env, &errors::ContractErrors::TallySeedError),
+            }
+        }
+    };
+
+    // before the end of the voting period, only settled outcomes. Hashed
+    // votes can change the outcome until the end of the reveal period
+    if curr_timestamp < vote_closes_at(&proposal)
+        && (takes_hashed_votes(&proposal) || !is_settled(&proposal, voted, &governance))
+    {
+        panic_with_error!(env, &errors::ContractErrors::ProposalVotingTime);
+    }
+    proposal.status = match unrevealed {
+        true => types::ProposalStatus::Expired,
+        false => resolve_status(&mut proposal, voted, &governance),
+    };
+    // maintainers can still veto approved proposals
+    if proposal.status == types::ProposalStatus::Approved
+        && governance.veto_period > 0
+        && curr_timestamp < vote_closes_at(&proposal) + governance.veto_period
+    {
+        panic_with_error!(env, &errors::ContractErrors::ProposalVotingTime);
+    }
+    // dependent proposals are only approved after their dependency
+    if proposal.status == types::ProposalStatus::Approved
+        && let Some(depends_on) = proposal.depends_on
+    {
+        match Tansu::get_proposal(env.clone(), project_key.clone(), depends_on).status {
+            types::ProposalStatus::Approved => (),
+            types::ProposalStatus::Active => {
+                panic_with_error!(env, &errors::ExtendedErrors::DependencyPending)
+            }
+            _ => proposal.status = types::ProposalStatus::Cancelled,
+        }
+    }
+    // stale approvals are not applied anymore
+    if proposal.status == types::ProposalStatus::Approved
+        && governance.execution_window > 0
+        && curr_timestamp
+            > vote_closes_at(&proposal) + governance.veto_period + governance.execution_window
+    {
+        proposal.status = types::ProposalStatus::Expired;
+    }
+    settle_deposit(
+        env,
+        project_key,
+        &proposal,
+        !unrevealed && is_spam(voted, &governance),
+    );
+    crate::update_project_stats(env, project_key, |stats| {
+        stats.executed_proposals += 1;
+        stats.weight_cast += weight_cast(&proposal);
+        stats.eligible_weight += proposal.vote_data.eligible_weight;
+    });
+
+    dao_page.proposals.set(sub_id, proposal.clone());
+
+    env.storage().persistent().set(
+        &types::ProjectKey::Dao(project_key.clone(), page),
+        &dao_page,
+    );
+
+    // text proposals are advisory, others carry the action to apply
+    let action = env
+        .storage()
+        .persistent()
+        .get(&types::ProjectKey::ProposalAction(
+            project_key.clone(),
+            proposal_id,
+        ));
+    clear_proposal_data(env, project_key, proposal_id);
+    if proposal.kind != types::ProposalKind::Text
+        && proposal.status == types::ProposalStatus::Approved
+        && let Some(action) = action
+    {
+        match action {
+            types::DaoAction::Commit(hash)
+                if !<Tansu as VersioningTrait>::is_dao_controlled(
+                    env.clone(),
+                    project_key.clone(),
+                ) =>
+            {
+                env.storage().persistent().set(
+                    &types::ProjectKey::CommitProposal(project_key.clone(), proposal_id),
+                    &(hash, false),
+                );
+            }
+            action => apply_action(env, project_key, executor, action),
+        }
+    }
+
+    events::ProposalExecuted {
+        project_key: project_key.clone(),
+        proposal_id,
+        status: match proposal.status {
+            types::ProposalStatus::Active => String::from_str(env, "Active"),
+            types::ProposalStatus::Approved => String::from_str(env, "Approved"),
+            types::ProposalStatus::Rejected => String::from_str(env, "Rejected"),
+            types::ProposalStatus::Cancelled => String::from_str(env, "Cancelled"),
+            types::ProposalStatus::Malicious => String::from_str(env, "Malicious"),
+            types::ProposalStatus::Vetoed => String::from_str(env, "Vetoed"),
+            types::ProposalStatus::Expired => String::from_str(env, "Expired"),
+        },
+        maintainer: executor.clone(),
+    }
+    .publish(env);
+
+    if let Some(outcomes_contract) = &proposal.outcomes_contract {
+        let client = outcomes_contract::Client::new(env, outcomes_contract);
+
+        if best_effort_outcome {
+            let (function, result) = match proposal.status {
+                types::ProposalStatus::Approved => {
+                    ("approve_outcome", client.try_approve_outcome(executor))
+                }
+                types::ProposalStatus::Rejected => {
+                    ("reject_outcome", client.try_reject_outcome(executor))
+                }
+                types::ProposalStatus::Cancelled | types::ProposalStatus::Expired => {
+                    ("abstain_outcome", client.try_abstain_outcome(executor))
+                }
+                _ => return proposal.status,
+            };
+
+            events::OutcomesContractInvoked {
+                project_key: project_key.clone(),
+                proposal_id,
+                contract: outcomes_contract.clone(),
+                function: Symbol::new(env, function),
+                success: result.is_ok_and(|result| result.is_ok()),
+            }
+            .publish(env);
+        } else {
+            match proposal.status {
+                types::ProposalStatus::Approved => client.approve_outcome(executor),
+                types::ProposalStatus::Rejected => client.reject_outcome(executor),
+                types::ProposalStatus::Cancelled | types::ProposalStatus::Expired => {
+                    client.abstain_outcome(executor)
+                }
+                _ => (),
+            };
+        }
+    }
+
+    proposal.status
+}
+
+/// Check that a public proposal can be executed without failing.
+///
+/// Panics cannot be caught within the contract, so the conditions under
+/// which `execute_proposal` or the action of the proposal would fail are
+/// checked beforehand. Used by `run_scheduled` to skip the proposals which cannot
+/// be executed anymore.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `proposal_id` - The ID of the proposal
+///
+/// # Returns
+/// * `Option<u32>` - The code of the error the execution would fail with
+pub fn check_execution(env: &Env, project_key: &Bytes, proposal_id: u32) -> Option<u32> {
+    let proposal = Tansu::get_proposal(env.clone(), project_key.clone(), proposal_id);
+    if proposal.status != types::ProposalStatus::Active {
+        return Some(errors::ContractErrors::ProposalActive as u32);
+    }
+    if !proposal.vote_data.public_voting {
+        return Some(errors::ContractErrors::TallySeedError as u32);
+    }
+    let governance = Tansu::get_governance_config(env.clone(), project_key.clone());
+    if proposal.kind == types::ProposalKind::Text
+        || public_outcome(&proposal, &governance) != types::ProposalStatus::Approved
+    {
+        return None;
+    }
+    let action: types::DaoAction =
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::ProposalAction(
+                project_key.clone(),
+                proposal_id,
+            ))?;
+
+    let treasury = Tansu::get_treasury(env.clone(), project_key.clone());
+    match action {
+        types::DaoAction::Transfer(token, _, amount)
+            if treasury.get(token.clone()).unwrap_or(0) < amount =>
+        {
+            Some(errors::ExtendedErrors::InsufficientTreasury as u32)
+        }
+        types::DaoAction::FundReward(reward_id, token, amount) => {
+            if treasury.get(token.clone()).unwrap_or(0) < amount {
+                return Some(errors::ExtendedErrors::InsufficientTreasury as u32);
+            }
+            match governance::client(env).get_proposal_reward(project_key, &reward_id) {
+                Some(pool) if pool.token != token => {
+                    Some(errors::ExtendedErrors::InvalidRewardPool as u32)
+                }
+                _ => None,
+            }
+        }
+        action => registry::release_action(&action)
+            .and_then(|action| registry::client(env).check_release_action(project_key, &action)),
+    }
+}
+
+/// Status of a proposal given its tallies, ignoring its timing and dependency.
+///
+/// Sets the winning option of multiple-choice proposals.
+fn resolve_status(
+    proposal: &mut types::Proposal,
+    voted: (u128, u128, u128),
+    governance: &types::GovernanceConfig,
+) -> types::ProposalStatus {
+    let (voted_approve, voted_reject, voted_abstain) = voted;
+    if proposal.options.is_empty() {
+        tallies_to_result(
+            voted_approve,
+            voted_reject,
+            voted_abstain,
+            approval_threshold(proposal, governance),
+            governance,
+        )
+    } else {
+        proposal.winning_option = winning_option(proposal, governance)
+            .filter(|_| quorum_weight(voted, governance) >= governance.quorum as u128);
+        match proposal.winning_option {
+            Some(_) => types::ProposalStatus::Approved,
+            None => types::ProposalStatus::Cancelled,
+        }
+    }
+}
+
+/// Status a public proposal would get from its current votes.
+///
+/// # Arguments
+/// * `proposal` - The proposal
+/// * `governance` - The governance configuration of its project
+///
+/// # Returns
+/// * `types::ProposalStatus` - The status resulting from the votes
+pub fn public_outcome(
+    proposal: &types::Proposal,
+    governance: &types::GovernanceConfig,
+) -> types::ProposalStatus {
+    let voted = public_tallies(proposal, governance);
+    resolve_status(&mut proposal.clone(), voted, governance)
+}
+
+/// Take the collateral of a vote, or of a commitment, from the voter.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `voter` - The address of the voter
+///
+/// # Panics
+/// * If the transfer fails
+pub fn take_vote_collateral(env: &Env, voter: &Address) {
+    let sac_contract = crate::retrieve_contract(env, types::ContractKey::CollateralContract);
+    let token_stellar = token::StellarAssetClient::new(env, &sac_contract.address);
+    match token_stellar.try_transfer(voter, env.current_contract_address(), &VOTE_COLLATERAL) {
+        Ok(..) => (),
+        _ => panic_with_error!(env, &errors::ContractErrors::CollateralError),
+    }
+}
+
+/// Credit the unclaimed part of the reward pool of a proposal closed before
+/// its end back to the treasury of the project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `proposal_id` - The ID of the proposal
+fn refund_reward_pool(env: &Env, project_key: &Bytes, proposal_id: u32) {
+    if let Some(pool) = governance::client(env).refund_reward_pool(project_key, &proposal_id) {
+        let amount = pool.amount - pool.claimed;
+        if amount > 0 {
+            credit_treasury(env, project_key, &pool.token, amount);
+        }
+    }
+}
+
+/// Settle the collaterals of the commitments of a proposal which were not
+/// revealed, see `commit_vote` of the governance contract.
+///
+/// Once the reveal period is over, the collaterals are slashed to the
+/// treasury of the project. Proposals closed earlier refund them.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `proposal_id` - The ID of the proposal
+/// * `slash` - Whether the collaterals are slashed
+///
+/// # Panics
+/// * If a refund fails
+fn settle_commitments(env: &Env, project_key: &Bytes, proposal_id: u32, slash: bool) {
+    let voters = governance::client(env).close_proposal(project_key, &proposal_id);
+    if voters.is_empty() {
+        return;
+    }
+    let sac_contract = crate::retrieve_contract(env, types::ContractKey::CollateralContract);
+    if !slash {
+        let token_stellar = token::StellarAssetClient::new(env, &sac_contract.address);
+        for voter in voters.iter() {
+            match token_stellar.try_transfer(
+                &env.current_contract_address(),
+                &voter,
+                &VOTE_COLLATERAL,
+            ) {
+                Ok(..) => (),
+                _ => panic_with_error!(env, &errors::ContractErrors::CollateralError),
+            }
+        }
+        return;
+    }
+
+    let amount = VOTE_COLLATERAL * voters.len() as i128;
+    credit_treasury(env, project_key, &sac_contract.address, amount);
+
+    events::CommitmentsSlashed {
+        project_key: project_key.clone(),
+        proposal_id,
+        voters: voters.len(),
+        amount,
+    }
+    .publish(env);
+}
+
+/// Refund the collaterals of the proposer and the voters of a proposal.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `proposal` - The proposal
+///
+/// # Panics
+/// * If a transfer fails
+fn refund_collaterals(env: &Env, proposal: &types::Proposal) {
+    // proposers get its collateral back
+    let sac_contract = crate::retrieve_contract(env, types::ContractKey::CollateralContract);
+    let token_stellar = token::StellarAssetClient::new(env, &sac_contract.address);
+    match token_stellar.try_transfer(
+        &env.current_contract_address(),
+        &proposal.proposer,
+        &PROPOSAL_COLLATERAL,
+    ) {
+        Ok(..) => (),
+        _ => panic_with_error!(env, &errors::ContractErrors::CollateralError),
+    }
+
+    // all voters get their collateral back
+    for vote_ in &proposal.vote_data.votes {
+        let vote_address = match &vote_ {
+            types::Vote::PublicVote(vote_choice) => &vote_choice.address,
+            types::Vote::AnonymousVote(vote_choice) => &vote_choice.address,
+        };
+        match token_stellar.try_transfer(
+            &env.current_contract_address(),
+            vote_address,
+            &VOTE_COLLATERAL,
+        ) {
+            Ok(..) => (),
+            _ => panic_with_error!(env, &errors::ContractErrors::CollateralError),
+        }
+    }
+}
+
+/// Settle the deposit of the proposer of a proposal.
+///
+/// The deposit is refunded to the proposer, or slashed to the treasury of
+/// the project if the proposal was spam.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `proposal` - The proposal
+/// * `slash` - Whether the deposit is slashed
+///
+/// # Panics
+/// * If the refund fails
+fn settle_deposit(env: &Env, project_key: &Bytes, proposal: &types::Proposal, slash: bool) {
+    if proposal.deposit == 0 {
+        return;
+    }
+    let sac_contract = crate::retrieve_contract(env, types::ContractKey::CollateralContract);
+    if !slash {
+        let token_stellar = token::StellarAssetClient::new(env, &sac_contract.address);
+        if token_stellar
+            .try_transfer(
+                &env.current_contract_address(),
+                &proposal.proposer,
+                &proposal.deposit,
+            )
+            .is_err()
+        {
+            panic_with_error!(env, &errors::ContractErrors::CollateralError);
+        }
+        return;
+    }
+
+    credit_treasury(env, project_key, &sac_contract.address, proposal.deposit);
+
+    events::DepositSlashed {
+        project_key: project_key.clone(),
+        proposal_id: proposal.id,
+        proposer: proposal.proposer.clone(),
+        amount: proposal.deposit,
+    }
+    .publish(env);
+}
+
+/// Add tokens held by the contract to the treasury of a project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `token` - The token contract
+/// * `amount` - The amount added
+pub fn credit_treasury(env: &Env, project_key: &Bytes, token: &Address, amount: i128) {
+    let mut treasury = Tansu::get_treasury(env.clone(), project_key.clone());
+    let balance = treasury.get(token.clone()).unwrap_or(0);
+    treasury.set(token.clone(), balance + amount);
+    env.storage()
+        .persistent()
+        .set(&types::ProjectKey::Treasury(project_key.clone()), &treasury);
+}
+
+/// Hand the treasury of a project over to the maintainer removing it.
+///
+/// Tokens which cannot be transferred are abandoned so that no token can
+/// block the removal of the project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `maintainer` - The maintainer removing the project
+pub fn sweep_treasury(env: &Env, project_key: &Bytes, maintainer: &Address) {
+    for (token, amount) in Tansu::get_treasury(env.clone(), project_key.clone()).iter() {
+        if let Ok(Ok(())) = token::TokenClient::new(env, &token).try_transfer(
+            &env.current_contract_address(),
+            maintainer,
+            &amount,
+        ) {
+            events::TreasurySpent {
+                project_key: project_key.clone(),
+                executor: maintainer.clone(),
+                token,
+                recipient: maintainer.clone(),
+                amount,
+            }
+            .publish(env);
+        }
+    }
+    env.storage()
+        .persistent()
+        .remove(&types::ProjectKey::Treasury(project_key.clone()));
+}
+
+/// Take tokens out of the treasury of a project.
+///
+/// The tokens stay held by the contract, the caller moves them.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `token` - The token contract
+/// * `amount` - The amount taken
+///
+/// # Panics
+/// * If the treasury does not hold the amount
+pub fn debit_treasury(env: &Env, project_key: &Bytes, token: &Address, amount: i128) {
+    let mut treasury = Tansu::get_treasury(env.clone(), project_key.clone());
+    let balance = treasury.get(token.clone()).unwrap_or(0);
+    if balance < amount {
+        panic_with_error!(env, &errors::ExtendedErrors::InsufficientTreasury);
+    }
+    match balance - amount {
+        0 => {
+            treasury.remove(token.clone());
+        }
+        remaining => treasury.set(token.clone(), remaining),
+    }
+    let treasury_key = types::ProjectKey::Treasury(project_key.clone());
+    if treasury.is_empty() {
+        env.storage().persistent().remove(&treasury_key);
+    } else {
+        env.storage().persistent().set(&treasury_key, &treasury);
+    }
+}
+
+/// Remove the data attached to a proposal once it is not active anymore.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `proposal_id` - The ID of the proposal
+fn clear_proposal_data(env: &Env, project_key: &Bytes, proposal_id: u32) {
+    for key_ in [
+        types::ProjectKey::ProposalAction(project_key.clone(), proposal_id),
+        types::ProjectKey::DelegatedVotes(project_key.clone(), proposal_id),
+        types::ProjectKey::Vetoes(project_key.clone(), proposal_id),
+        types::ProjectKey::WeightSnapshot(project_key.clone(), proposal_id),
+    ] {
+        env.storage().persistent().remove(&key_);
+    }
+    if let Some(public_key) =
+        Tansu::get_proposal_anonymous_key(env.clone(), project_key.clone(), proposal_id)
+    {
+        reference_key(env, project_key, &public_key, -1);
+    }
+    index_active_proposal(env, project_key, proposal_id, false);
+}
+
+/// Ids of the active proposals of a project, oldest first.
+fn active_proposals(env: &Env, project_key: &Bytes) -> Vec<u32> {
+    env.storage()
+        .persistent()
+        .get(&types::DaoIndexKey::Active(project_key.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Add a proposal to the index of the active proposals of its project, or
+/// remove it once it is no longer active.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `proposal_id` - The ID of the proposal
+/// * `active` - Whether the proposal is active
+pub fn index_active_proposal(env: &Env, project_key: &Bytes, proposal_id: u32, active: bool) {
+    let index_key = types::DaoIndexKey::Active(project_key.clone());
+    let mut active_ids = active_proposals(env, project_key);
+    match (active, active_ids.first_index_of(proposal_id)) {
+        (true, None) => active_ids.push_back(proposal_id),
+        (false, Some(index)) => {
+            active_ids.remove(index);
+        }
+        _ => return,
+    }
+    if active_ids.is_empty() {
+        env.storage().persistent().remove(&index_key);
+    } else {
+        env.storage().persistent().set(&index_key, &active_ids);
+    }
+}
+
+/// Anonymous voting keys of a project, oldest first.
+///
+/// Projects set up before the history was recorded start with their key.
+fn anonymous_keys(env: &Env, project_key: &Bytes) -> Vec<types::AnonymousKey> {
+    let history = Tansu::get_anonymous_key_history(env.clone(), project_key.clone());
+    if !history.is_empty() {
+        return history;
+    }
+    match env
+        .storage()
+        .instance()
+        .get::<_, types::AnonymousVoteConfig>(&types::ProjectKey::AnonymousVoteConfig(
+            project_key.clone(),
+        )) {
+        Some(vote_config) => vec![
+            env,
+            types::AnonymousKey {
+                public_key: vote_config.public_key,
+                active_from: 0,
+            },
+        ],
+        None => history,
+    }
+}
+
+/// Whether active proposals encrypt their votes with a public key.
+fn is_key_referenced(env: &Env, project_key: &Bytes, public_key: &String) -> bool {
+    env.storage()
+        .persistent()
+        .has(&types::AnonymousKeyKey::References(
+            project_key.clone(),
+            public_key.clone(),
+        ))
+}
+
+/// Count a proposal in or out of the active proposals using a public key.
+fn reference_key(env: &Env, project_key: &Bytes, public_key: &String, delta: i32) {
+    let references_key =
+        types::AnonymousKeyKey::References(project_key.clone(), public_key.clone());
+    let references = env
+        .storage()
+        .persistent()
+        .get::<_, u32>(&references_key)
+        .unwrap_or(0)
+        .saturating_add_signed(delta);
+    if references == 0 {
+        env.storage().persistent().remove(&references_key);
+    } else {
+        env.storage().persistent().set(&references_key, &references);
+    }
+}
+
+/// Whether an address voted on a proposal.
+pub fn has_voted(proposal: &types::Proposal, address: &Address) -> bool {
+    proposal.vote_data.votes.iter().any(|vote_| match vote_ {
+        types::Vote::PublicVote(vote_choice) => vote_choice.address == *address,
+        types::Vote::AnonymousVote(vote_choice) => vote_choice.address == *address,
+    })
+}
+
+/// Total weight of the votes of a proposal, public or anonymous.
+fn weight_cast(proposal: &types::Proposal) -> u128 {
+    proposal
+        .vote_data
+        .votes
+        .iter()
+        .map(|vote_| match vote_ {
+            types::Vote::PublicVote(vote_choice) => vote_choice.weight as u128,
+            types::Vote::AnonymousVote(vote_choice) => vote_choice.weight as u128,
+        })
+        .sum()
+}
+
+/// Count the votes of a public voting proposal.
+///
+/// For public voting, all votes are visible and the tallies are calculated
+/// by summing the weighted votes for each choice. Votes for an option of a
+/// multiple-choice proposal count as approvals.
+///
+/// # Arguments
+/// * `proposal` - The proposal to count
+/// * `governance` - The governance configuration of the project
+///
+/// # Returns
+/// * `(u128, u128, u128)` - The approve, reject and abstain tallies
+pub fn public_tallies(
+    proposal: &types::Proposal,
+    governance: &types::GovernanceConfig,
+) -> (u128, u128, u128) {
+    // count votes
+    let mut voted_approve = 0;
+    let mut voted_reject = 0;
+    let mut voted_abstain = 0;
+    for vote_ in &proposal.vote_data.votes {
+        if let types::Vote::PublicVote(vote) = &vote_ {
+            let weight = tally_weight(vote.weight, governance.quadratic_voting);
+            match vote.vote_choice {
+                types::VoteChoice::Approve | types::VoteChoice::Choice(_) => {
+                    voted_approve += weight
+                }
+                types::VoteChoice::Reject => voted_reject += weight,
+                types::VoteChoice::Abstain => voted_abstain += weight,
+            };
+        }
+    }
+
+    (voted_approve, voted_reject, voted_abstain)
+}
+
+/// Weight of a vote in the tallies.
+///
+/// The square root limits the influence of members with a lot of weight:
+/// a hundred times the weight only gives ten times the say.
+///
+/// # Arguments
+/// * `weight` - The weight of the vote
+/// * `quadratic` - Whether the vote weighs the square root of its weight
+///
+/// # Returns
+/// * `u128` - The weight counted, rounded down
+fn tally_weight(weight: u32, quadratic: bool) -> u128 {
+    match quadratic {
+        true => weight.isqrt() as u128,
+        false => weight as u128,
+    }
 }
 
-/// Execute a public voting proposal.
+/// Weight a member can cast on a proposal, capped to a share of the
+/// eligible weight of the proposal if the project sets one.
 ///
-/// Helper function to determine the final status of a public voting proposal
-/// based on the vote counts. For public voting, all votes are visible and
-/// the result is calculated by summing the weighted votes for each choice.
+/// Proposals whose eligible weight is unknown, like proposals created before
+/// it was recorded, are not capped.
 ///
 /// # Arguments
-/// * `proposal` - The proposal to execute
+/// * `proposal` - The proposal voted on
+/// * `weight` - The voting weight of the member
+/// * `governance` - The governance configuration of the project
 ///
 /// # Returns
-/// * `types::ProposalStatus` - The final status (Approved if approve > reject, Rejected if reject > approve, Cancelled if equal)
-pub fn public_execute(proposal: &types::Proposal) -> types::ProposalStatus {
-    // count votes
-    let mut voted_approve = 0;
-    let mut voted_reject = 0;
-    let mut voted_abstain = 0;
+/// * `u32` - The weight the member can cast
+fn capped_weight(
+    proposal: &types::Proposal,
+    weight: u32,
+    governance: &types::GovernanceConfig,
+) -> u32 {
+    let eligible_weight = proposal.vote_data.eligible_weight;
+    if governance.max_voter_weight_bps == 0 || eligible_weight == 0 {
+        return weight;
+    }
+    let cap = eligible_weight * governance.max_voter_weight_bps as u128 / TOTAL_WEIGHT_BPS as u128;
+    (weight as u128).min(cap) as u32
+}
+
+/// Find the winning option of a multiple-choice proposal.
+///
+/// # Arguments
+/// * `proposal` - The proposal to count
+/// * `governance` - The governance configuration of the project
+///
+/// # Returns
+/// * `Option<u32>` - The option with the most weight, `None` on a tie or
+///   without votes
+fn winning_option(proposal: &types::Proposal, governance: &types::GovernanceConfig) -> Option<u32> {
+    let mut tallies = [0u128; MAX_OPTIONS as usize];
     for vote_ in &proposal.vote_data.votes {
-        if let types::Vote::PublicVote(vote) = &vote_ {
-            match vote.vote_choice {
-                types::VoteChoice::Approve => voted_approve += vote.weight as u128,
-                types::VoteChoice::Reject => voted_reject += vote.weight as u128,
-                types::VoteChoice::Abstain => voted_abstain += vote.weight as u128,
-            };
+        if let types::Vote::PublicVote(vote) = &vote_
+            && let types::VoteChoice::Choice(index) = vote.vote_choice
+        {
+            tallies[index as usize] += tally_weight(vote.weight, governance.quadratic_voting);
         }
     }
 
-    tallies_to_result(voted_approve, voted_reject, voted_abstain)
+    let mut winner = None;
+    let mut max = 0;
+    for (index, tally) in tallies.into_iter().enumerate() {
+        if tally > max {
+            max = tally;
+            winner = Some(index as u32);
+        } else if tally == max {
+            winner = None;
+        }
+    }
+    winner
+}
+
+/// Validate the choice of a public vote for a proposal.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `proposal` - The proposal voted on
+/// * `vote_choice` - The choice of the voter
+///
+/// # Panics
+/// * If an option is chosen on a regular proposal, approve or reject on a
+///   multiple-choice proposal, or the option doesn't exist
+fn validate_choice(env: &Env, proposal: &types::Proposal, vote_choice: &types::VoteChoice) {
+    let valid = match vote_choice {
+        types::VoteChoice::Abstain => true,
+        types::VoteChoice::Approve | types::VoteChoice::Reject => proposal.options.is_empty(),
+        types::VoteChoice::Choice(index) => *index < proposal.options.len(),
+    };
+    if !valid {
+        panic_with_error!(env, &errors::ExtendedErrors::InvalidVoteOption);
+    }
 }
 
-/// Execute an anonymous voting proposal.
+/// Read the tallies of an anonymous voting proposal.
 ///
-/// Helper function to determine the final status of an anonymous voting proposal
-/// based on the tallied vote counts. For anonymous voting, individual votes are
-/// not visible, only the aggregated tallies.
+/// For anonymous voting, individual votes are not visible, only the
+/// aggregated tallies.
 ///
 /// # Arguments
 /// * `tallies` - The tallied vote counts [approve, reject, abstain]
 ///
 /// # Returns
-/// * `types::ProposalStatus` - The final status (Approved if approve > reject, Rejected if reject > approve, Cancelled if equal)
-pub fn anonymous_execute(tallies: &Vec<u128>) -> types::ProposalStatus {
+/// * `(u128, u128, u128)` - The approve, reject and abstain tallies
+pub fn anonymous_tallies(tallies: &Vec<u128>) -> (u128, u128, u128) {
     // Use get() method to access elements safely
     let voted_approve = tallies
         .get(0)
-        .expect("anonymous_execute missing approve tally entry");
+        .expect("anonymous_tallies missing approve tally entry");
     let voted_reject = tallies
         .get(1)
-        .expect("anonymous_execute missing reject tally entry");
+        .expect("anonymous_tallies missing reject tally entry");
     let voted_abstain = tallies
         .get(2)
-        .expect("anonymous_execute missing abstain tally entry");
+        .expect("anonymous_tallies missing abstain tally entry");
+
+    (voted_approve, voted_reject, voted_abstain)
+}
+
+/// Whether the outcome of a proposal cannot change anymore.
+///
+/// The weight which can still be cast is the eligible weight of the
+/// proposal not cast yet, plus the default weight of the voters and
+/// delegators without badges. Approvals are settled if they resist this weight
+/// rejecting, rejections if they resist it approving. Cancellations and
+/// multiple-choice proposals are never settled, nor proposals without an
+/// eligible weight, like the ones migrated from the first layout whose
+/// eligible weight is unknown. With quadratic voting, the remaining weight is
+/// still counted linearly which overestimates it.
+///
+/// # Arguments
+/// * `proposal` - The proposal
+/// * `tallies` - The approve, reject and abstain tallies
+/// * `governance` - The governance configuration of the project
+///
+/// # Returns
+/// * `bool` - True if the remaining weight cannot change the outcome
+fn is_settled(
+    proposal: &types::Proposal,
+    (voted_approve, voted_reject, voted_abstain): (u128, u128, u128),
+    governance: &types::GovernanceConfig,
+) -> bool {
+    if !proposal.options.is_empty() || proposal.vote_data.eligible_weight == 0 {
+        return false;
+    }
+    let threshold = approval_threshold(proposal, governance);
+    let remaining = (proposal.vote_data.eligible_weight
+        + (MAX_VOTES_PER_PROPOSAL + MAX_DELEGATIONS) as u128 * types::Badge::Default as u128)
+        .saturating_sub(voted_approve + voted_reject + voted_abstain);
 
-    tallies_to_result(voted_approve, voted_reject, voted_abstain)
+    match tallies_to_result(
+        voted_approve,
+        voted_reject,
+        voted_abstain,
+        threshold,
+        governance,
+    ) {
+        types::ProposalStatus::Approved => {
+            tallies_to_result(
+                voted_approve,
+                voted_reject + remaining,
+                voted_abstain,
+                threshold,
+                governance,
+            ) == types::ProposalStatus::Approved
+        }
+        types::ProposalStatus::Rejected => {
+            tallies_to_result(
+                voted_approve + remaining,
+                voted_reject,
+                voted_abstain,
+                threshold,
+                governance,
+            ) == types::ProposalStatus::Rejected
+        }
+        _ => false,
+    }
 }
 
 /// Convert vote tallies to proposal status.
 ///
 /// Helper function to determine the final status based on vote counts.
-/// Abstain votes are ignored in the decision. If approve and reject are equal,
-/// the proposal is cancelled.
+/// The approve weight must exceed the approval threshold of the proposal,
+/// see `approval_threshold`. The proposal is cancelled if neither side wins
+/// or if the total weight cast does not reach the quorum of the project.
+/// Whether abstain votes count toward the quorum and in the approval ratio
+/// depends on the governance configuration.
 ///
 /// # Arguments
 /// * `voted_approve` - Number of approve votes
 /// * `voted_reject` - Number of reject votes
-/// * `voted_abstain` - Number of abstain votes (not used in decision)
+/// * `voted_abstain` - Number of abstain votes
+/// * `threshold` - The approval threshold in basis points
+/// * `governance` - The governance configuration of the project
 ///
 /// # Returns
 /// * `types::ProposalStatus` - The final status (Approved, Rejected, or Cancelled)
@@ -863,17 +3421,919 @@ fn tallies_to_result(
     voted_approve: u128,
     voted_reject: u128,
     voted_abstain: u128,
+    threshold: u32,
+    governance: &types::GovernanceConfig,
 ) -> types::ProposalStatus {
-    // Supermajority governance: requires more than half of all votes (including abstains)
+    let quorum_weight = quorum_weight((voted_approve, voted_reject, voted_abstain), governance);
+    if quorum_weight < governance.quorum as u128 {
+        return types::ProposalStatus::Cancelled;
+    }
+    let counted_abstain = if governance.abstain_in_approval {
+        voted_abstain
+    } else {
+        0
+    };
+    let total = voted_approve + voted_reject + counted_abstain;
+
+    // Supermajority governance: requires a share of all votes (including counted abstains)
     // This ensures broad consensus before passing any proposal
-    // Approve needs: approve > (reject + abstain)
+    // Approve needs: approve > threshold * (approve + reject + abstain)
     // Reject needs: reject > (approve + abstain)
     // Otherwise: cancelled (tie or no clear supermajority)
-    if voted_approve > (voted_reject + voted_abstain) {
+    if voted_approve * TOTAL_WEIGHT_BPS as u128 > threshold as u128 * total {
         types::ProposalStatus::Approved
-    } else if voted_reject > (voted_approve + voted_abstain) {
+    } else if voted_reject > (voted_approve + counted_abstain) {
         types::ProposalStatus::Rejected
     } else {
         types::ProposalStatus::Cancelled
     }
 }
+
+/// Approval threshold of a proposal in basis points.
+///
+/// A simple majority unless the governance configuration of the project sets
+/// another one for the kind of the proposal. Emergency proposals need at
+/// least the emergency threshold.
+///
+/// # Arguments
+/// * `proposal` - The proposal
+/// * `governance` - The governance configuration of the project
+///
+/// # Returns
+/// * `u32` - The share of the weight cast the approve weight must exceed
+fn approval_threshold(proposal: &types::Proposal, governance: &types::GovernanceConfig) -> u32 {
+    let threshold = governance
+        .approval_thresholds
+        .get(proposal.kind)
+        .unwrap_or(SIMPLE_MAJORITY_BPS);
+    match proposal.cosigner {
+        Some(_) => threshold.max(governance.emergency_threshold),
+        None => threshold,
+    }
+}
+
+/// Weight cast on a proposal counting toward the quorum.
+///
+/// # Arguments
+/// * `tallies` - The approve, reject and abstain tallies
+/// * `governance` - The governance configuration of the project
+///
+/// # Returns
+/// * `u128` - The weight cast, abstain votes included if they count
+fn quorum_weight(
+    (voted_approve, voted_reject, voted_abstain): (u128, u128, u128),
+    governance: &types::GovernanceConfig,
+) -> u128 {
+    let counted_abstain = if governance.abstain_in_quorum {
+        voted_abstain
+    } else {
+        0
+    };
+    voted_approve + voted_reject + counted_abstain
+}
+
+/// Whether a proposal is spam: it failed to reach a share of the quorum.
+///
+/// # Arguments
+/// * `tallies` - The approve, reject and abstain tallies
+/// * `governance` - The governance configuration of the project
+///
+/// # Returns
+/// * `bool` - True if the deposit of the proposer must be slashed
+fn is_spam(tallies: (u128, u128, u128), governance: &types::GovernanceConfig) -> bool {
+    quorum_weight(tallies, governance) * (TOTAL_WEIGHT_BPS as u128)
+        < governance.spam_quorum_bps as u128 * governance.quorum as u128
+}
+
+/// Create a proposal of a given kind.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `proposer` - Address of the proposal creator
+/// * `project_key` - Unique identifier for the project
+/// * `title` - Title of the proposal
+/// * `ipfs` - IPFS content identifier describing the proposal
+/// * `voting_ends_at` - UNIX timestamp when voting ends
+/// * `public_voting` - Whether voting is public or anonymous
+/// * `outcomes_contract` - Contract called with the outcome, if any
+/// * `kind` - The kind of the proposal
+/// * `options` - Labels of the options of a multiple-choice proposal
+/// * `cosigner` - Maintainer co-signing an emergency proposal, if any
+///
+/// # Returns
+/// * `u32` - The ID of the created proposal.
+#[allow(clippy::too_many_arguments)]
+fn create(
+    env: &Env,
+    proposer: Address,
+    project_key: Bytes,
+    title: String,
+    ipfs: String,
+    voting_ends_at: u64,
+    public_voting: bool,
+    outcomes_contract: Option<Address>,
+    kind: types::ProposalKind,
+    options: Vec<String>,
+    cosigner: Option<Address>,
+) -> u32 {
+    Tansu::require_not_paused(env.clone());
+    crate::require_not_archived(env, &project_key);
+    crate::require_project_not_paused(env, &project_key);
+
+    // Some input validations
+    let governance = Tansu::get_governance_config(env.clone(), project_key.clone());
+    let min_voting_period = match &cosigner {
+        Some(cosigner) => {
+            if governance.emergency_voting_period == 0 {
+                panic_with_error!(env, &errors::ExtendedErrors::EmergencyDisabled);
+            }
+            if *cosigner == proposer {
+                panic_with_error!(env, &errors::ContractErrors::UnauthorizedSigner);
+            }
+            crate::auth_maintainers_role(env, cosigner, &project_key, types::MaintainerRole::Admin);
+            governance.emergency_voting_period
+        }
+        None => governance.min_voting_period,
+    };
+    let curr_timestamp = env.ledger().timestamp();
+    let min_voting_timestamp = curr_timestamp + min_voting_period;
+    let max_voting_timestamp = curr_timestamp + governance.max_voting_period;
+    let title_len = title.len();
+
+    if !((min_voting_timestamp..=max_voting_timestamp).contains(&voting_ends_at)
+        && (MIN_TITLE_LENGTH..=governance.max_title_length).contains(&title_len))
+    {
+        panic_with_error!(env, &errors::ContractErrors::ProposalInputValidation);
+    }
+    validation::validate_cid(env, &ipfs);
+    require_proposal_cooldown(env, &project_key, &proposer, governance.proposal_cooldown);
+
+    // proposers deposit a collateral, and the bond of the project if any
+    proposer.require_auth();
+    let sac_contract = crate::retrieve_contract(env, types::ContractKey::CollateralContract);
+    let token_stellar = token::StellarAssetClient::new(env, &sac_contract.address);
+    token_stellar.transfer(
+        &proposer.clone(),
+        env.current_contract_address(),
+        &(PROPOSAL_COLLATERAL + VOTE_COLLATERAL + governance.proposal_deposit),
+    );
+
+    let proposal_id = env
+        .storage()
+        .persistent()
+        .get(&types::ProjectKey::DaoTotalProposals(project_key.clone()))
+        .unwrap_or(0);
+
+    // proposer is automatically in the abstain group
+    // use the first level to not block a vote from proposer with
+    // a very high level of trust
+    let abstain_weight = types::Badge::Verified as u32;
+    let vote_ = match public_voting {
+        true => types::Vote::PublicVote(types::PublicVote {
+            address: proposer.clone(),
+            weight: abstain_weight,
+            vote_choice: types::VoteChoice::Abstain,
+        }),
+        false => types::Vote::AnonymousVote(types::AnonymousVote {
+            address: proposer.clone(),
+            weight: abstain_weight,
+            encrypted_seeds: vec![
+                env,
+                String::from_str(env, "0"),
+                String::from_str(env, "0"),
+                String::from_str(env, "0"),
+            ],
+            encrypted_votes: vec![
+                env,
+                String::from_str(env, "0"),
+                String::from_str(env, "0"),
+                String::from_str(env, "1"),
+            ],
+            commitments: Tansu::build_commitments_from_votes(
+                env.clone(),
+                project_key.clone(),
+                vec![env, 0u128, 0u128, 1u128],
+                vec![
+                    env,
+                    U256::from_u32(env, 0),
+                    U256::from_u32(env, 0),
+                    U256::from_u32(env, 0),
+                ],
+            ),
+        }),
+    };
+
+    let (weight_snapshot, eligible_weight) =
+        contract_membership::weight_snapshot(env, &project_key, &governance);
+    let votes = vec![env, vote_];
+    let vote_data = types::VoteData {
+        voting_ends_at,
+        public_voting,
+        votes,
+        eligible_weight,
+    };
+    // public votes are revealed by voters, anonymous tallies by maintainers
+    let reveal_period = match public_voting {
+        true => governance.reveal_period,
+        false => governance.tally_reveal_period,
+    };
+    let proposal = types::Proposal {
+        id: proposal_id,
+        title,
+        proposer: proposer.clone(),
+        ipfs,
+        vote_data,
+        status: types::ProposalStatus::Active,
+        outcomes_contract,
+        kind,
+        created_at: curr_timestamp,
+        deposit: governance.proposal_deposit,
+        depends_on: None,
+        options,
+        winning_option: None,
+        cosigner,
+        reveal_ends_at: (reveal_period > 0).then(|| voting_ends_at + reveal_period),
+    };
+
+    let next_id = proposal_id + 1;
+    let page = proposal_id / MAX_PROPOSALS_PER_PAGE;
+
+    // Prevent exceeding maximum page limit
+    if page >= MAX_PAGES {
+        panic_with_error!(env, &errors::ContractErrors::NoProposalorPageFound);
+    }
+
+    env.storage().persistent().set(
+        &types::ProjectKey::DaoTotalProposals(project_key.clone()),
+        &next_id,
+    );
+    env.storage().persistent().set(
+        &types::ProjectKey::WeightSnapshot(project_key.clone(), proposal_id),
+        &weight_snapshot,
+    );
+    if !public_voting {
+        let public_key =
+            Tansu::get_anonymous_voting_config(env.clone(), project_key.clone()).public_key;
+        reference_key(env, &project_key, &public_key, 1);
+        env.storage().persistent().set(
+            &types::AnonymousKeyKey::Proposal(project_key.clone(), proposal_id),
+            &public_key,
+        );
+    }
+    crate::update_stats(env, |stats| stats.proposals += 1);
+    crate::update_project_stats(env, &project_key, |stats| stats.proposals += 1);
+    if governance.sponsors_required > 0 {
+        governance::client(env).open_sponsorship(
+            &project_key,
+            &proposal_id,
+            &governance.sponsors_required,
+        );
+    }
+
+    let mut dao_page = Tansu::get_dao(env.clone(), project_key.clone(), page);
+    dao_page.proposals.push_back(proposal.clone());
+
+    env.storage().persistent().set(
+        &types::ProjectKey::Dao(project_key.clone(), page),
+        &dao_page,
+    );
+    index_active_proposal(env, &project_key, proposal_id, true);
+
+    events::ProposalCreated {
+        project_key,
+        proposal_id,
+        title: proposal.title,
+        proposer,
+        voting_ends_at,
+        public_voting,
+    }
+    .publish(env);
+
+    proposal_id
+}
+
+/// Whether a proposal can still be amended by its proposer.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `proposal` - The proposal
+///
+/// # Returns
+/// * `bool` - True if nobody else voted or the amendment window is ongoing
+fn is_amendable(env: &Env, proposal: &types::Proposal) -> bool {
+    // the first vote is the abstain vote of the proposer
+    proposal.vote_data.votes.len() <= 1
+        || env.ledger().timestamp() < proposal.created_at + AMENDMENT_WINDOW
+}
+
+/// Enforce the proposal cooldown of a project for a proposer.
+///
+/// Proposers must wait the cooldown between two proposals, which stops
+/// flooding the pages of the DAO.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `proposer` - Address of the proposal creator
+/// * `cooldown` - Ledgers between two proposals
+///
+/// # Panics
+/// * If the proposer created a proposal less than the cooldown ago
+pub fn require_proposal_cooldown(
+    env: &Env,
+    project_key: &Bytes,
+    proposer: &Address,
+    cooldown: u32,
+) {
+    if cooldown == 0 {
+        return;
+    }
+
+    let last_proposal_key = types::ProjectKey::LastProposal(project_key.clone(), proposer.clone());
+    let ledger = env.ledger().sequence();
+    if let Some(last_proposal) = env.storage().persistent().get::<_, u32>(&last_proposal_key)
+        && ledger < last_proposal.saturating_add(cooldown)
+    {
+        panic_with_error!(env, &errors::ExtendedErrors::ProposalCooldown);
+    }
+    env.storage().persistent().set(&last_proposal_key, &ledger);
+}
+
+/// Default governance configuration of a project.
+///
+/// # Arguments
+/// * `env` - The environment object
+///
+/// # Returns
+/// * `types::GovernanceConfig` - One day to one month of voting, no quorum,
+///   simple majority for all kinds of proposals, abstain votes counted, no
+///   veto, no deposit, no cooldown, linear weights and no conviction voting
+pub fn default_governance_config(env: &Env) -> types::GovernanceConfig {
+    types::GovernanceConfig {
+        min_voting_period: MIN_VOTING_PERIOD,
+        max_voting_period: MAX_VOTING_PERIOD,
+        max_title_length: MAX_TITLE_LENGTH,
+        quorum: 0,
+        approval_thresholds: Map::new(env),
+        abstain_in_quorum: true,
+        abstain_in_approval: true,
+        veto_period: 0,
+        proposal_deposit: 0,
+        spam_quorum_bps: 0,
+        proposal_cooldown: 0,
+        quadratic_voting: false,
+        conviction_half_life: 0,
+        conviction_min_support_bps: 0,
+        voting_weight: types::VotingWeight::Badges,
+        unbonding_period: 0,
+        reputation_half_life: 0,
+        reputation_weight: 0,
+        execution_window: 0,
+        emergency_voting_period: 0,
+        emergency_threshold: 0,
+        sponsors_required: 0,
+        max_voter_weight_bps: 0,
+        reveal_period: 0,
+        tally_reveal_period: 0,
+    }
+}
+
+/// Validate a governance configuration.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `config` - The governance configuration
+///
+/// # Panics
+/// * If the voting periods are empty, inverted or longer than a year
+/// * If the veto, unbonding or execution period is longer than a year
+/// * If the maximum title length is out of bounds
+/// * If an approval threshold is below a simple majority or unreachable
+/// * If the deposit is negative or the spam share of the quorum above 100%
+/// * If the minimal support of conviction voting is above 100%
+/// * If emergency proposals have a longer voting period or no
+///   supermajority threshold
+/// * If too many sponsors are required
+/// * If the voter weight cap is above 100%
+/// * If the voting token is not a token contract with a sane number of decimals
+fn validate_governance_config(env: &Env, config: &types::GovernanceConfig) {
+    if let types::VotingWeight::Token(token_address)
+    | types::VotingWeight::BadgesAndToken(token_address) = &config.voting_weight
+    {
+        match token::TokenClient::new(env, token_address).try_decimals() {
+            Ok(Ok(decimals)) if 10i128.checked_pow(decimals).is_some() => (),
+            _ => panic_with_error!(env, &errors::ContractErrors::InvalidGovernanceConfig),
+        }
+    }
+
+    if config.min_voting_period == 0
+        || config.min_voting_period > config.max_voting_period
+        || config.max_voting_period > MAX_GOVERNANCE_VOTING_PERIOD
+        || config.veto_period > MAX_GOVERNANCE_VOTING_PERIOD
+        || config.unbonding_period > MAX_GOVERNANCE_VOTING_PERIOD
+        || config.execution_window > MAX_GOVERNANCE_VOTING_PERIOD
+        || config.reveal_period > MAX_GOVERNANCE_VOTING_PERIOD
+        || config.tally_reveal_period > MAX_GOVERNANCE_VOTING_PERIOD
+        || !(MIN_TITLE_LENGTH..=MAX_GOVERNANCE_TITLE_LENGTH).contains(&config.max_title_length)
+        || config
+            .approval_thresholds
+            .values()
+            .iter()
+            .any(|threshold| !(SIMPLE_MAJORITY_BPS..TOTAL_WEIGHT_BPS).contains(&threshold))
+        || config.proposal_deposit < 0
+        || config.spam_quorum_bps > TOTAL_WEIGHT_BPS
+        || config.conviction_min_support_bps > TOTAL_WEIGHT_BPS
+        || config.sponsors_required > MAX_SPONSORS_REQUIRED
+        || config.max_voter_weight_bps > TOTAL_WEIGHT_BPS
+        || (config.emergency_voting_period > 0
+            && (config.emergency_voting_period > config.min_voting_period
+                || !(SIMPLE_MAJORITY_BPS + 1..TOTAL_WEIGHT_BPS)
+                    .contains(&config.emergency_threshold)))
+    {
+        panic_with_error!(env, &errors::ContractErrors::InvalidGovernanceConfig);
+    }
+}
+
+/// Apply the action of an approved proposal to a project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `executor` - The address executing the proposal
+/// * `action` - The action to apply
+pub fn apply_action(env: &Env, project_key: &Bytes, executor: &Address, action: types::DaoAction) {
+    match action {
+        types::DaoAction::Commit(hash) => {
+            crate::record_commit(env, project_key, executor, hash, None, None, None, None);
+        }
+        types::DaoAction::UpdateConfig(url, ipfs) => {
+            let key_ = types::ProjectKey::Key(project_key.clone());
+            let mut project: types::Project = env.storage().persistent().get(&key_).unwrap();
+            for (changed, field) in [
+                (project.config.url != url, types::ConfigField::Url),
+                (project.config.ipfs != ipfs, types::ConfigField::Ipfs),
+            ] {
+                if changed {
+                    crate::publish_config_updated(env, project_key, executor, field);
+                }
+            }
+            if project.config.ipfs != ipfs {
+                crate::record_metadata_commitment(env, project_key, &ipfs);
+            }
+            project.config.url = url;
+            project.config.ipfs = ipfs;
+            env.storage().persistent().set(&key_, &project);
+        }
+        types::DaoAction::SetGovernanceConfig(config) => {
+            validate_governance_config(env, &config);
+            env.storage().persistent().set(
+                &types::ProjectKey::GovernanceConfig(project_key.clone()),
+                &config,
+            );
+
+            events::GovernanceConfigUpdated {
+                project_key: project_key.clone(),
+                config,
+            }
+            .publish(env);
+        }
+        types::DaoAction::SetLicense(license) => {
+            let key_ = types::ProjectKey::Key(project_key.clone());
+            let mut project: types::Project = env.storage().persistent().get(&key_).unwrap();
+            let old_license = project.config.license.replace(license.clone());
+            env.storage().persistent().set(&key_, &project);
+
+            events::LicenseChanged {
+                project_key: project_key.clone(),
+                old_license,
+                new_license: license,
+            }
+            .publish(env);
+        }
+        types::DaoAction::PromoteRelease(..)
+        | types::DaoAction::YankRelease(..)
+        | types::DaoAction::CreateRelease(..)
+        | types::DaoAction::ArchiveRelease(..) => {
+            if let Some(action) = registry::release_action(&action) {
+                registry::client(env).apply_release_action(project_key, executor, &action);
+            }
+        }
+        types::DaoAction::SetBadges(member, badges) => {
+            contract_membership::store_badges(env, project_key, &member, &badges);
+
+            events::BadgesUpdated {
+                project_key: project_key.clone(),
+                maintainer: executor.clone(),
+                member,
+                badges,
+            }
+            .publish(env);
+        }
+        types::DaoAction::Transfer(token, recipient, amount) => {
+            debit_treasury(env, project_key, &token, amount);
+            token::TokenClient::new(env, &token).transfer(
+                &env.current_contract_address(),
+                &recipient,
+                &amount,
+            );
+
+            events::TreasurySpent {
+                project_key: project_key.clone(),
+                executor: executor.clone(),
+                token,
+                recipient,
+                amount,
+            }
+            .publish(env);
+        }
+        types::DaoAction::SetMaintainers(maintainers) => {
+            let key_ = types::ProjectKey::Key(project_key.clone());
+            let mut project: types::Project = env.storage().persistent().get(&key_).unwrap();
+            crate::validate_maintainers(env, &maintainers, &project.config);
+            crate::prune_maintainer_roles(env, project_key, &project.maintainers, &maintainers);
+            // maintainers elected by the DAO are admins
+            let mut elected = Vec::new(env);
+            for maintainer in maintainers.iter() {
+                if !project.maintainers.contains(&maintainer) {
+                    elected.push_back(maintainer);
+                }
+            }
+            crate::grant_admin_roles(env, project_key, &elected);
+            project.maintainers = maintainers;
+            env.storage().persistent().set(&key_, &project);
+            crate::publish_config_updated(
+                env,
+                project_key,
+                executor,
+                types::ConfigField::Maintainers,
+            );
+        }
+        types::DaoAction::SetCouncil(council) => {
+            validate_council(env, &council);
+            governance::client(env).set_council(project_key, &council);
+        }
+        types::DaoAction::FundReward(proposal_id, token, amount) => {
+            let closes_at = vote_closes_at(&Tansu::get_proposal(
+                env.clone(),
+                project_key.clone(),
+                proposal_id,
+            ));
+            debit_treasury(env, project_key, &token, amount);
+            let governance = governance::client(env);
+            token::TokenClient::new(env, &token).transfer(
+                &env.current_contract_address(),
+                &governance.address,
+                &amount,
+            );
+            governance.fund_reward_pool(project_key, &proposal_id, &token, &amount, &closes_at);
+        }
+        types::DaoAction::Invoke(contract, function, args) => {
+            // a failing call does not block the execution of the proposal
+            let success = can_invoke(env, &contract, &function)
+                && env
+                    .try_invoke_contract::<Val, InvokeError>(&contract, &function, args)
+                    .is_ok_and(|result| result.is_ok());
+
+            events::ContractInvoked {
+                project_key: project_key.clone(),
+                executor: executor.clone(),
+                contract,
+                function,
+                success,
+            }
+            .publish(env);
+        }
+    }
+}
+
+/// Validate the action of a proposal.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `action` - The action of the proposal
+///
+/// # Panics
+/// * If the action requires the project to be controlled by its DAO
+/// * If the action spends the treasury and the project requires no quorum
+/// * If the governance configuration is invalid
+/// * If the license identifier is invalid
+/// * If the URL or the CID of a new configuration is malformed
+/// * If a new release or the reason of a yank is invalid
+/// * If a transfer amount is not positive
+/// * If new maintainers are invalid
+/// * If a call has too many arguments, targets a function not allowed by
+///   the admins, this contract or a token it holds
+fn validate_action(env: &Env, project_key: &Bytes, action: &types::DaoAction) {
+    match action {
+        types::DaoAction::SetGovernanceConfig(config) => {
+            validate_governance_config(env, config);
+        }
+        types::DaoAction::SetLicense(license) => {
+            validation::validate_license(env, license);
+        }
+        // the DAO of a project controlled by its maintainers approves the
+        // hash, a maintainer then commits it, see `commit`
+        types::DaoAction::Commit(..) => {}
+        _ => {
+            // maintainers keep control of the project and its funds until
+            // they renounce to the DAO
+            if !<Tansu as VersioningTrait>::is_dao_controlled(env.clone(), project_key.clone()) {
+                panic_with_error!(env, &errors::ContractErrors::InvalidDaoAction);
+            }
+            match action {
+                types::DaoAction::UpdateConfig(url, ipfs) => {
+                    validation::validate_url(env, url);
+                    validation::validate_cid(env, ipfs);
+                }
+                types::DaoAction::YankRelease(_, reason_cid) => {
+                    validation::validate_cid(env, reason_cid)
+                }
+                types::DaoAction::CreateRelease(
+                    tag,
+                    commit_hash,
+                    artifact_cid,
+                    changelog_cid,
+                    _,
+                ) => {
+                    semver::validate(env, tag);
+                    let algorithm = <Tansu as VersioningTrait>::get_hash_algorithm(
+                        env.clone(),
+                        project_key.clone(),
+                    );
+                    validation::validate_commit_hash(env, commit_hash, algorithm);
+                    validation::validate_cid(env, artifact_cid);
+                    validation::validate_cid(env, changelog_cid);
+                }
+                types::DaoAction::SetMaintainers(maintainers) => {
+                    let project =
+                        <Tansu as VersioningTrait>::get_project(env.clone(), project_key.clone());
+                    crate::validate_maintainers(env, maintainers, &project.config);
+                }
+                types::DaoAction::Transfer(_, _, amount) => {
+                    if *amount <= 0 {
+                        panic_with_error!(env, &errors::ExtendedErrors::InvalidAmount);
+                    }
+                    require_treasury_quorum(env, project_key);
+                }
+                types::DaoAction::FundReward(proposal_id, _, amount) => {
+                    if *amount <= 0 {
+                        panic_with_error!(env, &errors::ExtendedErrors::InvalidAmount);
+                    }
+                    let proposal =
+                        Tansu::get_proposal(env.clone(), project_key.clone(), *proposal_id);
+                    if proposal.status != types::ProposalStatus::Active {
+                        panic_with_error!(env, &errors::ExtendedErrors::InvalidRewardPool);
+                    }
+                    require_treasury_quorum(env, project_key);
+                }
+                types::DaoAction::SetCouncil(council) => {
+                    validate_council(env, council);
+                    require_treasury_quorum(env, project_key);
+                }
+                // the contract authorizes the calls it makes, so functions
+                // spending its tokens could drain the collaterals and treasuries
+                types::DaoAction::Invoke(contract, function, args)
+                    if args.len() > MAX_INVOKE_ARGS || !can_invoke(env, contract, function) =>
+                {
+                    panic_with_error!(env, &errors::ContractErrors::InvalidDaoAction);
+                }
+                _ => (),
+            }
+        }
+    }
+}
+
+/// Validate a council before its election.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `council` - The council to validate
+///
+/// # Panics
+/// * If there are too many members or a member is duplicated
+/// * If the threshold is not between one and the number of members
+/// * If the spend limit is negative
+fn validate_council(env: &Env, council: &types::Council) {
+    let members = &council.members;
+    let dissolved = members.is_empty() && council.threshold == 0;
+    if members.len() > types::MAX_COUNCIL_MEMBERS
+        || members
+            .iter()
+            .enumerate()
+            .any(|(index, member)| members.first_index_of(&member) != Some(index as u32))
+        || !(dissolved || (1..=members.len()).contains(&council.threshold))
+        || council.spend_limit < 0
+    {
+        panic_with_error!(env, &errors::ExtendedErrors::InvalidCouncil);
+    }
+}
+
+/// Whether a proposal can call a contract function.
+///
+/// The contract authorizes the calls it makes, so only functions allowed by
+/// the admins can be called, and never on this contract, on the contracts
+/// trusting its calls (registry, staking, scheduler and governance) or on a
+/// token it holds as the call could move collaterals and treasuries.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `contract` - The contract to call
+/// * `function` - The function to call
+///
+/// # Returns
+/// * `bool` - True if the call is allowed
+fn can_invoke(env: &Env, contract: &Address, function: &Symbol) -> bool {
+    *contract != env.current_contract_address()
+        && [
+            types::ContractKey::RegistryContract,
+            types::ContractKey::StakingContract,
+            types::ContractKey::SchedulerContract,
+            types::ContractKey::GovernanceContract,
+        ]
+        .into_iter()
+        .all(|key| {
+            env.storage()
+                .instance()
+                .get::<_, types::Contract>(&key)
+                .is_none_or(|satellite| satellite.address != *contract)
+        })
+        && <Tansu as TansuTrait>::is_invoke_target(env.clone(), contract.clone(), function.clone())
+        && !matches!(
+            token::TokenClient::new(env, contract).try_balance(&env.current_contract_address()),
+            Ok(Ok(balance)) if balance > 0
+        )
+}
+
+/// Check that the votes of a project cannot be passed by a few addresses.
+///
+/// Any address votes with the default badge, so actions spending the
+/// treasury need the project to require a quorum.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+///
+/// # Panics
+/// * If the project does not require a quorum
+fn require_treasury_quorum(env: &Env, project_key: &Bytes) {
+    if Tansu::get_governance_config(env.clone(), project_key.clone()).quorum == 0 {
+        panic_with_error!(env, &errors::ContractErrors::InvalidDaoAction);
+    }
+}
+
+/// Kind of the proposal applying an action.
+///
+/// # Arguments
+/// * `action` - The action of the proposal
+///
+/// # Returns
+/// * `types::ProposalKind` - The kind of the proposal
+pub fn proposal_kind(action: &types::DaoAction) -> types::ProposalKind {
+    match action {
+        types::DaoAction::UpdateConfig(..)
+        | types::DaoAction::SetGovernanceConfig(..)
+        | types::DaoAction::SetLicense(..)
+        | types::DaoAction::SetBadges(..)
+        | types::DaoAction::SetCouncil(..) => types::ProposalKind::ConfigChange,
+        types::DaoAction::Transfer(..) | types::DaoAction::FundReward(..) => {
+            types::ProposalKind::Treasury
+        }
+        types::DaoAction::Commit(..)
+        | types::DaoAction::PromoteRelease(..)
+        | types::DaoAction::YankRelease(..)
+        | types::DaoAction::CreateRelease(..)
+        | types::DaoAction::ArchiveRelease(..) => types::ProposalKind::Upgrade,
+        types::DaoAction::SetMaintainers(..) => types::ProposalKind::MaintainerChange,
+        types::DaoAction::Invoke(..) => types::ProposalKind::ContractCall,
+    }
+}
+
+/// Whether an action responds to a security incident.
+///
+/// Emergency proposals shorten the vote, so they can only yank a
+/// compromised release, roll back to a known commit or remove compromised
+/// maintainers.
+///
+/// # Arguments
+/// * `action` - The action of the proposal
+///
+/// # Returns
+/// * `bool` - True if an emergency proposal can apply the action
+fn is_incident_action(action: &types::DaoAction) -> bool {
+    matches!(
+        action,
+        types::DaoAction::YankRelease(..)
+            | types::DaoAction::Commit(..)
+            | types::DaoAction::SetMaintainers(..)
+    )
+}
+
+/// Whether a project has proposals which are still active.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+///
+/// # Returns
+/// * `bool` - True if at least one proposal is active
+pub fn has_active_proposals(env: &Env, project_key: &Bytes) -> bool {
+    !active_proposals(env, project_key).is_empty()
+}
+
+/// Replace an address in the active proposals of a project.
+///
+/// The new address becomes the proposer and voter in place of the old one,
+/// so collaterals are refunded to it. Only the active proposals are
+/// visited, see `index_active_proposal`.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `old_address` - The address to replace
+/// * `new_address` - The replacing address
+///
+/// # Panics
+/// * If the new address already voted on an active proposal, as it would
+///   vote twice
+pub fn rotate_voter(env: &Env, project_key: &Bytes, old_address: &Address, new_address: &Address) {
+    for proposal_id in active_proposals(env, project_key).iter() {
+        let page = proposal_id / MAX_PROPOSALS_PER_PAGE;
+        let sub_id = proposal_id % MAX_PROPOSALS_PER_PAGE;
+        let mut dao_page = Tansu::get_dao(env.clone(), project_key.clone(), page);
+        let mut proposal = dao_page.proposals.get_unchecked(sub_id);
+        if has_voted(&proposal, new_address) {
+            panic_with_error!(env, &errors::ContractErrors::AlreadyVoted);
+        }
+        if proposal.proposer == *old_address {
+            proposal.proposer = new_address.clone();
+        }
+        for i in 0..proposal.vote_data.votes.len() {
+            let vote = match proposal.vote_data.votes.get_unchecked(i) {
+                types::Vote::PublicVote(mut vote) if vote.address == *old_address => {
+                    vote.address = new_address.clone();
+                    types::Vote::PublicVote(vote)
+                }
+                types::Vote::AnonymousVote(mut vote) if vote.address == *old_address => {
+                    vote.address = new_address.clone();
+                    types::Vote::AnonymousVote(vote)
+                }
+                _ => continue,
+            };
+            proposal.vote_data.votes.set(i, vote);
+        }
+        dao_page.proposals.set(sub_id, proposal);
+        env.storage().persistent().set(
+            &types::ProjectKey::Dao(project_key.clone(), page),
+            &dao_page,
+        );
+    }
+}
+
+/// Remove all DAO data of a project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+pub fn remove_dao(env: &Env, project_key: &Bytes) {
+    let total_proposals_key = types::ProjectKey::DaoTotalProposals(project_key.clone());
+    let total_proposals: u32 = env
+        .storage()
+        .persistent()
+        .get(&total_proposals_key)
+        .unwrap_or(0);
+
+    for page in 0..total_proposals.div_ceil(MAX_PROPOSALS_PER_PAGE) {
+        env.storage()
+            .persistent()
+            .remove(&types::ProjectKey::Dao(project_key.clone(), page));
+    }
+    for proposal_id in 0..total_proposals {
+        env.storage()
+            .persistent()
+            .remove(&types::AnonymousKeyKey::Proposal(
+                project_key.clone(),
+                proposal_id,
+            ));
+    }
+    env.storage().persistent().remove(&total_proposals_key);
+    env.storage()
+        .persistent()
+        .remove(&types::DaoIndexKey::Active(project_key.clone()));
+
+    env.storage()
+        .persistent()
+        .remove(&types::ProjectKey::GovernanceConfig(project_key.clone()));
+    env.storage()
+        .persistent()
+        .remove(&types::ProjectKey::Delegations(project_key.clone()));
+    env.storage()
+        .persistent()
+        .remove(&types::ProjectKey::ProposalTemplates(project_key.clone()));
+    env.storage()
+        .instance()
+        .remove(&types::ProjectKey::AnonymousVoteConfig(project_key.clone()));
+    env.storage()
+        .persistent()
+        .remove(&types::AnonymousKeyKey::History(project_key.clone()));
+}
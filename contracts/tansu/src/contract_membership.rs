@@ -1,6 +1,13 @@
-use soroban_sdk::{Address, Bytes, Env, String, Vec, contractimpl, panic_with_error};
+use soroban_sdk::{Address, Bytes, Env, Map, String, Vec, contractimpl, panic_with_error, token};
 
-use crate::{MembershipTrait, Tansu, TansuArgs, TansuClient, TansuTrait, errors, events, types};
+use crate::{
+    DaoTrait, MembershipTrait, Tansu, TansuArgs, TansuClient, TansuTrait, errors, events, staking,
+    types,
+};
+
+pub const REPUTATION_PER_VOTE: u32 = 10;
+pub const REPUTATION_PER_COMMIT: u32 = 1;
+const DECAY_SCALE: u128 = 1_000_000_000;
 
 #[contractimpl]
 impl MembershipTrait for Tansu {
@@ -32,6 +39,7 @@ impl MembershipTrait for Tansu {
                 meta,
             };
             env.storage().persistent().set(&member_key_, &member);
+            crate::update_stats(&env, |stats| stats.members += 1);
 
             events::MemberAdded { member_address }.publish(&env);
         };
@@ -75,6 +83,8 @@ impl MembershipTrait for Tansu {
     /// * If the maintainer is not authorized
     /// * If the member doesn't exist
     /// * If the project doesn't exist
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
     fn set_badges(
         env: Env,
         maintainer: Address,
@@ -85,81 +95,16 @@ impl MembershipTrait for Tansu {
         Tansu::require_not_paused(env.clone());
 
         crate::auth_maintainers(&env, &maintainer, &key);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
 
-        let member_key_ = types::DataKey::Member(member.clone());
-        let mut member_ = if let Some(member_) = env
-            .storage()
-            .persistent()
-            .get::<types::DataKey, types::Member>(&member_key_)
-        {
-            member_
-        } else {
-            panic_with_error!(&env, &errors::ContractErrors::UnknownMember)
-        };
-
-        // For a member, go over its projects and replace all badges for
-        // a project
-        'member_projects_badges: {
-            for i in 0..member_.projects.len() {
-                if let Some(project_badge) = member_.projects.get(i)
-                    && project_badge.project == key
-                {
-                    let mut project_badges = project_badge.clone();
-                    project_badges.badges = badges.clone();
-                    member_.projects.set(i, project_badges);
-                    break 'member_projects_badges;
-                }
-            }
-            let project_badges = types::ProjectBadges {
-                project: key.clone(),
-                badges: badges.clone(),
-            };
-            member_.projects.push_back(project_badges);
-        }
-
-        // For a project, go over all badges and add the specific member if it
-        // has the badge
-        let badges_key_ = types::ProjectKey::Badges(key.clone());
-        let mut badges_ = <Tansu as MembershipTrait>::get_badges(env.clone(), key.clone());
-
-        for badge_kind in [
-            types::Badge::Developer,
-            types::Badge::Triage,
-            types::Badge::Community,
-            types::Badge::Verified,
-        ] {
-            // Pick the right vector for this badge kind
-            let vec_ref: &mut Vec<Address> = match badge_kind {
-                types::Badge::Developer => &mut badges_.developer,
-                types::Badge::Triage => &mut badges_.triage,
-                types::Badge::Community => &mut badges_.community,
-                types::Badge::Verified => &mut badges_.verified,
-                _ => continue,
-            };
-
-            // Build a cleaned-up copy removing all badges from member
-            let mut new_vec: Vec<Address> = Vec::new(&env);
-            for addr in vec_ref.iter() {
-                if addr != member.clone() {
-                    new_vec.push_back(addr);
-                }
-            }
-            // Add the member back if they should hold this badge now
-            if badges.contains(badge_kind.clone()) {
-                new_vec.push_back(member.clone());
-            }
-            // Replace the old vector
-            *vec_ref = new_vec;
-        }
-
-        env.storage().persistent().set(&badges_key_, &badges_);
-        env.storage().persistent().set(&member_key_, &member_);
+        store_badges(&env, &key, &member, &badges);
 
         events::BadgesUpdated {
             project_key: key,
             maintainer,
             member,
-            badges_count: badges.len(),
+            badges,
         }
         .publish(&env);
     }
@@ -167,7 +112,7 @@ impl MembershipTrait for Tansu {
     /// Get all badges for a specific project, organized by badge type.
     ///
     /// Returns a structure containing vectors of member addresses for each badge type
-    /// (Developer, Triage, Community, Verified).
+    /// (Developer, Triage, Community, Verified, Reviewer).
     ///
     /// # Arguments
     /// * `env` - The environment object
@@ -189,6 +134,7 @@ impl MembershipTrait for Tansu {
                 triage: Vec::new(&env),
                 community: Vec::new(&env),
                 verified: Vec::new(&env),
+                reviewer: Vec::new(&env),
             }
         }
     }
@@ -240,4 +186,549 @@ impl MembershipTrait for Tansu {
             types::Badge::Default as u32
         }
     }
+
+    /// Get the current voting weight of a member in a project.
+    ///
+    /// The weight is counted as for new proposals, from the badges, tokens
+    /// and stakes of the member depending on the governance configuration.
+    /// Unlike `get_max_weight`, addresses without a badge, stake or tokens
+    /// have no weight. Conviction voting weighs its supporters with it.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `member_address` - The address of the member
+    ///
+    /// # Returns
+    /// * `u32` - The voting weight of the member
+    fn get_member_weight(env: Env, project_key: Bytes, member_address: Address) -> u32 {
+        let governance = Tansu::get_governance_config(env.clone(), project_key.clone());
+        member_weight(&env, &project_key, &governance, &member_address)
+    }
+
+    /// Get the reputation of a member in a project.
+    ///
+    /// Members gain reputation by voting and committing, and it halves
+    /// every `reputation_half_life` ledgers of the governance configuration.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `member_address` - The address of the member
+    ///
+    /// # Returns
+    /// * `u32` - The reputation score, 0 if reputation is disabled
+    fn get_reputation(env: Env, project_key: Bytes, member_address: Address) -> u32 {
+        let governance = Tansu::get_governance_config(env.clone(), project_key.clone());
+        reputation(
+            &env,
+            &project_key,
+            &member_address,
+            governance.reputation_half_life,
+        )
+    }
+}
+
+/// Remove all badges of a project.
+///
+/// The project is removed from the projects of every member holding a badge.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+pub fn remove_project_badges(env: &Env, project_key: &Bytes) {
+    let badges = <Tansu as MembershipTrait>::get_badges(env.clone(), project_key.clone());
+
+    for members in [
+        badges.developer,
+        badges.triage,
+        badges.community,
+        badges.verified,
+        badges.reviewer,
+    ] {
+        for member_address in members.iter() {
+            let member_key_ = types::DataKey::Member(member_address);
+            if let Some(mut member) = env
+                .storage()
+                .persistent()
+                .get::<types::DataKey, types::Member>(&member_key_)
+                && let Some(index) = member
+                    .projects
+                    .iter()
+                    .position(|project_badges| project_badges.project == *project_key)
+            {
+                member.projects.remove(index as u32);
+                env.storage().persistent().set(&member_key_, &member);
+            }
+        }
+    }
+
+    env.storage()
+        .persistent()
+        .remove(&types::ProjectKey::Badges(project_key.clone()));
+}
+
+/// Max voting weight of every member holding a badge of a project.
+///
+/// Depending on the voting weight of the project, the balances of the
+/// voting token are read and replace or add to the badge weights. Tokens
+/// staked count as held. The reputation of the members adds to their weight
+/// if the project counts it. Members without any weight are left out.
+/// Stakers without a badge are not listed, see `staker_weight`, but their
+/// stakes count in the eligible weight.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `governance` - The governance configuration of the project
+///
+/// # Returns
+/// * `(Map<Address, u32>, u128)` - The voting weight of each member holding
+///   a badge, and the weight eligible to vote
+pub fn weight_snapshot(
+    env: &Env,
+    project_key: &Bytes,
+    governance: &types::GovernanceConfig,
+) -> (Map<Address, u32>, u128) {
+    let badges = <Tansu as MembershipTrait>::get_badges(env.clone(), project_key.clone());
+
+    let mut weights = Map::new(env);
+    for (members, badge) in [
+        (badges.developer, types::Badge::Developer),
+        (badges.triage, types::Badge::Triage),
+        (badges.community, types::Badge::Community),
+        (badges.verified, types::Badge::Verified),
+        (badges.reviewer, types::Badge::Reviewer),
+    ] {
+        let badge_weight = badge as u32;
+        for member_address in members.iter() {
+            let weight = weights.get(member_address.clone()).unwrap_or(0);
+            weights.set(member_address, weight + badge_weight);
+        }
+    }
+
+    let voting_token = voting_token(&governance.voting_weight).map(|token_address| {
+        let token_client = token::TokenClient::new(env, &token_address);
+        let unit = token_unit(&token_client);
+        (token_client, unit)
+    });
+
+    let now = env.ledger().timestamp();
+    let mut snapshot = Map::new(env);
+    let mut eligible_weight: u128 = 0;
+    let mut members_stake: i128 = 0;
+    for (member_address, badge_weight) in weights.iter() {
+        let token_weight = match &voting_token {
+            Some((token_client, unit)) => {
+                let staked = staking::client(env).get_voting_stake(
+                    project_key,
+                    &member_address,
+                    &token_client.address,
+                    &now,
+                );
+                members_stake += staked;
+                let held = token_client.balance(&member_address).saturating_add(staked);
+                u32::try_from(held / unit).unwrap_or(u32::MAX)
+            }
+            None => 0,
+        };
+        let weight = combine_weights(
+            env,
+            project_key,
+            governance,
+            &member_address,
+            badge_weight,
+            token_weight,
+        );
+        if weight > 0 {
+            snapshot.set(member_address, weight);
+            eligible_weight += weight as u128;
+        }
+    }
+    if let Some((_, unit)) = voting_token {
+        let stakers_stake = staking::client(env).get_total_stake(project_key) - members_stake;
+        eligible_weight += (stakers_stake.max(0) / unit) as u128;
+    }
+    (snapshot, eligible_weight)
+}
+
+/// Voting weight of a staker without a badge on a proposal.
+///
+/// Only the tokens staked before the proposal was created count.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `governance` - The governance configuration of the project
+/// * `member_address` - The address of the staker
+/// * `created_at` - UNIX timestamp of the creation of the proposal
+///
+/// # Returns
+/// * `u32` - The voting weight of the staker, 0 without stake
+pub fn staker_weight(
+    env: &Env,
+    project_key: &Bytes,
+    governance: &types::GovernanceConfig,
+    member_address: &Address,
+    created_at: u64,
+) -> u32 {
+    let Some(token_address) = voting_token(&governance.voting_weight) else {
+        return 0;
+    };
+    let staked = staking::client(env).get_voting_stake(
+        project_key,
+        member_address,
+        &token_address,
+        &created_at,
+    );
+    if staked == 0 {
+        return 0;
+    }
+    let unit = token_unit(&token::TokenClient::new(env, &token_address));
+    combine_weights(
+        env,
+        project_key,
+        governance,
+        member_address,
+        0,
+        u32::try_from(staked / unit).unwrap_or(u32::MAX),
+    )
+}
+
+/// Current voting weight of a member, counted as in `weight_snapshot`.
+///
+/// Unlike `get_max_weight`, addresses without a badge, stake or tokens have
+/// no weight.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `governance` - The governance configuration of the project
+/// * `member_address` - The address of the member
+///
+/// # Returns
+/// * `u32` - The voting weight of the member
+pub fn member_weight(
+    env: &Env,
+    project_key: &Bytes,
+    governance: &types::GovernanceConfig,
+    member_address: &Address,
+) -> u32 {
+    let badge_weight = env
+        .storage()
+        .persistent()
+        .get::<_, types::Member>(&types::DataKey::Member(member_address.clone()))
+        .and_then(|member| {
+            member
+                .projects
+                .iter()
+                .find(|project_badges| project_badges.project == *project_key)
+        })
+        .map_or(0, |project_badges| {
+            project_badges.badges.iter().map(|badge| badge as u32).sum()
+        });
+
+    let token_weight = match voting_token(&governance.voting_weight) {
+        Some(token_address) => {
+            let token_client = token::TokenClient::new(env, &token_address);
+            let unit = token_unit(&token_client);
+            let staked = staking::client(env).get_voting_stake(
+                project_key,
+                member_address,
+                &token_address,
+                &env.ledger().timestamp(),
+            );
+            let held = token_client.balance(member_address).saturating_add(staked);
+            u32::try_from(held / unit).unwrap_or(u32::MAX)
+        }
+        None => 0,
+    };
+
+    combine_weights(
+        env,
+        project_key,
+        governance,
+        member_address,
+        badge_weight,
+        token_weight,
+    )
+}
+
+/// Voting token of a project, if any.
+///
+/// # Arguments
+/// * `voting_weight` - Source of the voting weight of the project
+///
+/// # Returns
+/// * `Option<Address>` - The token contract whose balances grant voting weight
+fn voting_token(voting_weight: &types::VotingWeight) -> Option<Address> {
+    match voting_weight {
+        types::VotingWeight::Badges => None,
+        types::VotingWeight::Token(token_address)
+        | types::VotingWeight::BadgesAndToken(token_address) => Some(token_address.clone()),
+    }
+}
+
+/// Amount of a whole token.
+fn token_unit(token_client: &token::TokenClient) -> i128 {
+    10i128
+        .checked_pow(token_client.decimals())
+        .unwrap_or(i128::MAX)
+}
+
+/// Voting weight of a member from its badges and tokens, depending on the
+/// voting weight of the project, plus its reputation if the project counts
+/// it.
+fn combine_weights(
+    env: &Env,
+    project_key: &Bytes,
+    governance: &types::GovernanceConfig,
+    member_address: &Address,
+    badge_weight: u32,
+    token_weight: u32,
+) -> u32 {
+    let weight = match governance.voting_weight {
+        types::VotingWeight::Badges => badge_weight,
+        types::VotingWeight::Token(_) => token_weight,
+        types::VotingWeight::BadgesAndToken(_) => badge_weight.saturating_add(token_weight),
+    };
+    if governance.reputation_weight == 0 {
+        return weight;
+    }
+    let score = reputation(
+        env,
+        project_key,
+        member_address,
+        governance.reputation_half_life,
+    );
+    weight.saturating_add(score.saturating_mul(governance.reputation_weight))
+}
+
+/// Reputation of a member decayed to the current ledger.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `member_address` - The address of the member
+/// * `half_life` - Ledgers for reputation to halve
+///
+/// # Returns
+/// * `u32` - The reputation score, 0 if reputation is disabled
+fn reputation(env: &Env, project_key: &Bytes, member_address: &Address, half_life: u32) -> u32 {
+    match env
+        .storage()
+        .persistent()
+        .get::<_, types::Reputation>(&types::ProjectKey::Reputation(
+            project_key.clone(),
+            member_address.clone(),
+        )) {
+        Some(reputation) => {
+            let decay = decay(
+                env.ledger()
+                    .sequence()
+                    .saturating_sub(reputation.updated_at),
+                half_life,
+            );
+            (reputation.score as u128 * decay / DECAY_SCALE) as u32
+        }
+        None => 0,
+    }
+}
+
+/// Share of a reputation score remaining after some ledgers.
+///
+/// The score halves every half-life, and decreases linearly in between.
+/// Conviction voting decays the same way.
+///
+/// # Arguments
+/// * `elapsed` - Ledgers since the last update of the score
+/// * `half_life` - Ledgers for the score to halve
+///
+/// # Returns
+/// * `u128` - The remaining share, scaled by `DECAY_SCALE`
+fn decay(elapsed: u32, half_life: u32) -> u128 {
+    if half_life == 0 || elapsed / half_life >= u128::BITS {
+        return 0;
+    }
+    let remainder = (elapsed % half_life) as u128;
+    (DECAY_SCALE - DECAY_SCALE * remainder / (2 * half_life as u128)) >> (elapsed / half_life)
+}
+
+/// Increase the reputation of a member for participating in a project.
+///
+/// Nothing is tracked if reputation is disabled for the project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `member_address` - The address of the member
+/// * `points` - The reputation gained
+pub fn add_reputation(env: &Env, project_key: &Bytes, member_address: &Address, points: u32) {
+    let governance = Tansu::get_governance_config(env.clone(), project_key.clone());
+    if governance.reputation_half_life == 0 {
+        return;
+    }
+
+    let score = reputation(
+        env,
+        project_key,
+        member_address,
+        governance.reputation_half_life,
+    );
+    env.storage().persistent().set(
+        &types::ProjectKey::Reputation(project_key.clone(), member_address.clone()),
+        &types::Reputation {
+            score: score.saturating_add(points),
+            updated_at: env.ledger().sequence(),
+        },
+    );
+}
+
+/// Move the badges of a member for a project to another address.
+///
+/// The new address is registered as a member with the metadata of the old
+/// one if needed.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+/// * `old_member` - The address holding the badges
+/// * `new_member` - The address receiving the badges
+pub fn rotate_member_badges(env: &Env, key: &Bytes, old_member: &Address, new_member: &Address) {
+    let old_member_key_ = types::DataKey::Member(old_member.clone());
+    let Some(old_member_) = env
+        .storage()
+        .persistent()
+        .get::<types::DataKey, types::Member>(&old_member_key_)
+    else {
+        return;
+    };
+    let Some(index) = old_member_
+        .projects
+        .iter()
+        .position(|project_badges| project_badges.project == *key)
+    else {
+        return;
+    };
+    let badges = old_member_.projects.get_unchecked(index as u32).badges;
+
+    store_badges(env, key, old_member, &Vec::new(env));
+    let mut old_member_: types::Member = env.storage().persistent().get(&old_member_key_).unwrap();
+    old_member_.projects.remove(index as u32);
+    env.storage()
+        .persistent()
+        .set(&old_member_key_, &old_member_);
+
+    let new_member_key_ = types::DataKey::Member(new_member.clone());
+    if !env.storage().persistent().has(&new_member_key_) {
+        let member = types::Member {
+            projects: Vec::new(env),
+            meta: old_member_.meta,
+        };
+        env.storage().persistent().set(&new_member_key_, &member);
+        crate::update_stats(env, |stats| stats.members += 1);
+
+        events::MemberAdded {
+            member_address: new_member.clone(),
+        }
+        .publish(env);
+    }
+    store_badges(env, key, new_member, &badges);
+}
+
+/// Replace the badges of a member for a project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+/// * `member` - The address of the member to set badges for
+/// * `badges` - Vector of badges to assign
+///
+/// # Panics
+/// * If the member doesn't exist
+pub fn store_badges(env: &Env, key: &Bytes, member: &Address, badges: &Vec<types::Badge>) {
+    let member_key_ = types::DataKey::Member(member.clone());
+    let mut member_ = if let Some(member_) = env
+        .storage()
+        .persistent()
+        .get::<types::DataKey, types::Member>(&member_key_)
+    {
+        member_
+    } else {
+        panic_with_error!(&env, &errors::ContractErrors::UnknownMember)
+    };
+
+    // For a member, go over its projects and replace all badges for
+    // a project
+    let mut previous_badges = Vec::new(env);
+    'member_projects_badges: {
+        for i in 0..member_.projects.len() {
+            if let Some(project_badge) = member_.projects.get(i)
+                && project_badge.project == *key
+            {
+                previous_badges = project_badge.badges.clone();
+                let mut project_badges = project_badge.clone();
+                project_badges.badges = badges.clone();
+                member_.projects.set(i, project_badges);
+                break 'member_projects_badges;
+            }
+        }
+        let project_badges = types::ProjectBadges {
+            project: key.clone(),
+            badges: badges.clone(),
+        };
+        member_.projects.push_back(project_badges);
+    }
+
+    // For a project, go over all badges and add the specific member if it
+    // has the badge
+    let badges_key_ = types::ProjectKey::Badges(key.clone());
+    let mut badges_ = <Tansu as MembershipTrait>::get_badges(env.clone(), key.clone());
+
+    for badge_kind in [
+        types::Badge::Developer,
+        types::Badge::Triage,
+        types::Badge::Community,
+        types::Badge::Verified,
+        types::Badge::Reviewer,
+    ] {
+        // Pick the right vector for this badge kind
+        let vec_ref: &mut Vec<Address> = match badge_kind {
+            types::Badge::Developer => &mut badges_.developer,
+            types::Badge::Triage => &mut badges_.triage,
+            types::Badge::Community => &mut badges_.community,
+            types::Badge::Verified => &mut badges_.verified,
+            types::Badge::Reviewer => &mut badges_.reviewer,
+            _ => continue,
+        };
+
+        // Build a cleaned-up copy removing all badges from member
+        let mut new_vec: Vec<Address> = Vec::new(env);
+        for addr in vec_ref.iter() {
+            if addr != *member {
+                new_vec.push_back(addr);
+            }
+        }
+        // Add the member back if they should hold this badge now
+        if badges.contains(badge_kind.clone()) {
+            new_vec.push_back(member.clone());
+        }
+        // Replace the old vector
+        *vec_ref = new_vec;
+    }
+
+    env.storage().persistent().set(&badges_key_, &badges_);
+    env.storage().persistent().set(&member_key_, &member_);
+
+    let weight = |badges: &Vec<types::Badge>| badges.iter().map(|badge| badge as u32).sum::<u32>();
+    let (previous_weight, new_weight) = (weight(&previous_badges), weight(badges));
+    // members holding badges before the stats were tracked are not counted
+    crate::update_project_stats(env, key, |stats| {
+        match (previous_badges.is_empty(), badges.is_empty()) {
+            (true, false) => stats.members += 1,
+            (false, true) => stats.members = stats.members.saturating_sub(1),
+            _ => {}
+        }
+        stats.voting_weight = stats.voting_weight.saturating_sub(previous_weight) + new_weight;
+    });
 }
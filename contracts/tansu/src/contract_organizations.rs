@@ -0,0 +1,425 @@
+use soroban_sdk::{Address, Bytes, Env, String, Vec, contractimpl, panic_with_error};
+
+use crate::{
+    OrganizationsTrait, Tansu, TansuArgs, TansuClient, TansuTrait, VersioningTrait,
+    contract_membership, errors, events, types, validation,
+};
+
+#[contractimpl]
+impl OrganizationsTrait for Tansu {
+    /// Create an organization.
+    ///
+    /// An organization groups several projects under a common set of admins
+    /// which can apply actions to all its projects at once. The organization
+    /// key is the keccak256 hash of the normalized name.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `admin` - The address of the admin creating the organization
+    /// * `name` - The organization name
+    /// * `admins` - List of admin addresses
+    ///
+    /// # Returns
+    /// * `Bytes` - The organization key
+    ///
+    /// # Panics
+    /// * If the name is not 1 to 15 ASCII letters
+    /// * If the organization already exists
+    /// * If the admin is not part of the admins
+    /// * If there are more admins than allowed
+    fn create_organization(env: Env, admin: Address, name: String, admins: Vec<Address>) -> Bytes {
+        Tansu::require_not_paused(env.clone());
+
+        admin.require_auth();
+        validate_admins(&env, &admins);
+        if !admins.contains(&admin) {
+            panic_with_error!(&env, &errors::ContractErrors::UnauthorizedSigner);
+        }
+
+        let name_b = validation::normalize_project_name(&env, &name);
+        let key: Bytes = env.crypto().keccak256(&name_b).into();
+        let key_ = types::OrganizationKey::Organization(key.clone());
+        if env.storage().persistent().has(&key_) {
+            panic_with_error!(&env, &errors::ContractErrors::OrganizationAlreadyExist);
+        }
+
+        let mut slice = [0u8; types::MAX_PROJECT_NAME_LENGTH as usize];
+        let name_slice = &mut slice[..name_b.len() as usize];
+        name_b.copy_into_slice(name_slice);
+        let name = String::from_bytes(&env, name_slice);
+
+        env.storage().persistent().set(
+            &key_,
+            &types::Organization {
+                name: name.clone(),
+                admins,
+                projects: Vec::new(&env),
+            },
+        );
+
+        events::OrganizationCreated {
+            organization_key: key.clone(),
+            name,
+            admin,
+        }
+        .publish(&env);
+
+        key
+    }
+
+    /// Add a project to an organization.
+    ///
+    /// Both an admin of the organization and an admin maintainer of the
+    /// project must approve. A project belongs to at most one organization.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `admin` - The address of the organization admin
+    /// * `organization_key` - The organization key identifier
+    /// * `maintainer` - The address of the project admin maintainer
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Panics
+    /// * If the organization doesn't exist
+    /// * If the admin is not an organization admin
+    /// * If the project doesn't exist or the maintainer is not an admin
+    /// * If the project already belongs to an organization
+    /// * If the maximum number of projects is reached
+    fn add_organization_project(
+        env: Env,
+        admin: Address,
+        organization_key: Bytes,
+        maintainer: Address,
+        project_key: Bytes,
+    ) {
+        Tansu::require_not_paused(env.clone());
+
+        let mut organization = auth_admins(&env, &admin, &organization_key);
+        if maintainer != admin {
+            maintainer.require_auth();
+        }
+        crate::check_maintainers_role(
+            &env,
+            &maintainer,
+            &project_key,
+            types::MaintainerRole::Admin,
+        );
+
+        let project_org_key = types::ProjectKey::Organization(project_key.clone());
+        if env.storage().persistent().has(&project_org_key)
+            || organization.projects.len() >= types::MAX_ORGANIZATION_PROJECTS
+        {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidOrganization);
+        }
+
+        organization.projects.push_back(project_key.clone());
+        env.storage().persistent().set(
+            &types::OrganizationKey::Organization(organization_key.clone()),
+            &organization,
+        );
+        env.storage()
+            .persistent()
+            .set(&project_org_key, &organization_key);
+
+        events::OrganizationProjectAdded {
+            organization_key,
+            project_key,
+        }
+        .publish(&env);
+    }
+
+    /// Remove a project from an organization.
+    ///
+    /// Either an admin of the organization or an admin maintainer of the
+    /// project can remove the project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `caller` - The address of an organization admin or project admin
+    /// * `organization_key` - The organization key identifier
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Panics
+    /// * If the organization doesn't exist
+    /// * If the caller is neither an organization admin nor a project admin
+    /// * If the project is not part of the organization
+    fn remove_organization_project(
+        env: Env,
+        caller: Address,
+        organization_key: Bytes,
+        project_key: Bytes,
+    ) {
+        Tansu::require_not_paused(env.clone());
+
+        let organization = Self::get_organization(env.clone(), organization_key.clone());
+        if organization.admins.contains(&caller) {
+            caller.require_auth();
+        } else {
+            crate::auth_maintainers_role(&env, &caller, &project_key, types::MaintainerRole::Admin);
+        }
+
+        if !organization.projects.contains(&project_key) {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidOrganization);
+        }
+        leave_organization(&env, &project_key);
+    }
+
+    /// Replace a maintainer in all the projects of an organization.
+    ///
+    /// Each admin calls this function to approve the rotation which is applied
+    /// once a majority of the admins approved it. Projects which are archived,
+    /// paused or controlled by their DAO, where the old maintainer is not a
+    /// maintainer or where the new maintainer already is one are left
+    /// untouched. The new maintainer takes over the role and the badges of
+    /// the old maintainer.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `admin` - The address of the organization admin
+    /// * `organization_key` - The organization key identifier
+    /// * `old_maintainer` - The address of the maintainer to replace
+    /// * `new_maintainer` - The address of the new maintainer
+    ///
+    /// # Returns
+    /// * `bool` - True if the rotation was applied
+    ///
+    /// # Panics
+    /// * If the organization doesn't exist
+    /// * If the admin is not an organization admin
+    fn rotate_organization_maintainer(
+        env: Env,
+        admin: Address,
+        organization_key: Bytes,
+        old_maintainer: Address,
+        new_maintainer: Address,
+    ) -> bool {
+        Tansu::require_not_paused(env.clone());
+
+        let organization = auth_admins(&env, &admin, &organization_key);
+
+        let action = types::OrganizationAction::RotateMaintainer(
+            old_maintainer.clone(),
+            new_maintainer.clone(),
+        );
+        if !approve_action(&env, &admin, &organization_key, &organization, action) {
+            return false;
+        }
+
+        for project_key in organization.projects.iter() {
+            let key_ = types::ProjectKey::Key(project_key.clone());
+            let Some(mut project) = env.storage().persistent().get::<_, types::Project>(&key_)
+            else {
+                continue;
+            };
+            if Tansu::is_archived(env.clone(), project_key.clone())
+                || Tansu::is_project_paused(env.clone(), project_key.clone())
+                || Tansu::is_dao_controlled(env.clone(), project_key.clone())
+                || project.maintainers.contains(&new_maintainer)
+            {
+                continue;
+            }
+            let Some(index) = project.maintainers.first_index_of(&old_maintainer) else {
+                continue;
+            };
+
+            let role = crate::maintainer_role(&env, &project_key, &old_maintainer);
+            project.maintainers.set(index, new_maintainer.clone());
+            env.storage().persistent().set(&key_, &project);
+
+            env.storage()
+                .persistent()
+                .remove(&types::ProjectKey::MaintainerRole(
+                    project_key.clone(),
+                    old_maintainer.clone(),
+                ));
+            env.storage().persistent().set(
+                &types::ProjectKey::MaintainerRole(project_key.clone(), new_maintainer.clone()),
+                &role,
+            );
+            contract_membership::rotate_member_badges(
+                &env,
+                &project_key,
+                &old_maintainer,
+                &new_maintainer,
+            );
+        }
+        true
+    }
+
+    /// Replace the admins of an organization.
+    ///
+    /// Each admin calls this function to approve the new admins which are set
+    /// once a majority of the current admins approved it.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `admin` - The address of the organization admin
+    /// * `organization_key` - The organization key identifier
+    /// * `admins` - New list of admin addresses
+    ///
+    /// # Returns
+    /// * `bool` - True if the admins were set
+    ///
+    /// # Panics
+    /// * If the organization doesn't exist
+    /// * If the admin is not an organization admin
+    /// * If the admins are empty or more than allowed
+    fn set_organization_admins(
+        env: Env,
+        admin: Address,
+        organization_key: Bytes,
+        admins: Vec<Address>,
+    ) -> bool {
+        Tansu::require_not_paused(env.clone());
+
+        let mut organization = auth_admins(&env, &admin, &organization_key);
+        validate_admins(&env, &admins);
+
+        let action = types::OrganizationAction::SetAdmins(admins.clone());
+        if !approve_action(&env, &admin, &organization_key, &organization, action) {
+            return false;
+        }
+
+        organization.admins = admins;
+        env.storage().persistent().set(
+            &types::OrganizationKey::Organization(organization_key),
+            &organization,
+        );
+        true
+    }
+
+    /// Get an organization.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `organization_key` - The organization key identifier
+    ///
+    /// # Returns
+    /// * `types::Organization` - The organization with its admins and projects
+    ///
+    /// # Panics
+    /// * If the organization doesn't exist
+    fn get_organization(env: Env, organization_key: Bytes) -> types::Organization {
+        env.storage()
+            .persistent()
+            .get(&types::OrganizationKey::Organization(organization_key))
+            .unwrap_or_else(|| {
+                panic_with_error!(&env, &errors::ContractErrors::UnknownOrganization);
+            })
+    }
+
+    /// Get the organization a project belongs to.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Option<Bytes>` - The organization key, if any
+    fn get_project_organization(env: Env, project_key: Bytes) -> Option<Bytes> {
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::Organization(project_key))
+    }
+}
+
+/// Remove a project from its organization, if any.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+pub fn leave_organization(env: &Env, project_key: &Bytes) {
+    let project_org_key = types::ProjectKey::Organization(project_key.clone());
+    let Some(organization_key) = env.storage().persistent().get::<_, Bytes>(&project_org_key)
+    else {
+        return;
+    };
+    env.storage().persistent().remove(&project_org_key);
+
+    let key_ = types::OrganizationKey::Organization(organization_key.clone());
+    let mut organization: types::Organization = env.storage().persistent().get(&key_).unwrap();
+    if let Some(index) = organization.projects.first_index_of(project_key) {
+        organization.projects.remove(index);
+    }
+    env.storage().persistent().set(&key_, &organization);
+
+    events::OrganizationProjectRemoved {
+        organization_key,
+        project_key: project_key.clone(),
+    }
+    .publish(env);
+}
+
+/// Authenticate an organization admin.
+///
+/// # Panics
+/// * If the organization doesn't exist
+/// * If the admin is not an organization admin
+fn auth_admins(env: &Env, admin: &Address, organization_key: &Bytes) -> types::Organization {
+    admin.require_auth();
+    let organization = Tansu::get_organization(env.clone(), organization_key.clone());
+    if !organization.admins.contains(admin) {
+        panic_with_error!(&env, &errors::ContractErrors::UnauthorizedSigner);
+    }
+    organization
+}
+
+/// Validate the admins of an organization.
+///
+/// # Panics
+/// * If the admins are empty or more than `MAX_MAINTAINERS`
+fn validate_admins(env: &Env, admins: &Vec<Address>) {
+    if admins.is_empty() || admins.len() > types::MAX_MAINTAINERS {
+        panic_with_error!(&env, &errors::ContractErrors::InvalidOrganization);
+    }
+}
+
+/// Record the approval of an admin for an action on an organization.
+///
+/// Approvals accumulate until a majority of the admins is reached. Only
+/// approvals from current admins are counted.
+///
+/// # Returns
+/// * `bool` - True if the majority is reached and the action must be executed
+fn approve_action(
+    env: &Env,
+    admin: &Address,
+    organization_key: &Bytes,
+    organization: &types::Organization,
+    action: types::OrganizationAction,
+) -> bool {
+    let approvals_key = types::OrganizationKey::Approvals(organization_key.clone(), action.clone());
+    let approvals: Vec<Address> = env
+        .storage()
+        .persistent()
+        .get(&approvals_key)
+        .unwrap_or(Vec::new(env));
+
+    let mut approvals_: Vec<Address> = Vec::new(env);
+    for approval in approvals.iter() {
+        if organization.admins.contains(&approval) {
+            approvals_.push_back(approval);
+        }
+    }
+    if !approvals_.contains(admin) {
+        approvals_.push_back(admin.clone());
+    }
+
+    let executed = approvals_.len() > organization.admins.len() / 2;
+    if executed {
+        env.storage().persistent().remove(&approvals_key);
+    } else {
+        env.storage().persistent().set(&approvals_key, &approvals_);
+    }
+
+    events::OrganizationActionApproved {
+        organization_key: organization_key.clone(),
+        admin: admin.clone(),
+        action,
+        approvals_count: approvals_.len(),
+        executed,
+    }
+    .publish(env);
+
+    executed
+}
@@ -0,0 +1,258 @@
+use soroban_sdk::{Address, Bytes, Env, String, Vec, contractimpl, panic_with_error};
+
+use crate::{
+    PackagesTrait, Tansu, TansuArgs, TansuClient, TansuTrait, VersioningTrait, errors, events,
+    semver, types, validation,
+};
+
+#[contractimpl]
+impl PackagesTrait for Tansu {
+    /// Add a package to a project.
+    ///
+    /// Packages let a monorepo track its components separately, e.g.
+    /// `tansu/contracts` and `tansu/dapp`. They share the maintainers and
+    /// the DAO of the project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the admin calling this function
+    /// * `key` - The project key identifier
+    /// * `name` - The package name
+    ///
+    /// # Returns
+    /// * `String` - The normalized package name
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not an admin
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the name is invalid or already used
+    /// * If the maximum number of packages is reached
+    fn add_package(env: Env, maintainer: Address, key: Bytes, name: String) -> String {
+        Tansu::require_not_paused(env.clone());
+
+        crate::auth_maintainers_role(&env, &maintainer, &key, types::MaintainerRole::Admin);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        let name = validation::normalize_package_name(&env, &name);
+        let mut packages = Self::get_packages(env.clone(), key.clone());
+        if packages.contains(&name) || packages.len() >= types::MAX_PACKAGES {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidPackage);
+        }
+        packages.push_back(name.clone());
+
+        env.storage()
+            .persistent()
+            .set(&types::ProjectKey::Packages(key.clone()), &packages);
+        env.storage().persistent().set(
+            &types::ProjectKey::Package(key.clone(), name.clone()),
+            &types::Package {
+                name: name.clone(),
+                hash: None,
+                releases: Vec::new(&env),
+            },
+        );
+
+        events::PackageAdded {
+            project_key: key,
+            maintainer,
+            name: name.clone(),
+        }
+        .publish(&env);
+
+        name
+    }
+
+    /// Remove a package from a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the admin calling this function
+    /// * `key` - The project key identifier
+    /// * `name` - The package name
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not an admin
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the package doesn't exist
+    fn remove_package(env: Env, maintainer: Address, key: Bytes, name: String) {
+        Tansu::require_not_paused(env.clone());
+
+        crate::auth_maintainers_role(&env, &maintainer, &key, types::MaintainerRole::Admin);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        let name = validation::normalize_package_name(&env, &name);
+        let mut packages = Self::get_packages(env.clone(), key.clone());
+        let index = packages.first_index_of(&name).unwrap_or_else(|| {
+            panic_with_error!(&env, &errors::ContractErrors::UnknownPackage);
+        });
+        packages.remove(index);
+
+        env.storage()
+            .persistent()
+            .set(&types::ProjectKey::Packages(key.clone()), &packages);
+        env.storage()
+            .persistent()
+            .remove(&types::ProjectKey::Package(key.clone(), name.clone()));
+
+        events::PackageRemoved {
+            project_key: key,
+            maintainer,
+            name,
+        }
+        .publish(&env);
+    }
+
+    /// Commit to a package of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `key` - The project key identifier
+    /// * `name` - The package name
+    /// * `hash` - The commit hash
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the project is paused
+    /// * If the package doesn't exist
+    /// * If the hash is not a commit hash of the object format of the project
+    fn commit_package(env: Env, maintainer: Address, key: Bytes, name: String, hash: String) {
+        Tansu::require_not_paused(env.clone());
+
+        crate::auth_maintainers_role(&env, &maintainer, &key, types::MaintainerRole::Committer);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+        crate::require_project_not_paused(&env, &key);
+        validation::validate_commit_hash(
+            &env,
+            &hash,
+            Self::get_hash_algorithm(env.clone(), key.clone()),
+        );
+
+        let mut package = Self::get_package(env.clone(), key.clone(), name);
+        package.hash = Some(hash.clone());
+        env.storage().persistent().set(
+            &types::ProjectKey::Package(key.clone(), package.name.clone()),
+            &package,
+        );
+
+        events::PackageCommit {
+            project_key: key,
+            name: package.name,
+            hash,
+        }
+        .publish(&env);
+    }
+
+    /// Add a release to a package of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the releaser calling this function
+    /// * `key` - The project key identifier
+    /// * `name` - The package name
+    /// * `version` - The released version
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not a releaser
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the package doesn't exist
+    /// * If the version is not a semantic version
+    /// * If the version is already released or the maximum number of
+    ///   releases is reached
+    fn release_package(env: Env, maintainer: Address, key: Bytes, name: String, version: String) {
+        Tansu::require_not_paused(env.clone());
+
+        crate::auth_maintainers_role(&env, &maintainer, &key, types::MaintainerRole::Releaser);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+        semver::validate(&env, &version);
+
+        let mut package = Self::get_package(env.clone(), key.clone(), name);
+        if package.releases.contains(&version)
+            || package.releases.len() >= types::MAX_PACKAGE_RELEASES
+        {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidPackage);
+        }
+        package.releases.push_back(version.clone());
+        env.storage().persistent().set(
+            &types::ProjectKey::Package(key.clone(), package.name.clone()),
+            &package,
+        );
+
+        events::PackageReleased {
+            project_key: key,
+            name: package.name,
+            version,
+        }
+        .publish(&env);
+    }
+
+    /// Get the package names of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Vec<String>` - The package names
+    fn get_packages(env: Env, key: Bytes) -> Vec<String> {
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::Packages(key))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get a package of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    /// * `name` - The package name
+    ///
+    /// # Returns
+    /// * `types::Package` - The package with its latest commit and releases
+    ///
+    /// # Panics
+    /// * If the package doesn't exist
+    fn get_package(env: Env, key: Bytes, name: String) -> types::Package {
+        let name = validation::normalize_package_name(&env, &name);
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::Package(key, name))
+            .unwrap_or_else(|| {
+                panic_with_error!(&env, &errors::ContractErrors::UnknownPackage);
+            })
+    }
+}
+
+/// Remove all packages of a project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+pub fn remove_packages(env: &Env, key: &Bytes) {
+    let packages_key = types::ProjectKey::Packages(key.clone());
+    let packages: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&packages_key)
+        .unwrap_or(Vec::new(env));
+    for name in packages.iter() {
+        env.storage()
+            .persistent()
+            .remove(&types::ProjectKey::Package(key.clone(), name));
+    }
+    env.storage().persistent().remove(&packages_key);
+}
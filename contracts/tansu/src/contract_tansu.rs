@@ -1,5 +1,5 @@
-use crate::{Tansu, TansuArgs, TansuClient, TansuTrait, events, types, validate_contract};
-use soroban_sdk::{Address, BytesN, Env, String, contractimpl, panic_with_error, vec};
+use crate::{Tansu, TansuArgs, TansuClient, TansuTrait, domain, events, types, validate_contract};
+use soroban_sdk::{Address, BytesN, Env, String, Symbol, contractimpl, panic_with_error, vec};
 
 #[contractimpl]
 impl TansuTrait for Tansu {
@@ -109,6 +109,45 @@ impl TansuTrait for Tansu {
         .publish(&env);
     }
 
+    /// Set the name service binding project names to their owner.
+    ///
+    /// Soroban Domains is used by default. Any other name service must
+    /// implement the `domain::NameServiceInterface`. Projects registered
+    /// through a previous provider keep their domain there.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `admin` - The admin address
+    /// * `provider` - The new domain provider
+    ///
+    /// # Panics
+    /// * If the admin is not authorized
+    /// * If the WASM hash of the name service does not match on-chain data
+    fn set_domain_provider(env: Env, admin: Address, provider: types::DomainProvider) {
+        auth_admin(&env, &admin);
+
+        if let types::DomainProvider::NameService(contract) = &provider {
+            validate_contract(&env, contract);
+        }
+
+        env.storage()
+            .instance()
+            .set(&types::ContractKey::DomainProvider, &provider);
+
+        events::DomainProviderUpdated { admin, provider }.publish(&env);
+    }
+
+    /// Get the name service binding project names to their owner.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    ///
+    /// # Returns
+    /// * `types::DomainProvider` - The domain provider
+    fn get_domain_provider(env: Env) -> types::DomainProvider {
+        domain::provider(&env)
+    }
+
     /// Set the Collateral contract.
     ///
     /// # Arguments
@@ -134,6 +173,276 @@ impl TansuTrait for Tansu {
         .publish(&env);
     }
 
+    /// Set the registry contract storing the releases and attestations of
+    /// the projects.
+    ///
+    /// The registry must be deployed with the address of this contract, see
+    /// `registry::RegistryInterface`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `admin` - The admin address
+    /// * `registry_contract` - The new registry contract
+    fn set_registry_contract(env: Env, admin: Address, registry_contract: types::Contract) {
+        auth_admin(&env, &admin);
+
+        validate_contract(&env, &registry_contract);
+
+        env.storage()
+            .instance()
+            .set(&types::ContractKey::RegistryContract, &registry_contract);
+
+        events::ContractUpdated {
+            admin,
+            contract_key: String::from_str(&env, "registry"),
+            address: registry_contract.address,
+            wasm_hash: registry_contract.wasm_hash,
+        }
+        .publish(&env);
+    }
+
+    /// Set the staking contract holding the tokens staked for voting
+    /// weight.
+    ///
+    /// The staking contract must be deployed with the address of this
+    /// contract, see `staking::StakingInterface`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `admin` - The admin address
+    /// * `staking_contract` - The new staking contract
+    fn set_staking_contract(env: Env, admin: Address, staking_contract: types::Contract) {
+        auth_admin(&env, &admin);
+
+        validate_contract(&env, &staking_contract);
+
+        env.storage()
+            .instance()
+            .set(&types::ContractKey::StakingContract, &staking_contract);
+
+        events::ContractUpdated {
+            admin,
+            contract_key: String::from_str(&env, "staking"),
+            address: staking_contract.address,
+            wasm_hash: staking_contract.wasm_hash,
+        }
+        .publish(&env);
+    }
+
+    /// Set the scheduler contract running the proposals scheduled for
+    /// execution.
+    ///
+    /// The scheduler contract must be deployed with the address of this
+    /// contract, see `scheduler::SchedulerInterface`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `admin` - The admin address
+    /// * `scheduler_contract` - The new scheduler contract
+    fn set_scheduler_contract(env: Env, admin: Address, scheduler_contract: types::Contract) {
+        auth_admin(&env, &admin);
+
+        validate_contract(&env, &scheduler_contract);
+
+        env.storage()
+            .instance()
+            .set(&types::ContractKey::SchedulerContract, &scheduler_contract);
+
+        events::ContractUpdated {
+            admin,
+            contract_key: String::from_str(&env, "scheduler"),
+            address: scheduler_contract.address,
+            wasm_hash: scheduler_contract.wasm_hash,
+        }
+        .publish(&env);
+    }
+
+    /// Set the governance contract of the councils, federations, hashed
+    /// votes, sponsorships, reward pools and conviction voting.
+    ///
+    /// The governance contract must be deployed with the address of this
+    /// contract, see `governance::GovernanceInterface`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `admin` - The admin address
+    /// * `governance_contract` - The new governance contract
+    fn set_governance_contract(env: Env, admin: Address, governance_contract: types::Contract) {
+        auth_admin(&env, &admin);
+
+        validate_contract(&env, &governance_contract);
+
+        env.storage().instance().set(
+            &types::ContractKey::GovernanceContract,
+            &governance_contract,
+        );
+
+        events::ContractUpdated {
+            admin,
+            contract_key: String::from_str(&env, "governance"),
+            address: governance_contract.address,
+            wasm_hash: governance_contract.wasm_hash,
+        }
+        .publish(&env);
+    }
+
+    /// Set the registration bond.
+    ///
+    /// The bond is deposited in the collateral asset when registering a
+    /// project. It deters name squatting and is refunded on deregistration
+    /// or once the lock period is over.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `admin` - The admin address
+    /// * `bond_config` - The new bond configuration
+    ///
+    /// # Panics
+    /// * If the admin is not authorized
+    /// * If the amount is negative
+    fn set_bond_config(env: Env, admin: Address, bond_config: types::BondConfig) {
+        auth_admin(&env, &admin);
+
+        if bond_config.amount < 0 {
+            panic_with_error!(&env, &crate::errors::ContractErrors::InsufficientBond);
+        }
+
+        env.storage()
+            .instance()
+            .set(&types::DataKey::BondConfig, &bond_config);
+
+        events::BondConfigUpdated {
+            admin,
+            amount: bond_config.amount,
+            lock_ledgers: bond_config.lock_ledgers,
+        }
+        .publish(&env);
+    }
+
+    /// Get the registration bond configuration.
+    ///
+    /// # Returns
+    /// * `types::BondConfig` - The bond configuration, no bond by default
+    fn get_bond_config(env: Env) -> types::BondConfig {
+        env.storage()
+            .instance()
+            .get(&types::DataKey::BondConfig)
+            .unwrap_or(types::BondConfig {
+                amount: 0,
+                lock_ledgers: 0,
+            })
+    }
+
+    /// Register or unregister a build attestor.
+    ///
+    /// Attestors are independent builders who can vouch for the binaries of
+    /// any project, see `attest_build`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `admin` - The admin address
+    /// * `attestor` - The address of the attestor
+    /// * `enabled` - Whether the address is an attestor
+    ///
+    /// # Panics
+    /// * If the admin is not authorized
+    fn set_attestor(env: Env, admin: Address, attestor: Address, enabled: bool) {
+        auth_admin(&env, &admin);
+
+        let attestor_key = types::DataKey::Attestor(attestor.clone());
+        if enabled {
+            env.storage().persistent().set(&attestor_key, &true);
+        } else {
+            env.storage().persistent().remove(&attestor_key);
+        }
+
+        events::AttestorUpdated {
+            admin,
+            attestor,
+            enabled,
+        }
+        .publish(&env);
+    }
+
+    /// Check if an address is a registered build attestor.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `attestor` - The address to check
+    ///
+    /// # Returns
+    /// * `bool` - True if the address is an attestor
+    fn is_attestor(env: Env, attestor: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&types::DataKey::Attestor(attestor))
+    }
+
+    /// Allow or disallow DAOs to call a contract function.
+    ///
+    /// Proposals can only call allowed functions, see
+    /// `types::DaoAction::Invoke`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `admin` - The admin address
+    /// * `contract` - The contract to call
+    /// * `function` - The function to call
+    /// * `enabled` - Whether DAOs can call the function
+    ///
+    /// # Panics
+    /// * If the admin is not authorized
+    fn set_invoke_target(
+        env: Env,
+        admin: Address,
+        contract: Address,
+        function: Symbol,
+        enabled: bool,
+    ) {
+        auth_admin(&env, &admin);
+
+        let target_key = types::DataKey::InvokeTarget(contract.clone(), function.clone());
+        if enabled {
+            env.storage().persistent().set(&target_key, &true);
+        } else {
+            env.storage().persistent().remove(&target_key);
+        }
+
+        events::InvokeTargetUpdated {
+            admin,
+            contract,
+            function,
+            enabled,
+        }
+        .publish(&env);
+    }
+
+    /// Check if DAOs can call a contract function.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `contract` - The contract to call
+    /// * `function` - The function to call
+    ///
+    /// # Returns
+    /// * `bool` - True if DAOs can call the function
+    fn is_invoke_target(env: Env, contract: Address, function: Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .has(&types::DataKey::InvokeTarget(contract, function))
+    }
+
+    /// Get the global counters of the contract.
+    ///
+    /// # Returns
+    /// * `types::Stats` - Number of projects, proposals, members and commits
+    fn get_stats(env: Env) -> types::Stats {
+        env.storage()
+            .persistent()
+            .get(&types::DataKey::Stats)
+            .unwrap_or_default()
+    }
+
     /// Propose a contract upgrade.
     ///
     /// # Arguments
@@ -325,7 +634,7 @@ impl TansuTrait for Tansu {
 }
 
 /// Authenticate that the caller is an admin, panic if not
-fn auth_admin(env: &Env, admin: &Address) -> types::AdminsConfig {
+pub fn auth_admin(env: &Env, admin: &Address) -> types::AdminsConfig {
     admin.require_auth();
     let admins_config = Tansu::get_admins_config(env.clone());
     if !admins_config.admins.contains(admin) {
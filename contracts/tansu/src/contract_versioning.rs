@@ -1,11 +1,12 @@
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, String, Symbol, Val, Vec, contractimpl, panic_with_error,
-    vec,
+    Address, Bytes, BytesN, Env, Map, String, Symbol, Val, Vec, contractimpl, panic_with_error,
+    token,
 };
 
 use crate::{
-    Tansu, TansuArgs, TansuClient, TansuTrait, VersioningTrait, domain_contract, errors, events,
-    types,
+    DaoTrait, Tansu, TansuArgs, TansuClient, TansuTrait, VersioningTrait, contract_dao,
+    contract_membership, contract_organizations, contract_packages, contract_tansu, domain, errors,
+    events, governance, registry, scheduler, staking, types, validation,
 };
 
 #[contractimpl]
@@ -14,25 +15,32 @@ impl VersioningTrait for Tansu {
     ///
     /// Creates a new project entry with maintainers, URL, and commit hash.
     /// Also registers the project name in the domain contract if not already registered.
-    /// The project key is generated using keccak256 hash of the project name.
+    /// The project name is normalized to lowercase and the project key is
+    /// generated using keccak256 hash of the normalized project name.
     ///
     /// # Arguments
     /// * `env` - The environment object
     /// * `maintainer` - The address of the maintainer calling this function
-    /// * `name` - The project name (max 15 characters)
+    /// * `name` - The project name (max 15 ASCII letters)
     /// * `maintainers` - List of maintainer addresses for the project
     /// * `url` - The project's Git repository URL
     /// * `ipfs` - CID of the tansu.toml file with associated metadata
+    /// * `license` - SPDX license identifier of the project, if any
     ///
     /// # Returns
     /// * `Bytes` - The project key (keccak256 hash of the name)
     ///
     /// # Panics
-    /// * If the project name is longer than 15 characters
+    /// * If the project name is not 1 to 15 ASCII letters
     /// * If the project already exists
     /// * If the maintainer is not authorized
     /// * If the domain registration fails
     /// * If the maintainer doesn't own an existing domain
+    /// * If the existing domain expired
+    /// * If the maintainer cannot pay the registration bond
+    /// * If there are more maintainers than allowed
+    /// * If the license identifier is invalid
+    /// * If the Git repository URL is invalid
     fn register(
         env: Env,
         maintainer: Address,
@@ -40,72 +48,448 @@ impl VersioningTrait for Tansu {
         maintainers: Vec<Address>,
         url: String,
         ipfs: String,
+        license: Option<String>,
     ) -> Bytes {
         Tansu::require_not_paused(env.clone());
 
-        let project = types::Project {
-            name: name.clone(),
-            config: types::Config { url, ipfs },
-            maintainers: maintainers.clone(),
-        };
-        let str_len = name.len() as usize;
-        if str_len > 15 {
-            // could add more checks but handled in any case with later calls
-            panic_with_error!(&env, &errors::ContractErrors::InvalidDomainError);
-        }
-        let mut slice: [u8; 15] = [0; 15];
-        name.copy_into_slice(&mut slice[..str_len]);
-        let name_b = Bytes::from_slice(&env, &slice[0..str_len]);
+        let (key, name_b, project) = new_project(&env, name, maintainers, url, ipfs, license);
 
-        let key: Bytes = env.crypto().keccak256(&name_b).into();
+        maintainer.require_auth();
+        if !project.maintainers.contains(&maintainer) {
+            panic_with_error!(&env, &errors::ContractErrors::UnauthorizedSigner);
+        }
 
-        let key_ = types::ProjectKey::Key(key.clone());
-        if env
-            .storage()
-            .persistent()
-            .get::<types::ProjectKey, types::Project>(&key_)
-            .is_some()
-        {
-            panic_with_error!(&env, &errors::ContractErrors::ProjectAlreadyExist);
-        } else {
-            maintainer.require_auth();
-            if !project.maintainers.contains(&maintainer) {
-                panic_with_error!(&env, &errors::ContractErrors::UnauthorizedSigner);
+        let provider = domain::provider(&env);
+        match domain::owner(&env, &provider, &name_b) {
+            Ok(None) => domain::register(&env, &provider, &name_b, &maintainer),
+            Ok(Some(owner)) => {
+                if owner != maintainer {
+                    panic_with_error!(&env, &errors::ContractErrors::MaintainerNotDomainOwner)
+                }
             }
+            Err(error) => panic_with_error!(&env, error),
+        }
+        store_project(&env, &key, &name_b, &project, &provider);
+
+        deposit_bond(&env, &key, &maintainer);
+
+        events::ProjectRegistered {
+            project_key: key.clone(),
+            name: project.name,
+            maintainer,
+        }
+        .publish(&env);
+        key
+    }
+
+    /// Register projects whose domains are already owned by a maintainer.
+    ///
+    /// Used by the contract admins to migrate an existing registry in a
+    /// single invocation. No domain is registered and no bond is deposited:
+    /// the domain of each project must already be owned by its `owner`,
+    /// which must be one of its maintainers. Each maintainer, the owner
+    /// included, authorizes the registration of its projects.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `admin` - The address of the contract admin calling this function
+    /// * `projects` - The projects to register, at most `MAX_REGISTER_BATCH`
+    ///
+    /// # Returns
+    /// * `Vec<Bytes>` - The project keys, in the order of `projects`
+    ///
+    /// # Panics
+    /// * If the admin or a maintainer is not authorized
+    /// * If there are more projects than allowed
+    /// * If a project name is invalid or already registered
+    /// * If the owner of a project is not one of its maintainers
+    /// * If the domain of a project is not owned by its owner
+    /// * If there are more maintainers than allowed
+    fn register_batch(
+        env: Env,
+        admin: Address,
+        projects: Vec<types::ProjectRegistration>,
+    ) -> Vec<Bytes> {
+        Tansu::require_not_paused(env.clone());
 
-            let domain_contract =
-                crate::retrieve_contract(&env, types::ContractKey::DomainContract);
+        contract_tansu::auth_admin(&env, &admin);
+        if projects.len() > types::MAX_REGISTER_BATCH {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidBatch);
+        }
 
-            let node = domain_node(&env, &key);
-            let record_keys = domain_contract::RecordKeys::Record(node);
+        let provider = domain::provider(&env);
+        let mut keys = Vec::new(&env);
+        let mut authorized = Vec::new(&env);
+        for registration in projects.iter() {
+            let (key, name_b, project) = new_project(
+                &env,
+                registration.name,
+                registration.maintainers,
+                registration.url,
+                registration.ipfs,
+                registration.license,
+            );
 
-            let domain_client = domain_contract::Client::new(&env, &domain_contract.address);
-            match domain_client.try_record(&record_keys) {
-                Ok(Ok(None)) => {
-                    domain_register(&env, &name_b, &maintainer, domain_contract.address)
+            let owner = registration.owner;
+            if !project.maintainers.contains(&owner) {
+                panic_with_error!(&env, &errors::ContractErrors::UnauthorizedSigner);
+            }
+            for maintainer in project.maintainers.iter() {
+                if !authorized.contains(&maintainer) {
+                    maintainer.require_auth();
+                    authorized.push_back(maintainer);
                 }
-                Ok(Ok(Some(domain_contract::Record::Domain(domain)))) => {
-                    if domain.owner != maintainer {
-                        panic_with_error!(&env, &errors::ContractErrors::MaintainerNotDomainOwner)
-                    }
+            }
+            match domain::owner(&env, &provider, &name_b) {
+                Ok(Some(domain_owner)) if domain_owner == owner => {}
+                Ok(_) => {
+                    panic_with_error!(&env, &errors::ContractErrors::MaintainerNotDomainOwner)
                 }
-                _ => panic_with_error!(&env, &errors::ContractErrors::InvalidDomainError),
+                Err(error) => panic_with_error!(&env, error),
             }
-            env.storage().persistent().set(&key_, &project);
+            store_project(&env, &key, &name_b, &project, &provider);
 
             events::ProjectRegistered {
                 project_key: key.clone(),
-                name,
-                maintainer,
+                name: project.name,
+                maintainer: owner,
             }
             .publish(&env);
-            key
+            keys.push_back(key);
+        }
+        keys
+    }
+
+    /// Make the maintainers of projects registered before maintainer roles
+    /// existed admins.
+    ///
+    /// Maintainers without a role are committers, so the contract admins
+    /// call this once for the projects registered before roles existed.
+    /// Maintainers with a role keep it.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `admin` - The address of the contract admin calling this function
+    /// * `keys` - The project keys, at most `MAX_REGISTER_BATCH`
+    ///
+    /// # Panics
+    /// * If the admin is not authorized
+    /// * If there are more projects than allowed
+    /// * If a project doesn't exist
+    fn migrate_maintainer_roles(env: Env, admin: Address, keys: Vec<Bytes>) {
+        contract_tansu::auth_admin(&env, &admin);
+        if keys.len() > types::MAX_REGISTER_BATCH {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidBatch);
+        }
+
+        for key in keys.iter() {
+            let project = Self::get_project(env.clone(), key.clone());
+            let mut maintainers = Vec::new(&env);
+            for maintainer in project.maintainers.iter() {
+                if !env
+                    .storage()
+                    .persistent()
+                    .has(&types::ProjectKey::MaintainerRole(
+                        key.clone(),
+                        maintainer.clone(),
+                    ))
+                {
+                    maintainers.push_back(maintainer);
+                }
+            }
+            crate::grant_admin_roles(&env, &key, &maintainers);
+        }
+    }
+
+    /// Rewrite projects and badges stored with the previous layouts.
+    ///
+    /// Projects registered before the upgrade miss the maintainer settings,
+    /// the license and the metadata, and their badges miss the reviewers.
+    /// The contract admins call this once for these projects, which get the
+    /// defaults of `register`. Projects already in the current layout are
+    /// left untouched.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `admin` - The address of the contract admin calling this function
+    /// * `keys` - The project keys, at most `MAX_REGISTER_BATCH`
+    ///
+    /// # Panics
+    /// * If the admin is not authorized
+    /// * If there are more projects than allowed
+    /// * If a project doesn't exist
+    fn migrate_projects(env: Env, admin: Address, keys: Vec<Bytes>) {
+        contract_tansu::auth_admin(&env, &admin);
+        if keys.len() > types::MAX_REGISTER_BATCH {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidBatch);
+        }
+
+        for key in keys.iter() {
+            let project_key = types::ProjectKey::Key(key.clone());
+            let Some(fields) = env
+                .storage()
+                .persistent()
+                .get::<_, Map<Symbol, Val>>(&project_key)
+            else {
+                panic_with_error!(&env, &errors::ContractErrors::InvalidKey);
+            };
+            if !fields.contains_key(Symbol::new(&env, "metadata")) {
+                let project: types::ProjectV1 =
+                    env.storage().persistent().get(&project_key).unwrap();
+                env.storage().persistent().set(
+                    &project_key,
+                    &types::Project {
+                        name: project.name,
+                        config: types::Config {
+                            url: project.config.url,
+                            ipfs: project.config.ipfs,
+                            mirrors: Vec::new(&env),
+                            maintainers_threshold: project.maintainers.len() / 2 + 1,
+                            max_maintainers: types::MAX_MAINTAINERS,
+                            license: None,
+                        },
+                        maintainers: project.maintainers,
+                        metadata: types::Metadata {
+                            description: None,
+                            website: None,
+                            logo: None,
+                        },
+                    },
+                );
+            }
+
+            let badges_key = types::ProjectKey::Badges(key.clone());
+            if let Some(fields) = env
+                .storage()
+                .persistent()
+                .get::<_, Map<Symbol, Val>>(&badges_key)
+                && !fields.contains_key(Symbol::new(&env, "reviewer"))
+            {
+                let badges: types::BadgesV1 = env.storage().persistent().get(&badges_key).unwrap();
+                env.storage().persistent().set(
+                    &badges_key,
+                    &types::Badges {
+                        developer: badges.developer,
+                        triage: badges.triage,
+                        community: badges.community,
+                        verified: badges.verified,
+                        reviewer: Vec::new(&env),
+                    },
+                );
+            }
+        }
+    }
+
+    /// Rewrite a page of proposals stored with the previous layout.
+    ///
+    /// Proposals created before the upgrade become text proposals without
+    /// deposit, options nor reveal, and their eligible weight is unknown.
+    /// Active proposals are indexed, see `has_active_proposals`. Pages
+    /// already in the current layout are left untouched.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `admin` - The address of the contract admin calling this function
+    /// * `key` - The project key identifier
+    /// * `page` - The page of the DAO of the project
+    ///
+    /// # Panics
+    /// * If the admin is not authorized
+    /// * If the page doesn't exist
+    fn migrate_proposals(env: Env, admin: Address, key: Bytes, page: u32) {
+        contract_tansu::auth_admin(&env, &admin);
+
+        let page_key = types::ProjectKey::Dao(key.clone(), page);
+        let Some(fields) = env
+            .storage()
+            .persistent()
+            .get::<_, Map<Symbol, Vec<Map<Symbol, Val>>>>(&page_key)
+        else {
+            panic_with_error!(&env, &errors::ContractErrors::NoProposalorPageFound);
+        };
+        let proposals = fields
+            .get(Symbol::new(&env, "proposals"))
+            .unwrap_or(Vec::new(&env));
+        match proposals.first() {
+            Some(proposal) if !proposal.contains_key(Symbol::new(&env, "kind")) => {}
+            _ => return,
+        }
+
+        let dao: types::DaoV1 = env.storage().persistent().get(&page_key).unwrap();
+        let mut proposals = Vec::new(&env);
+        for proposal in dao.proposals.iter() {
+            if proposal.status == types::ProposalStatus::Active {
+                contract_dao::index_active_proposal(&env, &key, proposal.id, true);
+            }
+            proposals.push_back(types::Proposal {
+                id: proposal.id,
+                title: proposal.title,
+                proposer: proposal.proposer,
+                ipfs: proposal.ipfs,
+                vote_data: types::VoteData {
+                    voting_ends_at: proposal.vote_data.voting_ends_at,
+                    public_voting: proposal.vote_data.public_voting,
+                    votes: proposal.vote_data.votes,
+                    eligible_weight: 0,
+                },
+                status: proposal.status,
+                outcomes_contract: proposal.outcomes_contract,
+                kind: types::ProposalKind::Text,
+                created_at: 0,
+                deposit: 0,
+                depends_on: None,
+                options: Vec::new(&env),
+                winning_option: None,
+                cosigner: None,
+                reveal_ends_at: None,
+            });
+        }
+        env.storage()
+            .persistent()
+            .set(&page_key, &types::Dao { proposals });
+    }
+
+    /// Register a new project forked from a registered project.
+    ///
+    /// Registers the project like `register` and records the fork-of link
+    /// so the provenance of the codebase can be queried.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `name` - The project name (max 15 ASCII letters)
+    /// * `maintainers` - List of maintainer addresses for the project
+    /// * `url` - The project's Git repository URL
+    /// * `ipfs` - CID of the tansu.toml file with associated metadata
+    /// * `parent_key` - The key of the project which is forked
+    /// * `license` - SPDX license identifier of the fork, if any
+    ///
+    /// # Returns
+    /// * `Bytes` - The project key of the fork
+    ///
+    /// # Panics
+    /// * If the parent project doesn't exist
+    /// * If the parent project has the maximum number of forks, see
+    ///   `remove_fork`
+    /// * If the project cannot be registered, see `register`
+    #[allow(clippy::too_many_arguments)]
+    fn register_fork(
+        env: Env,
+        maintainer: Address,
+        name: String,
+        maintainers: Vec<Address>,
+        url: String,
+        ipfs: String,
+        parent_key: Bytes,
+        license: Option<String>,
+    ) -> Bytes {
+        Self::get_project(env.clone(), parent_key.clone());
+
+        let forks_key = types::ProjectKey::Forks(parent_key.clone());
+        let mut forks = Self::get_forks(env.clone(), parent_key.clone());
+        if forks.len() >= types::MAX_FORKS {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidFork);
+        }
+
+        let key = Self::register(
+            env.clone(),
+            maintainer.clone(),
+            name,
+            maintainers,
+            url,
+            ipfs,
+            license,
+        );
+
+        forks.push_back(key.clone());
+        env.storage().persistent().set(&forks_key, &forks);
+        env.storage()
+            .persistent()
+            .set(&types::ProjectKey::ForkOf(key.clone()), &parent_key);
+
+        events::ProjectForked {
+            project_key: key.clone(),
+            parent_key,
+            maintainer,
+        }
+        .publish(&env);
+        key
+    }
+
+    /// Get the project a project was forked from.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Option<Bytes>` - The key of the parent project, `None` if not a fork
+    fn get_fork_parent(env: Env, key: Bytes) -> Option<Bytes> {
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::ForkOf(key))
+    }
+
+    /// Get the projects forked from a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Vec<Bytes>` - The keys of the forks, in registration order
+    fn get_forks(env: Env, key: Bytes) -> Vec<Bytes> {
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::Forks(key))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Remove the fork-of link of a project forked from a project.
+    ///
+    /// Projects have at most `MAX_FORKS` forks, maintainers of the parent can
+    /// prune forks they do not want to list anymore so that new forks can be
+    /// registered. The fork itself stays registered.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of a maintainer of the parent project
+    /// * `key` - The key of the parent project
+    /// * `fork_key` - The key of the fork to remove
+    ///
+    /// # Panics
+    /// * If the parent project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the project is not a fork of the parent project
+    fn remove_fork(env: Env, maintainer: Address, key: Bytes, fork_key: Bytes) {
+        Tansu::require_not_paused(env.clone());
+
+        crate::auth_maintainers(&env, &maintainer, &key);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        if Self::get_fork_parent(env.clone(), fork_key.clone()) != Some(key.clone()) {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidFork);
+        }
+        unlink_fork(&env, &key, &fork_key);
+
+        events::ForkRemoved {
+            project_key: key,
+            fork_key,
+            maintainer,
         }
+        .publish(&env);
     }
 
     /// Update the configuration of an existing project.
     ///
     /// Allows maintainers to change the project's URL, commit hash, and maintainer list.
+    /// Each maintainer calls this function with the same configuration to
+    /// approve it. The configuration is applied once the maintainers
+    /// threshold of the project is reached, so a single maintainer key
+    /// cannot repoint the repository.
     ///
     /// # Arguments
     /// * `env` - The environment object
@@ -115,9 +499,18 @@ impl VersioningTrait for Tansu {
     /// * `url` - New Git repository URL
     /// * `hash` - New commit hash
     ///
+    /// # Returns
+    /// * `bool` - True if the configuration was applied
+    ///
     /// # Panics
     /// * If the project doesn't exist
-    /// * If the maintainer is not authorized
+    /// * If the maintainer is not an admin
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the maintainers don't match the maintainers configuration
+    /// * If none of the maintainers is an admin
+    /// * If the Git repository URL is invalid
+    /// * If the CID of the tansu.toml file is malformed
     fn update_config(
         env: Env,
         maintainer: Address,
@@ -125,156 +518,2616 @@ impl VersioningTrait for Tansu {
         maintainers: Vec<Address>,
         url: String,
         ipfs: String,
-    ) {
+    ) -> bool {
         Tansu::require_not_paused(env.clone());
 
         let key_ = types::ProjectKey::Key(key.clone());
 
-        let mut project = crate::auth_maintainers(&env, &maintainer, &key);
+        let mut project =
+            crate::auth_maintainers_role(&env, &maintainer, &key, types::MaintainerRole::Admin);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
 
-        let config = types::Config { url, ipfs };
-        project.config = config;
-        project.maintainers = maintainers;
-        env.storage().persistent().set(&key_, &project);
+        crate::validate_maintainers(&env, &maintainers, &project.config);
+        crate::require_admin_maintainer(&env, &key, &maintainers);
+        validation::validate_url(&env, &url);
+        validation::validate_cid(&env, &ipfs);
 
-        events::ProjectConfigUpdated {
-            project_key: key,
-            maintainer,
+        let action =
+            types::MaintainersAction::UpdateConfig(maintainers.clone(), url.clone(), ipfs.clone());
+        if !crate::approve_maintainers_action(&env, &maintainer, &key, &project, action) {
+            return false;
         }
-        .publish(&env);
+
+        crate::prune_maintainer_roles(&env, &key, &project.maintainers, &maintainers);
+        for (changed, field) in [
+            (project.config.url != url, types::ConfigField::Url),
+            (project.config.ipfs != ipfs, types::ConfigField::Ipfs),
+            (
+                project.maintainers != maintainers,
+                types::ConfigField::Maintainers,
+            ),
+        ] {
+            if changed {
+                crate::publish_config_updated(&env, &key, &maintainer, field);
+            }
+        }
+        if project.config.ipfs != ipfs {
+            crate::record_metadata_commitment(&env, &key, &ipfs);
+        }
+        project.config.url = url;
+        project.config.ipfs = ipfs;
+        project.maintainers = maintainers;
+        env.storage().persistent().set(&key_, &project);
+        true
     }
 
-    /// Set the latest commit hash for a project.
+    /// Add a maintainer to a project.
     ///
-    /// Updates the current commit hash for the specified project.
+    /// Each maintainer calls this function to approve adding the new
+    /// maintainer. The maintainer is added once the maintainers threshold of
+    /// the project is reached.
     ///
     /// # Arguments
     /// * `env` - The environment object
     /// * `maintainer` - The address of the maintainer calling this function
-    /// * `project_key` - The project key identifier
-    /// * `hash` - The new commit hash
+    /// * `key` - The project key identifier
+    /// * `new_maintainer` - The address of the maintainer to add
+    ///
+    /// # Returns
+    /// * `bool` - True if the maintainer was added
     ///
     /// # Panics
     /// * If the project doesn't exist
-    /// * If the maintainer is not authorized
-    fn commit(env: Env, maintainer: Address, project_key: Bytes, hash: String) {
+    /// * If the maintainer is not an admin
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the new maintainer is already a maintainer
+    /// * If the maximum number of maintainers is reached
+    fn add_maintainer(env: Env, maintainer: Address, key: Bytes, new_maintainer: Address) -> bool {
         Tansu::require_not_paused(env.clone());
 
-        crate::auth_maintainers(&env, &maintainer, &project_key);
+        let mut project =
+            crate::auth_maintainers_role(&env, &maintainer, &key, types::MaintainerRole::Admin);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        if project.maintainers.contains(&new_maintainer)
+            || project.maintainers.len() >= project.config.max_maintainers
+        {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidMaintainersConfig);
+        }
+
+        let action = types::MaintainersAction::AddMaintainer(new_maintainer.clone());
+        if !crate::approve_maintainers_action(&env, &maintainer, &key, &project, action) {
+            return false;
+        }
+
+        project.maintainers.push_back(new_maintainer);
         env.storage()
             .persistent()
-            .set(&types::ProjectKey::LastHash(project_key.clone()), &hash);
-
-        events::Commit { project_key, hash }.publish(&env);
+            .set(&types::ProjectKey::Key(key.clone()), &project);
+        crate::publish_config_updated(&env, &key, &maintainer, types::ConfigField::Maintainers);
+        true
     }
 
-    /// Get the last commit hash
-    /// Get the latest commit hash for a project.
+    /// Remove a maintainer from a project.
+    ///
+    /// Each maintainer calls this function to approve removing the
+    /// maintainer. The maintainer is removed once the maintainers threshold
+    /// of the project is reached.
     ///
     /// # Arguments
     /// * `env` - The environment object
-    /// * `project_key` - The project key identifier
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `key` - The project key identifier
+    /// * `old_maintainer` - The address of the maintainer to remove
     ///
     /// # Returns
-    /// * `String` - The current commit hash
+    /// * `bool` - True if the maintainer was removed
     ///
     /// # Panics
     /// * If the project doesn't exist
-    fn get_commit(env: Env, project_key: Bytes) -> String {
-        let key_ = types::ProjectKey::Key(project_key.clone());
-        if env
-            .storage()
-            .persistent()
-            .get::<types::ProjectKey, types::Project>(&key_)
-            .is_some()
-        {
-            env.storage()
-                .persistent()
-                .get(&types::ProjectKey::LastHash(project_key))
-                .unwrap_or_else(|| {
-                    panic_with_error!(&env, &errors::ContractErrors::NoHashFound);
-                })
-        } else {
-            panic_with_error!(&env, &errors::ContractErrors::InvalidKey);
+    /// * If the maintainer is not an admin
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the old maintainer is not a maintainer
+    /// * If the threshold could not be reached anymore after the removal
+    /// * If no admin would be left after the removal
+    fn remove_maintainer(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        old_maintainer: Address,
+    ) -> bool {
+        Tansu::require_not_paused(env.clone());
+
+        let mut project =
+            crate::auth_maintainers_role(&env, &maintainer, &key, types::MaintainerRole::Admin);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        let index = project
+            .maintainers
+            .first_index_of(&old_maintainer)
+            .unwrap_or_else(|| {
+                panic_with_error!(&env, &errors::ContractErrors::InvalidMaintainersConfig)
+            });
+        let mut maintainers = project.maintainers.clone();
+        maintainers.remove(index);
+        crate::validate_maintainers(&env, &maintainers, &project.config);
+        crate::require_admin_maintainer(&env, &key, &maintainers);
+
+        let action = types::MaintainersAction::RemoveMaintainer(old_maintainer);
+        if !crate::approve_maintainers_action(&env, &maintainer, &key, &project, action) {
+            return false;
         }
+
+        crate::prune_maintainer_roles(&env, &key, &project.maintainers, &maintainers);
+        project.maintainers = maintainers;
+        env.storage()
+            .persistent()
+            .set(&types::ProjectKey::Key(key.clone()), &project);
+        crate::publish_config_updated(&env, &key, &maintainer, types::ConfigField::Maintainers);
+        true
     }
 
-    /// Get project information including configuration and maintainers.
+    /// Replace the key of a maintainer.
+    ///
+    /// The new address takes the place of the old one in the maintainers
+    /// list, keeps its role and badges, and replaces it as proposer and voter
+    /// of the active proposals of the project. If the old key is lost, each
+    /// maintainer calls this function to approve the rotation which is
+    /// applied once the maintainers threshold is reached. Otherwise the old
+    /// key can rotate itself directly.
     ///
     /// # Arguments
     /// * `env` - The environment object
-    /// * `project_key` - The project key identifier
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `key` - The project key identifier
+    /// * `old_maintainer` - The address of the maintainer to replace
+    /// * `new_maintainer` - The new address of the maintainer
     ///
     /// # Returns
-    /// * `types::Project` - Project information including name, config, and maintainers
+    /// * `bool` - True if the maintainer was rotated
     ///
     /// # Panics
     /// * If the project doesn't exist
-    fn get_project(env: Env, project_key: Bytes) -> types::Project {
-        let key_ = types::ProjectKey::Key(project_key.clone());
+    /// * If the maintainer is not authorized
+    /// * If the new maintainer did not authorize the rotation
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the old maintainer is not a maintainer
+    /// * If the new maintainer is already a maintainer
+    /// * If the new maintainer already voted on an active proposal
+    fn rotate_maintainer(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        old_maintainer: Address,
+        new_maintainer: Address,
+    ) -> bool {
+        Tansu::require_not_paused(env.clone());
+
+        let mut project = crate::auth_maintainers(&env, &maintainer, &key);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        let index = project
+            .maintainers
+            .first_index_of(&old_maintainer)
+            .unwrap_or_else(|| {
+                panic_with_error!(&env, &errors::ContractErrors::InvalidMaintainersConfig)
+            });
+        if project.maintainers.contains(&new_maintainer) {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidMaintainersConfig);
+        }
+
+        if maintainer != old_maintainer {
+            let action = types::MaintainersAction::RotateMaintainer(
+                old_maintainer.clone(),
+                new_maintainer.clone(),
+            );
+            if !crate::approve_maintainers_action(&env, &maintainer, &key, &project, action) {
+                return false;
+            }
+        }
+        new_maintainer.require_auth();
 
+        project.maintainers.set(index, new_maintainer.clone());
         env.storage()
             .persistent()
-            .get::<types::ProjectKey, types::Project>(&key_)
-            .unwrap_or_else(|| {
-                panic_with_error!(&env, &errors::ContractErrors::InvalidKey);
-            })
+            .set(&types::ProjectKey::Key(key.clone()), &project);
+
+        let old_role_key = types::ProjectKey::MaintainerRole(key.clone(), old_maintainer.clone());
+        if let Some(role) = env
+            .storage()
+            .persistent()
+            .get::<_, types::MaintainerRole>(&old_role_key)
+        {
+            env.storage().persistent().remove(&old_role_key);
+            env.storage().persistent().set(
+                &types::ProjectKey::MaintainerRole(key.clone(), new_maintainer.clone()),
+                &role,
+            );
+        }
+
+        contract_membership::rotate_member_badges(&env, &key, &old_maintainer, &new_maintainer);
+        contract_dao::rotate_voter(&env, &key, &old_maintainer, &new_maintainer);
+        crate::publish_config_updated(&env, &key, &maintainer, types::ConfigField::Maintainers);
+
+        events::MaintainerRotated {
+            project_key: key,
+            old_maintainer,
+            new_maintainer,
+        }
+        .publish(&env);
+        true
     }
-}
 
-/// Register a Soroban Domain: https://sorobandomains.org
-/// Register a project name in the domain contract.
+    /// Set the maintainers configuration of a project.
+    ///
+    /// Each maintainer calls this function to approve the new configuration
+    /// which is applied once the current maintainers threshold is reached.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `key` - The project key identifier
+    /// * `maintainers_threshold` - Approvals required to change maintainers
+    /// * `max_maintainers` - Cap on the number of maintainers
+    ///
+    /// # Returns
+    /// * `bool` - True if the configuration was applied
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not an admin
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the configuration is invalid for the current maintainers
+    fn set_maintainers_config(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        maintainers_threshold: u32,
+        max_maintainers: u32,
+    ) -> bool {
+        Tansu::require_not_paused(env.clone());
+
+        let mut project =
+            crate::auth_maintainers_role(&env, &maintainer, &key, types::MaintainerRole::Admin);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        let mut config = project.config.clone();
+        config.maintainers_threshold = maintainers_threshold;
+        config.max_maintainers = max_maintainers;
+        crate::validate_maintainers(&env, &project.maintainers, &config);
+
+        let action =
+            types::MaintainersAction::SetMaintainersConfig(maintainers_threshold, max_maintainers);
+        if !crate::approve_maintainers_action(&env, &maintainer, &key, &project, action) {
+            return false;
+        }
+
+        project.config = config;
+        env.storage()
+            .persistent()
+            .set(&types::ProjectKey::Key(key.clone()), &project);
+        crate::publish_config_updated(
+            &env,
+            &key,
+            &maintainer,
+            types::ConfigField::MaintainersConfig,
+        );
+        true
+    }
+
+    /// Set the role of a maintainer of a project.
+    ///
+    /// Only admins can approve a role change. The role is set once the
+    /// maintainers threshold of the project is reached.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the admin calling this function
+    /// * `key` - The project key identifier
+    /// * `member` - The address of the maintainer getting the role
+    /// * `role` - The new role
+    ///
+    /// # Returns
+    /// * `bool` - True if the role was set
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not an admin
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the member is not a maintainer
+    /// * If no admin would be left after the change
+    fn set_maintainer_role(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        member: Address,
+        role: types::MaintainerRole,
+    ) -> bool {
+        Tansu::require_not_paused(env.clone());
+
+        let project =
+            crate::auth_maintainers_role(&env, &maintainer, &key, types::MaintainerRole::Admin);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        let index = project
+            .maintainers
+            .first_index_of(&member)
+            .unwrap_or_else(|| {
+                panic_with_error!(&env, &errors::ContractErrors::InvalidMaintainersConfig)
+            });
+        if role != types::MaintainerRole::Admin {
+            let mut admins = project.maintainers.clone();
+            admins.remove(index);
+            crate::require_admin_maintainer(&env, &key, &admins);
+        }
+
+        let action = types::MaintainersAction::SetMaintainerRole(member.clone(), role);
+        if !crate::approve_maintainers_action(&env, &maintainer, &key, &project, action) {
+            return false;
+        }
+
+        env.storage().persistent().set(
+            &types::ProjectKey::MaintainerRole(key.clone(), member),
+            &role,
+        );
+        crate::publish_config_updated(&env, &key, &maintainer, types::ConfigField::MaintainerRole);
+        true
+    }
+
+    /// Get the role of a maintainer of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    /// * `maintainer` - The address of the maintainer
+    ///
+    /// # Returns
+    /// * `types::MaintainerRole` - The role of the maintainer
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the address is not a maintainer
+    fn get_maintainer_role(env: Env, key: Bytes, maintainer: Address) -> types::MaintainerRole {
+        let project = Self::get_project(env.clone(), key.clone());
+        if !project.maintainers.contains(&maintainer) {
+            panic_with_error!(&env, &errors::ContractErrors::UnauthorizedSigner);
+        }
+        crate::maintainer_role(&env, &key, &maintainer)
+    }
+
+    /// Deregister a project.
+    ///
+    /// Each admin calls this function to approve the removal of the project.
+    /// Once the maintainers threshold is reached, the removal is scheduled
+    /// after a cooldown period. A call after the cooldown removes the project,
+    /// its DAO, badges and commit data and releases the Soroban Domain so the
+    /// name can be reused. Releasing the domain requires the authorization of
+    /// its owner, hence the domain owner should make the final call.
+    ///
+    /// The registration bond, if any, is refunded. Open reward pools return
+    /// to the treasury, which is sent to the maintainer making the final
+    /// call, tokens which cannot be transferred are abandoned.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `bool` - True if the project was removed
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not an admin
+    /// * If the project has active proposals
+    /// * If the project is controlled by its DAO
+    fn deregister(env: Env, maintainer: Address, key: Bytes) -> bool {
+        Tansu::require_not_paused(env.clone());
+
+        let project =
+            crate::auth_maintainers_role(&env, &maintainer, &key, types::MaintainerRole::Admin);
+        crate::require_not_dao_controlled(&env, &key);
+
+        if contract_dao::has_active_proposals(&env, &key) {
+            panic_with_error!(&env, &errors::ContractErrors::ProposalActive);
+        }
+
+        let deregistration_key = types::ProjectKey::Deregistration(key.clone());
+        let executable_at = match env.storage().persistent().get(&deregistration_key) {
+            Some(executable_at) => executable_at,
+            None => {
+                let action = types::MaintainersAction::Deregister;
+                if !crate::approve_maintainers_action(&env, &maintainer, &key, &project, action) {
+                    return false;
+                }
+                let executable_at = env.ledger().timestamp() + types::DEREGISTRATION_DELAY;
+                env.storage()
+                    .persistent()
+                    .set(&deregistration_key, &executable_at);
+
+                events::DeregistrationApproved {
+                    project_key: key.clone(),
+                    maintainer: maintainer.clone(),
+                    executable_at,
+                }
+                .publish(&env);
+                executable_at
+            }
+        };
+        if env.ledger().timestamp() < executable_at {
+            return false;
+        }
+
+        // release the domain if it is still held by the project
+        let name_b = validation::normalize_project_name(&env, &project.name);
+        let provider = domain::project_provider(&env, &key);
+        let held = match domain::lookup(&env, &provider, &name_b) {
+            Ok(Some((owner, _))) => project.maintainers.contains(&owner),
+            Err(errors::ContractErrors::DomainExpired) => matches!(
+                domain_status(&env, &key, &name_b),
+                types::DomainStatus::Grace(_)
+            ),
+            _ => false,
+        };
+        if held {
+            domain::release(&env, &provider, &name_b);
+        }
+
+        refund_bond(&env, &key);
+        for (token, amount) in governance::client(&env).remove_project(&key).iter() {
+            contract_dao::credit_treasury(&env, &key, &token, amount);
+        }
+        staking::client(&env).remove_project(&key);
+        contract_dao::sweep_treasury(&env, &key, &maintainer);
+        unindex_project(&env, &key);
+        for tag in Self::get_tags(env.clone(), key.clone()).iter() {
+            unindex_tag(&env, &tag, &key);
+        }
+        remove_fork_links(&env, &key);
+        remove_commit_history(&env, &key);
+        remove_refs(&env, &key);
+        contract_dao::remove_dao(&env, &key);
+        contract_packages::remove_packages(&env, &key);
+        registry::client(&env).remove_project(&key);
+        scheduler::client(&env).remove_project(&key);
+        contract_organizations::leave_organization(&env, &key);
+        contract_membership::remove_project_badges(&env, &key);
+        crate::prune_maintainer_roles(&env, &key, &project.maintainers, &Vec::new(&env));
+        for key_ in [
+            types::ProjectKey::LastHash(key.clone()),
+            types::ProjectKey::Archived(key.clone()),
+            types::ProjectKey::Paused(key.clone()),
+            types::ProjectKey::DomainExpiry(key.clone()),
+            types::ProjectKey::Tags(key.clone()),
+            types::ProjectKey::DomainVerification(key.clone()),
+            types::ProjectKey::Deprecated(key.clone()),
+            types::ProjectKey::Stats(key.clone()),
+            types::ProjectKey::MetadataHistory(key.clone()),
+            types::ProjectKey::HashAlgorithm(key.clone()),
+            types::ProjectKey::CommitCooldown(key.clone()),
+            deregistration_key,
+            types::ProjectKey::Key(key.clone()),
+        ] {
+            env.storage().persistent().remove(&key_);
+        }
+        env.storage()
+            .persistent()
+            .remove(&types::DomainKey::Owner(key.clone()));
+        env.storage()
+            .persistent()
+            .remove(&types::DomainKey::Provider(key.clone()));
+
+        events::ProjectDeregistered {
+            project_key: key,
+            maintainer,
+        }
+        .publish(&env);
+        true
+    }
+
+    /// Claim back the registration bond of a project.
+    ///
+    /// The bond is refunded to the address which deposited it once the
+    /// project is still active after the lock period, that is once it
+    /// committed after the end of the lock period. Projects which are not
+    /// developed only get their bond back when deregistered.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `key` - The project key identifier
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If there is no bond for the project
+    /// * If the project did not commit since the end of the lock period
+    fn claim_bond(env: Env, maintainer: Address, key: Bytes) {
+        Tansu::require_not_paused(env.clone());
+
+        crate::auth_maintainers(&env, &maintainer, &key);
+
+        let bond = Self::get_bond(env.clone(), key.clone())
+            .unwrap_or_else(|| panic_with_error!(&env, &errors::ContractErrors::InvalidKey));
+        let stats = Self::get_project_stats(env.clone(), key.clone());
+        if stats.last_commit_ledger < bond.refundable_at {
+            panic_with_error!(&env, &errors::ContractErrors::BondLocked);
+        }
+
+        refund_bond(&env, &key);
+    }
+
+    /// Get the registration bond held for a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Option<types::Bond>` - The bond, if any
+    fn get_bond(env: Env, key: Bytes) -> Option<types::Bond> {
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::Bond(key))
+    }
+
+    /// Add a mirror repository to a project.
+    ///
+    /// Mirrors are additional VCS locations (GitLab, self-hosted, etc.) which
+    /// serve the same repository as the main project URL.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `key` - The project key identifier
+    /// * `mirror` - The mirror to add
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the URL is invalid or already registered
+    /// * If the maximum number of mirrors is reached
+    fn add_mirror(env: Env, maintainer: Address, key: Bytes, mirror: types::RepoRef) {
+        Tansu::require_not_paused(env.clone());
+
+        let key_ = types::ProjectKey::Key(key.clone());
+
+        let mut project = crate::auth_maintainers(&env, &maintainer, &key);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        validation::validate_url(&env, &mirror.url);
+        if project.config.mirrors.len() >= types::MAX_MIRRORS
+            || project.config.url == mirror.url
+            || project
+                .config
+                .mirrors
+                .iter()
+                .any(|mirror_| mirror_.url == mirror.url)
+        {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidUrl);
+        }
+
+        project.config.mirrors.push_back(mirror.clone());
+        env.storage().persistent().set(&key_, &project);
+
+        events::MirrorAdded {
+            project_key: key,
+            maintainer,
+            name: mirror.name,
+            url: mirror.url,
+        }
+        .publish(&env);
+    }
+
+    /// Remove a mirror repository from a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `key` - The project key identifier
+    /// * `url` - The URL of the mirror to remove
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the mirror is not registered
+    fn remove_mirror(env: Env, maintainer: Address, key: Bytes, url: String) {
+        Tansu::require_not_paused(env.clone());
+
+        let key_ = types::ProjectKey::Key(key.clone());
+
+        let mut project = crate::auth_maintainers(&env, &maintainer, &key);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        let index = project
+            .config
+            .mirrors
+            .iter()
+            .position(|mirror_| mirror_.url == url)
+            .unwrap_or_else(|| panic_with_error!(&env, &errors::ContractErrors::InvalidUrl));
+        project.config.mirrors.remove(index as u32);
+        env.storage().persistent().set(&key_, &project);
+
+        events::MirrorRemoved {
+            project_key: key,
+            maintainer,
+            url,
+        }
+        .publish(&env);
+    }
+
+    /// Update the metadata of a project.
+    ///
+    /// The previous metadata is part of the emitted event so that indexers
+    /// can reconstruct the full history of a project's metadata.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `key` - The project key identifier
+    /// * `metadata` - New metadata (description CID, website, logo CID)
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the description or logo CID is malformed
+    fn update_metadata(env: Env, maintainer: Address, key: Bytes, metadata: types::Metadata) {
+        Tansu::require_not_paused(env.clone());
+
+        let key_ = types::ProjectKey::Key(key.clone());
+
+        let mut project = crate::auth_maintainers(&env, &maintainer, &key);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+        for cid in [&metadata.description, &metadata.logo]
+            .into_iter()
+            .flatten()
+        {
+            validation::validate_cid(&env, cid);
+        }
+
+        let previous = core::mem::replace(&mut project.metadata, metadata.clone());
+        env.storage().persistent().set(&key_, &project);
+
+        events::ProjectMetadataUpdated {
+            project_key: key,
+            maintainer,
+            previous,
+            metadata,
+        }
+        .publish(&env);
+    }
+
+    /// Transfer a project to a new set of maintainers.
+    ///
+    /// Each admin calls this function to approve the transfer which is
+    /// applied once the maintainers threshold is reached. The domain bound to
+    /// the project is transferred to the new lead maintainer with the
+    /// authorization of its owner, who must therefore be the maintainer
+    /// reaching the threshold. The maintainer list is replaced in the same
+    /// operation.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `key` - The project key identifier
+    /// * `new_maintainer` - The address of the new lead maintainer (domain owner)
+    /// * `maintainers` - New list of maintainer addresses
+    ///
+    /// # Returns
+    /// * `bool` - True if the project was transferred
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not an admin
+    /// * If the new lead maintainer is not the first of the new maintainers
+    /// * If the maintainer applying the transfer doesn't own the project's domain
+    /// * If the project's domain expired, it must be renewed first
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If there are more maintainers than allowed
+    fn transfer_project(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        new_maintainer: Address,
+        maintainers: Vec<Address>,
+    ) -> bool {
+        Tansu::require_not_paused(env.clone());
+
+        let key_ = types::ProjectKey::Key(key.clone());
+
+        let mut project =
+            crate::auth_maintainers_role(&env, &maintainer, &key, types::MaintainerRole::Admin);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        if maintainers.first().as_ref() != Some(&new_maintainer) {
+            panic_with_error!(&env, &errors::ContractErrors::UnauthorizedSigner);
+        }
+
+        // the new maintainers start from a simple majority
+        let mut config = project.config.clone();
+        config.maintainers_threshold = maintainers.len() / 2 + 1;
+        crate::validate_maintainers(&env, &maintainers, &config);
+
+        let name_b = validation::normalize_project_name(&env, &project.name);
+        let owner = match domain_status(&env, &key, &name_b) {
+            types::DomainStatus::Active(owner, _) => owner,
+            types::DomainStatus::Grace(_) | types::DomainStatus::Expired => {
+                panic_with_error!(&env, &errors::ContractErrors::DomainExpired)
+            }
+            types::DomainStatus::Unregistered => {
+                panic_with_error!(&env, &errors::ContractErrors::InvalidDomainError)
+            }
+        };
+
+        let action =
+            types::MaintainersAction::TransferProject(new_maintainer.clone(), maintainers.clone());
+        if !crate::approve_maintainers_action(&env, &maintainer, &key, &project, action) {
+            return false;
+        }
+
+        if owner != maintainer {
+            panic_with_error!(&env, &errors::ContractErrors::MaintainerNotDomainOwner)
+        }
+        new_maintainer.require_auth();
+        project.config = config;
+
+        if new_maintainer != maintainer {
+            domain::transfer(
+                &env,
+                &domain::project_provider(&env, &key),
+                &name_b,
+                &new_maintainer,
+            );
+            store_domain_expiry(&env, &key, &name_b);
+        }
+
+        // the new maintainers start as admins
+        crate::prune_maintainer_roles(&env, &key, &project.maintainers, &Vec::new(&env));
+        crate::grant_admin_roles(&env, &key, &maintainers);
+        project.maintainers = maintainers;
+        env.storage().persistent().set(&key_, &project);
+        crate::publish_config_updated(&env, &key, &maintainer, types::ConfigField::Maintainers);
+
+        events::ProjectTransferred {
+            project_key: key,
+            maintainer,
+            new_maintainer,
+        }
+        .publish(&env);
+        true
+    }
+
+    /// Archive a project.
+    ///
+    /// Freezes the project: commits, configuration changes, proposals, votes
+    /// and badge changes are rejected until the project is unarchived. All
+    /// existing data stays readable. Each admin calls this function to
+    /// approve the archival which is applied once the maintainers threshold
+    /// is reached.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `bool` - True if the project was archived
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not an admin
+    /// * If the project is already archived
+    /// * If the project is controlled by its DAO
+    fn archive_project(env: Env, maintainer: Address, key: Bytes) -> bool {
+        Tansu::require_not_paused(env.clone());
+
+        let project =
+            crate::auth_maintainers_role(&env, &maintainer, &key, types::MaintainerRole::Admin);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        let action = types::MaintainersAction::ArchiveProject;
+        if !crate::approve_maintainers_action(&env, &maintainer, &key, &project, action) {
+            return false;
+        }
+
+        env.storage()
+            .persistent()
+            .set(&types::ProjectKey::Archived(key.clone()), &true);
+
+        events::ProjectArchived {
+            project_key: key,
+            archived: true,
+            maintainer,
+        }
+        .publish(&env);
+        true
+    }
+
+    /// Unarchive a project, allowing changes again.
+    ///
+    /// Each admin calls this function to approve the reactivation which is
+    /// applied once the maintainers threshold is reached.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `bool` - True if the project was unarchived
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not an admin
+    /// * If the project is not archived
+    fn unarchive_project(env: Env, maintainer: Address, key: Bytes) -> bool {
+        Tansu::require_not_paused(env.clone());
+
+        let project =
+            crate::auth_maintainers_role(&env, &maintainer, &key, types::MaintainerRole::Admin);
+
+        let archived_key = types::ProjectKey::Archived(key.clone());
+        if !env.storage().persistent().has(&archived_key) {
+            panic_with_error!(&env, &errors::AdditionalErrors::NotArchived);
+        }
+
+        let action = types::MaintainersAction::UnarchiveProject;
+        if !crate::approve_maintainers_action(&env, &maintainer, &key, &project, action) {
+            return false;
+        }
+
+        env.storage().persistent().remove(&archived_key);
+
+        events::ProjectArchived {
+            project_key: key,
+            archived: false,
+            maintainer,
+        }
+        .publish(&env);
+        true
+    }
+
+    /// Whether a project is archived.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `bool` - True if the project is archived
+    fn is_archived(env: Env, key: Bytes) -> bool {
+        env.storage()
+            .persistent()
+            .has(&types::ProjectKey::Archived(key))
+    }
+
+    /// Deprecate a project, optionally pointing to its replacement.
+    ///
+    /// Each maintainer calls this function to approve the deprecation which
+    /// is applied once the maintainers threshold is reached. The project
+    /// stays usable, the deprecation only informs users. Deprecating again
+    /// updates the successor.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `key` - The project key identifier
+    /// * `successor` - The key of the project replacing it, if any
+    ///
+    /// # Returns
+    /// * `bool` - True if the project was deprecated
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the project is controlled by its DAO
+    /// * If the successor doesn't exist or is the project itself
+    fn deprecate(env: Env, maintainer: Address, key: Bytes, successor: Option<Bytes>) -> bool {
+        Tansu::require_not_paused(env.clone());
+
+        let project = crate::auth_maintainers(&env, &maintainer, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        if let Some(successor) = &successor {
+            if *successor == key {
+                panic_with_error!(&env, &errors::ContractErrors::InvalidKey);
+            }
+            Self::get_project(env.clone(), successor.clone());
+        }
+
+        let action = types::MaintainersAction::Deprecate(successor.clone());
+        if !crate::approve_maintainers_action(&env, &maintainer, &key, &project, action) {
+            return false;
+        }
+
+        env.storage().persistent().set(
+            &types::ProjectKey::Deprecated(key.clone()),
+            &types::Deprecation {
+                successor: successor.clone(),
+                deprecated_at: env.ledger().timestamp(),
+            },
+        );
+
+        events::ProjectDeprecated {
+            project_key: key,
+            deprecated: true,
+            maintainer,
+            successor,
+        }
+        .publish(&env);
+        true
+    }
+
+    /// Withdraw the deprecation of a project.
+    ///
+    /// Each maintainer calls this function to approve withdrawing the
+    /// deprecation which is applied once the maintainers threshold is
+    /// reached.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `bool` - True if the deprecation was withdrawn
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the project is controlled by its DAO
+    /// * If the project is not deprecated
+    fn undeprecate(env: Env, maintainer: Address, key: Bytes) -> bool {
+        Tansu::require_not_paused(env.clone());
+
+        let project = crate::auth_maintainers(&env, &maintainer, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        let deprecated_key = types::ProjectKey::Deprecated(key.clone());
+        if !env.storage().persistent().has(&deprecated_key) {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidKey);
+        }
+
+        let action = types::MaintainersAction::Undeprecate;
+        if !crate::approve_maintainers_action(&env, &maintainer, &key, &project, action) {
+            return false;
+        }
+
+        env.storage().persistent().remove(&deprecated_key);
+
+        events::ProjectDeprecated {
+            project_key: key,
+            deprecated: false,
+            maintainer,
+            successor: None,
+        }
+        .publish(&env);
+        true
+    }
+
+    /// Get the deprecation of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Option<types::Deprecation>` - The deprecation, `None` if the project
+    ///   is not deprecated
+    fn get_deprecation(env: Env, key: Bytes) -> Option<types::Deprecation> {
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::Deprecated(key))
+    }
+
+    /// Pause a project.
+    ///
+    /// Each maintainer calls this function to approve the pause. Once the
+    /// maintainers threshold is reached, new proposals, votes and commits are
+    /// rejected for this project only until it is resumed.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `bool` - True if the project was paused
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the project is already paused
+    fn pause_project(env: Env, maintainer: Address, key: Bytes) -> bool {
+        Tansu::require_not_paused(env.clone());
+
+        let project = crate::auth_maintainers(&env, &maintainer, &key);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+        crate::require_project_not_paused(&env, &key);
+
+        let action = types::MaintainersAction::PauseProject;
+        if !crate::approve_maintainers_action(&env, &maintainer, &key, &project, action) {
+            return false;
+        }
+
+        env.storage()
+            .persistent()
+            .set(&types::ProjectKey::Paused(key.clone()), &true);
+
+        events::ProjectPaused {
+            project_key: key,
+            paused: true,
+            maintainer,
+        }
+        .publish(&env);
+        true
+    }
+
+    /// Resume a paused project.
+    ///
+    /// Each maintainer calls this function to approve resuming the project
+    /// which is applied once the maintainers threshold is reached.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `bool` - True if the project was resumed
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the project is controlled by its DAO
+    /// * If the project is not paused
+    fn resume_project(env: Env, maintainer: Address, key: Bytes) -> bool {
+        Tansu::require_not_paused(env.clone());
+
+        let project = crate::auth_maintainers(&env, &maintainer, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        let paused_key = types::ProjectKey::Paused(key.clone());
+        if !env.storage().persistent().has(&paused_key) {
+            panic_with_error!(&env, &errors::AdditionalErrors::NotPaused);
+        }
+
+        let action = types::MaintainersAction::ResumeProject;
+        if !crate::approve_maintainers_action(&env, &maintainer, &key, &project, action) {
+            return false;
+        }
+
+        env.storage().persistent().remove(&paused_key);
+
+        events::ProjectPaused {
+            project_key: key,
+            paused: false,
+            maintainer,
+        }
+        .publish(&env);
+        true
+    }
+
+    /// Whether a project is paused.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `bool` - True if the project is paused
+    fn is_project_paused(env: Env, key: Bytes) -> bool {
+        env.storage()
+            .persistent()
+            .has(&types::ProjectKey::Paused(key))
+    }
+
+    /// Renounce the maintainers privileges in favor of the project's DAO.
+    ///
+    /// Each maintainer calls this function to approve the renunciation. Once
+    /// the maintainers threshold is reached, maintainers cannot change the
+    /// configuration, commit or set badges directly anymore. These changes
+    /// are applied by executing approved proposals created with
+    /// `create_action_proposal`, and any address can execute proposals.
+    /// This cannot be undone.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `bool` - True if the project is now controlled by its DAO
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the project is archived
+    /// * If the project is already controlled by its DAO
+    /// * If the project is paused
+    fn renounce_to_dao(env: Env, maintainer: Address, key: Bytes) -> bool {
+        Tansu::require_not_paused(env.clone());
+
+        let project = crate::auth_maintainers(&env, &maintainer, &key);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+        crate::require_project_not_paused(&env, &key);
+
+        let action = types::MaintainersAction::RenounceToDao;
+        if !crate::approve_maintainers_action(&env, &maintainer, &key, &project, action) {
+            return false;
+        }
+
+        env.storage()
+            .persistent()
+            .set(&types::ProjectKey::DaoControlled(key.clone()), &true);
+
+        events::ProjectRenouncedToDao {
+            project_key: key,
+            maintainer,
+        }
+        .publish(&env);
+        true
+    }
+
+    /// Whether a project is controlled by its DAO.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `bool` - True if the maintainers renounced to the project
+    fn is_dao_controlled(env: Env, key: Bytes) -> bool {
+        env.storage()
+            .persistent()
+            .has(&types::ProjectKey::DaoControlled(key))
+    }
+
+    /// Renew the domain bound to a project.
+    ///
+    /// The maintainer calling this function must own the domain. Once the
+    /// domain expired its owner cannot be resolved anymore, the last known
+    /// owner must then renew it. The registration is extended from its
+    /// current expiration date, see `domain::renew`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `key` - The project key identifier
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the project is archived
+    /// * If the maintainer doesn't own, or last owned, the project's domain
+    /// * If there is no domain for the project name
+    fn renew_domain(env: Env, maintainer: Address, key: Bytes) {
+        Tansu::require_not_paused(env.clone());
+
+        let project = crate::auth_maintainers(&env, &maintainer, &key);
+        crate::require_not_archived(&env, &key);
+
+        let name_b = validation::normalize_project_name(&env, &project.name);
+        let (owner, expires_at) = match domain_status(&env, &key, &name_b) {
+            types::DomainStatus::Active(owner, expires_at) => (Some(owner), expires_at),
+            types::DomainStatus::Grace(_) | types::DomainStatus::Expired => (
+                env.storage()
+                    .persistent()
+                    .get(&types::DomainKey::Owner(key.clone())),
+                env.storage()
+                    .persistent()
+                    .get(&types::ProjectKey::DomainExpiry(key.clone())),
+            ),
+            types::DomainStatus::Unregistered => {
+                panic_with_error!(&env, &errors::ContractErrors::InvalidDomainError)
+            }
+        };
+        if owner != Some(maintainer.clone()) {
+            panic_with_error!(&env, &errors::ContractErrors::MaintainerNotDomainOwner)
+        }
+
+        domain::renew(
+            &env,
+            &domain::project_provider(&env, &key),
+            &name_b,
+            &maintainer,
+            expires_at.unwrap_or(env.ledger().timestamp()),
+        );
+        let expires_at = store_domain_expiry(&env, &key, &name_b);
+
+        events::DomainRenewed {
+            project_key: key,
+            maintainer,
+            expires_at,
+        }
+        .publish(&env);
+    }
+
+    /// Get the status of the domain bound to a project.
+    ///
+    /// An expired domain stays bound to the project during
+    /// `DOMAIN_GRACE_PERIOD` after its last known expiration date, so that
+    /// maintainers can renew it before the name is released.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `types::DomainStatus` - The status of the domain
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the domain cannot be resolved
+    fn check_domain_status(env: Env, key: Bytes) -> types::DomainStatus {
+        let project = Self::get_project(env.clone(), key.clone());
+        let name_b = validation::normalize_project_name(&env, &project.name);
+        domain_status(&env, &key, &name_b)
+    }
+
+    /// Verify that the domain bound to a project is still owned by its lead
+    /// maintainer, the first of its maintainers.
+    ///
+    /// Anyone can call this function. The result is recorded and the project
+    /// is flagged with a `DomainOwnershipChanged` event when the verification
+    /// outcome changes. A domain in its grace period is still considered
+    /// bound to the project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `bool` - True if the lead maintainer owns the domain
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the domain cannot be resolved
+    fn verify_domain(env: Env, key: Bytes) -> bool {
+        Tansu::require_not_paused(env.clone());
+
+        let project = Self::get_project(env.clone(), key.clone());
+        let name_b = validation::normalize_project_name(&env, &project.name);
+        let (owner, verified) = match domain_status(&env, &key, &name_b) {
+            types::DomainStatus::Active(owner, _) => {
+                let verified = project.maintainers.first() == Some(owner.clone());
+                (Some(owner), verified)
+            }
+            types::DomainStatus::Grace(_) => (None, true),
+            types::DomainStatus::Expired | types::DomainStatus::Unregistered => (None, false),
+        };
+
+        // ownership was verified at registration
+        let previous = Self::get_domain_verification(env.clone(), key.clone());
+        let changed = match &previous {
+            Some(previous) => previous.verified != verified || previous.owner != owner,
+            None => !verified,
+        };
+
+        env.storage().persistent().set(
+            &types::ProjectKey::DomainVerification(key.clone()),
+            &types::DomainVerification {
+                owner: owner.clone(),
+                verified,
+                checked_at: env.ledger().timestamp(),
+            },
+        );
+
+        if changed {
+            events::DomainOwnershipChanged {
+                project_key: key,
+                owner,
+                verified,
+            }
+            .publish(&env);
+        }
+        verified
+    }
+
+    /// Get the result of the last domain ownership verification of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Option<types::DomainVerification>` - The verification, `None` if
+    ///   the domain was never verified with `verify_domain`
+    fn get_domain_verification(env: Env, key: Bytes) -> Option<types::DomainVerification> {
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::DomainVerification(key))
+    }
+
+    /// Set the latest commit hash for a project.
+    ///
+    /// Updates the current commit hash for the specified project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `project_key` - The project key identifier
+    /// * `hash` - The new commit hash
+    /// * `meta` - Optional attested details of the commit (author, message
+    ///   hash, tree hash and timestamp), see `get_commit_meta`
+    /// * `signature` - Optional ed25519 signature of the project key followed
+    ///   by the hash, made with the signing key of the maintainer
+    /// * `proposal_id` - Optional approved `DaoAction::Commit` proposal of
+    ///   the DAO of the project authorizing the hash, e.g. for
+    ///   governance-driven merges. A proposal authorizes a single commit
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the project is paused
+    /// * If the maintainer is within the commit cooldown
+    /// * If the hash does not match the hash algorithm of the project
+    /// * If the commit metadata is invalid
+    /// * If a signature is given and the maintainer has no signing key
+    /// * If the signature is invalid
+    /// * If the proposal doesn't exist or is not approved
+    /// * If the proposal doesn't approve a commit
+    /// * If the proposal approved another hash
+    /// * If the hash of the proposal was already committed
+    fn commit(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        hash: String,
+        meta: Option<types::CommitMeta>,
+        signature: Option<BytesN<64>>,
+        proposal_id: Option<u32>,
+    ) {
+        Tansu::require_not_paused(env.clone());
+
+        crate::auth_maintainers_role(
+            &env,
+            &maintainer,
+            &project_key,
+            types::MaintainerRole::Committer,
+        );
+        crate::require_not_archived(&env, &project_key);
+        crate::require_not_dao_controlled(&env, &project_key);
+        crate::require_project_not_paused(&env, &project_key);
+        crate::require_commit_cooldown(&env, &project_key, &maintainer);
+
+        let algorithm = Self::get_hash_algorithm(env.clone(), project_key.clone());
+        validation::validate_commit_hash(&env, &hash, algorithm);
+        if let Some(meta) = &meta {
+            validation::validate_commit_meta(&env, meta, algorithm);
+        }
+
+        let signing_key = signature.map(|signature| {
+            let signing_key = Self::get_signing_key(env.clone(), maintainer.clone())
+                .unwrap_or_else(|| {
+                    panic_with_error!(&env, &errors::ExtendedErrors::NoSigningKey);
+                });
+            let mut message = project_key.clone();
+            message.append(&hash.to_bytes());
+            env.crypto()
+                .ed25519_verify(&signing_key, &message, &signature);
+            signing_key
+        });
+
+        if let Some(proposal_id) = proposal_id {
+            let proposal =
+                <Tansu as DaoTrait>::get_proposal(env.clone(), project_key.clone(), proposal_id);
+            if proposal.status != types::ProposalStatus::Approved {
+                panic_with_error!(&env, &errors::ExtendedErrors::ProposalNotApproved);
+            }
+            let link_key = types::ProjectKey::CommitProposal(project_key.clone(), proposal_id);
+            let Some((approved, committed)) = env
+                .storage()
+                .persistent()
+                .get::<_, (String, bool)>(&link_key)
+            else {
+                panic_with_error!(&env, &errors::AdditionalErrors::NotCommitProposal);
+            };
+            if approved != hash {
+                panic_with_error!(&env, &errors::AdditionalErrors::CommitMismatch);
+            }
+            if committed {
+                panic_with_error!(&env, &errors::AdditionalErrors::ProposalAlreadyLinked);
+            }
+            env.storage().persistent().set(&link_key, &(approved, true));
+        }
+
+        crate::record_commit(
+            &env,
+            &project_key,
+            &maintainer,
+            hash,
+            meta,
+            signing_key,
+            None,
+            proposal_id,
+        );
+        contract_membership::add_reputation(
+            &env,
+            &project_key,
+            &maintainer,
+            contract_membership::REPUTATION_PER_COMMIT,
+        );
+    }
+
+    /// Revert the latest commit of a project.
+    ///
+    /// The previous commit becomes the latest again. The revert is recorded
+    /// as a new commit of the history, so the reverted commit stays in the
+    /// audit trail. Reverting a revert restores the reverted commit.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `String` - The restored commit hash
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the project is paused
+    /// * If the maintainer is within the commit cooldown
+    /// * If the history has no previous commit
+    fn revert_commit(env: Env, maintainer: Address, project_key: Bytes) -> String {
+        Tansu::require_not_paused(env.clone());
+
+        crate::auth_maintainers_role(
+            &env,
+            &maintainer,
+            &project_key,
+            types::MaintainerRole::Committer,
+        );
+        crate::require_not_archived(&env, &project_key);
+        crate::require_not_dao_controlled(&env, &project_key);
+        crate::require_project_not_paused(&env, &project_key);
+        crate::require_commit_cooldown(&env, &project_key, &maintainer);
+
+        let history = Self::get_commit_history(env.clone(), project_key.clone(), 0);
+        let (Some(latest), Some(previous)) = (history.get(0), history.get(1)) else {
+            panic_with_error!(&env, &errors::ExtendedErrors::NothingToRevert);
+        };
+        let meta = Self::get_commit_meta(env.clone(), project_key.clone(), 1);
+
+        crate::record_commit(
+            &env,
+            &project_key,
+            &maintainer,
+            previous.hash.clone(),
+            meta,
+            None,
+            Some(latest.hash.clone()),
+            None,
+        );
+
+        events::CommitReverted {
+            project_key,
+            maintainer,
+            reverted_hash: latest.hash,
+            hash: previous.hash.clone(),
+        }
+        .publish(&env);
+
+        previous.hash
+    }
+
+    /// Get the last commit hash
+    /// Get the latest commit hash for a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `String` - The current commit hash
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    fn get_commit(env: Env, project_key: Bytes) -> String {
+        let key_ = types::ProjectKey::Key(project_key.clone());
+        if env
+            .storage()
+            .persistent()
+            .get::<types::ProjectKey, types::Project>(&key_)
+            .is_some()
+        {
+            env.storage()
+                .persistent()
+                .get(&types::ProjectKey::LastHash(project_key))
+                .unwrap_or_else(|| {
+                    panic_with_error!(&env, &errors::ContractErrors::NoHashFound);
+                })
+        } else {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidKey);
+        }
+    }
+
+    /// Set the ed25519 key used by a maintainer to sign commits.
+    ///
+    /// Signed commits prove that a hash was authorized by a specific key and
+    /// not only by the submitter of the transaction.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer
+    /// * `signing_key` - The ed25519 public key, `None` to remove it
+    fn set_signing_key(env: Env, maintainer: Address, signing_key: Option<BytesN<32>>) {
+        Tansu::require_not_paused(env.clone());
+
+        maintainer.require_auth();
+
+        let key_ = types::DataKey::SigningKey(maintainer.clone());
+        match &signing_key {
+            Some(signing_key) => env.storage().persistent().set(&key_, signing_key),
+            None => env.storage().persistent().remove(&key_),
+        }
+
+        events::SigningKeyUpdated {
+            maintainer,
+            signing_key,
+        }
+        .publish(&env);
+    }
+
+    /// Get the ed25519 key used by a maintainer to sign commits.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer
+    ///
+    /// # Returns
+    /// * `Option<BytesN<32>>` - The public key, `None` if not set
+    fn get_signing_key(env: Env, maintainer: Address) -> Option<BytesN<32>> {
+        env.storage()
+            .persistent()
+            .get(&types::DataKey::SigningKey(maintainer))
+    }
+
+    /// Set the latest commit hash of a named ref of a project.
+    ///
+    /// Refs track branches such as `main`, `develop` or `release/1.x`
+    /// separately from the project's latest commit set with `commit`. A ref
+    /// is created by its first commit.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `project_key` - The project key identifier
+    /// * `name` - The ref name
+    /// * `hash` - The new commit hash
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If the project is paused
+    /// * If the maintainer is within the commit cooldown
+    /// * If the ref name is invalid
+    /// * If the hash does not match the hash algorithm of the project
+    /// * If the maximum number of refs is reached
+    fn commit_to_ref(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        name: String,
+        hash: String,
+    ) {
+        Tansu::require_not_paused(env.clone());
+
+        crate::auth_maintainers_role(
+            &env,
+            &maintainer,
+            &project_key,
+            types::MaintainerRole::Committer,
+        );
+        crate::require_not_archived(&env, &project_key);
+        crate::require_not_dao_controlled(&env, &project_key);
+        crate::require_project_not_paused(&env, &project_key);
+        crate::require_commit_cooldown(&env, &project_key, &maintainer);
+
+        validation::validate_ref_name(&env, &name);
+        let algorithm = Self::get_hash_algorithm(env.clone(), project_key.clone());
+        validation::validate_commit_hash(&env, &hash, algorithm);
+        let mut refs = Self::get_refs(env.clone(), project_key.clone());
+        if !refs.contains(&name) {
+            if refs.len() >= types::MAX_REFS {
+                panic_with_error!(&env, &errors::ExtendedErrors::InvalidRef);
+            }
+            refs.push_back(name.clone());
+            env.storage()
+                .persistent()
+                .set(&types::ProjectKey::Refs(project_key.clone()), &refs);
+        }
+        env.storage().persistent().set(
+            &types::ProjectKey::Ref(project_key.clone(), name.clone()),
+            &hash,
+        );
+
+        events::Commit {
+            project_key,
+            maintainer,
+            ref_name: Some(name),
+            hash,
+            ledger: env.ledger().sequence(),
+            meta: None,
+            signing_key: None,
+            proposal_id: None,
+        }
+        .publish(&env);
+    }
+
+    /// Get the latest commit hash of a named ref of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `name` - The ref name
+    ///
+    /// # Returns
+    /// * `String` - The latest commit hash of the ref
+    ///
+    /// # Panics
+    /// * If the ref doesn't exist
+    fn get_ref(env: Env, project_key: Bytes, name: String) -> String {
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::Ref(project_key, name))
+            .unwrap_or_else(|| {
+                panic_with_error!(&env, &errors::ExtendedErrors::UnknownRef);
+            })
+    }
+
+    /// Get the ref names of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Vec<String>` - The ref names, in creation order
+    fn get_refs(env: Env, project_key: Bytes) -> Vec<String> {
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::Refs(project_key))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Set the object format of the git repository of a project.
+    ///
+    /// Commit hashes and release commits must match the algorithm: 40
+    /// hexadecimal characters for SHA-1 and 64 for SHA-256. Projects use
+    /// SHA-1, git's default, until set otherwise.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the admin calling this function
+    /// * `key` - The project key identifier
+    /// * `algorithm` - The hash algorithm of the repository
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not an admin
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    fn set_hash_algorithm(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        algorithm: types::HashAlgorithm,
+    ) {
+        Tansu::require_not_paused(env.clone());
+
+        crate::auth_maintainers_role(&env, &maintainer, &key, types::MaintainerRole::Admin);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        env.storage()
+            .persistent()
+            .set(&types::ProjectKey::HashAlgorithm(key.clone()), &algorithm);
+        crate::publish_config_updated(&env, &key, &maintainer, types::ConfigField::HashAlgorithm);
+    }
+
+    /// Get the object format of the git repository of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `types::HashAlgorithm` - The hash algorithm, SHA-1 if not set
+    fn get_hash_algorithm(env: Env, key: Bytes) -> types::HashAlgorithm {
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::HashAlgorithm(key))
+            .unwrap_or_default()
+    }
+
+    /// Set the number of ledgers a maintainer waits between two commits.
+    ///
+    /// The cooldown applies to `commit`, `commit_to_ref` and `revert_commit`
+    /// and limits the events and storage churn of a misconfigured CI. Admins
+    /// are not rate limited, so they can always push a fix.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the admin calling this function
+    /// * `key` - The project key identifier
+    /// * `ledgers` - The cooldown in ledgers, 0 to disable it
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not an admin
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    fn set_commit_cooldown(env: Env, maintainer: Address, key: Bytes, ledgers: u32) {
+        Tansu::require_not_paused(env.clone());
+
+        crate::auth_maintainers_role(&env, &maintainer, &key, types::MaintainerRole::Admin);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        let cooldown_key = types::ProjectKey::CommitCooldown(key.clone());
+        if ledgers == 0 {
+            env.storage().persistent().remove(&cooldown_key);
+        } else {
+            env.storage().persistent().set(&cooldown_key, &ledgers);
+        }
+        crate::publish_config_updated(&env, &key, &maintainer, types::ConfigField::CommitCooldown);
+    }
+
+    /// Get the number of ledgers a maintainer waits between two commits.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `u32` - The cooldown in ledgers, 0 if commits are not rate limited
+    fn get_commit_cooldown(env: Env, key: Bytes) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::CommitCooldown(key))
+            .unwrap_or(0)
+    }
+
+    /// Get project information including configuration and maintainers.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `types::Project` - Project information including name, config, and maintainers
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    fn get_project(env: Env, project_key: Bytes) -> types::Project {
+        let key_ = types::ProjectKey::Key(project_key.clone());
+
+        env.storage()
+            .persistent()
+            .get::<types::ProjectKey, types::Project>(&key_)
+            .unwrap_or_else(|| {
+                panic_with_error!(&env, &errors::ContractErrors::InvalidKey);
+            })
+    }
+
+    /// Get the counters of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `types::ProjectStats` - Number of proposals, ledger of the last
+    ///   commit, number of members with badges and their total voting weight
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    fn get_project_stats(env: Env, project_key: Bytes) -> types::ProjectStats {
+        Self::get_project(env.clone(), project_key.clone());
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::Stats(project_key))
+            .unwrap_or_default()
+    }
+
+    /// Get the last tansu.toml files claimed by a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Vec<types::MetadataCommitment>` - Up to `MAX_METADATA_HISTORY`
+    ///   commitments, oldest first
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    fn get_metadata_history(env: Env, project_key: Bytes) -> Vec<types::MetadataCommitment> {
+        Self::get_project(env.clone(), project_key.clone());
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::MetadataHistory(project_key))
+            .unwrap_or_else(|| Vec::new(&env))
+    }
+
+    /// Get the last commits of a project.
+    ///
+    /// Only the last `MAX_COMMIT_HISTORY` commits are kept, listed by pages
+    /// of `MAX_COMMITS_PER_PAGE` commits, most recent first.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `page` - The page number, starting at 0
+    ///
+    /// # Returns
+    /// * `Vec<types::CommitRecord>` - The commits of the page, empty past the
+    ///   last page
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    fn get_commit_history(env: Env, project_key: Bytes, page: u32) -> Vec<types::CommitRecord> {
+        Self::get_project(env.clone(), project_key.clone());
+        let count: u32 = env
+            .storage()
+            .persistent()
+            .get(&types::ProjectKey::CommitCount(project_key.clone()))
+            .unwrap_or(0);
+        let available = count.min(types::MAX_COMMIT_HISTORY);
+
+        let mut commits = Vec::new(&env);
+        let start = page.saturating_mul(types::MAX_COMMITS_PER_PAGE);
+        let end = start
+            .saturating_add(types::MAX_COMMITS_PER_PAGE)
+            .min(available);
+        for i in start..end {
+            let slot = (count - 1 - i) % types::MAX_COMMIT_HISTORY;
+            if let Some(commit) = env
+                .storage()
+                .persistent()
+                .get(&types::ProjectKey::CommitHistory(project_key.clone(), slot))
+            {
+                commits.push_back(commit);
+            }
+        }
+        commits
+    }
+
+    /// Get the attested details of one of the last commits of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `index` - Position of the commit in the history, 0 for the latest
+    ///
+    /// # Returns
+    /// * `Option<types::CommitMeta>` - The details, `None` if the commit was
+    ///   recorded without details or is no longer in the history
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    fn get_commit_meta(env: Env, project_key: Bytes, index: u32) -> Option<types::CommitMeta> {
+        Self::get_project(env.clone(), project_key.clone());
+        let count: u32 = env
+            .storage()
+            .persistent()
+            .get(&types::ProjectKey::CommitCount(project_key.clone()))
+            .unwrap_or(0);
+        if index >= count.min(types::MAX_COMMIT_HISTORY) {
+            return None;
+        }
+        let slot = (count - 1 - index) % types::MAX_COMMIT_HISTORY;
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::CommitMeta(project_key, slot))
+    }
+
+    /// Walk the last commits of a project, most recent first.
+    ///
+    /// Commits are identified by their sequence number, 0 for the first
+    /// commit ever recorded, so cursors stay valid when new commits are
+    /// recorded. Only the last `MAX_COMMIT_HISTORY` commits are kept.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `project_key` - The project key identifier
+    /// * `cursor` - Sequence number of the first commit of the page, `None`
+    ///   to start from the latest commit
+    /// * `limit` - Maximum number of commits, at most `MAX_COMMITS_PER_PAGE`
+    ///
+    /// # Returns
+    /// * `types::CommitPage` - The commits and the cursor of the next page,
+    ///   empty if the cursor points past the kept history
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    fn get_commits(
+        env: Env,
+        project_key: Bytes,
+        cursor: Option<u32>,
+        limit: u32,
+    ) -> types::CommitPage {
+        Self::get_project(env.clone(), project_key.clone());
+        let count: u32 = env
+            .storage()
+            .persistent()
+            .get(&types::ProjectKey::CommitCount(project_key.clone()))
+            .unwrap_or(0);
+        let oldest = count.saturating_sub(types::MAX_COMMIT_HISTORY);
+        let limit = limit.min(types::MAX_COMMITS_PER_PAGE);
+
+        let mut commits = Vec::new(&env);
+        let start = cursor.unwrap_or(u32::MAX).min(count.saturating_sub(1));
+        if count == 0 || start < oldest || limit == 0 {
+            return types::CommitPage {
+                commits,
+                next_cursor: None,
+            };
+        }
+
+        let first = (start + 1).saturating_sub(limit).max(oldest);
+        for sequence in (first..=start).rev() {
+            if let Some(commit) = env
+                .storage()
+                .persistent()
+                .get(&types::ProjectKey::CommitHistory(
+                    project_key.clone(),
+                    sequence % types::MAX_COMMIT_HISTORY,
+                ))
+            {
+                commits.push_back(commit);
+            }
+        }
+        let next_cursor = if first > oldest {
+            Some(first - 1)
+        } else {
+            None
+        };
+        types::CommitPage {
+            commits,
+            next_cursor,
+        }
+    }
+
+    /// Get the project key of a project name.
+    ///
+    /// The name is normalized like at registration, so the key can be
+    /// computed for names which are not registered.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `name` - The project name
+    ///
+    /// # Returns
+    /// * `Bytes` - The project key, keccak256 of the normalized name
+    ///
+    /// # Panics
+    /// * If the project name is not 1 to 15 ASCII letters
+    fn get_project_id(env: Env, name: String) -> Bytes {
+        let name_b = validation::normalize_project_name(&env, &name);
+        env.crypto().keccak256(&name_b).into()
+    }
+
+    /// Get project information from its name.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `name` - The project name
+    ///
+    /// # Returns
+    /// * `types::Project` - Project information including name, config, and maintainers
+    ///
+    /// # Panics
+    /// * If the project name is not 1 to 15 ASCII letters
+    /// * If the project doesn't exist
+    fn get_project_by_name(env: Env, name: String) -> types::Project {
+        let key = Self::get_project_id(env.clone(), name);
+        Self::get_project(env, key)
+    }
+
+    /// List the keys of registered projects.
+    ///
+    /// Projects are listed by pages of `MAX_PROJECTS_PER_PAGE` keys. The order
+    /// is the registration order, except that a deregistered project is
+    /// replaced by the last registered project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `page` - The page number to retrieve
+    ///
+    /// # Returns
+    /// * `Vec<Bytes>` - The project keys of the page, empty past the last page
+    fn list_projects(env: Env, page: u32) -> Vec<Bytes> {
+        env.storage()
+            .persistent()
+            .get(&types::DataKey::Projects(page))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Set the discovery tags of a project.
+    ///
+    /// Replaces the tags of the project and updates the tag listings used by
+    /// `list_projects_by_tag`. Tags are normalized to lowercase.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `maintainer` - The address of the maintainer calling this function
+    /// * `key` - The project key identifier
+    /// * `tags` - The new tags, at most `MAX_TAGS`
+    ///
+    /// # Panics
+    /// * If the project doesn't exist
+    /// * If the maintainer is not authorized
+    /// * If the project is archived
+    /// * If the project is controlled by its DAO
+    /// * If a tag is invalid or duplicated
+    /// * If there are more tags than allowed
+    fn set_tags(env: Env, maintainer: Address, key: Bytes, tags: Vec<String>) {
+        Tansu::require_not_paused(env.clone());
+
+        crate::auth_maintainers(&env, &maintainer, &key);
+        crate::require_not_archived(&env, &key);
+        crate::require_not_dao_controlled(&env, &key);
+
+        if tags.len() > types::MAX_TAGS {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidTag);
+        }
+        let mut normalized: Vec<String> = Vec::new(&env);
+        for tag in tags.iter() {
+            let tag = validation::normalize_tag(&env, &tag);
+            if normalized.contains(&tag) {
+                panic_with_error!(&env, &errors::ContractErrors::InvalidTag);
+            }
+            normalized.push_back(tag);
+        }
+
+        let previous = Self::get_tags(env.clone(), key.clone());
+        for tag in previous.iter() {
+            if !normalized.contains(&tag) {
+                unindex_tag(&env, &tag, &key);
+            }
+        }
+        for tag in normalized.iter() {
+            if !previous.contains(&tag) {
+                index_tag(&env, &tag, &key);
+            }
+        }
+
+        let tags_key = types::ProjectKey::Tags(key.clone());
+        if normalized.is_empty() {
+            env.storage().persistent().remove(&tags_key);
+        } else {
+            env.storage().persistent().set(&tags_key, &normalized);
+        }
+
+        events::ProjectTagsUpdated {
+            project_key: key,
+            maintainer,
+            tags: normalized,
+        }
+        .publish(&env);
+    }
+
+    /// Get the discovery tags of a project.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `key` - The project key identifier
+    ///
+    /// # Returns
+    /// * `Vec<String>` - The normalized tags, empty if none
+    fn get_tags(env: Env, key: Bytes) -> Vec<String> {
+        env.storage()
+            .persistent()
+            .get(&types::ProjectKey::Tags(key))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// List the keys of the projects with a tag.
+    ///
+    /// Projects are listed by pages of `MAX_PROJECTS_PER_PAGE` keys, like
+    /// `list_projects`.
+    ///
+    /// # Arguments
+    /// * `env` - The environment object
+    /// * `tag` - The tag, case insensitive
+    /// * `page` - The page number to retrieve
+    ///
+    /// # Returns
+    /// * `Vec<Bytes>` - The project keys of the page, empty past the last page
+    ///
+    /// # Panics
+    /// * If the tag is invalid
+    fn list_projects_by_tag(env: Env, tag: String, page: u32) -> Vec<Bytes> {
+        let tag = validation::normalize_tag(&env, &tag);
+        env.storage()
+            .persistent()
+            .get(&types::DataKey::TagProjects(tag, page))
+            .unwrap_or(Vec::new(&env))
+    }
+}
+
+/// Build a new project and check that it can be registered.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `name` - The project name
+/// * `maintainers` - List of maintainer addresses for the project
+/// * `url` - The project's Git repository URL
+/// * `ipfs` - CID of the tansu.toml file with associated metadata
+/// * `license` - SPDX license identifier of the project, if any
+///
+/// # Returns
+/// * `(Bytes, Bytes, types::Project)` - The project key, the normalized name and the project
 ///
-/// Helper function to register a project name in the domain contract system.
+/// # Panics
+/// * If the project name is not 1 to 15 ASCII letters
+/// * If the license identifier is invalid
+/// * If the CID of the tansu.toml file is malformed
+/// * If the project already exists
+/// * If there are more maintainers than allowed
+fn new_project(
+    env: &Env,
+    name: String,
+    maintainers: Vec<Address>,
+    url: String,
+    ipfs: String,
+    license: Option<String>,
+) -> (Bytes, Bytes, types::Project) {
+    let name_b = validation::normalize_project_name(env, &name);
+    if let Some(license) = &license {
+        validation::validate_license(env, license);
+    }
+    validation::validate_url(env, &url);
+    validation::validate_cid(env, &ipfs);
+    let mut slice = [0u8; types::MAX_PROJECT_NAME_LENGTH as usize];
+    let name_slice = &mut slice[..name_b.len() as usize];
+    name_b.copy_into_slice(name_slice);
+    let name = String::from_bytes(env, name_slice);
+
+    let project = types::Project {
+        name,
+        config: types::Config {
+            url,
+            ipfs,
+            mirrors: Vec::new(env),
+            maintainers_threshold: maintainers.len() / 2 + 1,
+            max_maintainers: types::MAX_MAINTAINERS,
+            license,
+        },
+        maintainers,
+        metadata: types::Metadata {
+            description: None,
+            website: None,
+            logo: None,
+        },
+    };
+    let key: Bytes = env.crypto().keccak256(&name_b).into();
+
+    if env
+        .storage()
+        .persistent()
+        .has(&types::ProjectKey::Key(key.clone()))
+    {
+        panic_with_error!(env, &errors::ContractErrors::ProjectAlreadyExist);
+    }
+    crate::validate_maintainers(env, &project.maintainers, &project.config);
+
+    (key, name_b, project)
+}
+
+/// Store a new project and add it to the projects listing.
 ///
 /// # Arguments
 /// * `env` - The environment object
-/// * `name` - The project name to register
-/// * `maintainer` - The maintainer address to set as owner
-/// * `domain_contract_id` - The domain contract address
-pub fn domain_register(env: &Env, name: &Bytes, maintainer: &Address, domain_contract_id: Address) {
-    let tld = Bytes::from_slice(env, &[120, 108, 109]); // xlm
-    let min_duration: u64 = 31536000;
-
-    // Convert the arguments to Val
-    let name_raw = name.to_val();
-    let tld_raw = tld.to_val();
-    let maintainer_raw = maintainer.to_val();
-    let min_duration_raw: Val = min_duration.into_val(env);
-
-    // Construct the init_args
-    let init_args = vec![
-        &env,
-        name_raw,
-        tld_raw,
-        maintainer_raw,
-        maintainer_raw,
-        min_duration_raw,
-    ];
-
-    env.invoke_contract::<()>(
-        &domain_contract_id,
-        &Symbol::new(env, "set_record"),
-        init_args,
+/// * `key` - The project key identifier
+/// * `name` - The normalized project name
+/// * `project` - The project
+/// * `provider` - The domain provider the project is registered with
+fn store_project(
+    env: &Env,
+    key: &Bytes,
+    name: &Bytes,
+    project: &types::Project,
+    provider: &types::DomainProvider,
+) {
+    env.storage()
+        .persistent()
+        .set(&types::DomainKey::Provider(key.clone()), provider);
+    store_domain_expiry(env, key, name);
+    env.storage()
+        .persistent()
+        .set(&types::ProjectKey::Key(key.clone()), project);
+    crate::grant_admin_roles(env, key, &project.maintainers);
+    env.storage().persistent().set(
+        &types::ProjectKey::GovernanceConfig(key.clone()),
+        &contract_dao::default_governance_config(env),
     );
+    index_project(env, key);
+    crate::record_metadata_commitment(env, key, &project.config.ipfs);
+}
+
+/// Remove the commit history of a project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+fn remove_commit_history(env: &Env, key: &Bytes) {
+    let count_key = types::ProjectKey::CommitCount(key.clone());
+    let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+    for slot in 0..count.min(types::MAX_COMMIT_HISTORY) {
+        env.storage()
+            .persistent()
+            .remove(&types::ProjectKey::CommitHistory(key.clone(), slot));
+        env.storage()
+            .persistent()
+            .remove(&types::ProjectKey::CommitMeta(key.clone(), slot));
+    }
+    env.storage().persistent().remove(&count_key);
+}
+
+/// Remove the refs of a project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+fn remove_refs(env: &Env, key: &Bytes) {
+    let refs_key = types::ProjectKey::Refs(key.clone());
+    let refs: Vec<String> = env
+        .storage()
+        .persistent()
+        .get(&refs_key)
+        .unwrap_or(Vec::new(env));
+    for name in refs.iter() {
+        env.storage()
+            .persistent()
+            .remove(&types::ProjectKey::Ref(key.clone(), name));
+    }
+    env.storage().persistent().remove(&refs_key);
 }
 
-/// Generate a domain node hash for the domain contract.
+/// Status of the domain bound to a project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+/// * `name` - The normalized project name
+///
+/// # Returns
+/// * `types::DomainStatus` - The status of the domain
 ///
-/// Helper function to create a domain node hash from a project key.
+/// # Panics
+/// * If the domain cannot be resolved
+fn domain_status(env: &Env, key: &Bytes, name: &Bytes) -> types::DomainStatus {
+    match domain::lookup(env, &domain::project_provider(env, key), name) {
+        Ok(None) => types::DomainStatus::Unregistered,
+        Ok(Some((owner, expires_at))) => types::DomainStatus::Active(owner, expires_at),
+        Err(errors::ContractErrors::DomainExpired) => {
+            let expires_at: Option<u64> = env
+                .storage()
+                .persistent()
+                .get(&types::ProjectKey::DomainExpiry(key.clone()));
+            match expires_at {
+                Some(expires_at)
+                    if env.ledger().timestamp() < expires_at + types::DOMAIN_GRACE_PERIOD =>
+                {
+                    types::DomainStatus::Grace(expires_at + types::DOMAIN_GRACE_PERIOD)
+                }
+                _ => types::DomainStatus::Expired,
+            }
+        }
+        Err(error) => panic_with_error!(env, error),
+    }
+}
+
+/// Record the owner and the expiration date of the domain bound to a
+/// project.
 ///
 /// # Arguments
 /// * `env` - The environment object
-/// * `domain` - The domain bytes to hash
+/// * `key` - The project key identifier
+/// * `name` - The normalized project name
 ///
 /// # Returns
-/// * `BytesN<32>` - The domain node hash
-pub fn domain_node(env: &Env, domain: &Bytes) -> BytesN<32> {
-    let tld = Bytes::from_slice(env, &[120, 108, 109]); // xlm
-    let parent_hash: Bytes = env.crypto().keccak256(&tld).into();
-    let mut node_builder: Bytes = Bytes::new(env);
-    node_builder.append(&parent_hash);
-    node_builder.append(domain);
-
-    env.crypto().keccak256(&node_builder).into()
+/// * `Option<u64>` - The expiration timestamp, `None` if the domain does not expire
+fn store_domain_expiry(env: &Env, key: &Bytes, name: &Bytes) -> Option<u64> {
+    let expiry_key = types::ProjectKey::DomainExpiry(key.clone());
+    let owner_key = types::DomainKey::Owner(key.clone());
+    let expires_at = match domain::lookup(env, &domain::project_provider(env, key), name) {
+        Ok(Some((owner, expires_at))) => {
+            env.storage().persistent().set(&owner_key, &owner);
+            expires_at
+        }
+        _ => None,
+    };
+    match expires_at {
+        Some(expires_at) => env.storage().persistent().set(&expiry_key, &expires_at),
+        None => env.storage().persistent().remove(&expiry_key),
+    }
+    expires_at
+}
+
+/// Add a project to the projects listing.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+fn index_project(env: &Env, key: &Bytes) {
+    let count = crate::update_stats(env, |stats| stats.projects += 1).projects;
+
+    let page_key = types::DataKey::Projects(count / types::MAX_PROJECTS_PER_PAGE);
+    let mut page: Vec<Bytes> = env
+        .storage()
+        .persistent()
+        .get(&page_key)
+        .unwrap_or(Vec::new(env));
+    page.push_back(key.clone());
+
+    env.storage().persistent().set(&page_key, &page);
+    env.storage()
+        .persistent()
+        .set(&types::ProjectKey::ProjectIndex(key.clone()), &count);
+}
+
+/// Remove a project from the projects listing.
+///
+/// The last project of the listing takes the place of the removed one so
+/// that pages stay contiguous.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+fn unindex_project(env: &Env, key: &Bytes) {
+    let index_key = types::ProjectKey::ProjectIndex(key.clone());
+    let Some(index) = env.storage().persistent().get::<_, u32>(&index_key) else {
+        return;
+    };
+    let last = crate::update_stats(env, |stats| stats.projects -= 1).projects - 1;
+
+    let last_page_key = types::DataKey::Projects(last / types::MAX_PROJECTS_PER_PAGE);
+    let mut last_page: Vec<Bytes> = env.storage().persistent().get(&last_page_key).unwrap();
+    let last_key = last_page.pop_back().unwrap();
+    if last_page.is_empty() {
+        env.storage().persistent().remove(&last_page_key);
+    } else {
+        env.storage().persistent().set(&last_page_key, &last_page);
+    }
+
+    if index != last {
+        let page_key = types::DataKey::Projects(index / types::MAX_PROJECTS_PER_PAGE);
+        let mut page: Vec<Bytes> = env.storage().persistent().get(&page_key).unwrap();
+        page.set(index % types::MAX_PROJECTS_PER_PAGE, last_key.clone());
+        env.storage().persistent().set(&page_key, &page);
+        env.storage()
+            .persistent()
+            .set(&types::ProjectKey::ProjectIndex(last_key), &index);
+    }
+
+    env.storage().persistent().remove(&index_key);
+}
+
+/// Add a project to the listing of a tag.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `tag` - The normalized tag
+/// * `key` - The project key identifier
+fn index_tag(env: &Env, tag: &String, key: &Bytes) {
+    let count_key = types::DataKey::TagCount(tag.clone());
+    let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+
+    let page_key = types::DataKey::TagProjects(tag.clone(), count / types::MAX_PROJECTS_PER_PAGE);
+    let mut page: Vec<Bytes> = env
+        .storage()
+        .persistent()
+        .get(&page_key)
+        .unwrap_or(Vec::new(env));
+    page.push_back(key.clone());
+
+    env.storage().persistent().set(&page_key, &page);
+    env.storage().persistent().set(&count_key, &(count + 1));
+    env.storage().persistent().set(
+        &types::ProjectKey::TagIndex(key.clone(), tag.clone()),
+        &count,
+    );
+}
+
+/// Remove a project from the listing of a tag.
+///
+/// The last project of the listing takes the place of the removed one so
+/// that pages stay contiguous.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `tag` - The normalized tag
+/// * `key` - The project key identifier
+fn unindex_tag(env: &Env, tag: &String, key: &Bytes) {
+    let index_key = types::ProjectKey::TagIndex(key.clone(), tag.clone());
+    let Some(index) = env.storage().persistent().get::<_, u32>(&index_key) else {
+        return;
+    };
+    let count_key = types::DataKey::TagCount(tag.clone());
+    let last = env
+        .storage()
+        .persistent()
+        .get::<_, u32>(&count_key)
+        .unwrap()
+        - 1;
+    if last == 0 {
+        env.storage().persistent().remove(&count_key);
+    } else {
+        env.storage().persistent().set(&count_key, &last);
+    }
+
+    let last_page_key =
+        types::DataKey::TagProjects(tag.clone(), last / types::MAX_PROJECTS_PER_PAGE);
+    let mut last_page: Vec<Bytes> = env.storage().persistent().get(&last_page_key).unwrap();
+    let last_key = last_page.pop_back().unwrap();
+    if last_page.is_empty() {
+        env.storage().persistent().remove(&last_page_key);
+    } else {
+        env.storage().persistent().set(&last_page_key, &last_page);
+    }
+
+    if index != last {
+        let page_key =
+            types::DataKey::TagProjects(tag.clone(), index / types::MAX_PROJECTS_PER_PAGE);
+        let mut page: Vec<Bytes> = env.storage().persistent().get(&page_key).unwrap();
+        page.set(index % types::MAX_PROJECTS_PER_PAGE, last_key.clone());
+        env.storage().persistent().set(&page_key, &page);
+        env.storage()
+            .persistent()
+            .set(&types::ProjectKey::TagIndex(last_key, tag.clone()), &index);
+    }
+
+    env.storage().persistent().remove(&index_key);
+}
+
+/// Remove the fork links of a project.
+///
+/// The project leaves the forks of its parent and its own forks are not
+/// linked to it anymore.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+fn remove_fork_links(env: &Env, key: &Bytes) {
+    if let Some(parent_key) = Tansu::get_fork_parent(env.clone(), key.clone()) {
+        unlink_fork(env, &parent_key, key);
+    }
+
+    let forks_key = types::ProjectKey::Forks(key.clone());
+    if let Some(forks) = env.storage().persistent().get::<_, Vec<Bytes>>(&forks_key) {
+        for fork in forks.iter() {
+            env.storage()
+                .persistent()
+                .remove(&types::ProjectKey::ForkOf(fork));
+        }
+        env.storage().persistent().remove(&forks_key);
+    }
+}
+
+/// Remove a fork from the forks of its parent project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `parent_key` - The key of the parent project
+/// * `fork_key` - The key of the fork
+fn unlink_fork(env: &Env, parent_key: &Bytes, fork_key: &Bytes) {
+    let forks_key = types::ProjectKey::Forks(parent_key.clone());
+    let mut forks: Vec<Bytes> = env.storage().persistent().get(&forks_key).unwrap();
+    forks.remove(forks.first_index_of(fork_key).unwrap());
+    if forks.is_empty() {
+        env.storage().persistent().remove(&forks_key);
+    } else {
+        env.storage().persistent().set(&forks_key, &forks);
+    }
+    env.storage()
+        .persistent()
+        .remove(&types::ProjectKey::ForkOf(fork_key.clone()));
+}
+
+/// Deposit the registration bond of a project, if any.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+/// * `depositor` - The address paying the bond
+///
+/// # Panics
+/// * If the depositor cannot pay the bond
+fn deposit_bond(env: &Env, key: &Bytes, depositor: &Address) {
+    let bond_config = Tansu::get_bond_config(env.clone());
+    if bond_config.amount == 0 {
+        return;
+    }
+
+    let sac_contract = crate::retrieve_contract(env, types::ContractKey::CollateralContract);
+    let token_stellar = token::StellarAssetClient::new(env, &sac_contract.address);
+    match token_stellar.try_transfer(
+        depositor,
+        env.current_contract_address(),
+        &bond_config.amount,
+    ) {
+        Ok(Ok(())) => (),
+        _ => panic_with_error!(&env, &errors::ContractErrors::InsufficientBond),
+    }
+
+    let bond = types::Bond {
+        depositor: depositor.clone(),
+        amount: bond_config.amount,
+        refundable_at: env
+            .ledger()
+            .sequence()
+            .saturating_add(bond_config.lock_ledgers),
+    };
+    env.storage()
+        .persistent()
+        .set(&types::ProjectKey::Bond(key.clone()), &bond);
+}
+
+/// Refund the registration bond of a project, if any.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+fn refund_bond(env: &Env, key: &Bytes) {
+    let bond_key = types::ProjectKey::Bond(key.clone());
+    if let Some(bond) = env
+        .storage()
+        .persistent()
+        .get::<types::ProjectKey, types::Bond>(&bond_key)
+    {
+        let sac_contract = crate::retrieve_contract(env, types::ContractKey::CollateralContract);
+        let token_stellar = token::StellarAssetClient::new(env, &sac_contract.address);
+        token_stellar.transfer(
+            &env.current_contract_address(),
+            &bond.depositor,
+            &bond.amount,
+        );
+
+        env.storage().persistent().remove(&bond_key);
+
+        events::BondRefunded {
+            project_key: key.clone(),
+            depositor: bond.depositor,
+            amount: bond.amount,
+        }
+        .publish(env);
+    }
 }
@@ -0,0 +1,326 @@
+//! Name services binding project names to their owner.
+//!
+//! Soroban Domains is the default provider. The contract admin can register
+//! any other name-service contract implementing [`NameServiceInterface`].
+//! Projects keep the provider they were registered with, see
+//! [`project_provider`].
+
+use soroban_sdk::{Address, Bytes, BytesN, Env, IntoVal, Symbol, Val, contractclient, vec};
+
+use crate::{domain_contract, errors, types};
+
+/// Interface of the name-service contracts usable as domain provider.
+///
+/// Domains are identified by the normalized project name. Tansu only uses
+/// the generated `NameServiceClient`.
+#[allow(dead_code)]
+#[contractclient(name = "NameServiceClient")]
+pub trait NameServiceInterface {
+    /// Owner of a domain, `None` if the domain is not registered.
+    fn owner(env: Env, name: Bytes) -> Option<Address>;
+
+    /// Register a domain, authorized by its owner.
+    fn register(env: Env, name: Bytes, owner: Address);
+
+    /// Transfer a domain, authorized by its current owner.
+    fn transfer(env: Env, name: Bytes, new_owner: Address);
+
+    /// Release a domain, authorized by its owner.
+    fn release(env: Env, name: Bytes);
+
+    /// Expiration timestamp of a domain, `None` if it does not expire.
+    fn expires_at(env: Env, name: Bytes) -> Option<u64>;
+
+    /// Renew a domain, authorized by its owner.
+    fn renew(env: Env, name: Bytes);
+}
+
+/// Get the domain provider of new projects.
+///
+/// # Arguments
+/// * `env` - The environment object
+///
+/// # Returns
+/// * `types::DomainProvider` - The provider, Soroban Domains if none was set
+pub fn provider(env: &Env) -> types::DomainProvider {
+    env.storage()
+        .instance()
+        .get(&types::ContractKey::DomainProvider)
+        .unwrap_or(types::DomainProvider::SorobanDomains)
+}
+
+/// Get the domain provider a project was registered with.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `key` - The project key identifier
+///
+/// # Returns
+/// * `types::DomainProvider` - The provider, Soroban Domains for projects
+///   registered before providers were recorded
+pub fn project_provider(env: &Env, key: &Bytes) -> types::DomainProvider {
+    env.storage()
+        .persistent()
+        .get(&types::DomainKey::Provider(key.clone()))
+        .unwrap_or(types::DomainProvider::SorobanDomains)
+}
+
+/// Owner of the domain of a project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `provider` - The domain provider of the project
+/// * `name` - The normalized project name
+///
+/// # Returns
+/// * `Ok(Option<Address>)` - The owner, `None` if the domain is not registered
+/// * `Err(errors::ContractErrors)` - If the domain expired or cannot be resolved
+///
+/// # Panics
+/// * If the provider contract does not match on-chain data
+pub fn owner(
+    env: &Env,
+    provider: &types::DomainProvider,
+    name: &Bytes,
+) -> Result<Option<Address>, errors::ContractErrors> {
+    Ok(lookup(env, provider, name)?.map(|(owner, _)| owner))
+}
+
+/// Owner and expiration date of the domain of a project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `provider` - The domain provider of the project
+/// * `name` - The normalized project name
+///
+/// # Returns
+/// * `Ok(Option<(Address, Option<u64>)>)` - The owner and the expiration
+///   timestamp, `None` if the domain is not registered
+/// * `Err(errors::ContractErrors::DomainExpired)` - If the domain expired
+/// * `Err(errors::ContractErrors::InvalidDomainError)` - If the domain cannot be resolved
+///
+/// # Panics
+/// * If the provider contract does not match on-chain data
+pub fn lookup(
+    env: &Env,
+    provider: &types::DomainProvider,
+    name: &Bytes,
+) -> Result<Option<(Address, Option<u64>)>, errors::ContractErrors> {
+    match provider {
+        types::DomainProvider::SorobanDomains => {
+            let domain_client = soroban_domains_client(env);
+            match domain_client.try_record(&soroban_domains_record(env, name)) {
+                Ok(Ok(None)) => Ok(None),
+                Ok(Ok(Some(domain_contract::Record::Domain(domain)))) => {
+                    Ok(Some((domain.owner, Some(domain.exp_date))))
+                }
+                Err(Ok(error))
+                    if error == domain_contract::ContractErrors::ExpiredDomain.into() =>
+                {
+                    Err(errors::ContractErrors::DomainExpired)
+                }
+                _ => Err(errors::ContractErrors::InvalidDomainError),
+            }
+        }
+        types::DomainProvider::NameService(contract) => {
+            crate::validate_contract(env, contract);
+            let client = NameServiceClient::new(env, &contract.address);
+            let owner = match client.try_owner(name) {
+                Ok(Ok(Some(owner))) => owner,
+                Ok(Ok(None)) => return Ok(None),
+                _ => return Err(errors::ContractErrors::InvalidDomainError),
+            };
+            match client.try_expires_at(name) {
+                Ok(Ok(Some(expires_at))) if expires_at <= env.ledger().timestamp() => {
+                    Err(errors::ContractErrors::DomainExpired)
+                }
+                Ok(Ok(expires_at)) => Ok(Some((owner, expires_at))),
+                _ => Err(errors::ContractErrors::InvalidDomainError),
+            }
+        }
+    }
+}
+
+/// Register the domain of a project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `provider` - The domain provider of the project
+/// * `name` - The normalized project name
+/// * `owner` - The address owning the domain
+pub fn register(env: &Env, provider: &types::DomainProvider, name: &Bytes, owner: &Address) {
+    match provider {
+        types::DomainProvider::SorobanDomains => {
+            let domain_contract = crate::retrieve_contract(env, types::ContractKey::DomainContract);
+            domain_register(env, name, owner, domain_contract.address);
+        }
+        types::DomainProvider::NameService(contract) => {
+            crate::validate_contract(env, contract);
+            NameServiceClient::new(env, &contract.address).register(name, owner);
+        }
+    }
+}
+
+/// Transfer the domain of a project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `provider` - The domain provider of the project
+/// * `name` - The normalized project name
+/// * `new_owner` - The address of the new owner
+pub fn transfer(env: &Env, provider: &types::DomainProvider, name: &Bytes, new_owner: &Address) {
+    match provider {
+        types::DomainProvider::SorobanDomains => {
+            soroban_domains_client(env).transfer(&soroban_domains_record(env, name), new_owner);
+        }
+        types::DomainProvider::NameService(contract) => {
+            crate::validate_contract(env, contract);
+            NameServiceClient::new(env, &contract.address).transfer(name, new_owner);
+        }
+    }
+}
+
+/// Release the domain of a project so the name can be reused.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `provider` - The domain provider of the project
+/// * `name` - The normalized project name
+pub fn release(env: &Env, provider: &types::DomainProvider, name: &Bytes) {
+    match provider {
+        types::DomainProvider::SorobanDomains => {
+            soroban_domains_client(env).burn_record(&soroban_domains_record(env, name));
+        }
+        types::DomainProvider::NameService(contract) => {
+            crate::validate_contract(env, contract);
+            NameServiceClient::new(env, &contract.address).release(name);
+        }
+    }
+}
+
+/// Renew the domain of a project for `DOMAIN_PERIOD`.
+///
+/// The period is added to the current expiration date, or to now once the
+/// domain expired. Soroban Domains records cannot be extended, the record is
+/// burned and registered again to the same owner until the new expiration
+/// date, for at least a year.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `provider` - The domain provider of the project
+/// * `name` - The normalized project name
+/// * `owner` - The address owning the domain
+/// * `expires_at` - The current expiration timestamp of the domain
+pub fn renew(
+    env: &Env,
+    provider: &types::DomainProvider,
+    name: &Bytes,
+    owner: &Address,
+    expires_at: u64,
+) {
+    match provider {
+        types::DomainProvider::SorobanDomains => {
+            let domain_contract = crate::retrieve_contract(env, types::ContractKey::DomainContract);
+            let now = env.ledger().timestamp();
+            let duration = expires_at.max(now) + types::DOMAIN_PERIOD - now;
+            soroban_domains_client(env).burn_record(&soroban_domains_record(env, name));
+            set_domain_record(env, name, owner, domain_contract.address, duration);
+        }
+        types::DomainProvider::NameService(contract) => {
+            crate::validate_contract(env, contract);
+            NameServiceClient::new(env, &contract.address).renew(name);
+        }
+    }
+}
+
+/// Client of the Soroban Domains contract.
+fn soroban_domains_client(env: &Env) -> domain_contract::Client<'_> {
+    let domain_contract = crate::retrieve_contract(env, types::ContractKey::DomainContract);
+    domain_contract::Client::new(env, &domain_contract.address)
+}
+
+/// Soroban Domains record of a project name.
+fn soroban_domains_record(env: &Env, name: &Bytes) -> domain_contract::RecordKeys {
+    let key: Bytes = env.crypto().keccak256(name).into();
+    domain_contract::RecordKeys::Record(domain_node(env, &key))
+}
+
+/// Register a Soroban Domain: https://sorobandomains.org
+/// Register a project name in the domain contract.
+///
+/// Helper function to register a project name in the domain contract system.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `name` - The project name to register
+/// * `maintainer` - The maintainer address to set as owner
+/// * `domain_contract_id` - The domain contract address
+pub fn domain_register(env: &Env, name: &Bytes, maintainer: &Address, domain_contract_id: Address) {
+    set_domain_record(
+        env,
+        name,
+        maintainer,
+        domain_contract_id,
+        types::DOMAIN_PERIOD,
+    );
+}
+
+/// Set the record of a project name in the domain contract.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `name` - The project name to register
+/// * `maintainer` - The maintainer address to set as owner
+/// * `domain_contract_id` - The domain contract address
+/// * `min_duration` - The duration of the registration in seconds
+fn set_domain_record(
+    env: &Env,
+    name: &Bytes,
+    maintainer: &Address,
+    domain_contract_id: Address,
+    min_duration: u64,
+) {
+    let tld = Bytes::from_slice(env, &[120, 108, 109]); // xlm
+
+    // Convert the arguments to Val
+    let name_raw = name.to_val();
+    let tld_raw = tld.to_val();
+    let maintainer_raw = maintainer.to_val();
+    let min_duration_raw: Val = min_duration.into_val(env);
+
+    // Construct the init_args
+    let init_args = vec![
+        &env,
+        name_raw,
+        tld_raw,
+        maintainer_raw,
+        maintainer_raw,
+        min_duration_raw,
+    ];
+
+    env.invoke_contract::<()>(
+        &domain_contract_id,
+        &Symbol::new(env, "set_record"),
+        init_args,
+    );
+}
+
+/// Generate a domain node hash for the domain contract.
+///
+/// Helper function to create a domain node hash from a project key.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `domain` - The domain bytes to hash
+///
+/// # Returns
+/// * `BytesN<32>` - The domain node hash
+pub fn domain_node(env: &Env, domain: &Bytes) -> BytesN<32> {
+    let tld = Bytes::from_slice(env, &[120, 108, 109]); // xlm
+    let parent_hash: Bytes = env.crypto().keccak256(&tld).into();
+    let mut node_builder: Bytes = Bytes::new(env);
+    node_builder.append(&parent_hash);
+    node_builder.append(domain);
+
+    env.crypto().keccak256(&node_builder).into()
+}
@@ -36,4 +36,147 @@ pub enum ContractErrors {
     UpgradeError = 23,
     ContractValidation = 24,
     CollateralError = 25,
+    // Project lifecycle
+    ProjectArchived = 26,
+    InvalidUrl = 27,
+    InsufficientBond = 28,
+    BondLocked = 29,
+    InvalidProjectName = 30,
+    InvalidMaintainersConfig = 31,
+    InsufficientRole = 32,
+    InvalidPackage = 33,
+    UnknownPackage = 34,
+    // Organizations
+    OrganizationAlreadyExist = 35,
+    UnknownOrganization = 36,
+    InvalidOrganization = 37,
+    // DAO control
+    DaoControlled = 38,
+    InvalidDaoAction = 39,
+    // Incident response
+    ProjectPaused = 40,
+    // Governance
+    InvalidGovernanceConfig = 41,
+    // Domain expiry
+    DomainExpired = 42,
+    // Discovery
+    InvalidTag = 43,
+    InvalidFork = 44,
+    // License
+    InvalidLicense = 45,
+    // Migration
+    InvalidBatch = 46,
+    // Commits
+    InvalidCommitMeta = 47,
+    // Releases
+    InvalidRelease = 48,
+    UnknownRelease = 49,
+}
+
+/// Errors added once `ContractErrors` reached the limit of 50 cases of
+/// contract specifications, codes continue after `ContractErrors`.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ExtendedErrors {
+    // Refs
+    InvalidRef = 50,
+    UnknownRef = 51,
+    // Commits
+    InvalidCommitHash = 52,
+    NoSigningKey = 53,
+    // Proofs
+    TreeAlreadyAnchored = 54,
+    // Releases
+    InvalidArtifact = 55,
+    // Attestations
+    NotAttestor = 56,
+    InvalidAttestation = 57,
+    InvalidAudit = 58,
+    // Advisories
+    InvalidAdvisory = 59,
+    UnknownAdvisory = 60,
+    // Releases
+    ReleaseNotApproved = 61,
+    // Commits
+    RateLimited = 62,
+    NothingToRevert = 63,
+    ProposalNotApproved = 64,
+    // Dependencies
+    InvalidDependency = 65,
+    UnknownDependency = 66,
+    // Reviews
+    NotReviewer = 67,
+    InvalidReview = 68,
+    // Credits
+    InvalidCredit = 69,
+    // IPFS
+    InvalidCid = 70,
+    // Releases
+    InvalidSigningKey = 71,
+    UnknownSigningKey = 72,
+    InvalidToolchain = 73,
+    // Treasury
+    InvalidAmount = 74,
+    InsufficientTreasury = 75,
+    // Delegation
+    InvalidDelegation = 77,
+    VoteDelegated = 78,
+    // DAO
+    UnknownVote = 79,
+    ProposalNotCancellable = 80,
+    InvalidVeto = 81,
+    ProposalCooldown = 82,
+    ProposalNotAmendable = 83,
+    InvalidProposalDependency = 84,
+    DependencyPending = 85,
+    InvalidTemplate = 86,
+    UnknownTemplate = 87,
+    InvalidVoteOption = 88,
+    // Conviction voting
+    ConvictionDisabled = 89,
+    ConvictionTooLow = 90,
+    InvalidSupport = 91,
+    // Staking
+    StakingDisabled = 92,
+    InvalidStake = 93,
+    StakeUnbonding = 94,
+    // Participation rewards
+    InvalidRewardPool = 95,
+    RewardAlreadyClaimed = 96,
+    // Scheduler
+    ScheduleFull = 97,
+    // Emergency proposals
+    EmergencyDisabled = 98,
+    // Council
+    InvalidCouncil = 99,
+}
+
+/// Errors added once `ExtendedErrors` reached the limit of 50 cases of
+/// contract specifications, codes continue after `ExtendedErrors`.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum AdditionalErrors {
+    // Federated proposals
+    InvalidFederation = 100,
+    UnknownFederation = 101,
+    // Sponsorship
+    ProposalNotSponsored = 102,
+    InvalidSponsor = 103,
+    // Commit-reveal voting
+    InvalidReveal = 104,
+    // Rewards
+    RewardClaimPeriod = 105,
+    // Anonymous voting
+    AnonymousKeysInUse = 106,
+    // Projects
+    NotPaused = 107,
+    NotArchived = 108,
+    // Anonymous voting
+    AnonymousVotingAlreadySetup = 109,
+    // Commits
+    NotCommitProposal = 110,
+    CommitMismatch = 111,
+    ProposalAlreadyLinked = 112,
 }
@@ -1,6 +1,10 @@
 //! Event definitions for the Tansu contract.
 
-use soroban_sdk::{Address, Bytes, BytesN, String, contractevent};
+use crate::types::{
+    Badge, CommitMeta, ConfigField, DomainProvider, GovernanceConfig, MaintainersAction, Metadata,
+    OrganizationAction,
+};
+use soroban_sdk::{Address, Bytes, BytesN, String, Symbol, Vec, contractevent};
 
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -14,6 +18,165 @@ pub struct ProjectRegistered {
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ProjectConfigUpdated {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub maintainer: Address,
+    #[topic]
+    pub field: ConfigField,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProjectMetadataUpdated {
+    #[topic]
+    pub project_key: Bytes,
+    pub maintainer: Address,
+    pub previous: Metadata,
+    pub metadata: Metadata,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MirrorAdded {
+    #[topic]
+    pub project_key: Bytes,
+    pub maintainer: Address,
+    pub name: String,
+    pub url: String,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MirrorRemoved {
+    #[topic]
+    pub project_key: Bytes,
+    pub maintainer: Address,
+    pub url: String,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MaintainersActionApproved {
+    #[topic]
+    pub project_key: Bytes,
+    pub maintainer: Address,
+    pub action: MaintainersAction,
+    pub approvals_count: u32,
+    pub executed: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectTransferred {
+    #[topic]
+    pub project_key: Bytes,
+    pub maintainer: Address,
+    pub new_maintainer: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectArchived {
+    #[topic]
+    pub project_key: Bytes,
+    pub archived: bool,
+    pub maintainer: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectPaused {
+    #[topic]
+    pub project_key: Bytes,
+    pub paused: bool,
+    pub maintainer: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectRenouncedToDao {
+    #[topic]
+    pub project_key: Bytes,
+    pub maintainer: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeregistrationApproved {
+    #[topic]
+    pub project_key: Bytes,
+    pub maintainer: Address,
+    pub executable_at: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DomainOwnershipChanged {
+    #[topic]
+    pub project_key: Bytes,
+    pub owner: Option<Address>,
+    pub verified: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LicenseChanged {
+    #[topic]
+    pub project_key: Bytes,
+    pub old_license: Option<String>,
+    pub new_license: String,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectDeprecated {
+    #[topic]
+    pub project_key: Bytes,
+    pub deprecated: bool,
+    pub maintainer: Address,
+    pub successor: Option<Bytes>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MaintainerRotated {
+    #[topic]
+    pub project_key: Bytes,
+    pub old_maintainer: Address,
+    pub new_maintainer: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectForked {
+    #[topic]
+    pub project_key: Bytes,
+    pub parent_key: Bytes,
+    pub maintainer: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ForkRemoved {
+    #[topic]
+    pub project_key: Bytes,
+    pub fork_key: Bytes,
+    pub maintainer: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectTagsUpdated {
+    #[topic]
+    pub project_key: Bytes,
+    pub maintainer: Address,
+    pub tags: Vec<String>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProjectDeregistered {
     #[topic]
     pub project_key: Bytes,
     pub maintainer: Address,
@@ -24,6 +187,25 @@ pub struct ProjectConfigUpdated {
 pub struct Commit {
     #[topic]
     pub project_key: Bytes,
+    #[topic]
+    pub maintainer: Address,
+    #[topic]
+    pub ref_name: Option<String>, // None for the latest commit of the project
+    pub hash: String,
+    pub ledger: u32,
+    pub meta: Option<CommitMeta>,
+    pub signing_key: Option<BytesN<32>>,
+    pub proposal_id: Option<u32>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitReverted {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub maintainer: Address,
+    pub reverted_hash: String,
     pub hash: String,
 }
 
@@ -36,10 +218,13 @@ pub struct MemberAdded {
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct BadgesUpdated {
+    #[topic]
     pub project_key: Bytes,
+    #[topic]
     pub maintainer: Address,
+    #[topic]
     pub member: Address,
-    pub badges_count: u32,
+    pub badges: Vec<Badge>,
 }
 
 #[contractevent]
@@ -54,6 +239,147 @@ pub struct ProposalCreated {
     pub public_voting: bool,
 }
 
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceConfigUpdated {
+    #[topic]
+    pub project_key: Bytes,
+    pub config: GovernanceConfig,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryFunded {
+    #[topic]
+    pub project_key: Bytes,
+    pub funder: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DepositSlashed {
+    #[topic]
+    pub project_key: Bytes,
+    pub proposal_id: u32,
+    pub proposer: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OutcomesContractInvoked {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub proposal_id: u32,
+    pub contract: Address,
+    pub function: Symbol,
+    pub success: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitmentsSlashed {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub proposal_id: u32,
+    pub voters: u32,
+    pub amount: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractInvoked {
+    #[topic]
+    pub project_key: Bytes,
+    pub executor: Address,
+    pub contract: Address,
+    pub function: Symbol,
+    pub success: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasurySpent {
+    #[topic]
+    pub project_key: Bytes,
+    pub executor: Address,
+    pub token: Address,
+    pub recipient: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VetoApproved {
+    #[topic]
+    pub project_key: Bytes,
+    pub proposal_id: u32,
+    pub maintainer: Address,
+    pub reason: String,
+    pub approvals_count: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalAmended {
+    #[topic]
+    pub project_key: Bytes,
+    pub proposal_id: u32,
+    pub ipfs: String,
+    pub revision: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalTemplateUpdated {
+    #[topic]
+    pub project_key: Bytes,
+    pub template_id: u32,
+    pub maintainer: Address,
+    pub active: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalLinked {
+    #[topic]
+    pub project_key: Bytes,
+    pub proposal_id: u32,
+    pub depends_on: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalCancelled {
+    #[topic]
+    pub project_key: Bytes,
+    pub proposal_id: u32,
+    pub proposer: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteChanged {
+    #[topic]
+    pub project_key: Bytes,
+    pub proposal_id: u32,
+    pub voter: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteDelegated {
+    #[topic]
+    pub project_key: Bytes,
+    #[topic]
+    pub member: Address,
+    pub delegate: Option<Address>,
+}
+
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct VoteCast {
@@ -89,6 +415,16 @@ pub struct AnonymousVotingSetup {
     pub public_key: String,
 }
 
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AnonymousKeyRotated {
+    #[topic]
+    pub project_key: Bytes,
+    pub maintainer: Address,
+    pub public_key: String,
+    pub active_from: u64,
+}
+
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ContractUpdated {
@@ -98,6 +434,58 @@ pub struct ContractUpdated {
     pub wasm_hash: Option<BytesN<32>>,
 }
 
+#[contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DomainProviderUpdated {
+    pub admin: Address,
+    pub provider: DomainProvider,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DomainRenewed {
+    #[topic]
+    pub project_key: Bytes,
+    pub maintainer: Address,
+    pub expires_at: Option<u64>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BondConfigUpdated {
+    pub admin: Address,
+    pub amount: i128,
+    pub lock_ledgers: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestorUpdated {
+    pub admin: Address,
+    #[topic]
+    pub attestor: Address,
+    pub enabled: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvokeTargetUpdated {
+    pub admin: Address,
+    #[topic]
+    pub contract: Address,
+    pub function: Symbol,
+    pub enabled: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BondRefunded {
+    #[topic]
+    pub project_key: Bytes,
+    pub depositor: Address,
+    pub amount: i128,
+}
+
 #[contractevent]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct UpgradeProposed {
@@ -121,3 +509,83 @@ pub struct UpgradeStatus {
     pub wasm_hash: Bytes,
     pub status: String,
 }
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PackageAdded {
+    #[topic]
+    pub project_key: Bytes,
+    pub maintainer: Address,
+    pub name: String,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PackageRemoved {
+    #[topic]
+    pub project_key: Bytes,
+    pub maintainer: Address,
+    pub name: String,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PackageCommit {
+    #[topic]
+    pub project_key: Bytes,
+    pub name: String,
+    pub hash: String,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SigningKeyUpdated {
+    #[topic]
+    pub maintainer: Address,
+    pub signing_key: Option<BytesN<32>>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PackageReleased {
+    #[topic]
+    pub project_key: Bytes,
+    pub name: String,
+    pub version: String,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrganizationCreated {
+    #[topic]
+    pub organization_key: Bytes,
+    pub name: String,
+    pub admin: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrganizationProjectAdded {
+    #[topic]
+    pub organization_key: Bytes,
+    pub project_key: Bytes,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrganizationProjectRemoved {
+    #[topic]
+    pub organization_key: Bytes,
+    pub project_key: Bytes,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrganizationActionApproved {
+    #[topic]
+    pub organization_key: Bytes,
+    pub admin: Address,
+    pub action: OrganizationAction,
+    pub approvals_count: u32,
+    pub executed: bool,
+}
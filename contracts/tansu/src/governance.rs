@@ -0,0 +1,71 @@
+//! Governance extensions of the DAOs.
+//!
+//! Councils, federated proposals, hashed votes, sponsorships, participation
+//! rewards and conviction voting are handled by a separate governance
+//! contract, keeping Tansu within the contract size limit. The governance
+//! contract calls Tansu to read the proposals and to spend the treasuries,
+//! see `spend_treasury`. Tansu calls the governance contract when proposals
+//! are created, voted, closed or executed, and to clean up deregistered
+//! projects.
+
+use soroban_sdk::{Address, Bytes, Env, Map, Vec, contractclient};
+
+use crate::types;
+
+/// Functions of the governance contract used by Tansu.
+///
+/// Tansu only uses the generated `GovernanceClient`.
+#[allow(dead_code)]
+#[contractclient(name = "GovernanceClient")]
+pub trait GovernanceInterface {
+    /// Record that a new proposal needs sponsors, authorized by Tansu.
+    fn open_sponsorship(env: Env, project_key: Bytes, proposal_id: u32, required: u32);
+
+    /// Whether a proposal is open for voting.
+    fn is_sponsored(env: Env, project_key: Bytes, proposal_id: u32) -> bool;
+
+    /// Remove the sponsors and hashed votes of a closed proposal, authorized
+    /// by Tansu. Returns the voters whose hashed vote was not revealed.
+    fn close_proposal(env: Env, project_key: Bytes, proposal_id: u32) -> Vec<Address>;
+
+    /// Add tokens transferred from the treasury to a reward pool, authorized
+    /// by Tansu.
+    fn fund_reward_pool(
+        env: Env,
+        project_key: Bytes,
+        proposal_id: u32,
+        token: Address,
+        amount: i128,
+        closes_at: u64,
+    );
+
+    /// Transfer the unclaimed part of a reward pool back, authorized by Tansu.
+    fn refund_reward_pool(
+        env: Env,
+        project_key: Bytes,
+        proposal_id: u32,
+    ) -> Option<types::RewardPool>;
+
+    /// Reward pool of a proposal.
+    fn get_proposal_reward(
+        env: Env,
+        project_key: Bytes,
+        proposal_id: u32,
+    ) -> Option<types::RewardPool>;
+
+    /// Elect the council of a project, authorized by Tansu.
+    fn set_council(env: Env, project_key: Bytes, council: types::Council);
+
+    /// Remove the data of a deregistered project, authorized by Tansu.
+    /// Returns the amount of each token transferred back.
+    fn remove_project(env: Env, project_key: Bytes) -> Map<Address, i128>;
+}
+
+/// Get a client of the governance contract.
+///
+/// # Panics
+/// * If the governance contract is not set or does not match on-chain data
+pub fn client(env: &Env) -> GovernanceClient<'_> {
+    let governance = crate::retrieve_contract(env, types::ContractKey::GovernanceContract);
+    GovernanceClient::new(env, &governance.address)
+}
@@ -1,6 +1,8 @@
 #![no_std]
 
-use soroban_sdk::{Address, Bytes, BytesN, Env, String, Vec, contract, panic_with_error};
+use soroban_sdk::{
+    Address, Bytes, BytesN, Env, Map, String, Symbol, U256, Vec, contract, panic_with_error,
+};
 use soroban_sdk::{Executable, contractmeta};
 
 mod domain_contract {
@@ -13,13 +15,22 @@ mod outcomes_contract {
 
 mod contract_dao;
 mod contract_membership;
+mod contract_organizations;
+mod contract_packages;
 mod contract_tansu;
 mod contract_versioning;
+mod domain;
 mod errors;
 mod events;
+mod governance;
+mod registry;
+mod scheduler;
+mod semver;
+mod staking;
 #[cfg(test)]
 mod tests;
 mod types;
+mod validation;
 
 contractmeta!(key = "Description", val = "Tansu - Soroban Versioning");
 
@@ -37,8 +48,40 @@ pub trait TansuTrait {
 
     fn set_domain_contract(env: Env, admin: Address, domain_contract: types::Contract);
 
+    fn set_domain_provider(env: Env, admin: Address, provider: types::DomainProvider);
+
+    fn get_domain_provider(env: Env) -> types::DomainProvider;
+
     fn set_collateral_contract(env: Env, admin: Address, collateral_contract: types::Contract);
 
+    fn set_registry_contract(env: Env, admin: Address, registry_contract: types::Contract);
+
+    fn set_staking_contract(env: Env, admin: Address, staking_contract: types::Contract);
+
+    fn set_scheduler_contract(env: Env, admin: Address, scheduler_contract: types::Contract);
+
+    fn set_governance_contract(env: Env, admin: Address, governance_contract: types::Contract);
+
+    fn set_bond_config(env: Env, admin: Address, bond_config: types::BondConfig);
+
+    fn get_bond_config(env: Env) -> types::BondConfig;
+
+    fn set_attestor(env: Env, admin: Address, attestor: Address, enabled: bool);
+
+    fn is_attestor(env: Env, attestor: Address) -> bool;
+
+    fn set_invoke_target(
+        env: Env,
+        admin: Address,
+        contract: Address,
+        function: Symbol,
+        enabled: bool,
+    );
+
+    fn is_invoke_target(env: Env, contract: Address, function: Symbol) -> bool;
+
+    fn get_stats(env: Env) -> types::Stats;
+
     fn propose_upgrade(
         env: Env,
         caller: Address,
@@ -71,6 +114,10 @@ pub trait MembershipTrait {
     fn get_badges(env: Env, key: Bytes) -> types::Badges;
 
     fn get_max_weight(env: Env, key: Bytes, member_address: Address) -> u32;
+
+    fn get_member_weight(env: Env, project_key: Bytes, member_address: Address) -> u32;
+
+    fn get_reputation(env: Env, project_key: Bytes, member_address: Address) -> u32;
 }
 
 pub trait VersioningTrait {
@@ -81,8 +128,39 @@ pub trait VersioningTrait {
         maintainers: Vec<Address>,
         url: String,
         ipfs: String,
+        license: Option<String>,
     ) -> Bytes;
 
+    fn register_batch(
+        env: Env,
+        admin: Address,
+        projects: Vec<types::ProjectRegistration>,
+    ) -> Vec<Bytes>;
+
+    fn migrate_maintainer_roles(env: Env, admin: Address, keys: Vec<Bytes>);
+
+    fn migrate_projects(env: Env, admin: Address, keys: Vec<Bytes>);
+
+    fn migrate_proposals(env: Env, admin: Address, key: Bytes, page: u32);
+
+    #[allow(clippy::too_many_arguments)]
+    fn register_fork(
+        env: Env,
+        maintainer: Address,
+        name: String,
+        maintainers: Vec<Address>,
+        url: String,
+        ipfs: String,
+        parent_key: Bytes,
+        license: Option<String>,
+    ) -> Bytes;
+
+    fn get_fork_parent(env: Env, key: Bytes) -> Option<Bytes>;
+
+    fn get_forks(env: Env, key: Bytes) -> Vec<Bytes>;
+
+    fn remove_fork(env: Env, maintainer: Address, key: Bytes, fork_key: Bytes);
+
     fn update_config(
         env: Env,
         maintainer: Address,
@@ -90,13 +168,210 @@ pub trait VersioningTrait {
         maintainers: Vec<Address>,
         url: String,
         hash: String,
+    ) -> bool;
+
+    fn add_maintainer(env: Env, maintainer: Address, key: Bytes, new_maintainer: Address) -> bool;
+
+    fn remove_maintainer(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        old_maintainer: Address,
+    ) -> bool;
+
+    fn rotate_maintainer(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        old_maintainer: Address,
+        new_maintainer: Address,
+    ) -> bool;
+
+    fn set_maintainers_config(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        maintainers_threshold: u32,
+        max_maintainers: u32,
+    ) -> bool;
+
+    fn set_maintainer_role(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        member: Address,
+        role: types::MaintainerRole,
+    ) -> bool;
+
+    fn get_maintainer_role(env: Env, key: Bytes, maintainer: Address) -> types::MaintainerRole;
+
+    fn deregister(env: Env, maintainer: Address, key: Bytes) -> bool;
+
+    fn claim_bond(env: Env, maintainer: Address, key: Bytes);
+
+    fn get_bond(env: Env, key: Bytes) -> Option<types::Bond>;
+
+    fn add_mirror(env: Env, maintainer: Address, key: Bytes, mirror: types::RepoRef);
+
+    fn remove_mirror(env: Env, maintainer: Address, key: Bytes, url: String);
+
+    fn update_metadata(env: Env, maintainer: Address, key: Bytes, metadata: types::Metadata);
+
+    fn transfer_project(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        new_maintainer: Address,
+        maintainers: Vec<Address>,
+    ) -> bool;
+
+    fn archive_project(env: Env, maintainer: Address, key: Bytes) -> bool;
+
+    fn unarchive_project(env: Env, maintainer: Address, key: Bytes) -> bool;
+
+    fn is_archived(env: Env, key: Bytes) -> bool;
+
+    fn deprecate(env: Env, maintainer: Address, key: Bytes, successor: Option<Bytes>) -> bool;
+
+    fn undeprecate(env: Env, maintainer: Address, key: Bytes) -> bool;
+
+    fn get_deprecation(env: Env, key: Bytes) -> Option<types::Deprecation>;
+
+    fn pause_project(env: Env, maintainer: Address, key: Bytes) -> bool;
+
+    fn resume_project(env: Env, maintainer: Address, key: Bytes) -> bool;
+
+    fn is_project_paused(env: Env, key: Bytes) -> bool;
+
+    fn renounce_to_dao(env: Env, maintainer: Address, key: Bytes) -> bool;
+
+    fn is_dao_controlled(env: Env, key: Bytes) -> bool;
+
+    fn renew_domain(env: Env, maintainer: Address, key: Bytes);
+
+    fn check_domain_status(env: Env, key: Bytes) -> types::DomainStatus;
+
+    fn verify_domain(env: Env, key: Bytes) -> bool;
+
+    fn get_domain_verification(env: Env, key: Bytes) -> Option<types::DomainVerification>;
+
+    fn commit(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        hash: String,
+        meta: Option<types::CommitMeta>,
+        signature: Option<BytesN<64>>,
+        proposal_id: Option<u32>,
     );
 
-    fn commit(env: Env, maintainer: Address, project_key: Bytes, hash: String);
+    fn revert_commit(env: Env, maintainer: Address, project_key: Bytes) -> String;
+
+    fn set_signing_key(env: Env, maintainer: Address, signing_key: Option<BytesN<32>>);
+
+    fn get_signing_key(env: Env, maintainer: Address) -> Option<BytesN<32>>;
 
     fn get_commit(env: Env, project_key: Bytes) -> String;
 
+    fn commit_to_ref(env: Env, maintainer: Address, project_key: Bytes, name: String, hash: String);
+
+    fn get_ref(env: Env, project_key: Bytes, name: String) -> String;
+
+    fn get_refs(env: Env, project_key: Bytes) -> Vec<String>;
+
+    fn set_hash_algorithm(
+        env: Env,
+        maintainer: Address,
+        key: Bytes,
+        algorithm: types::HashAlgorithm,
+    );
+
+    fn get_hash_algorithm(env: Env, key: Bytes) -> types::HashAlgorithm;
+
+    fn set_commit_cooldown(env: Env, maintainer: Address, key: Bytes, ledgers: u32);
+
+    fn get_commit_cooldown(env: Env, key: Bytes) -> u32;
+
+    fn get_commit_history(env: Env, project_key: Bytes, page: u32) -> Vec<types::CommitRecord>;
+
+    fn get_commit_meta(env: Env, project_key: Bytes, index: u32) -> Option<types::CommitMeta>;
+
+    fn get_commits(
+        env: Env,
+        project_key: Bytes,
+        cursor: Option<u32>,
+        limit: u32,
+    ) -> types::CommitPage;
+
     fn get_project(env: Env, project_key: Bytes) -> types::Project;
+
+    fn get_project_stats(env: Env, project_key: Bytes) -> types::ProjectStats;
+
+    fn get_metadata_history(env: Env, project_key: Bytes) -> Vec<types::MetadataCommitment>;
+
+    fn list_projects(env: Env, page: u32) -> Vec<Bytes>;
+
+    fn set_tags(env: Env, maintainer: Address, key: Bytes, tags: Vec<String>);
+
+    fn get_tags(env: Env, key: Bytes) -> Vec<String>;
+
+    fn list_projects_by_tag(env: Env, tag: String, page: u32) -> Vec<Bytes>;
+
+    fn get_project_id(env: Env, name: String) -> Bytes;
+
+    fn get_project_by_name(env: Env, name: String) -> types::Project;
+}
+
+pub trait PackagesTrait {
+    fn add_package(env: Env, maintainer: Address, key: Bytes, name: String) -> String;
+
+    fn remove_package(env: Env, maintainer: Address, key: Bytes, name: String);
+
+    fn commit_package(env: Env, maintainer: Address, key: Bytes, name: String, hash: String);
+
+    fn release_package(env: Env, maintainer: Address, key: Bytes, name: String, version: String);
+
+    fn get_packages(env: Env, key: Bytes) -> Vec<String>;
+
+    fn get_package(env: Env, key: Bytes, name: String) -> types::Package;
+}
+
+pub trait OrganizationsTrait {
+    fn create_organization(env: Env, admin: Address, name: String, admins: Vec<Address>) -> Bytes;
+
+    fn add_organization_project(
+        env: Env,
+        admin: Address,
+        organization_key: Bytes,
+        maintainer: Address,
+        project_key: Bytes,
+    );
+
+    fn remove_organization_project(
+        env: Env,
+        caller: Address,
+        organization_key: Bytes,
+        project_key: Bytes,
+    );
+
+    fn rotate_organization_maintainer(
+        env: Env,
+        admin: Address,
+        organization_key: Bytes,
+        old_maintainer: Address,
+        new_maintainer: Address,
+    ) -> bool;
+
+    fn set_organization_admins(
+        env: Env,
+        admin: Address,
+        organization_key: Bytes,
+        admins: Vec<Address>,
+    ) -> bool;
+
+    fn get_organization(env: Env, organization_key: Bytes) -> types::Organization;
+
+    fn get_project_organization(env: Env, project_key: Bytes) -> Option<Bytes>;
 }
 
 pub trait DaoTrait {
@@ -109,11 +384,23 @@ pub trait DaoTrait {
 
     fn get_anonymous_voting_config(env: Env, project_key: Bytes) -> types::AnonymousVoteConfig;
 
+    fn rotate_anonymous_key(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        public_key: String,
+    ) -> Option<u64>;
+
+    fn get_anonymous_key_history(env: Env, project_key: Bytes) -> Vec<types::AnonymousKey>;
+
+    fn get_proposal_anonymous_key(env: Env, project_key: Bytes, proposal_id: u32)
+    -> Option<String>;
+
     fn build_commitments_from_votes(
         env: Env,
         project_key: Bytes,
         votes: Vec<u128>,
-        seeds: Vec<u128>,
+        seeds: Vec<U256>,
     ) -> Vec<BytesN<96>>;
 
     #[allow(clippy::too_many_arguments)]
@@ -128,34 +415,209 @@ pub trait DaoTrait {
         outcomes_contract: Option<Address>,
     ) -> u32;
 
+    #[allow(clippy::too_many_arguments)]
+    fn create_multiple_choice_proposal(
+        env: Env,
+        proposer: Address,
+        project_key: Bytes,
+        title: String,
+        ipfs: String,
+        voting_ends_at: u64,
+        options: Vec<String>,
+    ) -> u32;
+
+    #[allow(clippy::too_many_arguments)]
+    fn create_action_proposal(
+        env: Env,
+        proposer: Address,
+        project_key: Bytes,
+        title: String,
+        ipfs: String,
+        voting_ends_at: u64,
+        public_voting: bool,
+        action: types::DaoAction,
+    ) -> u32;
+
+    #[allow(clippy::too_many_arguments)]
+    fn create_emergency_proposal(
+        env: Env,
+        proposer: Address,
+        cosigner: Address,
+        project_key: Bytes,
+        title: String,
+        ipfs: String,
+        voting_ends_at: u64,
+        action: Option<types::DaoAction>,
+    ) -> u32;
+
+    fn get_proposal_action(
+        env: Env,
+        project_key: Bytes,
+        proposal_id: u32,
+    ) -> Option<types::DaoAction>;
+
+    fn get_governance_config(env: Env, project_key: Bytes) -> types::GovernanceConfig;
+
+    fn fund_treasury(env: Env, funder: Address, project_key: Bytes, token: Address, amount: i128);
+
+    fn get_treasury(env: Env, project_key: Bytes) -> Map<Address, i128>;
+
     fn vote(env: Env, voter: Address, project_key: Bytes, proposal_id: u32, vote: types::Vote);
 
+    fn change_vote(
+        env: Env,
+        voter: Address,
+        project_key: Bytes,
+        proposal_id: u32,
+        vote: types::PublicVote,
+    );
+
+    fn delegate(env: Env, member: Address, project_key: Bytes, delegate: Address);
+
+    fn undelegate(env: Env, member: Address, project_key: Bytes);
+
+    fn get_delegations(env: Env, project_key: Bytes) -> Map<Address, Address>;
+
     fn revoke_proposal(env: Env, maintainer: Address, project_key: Bytes, proposal_id: u32);
 
+    fn cancel_proposal(env: Env, proposer: Address, project_key: Bytes, proposal_id: u32);
+
+    fn amend_proposal(
+        env: Env,
+        proposer: Address,
+        project_key: Bytes,
+        proposal_id: u32,
+        ipfs: String,
+    ) -> u32;
+
+    fn get_revisions(env: Env, project_key: Bytes, proposal_id: u32) -> Vec<types::Revision>;
+
+    fn link_proposal(
+        env: Env,
+        proposer: Address,
+        project_key: Bytes,
+        proposal_id: u32,
+        depends_on: u32,
+    );
+
+    fn add_proposal_template(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        template: types::ProposalTemplate,
+    ) -> u32;
+
+    fn remove_proposal_template(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        template_id: u32,
+    );
+
+    fn get_proposal_templates(env: Env, project_key: Bytes) -> Map<u32, types::ProposalTemplate>;
+
+    fn tick(env: Env, proposer: Address, project_key: Bytes) -> Vec<u32>;
+
+    fn veto_proposal(
+        env: Env,
+        maintainer: Address,
+        project_key: Bytes,
+        proposal_id: u32,
+        reason: String,
+    ) -> bool;
+
+    fn get_vetoes(env: Env, project_key: Bytes, proposal_id: u32) -> Vec<types::Veto>;
+
+    fn get_voting_weight(
+        env: Env,
+        project_key: Bytes,
+        proposal_id: u32,
+        member_address: Address,
+    ) -> u32;
+
     fn execute(
         env: Env,
         maintainer: Address,
         project_key: Bytes,
         proposal_id: u32,
         tallies: Option<Vec<u128>>,
-        seeds: Option<Vec<u128>>,
+        seeds: Option<Vec<U256>>,
     ) -> types::ProposalStatus;
 
+    fn get_outcome(env: Env, project_key: Bytes, proposal_id: u32) -> types::ProposalStatus;
+
+    fn run_scheduled(env: Env, project_key: Bytes, proposal_id: u32) -> types::ScheduledRun;
+
+    fn spend_treasury(
+        env: Env,
+        project_key: Bytes,
+        executor: Address,
+        token: Address,
+        recipient: Address,
+        amount: i128,
+    );
+
+    fn refund_treasury(env: Env, project_key: Bytes, token: Address, amount: i128);
+
+    fn use_proposal_cooldown(env: Env, project_key: Bytes, proposer: Address);
+
+    fn take_vote_collateral(env: Env, voter: Address);
+
+    fn cast_revealed_vote(
+        env: Env,
+        voter: Address,
+        project_key: Bytes,
+        proposal_id: u32,
+        vote: types::PublicVote,
+    );
+
     fn proof(
         env: Env,
         project_key: Bytes,
         proposal: types::Proposal,
         tallies: Vec<u128>,
-        seeds: Vec<u128>,
+        seeds: Vec<U256>,
     ) -> bool;
 
     fn get_dao(env: Env, project_key: Bytes, page: u32) -> types::Dao;
 
     fn get_proposal(env: Env, project_key: Bytes, proposal_id: u32) -> types::Proposal;
+
+    fn get_proposal_status(env: Env, project_key: Bytes, proposal_id: u32)
+    -> types::ProposalStatus;
+
+    fn get_proposals(
+        env: Env,
+        project_key: Bytes,
+        status: Option<types::ProposalStatus>,
+        cursor: Option<u32>,
+        limit: u32,
+    ) -> types::ProposalPage;
+
+    fn tally(env: Env, project_key: Bytes, proposal_id: u32) -> types::Tally;
+
+    fn get_turnout(env: Env, project_key: Bytes, proposal_id: u32) -> types::Turnout;
+
+    fn get_vote(
+        env: Env,
+        project_key: Bytes,
+        proposal_id: u32,
+        voter: Address,
+    ) -> Option<types::Vote>;
 }
 
 fn auth_maintainers(env: &Env, maintainer: &Address, project_key: &Bytes) -> types::Project {
     maintainer.require_auth();
+    check_maintainers(env, maintainer, project_key)
+}
+
+/// Check that an address is a maintainer of a project, without requiring its
+/// authorization.
+///
+/// # Panics
+/// * If the project doesn't exist
+/// * If the address is not a maintainer
+fn check_maintainers(env: &Env, maintainer: &Address, project_key: &Bytes) -> types::Project {
     let project_key_ = types::ProjectKey::Key(project_key.clone());
     if let Some(project) = env
         .storage()
@@ -171,6 +633,483 @@ fn auth_maintainers(env: &Env, maintainer: &Address, project_key: &Bytes) -> typ
     }
 }
 
+/// Authenticate a maintainer and check that it holds at least the given role.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `maintainer` - The address of the maintainer
+/// * `project_key` - The project key identifier
+/// * `role` - The minimal role required
+///
+/// # Returns
+/// * `types::Project` - The project
+///
+/// # Panics
+/// * If the project doesn't exist
+/// * If the maintainer is not authorized
+/// * If the maintainer's role is not sufficient
+fn auth_maintainers_role(
+    env: &Env,
+    maintainer: &Address,
+    project_key: &Bytes,
+    role: types::MaintainerRole,
+) -> types::Project {
+    maintainer.require_auth();
+    check_maintainers_role(env, maintainer, project_key, role)
+}
+
+/// Check that an address is a maintainer holding at least the given role,
+/// without requiring its authorization.
+///
+/// # Panics
+/// * If the project doesn't exist
+/// * If the address is not a maintainer
+/// * If the maintainer's role is not sufficient
+fn check_maintainers_role(
+    env: &Env,
+    maintainer: &Address,
+    project_key: &Bytes,
+    role: types::MaintainerRole,
+) -> types::Project {
+    let project = check_maintainers(env, maintainer, project_key);
+    if maintainer_role(env, project_key, maintainer) < role {
+        panic_with_error!(&env, &errors::ContractErrors::InsufficientRole);
+    }
+    project
+}
+
+/// Role of a maintainer on a project.
+///
+/// Maintainers without an explicit role are committers, the maintainers
+/// registering or receiving a project are made admins explicitly.
+fn maintainer_role(env: &Env, project_key: &Bytes, maintainer: &Address) -> types::MaintainerRole {
+    env.storage()
+        .persistent()
+        .get(&types::ProjectKey::MaintainerRole(
+            project_key.clone(),
+            maintainer.clone(),
+        ))
+        .unwrap_or(types::MaintainerRole::Committer)
+}
+
+/// Check that at least one of the maintainers is an admin.
+///
+/// # Panics
+/// * If none of the maintainers is an admin
+fn require_admin_maintainer(env: &Env, project_key: &Bytes, maintainers: &Vec<Address>) {
+    for maintainer in maintainers.iter() {
+        if maintainer_role(env, project_key, &maintainer) == types::MaintainerRole::Admin {
+            return;
+        }
+    }
+    panic_with_error!(&env, &errors::ContractErrors::InvalidMaintainersConfig);
+}
+
+/// Make maintainers admins of a project.
+fn grant_admin_roles(env: &Env, project_key: &Bytes, maintainers: &Vec<Address>) {
+    for maintainer in maintainers.iter() {
+        env.storage().persistent().set(
+            &types::ProjectKey::MaintainerRole(project_key.clone(), maintainer),
+            &types::MaintainerRole::Admin,
+        );
+    }
+}
+
+/// Remove the roles and last commits of the maintainers which are not part
+/// of the project anymore.
+fn prune_maintainer_roles(
+    env: &Env,
+    project_key: &Bytes,
+    old_maintainers: &Vec<Address>,
+    new_maintainers: &Vec<Address>,
+) {
+    for maintainer in old_maintainers.iter() {
+        if !new_maintainers.contains(&maintainer) {
+            env.storage()
+                .persistent()
+                .remove(&types::ProjectKey::MaintainerRole(
+                    project_key.clone(),
+                    maintainer.clone(),
+                ));
+            env.storage()
+                .persistent()
+                .remove(&types::ProjectKey::LastCommit(
+                    project_key.clone(),
+                    maintainer,
+                ));
+        }
+    }
+}
+
+/// Update the global counters.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `update` - Function applying the changes to the counters
+///
+/// # Returns
+/// * `types::Stats` - The counters before the update
+fn update_stats(env: &Env, update: impl FnOnce(&mut types::Stats)) -> types::Stats {
+    let previous: types::Stats = env
+        .storage()
+        .persistent()
+        .get(&types::DataKey::Stats)
+        .unwrap_or_default();
+    let mut stats = previous.clone();
+    update(&mut stats);
+    env.storage()
+        .persistent()
+        .set(&types::DataKey::Stats, &stats);
+    previous
+}
+
+/// Update the counters of a project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `update` - Function applying the changes to the counters
+fn update_project_stats(
+    env: &Env,
+    project_key: &Bytes,
+    update: impl FnOnce(&mut types::ProjectStats),
+) {
+    let key_ = types::ProjectKey::Stats(project_key.clone());
+    let mut stats: types::ProjectStats = env.storage().persistent().get(&key_).unwrap_or_default();
+    update(&mut stats);
+    env.storage().persistent().set(&key_, &stats);
+}
+
+/// Record a new commit of a project.
+///
+/// The commit becomes the last hash of the project and is kept in a ring
+/// buffer of the last `MAX_COMMIT_HISTORY` commits.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `maintainer` - The maintainer, or executor of the proposal, committing
+/// * `hash` - The commit hash
+/// * `meta` - Optional attested details of the commit
+/// * `signing_key` - The ed25519 key which signed the commit, if signed
+/// * `reverts` - The hash reverted by the commit, if it is a revert
+/// * `proposal_id` - The approved proposal authorizing the commit, if any
+#[allow(clippy::too_many_arguments)]
+fn record_commit(
+    env: &Env,
+    project_key: &Bytes,
+    maintainer: &Address,
+    hash: String,
+    meta: Option<types::CommitMeta>,
+    signing_key: Option<BytesN<32>>,
+    reverts: Option<String>,
+    proposal_id: Option<u32>,
+) {
+    env.storage()
+        .persistent()
+        .set(&types::ProjectKey::LastHash(project_key.clone()), &hash);
+    update_stats(env, |stats| stats.commits += 1);
+    let ledger = env.ledger().sequence();
+    update_project_stats(env, project_key, |stats| stats.last_commit_ledger = ledger);
+
+    let count_key = types::ProjectKey::CommitCount(project_key.clone());
+    let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+    env.storage().persistent().set(
+        &types::ProjectKey::CommitHistory(project_key.clone(), count % types::MAX_COMMIT_HISTORY),
+        &types::CommitRecord {
+            hash: hash.clone(),
+            timestamp: env.ledger().timestamp(),
+            ledger,
+            signing_key: signing_key.clone(),
+            reverts,
+            proposal_id,
+        },
+    );
+    let meta_key =
+        types::ProjectKey::CommitMeta(project_key.clone(), count % types::MAX_COMMIT_HISTORY);
+    match &meta {
+        Some(meta) => env.storage().persistent().set(&meta_key, meta),
+        None => env.storage().persistent().remove(&meta_key),
+    }
+    env.storage().persistent().set(&count_key, &(count + 1));
+
+    events::Commit {
+        project_key: project_key.clone(),
+        maintainer: maintainer.clone(),
+        ref_name: None,
+        hash,
+        ledger,
+        meta,
+        signing_key,
+        proposal_id,
+    }
+    .publish(env);
+}
+
+/// Record the tansu.toml file claimed by a project.
+///
+/// Only the last `MAX_METADATA_HISTORY` commitments are kept.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `ipfs` - CID of the tansu.toml file
+fn record_metadata_commitment(env: &Env, project_key: &Bytes, ipfs: &String) {
+    let key_ = types::ProjectKey::MetadataHistory(project_key.clone());
+    let mut history: Vec<types::MetadataCommitment> = env
+        .storage()
+        .persistent()
+        .get(&key_)
+        .unwrap_or_else(|| Vec::new(env));
+    if history.len() >= types::MAX_METADATA_HISTORY {
+        history.pop_front();
+    }
+    history.push_back(types::MetadataCommitment {
+        ipfs: ipfs.clone(),
+        ledger: env.ledger().sequence(),
+    });
+    env.storage().persistent().set(&key_, &history);
+}
+
+/// Number of maintainers approvals required for sensitive project operations.
+///
+/// # Arguments
+/// * `project` - The project
+///
+/// # Returns
+/// * `u32` - The number of approvals required
+fn maintainers_threshold(project: &types::Project) -> u32 {
+    project.config.maintainers_threshold
+}
+
+/// Validate the maintainers of a project against its configuration.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `maintainers` - List of maintainer addresses
+/// * `config` - The project configuration
+///
+/// # Panics
+/// * If there are more maintainers than allowed
+/// * If the threshold cannot be reached or is zero
+/// * If a maintainer is listed twice
+fn validate_maintainers(env: &Env, maintainers: &Vec<Address>, config: &types::Config) {
+    if maintainers.len() > config.max_maintainers
+        || config.max_maintainers > types::MAX_MAINTAINERS
+        || config.maintainers_threshold == 0
+        || config.maintainers_threshold > maintainers.len()
+    {
+        panic_with_error!(&env, &errors::ContractErrors::InvalidMaintainersConfig);
+    }
+    for (index, maintainer) in maintainers.iter().enumerate() {
+        if maintainers.last_index_of(&maintainer) != Some(index as u32) {
+            panic_with_error!(&env, &errors::ContractErrors::InvalidMaintainersConfig);
+        }
+    }
+}
+
+/// Record the approval of a maintainer for an action on the maintainers.
+///
+/// Approvals accumulate until the maintainers threshold of the project is
+/// reached. Only approvals from current maintainers are counted, and only
+/// from admins for actions reserved to admins, in which case the threshold
+/// is capped to the number of admins. Approvals expire
+/// `MAINTAINERS_APPROVAL_PERIOD` after the first one, so stale approvals
+/// cannot be combined with later ones.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `maintainer` - The address of the approving maintainer
+/// * `key` - The project key identifier
+/// * `project` - The project
+/// * `action` - The action to approve
+///
+/// # Returns
+/// * `bool` - True if the threshold is reached and the action must be executed
+fn approve_maintainers_action(
+    env: &Env,
+    maintainer: &Address,
+    key: &Bytes,
+    project: &types::Project,
+    action: types::MaintainersAction,
+) -> bool {
+    let approvals_key = types::ProjectKey::MaintainersApprovals(key.clone(), action.clone());
+    let pending = match env
+        .storage()
+        .persistent()
+        .get::<_, types::PendingApprovals>(&approvals_key)
+    {
+        Some(pending) if env.ledger().timestamp() < pending.expires_at => pending,
+        _ => types::PendingApprovals {
+            approvals: Vec::new(env),
+            expires_at: env.ledger().timestamp() + types::MAINTAINERS_APPROVAL_PERIOD,
+        },
+    };
+
+    let role = match action {
+        types::MaintainersAction::AddMaintainer(..)
+        | types::MaintainersAction::RemoveMaintainer(..)
+        | types::MaintainersAction::UpdateConfig(..)
+        | types::MaintainersAction::SetMaintainersConfig(..)
+        | types::MaintainersAction::SetMaintainerRole(..)
+        | types::MaintainersAction::RotateAnonymousKey(..)
+        | types::MaintainersAction::TransferProject(..)
+        | types::MaintainersAction::ArchiveProject
+        | types::MaintainersAction::UnarchiveProject
+        | types::MaintainersAction::Deregister => types::MaintainerRole::Admin,
+        _ => types::MaintainerRole::Committer,
+    };
+    let mut eligible: u32 = 0;
+    for maintainer_ in project.maintainers.iter() {
+        if maintainer_role(env, key, &maintainer_) >= role {
+            eligible += 1;
+        }
+    }
+
+    let mut approvals_: Vec<Address> = Vec::new(env);
+    for approval in pending.approvals.iter() {
+        if project.maintainers.contains(&approval) && maintainer_role(env, key, &approval) >= role {
+            approvals_.push_back(approval);
+        }
+    }
+    if !approvals_.contains(maintainer) {
+        approvals_.push_back(maintainer.clone());
+    }
+
+    let executed = approvals_.len() >= maintainers_threshold(project).min(eligible);
+    if executed {
+        env.storage().persistent().remove(&approvals_key);
+    } else {
+        env.storage().persistent().set(
+            &approvals_key,
+            &types::PendingApprovals {
+                approvals: approvals_.clone(),
+                expires_at: pending.expires_at,
+            },
+        );
+    }
+
+    events::MaintainersActionApproved {
+        project_key: key.clone(),
+        maintainer: maintainer.clone(),
+        action,
+        approvals_count: approvals_.len(),
+        executed,
+    }
+    .publish(env);
+
+    executed
+}
+
+/// Publish the change of a configuration field of a project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `maintainer` - The address which changed the field
+/// * `field` - The changed field
+fn publish_config_updated(
+    env: &Env,
+    project_key: &Bytes,
+    maintainer: &Address,
+    field: types::ConfigField,
+) {
+    events::ProjectConfigUpdated {
+        project_key: project_key.clone(),
+        maintainer: maintainer.clone(),
+        field,
+    }
+    .publish(env);
+}
+
+/// Require that the project is not archived, panic if it is.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+///
+/// # Panics
+/// * If the project is archived
+fn require_not_archived(env: &Env, project_key: &Bytes) {
+    if env
+        .storage()
+        .persistent()
+        .has(&types::ProjectKey::Archived(project_key.clone()))
+    {
+        panic_with_error!(&env, &errors::ContractErrors::ProjectArchived);
+    }
+}
+
+/// Enforce the commit cooldown of a project for a maintainer.
+///
+/// Maintainers must wait the cooldown between two commits, which limits
+/// the churn of a misconfigured CI. Admins are not rate limited.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+/// * `maintainer` - The maintainer committing
+///
+/// # Panics
+/// * If the maintainer committed less than the cooldown ago
+fn require_commit_cooldown(env: &Env, project_key: &Bytes, maintainer: &Address) {
+    let cooldown =
+        <Tansu as VersioningTrait>::get_commit_cooldown(env.clone(), project_key.clone());
+    if cooldown == 0
+        || maintainer_role(env, project_key, maintainer) == types::MaintainerRole::Admin
+    {
+        return;
+    }
+
+    let last_commit_key = types::ProjectKey::LastCommit(project_key.clone(), maintainer.clone());
+    let ledger = env.ledger().sequence();
+    if let Some(last_commit) = env.storage().persistent().get::<_, u32>(&last_commit_key)
+        && ledger < last_commit.saturating_add(cooldown)
+    {
+        panic_with_error!(&env, &errors::ExtendedErrors::RateLimited);
+    }
+    env.storage().persistent().set(&last_commit_key, &ledger);
+}
+
+/// Require that the project is not paused, panic if it is.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+///
+/// # Panics
+/// * If the project is paused
+fn require_project_not_paused(env: &Env, project_key: &Bytes) {
+    if env
+        .storage()
+        .persistent()
+        .has(&types::ProjectKey::Paused(project_key.clone()))
+    {
+        panic_with_error!(&env, &errors::ContractErrors::ProjectPaused);
+    }
+}
+
+/// Require that the project is not controlled by its DAO, panic if it is.
+///
+/// Once the maintainers renounced to the project, changes are only applied
+/// through approved proposals.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `project_key` - The project key identifier
+///
+/// # Panics
+/// * If the project is controlled by its DAO
+fn require_not_dao_controlled(env: &Env, project_key: &Bytes) {
+    if env
+        .storage()
+        .persistent()
+        .has(&types::ProjectKey::DaoControlled(project_key.clone()))
+    {
+        panic_with_error!(&env, &errors::ContractErrors::DaoControlled);
+    }
+}
+
 /// Retrieve a contract address and WASM hash.
 ///
 /// # Arguments
@@ -0,0 +1,67 @@
+//! Registry of the releases and attestations of the projects.
+//!
+//! Releases, artifacts, attestations, advisories, CI runs, reviews,
+//! credits, dependencies and file tree anchors are stored by a separate
+//! registry contract, keeping Tansu within the contract size limit. The
+//! registry calls Tansu to authenticate the maintainers, Tansu calls the
+//! registry to apply the release actions of approved proposals and to clean
+//! up deregistered projects.
+
+use soroban_sdk::{Address, Bytes, Env, contractclient};
+
+use crate::types;
+
+/// Functions of the registry contract used by Tansu.
+///
+/// Tansu only uses the generated `RegistryClient`.
+#[allow(dead_code)]
+#[contractclient(name = "RegistryClient")]
+pub trait RegistryInterface {
+    /// Code of the error applying a release action would fail with.
+    fn check_release_action(env: Env, key: Bytes, action: types::ReleaseAction) -> Option<u32>;
+
+    /// Apply a release action, authorized by Tansu.
+    fn apply_release_action(env: Env, key: Bytes, executor: Address, action: types::ReleaseAction);
+
+    /// Remove the data of a deregistered project, authorized by Tansu.
+    fn remove_project(env: Env, key: Bytes);
+}
+
+/// Get a client of the registry contract.
+///
+/// # Panics
+/// * If the registry contract is not set or does not match on-chain data
+pub fn client(env: &Env) -> RegistryClient<'_> {
+    let registry = crate::retrieve_contract(env, types::ContractKey::RegistryContract);
+    RegistryClient::new(env, &registry.address)
+}
+
+/// Release action of a DAO action.
+///
+/// # Arguments
+/// * `action` - The action of a proposal
+///
+/// # Returns
+/// * `Option<types::ReleaseAction>` - The release action, `None` if the
+///   action does not change the releases
+pub fn release_action(action: &types::DaoAction) -> Option<types::ReleaseAction> {
+    match action.clone() {
+        types::DaoAction::CreateRelease(tag, commit_hash, artifact_cid, changelog_cid, channel) => {
+            Some(types::ReleaseAction::Create(
+                tag,
+                commit_hash,
+                artifact_cid,
+                changelog_cid,
+                channel,
+            ))
+        }
+        types::DaoAction::PromoteRelease(tag, channel) => {
+            Some(types::ReleaseAction::Promote(tag, channel))
+        }
+        types::DaoAction::YankRelease(tag, reason_cid) => {
+            Some(types::ReleaseAction::Yank(tag, reason_cid))
+        }
+        types::DaoAction::ArchiveRelease(tag) => Some(types::ReleaseAction::Archive(tag)),
+        _ => None,
+    }
+}
@@ -0,0 +1,29 @@
+//! Scheduled execution of the proposals.
+//!
+//! The queue of the proposals scheduled for execution is held by a separate
+//! scheduler contract, keeping Tansu within the contract size limit. The
+//! scheduler calls Tansu to execute the proposals due, see `run_scheduled`,
+//! Tansu calls the scheduler to clean up deregistered projects.
+
+use soroban_sdk::{Bytes, Env, contractclient};
+
+use crate::types;
+
+/// Functions of the scheduler contract used by Tansu.
+///
+/// Tansu only uses the generated `SchedulerClient`.
+#[allow(dead_code)]
+#[contractclient(name = "SchedulerClient")]
+pub trait SchedulerInterface {
+    /// Remove the queue of a deregistered project, authorized by Tansu.
+    fn remove_project(env: Env, project_key: Bytes);
+}
+
+/// Get a client of the scheduler contract.
+///
+/// # Panics
+/// * If the scheduler contract is not set or does not match on-chain data
+pub fn client(env: &Env) -> SchedulerClient<'_> {
+    let scheduler = crate::retrieve_contract(env, types::ContractKey::SchedulerContract);
+    SchedulerClient::new(env, &scheduler.address)
+}
@@ -0,0 +1,83 @@
+//! Semantic versions of package releases, see https://semver.org.
+//!
+//! Versions are optionally prefixed with `v`, e.g. `v1.2.3-rc.1`. The
+//! ordering of versions is only needed by the release registry.
+
+use soroban_sdk::{Env, String, panic_with_error};
+
+use crate::{errors, types};
+
+/// Validate a version.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `tag` - The version
+///
+/// # Panics
+/// * If the version is longer than `MAX_RELEASE_TAG_LENGTH` or is not a
+///   semantic version
+pub fn validate(env: &Env, tag: &String) {
+    let len = tag.len() as usize;
+    let mut buffer = [0u8; types::MAX_RELEASE_TAG_LENGTH as usize];
+    if len > buffer.len() {
+        panic_with_error!(env, &errors::ContractErrors::InvalidRelease);
+    }
+    let bytes = &mut buffer[..len];
+    tag.copy_into_slice(bytes);
+    if !is_version(bytes) {
+        panic_with_error!(env, &errors::ContractErrors::InvalidRelease);
+    }
+}
+
+/// Whether a tag is a semantic version.
+fn is_version(tag: &[u8]) -> bool {
+    let tag = tag.strip_prefix(b"v").unwrap_or(tag);
+    let (version, build) = split_once(tag, b'+');
+    if build.is_some_and(|build| !valid_identifiers(build, false)) {
+        return false;
+    }
+    let (core, pre) = split_once(version, b'-');
+    if pre.is_some_and(|pre| !valid_identifiers(pre, true)) {
+        return false;
+    }
+
+    let mut numbers = core.split(|c| *c == b'.');
+    numbers.clone().count() == 3 && numbers.all(|number| parse_number(number).is_some())
+}
+
+/// Split a slice at the first occurrence of a separator.
+fn split_once(bytes: &[u8], separator: u8) -> (&[u8], Option<&[u8]>) {
+    match bytes.iter().position(|c| *c == separator) {
+        Some(index) => (&bytes[..index], Some(&bytes[index + 1..])),
+        None => (bytes, None),
+    }
+}
+
+/// Parse a numeric identifier, leading zeros are not allowed.
+fn parse_number(bytes: &[u8]) -> Option<u64> {
+    if bytes.is_empty() || (bytes.len() > 1 && bytes[0] == b'0') {
+        return None;
+    }
+    bytes.iter().try_fold(0u64, |number, c| {
+        if !c.is_ascii_digit() {
+            return None;
+        }
+        number.checked_mul(10)?.checked_add((c - b'0') as u64)
+    })
+}
+
+/// Validate dot separated identifiers made of ASCII alphanumerics and `-`.
+///
+/// Numeric identifiers of pre-releases cannot have leading zeros.
+fn valid_identifiers(bytes: &[u8], pre: bool) -> bool {
+    bytes.split(|c| *c == b'.').all(|identifier| {
+        !identifier.is_empty()
+            && identifier
+                .iter()
+                .all(|c| c.is_ascii_alphanumeric() || *c == b'-')
+            && !(pre
+                && identifier.iter().all(u8::is_ascii_digit)
+                && identifier.len() > 1
+                && identifier[0] == b'0')
+    })
+}
@@ -0,0 +1,43 @@
+//! Stakes of the voting tokens of the projects.
+//!
+//! Tokens staked for voting weight are held by a separate staking contract,
+//! keeping Tansu within the contract size limit. The staking contract calls
+//! Tansu to read the governance configuration of the projects, Tansu calls
+//! the staking contract to weigh the votes and to release the stakes of
+//! deregistered projects.
+
+use soroban_sdk::{Address, Bytes, Env, contractclient};
+
+use crate::types;
+
+/// Functions of the staking contract used by Tansu.
+///
+/// Tansu only uses the generated `StakingClient`.
+#[allow(dead_code)]
+#[contractclient(name = "StakingClient")]
+pub trait StakingInterface {
+    /// Tokens of a member staked in the voting token of a project at or
+    /// before a UNIX timestamp.
+    fn get_voting_stake(
+        env: Env,
+        project_key: Bytes,
+        member: Address,
+        token: Address,
+        since: u64,
+    ) -> i128;
+
+    /// Tokens staked in a project, unbonding tokens excluded.
+    fn get_total_stake(env: Env, project_key: Bytes) -> i128;
+
+    /// Release the stakes of a deregistered project, authorized by Tansu.
+    fn remove_project(env: Env, project_key: Bytes);
+}
+
+/// Get a client of the staking contract.
+///
+/// # Panics
+/// * If the staking contract is not set or does not match on-chain data
+pub fn client(env: &Env) -> StakingClient<'_> {
+    let staking = crate::retrieve_contract(env, types::ContractKey::StakingContract);
+    StakingClient::new(env, &staking.address)
+}
@@ -1,9 +1,27 @@
+pub mod test_advisories;
 pub mod test_anonym_votes;
+pub mod test_ci;
 pub mod test_commit;
+pub mod test_conviction;
 pub mod test_cost_estimates;
+pub mod test_council;
+pub mod test_credits;
 pub mod test_dao;
+pub mod test_dependencies;
 pub mod test_domain;
+pub mod test_federation;
 pub mod test_membership;
+pub mod test_organizations;
+pub mod test_packages;
 pub mod test_pause_upgrade;
+pub mod test_project;
+pub mod test_proofs;
 pub mod test_register;
+pub mod test_releases;
+pub mod test_reveal;
+pub mod test_reviews;
+pub mod test_rewards;
+pub mod test_scheduler;
+pub mod test_sponsorship;
+pub mod test_staking;
 pub mod test_utils;
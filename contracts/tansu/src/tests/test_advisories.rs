@@ -0,0 +1,277 @@
+use super::test_utils::{create_test_data, init_contract};
+use crate::errors::{ContractErrors, ExtendedErrors};
+use registry::types;
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{Address, IntoVal, Map, String, Symbol, Val, vec};
+
+#[test]
+fn advisories_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let cid = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let v1 = String::from_str(&setup.env, "v1.0.0");
+    let v1_1 = String::from_str(&setup.env, "v1.1.0");
+    for tag in [v1.clone(), v1_1.clone()] {
+        setup.registry.create_release(
+            &setup.grogu,
+            &id,
+            &tag,
+            &hash,
+            &cid,
+            &cid,
+            &types::ReleaseChannel::Stable,
+            &None,
+        );
+    }
+
+    let advisory_id = String::from_str(&setup.env, "RUSTSEC-2025-0001");
+    let affected = vec![&setup.env, v1.clone()];
+    setup.registry.publish_advisory(
+        &setup.grogu,
+        &id,
+        &advisory_id,
+        &types::Severity::High,
+        &affected,
+        &cid,
+    );
+
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.registry_id.clone(),
+                (
+                    Symbol::new(&setup.env, "advisory_published"),
+                    id.clone(),
+                    advisory_id.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "affected_releases"),
+                            affected.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "cid"),
+                            cid.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "maintainer"),
+                            setup.grogu.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "severity"),
+                            types::Severity::High.into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+
+    let mut advisory = types::Advisory {
+        id: advisory_id.clone(),
+        severity: types::Severity::High,
+        affected_releases: affected.clone(),
+        cid: cid.clone(),
+        open: true,
+        timestamp: setup.env.ledger().timestamp(),
+    };
+    assert_eq!(setup.registry.get_advisory(&id, &advisory_id), advisory);
+    assert_eq!(
+        setup.registry.get_open_advisories(&id),
+        vec![&setup.env, advisory.clone()]
+    );
+
+    // amended once the investigation shows more releases are affected
+    let affected = vec![&setup.env, v1.clone(), v1_1.clone()];
+    setup.registry.amend_advisory(
+        &setup.mando,
+        &id,
+        &advisory_id,
+        &types::Severity::Critical,
+        &affected,
+        &cid,
+    );
+    advisory.severity = types::Severity::Critical;
+    advisory.affected_releases = affected.clone();
+    assert_eq!(setup.registry.get_advisory(&id, &advisory_id), advisory);
+
+    let other_id = String::from_str(&setup.env, "GHSA-abcd-efgh-ijkl");
+    setup.registry.publish_advisory(
+        &setup.grogu,
+        &id,
+        &other_id,
+        &types::Severity::Low,
+        &vec![&setup.env, v1_1.clone()],
+        &cid,
+    );
+    assert_eq!(
+        setup.registry.get_advisories(&id),
+        vec![&setup.env, advisory_id.clone(), other_id.clone()]
+    );
+
+    setup
+        .registry
+        .resolve_advisory(&setup.grogu, &id, &advisory_id);
+    assert!(!setup.registry.get_advisory(&id, &advisory_id).open);
+    let open = setup.registry.get_open_advisories(&id);
+    assert_eq!(open.len(), 1);
+    assert_eq!(open.get(0).unwrap().id, other_id);
+
+    let error = setup
+        .registry
+        .try_resolve_advisory(&setup.grogu, &id, &advisory_id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::InvalidAdvisory.into());
+
+    let error = setup
+        .registry
+        .try_get_advisory(&id, &String::from_str(&setup.env, "CVE-2025-0001"))
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::UnknownAdvisory.into());
+}
+
+#[test]
+fn advisory_validation() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let cid = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let tag = String::from_str(&setup.env, "v1.0.0");
+    setup.registry.create_release(
+        &setup.grogu,
+        &id,
+        &tag,
+        &hash,
+        &cid,
+        &cid,
+        &types::ReleaseChannel::Stable,
+        &None,
+    );
+    let advisory_id = String::from_str(&setup.env, "RUSTSEC-2025-0001");
+    let affected = vec![&setup.env, tag.clone()];
+
+    let error = setup
+        .registry
+        .try_publish_advisory(
+            &Address::generate(&setup.env),
+            &id,
+            &advisory_id,
+            &types::Severity::Low,
+            &affected,
+            &cid,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::UnauthorizedSigner.into());
+
+    for bad_id in ["", "RUSTSEC 2025", "RUSTSEC/2025"] {
+        let error = setup
+            .registry
+            .try_publish_advisory(
+                &setup.grogu,
+                &id,
+                &String::from_str(&setup.env, bad_id),
+                &types::Severity::Low,
+                &affected,
+                &cid,
+            )
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(error, ExtendedErrors::InvalidAdvisory.into());
+    }
+
+    for (releases, details, expected) in [
+        (
+            affected.clone(),
+            String::from_str(&setup.env, ""),
+            ExtendedErrors::InvalidCid,
+        ),
+        (
+            vec![&setup.env, tag.clone(), tag.clone()],
+            cid.clone(),
+            ExtendedErrors::InvalidAdvisory,
+        ),
+    ] {
+        let error = setup
+            .registry
+            .try_publish_advisory(
+                &setup.grogu,
+                &id,
+                &advisory_id,
+                &types::Severity::Low,
+                &releases,
+                &details,
+            )
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(error, expected.into());
+    }
+
+    let error = setup
+        .registry
+        .try_publish_advisory(
+            &setup.grogu,
+            &id,
+            &advisory_id,
+            &types::Severity::Low,
+            &vec![&setup.env, String::from_str(&setup.env, "v2.0.0")],
+            &cid,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::UnknownRelease.into());
+
+    setup.registry.publish_advisory(
+        &setup.grogu,
+        &id,
+        &advisory_id,
+        &types::Severity::Low,
+        &affected,
+        &cid,
+    );
+    let error = setup
+        .registry
+        .try_publish_advisory(
+            &setup.grogu,
+            &id,
+            &advisory_id,
+            &types::Severity::Low,
+            &affected,
+            &cid,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::InvalidAdvisory.into());
+
+    let error = setup
+        .registry
+        .try_amend_advisory(
+            &setup.grogu,
+            &id,
+            &String::from_str(&setup.env, "CVE-2025-0001"),
+            &types::Severity::Low,
+            &affected,
+            &cid,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::UnknownAdvisory.into());
+}
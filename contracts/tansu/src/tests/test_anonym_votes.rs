@@ -1,8 +1,13 @@
 use core::ops::Add;
 use soroban_sdk::crypto::bls12_381::{Fr, G1Affine};
-use soroban_sdk::{Bytes, Env, U256, bytesn, vec};
+use soroban_sdk::testutils::{Events, Ledger};
+use soroban_sdk::{Bytes, Env, IntoVal, Map, String, Symbol, U256, Val, bytesn, vec};
 
 use super::test_utils::{create_test_data, init_contract};
+use crate::{
+    errors::{AdditionalErrors, ContractErrors},
+    types,
+};
 
 fn commitment(
     env: &Env,
@@ -187,7 +192,13 @@ fn anonymous_vote_commitments_roundtrip() {
 
     // test data
     let votes = vec![&setup.env, 0u128, 1u128, 2u128];
-    let seeds = vec![&setup.env, 42u128, 43u128, 44u128];
+    // seeds are full scalars of the field
+    let seeds = vec![
+        &setup.env,
+        U256::from_u32(&setup.env, 42),
+        U256::from_parts(&setup.env, u64::MAX, u64::MAX, u64::MAX, u64::MAX),
+        U256::from_u128(&setup.env, u128::MAX).shl(100),
+    ];
 
     // expected commitments (re-using same math off-chain)
     let bls12_381 = setup.env.crypto().bls12_381();
@@ -202,7 +213,10 @@ fn anonymous_vote_commitments_roundtrip() {
 
     let mut expected = vec![&setup.env];
     for (v, s) in votes.iter().zip(seeds.iter()) {
-        expected.push_back(commitment(&setup.env, &v, &s, &vote_gen, &seed_gen).to_bytes());
+        let vote_point =
+            bls12_381.g1_mul(&vote_gen, &Fr::from_u256(U256::from_u128(&setup.env, v)));
+        let seed_point = bls12_381.g1_mul(&seed_gen, &Fr::from_u256(s));
+        expected.push_back(bls12_381.g1_add(&vote_point, &seed_point).to_bytes());
     }
 
     // on-chain calculation
@@ -325,3 +339,185 @@ fn weighted_commitments_roundtrip_bounds_check() {
     // clarifies that the per-voter bound enforcement must happen at submission
     // via a proof (not implemented here).
 }
+
+#[test]
+fn rotate_anonymous_key_flow() {
+    let setup = create_test_data();
+    let project_key = init_contract(&setup);
+    let first_key = String::from_str(&setup.env, "pk_first");
+    let compromised_key = String::from_str(&setup.env, "pk_compromised");
+    let second_key = String::from_str(&setup.env, "pk_second");
+
+    let err = setup
+        .contract
+        .try_rotate_anonymous_key(&setup.mando, &project_key, &second_key)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::NoAnonymousVotingConfig.into());
+
+    let set_up_at = setup.env.ledger().timestamp();
+    setup
+        .contract
+        .anonymous_voting_setup(&setup.mando, &project_key, &first_key);
+    let config = setup.contract.get_anonymous_voting_config(&project_key);
+
+    // rotations are approved by the maintainers threshold
+    assert_eq!(
+        setup
+            .contract
+            .rotate_anonymous_key(&setup.grogu, &project_key, &compromised_key),
+        None
+    );
+    let active_from = setup
+        .contract
+        .rotate_anonymous_key(&setup.mando, &project_key, &compromised_key)
+        .unwrap();
+    assert_eq!(active_from, set_up_at + 24 * 3600);
+
+    // a pending key can be replaced before its activation
+    setup
+        .contract
+        .rotate_anonymous_key(&setup.grogu, &project_key, &second_key);
+    setup
+        .contract
+        .rotate_anonymous_key(&setup.mando, &project_key, &second_key);
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        vec![&setup.env, all_events.last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (
+                    Symbol::new(&setup.env, "anonymous_key_rotated"),
+                    project_key.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "active_from"),
+                            active_from.into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "maintainer"),
+                            setup.mando.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "public_key"),
+                            second_key.clone().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    assert_eq!(
+        setup.contract.get_anonymous_voting_config(&project_key),
+        config
+    );
+
+    setup.env.ledger().set_timestamp(active_from);
+    assert_eq!(
+        setup.contract.get_anonymous_voting_config(&project_key),
+        types::AnonymousVoteConfig {
+            public_key: second_key.clone(),
+            ..config
+        }
+    );
+    assert_eq!(
+        setup.contract.get_anonymous_key_history(&project_key),
+        vec![
+            &setup.env,
+            types::AnonymousKey {
+                public_key: first_key,
+                active_from: set_up_at,
+            },
+            types::AnonymousKey {
+                public_key: second_key,
+                active_from,
+            },
+        ]
+    );
+}
+
+#[test]
+fn anonymous_key_references_flow() {
+    let setup = create_test_data();
+    let project_key = init_contract(&setup);
+    let first_key = String::from_str(&setup.env, "pk_first");
+    setup
+        .contract
+        .anonymous_voting_setup(&setup.mando, &project_key, &first_key);
+
+    let proposal_id = setup.contract.create_proposal(
+        &setup.grogu,
+        &project_key,
+        &String::from_str(&setup.env, "Anonymous proposal"),
+        &String::from_str(
+            &setup.env,
+            "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        ),
+        &(setup.env.ledger().timestamp() + 3600 * 24 * 30),
+        &false,
+        &None,
+    );
+    assert_eq!(
+        setup
+            .contract
+            .get_proposal_anonymous_key(&project_key, &proposal_id),
+        Some(first_key.clone())
+    );
+
+    // the key of an active proposal is never evicted from the history
+    for index in 0..20u8 {
+        let public_key = String::from_bytes(&setup.env, &[b'k', b'a' + index]);
+        for maintainer in [&setup.grogu, &setup.mando] {
+            setup
+                .contract
+                .rotate_anonymous_key(maintainer, &project_key, &public_key);
+        }
+        setup
+            .env
+            .ledger()
+            .set_timestamp(setup.env.ledger().timestamp() + 24 * 3600);
+    }
+    let history = setup.contract.get_anonymous_key_history(&project_key);
+    assert_eq!(history.len(), 20);
+    assert_eq!(history.get_unchecked(0).public_key, first_key);
+    assert_eq!(
+        history.get_unchecked(1).public_key,
+        String::from_bytes(&setup.env, b"kb")
+    );
+
+    // nor replaced by setting up anonymous voting again
+    let second_key = String::from_str(&setup.env, "pk_second");
+    let err = setup
+        .contract
+        .try_anonymous_voting_setup(&setup.mando, &project_key, &second_key)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, AdditionalErrors::AnonymousVotingAlreadySetup.into());
+
+    // closed proposals release their key
+    setup
+        .contract
+        .cancel_proposal(&setup.grogu, &project_key, &proposal_id);
+    assert_eq!(
+        setup
+            .contract
+            .get_proposal_anonymous_key(&project_key, &proposal_id),
+        Some(first_key.clone())
+    );
+    for maintainer in [&setup.grogu, &setup.mando] {
+        setup
+            .contract
+            .rotate_anonymous_key(maintainer, &project_key, &second_key);
+    }
+    let history = setup.contract.get_anonymous_key_history(&project_key);
+    assert_eq!(history.len(), 20);
+    assert!(history.iter().all(|key| key.public_key != first_key));
+    assert_eq!(history.last_unchecked().public_key, second_key);
+}
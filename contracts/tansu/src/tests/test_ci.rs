@@ -0,0 +1,188 @@
+use super::test_utils::{create_test_data, init_contract};
+use crate::errors::{ContractErrors, ExtendedErrors};
+use registry::types;
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{Address, Bytes, IntoVal, Map, String, Symbol, Val, vec};
+
+#[test]
+fn ci_status_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let run_cid = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let oracle = Address::generate(&setup.env);
+    let other_oracle = Address::generate(&setup.env);
+
+    // maintainers alone cannot vouch for their CI
+    let error = setup
+        .registry
+        .try_ci_status(&setup.grogu, &id, &hash, &types::CiStatus::Passed, &run_cid)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::NotAttestor.into());
+
+    setup
+        .contract
+        .set_attestor(&setup.contract_admin, &oracle, &true);
+    setup
+        .contract
+        .set_attestor(&setup.contract_admin, &other_oracle, &true);
+
+    setup
+        .registry
+        .ci_status(&oracle, &id, &hash, &types::CiStatus::Pending, &run_cid);
+    assert_eq!(
+        setup
+            .registry
+            .get_ci_status(&id, &hash, &oracle)
+            .unwrap()
+            .status,
+        types::CiStatus::Pending
+    );
+
+    // the run is updated once it completes
+    setup
+        .registry
+        .ci_status(&oracle, &id, &hash, &types::CiStatus::Passed, &run_cid);
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.registry_id.clone(),
+                (
+                    Symbol::new(&setup.env, "ci_status_posted"),
+                    id.clone(),
+                    hash.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "attestor"),
+                            oracle.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "run_cid"),
+                            run_cid.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "status"),
+                            types::CiStatus::Passed.into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+
+    setup.registry.ci_status(
+        &other_oracle,
+        &id,
+        &hash,
+        &types::CiStatus::Failed,
+        &run_cid,
+    );
+    assert_eq!(
+        setup.registry.get_ci_runs(&id, &hash),
+        vec![
+            &setup.env,
+            types::CiRun {
+                attestor: oracle.clone(),
+                status: types::CiStatus::Passed,
+                run_cid: run_cid.clone(),
+                timestamp: 0,
+            },
+            types::CiRun {
+                attestor: other_oracle.clone(),
+                status: types::CiStatus::Failed,
+                run_cid: run_cid.clone(),
+                timestamp: 0,
+            },
+        ]
+    );
+    assert_eq!(setup.registry.get_ci_status(&id, &hash, &setup.grogu), None);
+
+    let error = setup
+        .registry
+        .try_ci_status(
+            &oracle,
+            &id,
+            &String::from_str(&setup.env, "deadbeef"),
+            &types::CiStatus::Passed,
+            &run_cid,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::InvalidCommitHash.into());
+
+    let error = setup
+        .registry
+        .try_ci_status(
+            &oracle,
+            &id,
+            &hash,
+            &types::CiStatus::Passed,
+            &String::from_str(&setup.env, ""),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::InvalidCid.into());
+
+    let error = setup
+        .registry
+        .try_ci_status(
+            &oracle,
+            &Bytes::from_array(&setup.env, &[0; 32]),
+            &hash,
+            &types::CiStatus::Passed,
+            &run_cid,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::InvalidKey.into());
+}
+
+#[test]
+fn ci_runs_are_bounded() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let oracle = Address::generate(&setup.env);
+    setup
+        .contract
+        .set_attestor(&setup.contract_admin, &oracle, &true);
+    let run_cid = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let hash_of = |i: u32| {
+        let mut hash = [b'0'; 40];
+        hash[38] = b'0' + (i / 10) as u8;
+        hash[39] = b'0' + (i % 10) as u8;
+        String::from_bytes(&setup.env, &hash)
+    };
+
+    for i in 0..types::MAX_CI_COMMITS + 1 {
+        setup.registry.ci_status(
+            &oracle,
+            &id,
+            &hash_of(i),
+            &types::CiStatus::Passed,
+            &run_cid,
+        );
+    }
+    // the runs of the oldest commit are dropped
+    assert_eq!(
+        setup.registry.get_ci_runs(&id, &hash_of(0)),
+        vec![&setup.env]
+    );
+    assert_eq!(setup.registry.get_ci_runs(&id, &hash_of(1)).len(), 1);
+}
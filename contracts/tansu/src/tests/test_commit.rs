@@ -1,7 +1,13 @@
-use super::test_utils::{create_test_data, init_contract};
-use crate::errors::ContractErrors;
-use soroban_sdk::testutils::{Address as _, Events};
-use soroban_sdk::{Address, IntoVal, Map, String, Symbol, Val, symbol_short, vec};
+use super::test_utils::{
+    approved_action, approved_proposal, community_member, create_test_data, init_contract, seeds,
+};
+use crate::{
+    errors::{AdditionalErrors, ContractErrors, ExtendedErrors},
+    types,
+};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{Address, Bytes, BytesN, IntoVal, Map, String, Symbol, Val, symbol_short, vec};
 
 #[test]
 fn commit_flow() {
@@ -9,7 +15,9 @@ fn commit_flow() {
     let id = init_contract(&setup);
 
     let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
-    setup.contract.commit(&setup.mando, &id, &hash);
+    setup
+        .contract
+        .commit(&setup.mando, &id, &hash, &None, &None, &None);
 
     let stored = setup.contract.get_commit(&id);
     assert_eq!(stored, hash);
@@ -21,7 +29,9 @@ fn commit_events() {
     let id = init_contract(&setup);
 
     let hash_commit = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
-    setup.contract.commit(&setup.mando, &id, &hash_commit);
+    setup
+        .contract
+        .commit(&setup.mando, &id, &hash_commit, &None, &None, &None);
 
     let all_events = setup.env.events().all();
     assert_eq!(
@@ -30,10 +40,25 @@ fn commit_events() {
             &setup.env,
             (
                 setup.contract_id.clone(),
-                (symbol_short!("commit"), id.clone()).into_val(&setup.env),
+                (symbol_short!("commit"), id.clone(), setup.mando.clone(), ()).into_val(&setup.env),
                 Map::<Symbol, Val>::from_array(
                     &setup.env,
-                    [(symbol_short!("hash"), hash_commit.into_val(&setup.env)),],
+                    [
+                        (symbol_short!("hash"), hash_commit.into_val(&setup.env)),
+                        (
+                            symbol_short!("ledger"),
+                            setup.env.ledger().sequence().into_val(&setup.env)
+                        ),
+                        (symbol_short!("meta"), ().into_val(&setup.env)),
+                        (
+                            Symbol::new(&setup.env, "proposal_id"),
+                            ().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "signing_key"),
+                            ().into_val(&setup.env)
+                        ),
+                    ],
                 )
                 .into_val(&setup.env),
             ),
@@ -41,6 +66,145 @@ fn commit_events() {
     );
 }
 
+#[test]
+fn commit_history_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    assert_eq!(setup.contract.get_commit_history(&id, &0), vec![&setup.env]);
+
+    let hash_of = |i: u32| {
+        let mut hash = [b'0'; 40];
+        hash[38] = b'0' + (i / 10) as u8;
+        hash[39] = b'0' + (i % 10) as u8;
+        String::from_bytes(&setup.env, &hash)
+    };
+
+    // overflow the ring buffer so the oldest commits are dropped
+    let total = types::MAX_COMMIT_HISTORY + 5;
+    for i in 0..total {
+        setup.env.ledger().set_sequence_number(1000 + i);
+        setup.env.ledger().set_timestamp(5000 + i as u64);
+        setup
+            .contract
+            .commit(&setup.mando, &id, &hash_of(i), &None, &None, &None);
+    }
+
+    let first_page = setup.contract.get_commit_history(&id, &0);
+    assert_eq!(first_page.len(), types::MAX_COMMITS_PER_PAGE);
+    assert_eq!(
+        first_page.first().unwrap(),
+        types::CommitRecord {
+            hash: hash_of(total - 1),
+            timestamp: 5000 + total as u64 - 1,
+            ledger: 1000 + total - 1,
+            signing_key: None,
+            reverts: None,
+            proposal_id: None,
+        }
+    );
+
+    let last_page = types::MAX_COMMIT_HISTORY / types::MAX_COMMITS_PER_PAGE - 1;
+    let oldest = setup
+        .contract
+        .get_commit_history(&id, &last_page)
+        .last()
+        .unwrap();
+    assert_eq!(oldest.hash, hash_of(5));
+    assert_eq!(
+        setup.contract.get_commit_history(&id, &(last_page + 1)),
+        vec![&setup.env]
+    );
+
+    let error = setup
+        .contract
+        .try_get_commit_history(&Bytes::from_array(&setup.env, &[0; 32]), &0)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::InvalidKey.into());
+}
+
+#[test]
+fn commit_meta_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let meta = types::CommitMeta {
+        author: String::from_str(&setup.env, "grogu"),
+        message_hash: BytesN::from_array(&setup.env, &[1; 32]),
+        tree_hash: String::from_str(&setup.env, "4b825dc642cb6eb9a060e54bf8d69288fbee4904"),
+        timestamp: 1_700_000_000,
+    };
+    setup
+        .contract
+        .commit(&setup.mando, &id, &hash, &Some(meta.clone()), &None, &None);
+
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (symbol_short!("commit"), id.clone(), setup.mando.clone(), ()).into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (symbol_short!("hash"), hash.into_val(&setup.env)),
+                        (
+                            symbol_short!("ledger"),
+                            setup.env.ledger().sequence().into_val(&setup.env)
+                        ),
+                        (symbol_short!("meta"), meta.clone().into_val(&setup.env)),
+                        (
+                            Symbol::new(&setup.env, "proposal_id"),
+                            ().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "signing_key"),
+                            ().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+
+    assert_eq!(setup.contract.get_commit_meta(&id, &0), Some(meta.clone()));
+
+    // a commit without details does not inherit the previous ones
+    setup
+        .contract
+        .commit(&setup.mando, &id, &hash, &None, &None, &None);
+    assert_eq!(setup.contract.get_commit_meta(&id, &0), None);
+    assert_eq!(setup.contract.get_commit_meta(&id, &1), Some(meta.clone()));
+    assert_eq!(setup.contract.get_commit_meta(&id, &2), None);
+
+    for invalid in [
+        types::CommitMeta {
+            author: String::from_str(&setup.env, ""),
+            ..meta.clone()
+        },
+        types::CommitMeta {
+            tree_hash: String::from_str(&setup.env, "4b825dc6"),
+            ..meta.clone()
+        },
+        types::CommitMeta {
+            tree_hash: String::from_str(&setup.env, "zb825dc642cb6eb9a060e54bf8d69288fbee4904"),
+            ..meta.clone()
+        },
+    ] {
+        let error = setup
+            .contract
+            .try_commit(&setup.mando, &id, &hash, &Some(invalid), &None, &None)
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(error, ContractErrors::InvalidCommitMeta.into());
+    }
+}
+
 #[test]
 fn commit_unregistered_maintainer_error() {
     let setup = create_test_data();
@@ -51,7 +215,7 @@ fn commit_unregistered_maintainer_error() {
     let hash_commit = String::from_str(&setup.env, "deadbeef");
     let err = setup
         .contract
-        .try_commit(&bob, &id, &hash_commit)
+        .try_commit(&bob, &id, &hash_commit, &None, &None, &None)
         .unwrap_err()
         .unwrap();
     assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
@@ -73,7 +237,7 @@ fn test_anonymous_vote_commitment_validation() {
     let result = setup.contract.try_build_commitments_from_votes(
         &id,
         &vec![&setup.env, 1u128, 2u128], // 2 votes
-        &vec![&setup.env, 1u128],        // 1 seed - mismatch!
+        &seeds(&setup.env, &[1]),        // 1 seed - mismatch!
     );
 
     assert_eq!(
@@ -101,7 +265,7 @@ fn test_malformed_commitments_mismatched_lengths() {
     let result = setup.contract.try_build_commitments_from_votes(
         &id,
         &vec![&setup.env, 1u128, 2u128, 3u128, 4u128], // 4 votes
-        &vec![&setup.env, 1u128, 2u128, 3u128],        // 3 seeds - mismatch!
+        &seeds(&setup.env, &[1, 2, 3]),                // 3 seeds - mismatch!
     );
 
     assert_eq!(
@@ -114,8 +278,8 @@ fn test_malformed_commitments_mismatched_lengths() {
     // Test mismatched votes and seeds length - seeds longer
     let result2 = setup.contract.try_build_commitments_from_votes(
         &id,
-        &vec![&setup.env, 1u128, 2u128],        // 2 votes
-        &vec![&setup.env, 1u128, 2u128, 3u128], // 3 seeds - mismatch!
+        &vec![&setup.env, 1u128, 2u128], // 2 votes
+        &seeds(&setup.env, &[1, 2, 3]),  // 3 seeds - mismatch!
     );
 
     assert_eq!(
@@ -125,3 +289,694 @@ fn test_malformed_commitments_mismatched_lengths() {
         )))
     );
 }
+
+#[test]
+fn refs_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let main = String::from_str(&setup.env, "main");
+    let release = String::from_str(&setup.env, "release/1.x");
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let next = String::from_str(&setup.env, "26d5f4c2e9b2a4d7c1f0a8b3e6d9c2f5a8b1e4d7");
+
+    setup
+        .contract
+        .commit_to_ref(&setup.mando, &id, &main, &hash);
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (
+                    symbol_short!("commit"),
+                    id.clone(),
+                    setup.mando.clone(),
+                    main.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (symbol_short!("hash"), hash.clone().into_val(&setup.env)),
+                        (
+                            symbol_short!("ledger"),
+                            setup.env.ledger().sequence().into_val(&setup.env)
+                        ),
+                        (symbol_short!("meta"), ().into_val(&setup.env)),
+                        (
+                            Symbol::new(&setup.env, "proposal_id"),
+                            ().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "signing_key"),
+                            ().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+
+    setup
+        .contract
+        .commit_to_ref(&setup.mando, &id, &release, &hash);
+    setup
+        .contract
+        .commit_to_ref(&setup.mando, &id, &main, &next);
+
+    // each ref moves independently of the others and of the latest commit
+    assert_eq!(setup.contract.get_ref(&id, &main), next);
+    assert_eq!(setup.contract.get_ref(&id, &release), hash);
+    assert_eq!(
+        setup.contract.get_refs(&id),
+        vec![&setup.env, main.clone(), release.clone()]
+    );
+    let error = setup.contract.try_get_commit(&id).unwrap_err().unwrap();
+    assert_eq!(error, ContractErrors::NoHashFound.into());
+
+    let error = setup
+        .contract
+        .try_get_ref(&id, &String::from_str(&setup.env, "develop"))
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::UnknownRef.into());
+
+    for name in [
+        "",
+        "/main",
+        "main/",
+        "release//1.x",
+        "feature/../main",
+        ".hidden",
+        "main.lock",
+        "feature branch",
+    ] {
+        let error = setup
+            .contract
+            .try_commit_to_ref(
+                &setup.mando,
+                &id,
+                &String::from_str(&setup.env, name),
+                &hash,
+            )
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(error, ExtendedErrors::InvalidRef.into(), "{name}");
+    }
+
+    let bob = Address::generate(&setup.env);
+    let error = setup
+        .contract
+        .try_commit_to_ref(&bob, &id, &main, &hash)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::UnauthorizedSigner.into());
+}
+
+#[test]
+fn commit_hash_validation() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    for hash in [
+        "6663520bd9e6ede248fef8157b2af0b6b6b41046",
+        "6663520BD9E6EDE248FEF8157B2AF0B6B6B41046",
+    ] {
+        let hash = String::from_str(&setup.env, hash);
+        setup
+            .contract
+            .commit(&setup.mando, &id, &hash, &None, &None, &None);
+        assert_eq!(setup.contract.get_commit(&id), hash);
+    }
+
+    for hash in [
+        "",
+        "deadbeef",
+        "6663520bd9e6ede248fef8157b2af0b6b6b4104",
+        "6663520bd9e6ede248fef8157b2af0b6b6b410466",
+        "g663520bd9e6ede248fef8157b2af0b6b6b41046",
+        "not a commit hash at all, but forty long",
+        "4a3b2c1d4a3b2c1d4a3b2c1d4a3b2c1d4a3b2c1d4a3b2c1d4a3b2c1d4a3b2c1d",
+    ] {
+        let error = setup
+            .contract
+            .try_commit(
+                &setup.mando,
+                &id,
+                &String::from_str(&setup.env, hash),
+                &None,
+                &None,
+                &None,
+            )
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(error, ExtendedErrors::InvalidCommitHash.into(), "{hash}");
+    }
+}
+
+#[test]
+fn sha256_hash_algorithm() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let sha1 = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let sha256 = String::from_str(
+        &setup.env,
+        "4a3b2c1d4a3b2c1d4a3b2c1d4a3b2c1d4a3b2c1d4a3b2c1d4a3b2c1d4a3b2c1d",
+    );
+    assert_eq!(
+        setup.contract.get_hash_algorithm(&id),
+        types::HashAlgorithm::Sha1
+    );
+
+    setup
+        .contract
+        .set_hash_algorithm(&setup.mando, &id, &types::HashAlgorithm::Sha256);
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (
+                    Symbol::new(&setup.env, "project_config_updated"),
+                    id.clone(),
+                    setup.mando.clone(),
+                    types::ConfigField::HashAlgorithm,
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::new(&setup.env).into_val(&setup.env),
+            ),
+        ]
+    );
+    assert_eq!(
+        setup.contract.get_hash_algorithm(&id),
+        types::HashAlgorithm::Sha256
+    );
+
+    // commits, refs and releases follow the object format of the repository
+    setup
+        .contract
+        .commit(&setup.mando, &id, &sha256, &None, &None, &None);
+    setup.contract.commit_to_ref(
+        &setup.mando,
+        &id,
+        &String::from_str(&setup.env, "main"),
+        &sha256,
+    );
+    setup.registry.create_release(
+        &setup.mando,
+        &id,
+        &String::from_str(&setup.env, "v1.0.0"),
+        &sha256,
+        &String::from_str(
+            &setup.env,
+            "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        ),
+        &String::from_str(
+            &setup.env,
+            "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        ),
+        &registry::types::ReleaseChannel::Stable,
+        &None,
+    );
+
+    let error = setup
+        .contract
+        .try_commit(&setup.mando, &id, &sha1, &None, &None, &None)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::InvalidCommitHash.into());
+
+    let meta = types::CommitMeta {
+        author: String::from_str(&setup.env, "grogu"),
+        message_hash: BytesN::from_array(&setup.env, &[1; 32]),
+        tree_hash: sha1.clone(),
+        timestamp: 1_700_000_000,
+    };
+    let error = setup
+        .contract
+        .try_commit(&setup.mando, &id, &sha256, &Some(meta), &None, &None)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::InvalidCommitMeta.into());
+
+    let bob = Address::generate(&setup.env);
+    let error = setup
+        .contract
+        .try_set_hash_algorithm(&bob, &id, &types::HashAlgorithm::Sha1)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::UnauthorizedSigner.into());
+}
+
+#[test]
+fn signed_commit_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let signer = SigningKey::from_bytes(&[7; 32]);
+    let public_key = BytesN::from_array(&setup.env, &signer.verifying_key().to_bytes());
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let sign = |project_key: &Bytes, hash: &String| {
+        let mut message = project_key.clone();
+        message.append(&hash.to_bytes());
+        let mut buffer = [0u8; 96];
+        message.copy_into_slice(&mut buffer[..message.len() as usize]);
+        let signature = signer.sign(&buffer[..message.len() as usize]);
+        BytesN::from_array(&setup.env, &signature.to_bytes())
+    };
+
+    // a signature requires a registered signing key
+    let error = setup
+        .contract
+        .try_commit(
+            &setup.mando,
+            &id,
+            &hash,
+            &None,
+            &Some(sign(&id, &hash)),
+            &None,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::NoSigningKey.into());
+
+    setup
+        .contract
+        .set_signing_key(&setup.mando, &Some(public_key.clone()));
+    assert_eq!(
+        setup.contract.get_signing_key(&setup.mando),
+        Some(public_key.clone())
+    );
+
+    setup.contract.commit(
+        &setup.mando,
+        &id,
+        &hash,
+        &None,
+        &Some(sign(&id, &hash)),
+        &None,
+    );
+    let record = setup.contract.get_commit_history(&id, &0).get_unchecked(0);
+    assert_eq!(record.signing_key, Some(public_key.clone()));
+
+    // signatures are bound to the hash and to the project
+    let other_hash = String::from_str(&setup.env, "26d5f4c2e9b2a4d7c1f0a8b3e6d9c2f5a8b1e4d7");
+    assert!(
+        setup
+            .contract
+            .try_commit(
+                &setup.mando,
+                &id,
+                &other_hash,
+                &None,
+                &Some(sign(&id, &hash)),
+                &None
+            )
+            .is_err()
+    );
+    let other_project = Bytes::from_array(&setup.env, &[0; 32]);
+    assert!(
+        setup
+            .contract
+            .try_commit(
+                &setup.mando,
+                &id,
+                &hash,
+                &None,
+                &Some(sign(&other_project, &hash)),
+                &None
+            )
+            .is_err()
+    );
+    assert_eq!(setup.contract.get_commit_history(&id, &0).len(), 1);
+
+    // unsigned commits stay possible
+    setup
+        .contract
+        .commit(&setup.mando, &id, &other_hash, &None, &None, &None);
+    let record = setup.contract.get_commit_history(&id, &0).get_unchecked(0);
+    assert_eq!(record.signing_key, None);
+
+    setup.contract.set_signing_key(&setup.mando, &None);
+    assert_eq!(setup.contract.get_signing_key(&setup.mando), None);
+}
+
+#[test]
+fn commit_cooldown_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let other_hash = String::from_str(&setup.env, "7773520bd9e6ede248fef8157b2af0b6b6b41046");
+    let main = String::from_str(&setup.env, "main");
+
+    // mando is the CI, with commit rights only
+    setup.contract.set_maintainer_role(
+        &setup.grogu,
+        &id,
+        &setup.mando,
+        &types::MaintainerRole::Committer,
+    );
+    setup.contract.set_maintainer_role(
+        &setup.mando,
+        &id,
+        &setup.mando,
+        &types::MaintainerRole::Committer,
+    );
+
+    assert_eq!(setup.contract.get_commit_cooldown(&id), 0);
+    let error = setup
+        .contract
+        .try_set_commit_cooldown(&setup.mando, &id, &10)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::InsufficientRole.into());
+    setup.contract.set_commit_cooldown(&setup.grogu, &id, &10);
+    assert_eq!(setup.contract.get_commit_cooldown(&id), 10);
+
+    setup.env.ledger().set_sequence_number(100);
+    setup
+        .contract
+        .commit(&setup.mando, &id, &hash, &None, &None, &None);
+    for ledger in [100, 109] {
+        setup.env.ledger().set_sequence_number(ledger);
+        let error = setup
+            .contract
+            .try_commit(&setup.mando, &id, &other_hash, &None, &None, &None)
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(error, ExtendedErrors::RateLimited.into());
+        let error = setup
+            .contract
+            .try_commit_to_ref(&setup.mando, &id, &main, &other_hash)
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(error, ExtendedErrors::RateLimited.into());
+    }
+
+    // admins can always push a fix
+    setup
+        .contract
+        .commit(&setup.grogu, &id, &other_hash, &None, &None, &None);
+    assert_eq!(setup.contract.get_commit(&id), other_hash);
+
+    setup.env.ledger().set_sequence_number(110);
+    setup
+        .contract
+        .commit_to_ref(&setup.mando, &id, &main, &hash);
+    let error = setup
+        .contract
+        .try_commit(&setup.mando, &id, &hash, &None, &None, &None)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::RateLimited.into());
+
+    // reverting is a commit too
+    let error = setup
+        .contract
+        .try_revert_commit(&setup.mando, &id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::RateLimited.into());
+    setup.env.ledger().set_sequence_number(120);
+    assert_eq!(setup.contract.revert_commit(&setup.mando, &id), hash);
+    let error = setup
+        .contract
+        .try_commit(&setup.mando, &id, &hash, &None, &None, &None)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::RateLimited.into());
+
+    setup.contract.set_commit_cooldown(&setup.grogu, &id, &0);
+    setup
+        .contract
+        .commit(&setup.mando, &id, &hash, &None, &None, &None);
+    assert_eq!(setup.contract.get_commit(&id), hash);
+}
+
+#[test]
+fn revert_commit_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let good = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let bad = String::from_str(&setup.env, "7773520bd9e6ede248fef8157b2af0b6b6b41046");
+
+    let error = setup
+        .contract
+        .try_revert_commit(&setup.mando, &id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::NothingToRevert.into());
+
+    setup
+        .contract
+        .commit(&setup.mando, &id, &good, &None, &None, &None);
+    let error = setup
+        .contract
+        .try_revert_commit(&setup.mando, &id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::NothingToRevert.into());
+
+    setup
+        .contract
+        .commit(&setup.mando, &id, &bad, &None, &None, &None);
+    let error = setup
+        .contract
+        .try_revert_commit(&Address::generate(&setup.env), &id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::UnauthorizedSigner.into());
+
+    assert_eq!(setup.contract.revert_commit(&setup.grogu, &id), good);
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        vec![&setup.env, all_events.last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (
+                    Symbol::new(&setup.env, "commit_reverted"),
+                    id.clone(),
+                    setup.grogu.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "hash"),
+                            good.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "reverted_hash"),
+                            bad.clone().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    assert_eq!(setup.contract.get_commit(&id), good);
+
+    // the bad commit stays in the history
+    let history = setup.contract.get_commit_history(&id, &0);
+    assert_eq!(history.len(), 3);
+    assert_eq!(history.get(0).unwrap().hash, good);
+    assert_eq!(history.get(0).unwrap().reverts, Some(bad.clone()));
+    assert_eq!(history.get(1).unwrap().hash, bad);
+    assert_eq!(history.get(1).unwrap().reverts, None);
+
+    // reverting the revert restores the commit
+    assert_eq!(setup.contract.revert_commit(&setup.grogu, &id), bad);
+    assert_eq!(setup.contract.get_commit(&id), bad);
+}
+
+#[test]
+fn commits_cursor_pagination() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let empty = setup.contract.get_commits(&id, &None, &5);
+    assert_eq!(empty.commits, vec![&setup.env]);
+    assert_eq!(empty.next_cursor, None);
+
+    let hash_of = |i: u32| {
+        let mut hash = [b'0'; 40];
+        hash[38] = b'0' + (i / 10) as u8;
+        hash[39] = b'0' + (i % 10) as u8;
+        String::from_bytes(&setup.env, &hash)
+    };
+    let total = types::MAX_COMMIT_HISTORY + 5;
+    for i in 0..total {
+        setup
+            .contract
+            .commit(&setup.mando, &id, &hash_of(i), &None, &None, &None);
+    }
+
+    // walk the kept history by pages of 7 commits
+    let mut cursor = None;
+    let mut expected = total;
+    let mut pages = 0;
+    loop {
+        let page = setup.contract.get_commits(&id, &cursor, &7);
+        for commit in page.commits.iter() {
+            expected -= 1;
+            assert_eq!(commit.hash, hash_of(expected));
+        }
+        pages += 1;
+        match page.next_cursor {
+            Some(next) => {
+                assert_eq!(next, expected - 1);
+                cursor = Some(next);
+            }
+            None => break,
+        }
+    }
+    assert_eq!(expected, total - types::MAX_COMMIT_HISTORY);
+    assert_eq!(pages, types::MAX_COMMIT_HISTORY.div_ceil(7));
+
+    // cursors stay valid when new commits are recorded
+    let page = setup.contract.get_commits(&id, &None, &2);
+    setup
+        .contract
+        .commit(&setup.mando, &id, &hash_of(total), &None, &None, &None);
+    let next = setup.contract.get_commits(&id, &page.next_cursor, &2);
+    assert_eq!(next.commits.get(0).unwrap().hash, hash_of(total - 3));
+
+    // the limit is capped and cursors past the history are clamped
+    let page = setup.contract.get_commits(&id, &Some(u32::MAX), &100);
+    assert_eq!(page.commits.len(), types::MAX_COMMITS_PER_PAGE);
+    assert_eq!(page.commits.get(0).unwrap().hash, hash_of(total));
+
+    // commits dropped from the ring buffer are gone
+    let page = setup.contract.get_commits(&id, &Some(2), &5);
+    assert_eq!(page.commits, vec![&setup.env]);
+    assert_eq!(page.next_cursor, None);
+}
+
+#[test]
+fn commit_linked_to_proposal() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let other_hash = String::from_str(&setup.env, "7773520bd9e6ede248fef8157b2af0b6b6b41046");
+
+    // the DAO approves the hash, the maintainers keep control of the history
+    let voter = community_member(&setup, &id);
+    let proposal_id = approved_action(&setup, &id, &voter, &types::DaoAction::Commit(hash.clone()));
+    setup
+        .contract
+        .execute(&setup.mando, &id, &proposal_id, &None, &None);
+    assert_eq!(setup.contract.get_commit_history(&id, &0), vec![&setup.env]);
+
+    let error = setup
+        .contract
+        .try_commit(
+            &setup.mando,
+            &id,
+            &other_hash,
+            &None,
+            &None,
+            &Some(proposal_id),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, AdditionalErrors::CommitMismatch.into());
+
+    setup
+        .contract
+        .commit(&setup.mando, &id, &hash, &None, &None, &Some(proposal_id));
+    let all_events = setup.env.events().all();
+    let (_, _, data) = all_events.last().unwrap();
+    let data: Map<Symbol, Val> = data.into_val(&setup.env);
+    let linked: Option<u32> = data
+        .get(Symbol::new(&setup.env, "proposal_id"))
+        .unwrap()
+        .into_val(&setup.env);
+    assert_eq!(linked, Some(proposal_id));
+    assert_eq!(
+        setup
+            .contract
+            .get_commit_history(&id, &0)
+            .first()
+            .unwrap()
+            .proposal_id,
+        Some(proposal_id)
+    );
+
+    // a proposal authorizes a single commit
+    let error = setup
+        .contract
+        .try_commit(&setup.grogu, &id, &hash, &None, &None, &Some(proposal_id))
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, AdditionalErrors::ProposalAlreadyLinked.into());
+
+    // other proposals don't approve a commit
+    let text_id = approved_proposal(&setup, &id);
+    let error = setup
+        .contract
+        .try_commit(&setup.mando, &id, &hash, &None, &None, &Some(text_id))
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, AdditionalErrors::NotCommitProposal.into());
+
+    // the vote of an active proposal is not over
+    let voting_ends_at = setup.env.ledger().timestamp() + 3600 * 24 * 2;
+    let active_id = setup.contract.create_proposal(
+        &setup.grogu,
+        &id,
+        &String::from_str(&setup.env, "Active proposal"),
+        &String::from_str(
+            &setup.env,
+            "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        ),
+        &voting_ends_at,
+        &true,
+        &None,
+    );
+    let error = setup
+        .contract
+        .try_commit(&setup.mando, &id, &hash, &None, &None, &Some(active_id))
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::ProposalNotApproved.into());
+
+    // proposals belong to a project
+    let other = setup.contract.register(
+        &setup.grogu,
+        &String::from_str(&setup.env, "bob"),
+        &vec![&setup.env, setup.grogu.clone()],
+        &String::from_str(&setup.env, "https://github.com/bob"),
+        &String::from_str(
+            &setup.env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        &None,
+    );
+    let error = setup
+        .contract
+        .try_commit(
+            &setup.grogu,
+            &other,
+            &hash,
+            &None,
+            &None,
+            &Some(proposal_id),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::NoProposalorPageFound.into());
+}
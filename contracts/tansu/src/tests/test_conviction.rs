@@ -0,0 +1,203 @@
+use super::test_utils::{approved_action, community_member, create_test_data, init_contract};
+use crate::{
+    errors::{ContractErrors, ExtendedErrors},
+    types,
+};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{Address, IntoVal, Map, String, Symbol, Val, vec};
+
+#[test]
+fn conviction_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let kuiil = community_member(&setup, &id);
+    let bossk = community_member(&setup, &id);
+    let recipient = Address::generate(&setup.env);
+    let token_id = setup.token_stellar.address.clone();
+    setup
+        .contract
+        .fund_treasury(&setup.grogu, &id, &token_id, &(1_000 * 10_000_000));
+
+    let amount = 100 * 10_000_000;
+    let title = String::from_str(&setup.env, "Fund the documentation");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let create_proposal = |amount: i128| {
+        setup.governance.try_create_funding_proposal(
+            &setup.grogu,
+            &id,
+            &title,
+            &ipfs,
+            &token_id,
+            &recipient,
+            &amount,
+        )
+    };
+
+    let err = create_proposal(amount).unwrap_err().unwrap();
+    assert_eq!(err, ExtendedErrors::ConvictionDisabled.into());
+
+    let config = types::GovernanceConfig {
+        conviction_half_life: 100,
+        conviction_min_support_bps: 1_000,
+        ..setup.contract.get_governance_config(&id)
+    };
+    let proposal_id = approved_action(
+        &setup,
+        &id,
+        &kuiil,
+        &types::DaoAction::SetGovernanceConfig(config),
+    );
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+
+    let err = create_proposal(2_000 * 10_000_000).unwrap_err().unwrap();
+    assert_eq!(err, ExtendedErrors::InsufficientTreasury.into());
+
+    setup.env.ledger().set_sequence_number(1_000);
+    let proposal_id = create_proposal(amount).unwrap().unwrap();
+    let other_proposal_id = create_proposal(amount).unwrap().unwrap();
+
+    // 10% of the treasury requires 10% + 90% * 10% of the weight, but at
+    // least the weight of a community member
+    let weight = types::Badge::Community as u32;
+    assert_eq!(
+        setup.governance.get_required_conviction(&id, &proposal_id),
+        weight as u128
+    );
+
+    // without badges, no weight
+    let err = setup
+        .governance
+        .try_support_funding_proposal(&recipient, &id, &proposal_id, &1)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnknownMember.into());
+    let err = setup
+        .governance
+        .try_support_funding_proposal(&kuiil, &id, &proposal_id, &(weight + 1))
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::InvalidSupport.into());
+
+    setup
+        .governance
+        .support_funding_proposal(&kuiil, &id, &proposal_id, &weight);
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.governance_id.clone(),
+                (
+                    Symbol::new(&setup.env, "funding_support_changed"),
+                    id.clone(),
+                    kuiil.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "proposal_id"),
+                            proposal_id.into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "weight"),
+                            weight.into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+
+    // the weight of a member is split among the proposals
+    let err = setup
+        .governance
+        .try_support_funding_proposal(&kuiil, &id, &other_proposal_id, &1)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::InvalidSupport.into());
+
+    let err = setup
+        .governance
+        .try_execute_funding_proposal(&kuiil, &id, &proposal_id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::ConvictionTooLow.into());
+
+    // the conviction moves halfway to the support every half-life
+    setup
+        .governance
+        .support_funding_proposal(&bossk, &id, &proposal_id, &weight);
+    setup.env.ledger().set_sequence_number(1_050);
+    let proposal = setup.governance.get_funding_proposal(&id, &proposal_id);
+    assert_eq!(proposal.support, 2 * weight as u128);
+    assert_eq!(proposal.conviction, weight as u128 / 2);
+    let err = setup
+        .governance
+        .try_execute_funding_proposal(&kuiil, &id, &proposal_id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::ConvictionTooLow.into());
+
+    setup.env.ledger().set_sequence_number(1_150);
+    assert_eq!(
+        setup
+            .governance
+            .get_funding_proposal(&id, &proposal_id)
+            .conviction,
+        weight as u128 * 5 / 4
+    );
+
+    // supporters who lost their weight do not count anymore
+    setup
+        .contract
+        .set_badges(&setup.mando, &id, &bossk, &vec![&setup.env]);
+    let balance = setup.token_stellar.balance(&recipient);
+    assert_eq!(
+        setup
+            .governance
+            .execute_funding_proposal(&kuiil, &id, &proposal_id),
+        weight as u128
+    );
+    assert_eq!(setup.token_stellar.balance(&recipient), balance + amount);
+    assert_eq!(
+        setup
+            .governance
+            .get_funding_proposal(&id, &proposal_id)
+            .status,
+        governance::types::ProposalStatus::Approved
+    );
+    assert_eq!(
+        setup.contract.get_treasury(&id).get(token_id.clone()),
+        Some(900 * 10_000_000)
+    );
+    let err = setup
+        .governance
+        .try_execute_funding_proposal(&kuiil, &id, &proposal_id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProposalActive.into());
+
+    // the weight is released once the proposal is executed
+    setup
+        .governance
+        .support_funding_proposal(&kuiil, &id, &other_proposal_id, &weight);
+    setup.env.ledger().set_sequence_number(1_250);
+    setup
+        .governance
+        .support_funding_proposal(&kuiil, &id, &other_proposal_id, &0);
+    setup.env.ledger().set_sequence_number(1_350);
+    let proposal = setup
+        .governance
+        .get_funding_proposal(&id, &other_proposal_id);
+    assert_eq!(proposal.support, 0);
+    assert_eq!(proposal.conviction, weight as u128 / 4);
+}
@@ -198,7 +198,9 @@ fn test_cost_commit_hash() {
     let project_id = init_contract(&setup);
 
     let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
-    setup.contract.commit(&setup.mando, &project_id, &hash);
+    setup
+        .contract
+        .commit(&setup.mando, &project_id, &hash, &None, &None, &None);
 
     let budget = setup.env.cost_estimate().budget();
     println!("Cost Estimate - commit_hash");
@@ -273,8 +275,10 @@ fn test_cost_comprehensive_dao_workflow() {
         .execute(&setup.mando, &project_id, &proposal_id, &None, &None);
 
     // Step 6: Commit new hash
-    let hash = String::from_str(&setup.env, "abc123def456789");
-    setup.contract.commit(&setup.mando, &project_id, &hash);
+    let hash = String::from_str(&setup.env, "abc123def4567890abc123def4567890abc123de");
+    setup
+        .contract
+        .commit(&setup.mando, &project_id, &hash, &None, &None, &None);
 
     let budget = setup.env.cost_estimate().budget();
     println!("Cost Estimate - comprehensive_dao_workflow");
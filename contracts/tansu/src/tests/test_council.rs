@@ -0,0 +1,170 @@
+use super::test_utils::{
+    approved_action, community_member, create_test_data, init_contract, treasury_dao,
+};
+use crate::{
+    errors::{ContractErrors, ExtendedErrors},
+    types,
+};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{Address, IntoVal, Map, String, Symbol, Val, token, vec};
+
+#[test]
+fn council_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let kuiil = community_member(&setup, &id);
+    let bossk = community_member(&setup, &id);
+    let token_id = setup.token_stellar.address.clone();
+    let token_client = token::TokenClient::new(&setup.env, &token_id);
+    let recipient = Address::generate(&setup.env);
+    let spend_limit: i128 = 10 * 10_000_000;
+    treasury_dao(&setup, &id, &kuiil);
+    setup
+        .contract
+        .fund_treasury(&setup.grogu, &id, &token_id, &(50 * 10_000_000));
+
+    let transfer = |amount: i128| {
+        governance::types::DaoAction::Transfer(token_id.clone(), recipient.clone(), amount)
+    };
+    let signers = vec![&setup.env, kuiil.clone(), bossk.clone()];
+    let err = setup
+        .governance
+        .try_council_execute(&signers, &id, &transfer(spend_limit))
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::InvalidCouncil.into());
+
+    for council in [
+        types::Council {
+            members: signers.clone(),
+            threshold: 3,
+            spend_limit,
+        },
+        types::Council {
+            members: vec![&setup.env, kuiil.clone(), kuiil.clone()],
+            threshold: 1,
+            spend_limit,
+        },
+        types::Council {
+            members: signers.clone(),
+            threshold: 2,
+            spend_limit: -1,
+        },
+    ] {
+        let err = setup
+            .contract
+            .try_create_action_proposal(
+                &setup.grogu,
+                &id,
+                &String::from_str(&setup.env, "Elect a council"),
+                &String::from_str(
+                    &setup.env,
+                    "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+                ),
+                &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+                &true,
+                &types::DaoAction::SetCouncil(council),
+            )
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, ExtendedErrors::InvalidCouncil.into());
+    }
+
+    let council = types::Council {
+        members: signers.clone(),
+        threshold: 2,
+        spend_limit,
+    };
+    let proposal_id = approved_action(
+        &setup,
+        &id,
+        &kuiil,
+        &types::DaoAction::SetCouncil(council.clone()),
+    );
+    assert_eq!(
+        setup.contract.get_proposal(&id, &proposal_id).kind,
+        types::ProposalKind::ConfigChange
+    );
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+    assert_eq!(
+        setup.governance.get_council(&id),
+        Some(governance::types::Council {
+            members: council.members,
+            threshold: council.threshold,
+            spend_limit: council.spend_limit,
+        })
+    );
+
+    // all signers are distinct members and reach the threshold
+    for signers in [
+        vec![&setup.env, kuiil.clone()],
+        vec![&setup.env, kuiil.clone(), kuiil.clone()],
+        vec![&setup.env, kuiil.clone(), setup.grogu.clone()],
+    ] {
+        let err = setup
+            .governance
+            .try_council_execute(&signers, &id, &transfer(spend_limit))
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+    }
+
+    // only transfers within the spend limit
+    for action in [
+        transfer(spend_limit + 1),
+        governance::types::DaoAction::SetLicense(String::from_str(&setup.env, "MIT")),
+    ] {
+        let err = setup
+            .governance
+            .try_council_execute(&signers, &id, &action)
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, ContractErrors::InvalidDaoAction.into());
+    }
+
+    setup
+        .governance
+        .council_execute(&signers, &id, &transfer(spend_limit));
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        vec![&setup.env, all_events.last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                setup.governance_id.clone(),
+                (Symbol::new(&setup.env, "council_acted"), id.clone()).into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [(
+                        Symbol::new(&setup.env, "signers"),
+                        signers.clone().into_val(&setup.env)
+                    )],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    assert_eq!(token_client.balance(&recipient), spend_limit);
+    assert_eq!(
+        setup.contract.get_treasury(&id),
+        Map::from_array(&setup.env, [(token_id.clone(), 40 * 10_000_000)])
+    );
+
+    // the DAO can dissolve its council
+    let proposal_id = approved_action(
+        &setup,
+        &id,
+        &kuiil,
+        &types::DaoAction::SetCouncil(types::Council {
+            members: vec![&setup.env],
+            threshold: 0,
+            spend_limit: 0,
+        }),
+    );
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+    assert_eq!(setup.governance.get_council(&id), None);
+}
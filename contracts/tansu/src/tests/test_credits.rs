@@ -0,0 +1,139 @@
+use super::test_utils::{create_test_data, init_contract};
+use crate::errors::{ContractErrors, ExtendedErrors};
+use registry::types;
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{Address, IntoVal, Map, String, Symbol, Val, vec};
+
+#[test]
+fn credits_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let commit = types::CreditTarget::Commit(hash.clone());
+    let alice = Address::generate(&setup.env);
+    let bob = Address::generate(&setup.env);
+
+    setup
+        .registry
+        .credit(&setup.grogu, &id, &commit, &alice, &6_000);
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.registry_id.clone(),
+                (
+                    Symbol::new(&setup.env, "contributor_credited"),
+                    id.clone(),
+                    alice.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "maintainer"),
+                            setup.grogu.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "share_bps"),
+                            6_000u32.into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "target"),
+                            commit.clone().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+
+    // shares of a target cannot exceed 100%
+    let error = setup
+        .registry
+        .try_credit(&setup.grogu, &id, &commit, &bob, &4_001)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::InvalidCredit.into());
+    setup
+        .registry
+        .credit(&setup.mando, &id, &commit, &bob, &4_000);
+    // crediting again replaces the share
+    setup
+        .registry
+        .credit(&setup.grogu, &id, &commit, &alice, &5_000);
+    let credits = setup.registry.get_credits(&id, &commit);
+    assert_eq!(credits.len(), 2);
+    assert_eq!(credits.get(alice.clone()), Some(5_000));
+    assert_eq!(credits.get(bob.clone()), Some(4_000));
+
+    // releases must exist
+    let tag = String::from_str(&setup.env, "v1.0.0");
+    let release = types::CreditTarget::Release(tag.clone());
+    let error = setup
+        .registry
+        .try_credit(&setup.grogu, &id, &release, &alice, &10_000)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::UnknownRelease.into());
+    let cid = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    setup.registry.create_release(
+        &setup.grogu,
+        &id,
+        &tag,
+        &hash,
+        &cid,
+        &cid,
+        &types::ReleaseChannel::Stable,
+        &None,
+    );
+    setup
+        .registry
+        .credit(&setup.grogu, &id, &release, &alice, &10_000);
+    assert_eq!(
+        setup.registry.get_credited_targets(&id),
+        vec![&setup.env, commit.clone(), release.clone()]
+    );
+
+    // a zero share removes the credit, and the target once empty
+    setup
+        .registry
+        .credit(&setup.grogu, &id, &release, &alice, &0);
+    assert_eq!(setup.registry.get_credits(&id, &release).len(), 0);
+    assert_eq!(
+        setup.registry.get_credited_targets(&id),
+        vec![&setup.env, commit.clone()]
+    );
+    let error = setup
+        .registry
+        .try_credit(&setup.grogu, &id, &release, &alice, &0)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::InvalidCredit.into());
+
+    let error = setup
+        .registry
+        .try_credit(
+            &setup.grogu,
+            &id,
+            &types::CreditTarget::Commit(String::from_str(&setup.env, "6663520b")),
+            &alice,
+            &1_000,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::InvalidCommitHash.into());
+    let error = setup
+        .registry
+        .try_credit(&alice, &id, &commit, &alice, &1_000)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::UnauthorizedSigner.into());
+}
@@ -1,10 +1,19 @@
-use super::test_utils::{create_test_data, init_contract};
+use super::test_utils::{
+    approved_action, community_member, create_test_data, init_contract, seeds, treasury_dao,
+};
 use crate::{
-    errors::ContractErrors,
-    types::{AnonymousVote, Badge, Dao, ProposalStatus, PublicVote, Vote, VoteChoice},
+    errors::{ContractErrors, ExtendedErrors},
+    types,
+    types::{
+        AnonymousVote, Badge, Dao, DaoAction, GovernanceConfig, ProposalKind, ProposalPage,
+        ProposalStatus, ProposalTemplate, PublicVote, Revision, Tally, TemplateAction, Turnout,
+        Vote, VoteChoice, VotingWeight,
+    },
+};
+use soroban_sdk::testutils::{Address as _, Events, IssuerFlags, Ledger};
+use soroban_sdk::{
+    Address, BytesN, IntoVal, Map, String, Symbol, U256, Val, Vec, bytesn, token, vec,
 };
-use soroban_sdk::testutils::{Address as _, Events, Ledger};
-use soroban_sdk::{Address, BytesN, IntoVal, Map, String, Symbol, Val, vec};
 
 #[test]
 fn proposal_flow() {
@@ -307,7 +316,7 @@ fn dao_anonymous() {
         commitments: setup.contract.build_commitments_from_votes(
             &id,
             &vec![&setup.env, 0u128, 0u128, 1u128],
-            &vec![&setup.env, 0u128, 0u128, 0u128],
+            &seeds(&setup.env, &[0, 0, 0]),
         ),
     });
 
@@ -316,6 +325,21 @@ fn dao_anonymous() {
         vec![&setup.env, abstain_vote.clone()]
     );
 
+    // seeds are full scalars, their weighted sums are given modulo the order
+    // of the field
+    let order = U256::from_be_bytes(
+        &setup.env,
+        &bytesn!(
+            &setup.env,
+            0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001
+        )
+        .into(),
+    );
+    let mut kuiil_seeds = seeds(&setup.env, &[5, 4]);
+    kuiil_seeds.push_back(order.sub(&U256::from_u32(&setup.env, 2)));
+    let mut seed_sums = seeds(&setup.env, &[15, 12]);
+    seed_sums.push_back(order.sub(&U256::from_u32(&setup.env, 6)));
+
     let vote_ = Vote::AnonymousVote(AnonymousVote {
         address: kuiil.clone(),
         weight: 3,
@@ -334,7 +358,7 @@ fn dao_anonymous() {
         commitments: setup.contract.build_commitments_from_votes(
             &id,
             &vec![&setup.env, 3u128, 1u128, 1u128],
-            &vec![&setup.env, 5u128, 4u128, 6u128],
+            &kuiil_seeds,
         ),
     });
     setup.contract.vote(&kuiil, &id, &proposal_id, &vote_);
@@ -346,12 +370,123 @@ fn dao_anonymous() {
         &id,
         &proposal_id,
         &Some(vec![&setup.env, 9u128, 3u128, 500003u128]),
-        &Some(vec![&setup.env, 15u128, 12u128, 18u128]),
+        &Some(seed_sums),
     );
 
     assert_eq!(vote_result, ProposalStatus::Cancelled);
 }
 
+#[test]
+fn anonymous_reveal_phase_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let kuiil = community_member(&setup, &id);
+    let title = String::from_str(&setup.env, "Hidden tallies");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    setup.contract.anonymous_voting_setup(
+        &setup.mando,
+        &id,
+        &String::from_str(&setup.env, "public key random"),
+    );
+
+    let config = setup.contract.get_governance_config(&id);
+    let proposal_id = approved_action(
+        &setup,
+        &id,
+        &kuiil,
+        &types::DaoAction::SetGovernanceConfig(types::GovernanceConfig {
+            tally_reveal_period: 3600,
+            ..config
+        }),
+    );
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+
+    // public proposals still take votes directly
+    let voting_ends_at = setup.env.ledger().timestamp() + 3600 * 24 * 2;
+    let public_id = setup.contract.create_proposal(
+        &setup.grogu,
+        &id,
+        &title,
+        &ipfs,
+        &voting_ends_at,
+        &true,
+        &None,
+    );
+    assert_eq!(
+        setup.contract.get_proposal(&id, &public_id).reveal_ends_at,
+        None
+    );
+
+    let revealed_id = setup.contract.create_proposal(
+        &setup.grogu,
+        &id,
+        &title,
+        &ipfs,
+        &voting_ends_at,
+        &false,
+        &None,
+    );
+    let unrevealed_id = setup.contract.create_proposal(
+        &setup.grogu,
+        &id,
+        &title,
+        &ipfs,
+        &voting_ends_at,
+        &false,
+        &None,
+    );
+    let reveal_ends_at = voting_ends_at + 3600;
+    assert_eq!(
+        setup
+            .contract
+            .get_proposal(&id, &revealed_id)
+            .reveal_ends_at,
+        Some(reveal_ends_at)
+    );
+
+    // only the abstain vote of the proposer
+    let tallies = Some(vec![
+        &setup.env,
+        0u128,
+        0u128,
+        types::Badge::Verified as u128,
+    ]);
+    let seeds = Some(seeds(&setup.env, &[0, 0, 0]));
+
+    // tallies are rejected before the reveal phase opens
+    let err = setup
+        .contract
+        .try_execute(&setup.grogu, &id, &revealed_id, &tallies, &seeds)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProposalVotingTime.into());
+
+    setup.env.ledger().set_timestamp(voting_ends_at);
+    let vote_result = setup
+        .contract
+        .execute(&setup.grogu, &id, &revealed_id, &tallies, &seeds);
+    assert_eq!(vote_result, types::ProposalStatus::Cancelled);
+
+    // tallies are not accepted anymore after the reveal phase
+    setup.env.ledger().set_timestamp(reveal_ends_at);
+    let err = setup
+        .contract
+        .try_execute(&setup.grogu, &id, &unrevealed_id, &tallies, &seeds)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProposalVotingTime.into());
+
+    let vote_result = setup
+        .contract
+        .execute(&setup.grogu, &id, &unrevealed_id, &None, &None);
+    assert_eq!(vote_result, types::ProposalStatus::Expired);
+}
+
 #[test]
 fn voting_errors() {
     let setup = create_test_data();
@@ -562,7 +697,7 @@ fn voting_errors() {
             &id,
             &proposal_id_anonymous,
             &Some(vec![&setup.env, 0u128, 0u128, 500_001u128]), // 500_000u128
-            &Some(vec![&setup.env, 0u128, 0u128, 0u128]),
+            &Some(seeds(&setup.env, &[0, 0, 0])),
         )
         .unwrap_err()
         .unwrap();
@@ -596,16 +731,6 @@ fn proposal_execution() {
         "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
     );
 
-    let proposal_id = setup.contract.create_proposal(
-        &setup.grogu,
-        &id,
-        &title,
-        &ipfs,
-        &voting_ends_at,
-        &true,
-        &None,
-    );
-
     // Add member with badge
     let kuiil = Address::generate(&setup.env);
     setup.token_stellar.mint(&kuiil, &(10 * 10_000_000));
@@ -616,6 +741,16 @@ fn proposal_execution() {
         .contract
         .set_badges(&setup.mando, &id, &kuiil, &badges);
 
+    let proposal_id = setup.contract.create_proposal(
+        &setup.grogu,
+        &id,
+        &title,
+        &ipfs,
+        &voting_ends_at,
+        &true,
+        &None,
+    );
+
     setup.contract.vote(
         &setup.mando,
         &id,
@@ -785,6 +920,50 @@ fn voter_weight_validation() {
     let max_weight = setup.contract.get_max_weight(&id, &kuiil);
     assert_eq!(max_weight, 11_000_000u32);
 
+    // badges given during the vote do not count
+    assert_eq!(
+        setup.contract.get_voting_weight(&id, &proposal_id, &kuiil),
+        Badge::Default as u32
+    );
+    let err = setup
+        .contract
+        .try_vote(
+            &kuiil,
+            &id,
+            &proposal_id,
+            &Vote::PublicVote(PublicVote {
+                address: kuiil.clone(),
+                weight: 42,
+                vote_choice: VoteChoice::Approve,
+            }),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::VoterWeight.into());
+
+    let proposal_id = setup.contract.create_proposal(
+        &setup.mando,
+        &id,
+        &title,
+        &ipfs,
+        &voting_ends_at,
+        &true,
+        &None,
+    );
+    assert_eq!(
+        setup.contract.get_voting_weight(&id, &proposal_id, &kuiil),
+        max_weight
+    );
+
+    // nor do badges removed during the vote
+    setup
+        .contract
+        .set_badges(&setup.mando, &id, &kuiil, &vec![&setup.env]);
+    assert_eq!(
+        setup.contract.get_voting_weight(&id, &proposal_id, &kuiil),
+        max_weight
+    );
+
     // Vote with reduced weight (should work)
     setup.contract.vote(
         &kuiil,
@@ -830,16 +1009,6 @@ fn outcomes_execution() {
         "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
     );
 
-    let proposal_id = setup.contract.create_proposal(
-        &setup.grogu,
-        &id,
-        &title,
-        &ipfs,
-        &voting_ends_at,
-        &true,
-        &Some(setup.outcomes_id),
-    );
-
     // Add member with badge
     let kuiil = Address::generate(&setup.env);
     setup.token_stellar.mint(&kuiil, &(10 * 10_000_000));
@@ -850,6 +1019,16 @@ fn outcomes_execution() {
         .contract
         .set_badges(&setup.mando, &id, &kuiil, &badges);
 
+    let proposal_id = setup.contract.create_proposal(
+        &setup.grogu,
+        &id,
+        &title,
+        &ipfs,
+        &voting_ends_at,
+        &true,
+        &Some(setup.outcomes_id),
+    );
+
     setup.contract.vote(
         &setup.mando,
         &id,
@@ -882,3 +1061,3228 @@ fn outcomes_execution() {
     let proposal = setup.contract.get_proposal(&id, &proposal_id);
     assert_eq!(proposal.status, ProposalStatus::Approved);
 }
+
+#[test]
+fn dao_control_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let voting_ends_at = setup.env.ledger().timestamp() + 3600 * 24 * 2;
+    let title = String::from_str(&setup.env, "Commit the new release");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let action = DaoAction::Commit(hash.clone());
+
+    // actions are only for projects controlled by their DAO
+    let err = setup
+        .contract
+        .try_create_action_proposal(
+            &setup.grogu,
+            &id,
+            &title,
+            &ipfs,
+            &voting_ends_at,
+            &true,
+            &DaoAction::UpdateConfig(
+                String::from_str(&setup.env, "https://github.com/tansu"),
+                ipfs.clone(),
+            ),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidDaoAction.into());
+
+    let kuiil = Address::generate(&setup.env);
+    setup.token_stellar.mint(&kuiil, &(10 * 10_000_000));
+    setup
+        .contract
+        .add_member(&kuiil, &String::from_str(&setup.env, "test"));
+    setup.contract.set_badges(
+        &setup.mando,
+        &id,
+        &kuiil,
+        &vec![&setup.env, Badge::Community],
+    );
+
+    // both maintainers must approve
+    assert!(!setup.contract.renounce_to_dao(&setup.grogu, &id));
+    assert!(!setup.contract.is_dao_controlled(&id));
+    assert!(setup.contract.renounce_to_dao(&setup.mando, &id));
+
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        vec![&setup.env, all_events.last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (
+                    Symbol::new(&setup.env, "project_renounced_to_dao"),
+                    id.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [(
+                        Symbol::new(&setup.env, "maintainer"),
+                        setup.mando.clone().into_val(&setup.env)
+                    ),],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+
+    assert!(setup.contract.is_dao_controlled(&id));
+
+    // maintainers lost their direct privileges
+    let err = setup
+        .contract
+        .try_commit(&setup.grogu, &id, &hash, &None, &None, &None)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::DaoControlled.into());
+    let err = setup
+        .contract
+        .try_renounce_to_dao(&setup.grogu, &id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::DaoControlled.into());
+
+    let proposal_id = setup.contract.create_action_proposal(
+        &setup.grogu,
+        &id,
+        &title,
+        &ipfs,
+        &voting_ends_at,
+        &true,
+        &action,
+    );
+    assert_eq!(
+        setup.contract.get_proposal_action(&id, &proposal_id),
+        Some(action)
+    );
+
+    let err = setup
+        .contract
+        .try_revoke_proposal(&setup.grogu, &id, &proposal_id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::DaoControlled.into());
+
+    setup.contract.vote(
+        &kuiil,
+        &id,
+        &proposal_id,
+        &Vote::PublicVote(PublicVote {
+            address: kuiil.clone(),
+            weight: Badge::Community as u32,
+            vote_choice: VoteChoice::Approve,
+        }),
+    );
+
+    // anyone can execute the proposal
+    setup.env.ledger().set_timestamp(voting_ends_at + 1);
+    let vote_result = setup
+        .contract
+        .execute(&kuiil, &id, &proposal_id, &None, &None);
+    assert_eq!(vote_result, ProposalStatus::Approved);
+
+    assert_eq!(setup.contract.get_commit(&id), hash);
+    assert_eq!(setup.contract.get_proposal_action(&id, &proposal_id), None);
+}
+
+#[test]
+fn governance_config_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let day = 3600 * 24;
+    let default_config = GovernanceConfig {
+        min_voting_period: day,
+        max_voting_period: 30 * day,
+        max_title_length: 256,
+        quorum: 0,
+        approval_thresholds: Map::new(&setup.env),
+        abstain_in_quorum: true,
+        abstain_in_approval: true,
+        veto_period: 0,
+        proposal_deposit: 0,
+        spam_quorum_bps: 0,
+        proposal_cooldown: 0,
+        quadratic_voting: false,
+        conviction_half_life: 0,
+        conviction_min_support_bps: 0,
+        voting_weight: VotingWeight::Badges,
+        unbonding_period: 0,
+        reputation_half_life: 0,
+        reputation_weight: 0,
+        execution_window: 0,
+        emergency_voting_period: 0,
+        emergency_threshold: 0,
+        sponsors_required: 0,
+        max_voter_weight_bps: 0,
+        reveal_period: 0,
+        tally_reveal_period: 0,
+    };
+    assert_eq!(setup.contract.get_governance_config(&id), default_config);
+
+    let title = String::from_str(&setup.env, "Longer votes with a quorum");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let voting_ends_at = setup.env.ledger().timestamp() + 2 * day;
+
+    let err = setup
+        .contract
+        .try_create_action_proposal(
+            &setup.grogu,
+            &id,
+            &title,
+            &ipfs,
+            &voting_ends_at,
+            &true,
+            &DaoAction::SetGovernanceConfig(GovernanceConfig {
+                min_voting_period: 31 * day,
+                ..default_config.clone()
+            }),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidGovernanceConfig.into());
+
+    // proposer abstain weight alone does not reach the quorum
+    let config = GovernanceConfig {
+        min_voting_period: 2 * day,
+        max_voting_period: 10 * day,
+        max_title_length: 64,
+        quorum: Badge::Verified as u32 + 1,
+        approval_thresholds: Map::new(&setup.env),
+        abstain_in_quorum: true,
+        abstain_in_approval: true,
+        veto_period: 0,
+        proposal_deposit: 0,
+        spam_quorum_bps: 0,
+        proposal_cooldown: 0,
+        quadratic_voting: false,
+        conviction_half_life: 0,
+        conviction_min_support_bps: 0,
+        voting_weight: VotingWeight::Badges,
+        unbonding_period: 0,
+        reputation_half_life: 0,
+        reputation_weight: 0,
+        execution_window: 0,
+        emergency_voting_period: 0,
+        emergency_threshold: 0,
+        sponsors_required: 0,
+        max_voter_weight_bps: 0,
+        reveal_period: 0,
+        tally_reveal_period: 0,
+    };
+    let kuiil = Address::generate(&setup.env);
+    setup.token_stellar.mint(&kuiil, &(10 * 10_000_000));
+    setup
+        .contract
+        .add_member(&kuiil, &String::from_str(&setup.env, "test"));
+    setup.contract.set_badges(
+        &setup.mando,
+        &id,
+        &kuiil,
+        &vec![&setup.env, Badge::Community],
+    );
+
+    let proposal_id = setup.contract.create_action_proposal(
+        &setup.grogu,
+        &id,
+        &title,
+        &ipfs,
+        &voting_ends_at,
+        &true,
+        &DaoAction::SetGovernanceConfig(config.clone()),
+    );
+
+    setup.contract.vote(
+        &kuiil,
+        &id,
+        &proposal_id,
+        &Vote::PublicVote(PublicVote {
+            address: kuiil.clone(),
+            weight: Badge::Community as u32,
+            vote_choice: VoteChoice::Approve,
+        }),
+    );
+
+    setup.env.ledger().set_timestamp(voting_ends_at + 1);
+    let vote_result = setup
+        .contract
+        .execute(&setup.mando, &id, &proposal_id, &None, &None);
+    assert_eq!(vote_result, ProposalStatus::Approved);
+    assert_eq!(setup.contract.get_governance_config(&id), config);
+
+    // new limits apply
+    let err = setup
+        .contract
+        .try_create_proposal(
+            &setup.grogu,
+            &id,
+            &title,
+            &ipfs,
+            &(setup.env.ledger().timestamp() + day),
+            &true,
+            &None,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProposalInputValidation.into());
+
+    let voting_ends_at = setup.env.ledger().timestamp() + 2 * day;
+    let proposal_id = setup.contract.create_proposal(
+        &setup.grogu,
+        &id,
+        &title,
+        &ipfs,
+        &voting_ends_at,
+        &true,
+        &None,
+    );
+
+    // the quorum is not reached
+    setup.env.ledger().set_timestamp(voting_ends_at + 1);
+    let vote_result = setup
+        .contract
+        .execute(&setup.mando, &id, &proposal_id, &None, &None);
+    assert_eq!(vote_result, ProposalStatus::Cancelled);
+}
+
+#[test]
+fn license_change_flow() {
+    let setup = create_test_data();
+    init_contract(&setup);
+
+    let name = String::from_str(&setup.env, "kuiil");
+    let url = String::from_str(&setup.env, "https://github.com/kuiil");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let maintainers = vec![&setup.env, setup.grogu.clone()];
+
+    let err = setup
+        .contract
+        .try_register(
+            &setup.grogu,
+            &name,
+            &maintainers,
+            &url,
+            &ipfs,
+            &Some(String::from_str(&setup.env, "MIT;")),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidLicense.into());
+
+    let mit = String::from_str(&setup.env, "MIT");
+    let id = setup.contract.register(
+        &setup.grogu,
+        &name,
+        &maintainers,
+        &url,
+        &ipfs,
+        &Some(mit.clone()),
+    );
+    assert_eq!(
+        setup.contract.get_project(&id).config.license,
+        Some(mit.clone())
+    );
+
+    // the license only changes through an approved proposal
+    let title = String::from_str(&setup.env, "Dual license the project");
+    let voting_ends_at = setup.env.ledger().timestamp() + 3600 * 24 * 2;
+    let err = setup
+        .contract
+        .try_create_action_proposal(
+            &setup.grogu,
+            &id,
+            &title,
+            &ipfs,
+            &voting_ends_at,
+            &true,
+            &DaoAction::SetLicense(String::from_str(&setup.env, "")),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidLicense.into());
+
+    let license = String::from_str(&setup.env, "MIT OR Apache-2.0");
+    let kuiil = Address::generate(&setup.env);
+    setup.token_stellar.mint(&kuiil, &(10 * 10_000_000));
+    setup
+        .contract
+        .add_member(&kuiil, &String::from_str(&setup.env, "test"));
+    setup.contract.set_badges(
+        &setup.grogu,
+        &id,
+        &kuiil,
+        &vec![&setup.env, Badge::Community],
+    );
+
+    let proposal_id = setup.contract.create_action_proposal(
+        &setup.grogu,
+        &id,
+        &title,
+        &ipfs,
+        &voting_ends_at,
+        &true,
+        &DaoAction::SetLicense(license.clone()),
+    );
+
+    setup.contract.vote(
+        &kuiil,
+        &id,
+        &proposal_id,
+        &Vote::PublicVote(PublicVote {
+            address: kuiil.clone(),
+            weight: Badge::Community as u32,
+            vote_choice: VoteChoice::Approve,
+        }),
+    );
+
+    setup.env.ledger().set_timestamp(voting_ends_at + 1);
+    let vote_result = setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+    assert_eq!(vote_result, ProposalStatus::Approved);
+
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events.slice(all_events.len() - 2..all_events.len() - 1),
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (Symbol::new(&setup.env, "license_changed"), id.clone()).into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "old_license"),
+                            Some(mit).into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "new_license"),
+                            license.clone().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    assert_eq!(
+        setup.contract.get_project(&id).config.license,
+        Some(license)
+    );
+}
+
+#[test]
+fn treasury_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let token_id = setup.token_stellar.address.clone();
+    let token_client = token::TokenClient::new(&setup.env, &token_id);
+    let amount: i128 = 500 * 10_000_000;
+    let voter = community_member(&setup, &id);
+
+    let err = setup
+        .contract
+        .try_fund_treasury(&setup.grogu, &id, &token_id, &0)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::InvalidAmount.into());
+
+    setup
+        .contract
+        .fund_treasury(&setup.grogu, &id, &token_id, &amount);
+    assert_eq!(
+        setup.contract.get_treasury(&id),
+        Map::from_array(&setup.env, [(token_id.clone(), amount)])
+    );
+
+    // plain proposals are advisory
+    let text_id = setup.contract.create_proposal(
+        &setup.grogu,
+        &id,
+        &String::from_str(&setup.env, "Advisory proposal"),
+        &String::from_str(
+            &setup.env,
+            "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        ),
+        &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+        &true,
+        &None,
+    );
+    assert_eq!(
+        setup.contract.get_proposal(&id, &text_id).kind,
+        ProposalKind::Text
+    );
+
+    // the treasury is only spent by the DAO of the project with a quorum
+    let recipient = Address::generate(&setup.env);
+    let spent = 200 * 10_000_000;
+    let action = DaoAction::Transfer(token_id.clone(), recipient.clone(), spent);
+    for renounce in [false, true] {
+        if renounce {
+            setup.contract.renounce_to_dao(&setup.grogu, &id);
+            setup.contract.renounce_to_dao(&setup.mando, &id);
+        }
+        let err = setup
+            .contract
+            .try_create_action_proposal(
+                &setup.grogu,
+                &id,
+                &String::from_str(&setup.env, "Spend without a quorum"),
+                &String::from_str(
+                    &setup.env,
+                    "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+                ),
+                &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+                &true,
+                &action,
+            )
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, ContractErrors::InvalidDaoAction.into());
+    }
+    let config = setup.contract.get_governance_config(&id);
+    let proposal_id = approved_action(
+        &setup,
+        &id,
+        &voter,
+        &DaoAction::SetGovernanceConfig(GovernanceConfig {
+            quorum: Badge::Community as u32,
+            ..config
+        }),
+    );
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+
+    let err = setup
+        .contract
+        .try_create_action_proposal(
+            &setup.grogu,
+            &id,
+            &String::from_str(&setup.env, "Spend nothing at all"),
+            &String::from_str(
+                &setup.env,
+                "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+            ),
+            &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+            &true,
+            &DaoAction::Transfer(token_id.clone(), recipient.clone(), 0),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::InvalidAmount.into());
+
+    let proposal_id = approved_action(&setup, &id, &voter, &action);
+    assert_eq!(
+        setup.contract.get_proposal(&id, &proposal_id).kind,
+        ProposalKind::Treasury
+    );
+
+    let vote_result = setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+    assert_eq!(vote_result, ProposalStatus::Approved);
+
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events.slice(all_events.len() - 2..all_events.len() - 1),
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (Symbol::new(&setup.env, "treasury_spent"), id.clone()).into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "amount"),
+                            spent.into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "executor"),
+                            setup.grogu.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "recipient"),
+                            recipient.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "token"),
+                            token_id.clone().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    assert_eq!(token_client.balance(&recipient), spent);
+    assert_eq!(
+        setup.contract.get_treasury(&id),
+        Map::from_array(&setup.env, [(token_id.clone(), amount - spent)])
+    );
+
+    // the remaining funds are not enough
+    let action = DaoAction::Transfer(token_id.clone(), recipient.clone(), amount);
+    let proposal_id = approved_action(&setup, &id, &voter, &action);
+    let err = setup
+        .contract
+        .try_execute(&setup.grogu, &id, &proposal_id, &None, &None)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::InsufficientTreasury.into());
+
+    let action = DaoAction::Transfer(token_id.clone(), recipient.clone(), amount - spent);
+    let proposal_id = approved_action(&setup, &id, &voter, &action);
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+    assert_eq!(token_client.balance(&recipient), amount);
+    assert!(setup.contract.get_treasury(&id).is_empty());
+}
+
+#[test]
+fn treasury_sweep_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let token_id = setup.token_stellar.address.clone();
+    let token_client = token::TokenClient::new(&setup.env, &token_id);
+    let amount: i128 = 500 * 10_000_000;
+    setup
+        .contract
+        .fund_treasury(&setup.grogu, &id, &token_id, &amount);
+
+    // a token the contract cannot transfer does not block the removal
+    let sac = setup
+        .env
+        .register_stellar_asset_contract_v2(Address::generate(&setup.env));
+    let frozen_token = token::StellarAssetClient::new(&setup.env, &sac.address());
+    frozen_token.mint(&setup.grogu, &1);
+    setup
+        .contract
+        .fund_treasury(&setup.grogu, &id, &sac.address(), &1);
+    sac.issuer().set_flag(IssuerFlags::RevocableFlag);
+    frozen_token.set_authorized(&setup.contract_id, &false);
+
+    setup.contract.deregister(&setup.grogu, &id);
+    setup.contract.deregister(&setup.mando, &id);
+    setup
+        .env
+        .ledger()
+        .set_timestamp(setup.env.ledger().timestamp() + types::DEREGISTRATION_DELAY);
+    let held = token_client.balance(&setup.contract_id);
+    let bond = setup.contract.get_bond(&id).map_or(0, |bond| bond.amount);
+    assert!(setup.contract.deregister(&setup.grogu, &id));
+    assert_eq!(
+        token_client.balance(&setup.contract_id),
+        held - bond - amount
+    );
+    assert!(setup.contract.get_treasury(&id).is_empty());
+}
+
+#[test]
+fn contract_call_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let voter = community_member(&setup, &id);
+    treasury_dao(&setup, &id, &voter);
+    let token_id = setup.token_stellar.address.clone();
+    setup
+        .contract
+        .fund_treasury(&setup.grogu, &id, &token_id, &10_000_000);
+    let function = Symbol::new(&setup.env, "approve_outcome");
+    for (contract, function) in [
+        (setup.outcomes_id.clone(), function.clone()),
+        (token_id.clone(), Symbol::new(&setup.env, "transfer")),
+        (
+            setup.contract_id.clone(),
+            Symbol::new(&setup.env, "fund_treasury"),
+        ),
+        (
+            setup.governance_id.clone(),
+            Symbol::new(&setup.env, "set_council"),
+        ),
+    ] {
+        setup
+            .contract
+            .set_invoke_target(&setup.contract_admin, &contract, &function, &true);
+    }
+
+    // calls not allowed by the admins, to the contracts trusting this one or
+    // which could move funds held by the contract are rejected
+    let too_many_args = Vec::from_iter(
+        &setup.env,
+        (0..11u32).map(|arg| -> Val { arg.into_val(&setup.env) }),
+    );
+    for action in [
+        DaoAction::Invoke(
+            token_id.clone(),
+            Symbol::new(&setup.env, "transfer"),
+            vec![&setup.env],
+        ),
+        DaoAction::Invoke(
+            setup.contract_id.clone(),
+            Symbol::new(&setup.env, "fund_treasury"),
+            vec![&setup.env],
+        ),
+        DaoAction::Invoke(
+            setup.governance_id.clone(),
+            Symbol::new(&setup.env, "set_council"),
+            vec![&setup.env],
+        ),
+        DaoAction::Invoke(
+            setup.outcomes_id.clone(),
+            Symbol::new(&setup.env, "approve_outcome"),
+            too_many_args,
+        ),
+        DaoAction::Invoke(
+            setup.outcomes_id.clone(),
+            Symbol::new(&setup.env, "reject_outcome"),
+            vec![&setup.env],
+        ),
+    ] {
+        let err = setup
+            .contract
+            .try_create_action_proposal(
+                &setup.grogu,
+                &id,
+                &String::from_str(&setup.env, "Call a contract"),
+                &String::from_str(
+                    &setup.env,
+                    "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+                ),
+                &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+                &true,
+                &action,
+            )
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, ContractErrors::InvalidDaoAction.into());
+    }
+
+    for (args, success) in [
+        (
+            vec![&setup.env, setup.grogu.clone().into_val(&setup.env)],
+            true,
+        ),
+        // a failing call does not block the execution
+        (vec![&setup.env], false),
+    ] {
+        let action = DaoAction::Invoke(setup.outcomes_id.clone(), function.clone(), args);
+        let proposal_id = approved_action(&setup, &id, &voter, &action);
+        assert_eq!(
+            setup.contract.get_proposal(&id, &proposal_id).kind,
+            ProposalKind::ContractCall
+        );
+
+        let vote_result = setup
+            .contract
+            .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+        assert_eq!(vote_result, ProposalStatus::Approved);
+
+        let all_events = setup.env.events().all();
+        assert_eq!(
+            all_events.slice(all_events.len() - 2..all_events.len() - 1),
+            vec![
+                &setup.env,
+                (
+                    setup.contract_id.clone(),
+                    (Symbol::new(&setup.env, "contract_invoked"), id.clone()).into_val(&setup.env),
+                    Map::<Symbol, Val>::from_array(
+                        &setup.env,
+                        [
+                            (
+                                Symbol::new(&setup.env, "contract"),
+                                setup.outcomes_id.clone().into_val(&setup.env)
+                            ),
+                            (
+                                Symbol::new(&setup.env, "executor"),
+                                setup.grogu.clone().into_val(&setup.env)
+                            ),
+                            (
+                                Symbol::new(&setup.env, "function"),
+                                function.clone().into_val(&setup.env)
+                            ),
+                            (
+                                Symbol::new(&setup.env, "success"),
+                                success.into_val(&setup.env)
+                            ),
+                        ],
+                    )
+                    .into_val(&setup.env),
+                ),
+            ]
+        );
+    }
+}
+
+#[test]
+fn maintainer_change_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let kuiil = Address::generate(&setup.env);
+    let maintainers = vec![&setup.env, setup.grogu.clone(), kuiil.clone()];
+    let action = DaoAction::SetMaintainers(maintainers.clone());
+    let voter = community_member(&setup, &id);
+
+    let err = setup
+        .contract
+        .try_create_action_proposal(
+            &setup.grogu,
+            &id,
+            &String::from_str(&setup.env, "Replace the maintainers"),
+            &String::from_str(
+                &setup.env,
+                "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+            ),
+            &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+            &true,
+            &action,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidDaoAction.into());
+
+    setup.contract.renounce_to_dao(&setup.grogu, &id);
+    setup.contract.renounce_to_dao(&setup.mando, &id);
+
+    let err = setup
+        .contract
+        .try_create_action_proposal(
+            &setup.grogu,
+            &id,
+            &String::from_str(&setup.env, "Replace the maintainers"),
+            &String::from_str(
+                &setup.env,
+                "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+            ),
+            &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+            &true,
+            &DaoAction::SetMaintainers(vec![&setup.env]),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidMaintainersConfig.into());
+
+    let proposal_id = approved_action(&setup, &id, &voter, &action);
+    assert_eq!(
+        setup.contract.get_proposal(&id, &proposal_id).kind,
+        ProposalKind::MaintainerChange
+    );
+    let vote_result = setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+    assert_eq!(vote_result, ProposalStatus::Approved);
+    assert_eq!(setup.contract.get_project(&id).maintainers, maintainers);
+
+    let proposal_id = approved_action(
+        &setup,
+        &id,
+        &voter,
+        &DaoAction::Commit(String::from_str(
+            &setup.env,
+            "6663520bd9e6ede248fef8157b2af0b6b6b41046",
+        )),
+    );
+    assert_eq!(
+        setup.contract.get_proposal(&id, &proposal_id).kind,
+        ProposalKind::Upgrade
+    );
+}
+
+#[test]
+fn approval_thresholds_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let voter = community_member(&setup, &id);
+    let default_config = setup.contract.get_governance_config(&id);
+
+    for threshold in [4_000, 10_000] {
+        let err = setup
+            .contract
+            .try_create_action_proposal(
+                &setup.grogu,
+                &id,
+                &String::from_str(&setup.env, "Change the thresholds"),
+                &String::from_str(
+                    &setup.env,
+                    "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+                ),
+                &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+                &true,
+                &DaoAction::SetGovernanceConfig(GovernanceConfig {
+                    approval_thresholds: Map::from_array(
+                        &setup.env,
+                        [(ProposalKind::Upgrade, threshold)],
+                    ),
+                    ..default_config.clone()
+                }),
+            )
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, ContractErrors::InvalidGovernanceConfig.into());
+    }
+
+    let config = GovernanceConfig {
+        approval_thresholds: Map::from_array(
+            &setup.env,
+            [
+                (ProposalKind::Text, 6_600),
+                (ProposalKind::ConfigChange, 7_500),
+            ],
+        ),
+        ..default_config
+    };
+    let action = DaoAction::SetGovernanceConfig(config.clone());
+    let proposal_id = approved_action(&setup, &id, &voter, &action);
+    let vote_result = setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+    assert_eq!(vote_result, ProposalStatus::Approved);
+    assert_eq!(setup.contract.get_governance_config(&id), config);
+
+    // two thirds of the weight cast approve, the proposer abstains
+    let voting_ends_at = setup.env.ledger().timestamp() + 3600 * 24 * 2;
+    let proposal_id = setup.contract.create_proposal(
+        &setup.grogu,
+        &id,
+        &String::from_str(&setup.env, "Advisory proposal"),
+        &String::from_str(
+            &setup.env,
+            "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        ),
+        &voting_ends_at,
+        &true,
+        &None,
+    );
+    setup.contract.vote(
+        &voter,
+        &id,
+        &proposal_id,
+        &Vote::PublicVote(PublicVote {
+            address: voter.clone(),
+            weight: Badge::Community as u32,
+            vote_choice: VoteChoice::Approve,
+        }),
+    );
+    setup.env.ledger().set_timestamp(voting_ends_at + 1);
+    let vote_result = setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+    assert_eq!(vote_result, ProposalStatus::Approved);
+
+    // which is not enough for configuration changes
+    let license = String::from_str(&setup.env, "Apache-2.0");
+    let action = DaoAction::SetLicense(license.clone());
+    let proposal_id = approved_action(&setup, &id, &voter, &action);
+    let vote_result = setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+    assert_eq!(vote_result, ProposalStatus::Cancelled);
+    assert_ne!(
+        setup.contract.get_project(&id).config.license,
+        Some(license)
+    );
+}
+
+#[test]
+fn abstain_policy_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let voter = community_member(&setup, &id);
+    let default_config = setup.contract.get_governance_config(&id);
+
+    // create an advisory proposal which the proposer abstains on and the
+    // member approves
+    let text_proposal = || {
+        let voting_ends_at = setup.env.ledger().timestamp() + 3600 * 24 * 2;
+        let proposal_id = setup.contract.create_proposal(
+            &setup.grogu,
+            &id,
+            &String::from_str(&setup.env, "Advisory proposal"),
+            &String::from_str(
+                &setup.env,
+                "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+            ),
+            &voting_ends_at,
+            &true,
+            &None,
+        );
+        setup.contract.vote(
+            &voter,
+            &id,
+            &proposal_id,
+            &Vote::PublicVote(PublicVote {
+                address: voter.clone(),
+                weight: Badge::Community as u32,
+                vote_choice: VoteChoice::Approve,
+            }),
+        );
+        setup.env.ledger().set_timestamp(voting_ends_at + 1);
+        setup
+            .contract
+            .execute(&setup.grogu, &id, &proposal_id, &None, &None)
+    };
+
+    // two thirds of the weight cast approve
+    let thresholds = Map::from_array(&setup.env, [(ProposalKind::Text, 7_500)]);
+    let config = GovernanceConfig {
+        approval_thresholds: thresholds.clone(),
+        ..default_config.clone()
+    };
+    let proposal_id = approved_action(&setup, &id, &voter, &DaoAction::SetGovernanceConfig(config));
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+    assert_eq!(text_proposal(), ProposalStatus::Cancelled);
+
+    // all the weight cast approve when abstaining is ignored
+    let config = GovernanceConfig {
+        approval_thresholds: thresholds.clone(),
+        abstain_in_approval: false,
+        ..default_config.clone()
+    };
+    let proposal_id = approved_action(&setup, &id, &voter, &DaoAction::SetGovernanceConfig(config));
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+    assert_eq!(text_proposal(), ProposalStatus::Approved);
+
+    // the quorum is only reached with the abstain weight
+    let config = GovernanceConfig {
+        quorum: Badge::Community as u32 + 1,
+        approval_thresholds: thresholds,
+        abstain_in_quorum: false,
+        abstain_in_approval: false,
+        ..default_config
+    };
+    let proposal_id = approved_action(
+        &setup,
+        &id,
+        &voter,
+        &DaoAction::SetGovernanceConfig(config.clone()),
+    );
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+    assert_eq!(setup.contract.get_governance_config(&id), config);
+    assert_eq!(text_proposal(), ProposalStatus::Cancelled);
+}
+
+#[test]
+fn delegation_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let kuiil = community_member(&setup, &id);
+    let bossk = community_member(&setup, &id);
+    setup
+        .contract
+        .set_badges(&setup.mando, &id, &bossk, &vec![&setup.env, Badge::Triage]);
+    let weight = Badge::Community as u32 + Badge::Triage as u32;
+
+    let err = setup
+        .contract
+        .try_delegate(&bossk, &id, &Address::generate(&setup.env))
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnknownMember.into());
+    let err = setup
+        .contract
+        .try_delegate(&bossk, &id, &bossk)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::InvalidDelegation.into());
+
+    setup.contract.delegate(&bossk, &id, &kuiil);
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (
+                    Symbol::new(&setup.env, "vote_delegated"),
+                    id.clone(),
+                    bossk.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [(
+                        Symbol::new(&setup.env, "delegate"),
+                        Some(kuiil.clone()).into_val(&setup.env)
+                    )],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    assert_eq!(
+        setup.contract.get_delegations(&id),
+        Map::from_array(&setup.env, [(bossk.clone(), kuiil.clone())])
+    );
+
+    // delegations cannot be chained
+    let err = setup
+        .contract
+        .try_delegate(&kuiil, &id, &bossk)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::InvalidDelegation.into());
+
+    let voting_ends_at = setup.env.ledger().timestamp() + 3600 * 24 * 2;
+    let proposal_id = setup.contract.create_proposal(
+        &setup.grogu,
+        &id,
+        &String::from_str(&setup.env, "Delegated proposal"),
+        &String::from_str(
+            &setup.env,
+            "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        ),
+        &voting_ends_at,
+        &true,
+        &None,
+    );
+    let public_vote = |address: &Address, weight: u32| {
+        Vote::PublicVote(PublicVote {
+            address: address.clone(),
+            weight,
+            vote_choice: VoteChoice::Approve,
+        })
+    };
+
+    let err = setup
+        .contract
+        .try_vote(
+            &bossk,
+            &id,
+            &proposal_id,
+            &public_vote(&bossk, Badge::Triage as u32),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::VoteDelegated.into());
+
+    setup
+        .contract
+        .vote(&kuiil, &id, &proposal_id, &public_vote(&kuiil, weight));
+
+    // the weight of the member was already used on the proposal
+    setup.contract.undelegate(&bossk, &id);
+    assert!(setup.contract.get_delegations(&id).is_empty());
+    let err = setup
+        .contract
+        .try_vote(
+            &bossk,
+            &id,
+            &proposal_id,
+            &public_vote(&bossk, Badge::Triage as u32),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::AlreadyVoted.into());
+
+    let err = setup
+        .contract
+        .try_undelegate(&bossk, &id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::InvalidDelegation.into());
+
+    setup.env.ledger().set_timestamp(voting_ends_at + 1);
+    let vote_result = setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+    assert_eq!(vote_result, ProposalStatus::Approved);
+
+    // without the delegation the member votes with its own weight only
+    let voting_ends_at = setup.env.ledger().timestamp() + 3600 * 24 * 2;
+    let proposal_id = setup.contract.create_proposal(
+        &setup.grogu,
+        &id,
+        &String::from_str(&setup.env, "Undelegated proposal"),
+        &String::from_str(
+            &setup.env,
+            "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        ),
+        &voting_ends_at,
+        &true,
+        &None,
+    );
+    let err = setup
+        .contract
+        .try_vote(&kuiil, &id, &proposal_id, &public_vote(&kuiil, weight))
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::VoterWeight.into());
+    setup.contract.vote(
+        &bossk,
+        &id,
+        &proposal_id,
+        &public_vote(&bossk, Badge::Triage as u32),
+    );
+}
+
+#[test]
+fn vote_change_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let kuiil = community_member(&setup, &id);
+    let bossk = community_member(&setup, &id);
+
+    let voting_ends_at = setup.env.ledger().timestamp() + 3600 * 24 * 2;
+    let proposal_id = setup.contract.create_proposal(
+        &setup.grogu,
+        &id,
+        &String::from_str(&setup.env, "Changing minds"),
+        &String::from_str(
+            &setup.env,
+            "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        ),
+        &voting_ends_at,
+        &true,
+        &None,
+    );
+    let public_vote = |address: &Address, weight: u32, vote_choice: VoteChoice| PublicVote {
+        address: address.clone(),
+        weight,
+        vote_choice,
+    };
+    let weight = Badge::Community as u32;
+    setup.contract.vote(
+        &kuiil,
+        &id,
+        &proposal_id,
+        &Vote::PublicVote(public_vote(&kuiil, weight, VoteChoice::Approve)),
+    );
+
+    // the proposer stays in the abstain group
+    for voter in [&bossk, &setup.grogu] {
+        let err = setup
+            .contract
+            .try_change_vote(
+                voter,
+                &id,
+                &proposal_id,
+                &public_vote(voter, 1, VoteChoice::Approve),
+            )
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, ExtendedErrors::UnknownVote.into());
+    }
+    let err = setup
+        .contract
+        .try_change_vote(
+            &kuiil,
+            &id,
+            &proposal_id,
+            &public_vote(&kuiil, weight + 1, VoteChoice::Reject),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::VoterWeight.into());
+
+    let new_vote = public_vote(&kuiil, weight, VoteChoice::Reject);
+    setup
+        .contract
+        .change_vote(&kuiil, &id, &proposal_id, &new_vote);
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (Symbol::new(&setup.env, "vote_changed"), id.clone()).into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "proposal_id"),
+                            proposal_id.into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "voter"),
+                            kuiil.clone().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    let votes = setup
+        .contract
+        .get_proposal(&id, &proposal_id)
+        .vote_data
+        .votes;
+    assert_eq!(votes.len(), 2);
+    assert_eq!(votes.get_unchecked(1), Vote::PublicVote(new_vote.clone()));
+
+    setup.env.ledger().set_timestamp(voting_ends_at);
+    let err = setup
+        .contract
+        .try_change_vote(&kuiil, &id, &proposal_id, &new_vote)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProposalVotingTime.into());
+
+    let vote_result = setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+    assert_eq!(vote_result, ProposalStatus::Rejected);
+}
+
+#[test]
+fn early_execution_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let kuiil = community_member(&setup, &id);
+    let bossk = community_member(&setup, &id);
+    setup.contract.set_badges(
+        &setup.mando,
+        &id,
+        &kuiil,
+        &vec![&setup.env, Badge::Developer],
+    );
+
+    let create_proposal = || {
+        setup.contract.create_proposal(
+            &setup.grogu,
+            &id,
+            &String::from_str(&setup.env, "Decisive proposal"),
+            &String::from_str(
+                &setup.env,
+                "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+            ),
+            &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+            &true,
+            &None,
+        )
+    };
+    let vote = |voter: &Address, proposal_id: u32, weight: Badge, vote_choice: VoteChoice| {
+        setup.contract.vote(
+            voter,
+            &id,
+            &proposal_id,
+            &Vote::PublicVote(PublicVote {
+                address: voter.clone(),
+                weight: weight as u32,
+                vote_choice,
+            }),
+        );
+    };
+
+    let proposal_id = create_proposal();
+    assert_eq!(
+        setup
+            .contract
+            .get_proposal(&id, &proposal_id)
+            .vote_data
+            .eligible_weight,
+        Badge::Developer as u128 + Badge::Community as u128
+    );
+
+    // the developer could still reject
+    vote(&bossk, proposal_id, Badge::Community, VoteChoice::Approve);
+    let err = setup
+        .contract
+        .try_execute(&setup.grogu, &id, &proposal_id, &None, &None)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProposalVotingTime.into());
+
+    vote(&kuiil, proposal_id, Badge::Developer, VoteChoice::Approve);
+    let vote_result = setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+    assert_eq!(vote_result, ProposalStatus::Approved);
+
+    let proposal_id = create_proposal();
+    vote(&kuiil, proposal_id, Badge::Developer, VoteChoice::Reject);
+    let vote_result = setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+    assert_eq!(vote_result, ProposalStatus::Rejected);
+
+    // nothing is settled without votes
+    let proposal_id = create_proposal();
+    let err = setup
+        .contract
+        .try_execute(&setup.grogu, &id, &proposal_id, &None, &None)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProposalVotingTime.into());
+}
+
+#[test]
+fn proposal_cancellation_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let kuiil = community_member(&setup, &id);
+    let token_client = token::TokenClient::new(&setup.env, &setup.token_stellar.address);
+
+    let create_proposal = || {
+        setup.contract.create_proposal(
+            &setup.grogu,
+            &id,
+            &String::from_str(&setup.env, "Proposal with a typo"),
+            &String::from_str(
+                &setup.env,
+                "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+            ),
+            &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+            &true,
+            &None,
+        )
+    };
+    let vote = |proposal_id: u32| {
+        setup.contract.vote(
+            &kuiil,
+            &id,
+            &proposal_id,
+            &Vote::PublicVote(PublicVote {
+                address: kuiil.clone(),
+                weight: Badge::Community as u32,
+                vote_choice: VoteChoice::Approve,
+            }),
+        );
+    };
+
+    let balance = token_client.balance(&setup.grogu);
+    let proposal_id = create_proposal();
+    let err = setup
+        .contract
+        .try_cancel_proposal(&kuiil, &id, &proposal_id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+
+    setup
+        .contract
+        .cancel_proposal(&setup.grogu, &id, &proposal_id);
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        vec![&setup.env, all_events.last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (Symbol::new(&setup.env, "proposal_cancelled"), id.clone()).into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "proposal_id"),
+                            proposal_id.into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "proposer"),
+                            setup.grogu.clone().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    assert_eq!(
+        setup.contract.get_proposal(&id, &proposal_id).status,
+        ProposalStatus::Cancelled
+    );
+    assert_eq!(token_client.balance(&setup.grogu), balance);
+
+    let err = setup
+        .contract
+        .try_cancel_proposal(&setup.grogu, &id, &proposal_id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProposalActive.into());
+
+    // votes do not prevent the cancellation during the grace period
+    let balance = token_client.balance(&kuiil);
+    let proposal_id = create_proposal();
+    vote(proposal_id);
+    setup
+        .contract
+        .cancel_proposal(&setup.grogu, &id, &proposal_id);
+    assert_eq!(token_client.balance(&kuiil), balance);
+
+    let proposal_id = create_proposal();
+    vote(proposal_id);
+    setup
+        .env
+        .ledger()
+        .set_timestamp(setup.env.ledger().timestamp() + 3600);
+    let err = setup
+        .contract
+        .try_cancel_proposal(&setup.grogu, &id, &proposal_id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::ProposalNotCancellable.into());
+}
+
+#[test]
+fn veto_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let kuiil = community_member(&setup, &id);
+    let token_client = token::TokenClient::new(&setup.env, &setup.token_stellar.address);
+    let day = 3600 * 24;
+
+    let config = GovernanceConfig {
+        veto_period: day,
+        ..setup.contract.get_governance_config(&id)
+    };
+    let proposal_id = approved_action(&setup, &id, &kuiil, &DaoAction::SetGovernanceConfig(config));
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+
+    let reason = String::from_str(&setup.env, "Breaks the release process");
+    let proposal_id = approved_action(
+        &setup,
+        &id,
+        &kuiil,
+        &DaoAction::SetGovernanceConfig(GovernanceConfig {
+            veto_period: 0,
+            ..setup.contract.get_governance_config(&id)
+        }),
+    );
+    let err = setup
+        .contract
+        .try_execute(&setup.grogu, &id, &proposal_id, &None, &None)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProposalVotingTime.into());
+
+    let err = setup
+        .contract
+        .try_veto_proposal(&kuiil, &id, &proposal_id, &reason)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+    let err = setup
+        .contract
+        .try_veto_proposal(
+            &setup.grogu,
+            &id,
+            &proposal_id,
+            &String::from_str(&setup.env, ""),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::InvalidVeto.into());
+
+    let balance = token_client.balance(&kuiil);
+    assert!(
+        !setup
+            .contract
+            .veto_proposal(&setup.grogu, &id, &proposal_id, &reason)
+    );
+    let err = setup
+        .contract
+        .try_veto_proposal(&setup.grogu, &id, &proposal_id, &reason)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::InvalidVeto.into());
+
+    assert!(
+        setup
+            .contract
+            .veto_proposal(&setup.mando, &id, &proposal_id, &reason)
+    );
+    let (_, topics, _) = setup.env.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (Symbol::new(&setup.env, "proposal_executed"), id.clone()).into_val(&setup.env)
+    );
+    assert_eq!(
+        setup.contract.get_proposal(&id, &proposal_id).status,
+        ProposalStatus::Vetoed
+    );
+    assert_eq!(setup.contract.get_vetoes(&id, &proposal_id).len(), 2);
+    assert!(token_client.balance(&kuiil) > balance);
+    let err = setup
+        .contract
+        .try_execute(&setup.grogu, &id, &proposal_id, &None, &None)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProposalActive.into());
+
+    // approved proposals are executed once the veto period is over
+    let proposal_id = approved_action(
+        &setup,
+        &id,
+        &kuiil,
+        &DaoAction::SetGovernanceConfig(setup.contract.get_governance_config(&id)),
+    );
+    let voting_ends_at = setup
+        .contract
+        .get_proposal(&id, &proposal_id)
+        .vote_data
+        .voting_ends_at;
+    setup.env.ledger().set_timestamp(voting_ends_at + day);
+    let err = setup
+        .contract
+        .try_veto_proposal(&setup.grogu, &id, &proposal_id, &reason)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProposalVotingTime.into());
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+    assert_eq!(
+        setup.contract.get_proposal(&id, &proposal_id).status,
+        ProposalStatus::Approved
+    );
+}
+
+#[test]
+fn execution_window_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let kuiil = community_member(&setup, &id);
+    let day = 3600 * 24;
+
+    let err = setup
+        .contract
+        .try_create_action_proposal(
+            &setup.grogu,
+            &id,
+            &String::from_str(&setup.env, "Execute whenever"),
+            &String::from_str(
+                &setup.env,
+                "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+            ),
+            &(setup.env.ledger().timestamp() + 2 * day),
+            &true,
+            &DaoAction::SetGovernanceConfig(GovernanceConfig {
+                execution_window: 366 * day,
+                ..setup.contract.get_governance_config(&id)
+            }),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidGovernanceConfig.into());
+
+    let config = GovernanceConfig {
+        veto_period: day,
+        execution_window: day,
+        ..setup.contract.get_governance_config(&id)
+    };
+    let proposal_id = approved_action(&setup, &id, &kuiil, &DaoAction::SetGovernanceConfig(config));
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+
+    // the window starts after the veto period
+    let config = GovernanceConfig {
+        quorum: 1,
+        ..setup.contract.get_governance_config(&id)
+    };
+    let proposal_id = approved_action(
+        &setup,
+        &id,
+        &kuiil,
+        &DaoAction::SetGovernanceConfig(config.clone()),
+    );
+    let voting_ends_at = setup
+        .contract
+        .get_proposal(&id, &proposal_id)
+        .vote_data
+        .voting_ends_at;
+    setup.env.ledger().set_timestamp(voting_ends_at + 2 * day);
+    let vote_result = setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+    assert_eq!(vote_result, ProposalStatus::Approved);
+    assert_eq!(setup.contract.get_governance_config(&id), config);
+
+    // stale approvals expire without applying their action
+    let proposal_id = approved_action(
+        &setup,
+        &id,
+        &kuiil,
+        &DaoAction::SetGovernanceConfig(GovernanceConfig {
+            quorum: 2,
+            ..config.clone()
+        }),
+    );
+    let voting_ends_at = setup
+        .contract
+        .get_proposal(&id, &proposal_id)
+        .vote_data
+        .voting_ends_at;
+    setup
+        .env
+        .ledger()
+        .set_timestamp(voting_ends_at + 2 * day + 1);
+    let vote_result = setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+    assert_eq!(vote_result, ProposalStatus::Expired);
+    assert_eq!(
+        setup.contract.get_proposal(&id, &proposal_id).status,
+        ProposalStatus::Expired
+    );
+    assert_eq!(setup.contract.get_governance_config(&id), config);
+
+    let err = setup
+        .contract
+        .try_execute(&setup.grogu, &id, &proposal_id, &None, &None)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProposalActive.into());
+}
+
+#[test]
+fn emergency_proposal_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let kuiil = community_member(&setup, &id);
+    let bossk = community_member(&setup, &id);
+    setup.contract.set_badges(
+        &setup.mando,
+        &id,
+        &bossk,
+        &vec![&setup.env, Badge::Verified],
+    );
+    let title = String::from_str(&setup.env, "Yank a compromised release");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let hour = 3600;
+
+    let create = |cosigner: &Address, voting_ends_at: u64| {
+        setup.contract.try_create_emergency_proposal(
+            &setup.grogu,
+            cosigner,
+            &id,
+            &title,
+            &ipfs,
+            &voting_ends_at,
+            &None,
+        )
+    };
+    let err = create(&setup.mando, setup.env.ledger().timestamp() + hour)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::EmergencyDisabled.into());
+
+    let default_config = setup.contract.get_governance_config(&id);
+    for config in [
+        GovernanceConfig {
+            emergency_voting_period: hour,
+            emergency_threshold: 5_000,
+            ..default_config.clone()
+        },
+        GovernanceConfig {
+            emergency_voting_period: 2 * default_config.min_voting_period,
+            emergency_threshold: 7_000,
+            ..default_config.clone()
+        },
+    ] {
+        let err = setup
+            .contract
+            .try_create_action_proposal(
+                &setup.grogu,
+                &id,
+                &title,
+                &ipfs,
+                &(setup.env.ledger().timestamp() + 2 * default_config.min_voting_period),
+                &true,
+                &DaoAction::SetGovernanceConfig(config),
+            )
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, ContractErrors::InvalidGovernanceConfig.into());
+    }
+
+    let config = GovernanceConfig {
+        emergency_voting_period: hour,
+        emergency_threshold: 7_000,
+        ..default_config.clone()
+    };
+    let proposal_id = approved_action(
+        &setup,
+        &id,
+        &kuiil,
+        &DaoAction::SetGovernanceConfig(config.clone()),
+    );
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+
+    // a maintainer other than the proposer co-signs
+    let voting_ends_at = setup.env.ledger().timestamp() + hour;
+    for cosigner in [&setup.grogu, &kuiil] {
+        let err = create(cosigner, voting_ends_at).unwrap_err().unwrap();
+        assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+    }
+    let err = create(&setup.mando, voting_ends_at - 1)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProposalInputValidation.into());
+
+    // regular proposals keep their voting period
+    let err = setup
+        .contract
+        .try_create_proposal(
+            &setup.grogu,
+            &id,
+            &title,
+            &ipfs,
+            &voting_ends_at,
+            &true,
+            &None,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProposalInputValidation.into());
+
+    let proposal_id = create(&setup.mando, voting_ends_at).unwrap().unwrap();
+    let proposal = setup.contract.get_proposal(&id, &proposal_id);
+    assert_eq!(proposal.cosigner, Some(setup.mando.clone()));
+    assert_eq!(proposal.kind, ProposalKind::Text);
+
+    // two thirds of the weight do not reach the emergency threshold
+    setup.contract.vote(
+        &kuiil,
+        &id,
+        &proposal_id,
+        &Vote::PublicVote(PublicVote {
+            address: kuiil.clone(),
+            weight: Badge::Community as u32,
+            vote_choice: VoteChoice::Approve,
+        }),
+    );
+    setup.env.ledger().set_timestamp(voting_ends_at + 1);
+    let vote_result = setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+    assert_eq!(vote_result, ProposalStatus::Cancelled);
+
+    // only incident actions can be rushed
+    let voting_ends_at = setup.env.ledger().timestamp() + hour;
+    let err = setup
+        .contract
+        .try_create_emergency_proposal(
+            &setup.grogu,
+            &setup.mando,
+            &id,
+            &title,
+            &ipfs,
+            &voting_ends_at,
+            &Some(DaoAction::SetGovernanceConfig(GovernanceConfig {
+                quorum: 1,
+                ..config
+            })),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidDaoAction.into());
+
+    let tag = String::from_str(&setup.env, "v1.0.0");
+    setup.registry.create_release(
+        &setup.mando,
+        &id,
+        &tag,
+        &String::from_str(&setup.env, "6b1b0e8ac7f7d4e2c4e7d8f1a9b3c5d7e9f1a3b5"),
+        &ipfs,
+        &ipfs,
+        &registry::types::ReleaseChannel::Stable,
+        &None,
+    );
+    let yank = DaoAction::YankRelease(tag.clone(), ipfs.clone());
+    let err = setup
+        .contract
+        .try_create_emergency_proposal(
+            &setup.grogu,
+            &setup.mando,
+            &id,
+            &title,
+            &ipfs,
+            &voting_ends_at,
+            &Some(yank.clone()),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidDaoAction.into());
+
+    setup.contract.renounce_to_dao(&setup.grogu, &id);
+    setup.contract.renounce_to_dao(&setup.mando, &id);
+    let proposal_id = setup.contract.create_emergency_proposal(
+        &setup.grogu,
+        &setup.mando,
+        &id,
+        &title,
+        &ipfs,
+        &voting_ends_at,
+        &Some(yank),
+    );
+    assert_eq!(
+        setup.contract.get_proposal(&id, &proposal_id).kind,
+        ProposalKind::Upgrade
+    );
+    for (voter, weight) in [
+        (&kuiil, Badge::Community as u32),
+        (&bossk, Badge::Verified as u32),
+    ] {
+        setup.contract.vote(
+            voter,
+            &id,
+            &proposal_id,
+            &Vote::PublicVote(PublicVote {
+                address: voter.clone(),
+                weight,
+                vote_choice: VoteChoice::Approve,
+            }),
+        );
+    }
+    setup.env.ledger().set_timestamp(voting_ends_at + 1);
+    let vote_result = setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+    assert_eq!(vote_result, ProposalStatus::Approved);
+    let release = setup.registry.get_release(&id, &tag);
+    assert_eq!(release.yanked, Some(ipfs.clone()));
+    assert_eq!(
+        setup
+            .registry
+            .get_channel_head(&id, &registry::types::ReleaseChannel::Stable),
+        None
+    );
+}
+
+#[test]
+fn proposal_deposit_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let kuiil = community_member(&setup, &id);
+    let token_client = token::TokenClient::new(&setup.env, &setup.token_stellar.address);
+    let deposit = 50 * 10_000_000;
+
+    let title = String::from_str(&setup.env, "Require a deposit");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let err = setup
+        .contract
+        .try_create_action_proposal(
+            &setup.grogu,
+            &id,
+            &title,
+            &ipfs,
+            &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+            &true,
+            &DaoAction::SetGovernanceConfig(GovernanceConfig {
+                proposal_deposit: -1,
+                ..setup.contract.get_governance_config(&id)
+            }),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidGovernanceConfig.into());
+
+    // the proposer abstain weight alone is below 60% of the quorum
+    let config = GovernanceConfig {
+        quorum: Badge::Community as u32,
+        proposal_deposit: deposit,
+        spam_quorum_bps: 6_000,
+        ..setup.contract.get_governance_config(&id)
+    };
+    let proposal_id = approved_action(&setup, &id, &kuiil, &DaoAction::SetGovernanceConfig(config));
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+
+    let create_proposal = || {
+        setup.contract.create_proposal(
+            &setup.grogu,
+            &id,
+            &title,
+            &ipfs,
+            &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+            &true,
+            &None,
+        )
+    };
+    let execute = |proposal_id: u32| {
+        let voting_ends_at = setup
+            .contract
+            .get_proposal(&id, &proposal_id)
+            .vote_data
+            .voting_ends_at;
+        setup.env.ledger().set_timestamp(voting_ends_at + 1);
+        setup
+            .contract
+            .execute(&setup.grogu, &id, &proposal_id, &None, &None)
+    };
+
+    // refunded when the quorum is reached
+    let balance = token_client.balance(&setup.grogu);
+    let proposal_id = create_proposal();
+    assert_eq!(
+        setup.contract.get_proposal(&id, &proposal_id).deposit,
+        deposit
+    );
+    assert_eq!(
+        token_client.balance(&setup.grogu),
+        balance - deposit - 110 * 10_000_000
+    );
+    setup.contract.vote(
+        &kuiil,
+        &id,
+        &proposal_id,
+        &Vote::PublicVote(PublicVote {
+            address: kuiil.clone(),
+            weight: Badge::Community as u32,
+            vote_choice: VoteChoice::Approve,
+        }),
+    );
+    assert_eq!(execute(proposal_id), ProposalStatus::Approved);
+    assert_eq!(token_client.balance(&setup.grogu), balance);
+
+    // refunded when the proposer cancels
+    let proposal_id = create_proposal();
+    setup
+        .contract
+        .cancel_proposal(&setup.grogu, &id, &proposal_id);
+    assert_eq!(token_client.balance(&setup.grogu), balance);
+    assert!(setup.contract.get_treasury(&id).is_empty());
+
+    // slashed to the treasury when nobody votes
+    let proposal_id = create_proposal();
+    assert_eq!(execute(proposal_id), ProposalStatus::Cancelled);
+    assert_eq!(token_client.balance(&setup.grogu), balance - deposit);
+    assert_eq!(
+        setup.contract.get_treasury(&id),
+        Map::from_array(&setup.env, [(setup.token_stellar.address.clone(), deposit)])
+    );
+
+    // slashed when revoked
+    let proposal_id = create_proposal();
+    setup
+        .contract
+        .revoke_proposal(&setup.grogu, &id, &proposal_id);
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        vec![&setup.env, all_events.get(all_events.len() - 2).unwrap()],
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (Symbol::new(&setup.env, "deposit_slashed"), id.clone()).into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "amount"),
+                            deposit.into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "proposal_id"),
+                            proposal_id.into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "proposer"),
+                            setup.grogu.clone().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    assert_eq!(
+        setup
+            .contract
+            .get_treasury(&id)
+            .get(setup.token_stellar.address.clone()),
+        Some(2 * deposit)
+    );
+}
+
+#[test]
+fn proposal_cooldown_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let kuiil = community_member(&setup, &id);
+
+    let config = GovernanceConfig {
+        proposal_cooldown: 100,
+        ..setup.contract.get_governance_config(&id)
+    };
+    let proposal_id = approved_action(&setup, &id, &kuiil, &DaoAction::SetGovernanceConfig(config));
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+
+    let create_proposal = |proposer: &Address| {
+        setup.contract.try_create_proposal(
+            proposer,
+            &id,
+            &String::from_str(&setup.env, "Flooding the DAO"),
+            &String::from_str(
+                &setup.env,
+                "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+            ),
+            &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+            &true,
+            &None,
+        )
+    };
+
+    setup.env.ledger().set_sequence_number(1_000);
+    assert!(create_proposal(&setup.grogu).is_ok());
+    setup.env.ledger().set_sequence_number(1_099);
+    let err = create_proposal(&setup.grogu).unwrap_err().unwrap();
+    assert_eq!(err, ExtendedErrors::ProposalCooldown.into());
+
+    // the cooldown is per proposer
+    setup.token_stellar.mint(&kuiil, &(200 * 10_000_000));
+    assert!(create_proposal(&kuiil).is_ok());
+
+    setup.env.ledger().set_sequence_number(1_100);
+    assert!(create_proposal(&setup.grogu).is_ok());
+}
+
+#[test]
+fn proposal_amendment_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let kuiil = community_member(&setup, &id);
+
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let amended_ipfs = String::from_str(
+        &setup.env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    let created_at = setup.env.ledger().timestamp();
+    let proposal_id = setup.contract.create_proposal(
+        &setup.grogu,
+        &id,
+        &String::from_str(&setup.env, "Proposal with a typo"),
+        &ipfs,
+        &(created_at + 3600 * 24 * 2),
+        &true,
+        &None,
+    );
+    assert!(setup.contract.get_revisions(&id, &proposal_id).is_empty());
+
+    let err = setup
+        .contract
+        .try_amend_proposal(&kuiil, &id, &proposal_id, &amended_ipfs)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+    let err = setup
+        .contract
+        .try_amend_proposal(&setup.grogu, &id, &proposal_id, &ipfs)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::ProposalNotAmendable.into());
+
+    setup.env.ledger().set_timestamp(created_at + 60);
+    assert_eq!(
+        setup
+            .contract
+            .amend_proposal(&setup.grogu, &id, &proposal_id, &amended_ipfs),
+        1
+    );
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (Symbol::new(&setup.env, "proposal_amended"), id.clone()).into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "ipfs"),
+                            amended_ipfs.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "proposal_id"),
+                            proposal_id.into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "revision"),
+                            1u32.into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    assert_eq!(
+        setup.contract.get_proposal(&id, &proposal_id).ipfs,
+        amended_ipfs
+    );
+    assert_eq!(
+        setup.contract.get_revisions(&id, &proposal_id),
+        vec![
+            &setup.env,
+            Revision {
+                ipfs: ipfs.clone(),
+                amended_at: created_at,
+            },
+            Revision {
+                ipfs: amended_ipfs.clone(),
+                amended_at: created_at + 60,
+            },
+        ]
+    );
+
+    // votes do not prevent amendments during the window
+    setup.contract.vote(
+        &kuiil,
+        &id,
+        &proposal_id,
+        &Vote::PublicVote(PublicVote {
+            address: kuiil.clone(),
+            weight: Badge::Community as u32,
+            vote_choice: VoteChoice::Approve,
+        }),
+    );
+    assert_eq!(
+        setup
+            .contract
+            .amend_proposal(&setup.grogu, &id, &proposal_id, &ipfs),
+        2
+    );
+
+    setup.env.ledger().set_timestamp(created_at + 3600);
+    let err = setup
+        .contract
+        .try_amend_proposal(&setup.grogu, &id, &proposal_id, &amended_ipfs)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::ProposalNotAmendable.into());
+    assert_eq!(setup.contract.get_revisions(&id, &proposal_id).len(), 3);
+}
+
+#[test]
+fn proposal_dependency_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let kuiil = community_member(&setup, &id);
+
+    let voting_ends_at = setup.env.ledger().timestamp() + 3600 * 24 * 2;
+    let create_proposal = || {
+        setup.contract.create_proposal(
+            &setup.grogu,
+            &id,
+            &String::from_str(&setup.env, "Step of a program"),
+            &String::from_str(
+                &setup.env,
+                "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+            ),
+            &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+            &true,
+            &None,
+        )
+    };
+    let vote = |proposal_id: u32, vote_choice: VoteChoice| {
+        setup.contract.vote(
+            &kuiil,
+            &id,
+            &proposal_id,
+            &Vote::PublicVote(PublicVote {
+                address: kuiil.clone(),
+                weight: Badge::Community as u32,
+                vote_choice,
+            }),
+        );
+    };
+
+    let first_step = create_proposal();
+    let second_step = create_proposal();
+    let err = setup
+        .contract
+        .try_link_proposal(&setup.grogu, &id, &first_step, &second_step)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::InvalidProposalDependency.into());
+    let err = setup
+        .contract
+        .try_link_proposal(&kuiil, &id, &second_step, &first_step)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+
+    setup
+        .contract
+        .link_proposal(&setup.grogu, &id, &second_step, &first_step);
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (Symbol::new(&setup.env, "proposal_linked"), id.clone()).into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "depends_on"),
+                            first_step.into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "proposal_id"),
+                            second_step.into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    assert_eq!(
+        setup.contract.get_proposal(&id, &second_step).depends_on,
+        Some(first_step)
+    );
+
+    let rejected_step = create_proposal();
+    let cancelled_step = create_proposal();
+    setup
+        .contract
+        .link_proposal(&setup.grogu, &id, &cancelled_step, &rejected_step);
+
+    vote(first_step, VoteChoice::Approve);
+    vote(second_step, VoteChoice::Approve);
+    vote(rejected_step, VoteChoice::Reject);
+    vote(cancelled_step, VoteChoice::Approve);
+    setup.env.ledger().set_timestamp(voting_ends_at + 1);
+
+    let err = setup
+        .contract
+        .try_execute(&setup.grogu, &id, &second_step, &None, &None)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::DependencyPending.into());
+    for (proposal_id, status) in [
+        (first_step, ProposalStatus::Approved),
+        (second_step, ProposalStatus::Approved),
+        (rejected_step, ProposalStatus::Rejected),
+        (cancelled_step, ProposalStatus::Cancelled),
+    ] {
+        assert_eq!(
+            setup
+                .contract
+                .execute(&setup.grogu, &id, &proposal_id, &None, &None),
+            status
+        );
+    }
+
+    // failed proposals cannot be depended on
+    let proposal_id = create_proposal();
+    let err = setup
+        .contract
+        .try_link_proposal(&setup.grogu, &id, &proposal_id, &rejected_step)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::InvalidProposalDependency.into());
+}
+
+#[test]
+fn recurring_proposal_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let kuiil = community_member(&setup, &id);
+    setup.token_stellar.mint(&kuiil, &(200 * 10_000_000));
+
+    let day = 3600 * 24;
+    let action = DaoAction::SetGovernanceConfig(setup.contract.get_governance_config(&id));
+    let template = ProposalTemplate {
+        title: String::from_str(&setup.env, "Monthly governance review"),
+        ipfs: String::from_str(
+            &setup.env,
+            "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        ),
+        voting_period: 2 * day,
+        public_voting: true,
+        action: TemplateAction::Action(action.clone()),
+        interval: 30 * day,
+        next_at: setup.env.ledger().timestamp() + day,
+    };
+
+    let err = setup
+        .contract
+        .try_add_proposal_template(&kuiil, &id, &template)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+    let err = setup
+        .contract
+        .try_add_proposal_template(
+            &setup.grogu,
+            &id,
+            &ProposalTemplate {
+                interval: day - 1,
+                ..template.clone()
+            },
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::InvalidTemplate.into());
+
+    let template_id = setup
+        .contract
+        .add_proposal_template(&setup.grogu, &id, &template);
+    assert_eq!(template_id, 0);
+    assert_eq!(
+        setup.contract.get_proposal_templates(&id),
+        Map::from_array(&setup.env, [(template_id, template.clone())])
+    );
+
+    // nothing is due yet
+    assert!(setup.contract.tick(&kuiil, &id).is_empty());
+
+    setup.env.ledger().set_timestamp(template.next_at);
+    let proposal_ids = setup.contract.tick(&kuiil, &id);
+    assert_eq!(proposal_ids.len(), 1);
+    let proposal = setup
+        .contract
+        .get_proposal(&id, &proposal_ids.get(0).unwrap());
+    assert_eq!(proposal.title, template.title);
+    assert_eq!(proposal.proposer, kuiil);
+    assert_eq!(proposal.kind, ProposalKind::ConfigChange);
+    assert_eq!(
+        proposal.vote_data.voting_ends_at,
+        template.next_at + 2 * day
+    );
+    assert_eq!(
+        setup
+            .contract
+            .get_proposal_action(&id, &proposal_ids.get(0).unwrap()),
+        Some(action)
+    );
+    assert!(setup.contract.tick(&kuiil, &id).is_empty());
+
+    // missed occurrences are skipped
+    setup
+        .env
+        .ledger()
+        .set_timestamp(template.next_at + 3 * 30 * day + day);
+    assert_eq!(setup.contract.tick(&setup.grogu, &id).len(), 1);
+    assert_eq!(
+        setup
+            .contract
+            .get_proposal_templates(&id)
+            .get(template_id)
+            .unwrap()
+            .next_at,
+        template.next_at + 4 * 30 * day
+    );
+
+    setup
+        .contract
+        .remove_proposal_template(&setup.grogu, &id, &template_id);
+    assert!(setup.contract.get_proposal_templates(&id).is_empty());
+    let err = setup
+        .contract
+        .try_remove_proposal_template(&setup.grogu, &id, &template_id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::UnknownTemplate.into());
+}
+
+#[test]
+fn multiple_choice_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let kuiil = community_member(&setup, &id);
+    let bossk = community_member(&setup, &id);
+
+    let title = String::from_str(&setup.env, "Pick the next logo");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let options = vec![
+        &setup.env,
+        String::from_str(&setup.env, "Mudhorn"),
+        String::from_str(&setup.env, "Beskar"),
+        String::from_str(&setup.env, "Grogu"),
+    ];
+    let create_proposal = |options: &Vec<String>| {
+        setup.contract.try_create_multiple_choice_proposal(
+            &setup.grogu,
+            &id,
+            &title,
+            &ipfs,
+            &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+            options,
+        )
+    };
+    let vote = |voter: &Address, proposal_id: u32, vote_choice: VoteChoice| {
+        setup.contract.try_vote(
+            voter,
+            &id,
+            &proposal_id,
+            &Vote::PublicVote(PublicVote {
+                address: voter.clone(),
+                weight: Badge::Community as u32,
+                vote_choice,
+            }),
+        )
+    };
+    let execute = |proposal_id: u32| {
+        let voting_ends_at = setup
+            .contract
+            .get_proposal(&id, &proposal_id)
+            .vote_data
+            .voting_ends_at;
+        setup.env.ledger().set_timestamp(voting_ends_at);
+        setup
+            .contract
+            .execute(&setup.grogu, &id, &proposal_id, &None, &None)
+    };
+
+    for invalid_options in [
+        vec![&setup.env, String::from_str(&setup.env, "Mudhorn")],
+        vec![
+            &setup.env,
+            String::from_str(&setup.env, "Mudhorn"),
+            String::from_str(&setup.env, "Mudhorn"),
+        ],
+        vec![
+            &setup.env,
+            String::from_str(&setup.env, "Mudhorn"),
+            String::from_str(&setup.env, ""),
+        ],
+    ] {
+        let err = create_proposal(&invalid_options).unwrap_err().unwrap();
+        assert_eq!(err, ContractErrors::ProposalInputValidation.into());
+    }
+
+    let proposal_id = create_proposal(&options).unwrap().unwrap();
+    assert_eq!(
+        setup.contract.get_proposal(&id, &proposal_id).options,
+        options
+    );
+    for vote_choice in [VoteChoice::Approve, VoteChoice::Choice(3)] {
+        let err = vote(&kuiil, proposal_id, vote_choice).unwrap_err().unwrap();
+        assert_eq!(err, ExtendedErrors::InvalidVoteOption.into());
+    }
+    vote(&kuiil, proposal_id, VoteChoice::Choice(1))
+        .unwrap()
+        .unwrap();
+    vote(&bossk, proposal_id, VoteChoice::Choice(2))
+        .unwrap()
+        .unwrap();
+
+    // early execution is not possible with multiple options
+    let err = setup
+        .contract
+        .try_execute(&setup.grogu, &id, &proposal_id, &None, &None)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProposalVotingTime.into());
+
+    setup.contract.change_vote(
+        &bossk,
+        &id,
+        &proposal_id,
+        &PublicVote {
+            address: bossk.clone(),
+            weight: Badge::Community as u32,
+            vote_choice: VoteChoice::Choice(1),
+        },
+    );
+    assert_eq!(execute(proposal_id), ProposalStatus::Approved);
+    assert_eq!(
+        setup
+            .contract
+            .get_proposal(&id, &proposal_id)
+            .winning_option,
+        Some(1)
+    );
+
+    // a tie has no winner
+    let proposal_id = create_proposal(&options).unwrap().unwrap();
+    vote(&kuiil, proposal_id, VoteChoice::Choice(0))
+        .unwrap()
+        .unwrap();
+    vote(&bossk, proposal_id, VoteChoice::Choice(2))
+        .unwrap()
+        .unwrap();
+    assert_eq!(execute(proposal_id), ProposalStatus::Cancelled);
+    assert_eq!(
+        setup
+            .contract
+            .get_proposal(&id, &proposal_id)
+            .winning_option,
+        None
+    );
+
+    // options cannot be chosen on regular proposals
+    let proposal_id = setup.contract.create_proposal(
+        &setup.grogu,
+        &id,
+        &title,
+        &ipfs,
+        &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+        &true,
+        &None,
+    );
+    let err = vote(&kuiil, proposal_id, VoteChoice::Choice(0))
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::InvalidVoteOption.into());
+}
+
+#[test]
+fn quadratic_voting_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let whale = community_member(&setup, &id);
+    setup.contract.set_badges(
+        &setup.mando,
+        &id,
+        &whale,
+        &vec![&setup.env, Badge::Developer],
+    );
+    let members: [Address; 4] = core::array::from_fn(|_| community_member(&setup, &id));
+
+    let vote = |voter: &Address, proposal_id: u32, weight: Badge, vote_choice: VoteChoice| {
+        setup.contract.vote(
+            voter,
+            &id,
+            &proposal_id,
+            &Vote::PublicVote(PublicVote {
+                address: voter.clone(),
+                weight: weight as u32,
+                vote_choice,
+            }),
+        );
+    };
+    let execute = |votes: &[(&Address, Badge, VoteChoice)]| {
+        let voting_ends_at = setup.env.ledger().timestamp() + 3600 * 24 * 2;
+        let proposal_id = setup.contract.create_proposal(
+            &setup.grogu,
+            &id,
+            &String::from_str(&setup.env, "Whale against the community"),
+            &String::from_str(
+                &setup.env,
+                "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+            ),
+            &voting_ends_at,
+            &true,
+            &None,
+        );
+        for (voter, weight, vote_choice) in votes {
+            vote(voter, proposal_id, weight.clone(), vote_choice.clone());
+        }
+        setup.env.ledger().set_timestamp(voting_ends_at);
+        setup
+            .contract
+            .execute(&setup.grogu, &id, &proposal_id, &None, &None)
+    };
+
+    // the whale outweighs four members, but not their square roots
+    let whale_votes = [
+        (&whale, Badge::Developer, VoteChoice::Approve),
+        (&members[0], Badge::Community, VoteChoice::Reject),
+        (&members[1], Badge::Community, VoteChoice::Reject),
+        (&members[2], Badge::Community, VoteChoice::Reject),
+        (&members[3], Badge::Community, VoteChoice::Reject),
+    ];
+    // equal weights tally the same in both modes
+    let equal_votes = [
+        (&members[0], Badge::Community, VoteChoice::Approve),
+        (&members[1], Badge::Community, VoteChoice::Approve),
+        (&members[2], Badge::Community, VoteChoice::Reject),
+    ];
+
+    assert_eq!(execute(&whale_votes), ProposalStatus::Approved);
+    assert_eq!(execute(&equal_votes), ProposalStatus::Approved);
+
+    let config = GovernanceConfig {
+        quadratic_voting: true,
+        ..setup.contract.get_governance_config(&id)
+    };
+    let proposal_id = approved_action(
+        &setup,
+        &id,
+        &members[3],
+        &DaoAction::SetGovernanceConfig(config),
+    );
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+
+    assert_eq!(execute(&whale_votes), ProposalStatus::Rejected);
+    assert_eq!(execute(&equal_votes), ProposalStatus::Approved);
+}
+
+#[test]
+fn voter_weight_cap_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let whale = community_member(&setup, &id);
+    setup.contract.set_badges(
+        &setup.mando,
+        &id,
+        &whale,
+        &vec![&setup.env, Badge::Developer],
+    );
+    let members: [Address; 4] = core::array::from_fn(|_| community_member(&setup, &id));
+
+    let new_proposal = || {
+        setup.contract.create_proposal(
+            &setup.grogu,
+            &id,
+            &String::from_str(&setup.env, "Whale against the community"),
+            &String::from_str(
+                &setup.env,
+                "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+            ),
+            &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+            &true,
+            &None,
+        )
+    };
+    let vote = |proposal_id: u32, voter: &Address, weight: u32, vote_choice: VoteChoice| {
+        setup.contract.try_vote(
+            voter,
+            &id,
+            &proposal_id,
+            &Vote::PublicVote(PublicVote {
+                address: voter.clone(),
+                weight,
+                vote_choice,
+            }),
+        )
+    };
+    let end_vote = |proposal_id: u32| {
+        let voting_ends_at = setup
+            .contract
+            .get_proposal(&id, &proposal_id)
+            .vote_data
+            .voting_ends_at;
+        setup.env.ledger().set_timestamp(voting_ends_at);
+    };
+    let voted_proposal = |votes: &[(&Address, Badge, VoteChoice)]| {
+        let proposal_id = new_proposal();
+        for (voter, weight, vote_choice) in votes {
+            vote(
+                proposal_id,
+                voter,
+                weight.clone() as u32,
+                vote_choice.clone(),
+            )
+            .unwrap()
+            .unwrap();
+        }
+        end_vote(proposal_id);
+        proposal_id
+    };
+    let execute = |proposal_id: u32| {
+        setup
+            .contract
+            .execute(&setup.grogu, &id, &proposal_id, &None, &None)
+    };
+
+    // the whale outweighs four members
+    let whale_votes = [
+        (&whale, Badge::Developer, VoteChoice::Approve),
+        (&members[0], Badge::Community, VoteChoice::Reject),
+        (&members[1], Badge::Community, VoteChoice::Reject),
+        (&members[2], Badge::Community, VoteChoice::Reject),
+        (&members[3], Badge::Community, VoteChoice::Reject),
+    ];
+    // weights under the cap are not affected
+    let equal_votes = [
+        (&members[0], Badge::Community, VoteChoice::Approve),
+        (&members[1], Badge::Community, VoteChoice::Approve),
+        (&members[2], Badge::Community, VoteChoice::Reject),
+    ];
+    assert_eq!(
+        execute(voted_proposal(&whale_votes)),
+        ProposalStatus::Approved
+    );
+    assert_eq!(
+        execute(voted_proposal(&equal_votes)),
+        ProposalStatus::Approved
+    );
+
+    let config = setup.contract.get_governance_config(&id);
+    let err = setup
+        .contract
+        .try_create_action_proposal(
+            &setup.grogu,
+            &id,
+            &String::from_str(&setup.env, "Cap the weight of voters"),
+            &String::from_str(
+                &setup.env,
+                "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+            ),
+            &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+            &true,
+            &DaoAction::SetGovernanceConfig(GovernanceConfig {
+                max_voter_weight_bps: 10_001,
+                ..config.clone()
+            }),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidGovernanceConfig.into());
+
+    let proposal_id = approved_action(
+        &setup,
+        &id,
+        &members[3],
+        &DaoAction::SetGovernanceConfig(GovernanceConfig {
+            max_voter_weight_bps: 2_000,
+            ..config
+        }),
+    );
+    execute(proposal_id);
+
+    // a fifth of the eligible weight at most, checked when voting
+    let proposal_id = new_proposal();
+    let eligible_weight = setup
+        .contract
+        .get_proposal(&id, &proposal_id)
+        .vote_data
+        .eligible_weight;
+    assert_eq!(
+        eligible_weight,
+        (Badge::Developer as u32 + 4 * Badge::Community as u32) as u128
+    );
+    let cap = (eligible_weight / 5) as u32;
+    let err = vote(
+        proposal_id,
+        &whale,
+        Badge::Developer as u32,
+        VoteChoice::Approve,
+    )
+    .unwrap_err()
+    .unwrap();
+    assert_eq!(err, ContractErrors::VoterWeight.into());
+    vote(proposal_id, &whale, cap, VoteChoice::Approve)
+        .unwrap()
+        .unwrap();
+    for member in &members {
+        vote(
+            proposal_id,
+            member,
+            Badge::Community as u32,
+            VoteChoice::Reject,
+        )
+        .unwrap()
+        .unwrap();
+    }
+    end_vote(proposal_id);
+    assert_eq!(
+        setup.contract.tally(&id, &proposal_id),
+        Tally {
+            approve: cap as u128,
+            reject: 4 * Badge::Community as u128,
+            abstain: Badge::Verified as u128,
+        }
+    );
+    assert_eq!(execute(proposal_id), ProposalStatus::Rejected);
+    assert_eq!(
+        execute(voted_proposal(&equal_votes)),
+        ProposalStatus::Approved
+    );
+
+    // the cap applies to each delegating member
+    setup.contract.delegate(&whale, &id, &members[0]);
+    let proposal_id = new_proposal();
+    let err = vote(
+        proposal_id,
+        &members[0],
+        (Badge::Community as u32) + (Badge::Developer as u32),
+        VoteChoice::Approve,
+    )
+    .unwrap_err()
+    .unwrap();
+    assert_eq!(err, ContractErrors::VoterWeight.into());
+    vote(
+        proposal_id,
+        &members[0],
+        Badge::Community as u32 + cap,
+        VoteChoice::Approve,
+    )
+    .unwrap()
+    .unwrap();
+}
+
+#[test]
+fn token_voting_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let holder = community_member(&setup, &id);
+    let member = community_member(&setup, &id);
+
+    let sac = setup
+        .env
+        .register_stellar_asset_contract_v2(Address::generate(&setup.env));
+    let voting_token = token::StellarAssetClient::new(&setup.env, &sac.address());
+    voting_token.mint(&holder, &(5 * 10_000_000));
+
+    let proposal = |voting_weight: VotingWeight| {
+        let config = GovernanceConfig {
+            voting_weight,
+            ..setup.contract.get_governance_config(&id)
+        };
+        setup
+            .contract
+            .try_create_action_proposal(
+                &setup.grogu,
+                &id,
+                &String::from_str(&setup.env, "Vote with tokens"),
+                &String::from_str(
+                    &setup.env,
+                    "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+                ),
+                &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+                &true,
+                &DaoAction::SetGovernanceConfig(config),
+            )
+            .map(|proposal_id| proposal_id.unwrap())
+    };
+
+    // the voting token must be a token contract
+    let err = proposal(VotingWeight::Token(Address::generate(&setup.env)))
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidGovernanceConfig.into());
+
+    // tokens add to the badges
+    let config = GovernanceConfig {
+        voting_weight: VotingWeight::BadgesAndToken(sac.address()),
+        ..setup.contract.get_governance_config(&id)
+    };
+    let proposal_id = approved_action(
+        &setup,
+        &id,
+        &holder,
+        &DaoAction::SetGovernanceConfig(config),
+    );
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+
+    let proposal_id = proposal(VotingWeight::Token(sac.address())).unwrap();
+    assert_eq!(
+        setup.contract.get_voting_weight(&id, &proposal_id, &holder),
+        Badge::Community as u32 + 5
+    );
+    assert_eq!(
+        setup.contract.get_voting_weight(&id, &proposal_id, &member),
+        Badge::Community as u32
+    );
+    setup.contract.vote(
+        &holder,
+        &id,
+        &proposal_id,
+        &Vote::PublicVote(PublicVote {
+            address: holder.clone(),
+            weight: Badge::Community as u32 + 5,
+            vote_choice: VoteChoice::Approve,
+        }),
+    );
+    setup
+        .env
+        .ledger()
+        .set_timestamp(setup.env.ledger().timestamp() + 3600 * 24 * 2 + 1);
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+
+    // only tokens count, balances are read when the proposal is created
+    let proposal_id = proposal(VotingWeight::Badges).unwrap();
+    voting_token.mint(&holder, &(5 * 10_000_000));
+    assert_eq!(
+        setup.contract.get_voting_weight(&id, &proposal_id, &holder),
+        5
+    );
+    assert_eq!(
+        setup.contract.get_voting_weight(&id, &proposal_id, &member),
+        Badge::Default as u32
+    );
+    assert_eq!(
+        setup
+            .contract
+            .get_proposal(&id, &proposal_id)
+            .vote_data
+            .eligible_weight,
+        5
+    );
+
+    let err = setup
+        .contract
+        .try_vote(
+            &holder,
+            &id,
+            &proposal_id,
+            &Vote::PublicVote(PublicVote {
+                address: holder.clone(),
+                weight: 10,
+                vote_choice: VoteChoice::Approve,
+            }),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::VoterWeight.into());
+
+    setup.contract.vote(
+        &holder,
+        &id,
+        &proposal_id,
+        &Vote::PublicVote(PublicVote {
+            address: holder.clone(),
+            weight: 5,
+            vote_choice: VoteChoice::Approve,
+        }),
+    );
+}
+
+#[test]
+fn proposal_listing_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let empty = setup.contract.get_proposals(&id, &None, &None, &5);
+    assert!(empty.proposals.is_empty());
+    assert_eq!(empty.next_cursor, None);
+
+    setup
+        .token_stellar
+        .mint(&setup.grogu, &(2_000 * 10_000_000));
+    let voting_ends_at = setup.env.ledger().timestamp() + 3600 * 24 * 2;
+    for _ in 0..12 {
+        setup.contract.create_proposal(
+            &setup.grogu,
+            &id,
+            &String::from_str(&setup.env, "Listed proposal"),
+            &String::from_str(
+                &setup.env,
+                "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+            ),
+            &voting_ends_at,
+            &true,
+            &None,
+        );
+    }
+    for proposal_id in [3, 8] {
+        setup
+            .contract
+            .cancel_proposal(&setup.grogu, &id, &proposal_id);
+    }
+    let ids = |page: &ProposalPage| {
+        Vec::from_iter(
+            &setup.env,
+            page.proposals.iter().map(|proposal| proposal.id),
+        )
+    };
+
+    // most recent first, across the pages of the DAO
+    let page = setup.contract.get_proposals(&id, &None, &None, &5);
+    assert_eq!(ids(&page), vec![&setup.env, 11, 10, 9, 8, 7]);
+    assert_eq!(page.next_cursor, Some(6));
+    let page = setup
+        .contract
+        .get_proposals(&id, &None, &page.next_cursor, &100);
+    assert_eq!(ids(&page), vec![&setup.env, 6, 5, 4, 3, 2, 1, 0]);
+    assert_eq!(page.next_cursor, None);
+
+    let cancelled = Some(ProposalStatus::Cancelled);
+    let page = setup.contract.get_proposals(&id, &cancelled, &None, &10);
+    assert_eq!(ids(&page), vec![&setup.env, 8, 3]);
+    assert_eq!(page.next_cursor, None);
+
+    let active = Some(ProposalStatus::Active);
+    let page = setup.contract.get_proposals(&id, &active, &Some(9), &3);
+    assert_eq!(ids(&page), vec![&setup.env, 9, 7, 6]);
+    assert_eq!(page.next_cursor, Some(5));
+
+    let page = setup.contract.get_proposals(&id, &active, &None, &0);
+    assert!(page.proposals.is_empty());
+}
+
+#[test]
+fn tally_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let kuiil = community_member(&setup, &id);
+    let bossk = community_member(&setup, &id);
+
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let voting_ends_at = setup.env.ledger().timestamp() + 3600 * 24 * 2;
+    let proposal_id = setup.contract.create_proposal(
+        &setup.grogu,
+        &id,
+        &String::from_str(&setup.env, "Live results"),
+        &ipfs,
+        &voting_ends_at,
+        &true,
+        &None,
+    );
+
+    // the proposer abstains
+    assert_eq!(
+        setup.contract.tally(&id, &proposal_id),
+        Tally {
+            approve: 0,
+            reject: 0,
+            abstain: Badge::Verified as u128,
+        }
+    );
+
+    for (voter, weight, vote_choice) in [
+        (&kuiil, Badge::Community as u32, VoteChoice::Approve),
+        (&bossk, Badge::Verified as u32, VoteChoice::Reject),
+    ] {
+        setup.contract.vote(
+            voter,
+            &id,
+            &proposal_id,
+            &Vote::PublicVote(PublicVote {
+                address: voter.clone(),
+                weight,
+                vote_choice,
+            }),
+        );
+    }
+    assert_eq!(
+        setup.contract.tally(&id, &proposal_id),
+        Tally {
+            approve: Badge::Community as u128,
+            reject: Badge::Verified as u128,
+            abstain: Badge::Verified as u128,
+        }
+    );
+
+    assert_eq!(
+        setup.contract.get_vote(&id, &proposal_id, &bossk),
+        Some(Vote::PublicVote(PublicVote {
+            address: bossk.clone(),
+            weight: Badge::Verified as u32,
+            vote_choice: VoteChoice::Reject,
+        }))
+    );
+    assert_eq!(
+        setup.contract.get_vote(&id, &proposal_id, &setup.mando),
+        None
+    );
+
+    // anonymous votes are only counted with the decoded tallies
+    setup.contract.anonymous_voting_setup(
+        &setup.mando,
+        &id,
+        &String::from_str(&setup.env, "public key random"),
+    );
+    let proposal_id = setup.contract.create_proposal(
+        &setup.grogu,
+        &id,
+        &String::from_str(&setup.env, "Secret results"),
+        &ipfs,
+        &voting_ends_at,
+        &false,
+        &None,
+    );
+    let err = setup
+        .contract
+        .try_tally(&id, &proposal_id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::WrongVoteType.into());
+
+    // only the commitments of anonymous votes are stored
+    match setup.contract.get_vote(&id, &proposal_id, &setup.grogu) {
+        Some(Vote::AnonymousVote(vote)) => assert_eq!(vote.commitments.len(), 3),
+        vote => panic!("unexpected vote {vote:?}"),
+    }
+}
+
+#[test]
+fn turnout_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let kuiil = community_member(&setup, &id);
+    community_member(&setup, &id);
+
+    let voting_ends_at = setup.env.ledger().timestamp() + 3600 * 24 * 2;
+    let proposal_id = setup.contract.create_proposal(
+        &setup.grogu,
+        &id,
+        &String::from_str(&setup.env, "Governance health"),
+        &String::from_str(
+            &setup.env,
+            "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        ),
+        &voting_ends_at,
+        &true,
+        &None,
+    );
+    setup.contract.vote(
+        &kuiil,
+        &id,
+        &proposal_id,
+        &Vote::PublicVote(PublicVote {
+            address: kuiil.clone(),
+            weight: Badge::Community as u32,
+            vote_choice: VoteChoice::Approve,
+        }),
+    );
+
+    // the proposer abstains and one of the two members voted
+    let weight_cast = (Badge::Verified as u32 + Badge::Community as u32) as u128;
+    let eligible_weight = 2 * Badge::Community as u128;
+    assert_eq!(
+        setup.contract.get_turnout(&id, &proposal_id),
+        Turnout {
+            weight_cast,
+            eligible_weight,
+            turnout_bps: 7_500,
+        }
+    );
+    assert_eq!(setup.contract.get_project_stats(&id).executed_proposals, 0);
+
+    setup.env.ledger().set_timestamp(voting_ends_at);
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+    let stats = setup.contract.get_project_stats(&id);
+    assert_eq!(stats.executed_proposals, 1);
+    assert_eq!(stats.weight_cast, weight_cast);
+    assert_eq!(stats.eligible_weight, eligible_weight);
+}
@@ -0,0 +1,343 @@
+use super::test_utils::{TestSetup, create_test_data, init_contract};
+use crate::errors::{ContractErrors, ExtendedErrors};
+use registry::types;
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{Address, Bytes, IntoVal, Map, String, Symbol, Val, vec};
+
+fn register(setup: &TestSetup, name: &str) -> Bytes {
+    setup.contract.register(
+        &setup.grogu,
+        &String::from_str(&setup.env, name),
+        &vec![&setup.env, setup.grogu.clone()],
+        &String::from_str(&setup.env, "https://github.com/tansu"),
+        &String::from_str(
+            &setup.env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        &None,
+    )
+}
+
+#[test]
+fn dependencies_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let soroban = register(&setup, "soroban");
+    let stellar = register(&setup, "stellar");
+
+    let range = String::from_str(&setup.env, "^1.2");
+    setup
+        .registry
+        .declare_dependency(&setup.mando, &id, &soroban, &range);
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.registry_id.clone(),
+                (
+                    Symbol::new(&setup.env, "dependency_updated"),
+                    id.clone(),
+                    soroban.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "maintainer"),
+                            setup.mando.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "range"),
+                            range.clone().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+
+    // declaring again updates the range
+    let range = String::from_str(&setup.env, ">=1.4.0, <2.0.0");
+    setup
+        .registry
+        .declare_dependency(&setup.mando, &id, &soroban, &range);
+    let any = String::from_str(&setup.env, "*");
+    setup
+        .registry
+        .declare_dependency(&setup.grogu, &id, &stellar, &any);
+    assert_eq!(
+        setup.registry.get_dependencies(&id),
+        Map::from_array(
+            &setup.env,
+            [
+                (soroban.clone(), range.clone()),
+                (stellar.clone(), any.clone())
+            ]
+        )
+    );
+
+    setup
+        .registry
+        .remove_dependency(&setup.grogu, &id, &stellar);
+    assert_eq!(
+        setup.registry.get_dependencies(&id),
+        Map::from_array(&setup.env, [(soroban.clone(), range.clone())])
+    );
+    let error = setup
+        .registry
+        .try_remove_dependency(&setup.grogu, &id, &stellar)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::UnknownDependency.into());
+
+    let error = setup
+        .registry
+        .try_declare_dependency(&Address::generate(&setup.env), &id, &stellar, &any)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::UnauthorizedSigner.into());
+}
+
+#[test]
+fn dependency_validation() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let soroban = register(&setup, "soroban");
+
+    for range in ["1", "1.2.3", "=1.2.3-rc.1", "~1.2", "> 1.0, <= 2.5.1", "<2"] {
+        setup.registry.declare_dependency(
+            &setup.grogu,
+            &id,
+            &soroban,
+            &String::from_str(&setup.env, range),
+        );
+    }
+
+    for range in [
+        "", "latest", "^1.2.3.4", "^01.2", ">=1.0,", "1.2-rc.1", "**", "^v1.2",
+    ] {
+        let error = setup
+            .registry
+            .try_declare_dependency(
+                &setup.grogu,
+                &id,
+                &soroban,
+                &String::from_str(&setup.env, range),
+            )
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(error, ExtendedErrors::InvalidDependency.into());
+    }
+
+    let any = String::from_str(&setup.env, "*");
+    let error = setup
+        .registry
+        .try_declare_dependency(&setup.grogu, &id, &id, &any)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::InvalidDependency.into());
+
+    let error = setup
+        .registry
+        .try_declare_dependency(
+            &setup.grogu,
+            &id,
+            &Bytes::from_array(&setup.env, &[0; 32]),
+            &any,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::InvalidKey.into());
+}
+
+#[test]
+fn dependents_index() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let soroban = register(&setup, "soroban");
+    let stellar = register(&setup, "stellar");
+    let horizon = register(&setup, "horizon");
+    let range = String::from_str(&setup.env, "^1");
+
+    assert_eq!(
+        setup.registry.get_dependents(&soroban, &0),
+        vec![&setup.env]
+    );
+    for dependent in [&id, &stellar, &horizon] {
+        let maintainer = if *dependent == id {
+            &setup.mando
+        } else {
+            &setup.grogu
+        };
+        setup
+            .registry
+            .declare_dependency(maintainer, dependent, &soroban, &range);
+    }
+    // updating a range does not list the dependent twice
+    setup
+        .registry
+        .declare_dependency(&setup.grogu, &stellar, &soroban, &range);
+    assert_eq!(
+        setup.registry.get_dependents(&soroban, &0),
+        vec![&setup.env, id.clone(), stellar.clone(), horizon.clone()]
+    );
+
+    // the last dependent takes the place of the removed one
+    setup
+        .registry
+        .remove_dependency(&setup.grogu, &stellar, &soroban);
+    assert_eq!(
+        setup.registry.get_dependents(&soroban, &0),
+        vec![&setup.env, id.clone(), horizon.clone()]
+    );
+
+    // deregistered projects leave the dependents of their dependencies
+    setup.contract.deregister(&setup.grogu, &id);
+    setup.contract.deregister(&setup.mando, &id);
+    setup
+        .env
+        .ledger()
+        .set_timestamp(setup.env.ledger().timestamp() + crate::types::DEREGISTRATION_DELAY);
+    setup.contract.deregister(&setup.grogu, &id);
+    assert_eq!(
+        setup.registry.get_dependents(&soroban, &0),
+        vec![&setup.env, horizon.clone()]
+    );
+    assert_eq!(setup.registry.get_dependencies(&id).len(), 0);
+    assert_eq!(
+        setup.registry.get_dependents(&soroban, &1),
+        vec![&setup.env]
+    );
+}
+
+#[test]
+fn pins_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let soroban = register(&setup, "soroban");
+    let stellar = register(&setup, "stellar");
+
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let cid = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let v1 = String::from_str(&setup.env, "v1.0.0");
+    let v1_1 = String::from_str(&setup.env, "v1.1.0");
+    for tag in [v1.clone(), v1_1.clone()] {
+        setup.registry.create_release(
+            &setup.grogu,
+            &soroban,
+            &tag,
+            &hash,
+            &cid,
+            &cid,
+            &types::ReleaseChannel::Stable,
+            &None,
+        );
+    }
+
+    // only declared dependencies can be pinned
+    let error = setup
+        .registry
+        .try_attest_pin(&setup.grogu, &id, &soroban, &v1)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::UnknownDependency.into());
+
+    let range = String::from_str(&setup.env, "^1");
+    setup
+        .registry
+        .declare_dependency(&setup.grogu, &id, &soroban, &range);
+    setup
+        .registry
+        .declare_dependency(&setup.grogu, &id, &stellar, &range);
+    setup.registry.attest_pin(&setup.mando, &id, &soroban, &v1);
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.registry_id.clone(),
+                (
+                    Symbol::new(&setup.env, "pin_attested"),
+                    id.clone(),
+                    soroban.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "maintainer"),
+                            setup.mando.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "tag"),
+                            v1.clone().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    assert_eq!(
+        setup.registry.get_pins(&id),
+        Map::from_array(&setup.env, [(soroban.clone(), v1.clone())])
+    );
+
+    // the dependency must have the release
+    let error = setup
+        .registry
+        .try_attest_pin(&setup.grogu, &id, &stellar, &v1)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::UnknownRelease.into());
+    let error = setup
+        .registry
+        .try_attest_pin(&Address::generate(&setup.env), &id, &soroban, &v1)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::UnauthorizedSigner.into());
+
+    assert_eq!(setup.registry.get_vulnerable_pins(&id), vec![&setup.env]);
+    let advisory_id = String::from_str(&setup.env, "RUSTSEC-2025-0001");
+    setup.registry.publish_advisory(
+        &setup.grogu,
+        &soroban,
+        &advisory_id,
+        &types::Severity::High,
+        &vec![&setup.env, v1.clone()],
+        &cid,
+    );
+    assert_eq!(
+        setup.registry.get_vulnerable_pins(&id),
+        vec![
+            &setup.env,
+            types::VulnerablePin {
+                dependency: soroban.clone(),
+                tag: v1.clone(),
+                advisory_id: advisory_id.clone(),
+                severity: types::Severity::High,
+            }
+        ]
+    );
+
+    // upgrading to an unaffected release clears the flag
+    setup
+        .registry
+        .attest_pin(&setup.grogu, &id, &soroban, &v1_1);
+    assert_eq!(setup.registry.get_vulnerable_pins(&id), vec![&setup.env]);
+
+    // removing the dependency drops its pin
+    setup
+        .registry
+        .remove_dependency(&setup.grogu, &id, &soroban);
+    assert_eq!(setup.registry.get_pins(&id).len(), 0);
+}
@@ -1,5 +1,10 @@
-use crate::{contract_versioning::domain_node, domain_contract};
-use soroban_sdk::{Bytes, Env};
+use super::test_utils::{create_test_data, init_contract};
+use crate::{
+    domain::{NameServiceInterface, domain_node},
+    domain_contract, errors, types,
+};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{Address, Bytes, Env, IntoVal, Map, Symbol, Val, contract, contractimpl, vec};
 
 #[test]
 fn domain_node_matches_contract() {
@@ -15,3 +20,288 @@ fn domain_node_matches_contract() {
 
     assert_eq!(node, node_official);
 }
+
+#[contract]
+struct NameService;
+
+#[contractimpl]
+impl NameServiceInterface for NameService {
+    fn owner(env: Env, name: Bytes) -> Option<Address> {
+        env.storage().persistent().get(&name)
+    }
+
+    fn register(env: Env, name: Bytes, owner: Address) {
+        owner.require_auth();
+        env.storage().persistent().set(&name, &owner);
+    }
+
+    fn transfer(env: Env, name: Bytes, new_owner: Address) {
+        let owner: Address = env.storage().persistent().get(&name).unwrap();
+        owner.require_auth();
+        env.storage().persistent().set(&name, &new_owner);
+    }
+
+    fn release(env: Env, name: Bytes) {
+        let owner: Address = env.storage().persistent().get(&name).unwrap();
+        owner.require_auth();
+        env.storage().persistent().remove(&name);
+    }
+
+    fn expires_at(_env: Env, _name: Bytes) -> Option<u64> {
+        None
+    }
+
+    fn renew(env: Env, name: Bytes) {
+        let owner: Address = env.storage().persistent().get(&name).unwrap();
+        owner.require_auth();
+    }
+}
+
+#[test]
+fn domain_provider_flow() {
+    let setup = create_test_data();
+    assert_eq!(
+        setup.contract.get_domain_provider(),
+        types::DomainProvider::SorobanDomains
+    );
+
+    let name_service_id = setup.env.register(NameService, ());
+    let name_service = NameServiceClient::new(&setup.env, &name_service_id);
+    let provider = types::DomainProvider::NameService(types::Contract {
+        address: name_service_id.clone(),
+        wasm_hash: None,
+    });
+    setup
+        .contract
+        .set_domain_provider(&setup.contract_admin, &provider);
+
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (Symbol::new(&setup.env, "domain_provider_updated"),).into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "admin"),
+                            setup.contract_admin.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "provider"),
+                            provider.clone().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    assert_eq!(setup.contract.get_domain_provider(), provider);
+
+    // the name is registered with the new provider
+    let id = init_contract(&setup);
+    let name_b = Bytes::from_slice(&setup.env, b"tansu");
+    assert_eq!(name_service.owner(&name_b), Some(setup.grogu.clone()));
+
+    let new_maintainers = vec![&setup.env, setup.mando.clone()];
+    setup
+        .contract
+        .transfer_project(&setup.mando, &id, &setup.mando, &new_maintainers);
+    setup
+        .contract
+        .transfer_project(&setup.grogu, &id, &setup.mando, &new_maintainers);
+    assert_eq!(name_service.owner(&name_b), Some(setup.mando.clone()));
+
+    // projects keep the provider they were registered with
+    setup.contract.set_domain_provider(
+        &setup.contract_admin,
+        &types::DomainProvider::SorobanDomains,
+    );
+    assert_eq!(
+        setup.contract.check_domain_status(&id),
+        types::DomainStatus::Active(setup.mando.clone(), None)
+    );
+
+    // and released on deregistration
+    assert!(!setup.contract.deregister(&setup.mando, &id));
+    setup
+        .env
+        .ledger()
+        .set_timestamp(setup.env.ledger().timestamp() + 7 * 24 * 3600);
+    assert!(setup.contract.deregister(&setup.mando, &id));
+    assert_eq!(name_service.owner(&name_b), None);
+}
+
+#[test]
+fn domain_renewal_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let year: u64 = 31536000;
+
+    assert_eq!(
+        setup.contract.check_domain_status(&id),
+        types::DomainStatus::Active(setup.grogu.clone(), Some(year))
+    );
+
+    // only the owner can renew an active domain
+    let err = setup
+        .contract
+        .try_renew_domain(&setup.mando, &id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, errors::ContractErrors::MaintainerNotDomainOwner.into());
+
+    // renewals extend the current registration
+    setup.env.ledger().set_timestamp(year / 2);
+    setup.contract.renew_domain(&setup.grogu, &id);
+    let year = 2 * year;
+    assert_eq!(
+        setup.contract.check_domain_status(&id),
+        types::DomainStatus::Active(setup.grogu.clone(), Some(year))
+    );
+
+    // an expired domain stays bound to the project during the grace period
+    setup.env.ledger().set_timestamp(year + 1);
+    let grace_end = year + types::DOMAIN_GRACE_PERIOD;
+    assert_eq!(
+        setup.contract.check_domain_status(&id),
+        types::DomainStatus::Grace(grace_end)
+    );
+
+    let new_maintainers = vec![&setup.env, setup.mando.clone()];
+    let err = setup
+        .contract
+        .try_transfer_project(&setup.grogu, &id, &setup.mando, &new_maintainers)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, errors::ContractErrors::DomainExpired.into());
+
+    // only its last owner can renew an expired domain, Soroban Domains
+    // registers it for at least a year
+    let err = setup
+        .contract
+        .try_renew_domain(&setup.mando, &id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, errors::ContractErrors::MaintainerNotDomainOwner.into());
+    setup.contract.renew_domain(&setup.grogu, &id);
+    let expires_at = year + 1 + types::DOMAIN_PERIOD;
+
+    // the domain contract events come first
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events.slice(all_events.len() - 1..),
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (Symbol::new(&setup.env, "domain_renewed"), id.clone()).into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "maintainer"),
+                            setup.grogu.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "expires_at"),
+                            Some(expires_at).into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    assert_eq!(
+        setup.contract.check_domain_status(&id),
+        types::DomainStatus::Active(setup.grogu.clone(), Some(expires_at))
+    );
+
+    // past the grace period the domain is not bound to the project anymore
+    setup
+        .env
+        .ledger()
+        .set_timestamp(expires_at + types::DOMAIN_GRACE_PERIOD);
+    assert_eq!(
+        setup.contract.check_domain_status(&id),
+        types::DomainStatus::Expired
+    );
+}
+
+#[test]
+fn verify_domain_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    assert!(setup.contract.verify_domain(&id));
+    assert_eq!(setup.env.events().all(), vec![&setup.env]);
+    let verification = setup.contract.get_domain_verification(&id).unwrap();
+    assert_eq!(verification.owner, Some(setup.grogu.clone()));
+    assert!(verification.verified);
+
+    // the domain is transferred outside of Tansu
+    let bob = Address::generate(&setup.env);
+    let domain = domain_contract::Client::new(&setup.env, &setup.domain_id);
+    domain.transfer(
+        &domain_contract::RecordKeys::Record(domain_node(&setup.env, &id)),
+        &bob,
+    );
+
+    assert!(!setup.contract.verify_domain(&id));
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (
+                    Symbol::new(&setup.env, "domain_ownership_changed"),
+                    id.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "owner"),
+                            Some(bob.clone()).into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "verified"),
+                            false.into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    assert!(
+        !setup
+            .contract
+            .get_domain_verification(&id)
+            .unwrap()
+            .verified
+    );
+
+    // the project is only flagged once
+    assert!(!setup.contract.verify_domain(&id));
+    assert_eq!(setup.env.events().all(), vec![&setup.env]);
+
+    // other maintainers do not stand for the lead maintainer
+    domain.transfer(
+        &domain_contract::RecordKeys::Record(domain_node(&setup.env, &id)),
+        &setup.mando,
+    );
+    assert!(!setup.contract.verify_domain(&id));
+    assert_eq!(
+        setup.contract.get_domain_verification(&id).unwrap().owner,
+        Some(setup.mando.clone())
+    );
+}
@@ -0,0 +1,308 @@
+use super::test_utils::{TestSetup, create_test_data, init_contract};
+use crate::{
+    errors::{AdditionalErrors, ContractErrors},
+    types,
+};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{Address, Bytes, IntoVal, Map, String, Symbol, Val, Vec, vec};
+
+fn register(setup: &TestSetup, name: &str) -> Bytes {
+    setup.contract.register(
+        &setup.grogu,
+        &String::from_str(&setup.env, name),
+        &vec![&setup.env, setup.grogu.clone()],
+        &String::from_str(&setup.env, "https://github.com/tansu"),
+        &String::from_str(
+            &setup.env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        &None,
+    )
+}
+
+/// Create a proposal on a project and get a vote from a member.
+fn voted_proposal(
+    setup: &TestSetup,
+    project_key: &Bytes,
+    voter: &Address,
+    vote_choice: types::VoteChoice,
+) -> u32 {
+    let proposal_id = setup.contract.create_proposal(
+        &setup.grogu,
+        project_key,
+        &String::from_str(&setup.env, "Adopt the security policy"),
+        &String::from_str(
+            &setup.env,
+            "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        ),
+        &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+        &true,
+        &None,
+    );
+    setup.contract.vote(
+        voter,
+        project_key,
+        &proposal_id,
+        &types::Vote::PublicVote(types::PublicVote {
+            address: voter.clone(),
+            weight: types::Badge::Community as u32,
+            vote_choice,
+        }),
+    );
+    proposal_id
+}
+
+#[test]
+fn federated_proposal_flow() {
+    let setup = create_test_data();
+    let tansu = init_contract(&setup);
+    let soroban = register(&setup, "soroban");
+    let stellar = register(&setup, "stellar");
+    let title = String::from_str(&setup.env, "Organization security policy");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+
+    let kuiil = Address::generate(&setup.env);
+    setup.token_stellar.mint(&kuiil, &(100 * 10_000_000));
+    setup
+        .contract
+        .add_member(&kuiil, &String::from_str(&setup.env, "test"));
+    for project_key in [&tansu, &soroban, &stellar] {
+        setup.contract.set_badges(
+            &setup.grogu,
+            project_key,
+            &kuiil,
+            &vec![&setup.env, types::Badge::Community],
+        );
+    }
+
+    let project_keys = vec![&setup.env, tansu.clone(), soroban.clone(), stellar.clone()];
+    for (project_keys, required_approvals) in [
+        (vec![&setup.env, tansu.clone()], 1),
+        (vec![&setup.env, tansu.clone(), tansu.clone()], 1),
+        (project_keys.clone(), 0),
+        (project_keys.clone(), 4),
+    ] {
+        let err = setup
+            .governance
+            .try_create_federated_proposal(
+                &setup.grogu,
+                &title,
+                &ipfs,
+                &project_keys,
+                &required_approvals,
+            )
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, AdditionalErrors::InvalidFederation.into());
+    }
+    let err = setup
+        .governance
+        .try_get_federated_proposal(&0)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, AdditionalErrors::UnknownFederation.into());
+
+    let federated_id =
+        setup
+            .governance
+            .create_federated_proposal(&setup.grogu, &title, &ipfs, &project_keys, &2);
+
+    // each project links the proposal its DAO votes on
+    let proposal_ids = Vec::from_iter(
+        &setup.env,
+        [
+            (&tansu, types::VoteChoice::Approve),
+            (&soroban, types::VoteChoice::Reject),
+            (&stellar, types::VoteChoice::Approve),
+        ]
+        .map(|(project_key, vote_choice)| voted_proposal(&setup, project_key, &kuiil, vote_choice)),
+    );
+    let err = setup
+        .governance
+        .try_link_federated_proposal(
+            &kuiil,
+            &federated_id,
+            &tansu,
+            &proposal_ids.get_unchecked(0),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+    for (project_key, proposal_id) in [&tansu, &soroban, &stellar].iter().zip(proposal_ids.iter()) {
+        setup.governance.link_federated_proposal(
+            &setup.grogu,
+            &federated_id,
+            project_key,
+            &proposal_id,
+        );
+    }
+    let err = setup
+        .governance
+        .try_link_federated_proposal(
+            &setup.grogu,
+            &federated_id,
+            &tansu,
+            &proposal_ids.get_unchecked(0),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, AdditionalErrors::InvalidFederation.into());
+    assert_eq!(
+        setup
+            .governance
+            .get_federated_proposal(&federated_id)
+            .proposals,
+        Map::from_array(
+            &setup.env,
+            [
+                (tansu.clone(), proposal_ids.get_unchecked(0)),
+                (soroban.clone(), proposal_ids.get_unchecked(1)),
+                (stellar.clone(), proposal_ids.get_unchecked(2)),
+            ]
+        )
+    );
+
+    let err = setup
+        .governance
+        .try_resolve_federated_proposal(&federated_id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProposalVotingTime.into());
+
+    setup
+        .env
+        .ledger()
+        .set_timestamp(setup.env.ledger().timestamp() + 3600 * 24 * 2 + 1);
+    for (project_key, proposal_id) in [&tansu, &soroban].iter().zip(proposal_ids.iter()) {
+        setup
+            .contract
+            .execute(&setup.grogu, project_key, &proposal_id, &None, &None);
+    }
+
+    // the last project can still approve
+    let err = setup
+        .governance
+        .try_resolve_federated_proposal(&federated_id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProposalVotingTime.into());
+
+    setup.contract.execute(
+        &setup.grogu,
+        &stellar,
+        &proposal_ids.get_unchecked(2),
+        &None,
+        &None,
+    );
+    assert_eq!(
+        setup.governance.resolve_federated_proposal(&federated_id),
+        governance::types::ProposalStatus::Approved
+    );
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        vec![&setup.env, all_events.last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                setup.governance_id.clone(),
+                (
+                    Symbol::new(&setup.env, "federated_proposal_resolved"),
+                    federated_id,
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "approvals"),
+                            2u32.into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "status"),
+                            types::ProposalStatus::Approved.into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    let err = setup
+        .governance
+        .try_resolve_federated_proposal(&federated_id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProposalActive.into());
+
+    // rejected once the approvals left cannot reach the requirement
+    let federated_id = setup.governance.create_federated_proposal(
+        &setup.grogu,
+        &title,
+        &ipfs,
+        &vec![&setup.env, tansu.clone(), soroban.clone()],
+        &2,
+    );
+    let proposal_id = voted_proposal(&setup, &soroban, &kuiil, types::VoteChoice::Reject);
+    setup
+        .governance
+        .link_federated_proposal(&setup.grogu, &federated_id, &soroban, &proposal_id);
+    setup
+        .env
+        .ledger()
+        .set_timestamp(setup.env.ledger().timestamp() + 3600 * 24 * 2 + 1);
+    setup
+        .contract
+        .execute(&setup.grogu, &soroban, &proposal_id, &None, &None);
+    assert_eq!(
+        setup.governance.resolve_federated_proposal(&federated_id),
+        governance::types::ProposalStatus::Rejected
+    );
+
+    // linked proposals describe the federated proposal
+    let federated_id = setup.governance.create_federated_proposal(
+        &setup.grogu,
+        &title,
+        &ipfs,
+        &vec![&setup.env, tansu.clone(), soroban.clone()],
+        &1,
+    );
+    let expires_at = setup
+        .governance
+        .get_federated_proposal(&federated_id)
+        .expires_at;
+    let proposal_id = setup.contract.create_proposal(
+        &setup.grogu,
+        &tansu,
+        &String::from_str(&setup.env, "Another policy"),
+        &String::from_str(
+            &setup.env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+        &true,
+        &None,
+    );
+    let err = setup
+        .governance
+        .try_link_federated_proposal(&setup.grogu, &federated_id, &tansu, &proposal_id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, AdditionalErrors::InvalidFederation.into());
+
+    // projects which did not link a proposal in time count as rejected
+    setup.env.ledger().set_timestamp(expires_at);
+    let proposal_id = voted_proposal(&setup, &tansu, &kuiil, types::VoteChoice::Approve);
+    let err = setup
+        .governance
+        .try_link_federated_proposal(&setup.grogu, &federated_id, &tansu, &proposal_id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProposalVotingTime.into());
+    assert_eq!(
+        setup.governance.resolve_federated_proposal(&federated_id),
+        governance::types::ProposalStatus::Rejected
+    );
+}
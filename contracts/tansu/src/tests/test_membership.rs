@@ -1,7 +1,9 @@
-use super::test_utils::{create_test_data, init_contract};
+use super::test_utils::{approved_action, community_member, create_test_data, init_contract};
 use crate::errors::ContractErrors;
-use crate::types::{Badge, ProjectBadges};
-use soroban_sdk::testutils::{Address as _, Events};
+use crate::types::{
+    Badge, DaoAction, GovernanceConfig, ProjectBadges, PublicVote, Vote, VoteChoice,
+};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
 use soroban_sdk::{Address, IntoVal, Map, String, Symbol, Val, vec};
 
 #[test]
@@ -47,27 +49,19 @@ fn membership_badges() {
             &setup.env,
             (
                 setup.contract_id.clone(),
-                (Symbol::new(&setup.env, "badges_updated"),).into_val(&setup.env),
+                (
+                    Symbol::new(&setup.env, "badges_updated"),
+                    id.clone(),
+                    setup.mando.clone(),
+                    member.clone(),
+                )
+                    .into_val(&setup.env),
                 Map::<Symbol, Val>::from_array(
                     &setup.env,
-                    [
-                        (
-                            Symbol::new(&setup.env, "project_key"),
-                            id.clone().into_val(&setup.env)
-                        ),
-                        (
-                            Symbol::new(&setup.env, "maintainer"),
-                            setup.mando.clone().into_val(&setup.env)
-                        ),
-                        (
-                            Symbol::new(&setup.env, "member"),
-                            member.clone().into_val(&setup.env)
-                        ),
-                        (
-                            Symbol::new(&setup.env, "badges_count"),
-                            1u32.into_val(&setup.env)
-                        ),
-                    ],
+                    [(
+                        Symbol::new(&setup.env, "badges"),
+                        badges.clone().into_val(&setup.env)
+                    ),],
                 )
                 .into_val(&setup.env),
             ),
@@ -246,3 +240,77 @@ fn membership_errors() {
         .unwrap();
     assert_eq!(error, ContractErrors::UnknownMember.into());
 }
+
+#[test]
+fn reputation_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let kuiil = community_member(&setup, &id);
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let create_proposal = || {
+        setup.contract.create_proposal(
+            &setup.grogu,
+            &id,
+            &String::from_str(&setup.env, "Build reputation"),
+            &ipfs,
+            &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+            &true,
+            &None,
+        )
+    };
+    let vote = |proposal_id: u32| {
+        setup.contract.vote(
+            &kuiil,
+            &id,
+            &proposal_id,
+            &Vote::PublicVote(PublicVote {
+                address: kuiil.clone(),
+                weight: Badge::Community as u32,
+                vote_choice: VoteChoice::Approve,
+            }),
+        );
+    };
+
+    // nothing is tracked while reputation is disabled
+    vote(create_proposal());
+    assert_eq!(setup.contract.get_reputation(&id, &kuiil), 0);
+
+    let config = GovernanceConfig {
+        reputation_half_life: 100,
+        reputation_weight: 1_000,
+        ..setup.contract.get_governance_config(&id)
+    };
+    let proposal_id = approved_action(&setup, &id, &kuiil, &DaoAction::SetGovernanceConfig(config));
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+
+    setup.env.ledger().set_sequence_number(1_000);
+    vote(create_proposal());
+    assert_eq!(setup.contract.get_reputation(&id, &kuiil), 10);
+
+    let hash = String::from_str(&setup.env, "2ef7bde608ce5404e97d5f042f95f89f1c232871");
+    setup
+        .contract
+        .commit(&setup.mando, &id, &hash, &None, &None, &None);
+    assert_eq!(setup.contract.get_reputation(&id, &setup.mando), 1);
+
+    // reputation halves every half-life
+    setup.env.ledger().set_sequence_number(1_100);
+    assert_eq!(setup.contract.get_reputation(&id, &kuiil), 5);
+    vote(create_proposal());
+    assert_eq!(setup.contract.get_reputation(&id, &kuiil), 15);
+
+    // and adds to the weight of the members
+    let proposal_id = create_proposal();
+    assert_eq!(
+        setup.contract.get_voting_weight(&id, &proposal_id, &kuiil),
+        Badge::Community as u32 + 15 * 1_000
+    );
+
+    setup.env.ledger().set_sequence_number(1_000_000);
+    assert_eq!(setup.contract.get_reputation(&id, &kuiil), 0);
+}
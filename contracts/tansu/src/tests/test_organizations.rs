@@ -0,0 +1,245 @@
+use super::test_utils::{create_test_data, init_contract};
+use crate::{errors::ContractErrors, types};
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{Address, Bytes, IntoVal, Map, String, Symbol, Val, vec};
+
+#[test]
+fn organization_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let admins = vec![&setup.env, setup.grogu.clone(), setup.mando.clone()];
+    let org_id = setup.contract.create_organization(
+        &setup.grogu,
+        &String::from_str(&setup.env, "Stellar"),
+        &admins,
+    );
+
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        vec![&setup.env, all_events.last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (
+                    Symbol::new(&setup.env, "organization_created"),
+                    org_id.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "admin"),
+                            setup.grogu.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "name"),
+                            String::from_str(&setup.env, "stellar").into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+
+    let id_ = setup.contract.register(
+        &setup.mando,
+        &String::from_str(&setup.env, "soroban"),
+        &vec![&setup.env, setup.mando.clone()],
+        &String::from_str(&setup.env, "https://github.com/soroban"),
+        &String::from_str(
+            &setup.env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        &None,
+    );
+
+    setup
+        .contract
+        .add_organization_project(&setup.grogu, &org_id, &setup.grogu, &id);
+    setup
+        .contract
+        .add_organization_project(&setup.mando, &org_id, &setup.mando, &id_);
+    assert_eq!(
+        setup.contract.get_organization(&org_id),
+        types::Organization {
+            name: String::from_str(&setup.env, "stellar"),
+            admins: admins.clone(),
+            projects: vec![&setup.env, id.clone(), id_.clone()],
+        }
+    );
+    assert_eq!(
+        setup.contract.get_project_organization(&id),
+        Some(org_id.clone())
+    );
+
+    // paused projects are left untouched
+    let paused_id = setup.contract.register(
+        &setup.mando,
+        &String::from_str(&setup.env, "kuiil"),
+        &vec![&setup.env, setup.mando.clone()],
+        &String::from_str(&setup.env, "https://github.com/kuiil"),
+        &String::from_str(
+            &setup.env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        &None,
+    );
+    setup
+        .contract
+        .add_organization_project(&setup.mando, &org_id, &setup.mando, &paused_id);
+    assert!(setup.contract.pause_project(&setup.mando, &paused_id));
+
+    // rotate mando in all projects, keeping its role and badges
+    setup
+        .contract
+        .add_member(&setup.mando, &String::from_str(&setup.env, "mando"));
+    setup.contract.set_badges(
+        &setup.grogu,
+        &id,
+        &setup.mando,
+        &vec![&setup.env, types::Badge::Developer],
+    );
+    setup.contract.set_maintainer_role(
+        &setup.grogu,
+        &id,
+        &setup.mando,
+        &types::MaintainerRole::Committer,
+    );
+    setup.contract.set_maintainer_role(
+        &setup.mando,
+        &id,
+        &setup.mando,
+        &types::MaintainerRole::Committer,
+    );
+
+    let bob = Address::generate(&setup.env);
+    assert!(!setup.contract.rotate_organization_maintainer(
+        &setup.grogu,
+        &org_id,
+        &setup.mando,
+        &bob
+    ));
+    assert!(setup.contract.rotate_organization_maintainer(
+        &setup.mando,
+        &org_id,
+        &setup.mando,
+        &bob
+    ));
+
+    assert_eq!(
+        setup.contract.get_project(&id).maintainers,
+        vec![&setup.env, setup.grogu.clone(), bob.clone()]
+    );
+    assert_eq!(
+        setup.contract.get_project(&id_).maintainers,
+        vec![&setup.env, bob.clone()]
+    );
+    assert_eq!(
+        setup.contract.get_maintainer_role(&id, &bob),
+        types::MaintainerRole::Committer
+    );
+    assert_eq!(
+        setup.contract.get_maintainer_role(&id_, &bob),
+        types::MaintainerRole::Admin
+    );
+    assert_eq!(
+        setup.contract.get_badges(&id).developer,
+        vec![&setup.env, bob.clone()]
+    );
+    assert_eq!(
+        setup.contract.get_project(&paused_id).maintainers,
+        vec![&setup.env, setup.mando.clone()]
+    );
+    setup
+        .contract
+        .remove_organization_project(&setup.mando, &org_id, &paused_id);
+
+    // a project admin can leave the organization
+    setup
+        .contract
+        .remove_organization_project(&bob, &org_id, &id_);
+    assert_eq!(
+        setup.contract.get_organization(&org_id).projects,
+        vec![&setup.env, id.clone()]
+    );
+    assert_eq!(setup.contract.get_project_organization(&id_), None);
+
+    // admins are replaced by majority
+    let new_admins = vec![&setup.env, bob.clone()];
+    assert!(
+        !setup
+            .contract
+            .set_organization_admins(&setup.grogu, &org_id, &new_admins)
+    );
+    assert!(
+        setup
+            .contract
+            .set_organization_admins(&setup.mando, &org_id, &new_admins)
+    );
+    assert_eq!(setup.contract.get_organization(&org_id).admins, new_admins);
+}
+
+#[test]
+fn organization_errors() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let name = String::from_str(&setup.env, "stellar");
+    let admins = vec![&setup.env, setup.grogu.clone()];
+    let org_id = setup
+        .contract
+        .create_organization(&setup.grogu, &name, &admins);
+
+    let err = setup
+        .contract
+        .try_create_organization(&setup.grogu, &name, &admins)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::OrganizationAlreadyExist.into());
+
+    let err = setup
+        .contract
+        .try_create_organization(
+            &setup.mando,
+            &String::from_str(&setup.env, "soroban"),
+            &admins,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+
+    let err = setup
+        .contract
+        .try_get_organization(&Bytes::from_array(&setup.env, &[0; 32]))
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnknownOrganization.into());
+
+    setup
+        .contract
+        .add_organization_project(&setup.grogu, &org_id, &setup.grogu, &id);
+    let err = setup
+        .contract
+        .try_add_organization_project(&setup.grogu, &org_id, &setup.grogu, &id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidOrganization.into());
+
+    let err = setup
+        .contract
+        .try_rotate_organization_maintainer(&setup.mando, &org_id, &setup.grogu, &setup.mando)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+
+    let err = setup
+        .contract
+        .try_set_organization_admins(&setup.grogu, &org_id, &vec![&setup.env])
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidOrganization.into());
+}
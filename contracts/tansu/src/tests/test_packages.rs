@@ -0,0 +1,210 @@
+use super::test_utils::{create_test_data, init_contract};
+use crate::{
+    errors::{ContractErrors, ExtendedErrors},
+    types,
+};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{Address, IntoVal, Map, String, Symbol, Val, vec};
+
+#[test]
+fn packages_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let name = setup.contract.add_package(
+        &setup.grogu,
+        &id,
+        &String::from_str(&setup.env, "Contracts"),
+    );
+    assert_eq!(name, String::from_str(&setup.env, "contracts"));
+
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        vec![&setup.env, all_events.last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (Symbol::new(&setup.env, "package_added"), id.clone()).into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "maintainer"),
+                            setup.grogu.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "name"),
+                            name.clone().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+
+    let dapp = setup
+        .contract
+        .add_package(&setup.grogu, &id, &String::from_str(&setup.env, "dapp"));
+    assert_eq!(
+        setup.contract.get_packages(&id),
+        vec![&setup.env, name.clone(), dapp.clone()]
+    );
+
+    // packages have their own commit pointer
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    setup
+        .contract
+        .commit_package(&setup.mando, &id, &name, &hash);
+    let version = String::from_str(&setup.env, "v1.0.0");
+    setup
+        .contract
+        .release_package(&setup.mando, &id, &name, &version);
+
+    assert_eq!(
+        setup.contract.get_package(&id, &name),
+        types::Package {
+            name: name.clone(),
+            hash: Some(hash.clone()),
+            releases: vec![&setup.env, version.clone()],
+        }
+    );
+    assert_eq!(setup.contract.get_package(&id, &dapp).hash, None);
+    let err = setup.contract.try_get_commit(&id).unwrap_err().unwrap();
+    assert_eq!(err, ContractErrors::NoHashFound.into());
+
+    setup.contract.remove_package(&setup.grogu, &id, &dapp);
+    assert_eq!(
+        setup.contract.get_packages(&id),
+        vec![&setup.env, name.clone()]
+    );
+    let err = setup
+        .contract
+        .try_get_package(&id, &dapp)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnknownPackage.into());
+
+    // packages are removed with the project
+    setup.contract.deregister(&setup.grogu, &id);
+    setup.contract.deregister(&setup.mando, &id);
+    setup
+        .env
+        .ledger()
+        .set_timestamp(setup.env.ledger().timestamp() + types::DEREGISTRATION_DELAY);
+    setup.contract.deregister(&setup.grogu, &id);
+    assert_eq!(setup.contract.get_packages(&id).len(), 0);
+    let err = setup
+        .contract
+        .try_get_package(&id, &name)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnknownPackage.into());
+}
+
+#[test]
+fn packages_errors() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let name = String::from_str(&setup.env, "contracts");
+    setup.contract.add_package(&setup.grogu, &id, &name);
+
+    for name in ["CONTRACTS", "", "tansu/contracts", "contrâcts"] {
+        let err = setup
+            .contract
+            .try_add_package(&setup.grogu, &id, &String::from_str(&setup.env, name))
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, ContractErrors::InvalidPackage.into());
+    }
+
+    let err = setup
+        .contract
+        .try_commit_package(
+            &setup.grogu,
+            &id,
+            &String::from_str(&setup.env, "dapp"),
+            &String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046"),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnknownPackage.into());
+
+    // hashes and versions are validated
+    let err = setup
+        .contract
+        .try_commit_package(
+            &setup.grogu,
+            &id,
+            &name,
+            &String::from_str(&setup.env, "main"),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::InvalidCommitHash.into());
+    for version in ["", "latest", "v1.0"] {
+        let err = setup
+            .contract
+            .try_release_package(
+                &setup.grogu,
+                &id,
+                &name,
+                &String::from_str(&setup.env, version),
+            )
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, ContractErrors::InvalidRelease.into());
+    }
+
+    let version = String::from_str(&setup.env, "v1.0.0");
+    setup
+        .contract
+        .release_package(&setup.grogu, &id, &name, &version);
+    let err = setup
+        .contract
+        .try_release_package(&setup.grogu, &id, &name, &version)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidPackage.into());
+
+    // committers cannot release nor manage packages
+    setup.contract.set_maintainer_role(
+        &setup.grogu,
+        &id,
+        &setup.mando,
+        &types::MaintainerRole::Committer,
+    );
+    setup.contract.set_maintainer_role(
+        &setup.mando,
+        &id,
+        &setup.mando,
+        &types::MaintainerRole::Committer,
+    );
+    let err = setup
+        .contract
+        .try_release_package(
+            &setup.mando,
+            &id,
+            &name,
+            &String::from_str(&setup.env, "v1.1.0"),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InsufficientRole.into());
+    let err = setup
+        .contract
+        .try_remove_package(&setup.mando, &id, &name)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InsufficientRole.into());
+
+    let outsider = Address::generate(&setup.env);
+    let err = setup
+        .contract
+        .try_add_package(&outsider, &id, &String::from_str(&setup.env, "dapp"))
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+}
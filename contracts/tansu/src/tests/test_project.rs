@@ -0,0 +1,1959 @@
+use super::test_utils::{community_member, create_test_data, init_contract};
+use crate::{
+    domain::domain_node,
+    domain_contract,
+    errors::{AdditionalErrors, ContractErrors},
+    types,
+};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{Address, Bytes, IntoVal, Map, String, Symbol, Val, vec};
+
+#[test]
+fn transfer_project_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let bob = Address::generate(&setup.env);
+    let new_maintainers = vec![&setup.env, bob.clone(), setup.mando.clone()];
+
+    // admins approve the transfer, the domain owner applies it
+    assert!(
+        !setup
+            .contract
+            .transfer_project(&setup.mando, &id, &bob, &new_maintainers)
+    );
+    assert_eq!(setup.contract.get_project(&id).maintainers.len(), 2);
+    assert!(
+        setup
+            .contract
+            .transfer_project(&setup.grogu, &id, &bob, &new_maintainers)
+    );
+
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        vec![&setup.env, all_events.last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (Symbol::new(&setup.env, "project_transferred"), id.clone()).into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "maintainer"),
+                            setup.grogu.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "new_maintainer"),
+                            bob.clone().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+
+    let project = setup.contract.get_project(&id);
+    assert_eq!(project.maintainers, new_maintainers);
+
+    // the domain followed the project
+    let domain_client = domain_contract::Client::new(&setup.env, &setup.domain_id);
+    let node = domain_node(&setup.env, &id);
+    match domain_client.record(&domain_contract::RecordKeys::Record(node)) {
+        Some(domain_contract::Record::Domain(domain)) => assert_eq!(domain.owner, bob),
+        _ => panic!("domain record missing"),
+    }
+
+    // previous steward lost its rights
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let err = setup
+        .contract
+        .try_commit(&setup.grogu, &id, &hash, &None, &None, &None)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+    setup.contract.commit(&bob, &id, &hash, &None, &None, &None);
+}
+
+#[test]
+fn transfer_project_errors() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let bob = Address::generate(&setup.env);
+
+    // new lead maintainer must be the first of the maintainers
+    for maintainers in [
+        vec![&setup.env, setup.mando.clone()],
+        vec![&setup.env, setup.mando.clone(), bob.clone()],
+    ] {
+        let err = setup
+            .contract
+            .try_transfer_project(&setup.grogu, &id, &bob, &maintainers)
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+    }
+
+    // new maintainers are validated against the project configuration
+    let mut too_many = vec![&setup.env, bob.clone()];
+    for _ in 0..types::MAX_MAINTAINERS {
+        too_many.push_back(Address::generate(&setup.env));
+    }
+    for maintainers in [vec![&setup.env, bob.clone(), bob.clone()], too_many] {
+        let err = setup
+            .contract
+            .try_transfer_project(&setup.grogu, &id, &bob, &maintainers)
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, ContractErrors::InvalidMaintainersConfig.into());
+    }
+
+    // only the domain owner can apply the transfer
+    let maintainers = vec![&setup.env, bob.clone()];
+    assert!(
+        !setup
+            .contract
+            .transfer_project(&setup.grogu, &id, &bob, &maintainers)
+    );
+    let err = setup
+        .contract
+        .try_transfer_project(&setup.mando, &id, &bob, &maintainers)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::MaintainerNotDomainOwner.into());
+
+    // committers cannot transfer the project
+    assert!(!setup.contract.set_maintainer_role(
+        &setup.grogu,
+        &id,
+        &setup.mando,
+        &types::MaintainerRole::Committer
+    ));
+    assert!(setup.contract.set_maintainer_role(
+        &setup.mando,
+        &id,
+        &setup.mando,
+        &types::MaintainerRole::Committer
+    ));
+    let err = setup
+        .contract
+        .try_transfer_project(
+            &setup.mando,
+            &id,
+            &setup.mando,
+            &vec![&setup.env, setup.mando.clone()],
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InsufficientRole.into());
+
+    // not a maintainer
+    let err = setup
+        .contract
+        .try_transfer_project(&bob, &id, &bob, &vec![&setup.env, bob.clone()])
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+}
+
+#[test]
+fn archive_project_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    setup
+        .contract
+        .commit(&setup.mando, &id, &hash, &None, &None, &None);
+
+    assert!(!setup.contract.is_archived(&id));
+
+    // admins approve the archival
+    assert!(!setup.contract.archive_project(&setup.mando, &id));
+    assert!(!setup.contract.is_archived(&id));
+    assert!(setup.contract.archive_project(&setup.grogu, &id));
+
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        vec![&setup.env, all_events.last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (Symbol::new(&setup.env, "project_archived"), id.clone()).into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "archived"),
+                            true.into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "maintainer"),
+                            setup.grogu.clone().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    assert!(setup.contract.is_archived(&id));
+
+    // history is still readable
+    assert_eq!(setup.contract.get_commit(&id), hash);
+    assert_eq!(
+        setup.contract.get_project(&id).name,
+        String::from_str(&setup.env, "tansu")
+    );
+
+    // but frozen
+    let err = setup
+        .contract
+        .try_commit(&setup.mando, &id, &hash, &None, &None, &None)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProjectArchived.into());
+
+    let err = setup
+        .contract
+        .try_set_badges(&setup.mando, &id, &setup.grogu, &vec![&setup.env])
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProjectArchived.into());
+
+    let err = setup
+        .contract
+        .try_create_proposal(
+            &setup.grogu,
+            &id,
+            &String::from_str(&setup.env, "Integrate with xlm.sh"),
+            &String::from_str(
+                &setup.env,
+                "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+            ),
+            &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+            &true,
+            &None,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProjectArchived.into());
+
+    let err = setup
+        .contract
+        .try_archive_project(&setup.grogu, &id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProjectArchived.into());
+
+    // reactivation
+    assert!(!setup.contract.unarchive_project(&setup.mando, &id));
+    assert!(setup.contract.is_archived(&id));
+    assert!(setup.contract.unarchive_project(&setup.grogu, &id));
+    assert!(!setup.contract.is_archived(&id));
+    setup
+        .contract
+        .commit(&setup.mando, &id, &hash, &None, &None, &None);
+
+    let err = setup
+        .contract
+        .try_unarchive_project(&setup.mando, &id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, AdditionalErrors::NotArchived.into());
+
+    // committers can neither archive the project
+    assert!(!setup.contract.set_maintainer_role(
+        &setup.grogu,
+        &id,
+        &setup.mando,
+        &types::MaintainerRole::Committer
+    ));
+    assert!(setup.contract.set_maintainer_role(
+        &setup.mando,
+        &id,
+        &setup.mando,
+        &types::MaintainerRole::Committer
+    ));
+    let err = setup
+        .contract
+        .try_archive_project(&setup.mando, &id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InsufficientRole.into());
+
+    // nor unarchive it
+    assert!(setup.contract.archive_project(&setup.grogu, &id));
+    let err = setup
+        .contract
+        .try_unarchive_project(&setup.mando, &id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InsufficientRole.into());
+}
+
+#[test]
+fn pause_project_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let title = String::from_str(&setup.env, "Integrate with xlm.sh");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let voting_ends_at = setup.env.ledger().timestamp() + 3600 * 24 * 2;
+    let proposal_id = setup.contract.create_proposal(
+        &setup.grogu,
+        &id,
+        &title,
+        &ipfs,
+        &voting_ends_at,
+        &true,
+        &None,
+    );
+
+    // both maintainers must approve
+    assert!(!setup.contract.pause_project(&setup.grogu, &id));
+    assert!(!setup.contract.is_project_paused(&id));
+    assert!(setup.contract.pause_project(&setup.mando, &id));
+
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        vec![&setup.env, all_events.last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (Symbol::new(&setup.env, "project_paused"), id.clone()).into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "maintainer"),
+                            setup.mando.clone().into_val(&setup.env)
+                        ),
+                        (Symbol::new(&setup.env, "paused"), true.into_val(&setup.env)),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    assert!(setup.contract.is_project_paused(&id));
+
+    let err = setup
+        .contract
+        .try_commit(&setup.mando, &id, &hash, &None, &None, &None)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProjectPaused.into());
+
+    let err = setup
+        .contract
+        .try_create_proposal(
+            &setup.grogu,
+            &id,
+            &title,
+            &ipfs,
+            &voting_ends_at,
+            &true,
+            &None,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProjectPaused.into());
+
+    let err = setup
+        .contract
+        .try_vote(
+            &setup.mando,
+            &id,
+            &proposal_id,
+            &types::Vote::PublicVote(types::PublicVote {
+                address: setup.mando.clone(),
+                weight: 1,
+                vote_choice: types::VoteChoice::Approve,
+            }),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProjectPaused.into());
+
+    let err = setup
+        .contract
+        .try_pause_project(&setup.grogu, &id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProjectPaused.into());
+
+    // resume
+    assert!(!setup.contract.resume_project(&setup.grogu, &id));
+    assert!(setup.contract.resume_project(&setup.mando, &id));
+    assert!(!setup.contract.is_project_paused(&id));
+    setup
+        .contract
+        .commit(&setup.mando, &id, &hash, &None, &None, &None);
+
+    let err = setup
+        .contract
+        .try_resume_project(&setup.mando, &id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, AdditionalErrors::NotPaused.into());
+}
+
+#[test]
+fn update_metadata_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let empty_metadata = types::Metadata {
+        description: None,
+        website: None,
+        logo: None,
+    };
+    assert_eq!(setup.contract.get_project(&id).metadata, empty_metadata);
+
+    let metadata = types::Metadata {
+        description: Some(String::from_str(
+            &setup.env,
+            "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        )),
+        website: Some(String::from_str(&setup.env, "https://tansu.dev")),
+        logo: None,
+    };
+    setup.contract.update_metadata(&setup.grogu, &id, &metadata);
+    assert_eq!(setup.contract.get_project(&id).metadata, metadata.clone());
+
+    let new_metadata = types::Metadata {
+        logo: Some(String::from_str(
+            &setup.env,
+            "bafybeicnbbhyc4vhbuokk57lrmg4hkbvkmtcp6p3ubaptbus6kl2idthki",
+        )),
+        ..metadata.clone()
+    };
+    setup
+        .contract
+        .update_metadata(&setup.mando, &id, &new_metadata);
+
+    // the previous metadata is part of the event
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (
+                    Symbol::new(&setup.env, "project_metadata_updated"),
+                    id.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "maintainer"),
+                            setup.mando.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "metadata"),
+                            new_metadata.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "previous"),
+                            metadata.into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    assert_eq!(
+        setup.contract.get_project(&id).metadata,
+        new_metadata.clone()
+    );
+
+    let bob = Address::generate(&setup.env);
+    let err = setup
+        .contract
+        .try_update_metadata(&bob, &id, &new_metadata)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+}
+
+#[test]
+fn mirrors_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    assert_eq!(setup.contract.get_project(&id).config.mirrors.len(), 0);
+
+    let gitlab = types::RepoRef {
+        name: String::from_str(&setup.env, "gitlab"),
+        url: String::from_str(&setup.env, "https://gitlab.com/tansu/tansu"),
+    };
+    let codeberg = types::RepoRef {
+        name: String::from_str(&setup.env, "codeberg"),
+        url: String::from_str(&setup.env, "https://codeberg.org/tansu/tansu"),
+    };
+    setup.contract.add_mirror(&setup.grogu, &id, &gitlab);
+    setup.contract.add_mirror(&setup.mando, &id, &codeberg);
+
+    let project = setup.contract.get_project(&id);
+    assert_eq!(
+        project.config.mirrors,
+        vec![&setup.env, gitlab.clone(), codeberg.clone()]
+    );
+
+    // mirrors are kept when the configuration changes
+    let url = String::from_str(&setup.env, "https://github.com/tansu/tansu");
+    for maintainer in [&setup.grogu, &setup.mando] {
+        setup.contract.update_config(
+            maintainer,
+            &id,
+            &project.maintainers,
+            &url,
+            &project.config.ipfs,
+        );
+    }
+    let project = setup.contract.get_project(&id);
+    assert_eq!(project.config.url, url);
+    assert_eq!(project.config.mirrors.len(), 2);
+
+    setup.contract.remove_mirror(&setup.grogu, &id, &gitlab.url);
+
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (Symbol::new(&setup.env, "mirror_removed"), id.clone()).into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "maintainer"),
+                            setup.grogu.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "url"),
+                            gitlab.url.clone().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    assert_eq!(
+        setup.contract.get_project(&id).config.mirrors,
+        vec![&setup.env, codeberg.clone()]
+    );
+}
+
+#[test]
+fn mirrors_errors() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    for url in [
+        "http://gitlab.com/tansu/tansu",
+        "gitlab.com/tansu/tansu",
+        "https://",
+        "https://gitlab.com/tansu tansu",
+    ] {
+        let mirror = types::RepoRef {
+            name: String::from_str(&setup.env, "gitlab"),
+            url: String::from_str(&setup.env, url),
+        };
+        let err = setup
+            .contract
+            .try_add_mirror(&setup.grogu, &id, &mirror)
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, ContractErrors::InvalidUrl.into());
+    }
+
+    let gitlab = types::RepoRef {
+        name: String::from_str(&setup.env, "gitlab"),
+        url: String::from_str(&setup.env, "https://gitlab.com/tansu/tansu"),
+    };
+    setup.contract.add_mirror(&setup.grogu, &id, &gitlab);
+
+    // duplicates
+    let err = setup
+        .contract
+        .try_add_mirror(&setup.grogu, &id, &gitlab)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidUrl.into());
+
+    // unknown mirror
+    let err = setup
+        .contract
+        .try_remove_mirror(
+            &setup.grogu,
+            &id,
+            &String::from_str(&setup.env, "https://codeberg.org/tansu/tansu"),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidUrl.into());
+
+    // not a maintainer
+    let bob = Address::generate(&setup.env);
+    let err = setup
+        .contract
+        .try_remove_mirror(&bob, &id, &gitlab.url)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+}
+
+#[test]
+fn deregister_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let member = Address::generate(&setup.env);
+    setup
+        .contract
+        .add_member(&member, &String::from_str(&setup.env, "abcd"));
+    setup.contract.set_badges(
+        &setup.mando,
+        &id,
+        &member,
+        &vec![&setup.env, types::Badge::Community],
+    );
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    setup
+        .contract
+        .commit(&setup.mando, &id, &hash, &None, &None, &None);
+
+    let proposal_id = setup.contract.create_proposal(
+        &setup.grogu,
+        &id,
+        &String::from_str(&setup.env, "Integrate with xlm.sh"),
+        &String::from_str(
+            &setup.env,
+            "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        ),
+        &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+        &true,
+        &None,
+    );
+
+    // cannot deregister with an active proposal
+    let err = setup
+        .contract
+        .try_deregister(&setup.grogu, &id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProposalActive.into());
+
+    setup
+        .contract
+        .revoke_proposal(&setup.grogu, &id, &proposal_id);
+
+    // reaching the maintainers threshold starts the cooldown
+    assert!(!setup.contract.deregister(&setup.grogu, &id));
+    assert!(!setup.contract.deregister(&setup.mando, &id));
+    let executable_at = setup.env.ledger().timestamp() + types::DEREGISTRATION_DELAY;
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        vec![&setup.env, all_events.last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (
+                    Symbol::new(&setup.env, "deregistration_approved"),
+                    id.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "maintainer"),
+                            setup.mando.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "executable_at"),
+                            executable_at.into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+
+    // the project is kept during the cooldown
+    assert!(!setup.contract.deregister(&setup.grogu, &id));
+    assert_eq!(setup.contract.get_commit(&id), hash);
+
+    setup.env.ledger().set_timestamp(executable_at);
+    assert!(setup.contract.deregister(&setup.grogu, &id));
+
+    let err = setup.contract.try_get_project(&id).unwrap_err().unwrap();
+    assert_eq!(err, ContractErrors::InvalidKey.into());
+    let err = setup.contract.try_get_dao(&id, &0).unwrap_err().unwrap();
+    assert_eq!(err, ContractErrors::InvalidKey.into());
+    assert_eq!(setup.contract.get_member(&member).projects.len(), 0);
+    assert_eq!(setup.contract.get_badges(&id).community.len(), 0);
+
+    // the domain was released
+    let domain_client = domain_contract::Client::new(&setup.env, &setup.domain_id);
+    let node = domain_node(&setup.env, &id);
+    assert_eq!(
+        domain_client.record(&domain_contract::RecordKeys::Record(node)),
+        None
+    );
+
+    // the name can be registered again
+    let id_ = setup.contract.register(
+        &setup.mando,
+        &String::from_str(&setup.env, "tansu"),
+        &vec![&setup.env, setup.mando.clone()],
+        &String::from_str(&setup.env, "https://github.com/tansu"),
+        &String::from_str(
+            &setup.env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        &None,
+    );
+    assert_eq!(id_, id);
+    let err = setup.contract.try_get_commit(&id).unwrap_err().unwrap();
+    assert_eq!(err, ContractErrors::NoHashFound.into());
+}
+
+#[test]
+fn maintainers_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let project = setup.contract.get_project(&id);
+    assert_eq!(project.config.maintainers_threshold, 2);
+    assert_eq!(project.config.max_maintainers, types::MAX_MAINTAINERS);
+
+    // add a maintainer, needs both grogu and mando
+    let bob = Address::generate(&setup.env);
+    assert!(!setup.contract.add_maintainer(&setup.grogu, &id, &bob));
+
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        vec![&setup.env, all_events.last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (
+                    Symbol::new(&setup.env, "maintainers_action_approved"),
+                    id.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "action"),
+                            types::MaintainersAction::AddMaintainer(bob.clone())
+                                .into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "approvals_count"),
+                            1u32.into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "executed"),
+                            false.into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "maintainer"),
+                            setup.grogu.clone().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+
+    // approving twice does not count twice
+    assert!(!setup.contract.add_maintainer(&setup.grogu, &id, &bob));
+    assert!(setup.contract.add_maintainer(&setup.mando, &id, &bob));
+
+    let project = setup.contract.get_project(&id);
+    assert_eq!(
+        project.maintainers,
+        vec![
+            &setup.env,
+            setup.grogu.clone(),
+            setup.mando.clone(),
+            bob.clone()
+        ]
+    );
+
+    // added maintainers are committers
+    assert_eq!(
+        setup.contract.get_maintainer_role(&id, &bob),
+        types::MaintainerRole::Committer
+    );
+    let error = setup
+        .contract
+        .try_set_maintainers_config(&bob, &id, &1, &3)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::InsufficientRole.into());
+
+    // lower the threshold to a single approval
+    assert!(
+        !setup
+            .contract
+            .set_maintainers_config(&setup.grogu, &id, &1, &3)
+    );
+    assert!(
+        setup
+            .contract
+            .set_maintainers_config(&setup.mando, &id, &1, &3)
+    );
+    let project = setup.contract.get_project(&id);
+    assert_eq!(project.config.maintainers_threshold, 1);
+    assert_eq!(project.config.max_maintainers, 3);
+
+    // cap reached
+    let leia = Address::generate(&setup.env);
+    let error = setup
+        .contract
+        .try_add_maintainer(&setup.grogu, &id, &leia)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::InvalidMaintainersConfig.into());
+
+    // remove a maintainer with a single approval
+    assert!(
+        setup
+            .contract
+            .remove_maintainer(&setup.grogu, &id, &setup.mando)
+    );
+    let project = setup.contract.get_project(&id);
+    assert_eq!(
+        project.maintainers,
+        vec![&setup.env, setup.grogu.clone(), bob.clone()]
+    );
+
+    // removed maintainers cannot act anymore
+    let error = setup
+        .contract
+        .try_add_maintainer(&setup.mando, &id, &leia)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::UnauthorizedSigner.into());
+}
+
+#[test]
+fn maintainers_errors() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    // already a maintainer
+    let error = setup
+        .contract
+        .try_add_maintainer(&setup.grogu, &id, &setup.mando)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::InvalidMaintainersConfig.into());
+
+    // maintainers listed twice
+    let error = setup
+        .contract
+        .try_register(
+            &setup.mando,
+            &String::from_str(&setup.env, "kuiil"),
+            &vec![&setup.env, setup.mando.clone(), setup.mando.clone()],
+            &String::from_str(&setup.env, "https://github.com/kuiil"),
+            &String::from_str(
+                &setup.env,
+                "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+            ),
+            &None,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::InvalidMaintainersConfig.into());
+
+    // not a maintainer
+    let bob = Address::generate(&setup.env);
+    let error = setup
+        .contract
+        .try_remove_maintainer(&setup.grogu, &id, &bob)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::InvalidMaintainersConfig.into());
+
+    // threshold of 2 would not be reachable with a single maintainer
+    let error = setup
+        .contract
+        .try_remove_maintainer(&setup.grogu, &id, &setup.mando)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::InvalidMaintainersConfig.into());
+
+    // threshold above the number of maintainers
+    let error = setup
+        .contract
+        .try_set_maintainers_config(&setup.grogu, &id, &3, &10)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::InvalidMaintainersConfig.into());
+
+    // cap below the number of maintainers
+    let error = setup
+        .contract
+        .try_set_maintainers_config(&setup.grogu, &id, &1, &1)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::InvalidMaintainersConfig.into());
+
+    // outsider
+    let error = setup
+        .contract
+        .try_add_maintainer(&bob, &id, &bob)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::UnauthorizedSigner.into());
+}
+
+#[test]
+fn maintainer_roles_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    assert_eq!(
+        setup.contract.get_maintainer_role(&id, &setup.mando),
+        types::MaintainerRole::Admin
+    );
+
+    // mando only keeps commit rights
+    assert!(!setup.contract.set_maintainer_role(
+        &setup.grogu,
+        &id,
+        &setup.mando,
+        &types::MaintainerRole::Committer
+    ));
+    assert!(setup.contract.set_maintainer_role(
+        &setup.mando,
+        &id,
+        &setup.mando,
+        &types::MaintainerRole::Committer
+    ));
+    assert_eq!(
+        setup.contract.get_maintainer_role(&id, &setup.mando),
+        types::MaintainerRole::Committer
+    );
+
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    setup
+        .contract
+        .commit(&setup.mando, &id, &hash, &None, &None, &None);
+    assert_eq!(setup.contract.get_commit(&id), hash);
+
+    // proposals are open to everyone, committers included
+    let proposal_id = setup.contract.create_proposal(
+        &setup.mando,
+        &id,
+        &String::from_str(&setup.env, "Integrate with xlm.sh"),
+        &String::from_str(
+            &setup.env,
+            "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        ),
+        &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+        &true,
+        &None,
+    );
+    setup
+        .env
+        .ledger()
+        .set_timestamp(setup.env.ledger().timestamp() + 3600 * 24 * 2 + 1);
+
+    let err = setup
+        .contract
+        .try_execute(&setup.mando, &id, &proposal_id, &None, &None)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InsufficientRole.into());
+
+    let err = setup
+        .contract
+        .try_set_maintainer_role(
+            &setup.mando,
+            &id,
+            &setup.mando,
+            &types::MaintainerRole::Admin,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InsufficientRole.into());
+
+    // the last admin cannot be demoted
+    let err = setup
+        .contract
+        .try_set_maintainer_role(
+            &setup.grogu,
+            &id,
+            &setup.grogu,
+            &types::MaintainerRole::Releaser,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidMaintainersConfig.into());
+
+    let err = setup
+        .contract
+        .try_remove_maintainer(&setup.grogu, &id, &setup.grogu)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidMaintainersConfig.into());
+
+    // only admins count toward the threshold of admin actions
+    let err = setup
+        .contract
+        .try_set_maintainers_config(&setup.mando, &id, &1, &types::MAX_MAINTAINERS)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InsufficientRole.into());
+    assert!(
+        setup
+            .contract
+            .set_maintainers_config(&setup.grogu, &id, &1, &types::MAX_MAINTAINERS)
+    );
+
+    // roles are dropped with the maintainer
+    assert!(
+        setup
+            .contract
+            .remove_maintainer(&setup.grogu, &id, &setup.mando)
+    );
+    let err = setup
+        .contract
+        .try_get_maintainer_role(&id, &setup.mando)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+
+    assert!(
+        setup
+            .contract
+            .add_maintainer(&setup.grogu, &id, &setup.mando)
+    );
+    assert_eq!(
+        setup.contract.get_maintainer_role(&id, &setup.mando),
+        types::MaintainerRole::Committer
+    );
+
+    // projects registered before roles existed are migrated by the admins
+    setup.env.as_contract(&setup.contract_id, || {
+        setup
+            .env
+            .storage()
+            .persistent()
+            .remove(&types::ProjectKey::MaintainerRole(
+                id.clone(),
+                setup.grogu.clone(),
+            ));
+    });
+    setup
+        .contract
+        .migrate_maintainer_roles(&setup.contract_admin, &vec![&setup.env, id.clone()]);
+    for maintainer in [&setup.grogu, &setup.mando] {
+        assert_eq!(
+            setup.contract.get_maintainer_role(&id, maintainer),
+            types::MaintainerRole::Admin
+        );
+    }
+}
+
+#[test]
+fn stats_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    assert_eq!(
+        setup.contract.get_stats(),
+        types::Stats {
+            projects: 1,
+            proposals: 0,
+            members: 0,
+            commits: 0,
+        }
+    );
+
+    let member = Address::generate(&setup.env);
+    setup
+        .contract
+        .add_member(&member, &String::from_str(&setup.env, "abcd"));
+
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    setup
+        .contract
+        .commit(&setup.grogu, &id, &hash, &None, &None, &None);
+    setup
+        .contract
+        .commit(&setup.grogu, &id, &hash, &None, &None, &None);
+
+    setup.contract.create_proposal(
+        &setup.grogu,
+        &id,
+        &String::from_str(&setup.env, "Integrate with xlm.sh"),
+        &String::from_str(
+            &setup.env,
+            "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        ),
+        &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+        &true,
+        &None,
+    );
+
+    setup.contract.register(
+        &setup.grogu,
+        &String::from_str(&setup.env, "soroban"),
+        &vec![&setup.env, setup.grogu.clone()],
+        &String::from_str(&setup.env, "https://github.com/soroban"),
+        &String::from_str(
+            &setup.env,
+            "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+        ),
+        &None,
+    );
+
+    assert_eq!(
+        setup.contract.get_stats(),
+        types::Stats {
+            projects: 2,
+            proposals: 1,
+            members: 1,
+            commits: 2,
+        }
+    );
+}
+
+#[test]
+fn project_tags_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let url = String::from_str(&setup.env, "https://github.com/kuiil");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    let name = String::from_str(&setup.env, "kuiil");
+    let maintainers = vec![&setup.env, setup.grogu.clone()];
+    let id_kuiil = setup
+        .contract
+        .register(&setup.grogu, &name, &maintainers, &url, &ipfs, &None);
+
+    let defi = String::from_str(&setup.env, "defi");
+    let tooling = String::from_str(&setup.env, "tooling");
+    let tags = vec![
+        &setup.env,
+        String::from_str(&setup.env, "DeFi"),
+        tooling.clone(),
+    ];
+    setup.contract.set_tags(&setup.grogu, &id, &tags);
+
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (Symbol::new(&setup.env, "project_tags_updated"), id.clone()).into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "maintainer"),
+                            setup.grogu.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "tags"),
+                            vec![&setup.env, defi.clone(), tooling.clone()].into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    assert_eq!(
+        setup.contract.get_tags(&id),
+        vec![&setup.env, defi.clone(), tooling.clone()]
+    );
+
+    setup
+        .contract
+        .set_tags(&setup.grogu, &id_kuiil, &vec![&setup.env, defi.clone()]);
+    assert_eq!(
+        setup
+            .contract
+            .list_projects_by_tag(&String::from_str(&setup.env, "DEFI"), &0),
+        vec![&setup.env, id.clone(), id_kuiil.clone()]
+    );
+    assert_eq!(
+        setup.contract.list_projects_by_tag(&tooling, &0),
+        vec![&setup.env, id.clone()]
+    );
+
+    // replacing the tags updates the listings
+    setup
+        .contract
+        .set_tags(&setup.grogu, &id, &vec![&setup.env, tooling.clone()]);
+    assert_eq!(
+        setup.contract.list_projects_by_tag(&defi, &0),
+        vec![&setup.env, id_kuiil.clone()]
+    );
+
+    let err = setup
+        .contract
+        .try_set_tags(
+            &setup.grogu,
+            &id,
+            &vec![&setup.env, defi.clone(), defi.clone()],
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidTag.into());
+
+    let err = setup
+        .contract
+        .try_set_tags(
+            &setup.grogu,
+            &id,
+            &vec![&setup.env, String::from_str(&setup.env, "de fi")],
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidTag.into());
+
+    // deregistered projects leave the listings
+    setup.contract.deregister(&setup.grogu, &id_kuiil);
+    setup
+        .env
+        .ledger()
+        .set_timestamp(setup.env.ledger().timestamp() + types::DEREGISTRATION_DELAY);
+    assert!(setup.contract.deregister(&setup.grogu, &id_kuiil));
+    assert!(setup.contract.list_projects_by_tag(&defi, &0).is_empty());
+}
+
+#[test]
+fn rotate_maintainer_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    setup
+        .contract
+        .add_member(&setup.grogu, &String::from_str(&setup.env, "grogu"));
+    let badges = vec![&setup.env, types::Badge::Developer];
+    setup
+        .contract
+        .set_badges(&setup.mando, &id, &setup.grogu, &badges);
+    setup.contract.set_maintainer_role(
+        &setup.grogu,
+        &id,
+        &setup.grogu,
+        &types::MaintainerRole::Releaser,
+    );
+    setup.contract.set_maintainer_role(
+        &setup.mando,
+        &id,
+        &setup.grogu,
+        &types::MaintainerRole::Releaser,
+    );
+
+    let title = String::from_str(&setup.env, "Integrate with xlm.sh");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    let voting_ends_at = setup.env.ledger().timestamp() + 3600 * 24 * 2;
+    let proposal_id = setup.contract.create_proposal(
+        &setup.mando,
+        &id,
+        &title,
+        &ipfs,
+        &voting_ends_at,
+        &true,
+        &None,
+    );
+    setup.contract.vote(
+        &setup.grogu,
+        &id,
+        &proposal_id,
+        &types::Vote::PublicVote(types::PublicVote {
+            address: setup.grogu.clone(),
+            weight: 1,
+            vote_choice: types::VoteChoice::Approve,
+        }),
+    );
+
+    // an address which already voted cannot take over the votes
+    let kuiil = Address::generate(&setup.env);
+    setup.token_stellar.mint(&kuiil, &(10 * 10_000_000));
+    setup.contract.vote(
+        &kuiil,
+        &id,
+        &proposal_id,
+        &types::Vote::PublicVote(types::PublicVote {
+            address: kuiil.clone(),
+            weight: 1,
+            vote_choice: types::VoteChoice::Reject,
+        }),
+    );
+    let err = setup
+        .contract
+        .try_rotate_maintainer(&setup.grogu, &id, &setup.grogu, &kuiil)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::AlreadyVoted.into());
+
+    // the old key rotates itself
+    let bob = Address::generate(&setup.env);
+    assert!(
+        setup
+            .contract
+            .rotate_maintainer(&setup.grogu, &id, &setup.grogu, &bob)
+    );
+
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events.slice(all_events.len() - 1..),
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (Symbol::new(&setup.env, "maintainer_rotated"), id.clone()).into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "old_maintainer"),
+                            setup.grogu.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "new_maintainer"),
+                            bob.clone().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+
+    let project = setup.contract.get_project(&id);
+    assert_eq!(
+        project.maintainers,
+        vec![&setup.env, bob.clone(), setup.mando.clone()]
+    );
+    assert_eq!(
+        setup.contract.get_maintainer_role(&id, &bob),
+        types::MaintainerRole::Releaser
+    );
+    assert_eq!(
+        setup.contract.get_badges(&id).developer,
+        vec![&setup.env, bob.clone()]
+    );
+    assert!(setup.contract.get_member(&setup.grogu).projects.is_empty());
+    let proposal = setup.contract.get_proposal(&id, &proposal_id);
+    assert!(proposal.vote_data.votes.iter().any(|vote| match vote {
+        types::Vote::PublicVote(vote) => vote.address == bob,
+        _ => false,
+    }));
+
+    // a lost key is rotated with the maintainers threshold
+    let err = setup
+        .contract
+        .try_rotate_maintainer(&bob, &id, &setup.mando, &setup.mando)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidMaintainersConfig.into());
+
+    let din = Address::generate(&setup.env);
+    assert!(setup.contract.add_maintainer(&setup.mando, &id, &din));
+
+    let ryu = Address::generate(&setup.env);
+    assert!(
+        !setup
+            .contract
+            .rotate_maintainer(&bob, &id, &setup.mando, &ryu)
+    );
+    assert!(
+        setup
+            .contract
+            .rotate_maintainer(&din, &id, &setup.mando, &ryu)
+    );
+    let project = setup.contract.get_project(&id);
+    assert_eq!(
+        project.maintainers,
+        vec![&setup.env, bob.clone(), ryu.clone(), din.clone()]
+    );
+    let proposal = setup.contract.get_proposal(&id, &proposal_id);
+    assert_eq!(proposal.proposer, ryu);
+}
+
+#[test]
+fn update_config_threshold() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let project = setup.contract.get_project(&id);
+
+    let url = String::from_str(&setup.env, "https://github.com/evil/tansu");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+
+    // a single maintainer cannot repoint the repository
+    assert!(
+        !setup
+            .contract
+            .update_config(&setup.grogu, &id, &project.maintainers, &url, &ipfs)
+    );
+    assert_eq!(setup.contract.get_project(&id), project);
+
+    // approvals are bound to the proposed configuration
+    let other_url = String::from_str(&setup.env, "https://github.com/tansu/tansu");
+    assert!(!setup.contract.update_config(
+        &setup.mando,
+        &id,
+        &project.maintainers,
+        &other_url,
+        &ipfs
+    ));
+    assert_eq!(setup.contract.get_project(&id), project);
+
+    // approvals expire
+    setup
+        .env
+        .ledger()
+        .set_timestamp(setup.env.ledger().timestamp() + types::MAINTAINERS_APPROVAL_PERIOD);
+    assert!(
+        !setup
+            .contract
+            .update_config(&setup.mando, &id, &project.maintainers, &url, &ipfs)
+    );
+    assert_eq!(setup.contract.get_project(&id), project);
+
+    assert!(
+        setup
+            .contract
+            .update_config(&setup.grogu, &id, &project.maintainers, &url, &ipfs)
+    );
+    let updated = setup.contract.get_project(&id);
+    assert_eq!(updated.config.url, url);
+    assert_eq!(updated.config.ipfs, ipfs);
+}
+
+#[test]
+fn deprecate_project_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    assert_eq!(setup.contract.get_deprecation(&id), None);
+
+    let url = String::from_str(&setup.env, "https://github.com/kuiil");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    let name = String::from_str(&setup.env, "kuiil");
+    let maintainers = vec![&setup.env, setup.grogu.clone()];
+    let successor = setup
+        .contract
+        .register(&setup.grogu, &name, &maintainers, &url, &ipfs, &None);
+
+    // the maintainers threshold applies
+    assert!(
+        !setup
+            .contract
+            .deprecate(&setup.grogu, &id, &Some(successor.clone()))
+    );
+    assert_eq!(setup.contract.get_deprecation(&id), None);
+    assert!(
+        setup
+            .contract
+            .deprecate(&setup.mando, &id, &Some(successor.clone()))
+    );
+
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        vec![&setup.env, all_events.last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (Symbol::new(&setup.env, "project_deprecated"), id.clone()).into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "deprecated"),
+                            true.into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "maintainer"),
+                            setup.mando.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "successor"),
+                            Some(successor.clone()).into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    assert_eq!(
+        setup.contract.get_deprecation(&id),
+        Some(types::Deprecation {
+            successor: Some(successor.clone()),
+            deprecated_at: setup.env.ledger().timestamp(),
+        })
+    );
+
+    // the successor must be another registered project
+    let err = setup
+        .contract
+        .try_deprecate(&setup.mando, &id, &Some(id.clone()))
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidKey.into());
+
+    let unknown = setup
+        .contract
+        .get_project_id(&String::from_str(&setup.env, "bokatan"));
+    let err = setup
+        .contract
+        .try_deprecate(&setup.mando, &id, &Some(unknown))
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidKey.into());
+
+    // the successor can be removed
+    setup.contract.deprecate(&setup.grogu, &id, &None);
+    setup.contract.deprecate(&setup.mando, &id, &None);
+    assert_eq!(setup.contract.get_deprecation(&id).unwrap().successor, None);
+
+    // and the deprecation withdrawn
+    assert!(!setup.contract.undeprecate(&setup.grogu, &id));
+    assert!(setup.contract.get_deprecation(&id).is_some());
+    assert!(setup.contract.undeprecate(&setup.mando, &id));
+    assert_eq!(setup.contract.get_deprecation(&id), None);
+    let err = setup
+        .contract
+        .try_undeprecate(&setup.grogu, &id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidKey.into());
+}
+
+#[test]
+fn config_update_events() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let project = setup.contract.get_project(&id);
+
+    // only the changed fields are published
+    let url = String::from_str(&setup.env, "https://github.com/tansu/tansu");
+    setup.contract.update_config(
+        &setup.grogu,
+        &id,
+        &project.maintainers,
+        &url,
+        &project.config.ipfs,
+    );
+    assert!(setup.contract.update_config(
+        &setup.mando,
+        &id,
+        &project.maintainers,
+        &url,
+        &project.config.ipfs
+    ));
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events.slice(all_events.len() - 1..),
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (
+                    Symbol::new(&setup.env, "project_config_updated"),
+                    id.clone(),
+                    setup.mando.clone(),
+                    types::ConfigField::Url,
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::new(&setup.env).into_val(&setup.env),
+            ),
+        ]
+    );
+
+    let bob = Address::generate(&setup.env);
+    setup.contract.add_maintainer(&setup.grogu, &id, &bob);
+    assert!(setup.contract.add_maintainer(&setup.mando, &id, &bob));
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events.slice(all_events.len() - 1..),
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (
+                    Symbol::new(&setup.env, "project_config_updated"),
+                    id.clone(),
+                    setup.mando.clone(),
+                    types::ConfigField::Maintainers,
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::new(&setup.env).into_val(&setup.env),
+            ),
+        ]
+    );
+}
+
+#[test]
+fn project_stats_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    assert_eq!(
+        setup.contract.get_project_stats(&id),
+        types::ProjectStats::default()
+    );
+
+    let member = Address::generate(&setup.env);
+    setup
+        .contract
+        .add_member(&member, &String::from_str(&setup.env, "abcd"));
+    setup.contract.set_badges(
+        &setup.mando,
+        &id,
+        &member,
+        &vec![&setup.env, types::Badge::Developer, types::Badge::Triage],
+    );
+    // members without badges are not counted
+    setup
+        .contract
+        .add_member(&setup.grogu, &String::from_str(&setup.env, "abcd"));
+    setup
+        .contract
+        .set_badges(&setup.mando, &id, &setup.grogu, &vec![&setup.env]);
+
+    setup.env.ledger().set_sequence_number(42);
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    setup
+        .contract
+        .commit(&setup.grogu, &id, &hash, &None, &None, &None);
+
+    setup.contract.create_proposal(
+        &setup.grogu,
+        &id,
+        &String::from_str(&setup.env, "Integrate with xlm.sh"),
+        &String::from_str(
+            &setup.env,
+            "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        ),
+        &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+        &true,
+        &None,
+    );
+
+    assert_eq!(
+        setup.contract.get_project_stats(&id),
+        types::ProjectStats {
+            proposals: 1,
+            last_commit_ledger: 42,
+            members: 1,
+            voting_weight: 15_000_000,
+            ..Default::default()
+        }
+    );
+
+    // replacing and removing badges keeps the counters consistent
+    setup.contract.set_badges(
+        &setup.mando,
+        &id,
+        &member,
+        &vec![&setup.env, types::Badge::Community],
+    );
+    assert_eq!(
+        setup.contract.get_project_stats(&id).voting_weight,
+        1_000_000
+    );
+    setup
+        .contract
+        .set_badges(&setup.mando, &id, &member, &vec![&setup.env]);
+    let stats = setup.contract.get_project_stats(&id);
+    assert_eq!((stats.members, stats.voting_weight), (0, 0));
+
+    // members holding badges before the stats were tracked
+    setup.contract.set_badges(
+        &setup.mando,
+        &id,
+        &member,
+        &vec![&setup.env, types::Badge::Developer],
+    );
+    setup.env.as_contract(&setup.contract_id, || {
+        setup
+            .env
+            .storage()
+            .persistent()
+            .remove(&types::ProjectKey::Stats(id.clone()));
+    });
+    setup
+        .contract
+        .set_badges(&setup.mando, &id, &member, &vec![&setup.env]);
+    let stats = setup.contract.get_project_stats(&id);
+    assert_eq!((stats.members, stats.voting_weight), (0, 0));
+
+    let error = setup
+        .contract
+        .try_get_project_stats(&Bytes::from_array(&setup.env, &[0; 32]))
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::InvalidKey.into());
+}
+
+#[test]
+fn metadata_history_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let project = setup.contract.get_project(&id);
+
+    assert_eq!(
+        setup.contract.get_metadata_history(&id),
+        vec![
+            &setup.env,
+            types::MetadataCommitment {
+                ipfs: project.config.ipfs.clone(),
+                ledger: setup.env.ledger().sequence(),
+            }
+        ]
+    );
+
+    let cids = [
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4a",
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4b",
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4c",
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4d",
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4e",
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4f",
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4g",
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4h",
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4j",
+    ];
+    for (i, cid) in cids.iter().enumerate() {
+        setup.env.ledger().set_sequence_number(100 + i as u32);
+        let ipfs = String::from_str(&setup.env, cid);
+        for maintainer in [&setup.grogu, &setup.mando] {
+            setup.contract.update_config(
+                maintainer,
+                &id,
+                &project.maintainers,
+                &project.config.url,
+                &ipfs,
+            );
+        }
+    }
+
+    // the oldest commitment was dropped
+    let history = setup.contract.get_metadata_history(&id);
+    assert_eq!(history.len(), types::MAX_METADATA_HISTORY);
+    assert_eq!(
+        history.first().unwrap(),
+        types::MetadataCommitment {
+            ipfs: String::from_str(&setup.env, cids[0]),
+            ledger: 100,
+        }
+    );
+    assert_eq!(
+        history.last().unwrap(),
+        types::MetadataCommitment {
+            ipfs: String::from_str(&setup.env, cids[9]),
+            ledger: 109,
+        }
+    );
+
+    // keeping the same file does not add a commitment
+    let url = String::from_str(&setup.env, "https://github.com/tansu/tansu");
+    for maintainer in [&setup.grogu, &setup.mando] {
+        setup.contract.update_config(
+            maintainer,
+            &id,
+            &project.maintainers,
+            &url,
+            &String::from_str(&setup.env, cids[9]),
+        );
+    }
+    assert_eq!(setup.contract.get_metadata_history(&id), history);
+}
+
+#[test]
+fn migrate_layouts_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let proposal_id = setup.contract.create_proposal(
+        &setup.grogu,
+        &id,
+        &String::from_str(&setup.env, "Legacy proposal"),
+        &String::from_str(
+            &setup.env,
+            "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        ),
+        &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+        &true,
+        &None,
+    );
+    let project = setup.contract.get_project(&id);
+    let proposal = setup.contract.get_proposal(&id, &proposal_id);
+
+    // values stored before the upgrade
+    setup.env.as_contract(&setup.contract_id, || {
+        let storage = setup.env.storage().persistent();
+        storage.remove(&types::DaoIndexKey::Active(id.clone()));
+        storage.remove(&types::ProjectKey::WeightSnapshot(id.clone(), proposal_id));
+        storage.set(
+            &types::ProjectKey::Key(id.clone()),
+            &types::ProjectV1 {
+                name: project.name.clone(),
+                config: types::ConfigV1 {
+                    url: project.config.url.clone(),
+                    ipfs: project.config.ipfs.clone(),
+                },
+                maintainers: project.maintainers.clone(),
+            },
+        );
+        storage.set(
+            &types::ProjectKey::Badges(id.clone()),
+            &types::BadgesV1 {
+                developer: vec![&setup.env, setup.mando.clone()],
+                triage: vec![&setup.env],
+                community: vec![&setup.env],
+                verified: vec![&setup.env],
+            },
+        );
+        storage.set(
+            &types::ProjectKey::Dao(id.clone(), 0),
+            &types::DaoV1 {
+                proposals: vec![
+                    &setup.env,
+                    types::ProposalV1 {
+                        id: proposal.id,
+                        title: proposal.title.clone(),
+                        proposer: proposal.proposer.clone(),
+                        ipfs: proposal.ipfs.clone(),
+                        vote_data: types::VoteDataV1 {
+                            voting_ends_at: proposal.vote_data.voting_ends_at,
+                            public_voting: proposal.vote_data.public_voting,
+                            votes: proposal.vote_data.votes.clone(),
+                        },
+                        status: proposal.status.clone(),
+                        outcomes_contract: None,
+                    },
+                ],
+            },
+        );
+    });
+
+    let err = setup
+        .contract
+        .try_migrate_projects(&setup.grogu, &vec![&setup.env, id.clone()])
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+
+    setup
+        .contract
+        .migrate_projects(&setup.contract_admin, &vec![&setup.env, id.clone()]);
+    setup
+        .contract
+        .migrate_proposals(&setup.contract_admin, &id, &0);
+    assert_eq!(setup.contract.get_project(&id), project);
+    assert_eq!(
+        setup.contract.get_badges(&id),
+        types::Badges {
+            developer: vec![&setup.env, setup.mando.clone()],
+            triage: vec![&setup.env],
+            community: vec![&setup.env],
+            verified: vec![&setup.env],
+            reviewer: vec![&setup.env],
+        }
+    );
+    let migrated = setup.contract.get_proposal(&id, &proposal_id);
+    assert_eq!(migrated.title, proposal.title);
+    assert_eq!(migrated.vote_data.votes, proposal.vote_data.votes);
+    assert_eq!(migrated.kind, types::ProposalKind::Text);
+    assert_eq!(migrated.vote_data.eligible_weight, 0);
+    setup.env.as_contract(&setup.contract_id, || {
+        assert_eq!(
+            setup
+                .env
+                .storage()
+                .persistent()
+                .get(&types::DaoIndexKey::Active(id.clone())),
+            Some(vec![&setup.env, proposal_id])
+        );
+    });
+
+    // migrating again leaves the current layouts untouched
+    setup
+        .contract
+        .migrate_projects(&setup.contract_admin, &vec![&setup.env, id.clone()]);
+    setup
+        .contract
+        .migrate_proposals(&setup.contract_admin, &id, &0);
+    assert_eq!(setup.contract.get_project(&id), project);
+    assert_eq!(setup.contract.get_proposal(&id, &proposal_id), migrated);
+
+    let err = setup
+        .contract
+        .try_migrate_proposals(&setup.contract_admin, &id, &1)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::NoProposalorPageFound.into());
+
+    // the eligible weight of migrated proposals is unknown, their outcome
+    // is never settled before the end of the vote
+    let kuiil = community_member(&setup, &id);
+    setup.contract.vote(
+        &kuiil,
+        &id,
+        &proposal_id,
+        &types::Vote::PublicVote(types::PublicVote {
+            address: kuiil.clone(),
+            weight: types::Badge::Community as u32,
+            vote_choice: types::VoteChoice::Approve,
+        }),
+    );
+    let err = setup
+        .contract
+        .try_execute(&setup.grogu, &id, &proposal_id, &None, &None)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProposalVotingTime.into());
+}
@@ -0,0 +1,172 @@
+use super::test_utils::{create_test_data, init_contract};
+use crate::errors::{ContractErrors, ExtendedErrors};
+use registry::types;
+use soroban_sdk::testutils::{Events, Ledger};
+use soroban_sdk::{Bytes, BytesN, IntoVal, Map, String, Symbol, Val, vec};
+
+#[test]
+fn anchor_tree_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let root = BytesN::from_array(&setup.env, &[1; 32]);
+    let error = setup
+        .registry
+        .try_anchor_tree(&setup.mando, &id, &root)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::NoHashFound.into());
+
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    setup
+        .contract
+        .commit(&setup.mando, &id, &hash, &None, &None, &None);
+    setup.registry.anchor_tree(&setup.mando, &id, &root);
+
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.registry_id.clone(),
+                (Symbol::new(&setup.env, "tree_anchored"), id.clone()).into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "commit_hash"),
+                            hash.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "root"),
+                            root.clone().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+
+    assert_eq!(setup.registry.get_tree_root(&id, &hash), Some(root.clone()));
+    assert_eq!(
+        setup.registry.get_tree_anchor(&id, &root),
+        Some(types::TreeAnchor {
+            root: root.clone(),
+            commit_hash: hash.clone(),
+            timestamp: setup.env.ledger().timestamp(),
+        })
+    );
+
+    // anchors are immutable
+    let error = setup
+        .registry
+        .try_anchor_tree(&setup.mando, &id, &BytesN::from_array(&setup.env, &[2; 32]))
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::TreeAlreadyAnchored.into());
+}
+
+#[test]
+fn anchor_tree_eviction() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let hash_of = |i: u32| {
+        let mut hash = [b'0'; 40];
+        hash[38] = b'0' + (i / 10) as u8;
+        hash[39] = b'0' + (i % 10) as u8;
+        String::from_bytes(&setup.env, &hash)
+    };
+    for i in 0..=types::MAX_TREE_ANCHORS {
+        setup.env.ledger().set_sequence_number(100 + i);
+        setup
+            .contract
+            .commit(&setup.mando, &id, &hash_of(i), &None, &None, &None);
+        setup.registry.anchor_tree(
+            &setup.mando,
+            &id,
+            &BytesN::from_array(&setup.env, &[i as u8; 32]),
+        );
+    }
+
+    // the oldest anchor was dropped
+    assert_eq!(setup.registry.get_tree_root(&id, &hash_of(0)), None);
+    assert_eq!(
+        setup
+            .registry
+            .get_tree_anchor(&id, &BytesN::from_array(&setup.env, &[0; 32])),
+        None
+    );
+    assert_eq!(
+        setup.registry.get_tree_root(&id, &hash_of(1)),
+        Some(BytesN::from_array(&setup.env, &[1; 32]))
+    );
+}
+
+#[test]
+fn verify_file_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let sha256 = |data: &Bytes| -> BytesN<32> { setup.env.crypto().sha256(data).to_bytes() };
+    let node = |a: &BytesN<32>, b: &BytesN<32>| {
+        let (left, right) = if a <= b { (a, b) } else { (b, a) };
+        let mut pair = Bytes::from(left.clone());
+        pair.append(&Bytes::from(right.clone()));
+        sha256(&pair)
+    };
+    let leaves: [BytesN<32>; 4] = core::array::from_fn(|i| {
+        let mut file = Bytes::from_slice(&setup.env, b"src/lib.rs");
+        file.push_back(i as u8);
+        sha256(&file)
+    });
+    let left = node(&leaves[0], &leaves[1]);
+    let right = node(&leaves[2], &leaves[3]);
+    let root = node(&left, &right);
+
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    setup
+        .contract
+        .commit(&setup.mando, &id, &hash, &None, &None, &None);
+    setup.registry.anchor_tree(&setup.mando, &id, &root);
+    let tag = String::from_str(&setup.env, "v1.0.0");
+    setup.registry.create_release(
+        &setup.mando,
+        &id,
+        &tag,
+        &hash,
+        &String::from_str(
+            &setup.env,
+            "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        ),
+        &String::from_str(
+            &setup.env,
+            "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        ),
+        &types::ReleaseChannel::Stable,
+        &None,
+    );
+
+    let proof = vec![&setup.env, leaves[3].clone(), left.clone()];
+    for tree in [
+        types::TreeRef::Commit(hash.clone()),
+        types::TreeRef::Root(root.clone()),
+        types::TreeRef::Release(tag.clone()),
+    ] {
+        assert!(setup.registry.verify_file(&id, &tree, &leaves[2], &proof));
+        // the proof does not hold for another file
+        assert!(!setup.registry.verify_file(&id, &tree, &leaves[1], &proof));
+    }
+
+    // trees which are not anchored cannot be verified
+    let other_hash = String::from_str(&setup.env, "26d5f4c2e9b2a4d7c1f0a8b3e6d9c2f5a8b1e4d7");
+    for tree in [
+        types::TreeRef::Commit(other_hash),
+        types::TreeRef::Root(left.clone()),
+        types::TreeRef::Release(String::from_str(&setup.env, "v2.0.0")),
+    ] {
+        assert!(!setup.registry.verify_file(&id, &tree, &leaves[2], &proof));
+    }
+}
@@ -1,7 +1,11 @@
 use super::test_utils::{create_test_data, init_contract};
-use crate::{contract_versioning::domain_register, errors::ContractErrors};
-use soroban_sdk::testutils::Events;
-use soroban_sdk::{Bytes, IntoVal, Map, String, Symbol, Val, symbol_short, vec};
+use crate::{
+    domain::domain_register,
+    errors::{ContractErrors, ExtendedErrors},
+    types,
+};
+use soroban_sdk::testutils::{Events, Ledger};
+use soroban_sdk::{Bytes, IntoVal, Map, String, Symbol, Val, Vec, symbol_short, vec};
 
 #[test]
 fn register_project() {
@@ -16,8 +20,11 @@ fn register_events() {
     let setup = create_test_data();
 
     let name = String::from_str(&setup.env, "tansu");
-    let url = String::from_str(&setup.env, "github.com/tansu");
-    let ipfs = String::from_str(&setup.env, "2ef4f49fdd8fa9dc463f1f06a094c26b88710990");
+    let url = String::from_str(&setup.env, "https://github.com/tansu");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
     let maintainers = vec![&setup.env, setup.grogu.clone(), setup.mando.clone()];
 
     let genesis_amount: i128 = 1_000_000_000 * 10_000_000;
@@ -25,7 +32,7 @@ fn register_events() {
 
     let id = setup
         .contract
-        .register(&setup.grogu, &name, &maintainers, &url, &ipfs);
+        .register(&setup.grogu, &name, &maintainers, &url, &ipfs, &None);
 
     let mut all_events = setup.env.events().all();
     all_events.pop_front();
@@ -66,14 +73,17 @@ fn register_double_registration_error() {
     let _id = init_contract(&setup);
 
     let name = String::from_str(&setup.env, "tansu");
-    let url = String::from_str(&setup.env, "github.com/tansu");
-    let ipfs = String::from_str(&setup.env, "2ef4f49fdd8fa9dc463f1f06a094c26b88710990");
+    let url = String::from_str(&setup.env, "https://github.com/tansu");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
     let maintainers = vec![&setup.env, setup.grogu.clone(), setup.mando.clone()];
 
     // double registration
     let err = setup
         .contract
-        .try_register(&setup.grogu, &name, &maintainers, &url, &ipfs)
+        .try_register(&setup.grogu, &name, &maintainers, &url, &ipfs, &None)
         .unwrap_err()
         .unwrap();
     assert_eq!(err, ContractErrors::ProjectAlreadyExist.into());
@@ -88,17 +98,186 @@ fn register_name_too_long_error() {
         &setup.env,
         "soroban-versioningsoroban-versioningsoroban-versioningsoroban-versioning",
     );
-    let url = String::from_str(&setup.env, "github.com/tansu");
-    let ipfs = String::from_str(&setup.env, "2ef4f49fdd8fa9dc463f1f06a094c26b88710990");
+    let url = String::from_str(&setup.env, "https://github.com/tansu");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
     let maintainers = vec![&setup.env, setup.grogu.clone(), setup.mando.clone()];
 
     // name too long
     let err = setup
         .contract
-        .try_register(&setup.grogu, &name_long, &maintainers, &url, &ipfs)
+        .try_register(&setup.grogu, &name_long, &maintainers, &url, &ipfs, &None)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidProjectName.into());
+}
+
+#[test]
+fn cid_validation() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let url = String::from_str(&setup.env, "https://github.com/tansu");
+    let maintainers = vec![&setup.env, setup.grogu.clone()];
+    let bad_cid = String::from_str(&setup.env, "2ef4f49fdd8fa9dc463f1f06a094c26b88710990");
+
+    // tansu.toml of a project
+    let err = setup
+        .contract
+        .try_register(
+            &setup.grogu,
+            &String::from_str(&setup.env, "soroban"),
+            &maintainers,
+            &url,
+            &bad_cid,
+            &None,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::InvalidCid.into());
+
+    // project metadata
+    let logo = String::from_str(&setup.env, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG");
+    setup.contract.update_metadata(
+        &setup.grogu,
+        &id,
+        &types::Metadata {
+            description: None,
+            website: None,
+            logo: Some(logo.clone()),
+        },
+    );
+    let err = setup
+        .contract
+        .try_update_metadata(
+            &setup.grogu,
+            &id,
+            &types::Metadata {
+                description: Some(bad_cid.clone()),
+                website: None,
+                logo: Some(logo),
+            },
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::InvalidCid.into());
+
+    // proposals
+    let err = setup
+        .contract
+        .try_create_proposal(
+            &setup.grogu,
+            &id,
+            &String::from_str(&setup.env, "Integrate with xlm.sh"),
+            &bad_cid,
+            &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+            &true,
+            &None,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::InvalidCid.into());
+}
+
+#[test]
+fn url_validation() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let project = setup.contract.get_project(&id);
+
+    for bad_url in ["github.com/tansu", "http://github.com/tansu", "https://"] {
+        let bad_url = String::from_str(&setup.env, bad_url);
+
+        // Git repository of a new project
+        let err = setup
+            .contract
+            .try_register(
+                &setup.grogu,
+                &String::from_str(&setup.env, "soroban"),
+                &project.maintainers,
+                &bad_url,
+                &project.config.ipfs,
+                &None,
+            )
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, ContractErrors::InvalidUrl.into());
+
+        // and of an existing one
+        let err = setup
+            .contract
+            .try_update_config(
+                &setup.grogu,
+                &id,
+                &project.maintainers,
+                &bad_url,
+                &project.config.ipfs,
+            )
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, ContractErrors::InvalidUrl.into());
+    }
+}
+
+#[test]
+fn register_name_normalization() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let url = String::from_str(&setup.env, "https://github.com/tansu");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    let maintainers = vec![&setup.env, setup.grogu.clone(), setup.mando.clone()];
+
+    // names are case insensitive
+    let err = setup
+        .contract
+        .try_register(
+            &setup.grogu,
+            &String::from_str(&setup.env, "TanSu"),
+            &maintainers,
+            &url,
+            &ipfs,
+            &None,
+        )
         .unwrap_err()
         .unwrap();
-    assert_eq!(err, ContractErrors::InvalidDomainError.into());
+    assert_eq!(err, ContractErrors::ProjectAlreadyExist.into());
+
+    let id_ = setup.contract.register(
+        &setup.grogu,
+        &String::from_str(&setup.env, "Soroban"),
+        &maintainers,
+        &url,
+        &ipfs,
+        &None,
+    );
+    assert_ne!(id_, id);
+    assert_eq!(
+        setup.contract.get_project(&id_).name,
+        String::from_str(&setup.env, "soroban")
+    );
+
+    // charset rules and Unicode lookalikes
+    for name in ["", "tan-su", "tansu1", "tan su", "tаnsu", "tansú"] {
+        let err = setup
+            .contract
+            .try_register(
+                &setup.grogu,
+                &String::from_str(&setup.env, name),
+                &maintainers,
+                &url,
+                &ipfs,
+                &None,
+            )
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, ContractErrors::InvalidProjectName.into());
+    }
 }
 
 #[test]
@@ -107,8 +286,11 @@ fn register_maintainer_not_domain_owner_error() {
     let _id = init_contract(&setup);
 
     let _name = String::from_str(&setup.env, "tansu");
-    let url = String::from_str(&setup.env, "github.com/tansu");
-    let ipfs = String::from_str(&setup.env, "2ef4f49fdd8fa9dc463f1f06a094c26b88710990");
+    let url = String::from_str(&setup.env, "https://github.com/tansu");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
     let maintainers = vec![&setup.env, setup.grogu.clone(), setup.mando.clone()];
 
     // maintainer not domain owner
@@ -119,8 +301,426 @@ fn register_maintainer_not_domain_owner_error() {
     let name_b_str = String::from_str(&setup.env, "bob");
     let err = setup
         .contract
-        .try_register(&setup.grogu, &name_b_str, &maintainers, &url, &ipfs)
+        .try_register(&setup.grogu, &name_b_str, &maintainers, &url, &ipfs, &None)
         .unwrap_err()
         .unwrap();
     assert_eq!(err, ContractErrors::MaintainerNotDomainOwner.into());
 }
+
+#[test]
+fn register_bond_flow() {
+    let setup = create_test_data();
+
+    let name = String::from_str(&setup.env, "tansu");
+    let url = String::from_str(&setup.env, "https://github.com/tansu");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    let maintainers = vec![&setup.env, setup.grogu.clone(), setup.mando.clone()];
+
+    let genesis_amount: i128 = 1_000_000_000 * 10_000_000;
+    setup.token_stellar.mint(&setup.grogu, &genesis_amount);
+
+    // cannot pay the bond
+    setup.contract.set_bond_config(
+        &setup.contract_admin,
+        &types::BondConfig {
+            amount: 2 * genesis_amount,
+            lock_ledgers: 100,
+        },
+    );
+    let err = setup
+        .contract
+        .try_register(&setup.grogu, &name, &maintainers, &url, &ipfs, &None)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InsufficientBond.into());
+
+    let bond_config = types::BondConfig {
+        amount: 50 * 10_000_000,
+        lock_ledgers: 100,
+    };
+    setup
+        .contract
+        .set_bond_config(&setup.contract_admin, &bond_config);
+    assert_eq!(setup.contract.get_bond_config(), bond_config);
+
+    let id = setup
+        .contract
+        .register(&setup.grogu, &name, &maintainers, &url, &ipfs, &None);
+    let bond = setup.contract.get_bond(&id).unwrap();
+    assert_eq!(bond.depositor, setup.grogu);
+    assert_eq!(bond.amount, bond_config.amount);
+    assert_eq!(
+        bond.refundable_at,
+        setup.env.ledger().sequence() + bond_config.lock_ledgers
+    );
+
+    // the bond is locked for a while
+    let err = setup
+        .contract
+        .try_claim_bond(&setup.mando, &id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::BondLocked.into());
+
+    // and only refunded to active projects
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    setup
+        .contract
+        .commit(&setup.mando, &id, &hash, &None, &None, &None);
+    setup.env.ledger().set_sequence_number(bond.refundable_at);
+    let err = setup
+        .contract
+        .try_claim_bond(&setup.mando, &id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::BondLocked.into());
+
+    let balance_init = setup.token_stellar.balance(&setup.grogu);
+    let hash = String::from_str(&setup.env, "7773520bd9e6ede248fef8157b2af0b6b6b41046");
+    setup
+        .contract
+        .commit(&setup.mando, &id, &hash, &None, &None, &None);
+    setup.contract.claim_bond(&setup.mando, &id);
+
+    assert_eq!(
+        setup.token_stellar.balance(&setup.grogu),
+        balance_init + bond_config.amount
+    );
+    assert_eq!(setup.contract.get_bond(&id), None);
+
+    let err = setup
+        .contract
+        .try_claim_bond(&setup.mando, &id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidKey.into());
+}
+
+#[test]
+fn register_bond_refunded_on_deregistration() {
+    let setup = create_test_data();
+
+    let bond_config = types::BondConfig {
+        amount: 50 * 10_000_000,
+        lock_ledgers: 1_000_000,
+    };
+    setup
+        .contract
+        .set_bond_config(&setup.contract_admin, &bond_config);
+
+    let id = init_contract(&setup);
+    assert_eq!(
+        setup.token_stellar.balance(&setup.contract_id),
+        bond_config.amount
+    );
+
+    setup.contract.deregister(&setup.grogu, &id);
+    setup.contract.deregister(&setup.mando, &id);
+    setup
+        .env
+        .ledger()
+        .set_timestamp(setup.env.ledger().timestamp() + types::DEREGISTRATION_DELAY);
+    // the domain owner releases the domain
+    assert!(setup.contract.deregister(&setup.grogu, &id));
+
+    assert_eq!(setup.token_stellar.balance(&setup.contract_id), 0);
+    assert_eq!(setup.contract.get_bond(&id), None);
+}
+
+#[test]
+fn list_projects_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    assert_eq!(
+        setup.contract.list_projects(&0),
+        vec![&setup.env, id.clone()]
+    );
+
+    let url = String::from_str(&setup.env, "https://github.com/tansu");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    let maintainers = vec![&setup.env, setup.grogu.clone()];
+
+    let mut ids = vec![&setup.env, id.clone()];
+    for i in 0..types::MAX_PROJECTS_PER_PAGE as u8 {
+        let name = [b'a', b'a' + i];
+        let name = String::from_bytes(&setup.env, &name);
+        ids.push_back(setup.contract.register(
+            &setup.grogu,
+            &name,
+            &maintainers,
+            &url,
+            &ipfs,
+            &None,
+        ));
+    }
+
+    let page_0 = setup.contract.list_projects(&0);
+    assert_eq!(page_0.len(), types::MAX_PROJECTS_PER_PAGE);
+    assert_eq!(page_0, ids.slice(0..types::MAX_PROJECTS_PER_PAGE));
+    let last = ids.last().unwrap();
+    assert_eq!(
+        setup.contract.list_projects(&1),
+        vec![&setup.env, last.clone()]
+    );
+    assert_eq!(
+        setup.contract.list_projects(&2),
+        Vec::<Bytes>::new(&setup.env)
+    );
+
+    // the last project takes the place of the deregistered one
+    setup.contract.deregister(&setup.mando, &id);
+    setup.contract.deregister(&setup.grogu, &id);
+    setup
+        .env
+        .ledger()
+        .set_timestamp(setup.env.ledger().timestamp() + types::DEREGISTRATION_DELAY);
+    assert!(setup.contract.deregister(&setup.grogu, &id));
+
+    let page_0 = setup.contract.list_projects(&0);
+    assert_eq!(page_0.len(), types::MAX_PROJECTS_PER_PAGE);
+    assert_eq!(page_0.first().unwrap(), last);
+    assert!(!page_0.contains(&id));
+    assert_eq!(
+        setup.contract.list_projects(&1),
+        Vec::<Bytes>::new(&setup.env)
+    );
+}
+
+#[test]
+fn project_lookup_by_name() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let name = String::from_str(&setup.env, "TANSU");
+    assert_eq!(setup.contract.get_project_id(&name), id);
+    assert_eq!(
+        setup.contract.get_project_by_name(&name),
+        setup.contract.get_project(&id)
+    );
+
+    let name = String::from_str(&setup.env, "soroban");
+    let expected: Bytes = setup
+        .env
+        .crypto()
+        .keccak256(&Bytes::from_slice(&setup.env, b"soroban"))
+        .into();
+    assert_eq!(setup.contract.get_project_id(&name), expected);
+    let err = setup
+        .contract
+        .try_get_project_by_name(&name)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidKey.into());
+
+    let err = setup
+        .contract
+        .try_get_project_id(&String::from_str(&setup.env, "tansu-dapp"))
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidProjectName.into());
+}
+
+#[test]
+fn register_fork_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let url = String::from_str(&setup.env, "https://github.com/kuiil");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    let name = String::from_str(&setup.env, "kuiil");
+    let maintainers = vec![&setup.env, setup.mando.clone()];
+    let fork_id =
+        setup
+            .contract
+            .register_fork(&setup.mando, &name, &maintainers, &url, &ipfs, &id, &None);
+
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events.slice(all_events.len() - 1..),
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (Symbol::new(&setup.env, "project_forked"), fork_id.clone()).into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "parent_key"),
+                            id.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "maintainer"),
+                            setup.mando.clone().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    assert_eq!(setup.contract.get_fork_parent(&fork_id), Some(id.clone()));
+    assert_eq!(setup.contract.get_fork_parent(&id), None);
+    assert_eq!(
+        setup.contract.get_forks(&id),
+        vec![&setup.env, fork_id.clone()]
+    );
+
+    // the parent must be registered
+    let unknown = Bytes::from_array(&setup.env, &[0; 32]);
+    let name = String::from_str(&setup.env, "bokatan");
+    let err = setup
+        .contract
+        .try_register_fork(
+            &setup.mando,
+            &name,
+            &maintainers,
+            &url,
+            &ipfs,
+            &unknown,
+            &None,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidKey.into());
+
+    // maintainers of the parent can prune forks
+    let name = String::from_str(&setup.env, "bokatan");
+    let pruned_id =
+        setup
+            .contract
+            .register_fork(&setup.mando, &name, &maintainers, &url, &ipfs, &id, &None);
+    let err = setup
+        .contract
+        .try_remove_fork(&setup.mando, &fork_id, &pruned_id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidFork.into());
+
+    setup.contract.remove_fork(&setup.grogu, &id, &pruned_id);
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (Symbol::new(&setup.env, "fork_removed"), id.clone()).into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "fork_key"),
+                            pruned_id.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "maintainer"),
+                            setup.grogu.clone().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    assert_eq!(setup.contract.get_fork_parent(&pruned_id), None);
+    assert_eq!(
+        setup.contract.get_forks(&id),
+        vec![&setup.env, fork_id.clone()]
+    );
+    let err = setup
+        .contract
+        .try_remove_fork(&setup.grogu, &id, &pruned_id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidFork.into());
+
+    // deregistering the fork removes the link
+    setup.contract.deregister(&setup.mando, &fork_id);
+    setup
+        .env
+        .ledger()
+        .set_timestamp(setup.env.ledger().timestamp() + types::DEREGISTRATION_DELAY);
+    assert!(setup.contract.deregister(&setup.mando, &fork_id));
+    assert_eq!(setup.contract.get_fork_parent(&fork_id), None);
+    assert_eq!(setup.contract.get_forks(&id), Vec::<Bytes>::new(&setup.env));
+}
+
+#[test]
+fn register_batch_flow() {
+    let setup = create_test_data();
+    let genesis_amount: i128 = 1_000_000_000 * 10_000_000;
+    setup.token_stellar.mint(&setup.grogu, &genesis_amount);
+    setup.token_stellar.mint(&setup.mando, &genesis_amount);
+
+    let url = String::from_str(&setup.env, "https://github.com/tansu");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    let registration = |name: &str, owner: &soroban_sdk::Address| types::ProjectRegistration {
+        name: String::from_str(&setup.env, name),
+        maintainers: vec![&setup.env, setup.grogu.clone(), setup.mando.clone()],
+        url: url.clone(),
+        ipfs: ipfs.clone(),
+        license: None,
+        owner: owner.clone(),
+    };
+
+    for (name, owner) in [("tansu", &setup.grogu), ("kuiil", &setup.mando)] {
+        let name_b = Bytes::from_slice(&setup.env, name.as_bytes());
+        domain_register(&setup.env, &name_b, owner, setup.domain_id.clone());
+    }
+
+    // only contract admins can migrate projects
+    let projects = vec![
+        &setup.env,
+        registration("tansu", &setup.grogu),
+        registration("kuiil", &setup.mando),
+    ];
+    let err = setup
+        .contract
+        .try_register_batch(&setup.grogu, &projects)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+
+    // domains must already be owned by the given maintainer
+    let err = setup
+        .contract
+        .try_register_batch(
+            &setup.contract_admin,
+            &vec![&setup.env, registration("kuiil", &setup.grogu)],
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::MaintainerNotDomainOwner.into());
+
+    let keys = setup
+        .contract
+        .register_batch(&setup.contract_admin, &projects);
+    // the maintainers authorize the registration of their projects
+    let auths = setup.env.auths();
+    for signer in [&setup.contract_admin, &setup.grogu, &setup.mando] {
+        assert!(auths.iter().any(|(address, _)| address == signer));
+    }
+    assert_eq!(setup.env.events().all().len(), 2);
+    assert_eq!(keys.len(), 2);
+    assert_eq!(setup.contract.list_projects(&0), keys);
+    assert_eq!(setup.contract.get_stats().projects, 2);
+    assert_eq!(
+        setup.contract.get_project(&keys.get_unchecked(1)).name,
+        String::from_str(&setup.env, "kuiil")
+    );
+    assert_eq!(setup.contract.get_bond(&keys.get_unchecked(0)), None);
+}
@@ -0,0 +1,1862 @@
+use super::test_utils::{
+    approved_action, approved_proposal, community_member, create_test_data, init_contract,
+};
+use crate::{
+    errors::{ContractErrors, ExtendedErrors},
+    types::{Badge, DaoAction, MaintainerRole, PublicVote, Vote, VoteChoice},
+};
+use registry::types;
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{Address, BytesN, IntoVal, Map, String, Symbol, Val, vec};
+
+#[test]
+fn releases_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let tag = String::from_str(&setup.env, "v1.0.0");
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let cid = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let changelog = String::from_str(&setup.env, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG");
+    setup.registry.create_release(
+        &setup.grogu,
+        &id,
+        &tag,
+        &hash,
+        &cid,
+        &changelog,
+        &types::ReleaseChannel::Stable,
+        &None,
+    );
+
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        vec![&setup.env, all_events.last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                setup.registry_id.clone(),
+                (
+                    Symbol::new(&setup.env, "release_created"),
+                    id.clone(),
+                    tag.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "artifact_cid"),
+                            cid.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "changelog_cid"),
+                            changelog.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "channel"),
+                            types::ReleaseChannel::Stable.into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "commit_hash"),
+                            hash.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "maintainer"),
+                            setup.grogu.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "proposal_id"),
+                            ().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+
+    assert_eq!(
+        setup.registry.get_release(&id, &tag),
+        types::Release {
+            tag: tag.clone(),
+            commit_hash: hash.clone(),
+            artifact_cid: cid.clone(),
+            changelog_cid: changelog.clone(),
+            timestamp: setup.env.ledger().timestamp(),
+            yanked: None,
+            sboms: vec![&setup.env],
+            proposal_id: None,
+            toolchain: Map::new(&setup.env),
+            channel: types::ReleaseChannel::Stable,
+        }
+    );
+    assert_eq!(
+        setup.registry.get_releases(&id),
+        vec![&setup.env, tag.clone()]
+    );
+
+    // releases are independent of the latest commit
+    let next = String::from_str(&setup.env, "26d5f4c2e9b2a4d7c1f0a8b3e6d9c2f5a8b1e4d7");
+    setup
+        .contract
+        .commit(&setup.grogu, &id, &next, &None, &None, &None);
+    assert_eq!(setup.registry.get_release(&id, &tag).commit_hash, hash);
+}
+
+#[test]
+fn releases_errors() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let tag = String::from_str(&setup.env, "v1.0.0");
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let cid = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+
+    let error = setup
+        .registry
+        .try_get_release(&id, &tag)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::UnknownRelease.into());
+
+    let bob = Address::generate(&setup.env);
+    let error = setup
+        .registry
+        .try_create_release(
+            &bob,
+            &id,
+            &tag,
+            &hash,
+            &cid,
+            &cid,
+            &types::ReleaseChannel::Stable,
+            &None,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::UnauthorizedSigner.into());
+
+    setup.registry.create_release(
+        &setup.grogu,
+        &id,
+        &tag,
+        &hash,
+        &cid,
+        &cid,
+        &types::ReleaseChannel::Stable,
+        &None,
+    );
+    for tag in [tag.clone(), String::from_str(&setup.env, "")] {
+        let error = setup
+            .registry
+            .try_create_release(
+                &setup.grogu,
+                &id,
+                &tag,
+                &hash,
+                &cid,
+                &cid,
+                &types::ReleaseChannel::Stable,
+                &None,
+            )
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(error, ContractErrors::InvalidRelease.into());
+    }
+
+    let error = setup
+        .registry
+        .try_create_release(
+            &setup.grogu,
+            &id,
+            &String::from_str(&setup.env, "v2.0.0"),
+            &String::from_str(&setup.env, "deadbeef"),
+            &cid,
+            &cid,
+            &types::ReleaseChannel::Stable,
+            &None,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::InvalidCommitHash.into());
+}
+
+#[test]
+fn release_cid_validation() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let cid = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    for (tag, changelog) in [
+        ("v1.0.0", "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"),
+        (
+            "v1.1.0",
+            "zdj7WWeQ43G6JJvLWQWZpyHuAMq6uYWRjkBXFad11vE2LHhQ7",
+        ),
+        (
+            "v1.2.0",
+            "k2k4r8jl0yz8qjgqbmc2cdu5hkqek5rj6flgnlkyywynci20j0iuyfuj",
+        ),
+    ] {
+        setup.registry.create_release(
+            &setup.grogu,
+            &id,
+            &String::from_str(&setup.env, tag),
+            &hash,
+            &cid,
+            &String::from_str(&setup.env, changelog),
+            &types::ReleaseChannel::Stable,
+            &None,
+        );
+    }
+
+    let tag = String::from_str(&setup.env, "v2.0.0");
+    for changelog in [
+        "",
+        "changelog",
+        // CIDv0 with the wrong length or outside of the base58 alphabet
+        "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbd",
+        "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbd0",
+        // uppercase base32
+        "BAFYBEIB6IOUPHO3P3PLIUSX7TGS7DVI6MPU2BWFHAYJ6W6IE44LO3VVC4I",
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc41",
+        // unsupported multibase
+        "fbafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4",
+    ] {
+        let error = setup
+            .registry
+            .try_create_release(
+                &setup.grogu,
+                &id,
+                &tag,
+                &hash,
+                &cid,
+                &String::from_str(&setup.env, changelog),
+                &types::ReleaseChannel::Stable,
+                &None,
+            )
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(error, ExtendedErrors::InvalidCid.into());
+    }
+}
+
+#[test]
+fn latest_release_semver_ordering() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let cid = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let release = |tag: &str| {
+        setup.registry.create_release(
+            &setup.grogu,
+            &id,
+            &String::from_str(&setup.env, tag),
+            &hash,
+            &cid,
+            &cid,
+            &types::ReleaseChannel::Stable,
+            &None,
+        );
+    };
+    let latest = || {
+        setup
+            .registry
+            .latest_release(&id)
+            .map(|release| release.tag)
+    };
+
+    assert_eq!(latest(), None);
+
+    // only pre-releases: the highest one is the latest
+    release("v2.0.0-alpha");
+    release("v2.0.0-alpha.1");
+    release("v2.0.0-alpha.beta");
+    release("v2.0.0-rc.1");
+    release("v2.0.0-beta.11");
+    release("v2.0.0-beta.2");
+    assert_eq!(latest(), Some(String::from_str(&setup.env, "v2.0.0-rc.1")));
+
+    // releases take precedence over pre-releases, regardless of insertion
+    release("v1.10.0");
+    release("v1.9.3+build.7");
+    assert_eq!(latest(), Some(String::from_str(&setup.env, "v1.10.0")));
+    release("1.10.1");
+    assert_eq!(latest(), Some(String::from_str(&setup.env, "1.10.1")));
+    release("v2.0.0");
+    release("v1.11.0");
+    assert_eq!(latest(), Some(String::from_str(&setup.env, "v2.0.0")));
+}
+
+#[test]
+fn release_tag_validation() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let cid = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+
+    for tag in [
+        "v1.2.3-rc.1",
+        "0.0.0",
+        "v10.20.30+build.2024-01-01",
+        "1.0.0-x-y-z.--",
+    ] {
+        setup.registry.create_release(
+            &setup.grogu,
+            &id,
+            &String::from_str(&setup.env, tag),
+            &hash,
+            &cid,
+            &cid,
+            &types::ReleaseChannel::Stable,
+            &None,
+        );
+    }
+
+    for tag in [
+        "",
+        "v1",
+        "v1.2",
+        "v1.2.3.4",
+        "v01.2.3",
+        "v1.2.3-",
+        "v1.2.3-rc..1",
+        "v1.2.3-rc.01",
+        "v1.2.3+",
+        "v1.2.3-rc_1",
+        "V1.2.3",
+        "vv1.2.3",
+        "v18446744073709551616.0.0",
+        "v1.0.0-aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa",
+    ] {
+        let error = setup
+            .registry
+            .try_create_release(
+                &setup.grogu,
+                &id,
+                &String::from_str(&setup.env, tag),
+                &hash,
+                &cid,
+                &cid,
+                &types::ReleaseChannel::Stable,
+                &None,
+            )
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(error, ContractErrors::InvalidRelease.into(), "{tag}");
+    }
+}
+
+#[test]
+fn yank_release_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let cid = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let reason = String::from_str(
+        &setup.env,
+        "bafybeihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku",
+    );
+    let v1 = String::from_str(&setup.env, "v1.0.0");
+    let v2 = String::from_str(&setup.env, "v2.0.0");
+    for tag in [&v1, &v2] {
+        setup.registry.create_release(
+            &setup.grogu,
+            &id,
+            tag,
+            &hash,
+            &cid,
+            &cid,
+            &types::ReleaseChannel::Stable,
+            &None,
+        );
+    }
+
+    let bob = Address::generate(&setup.env);
+    let error = setup
+        .registry
+        .try_yank_release(&bob, &id, &v2, &reason)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::UnauthorizedSigner.into());
+
+    setup.registry.yank_release(&setup.mando, &id, &v2, &reason);
+
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        vec![&setup.env, all_events.last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                setup.registry_id.clone(),
+                (
+                    Symbol::new(&setup.env, "release_yanked"),
+                    id.clone(),
+                    v2.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "maintainer"),
+                            setup.mando.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "reason_cid"),
+                            reason.clone().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+
+    // the record is kept, annotated with the reason
+    let release = setup.registry.get_release(&id, &v2);
+    assert_eq!(release.yanked, Some(reason.clone()));
+    assert_eq!(release.commit_hash, hash);
+    assert_eq!(
+        setup.registry.get_releases(&id),
+        vec![&setup.env, v1.clone(), v2.clone()]
+    );
+
+    // consumers fall back to the highest release which is not yanked
+    assert_eq!(setup.registry.latest_release(&id).unwrap().tag, v1);
+
+    // a yanked tag cannot be yanked again nor released again
+    let error = setup
+        .registry
+        .try_yank_release(&setup.mando, &id, &v2, &reason)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::InvalidRelease.into());
+    let error = setup
+        .registry
+        .try_create_release(
+            &setup.grogu,
+            &id,
+            &v2,
+            &hash,
+            &cid,
+            &cid,
+            &types::ReleaseChannel::Stable,
+            &None,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::InvalidRelease.into());
+
+    setup.registry.yank_release(&setup.mando, &id, &v1, &reason);
+    assert_eq!(setup.registry.latest_release(&id), None);
+}
+
+#[test]
+fn release_artifacts_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let tag = String::from_str(&setup.env, "v1.0.0");
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let cid = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let linux = String::from_str(&setup.env, "x86_64-unknown-linux-gnu");
+    let wasm = String::from_str(&setup.env, "wasm32v1-none");
+    let linux_hash = BytesN::from_array(&setup.env, &[1; 32]);
+    let wasm_hash = BytesN::from_array(&setup.env, &[2; 32]);
+
+    let error = setup
+        .registry
+        .try_add_artifact(&setup.grogu, &id, &tag, &linux, &linux_hash)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::UnknownRelease.into());
+
+    setup.registry.create_release(
+        &setup.grogu,
+        &id,
+        &tag,
+        &hash,
+        &cid,
+        &cid,
+        &types::ReleaseChannel::Stable,
+        &None,
+    );
+    setup
+        .registry
+        .add_artifact(&setup.grogu, &id, &tag, &linux, &linux_hash);
+
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.registry_id.clone(),
+                (
+                    Symbol::new(&setup.env, "artifact_added"),
+                    id.clone(),
+                    tag.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "platform"),
+                            linux.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "sha256"),
+                            linux_hash.clone().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+
+    setup
+        .registry
+        .add_artifact(&setup.grogu, &id, &tag, &wasm, &wasm_hash);
+    assert_eq!(
+        setup.registry.get_artifacts(&id, &tag),
+        vec![
+            &setup.env,
+            types::Artifact {
+                platform: linux.clone(),
+                sha256: linux_hash.clone(),
+            },
+            types::Artifact {
+                platform: wasm.clone(),
+                sha256: wasm_hash.clone(),
+            },
+        ]
+    );
+
+    for platform in [
+        linux.clone(),
+        String::from_str(&setup.env, ""),
+        String::from_str(&setup.env, "X86_64-unknown-linux-gnu"),
+        String::from_str(&setup.env, "linux gnu"),
+    ] {
+        let error = setup
+            .registry
+            .try_add_artifact(&setup.grogu, &id, &tag, &platform, &linux_hash)
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(error, ExtendedErrors::InvalidArtifact.into());
+    }
+
+    // yanked releases are frozen
+    setup.registry.yank_release(&setup.grogu, &id, &tag, &cid);
+    let error = setup
+        .registry
+        .try_add_artifact(
+            &setup.grogu,
+            &id,
+            &tag,
+            &String::from_str(&setup.env, "aarch64-apple-darwin"),
+            &linux_hash,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::InvalidRelease.into());
+}
+
+#[test]
+fn release_sbom_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let tag = String::from_str(&setup.env, "v1.0.0");
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let cid = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let sbom_cid = String::from_str(
+        &setup.env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    let sbom_hash = BytesN::from_array(&setup.env, &[3; 32]);
+
+    let error = setup
+        .registry
+        .try_attach_sbom(
+            &setup.grogu,
+            &id,
+            &tag,
+            &types::SbomFormat::CycloneDx,
+            &sbom_hash,
+            &sbom_cid,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::UnknownRelease.into());
+
+    setup.registry.create_release(
+        &setup.grogu,
+        &id,
+        &tag,
+        &hash,
+        &cid,
+        &cid,
+        &types::ReleaseChannel::Stable,
+        &None,
+    );
+    setup.registry.attach_sbom(
+        &setup.grogu,
+        &id,
+        &tag,
+        &types::SbomFormat::CycloneDx,
+        &sbom_hash,
+        &sbom_cid,
+    );
+
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.registry_id.clone(),
+                (
+                    Symbol::new(&setup.env, "sbom_attached"),
+                    id.clone(),
+                    tag.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "cid"),
+                            sbom_cid.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "format"),
+                            types::SbomFormat::CycloneDx.into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "sha256"),
+                            sbom_hash.clone().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+
+    setup.registry.attach_sbom(
+        &setup.grogu,
+        &id,
+        &tag,
+        &types::SbomFormat::Spdx,
+        &sbom_hash,
+        &sbom_cid,
+    );
+    assert_eq!(
+        setup.registry.get_release(&id, &tag).sboms,
+        vec![
+            &setup.env,
+            types::Sbom {
+                format: types::SbomFormat::CycloneDx,
+                sha256: sbom_hash.clone(),
+                cid: sbom_cid.clone(),
+            },
+            types::Sbom {
+                format: types::SbomFormat::Spdx,
+                sha256: sbom_hash.clone(),
+                cid: sbom_cid.clone(),
+            },
+        ]
+    );
+
+    // one SBOM per format, and it needs a CID
+    for (format, document) in [
+        (types::SbomFormat::Spdx, sbom_cid.clone()),
+        (
+            types::SbomFormat::CycloneDx,
+            String::from_str(&setup.env, ""),
+        ),
+    ] {
+        let error = setup
+            .registry
+            .try_attach_sbom(&setup.grogu, &id, &tag, &format, &sbom_hash, &document)
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(error, ExtendedErrors::InvalidArtifact.into());
+    }
+
+    let error = setup
+        .registry
+        .try_attach_sbom(
+            &Address::generate(&setup.env),
+            &id,
+            &tag,
+            &types::SbomFormat::Spdx,
+            &sbom_hash,
+            &sbom_cid,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::UnauthorizedSigner.into());
+}
+
+#[test]
+fn build_attestations_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let tag = String::from_str(&setup.env, "v1.0.0");
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let cid = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let builder_a = Address::generate(&setup.env);
+    let builder_b = Address::generate(&setup.env);
+    let builder_c = Address::generate(&setup.env);
+    let wasm_hash = BytesN::from_array(&setup.env, &[1; 32]);
+    let other_hash = BytesN::from_array(&setup.env, &[2; 32]);
+
+    setup.registry.create_release(
+        &setup.grogu,
+        &id,
+        &tag,
+        &hash,
+        &cid,
+        &cid,
+        &types::ReleaseChannel::Stable,
+        &None,
+    );
+
+    // only registered attestors
+    let error = setup
+        .registry
+        .try_attest_build(&id, &tag, &builder_a, &wasm_hash)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::NotAttestor.into());
+
+    let error = setup
+        .contract
+        .try_set_attestor(&setup.grogu, &builder_a, &true)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::UnauthorizedSigner.into());
+
+    setup
+        .contract
+        .set_attestor(&setup.contract_admin, &builder_a, &true);
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (
+                    Symbol::new(&setup.env, "attestor_updated"),
+                    builder_a.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "admin"),
+                            setup.contract_admin.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "enabled"),
+                            true.into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    assert!(setup.contract.is_attestor(&builder_a));
+    setup
+        .contract
+        .set_attestor(&setup.contract_admin, &builder_b, &true);
+    setup
+        .contract
+        .set_attestor(&setup.contract_admin, &builder_c, &true);
+
+    setup
+        .registry
+        .attest_build(&id, &tag, &builder_a, &wasm_hash);
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.registry_id.clone(),
+                (
+                    Symbol::new(&setup.env, "build_attested"),
+                    id.clone(),
+                    tag.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "builder"),
+                            builder_a.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "hash"),
+                            wasm_hash.clone().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+
+    setup
+        .registry
+        .attest_build(&id, &tag, &builder_b, &wasm_hash);
+    setup
+        .registry
+        .attest_build(&id, &tag, &builder_c, &other_hash);
+
+    let attestations = setup.registry.get_attestations(&id, &tag);
+    assert_eq!(attestations.len(), 3);
+    assert_eq!(
+        attestations.get(0).unwrap(),
+        types::Attestation {
+            builder: builder_a.clone(),
+            hash: wasm_hash.clone(),
+            timestamp: setup.env.ledger().timestamp(),
+        }
+    );
+    assert_eq!(
+        setup.registry.get_attestation_count(&id, &tag, &wasm_hash),
+        2
+    );
+    assert_eq!(
+        setup.registry.get_attestation_count(&id, &tag, &other_hash),
+        1
+    );
+
+    // one attestation per builder
+    let error = setup
+        .registry
+        .try_attest_build(&id, &tag, &builder_a, &other_hash)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::InvalidAttestation.into());
+
+    // unregistered attestors keep their past attestations
+    setup
+        .contract
+        .set_attestor(&setup.contract_admin, &builder_a, &false);
+    assert!(!setup.contract.is_attestor(&builder_a));
+    assert_eq!(setup.registry.get_attestations(&id, &tag).len(), 3);
+
+    let error = setup
+        .registry
+        .try_attest_build(
+            &id,
+            &String::from_str(&setup.env, "v2.0.0"),
+            &builder_b,
+            &wasm_hash,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::UnknownRelease.into());
+}
+
+#[test]
+fn release_audits_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let tag = String::from_str(&setup.env, "v1.0.0");
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let cid = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let report_cid = String::from_str(
+        &setup.env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
+    let auditor = Address::generate(&setup.env);
+    let second_auditor = Address::generate(&setup.env);
+
+    setup.registry.create_release(
+        &setup.grogu,
+        &id,
+        &tag,
+        &hash,
+        &cid,
+        &cid,
+        &types::ReleaseChannel::Stable,
+        &None,
+    );
+    assert_eq!(setup.registry.get_audits(&id, &tag), vec![&setup.env]);
+
+    // restricted to attestors
+    let error = setup
+        .registry
+        .try_attach_audit(
+            &id,
+            &tag,
+            &auditor,
+            &report_cid,
+            &types::AuditVerdict::Passed,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::NotAttestor.into());
+
+    setup
+        .contract
+        .set_attestor(&setup.contract_admin, &auditor, &true);
+    setup
+        .contract
+        .set_attestor(&setup.contract_admin, &second_auditor, &true);
+
+    setup.registry.attach_audit(
+        &id,
+        &tag,
+        &auditor,
+        &report_cid,
+        &types::AuditVerdict::PassedWithFindings,
+    );
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.registry_id.clone(),
+                (
+                    Symbol::new(&setup.env, "audit_attached"),
+                    id.clone(),
+                    tag.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "auditor"),
+                            auditor.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "report_cid"),
+                            report_cid.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "verdict"),
+                            types::AuditVerdict::PassedWithFindings.into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+
+    setup.registry.attach_audit(
+        &id,
+        &tag,
+        &second_auditor,
+        &cid,
+        &types::AuditVerdict::Failed,
+    );
+    assert_eq!(
+        setup.registry.get_audits(&id, &tag),
+        vec![
+            &setup.env,
+            types::Audit {
+                auditor: auditor.clone(),
+                report_cid: report_cid.clone(),
+                verdict: types::AuditVerdict::PassedWithFindings,
+                timestamp: setup.env.ledger().timestamp(),
+            },
+            types::Audit {
+                auditor: second_auditor.clone(),
+                report_cid: cid.clone(),
+                verdict: types::AuditVerdict::Failed,
+                timestamp: setup.env.ledger().timestamp(),
+            },
+        ]
+    );
+
+    // one report per auditor, and it needs a CID
+    let error = setup
+        .registry
+        .try_attach_audit(
+            &id,
+            &tag,
+            &auditor,
+            &report_cid,
+            &types::AuditVerdict::Passed,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::InvalidAudit.into());
+
+    let third_auditor = Address::generate(&setup.env);
+    setup
+        .contract
+        .set_attestor(&setup.contract_admin, &third_auditor, &true);
+    let error = setup
+        .registry
+        .try_attach_audit(
+            &id,
+            &tag,
+            &third_auditor,
+            &String::from_str(&setup.env, ""),
+            &types::AuditVerdict::Passed,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::InvalidCid.into());
+}
+
+#[test]
+fn dao_approved_release_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let cid = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let v1 = String::from_str(&setup.env, "v1.0.0");
+    let v2 = String::from_str(&setup.env, "v2.0.0");
+
+    assert!(!setup.registry.get_release_approval(&id));
+    setup
+        .registry
+        .set_release_approval(&setup.grogu, &id, &true);
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.registry_id.clone(),
+                (
+                    Symbol::new(&setup.env, "release_approval_updated"),
+                    id.clone(),
+                    setup.grogu.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [(
+                        Symbol::new(&setup.env, "required"),
+                        true.into_val(&setup.env)
+                    )],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    assert!(setup.registry.get_release_approval(&id));
+
+    let error = setup
+        .registry
+        .try_create_release(
+            &setup.grogu,
+            &id,
+            &v1,
+            &hash,
+            &cid,
+            &cid,
+            &types::ReleaseChannel::Stable,
+            &None,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::ReleaseNotApproved.into());
+
+    setup.env.ledger().set_timestamp(1234567890);
+    let voting_ends_at = 1234567890 + 3600 * 24 * 2;
+    let kuiil = Address::generate(&setup.env);
+    setup.token_stellar.mint(&kuiil, &(10 * 10_000_000));
+    setup
+        .contract
+        .add_member(&kuiil, &String::from_str(&setup.env, "test"));
+    setup.contract.set_badges(
+        &setup.mando,
+        &id,
+        &kuiil,
+        &vec![&setup.env, Badge::Community],
+    );
+
+    let proposal_id = setup.contract.create_proposal(
+        &setup.grogu,
+        &id,
+        &String::from_str(&setup.env, "Release v1.0.0"),
+        &cid,
+        &voting_ends_at,
+        &true,
+        &None,
+    );
+
+    // the vote is still ongoing
+    let error = setup
+        .registry
+        .try_create_release(
+            &setup.grogu,
+            &id,
+            &v1,
+            &hash,
+            &cid,
+            &cid,
+            &types::ReleaseChannel::Stable,
+            &Some(proposal_id),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::ReleaseNotApproved.into());
+
+    for (voter, weight) in [(&setup.mando, 1), (&kuiil, Badge::Community as u32)] {
+        setup.contract.vote(
+            voter,
+            &id,
+            &proposal_id,
+            &Vote::PublicVote(PublicVote {
+                address: voter.clone(),
+                weight,
+                vote_choice: VoteChoice::Approve,
+            }),
+        );
+    }
+    setup.env.ledger().set_timestamp(voting_ends_at + 1);
+    setup
+        .contract
+        .execute(&setup.mando, &id, &proposal_id, &None, &None);
+
+    setup.registry.create_release(
+        &setup.grogu,
+        &id,
+        &v1,
+        &hash,
+        &cid,
+        &cid,
+        &types::ReleaseChannel::Stable,
+        &Some(proposal_id),
+    );
+    assert_eq!(
+        setup.registry.get_release(&id, &v1).proposal_id,
+        Some(proposal_id)
+    );
+
+    // a proposal approves a single release
+    let error = setup
+        .registry
+        .try_create_release(
+            &setup.grogu,
+            &id,
+            &v2,
+            &hash,
+            &cid,
+            &cid,
+            &types::ReleaseChannel::Stable,
+            &Some(proposal_id),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::ReleaseNotApproved.into());
+
+    let error = setup
+        .registry
+        .try_create_release(
+            &setup.grogu,
+            &id,
+            &v2,
+            &hash,
+            &cid,
+            &cid,
+            &types::ReleaseChannel::Stable,
+            &Some(42),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::NoProposalorPageFound.into());
+
+    setup
+        .registry
+        .set_release_approval(&setup.grogu, &id, &false);
+    setup.registry.create_release(
+        &setup.grogu,
+        &id,
+        &v2,
+        &hash,
+        &cid,
+        &cid,
+        &types::ReleaseChannel::Stable,
+        &None,
+    );
+    assert_eq!(setup.registry.get_release(&id, &v2).proposal_id, None);
+}
+
+#[test]
+fn release_signing_keys_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let first = BytesN::from_array(&setup.env, &[1; 32]);
+    let second = BytesN::from_array(&setup.env, &[2; 32]);
+    let third = BytesN::from_array(&setup.env, &[3; 32]);
+
+    setup.registry.add_release_key(&setup.grogu, &id, &first);
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.registry_id.clone(),
+                (
+                    Symbol::new(&setup.env, "release_key_updated"),
+                    id.clone(),
+                    first.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "maintainer"),
+                            setup.grogu.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "revoked"),
+                            false.into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    let added = setup.env.ledger().timestamp();
+
+    let error = setup
+        .registry
+        .try_add_release_key(&setup.grogu, &id, &first)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::InvalidSigningKey.into());
+
+    // rotating keeps the old key in the history
+    setup.env.ledger().set_timestamp(added + 3600);
+    setup
+        .registry
+        .rotate_release_key(&setup.grogu, &id, &first, &second);
+    assert_eq!(
+        setup.registry.get_release_keys(&id),
+        vec![
+            &setup.env,
+            types::ReleaseSigningKey {
+                public_key: first.clone(),
+                added,
+                revoked: Some(added + 3600),
+            },
+            types::ReleaseSigningKey {
+                public_key: second.clone(),
+                added: added + 3600,
+                revoked: None,
+            },
+        ]
+    );
+    assert_eq!(
+        setup.registry.get_active_release_keys(&id),
+        vec![&setup.env, second.clone()]
+    );
+
+    // revoked keys cannot be rotated or registered again
+    let error = setup
+        .registry
+        .try_rotate_release_key(&setup.grogu, &id, &first, &third)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::UnknownSigningKey.into());
+    let error = setup
+        .registry
+        .try_add_release_key(&setup.grogu, &id, &first)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::InvalidSigningKey.into());
+
+    setup.registry.add_release_key(&setup.grogu, &id, &third);
+    setup
+        .registry
+        .revoke_release_key(&setup.grogu, &id, &second);
+    assert_eq!(
+        setup.registry.get_active_release_keys(&id),
+        vec![&setup.env, third.clone()]
+    );
+    assert_eq!(setup.registry.get_release_keys(&id).len(), 3);
+
+    // only admins manage the keys
+    for approver in [&setup.grogu, &setup.mando] {
+        setup
+            .contract
+            .set_maintainer_role(approver, &id, &setup.mando, &MaintainerRole::Releaser);
+    }
+    let error = setup
+        .registry
+        .try_revoke_release_key(&setup.mando, &id, &third)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::InsufficientRole.into());
+}
+
+#[test]
+fn release_toolchain_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let tag = String::from_str(&setup.env, "v1.0.0");
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let cid = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    setup.registry.create_release(
+        &setup.grogu,
+        &id,
+        &tag,
+        &hash,
+        &cid,
+        &cid,
+        &types::ReleaseChannel::Stable,
+        &None,
+    );
+
+    let toolchain = Map::from_array(
+        &setup.env,
+        [
+            (
+                String::from_str(&setup.env, "rustc"),
+                String::from_str(&setup.env, "1.89.0"),
+            ),
+            (
+                String::from_str(&setup.env, "soroban-sdk"),
+                String::from_str(&setup.env, "23.0.2"),
+            ),
+        ],
+    );
+    for bad in [
+        Map::new(&setup.env),
+        Map::from_array(
+            &setup.env,
+            [(
+                String::from_str(&setup.env, "Rustc"),
+                String::from_str(&setup.env, "1.89.0"),
+            )],
+        ),
+        Map::from_array(
+            &setup.env,
+            [(
+                String::from_str(&setup.env, "rustc"),
+                String::from_str(&setup.env, "1.89.0 stable"),
+            )],
+        ),
+        Map::from_array(
+            &setup.env,
+            [(
+                String::from_str(&setup.env, "rustc"),
+                String::from_str(&setup.env, ""),
+            )],
+        ),
+    ] {
+        let error = setup
+            .registry
+            .try_set_release_toolchain(&setup.grogu, &id, &tag, &bad)
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(error, ExtendedErrors::InvalidToolchain.into());
+    }
+
+    setup
+        .registry
+        .set_release_toolchain(&setup.grogu, &id, &tag, &toolchain);
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.registry_id.clone(),
+                (
+                    Symbol::new(&setup.env, "toolchain_recorded"),
+                    id.clone(),
+                    tag.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "maintainer"),
+                            setup.grogu.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "toolchain"),
+                            toolchain.clone().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    assert_eq!(setup.registry.get_release(&id, &tag).toolchain, toolchain);
+
+    // the toolchain of a release cannot change
+    let error = setup
+        .registry
+        .try_set_release_toolchain(&setup.grogu, &id, &tag, &toolchain)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::InvalidToolchain.into());
+
+    let error = setup
+        .registry
+        .try_set_release_toolchain(
+            &setup.grogu,
+            &id,
+            &String::from_str(&setup.env, "v2.0.0"),
+            &toolchain,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::UnknownRelease.into());
+}
+
+#[test]
+fn release_channels_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let cid = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let v1 = String::from_str(&setup.env, "v1.0.0");
+    let v2 = String::from_str(&setup.env, "v2.0.0");
+    let nightly = String::from_str(&setup.env, "v2.1.0-nightly.1");
+
+    setup.registry.create_release(
+        &setup.grogu,
+        &id,
+        &v1,
+        &hash,
+        &cid,
+        &cid,
+        &types::ReleaseChannel::Rc,
+        &None,
+    );
+    setup.registry.create_release(
+        &setup.grogu,
+        &id,
+        &nightly,
+        &hash,
+        &cid,
+        &cid,
+        &types::ReleaseChannel::Nightly,
+        &None,
+    );
+    assert_eq!(
+        setup
+            .registry
+            .get_channel_head(&id, &types::ReleaseChannel::Rc),
+        Some(v1.clone())
+    );
+    assert_eq!(
+        setup
+            .registry
+            .get_channel_head(&id, &types::ReleaseChannel::Nightly),
+        Some(nightly.clone())
+    );
+    assert_eq!(
+        setup
+            .registry
+            .get_channel_head(&id, &types::ReleaseChannel::Stable),
+        None
+    );
+
+    setup.registry.promote_release(
+        &setup.grogu,
+        &id,
+        &v1,
+        &types::ReleaseChannel::Stable,
+        &None,
+    );
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.registry_id.clone(),
+                (
+                    Symbol::new(&setup.env, "release_promoted"),
+                    id.clone(),
+                    v1.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "channel"),
+                            types::ReleaseChannel::Stable.into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "maintainer"),
+                            setup.grogu.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "proposal_id"),
+                            ().into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    assert_eq!(
+        setup.registry.get_release(&id, &v1).channel,
+        types::ReleaseChannel::Stable
+    );
+    assert_eq!(
+        setup
+            .registry
+            .get_channel_head(&id, &types::ReleaseChannel::Stable),
+        Some(v1.clone())
+    );
+
+    // releases only move forward
+    for channel in [types::ReleaseChannel::Rc, types::ReleaseChannel::Stable] {
+        let error = setup
+            .registry
+            .try_promote_release(&setup.grogu, &id, &v1, &channel, &None)
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(error, ContractErrors::InvalidRelease.into());
+    }
+
+    // with approvals, promotions to stable go through the DAO
+    setup
+        .registry
+        .set_release_approval(&setup.grogu, &id, &true);
+    let proposal_id = approved_proposal(&setup, &id);
+    setup.registry.create_release(
+        &setup.grogu,
+        &id,
+        &v2,
+        &hash,
+        &cid,
+        &cid,
+        &types::ReleaseChannel::Rc,
+        &Some(proposal_id),
+    );
+    let error = setup
+        .registry
+        .try_promote_release(
+            &setup.grogu,
+            &id,
+            &v2,
+            &types::ReleaseChannel::Stable,
+            &None,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::ReleaseNotApproved.into());
+    let error = setup
+        .registry
+        .try_promote_release(
+            &setup.grogu,
+            &id,
+            &v2,
+            &types::ReleaseChannel::Stable,
+            &Some(proposal_id),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::ReleaseNotApproved.into());
+    let promotion_id = approved_proposal(&setup, &id);
+    setup.registry.promote_release(
+        &setup.grogu,
+        &id,
+        &v2,
+        &types::ReleaseChannel::Stable,
+        &Some(promotion_id),
+    );
+    assert_eq!(
+        setup
+            .registry
+            .get_channel_head(&id, &types::ReleaseChannel::Stable),
+        Some(v2.clone())
+    );
+
+    // yanked releases leave the head of their channels
+    setup.registry.promote_release(
+        &setup.grogu,
+        &id,
+        &nightly,
+        &types::ReleaseChannel::Rc,
+        &None,
+    );
+    setup
+        .registry
+        .yank_release(&setup.grogu, &id, &nightly, &cid);
+    for channel in [types::ReleaseChannel::Nightly, types::ReleaseChannel::Rc] {
+        assert_eq!(setup.registry.get_channel_head(&id, &channel), None);
+    }
+    let error = setup
+        .registry
+        .try_promote_release(
+            &setup.grogu,
+            &id,
+            &nightly,
+            &types::ReleaseChannel::Stable,
+            &Some(approved_proposal(&setup, &id)),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::InvalidRelease.into());
+}
+
+#[test]
+fn archive_release_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let cid = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let create = |tag: &String| {
+        setup.registry.try_create_release(
+            &setup.grogu,
+            &id,
+            tag,
+            &hash,
+            &cid,
+            &cid,
+            &types::ReleaseChannel::Stable,
+            &None,
+        )
+    };
+    let tag = |patch: u32| {
+        let mut buffer = [0u8; 16];
+        let prefix = b"v1.0.";
+        buffer[..prefix.len()].copy_from_slice(prefix);
+        let mut len = prefix.len();
+        for (digit, from) in [(patch / 100, 100), (patch / 10 % 10, 10), (patch % 10, 1)] {
+            if patch >= from || from == 1 {
+                buffer[len] = b'0' + digit as u8;
+                len += 1;
+            }
+        }
+        String::from_bytes(&setup.env, &buffer[..len])
+    };
+    for patch in 0..types::MAX_RELEASES {
+        create(&tag(patch)).unwrap().unwrap();
+    }
+    let error = create(&tag(types::MAX_RELEASES)).unwrap_err().unwrap();
+    assert_eq!(error, ContractErrors::InvalidRelease.into());
+
+    // archiving frees a slot and keeps the record
+    let v0 = tag(0);
+    setup.registry.archive_release(&setup.mando, &id, &v0);
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        vec![&setup.env, all_events.last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                setup.registry_id.clone(),
+                (
+                    Symbol::new(&setup.env, "release_archived"),
+                    id.clone(),
+                    v0.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [(
+                        Symbol::new(&setup.env, "maintainer"),
+                        setup.mando.clone().into_val(&setup.env)
+                    ),],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    assert_eq!(
+        setup.registry.get_releases(&id).len(),
+        types::MAX_RELEASES - 1
+    );
+    assert!(!setup.registry.get_releases(&id).contains(&v0));
+    assert_eq!(setup.registry.get_release(&id, &v0).commit_hash, hash);
+
+    let error = setup
+        .registry
+        .try_archive_release(&setup.mando, &id, &v0)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::UnknownRelease.into());
+    let error = create(&v0).unwrap_err().unwrap();
+    assert_eq!(error, ContractErrors::InvalidRelease.into());
+
+    // the latest release leaves the head of its channel once archived
+    let latest = tag(types::MAX_RELEASES);
+    create(&latest).unwrap().unwrap();
+    setup.registry.archive_release(&setup.mando, &id, &latest);
+    assert_eq!(
+        setup
+            .registry
+            .get_channel_head(&id, &types::ReleaseChannel::Stable),
+        None
+    );
+    assert_eq!(setup.registry.latest_release(&id).unwrap().tag, tag(99));
+
+    // projects controlled by their DAO release through proposals
+    let kuiil = community_member(&setup, &id);
+    setup.contract.renounce_to_dao(&setup.grogu, &id);
+    setup.contract.renounce_to_dao(&setup.mando, &id);
+    let error = setup
+        .registry
+        .try_archive_release(&setup.mando, &id, &tag(1))
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::DaoControlled.into());
+
+    let error = setup
+        .contract
+        .try_create_action_proposal(
+            &setup.grogu,
+            &id,
+            &String::from_str(&setup.env, "Release v2.0.0"),
+            &cid,
+            &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+            &true,
+            &DaoAction::CreateRelease(
+                String::from_str(&setup.env, "two"),
+                hash.clone(),
+                cid.clone(),
+                cid.clone(),
+                crate::types::ReleaseChannel::Stable,
+            ),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ContractErrors::InvalidRelease.into());
+
+    let v2 = String::from_str(&setup.env, "v2.0.0");
+    for action in [
+        DaoAction::ArchiveRelease(tag(1)),
+        DaoAction::CreateRelease(
+            v2.clone(),
+            hash.clone(),
+            cid.clone(),
+            cid.clone(),
+            crate::types::ReleaseChannel::Stable,
+        ),
+    ] {
+        let proposal_id = approved_action(&setup, &id, &kuiil, &action);
+        setup
+            .contract
+            .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+    }
+    assert!(!setup.registry.get_releases(&id).contains(tag(1)));
+    assert_eq!(setup.registry.get_release(&id, &v2).proposal_id, None);
+    assert_eq!(
+        setup
+            .registry
+            .get_channel_head(&id, &types::ReleaseChannel::Stable),
+        Some(v2)
+    );
+}
+
+#[test]
+fn registry_release_actions() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    assert_eq!(setup.registry.get_tansu(), setup.contract_id);
+
+    let tag = String::from_str(&setup.env, "v1.0.0");
+    let archive = types::ReleaseAction::Archive(tag.clone());
+    assert_eq!(
+        setup.registry.check_release_action(&id, &archive),
+        Some(ContractErrors::UnknownRelease as u32)
+    );
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let cid = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let create = types::ReleaseAction::Create(
+        tag.clone(),
+        hash,
+        cid.clone(),
+        cid,
+        types::ReleaseChannel::Stable,
+    );
+    assert_eq!(setup.registry.check_release_action(&id, &create), None);
+
+    // only Tansu applies the actions of approved proposals and removes the
+    // releases of deregistered projects
+    setup.env.set_auths(&[]);
+    assert!(
+        setup
+            .registry
+            .try_apply_release_action(&id, &setup.grogu, &create)
+            .is_err()
+    );
+    assert!(setup.registry.try_remove_project(&id).is_err());
+    assert!(setup.registry.get_releases(&id).is_empty());
+}
@@ -0,0 +1,309 @@
+use super::test_utils::{approved_action, community_member, create_test_data, init_contract};
+use crate::{
+    contract_dao,
+    errors::{AdditionalErrors, ContractErrors},
+    types,
+};
+use soroban_sdk::testutils::{Events, Ledger};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Bytes, BytesN, Env, IntoVal, Map, String, Symbol, Val, token, vec};
+
+fn commitment(env: &Env, vote: &governance::types::PublicVote, salt: &Bytes) -> BytesN<32> {
+    let mut preimage = vote.clone().to_xdr(env);
+    preimage.append(salt);
+    env.crypto().sha256(&preimage).to_bytes()
+}
+
+#[test]
+fn commit_reveal_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let kuiil = community_member(&setup, &id);
+    let bossk = community_member(&setup, &id);
+    let cara = community_member(&setup, &id);
+    let title = String::from_str(&setup.env, "Hidden vote");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+
+    let config = setup.contract.get_governance_config(&id);
+    let err = setup
+        .contract
+        .try_create_action_proposal(
+            &setup.grogu,
+            &id,
+            &title,
+            &ipfs,
+            &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+            &true,
+            &types::DaoAction::SetGovernanceConfig(types::GovernanceConfig {
+                reveal_period: 366 * 24 * 3600,
+                ..config.clone()
+            }),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidGovernanceConfig.into());
+
+    let proposal_id = approved_action(
+        &setup,
+        &id,
+        &kuiil,
+        &types::DaoAction::SetGovernanceConfig(types::GovernanceConfig {
+            reveal_period: 3600,
+            ..config
+        }),
+    );
+    // proposals created before take votes directly
+    assert_eq!(
+        setup
+            .contract
+            .get_proposal(&id, &proposal_id)
+            .reveal_ends_at,
+        None
+    );
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+
+    let voting_ends_at = setup.env.ledger().timestamp() + 3600 * 24 * 2;
+    let proposal_id = setup.contract.create_proposal(
+        &setup.grogu,
+        &id,
+        &title,
+        &ipfs,
+        &voting_ends_at,
+        &true,
+        &None,
+    );
+    let reveal_ends_at = voting_ends_at + 3600;
+    assert_eq!(
+        setup
+            .contract
+            .get_proposal(&id, &proposal_id)
+            .reveal_ends_at,
+        Some(reveal_ends_at)
+    );
+
+    // votes are only taken hashed
+    let approve = governance::types::PublicVote {
+        address: kuiil.clone(),
+        weight: types::Badge::Community as u32,
+        vote_choice: governance::types::VoteChoice::Approve,
+    };
+    let err = setup
+        .contract
+        .try_vote(
+            &kuiil,
+            &id,
+            &proposal_id,
+            &types::Vote::PublicVote(types::PublicVote {
+                address: kuiil.clone(),
+                weight: types::Badge::Community as u32,
+                vote_choice: types::VoteChoice::Approve,
+            }),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::WrongVoteType.into());
+
+    let collateral = token::TokenClient::new(&setup.env, &setup.token_stellar.address);
+    let kuiil_balance = collateral.balance(&kuiil);
+    let salt = Bytes::from_slice(&setup.env, b"kuiil salt");
+    setup.governance.commit_vote(
+        &kuiil,
+        &id,
+        &proposal_id,
+        &commitment(&setup.env, &approve, &salt),
+    );
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        vec![&setup.env, all_events.last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                setup.governance_id.clone(),
+                (
+                    Symbol::new(&setup.env, "vote_committed"),
+                    id.clone(),
+                    proposal_id,
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [(
+                        Symbol::new(&setup.env, "voter"),
+                        kuiil.clone().into_val(&setup.env)
+                    ),],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+
+    // bossk never reveals its vote
+    let reject = governance::types::PublicVote {
+        address: bossk.clone(),
+        weight: types::Badge::Community as u32,
+        vote_choice: governance::types::VoteChoice::Reject,
+    };
+    setup.governance.commit_vote(
+        &bossk,
+        &id,
+        &proposal_id,
+        &commitment(&setup.env, &reject, &salt),
+    );
+    let cara_vote = governance::types::PublicVote {
+        address: cara.clone(),
+        weight: types::Badge::Community as u32,
+        vote_choice: governance::types::VoteChoice::Reject,
+    };
+    setup.governance.commit_vote(
+        &cara,
+        &id,
+        &proposal_id,
+        &commitment(&setup.env, &cara_vote, &salt),
+    );
+    assert_eq!(
+        setup
+            .governance
+            .get_vote_commitments(&id, &proposal_id)
+            .len(),
+        3
+    );
+
+    // committing takes the collateral of the vote, once
+    setup.governance.commit_vote(
+        &kuiil,
+        &id,
+        &proposal_id,
+        &commitment(&setup.env, &approve, &salt),
+    );
+    assert_eq!(
+        collateral.balance(&kuiil),
+        kuiil_balance - contract_dao::VOTE_COLLATERAL
+    );
+
+    // the proposer already abstained
+    let err = setup
+        .governance
+        .try_commit_vote(
+            &setup.grogu,
+            &id,
+            &proposal_id,
+            &commitment(&setup.env, &approve, &salt),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::AlreadyVoted.into());
+
+    // votes are revealed after the voting period
+    let err = setup
+        .governance
+        .try_reveal_vote(&kuiil, &id, &proposal_id, &approve, &salt)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProposalVotingTime.into());
+
+    setup.env.ledger().set_timestamp(voting_ends_at);
+    let err = setup
+        .governance
+        .try_commit_vote(
+            &kuiil,
+            &id,
+            &proposal_id,
+            &commitment(&setup.env, &approve, &salt),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProposalVotingTime.into());
+
+    // the vote must match the commitment
+    let err = setup
+        .governance
+        .try_reveal_vote(
+            &cara,
+            &id,
+            &proposal_id,
+            &governance::types::PublicVote {
+                vote_choice: governance::types::VoteChoice::Approve,
+                ..cara_vote.clone()
+            },
+            &salt,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, AdditionalErrors::InvalidReveal.into());
+    let err = setup
+        .governance
+        .try_reveal_vote(
+            &cara,
+            &id,
+            &proposal_id,
+            &cara_vote,
+            &Bytes::from_slice(&setup.env, b"other salt"),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, AdditionalErrors::InvalidReveal.into());
+
+    setup
+        .governance
+        .reveal_vote(&kuiil, &id, &proposal_id, &approve, &salt);
+    assert_eq!(
+        collateral.balance(&kuiil),
+        kuiil_balance - contract_dao::VOTE_COLLATERAL
+    );
+    let err = setup
+        .governance
+        .try_reveal_vote(&kuiil, &id, &proposal_id, &approve, &salt)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, AdditionalErrors::InvalidReveal.into());
+    assert_eq!(
+        setup.contract.tally(&id, &proposal_id),
+        types::Tally {
+            approve: types::Badge::Community as u128,
+            reject: 0,
+            abstain: types::Badge::Verified as u128,
+        }
+    );
+
+    // the outcome is only known at the end of the reveal period
+    let err = setup
+        .contract
+        .try_execute(&setup.grogu, &id, &proposal_id, &None, &None)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProposalVotingTime.into());
+
+    setup.env.ledger().set_timestamp(reveal_ends_at);
+    let err = setup
+        .governance
+        .try_reveal_vote(&cara, &id, &proposal_id, &cara_vote, &salt)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProposalVotingTime.into());
+
+    // unrevealed votes are not counted and their collateral is slashed
+    let vote_result = setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+    assert_eq!(vote_result, types::ProposalStatus::Approved);
+    assert_eq!(
+        setup
+            .governance
+            .get_vote_commitments(&id, &proposal_id)
+            .len(),
+        0
+    );
+    assert_eq!(collateral.balance(&kuiil), kuiil_balance);
+    assert_eq!(
+        setup
+            .contract
+            .get_treasury(&id)
+            .get(setup.token_stellar.address.clone()),
+        Some(2 * contract_dao::VOTE_COLLATERAL)
+    );
+}
@@ -0,0 +1,140 @@
+use super::test_utils::{create_test_data, init_contract};
+use crate::errors::ExtendedErrors;
+use registry::types;
+use soroban_sdk::testutils::{Address as _, Events};
+use soroban_sdk::{Address, IntoVal, Map, String, Symbol, Val, vec};
+
+#[test]
+fn reviews_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+
+    let hash = String::from_str(&setup.env, "6663520bd9e6ede248fef8157b2af0b6b6b41046");
+    let notes_cid = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+    let meta = String::from_str(&setup.env, "abcd");
+    let alice = Address::generate(&setup.env);
+    let bob = Address::generate(&setup.env);
+    for reviewer in [&alice, &bob] {
+        setup.contract.add_member(reviewer, &meta);
+    }
+
+    // members need the Reviewer badge
+    let error = setup
+        .registry
+        .try_attest_review(
+            &alice,
+            &id,
+            &hash,
+            &types::ReviewVerdict::Approved,
+            &notes_cid,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::NotReviewer.into());
+
+    let badges = vec![&setup.env, crate::types::Badge::Reviewer];
+    for reviewer in [&alice, &bob] {
+        setup
+            .contract
+            .set_badges(&setup.mando, &id, reviewer, &badges);
+    }
+    assert_eq!(
+        setup.contract.get_badges(&id).reviewer,
+        vec![&setup.env, alice.clone(), bob.clone()]
+    );
+
+    setup.registry.attest_review(
+        &alice,
+        &id,
+        &hash,
+        &types::ReviewVerdict::ChangesRequested,
+        &notes_cid,
+    );
+    assert_eq!(setup.registry.get_approval_count(&id, &hash), 0);
+
+    // reviewing again replaces the verdict
+    setup.registry.attest_review(
+        &alice,
+        &id,
+        &hash,
+        &types::ReviewVerdict::Approved,
+        &notes_cid,
+    );
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        all_events,
+        vec![
+            &setup.env,
+            (
+                setup.registry_id.clone(),
+                (
+                    Symbol::new(&setup.env, "review_attested"),
+                    id.clone(),
+                    hash.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "notes_cid"),
+                            notes_cid.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "reviewer"),
+                            alice.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "verdict"),
+                            types::ReviewVerdict::Approved.into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    setup.registry.attest_review(
+        &bob,
+        &id,
+        &hash,
+        &types::ReviewVerdict::Approved,
+        &notes_cid,
+    );
+    assert_eq!(setup.registry.get_reviews(&id, &hash).len(), 2);
+    assert_eq!(setup.registry.get_approval_count(&id, &hash), 2);
+
+    // revoking the badge withdraws the approval
+    setup
+        .contract
+        .set_badges(&setup.mando, &id, &bob, &vec![&setup.env]);
+    assert_eq!(setup.registry.get_approval_count(&id, &hash), 1);
+
+    let error = setup
+        .registry
+        .try_attest_review(
+            &alice,
+            &id,
+            &hash,
+            &types::ReviewVerdict::Approved,
+            &String::from_str(&setup.env, ""),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::InvalidCid.into());
+    let error = setup
+        .registry
+        .try_attest_review(
+            &alice,
+            &id,
+            &String::from_str(&setup.env, "6663520b"),
+            &types::ReviewVerdict::Approved,
+            &notes_cid,
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(error, ExtendedErrors::InvalidCommitHash.into());
+}
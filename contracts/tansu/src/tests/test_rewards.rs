@@ -0,0 +1,255 @@
+use super::test_utils::{
+    approved_action, community_member, create_test_data, init_contract, treasury_dao,
+};
+use crate::{
+    errors::{AdditionalErrors, ContractErrors, ExtendedErrors},
+    types,
+};
+use soroban_sdk::testutils::Ledger;
+use soroban_sdk::{String, token, vec};
+
+#[test]
+fn participation_rewards_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let kuiil = community_member(&setup, &id);
+    let bossk = community_member(&setup, &id);
+    setup.contract.set_badges(
+        &setup.mando,
+        &id,
+        &bossk,
+        &vec![&setup.env, types::Badge::Developer],
+    );
+    let token_id = setup.token_stellar.address.clone();
+    let token_client = token::TokenClient::new(&setup.env, &token_id);
+    setup
+        .contract
+        .fund_treasury(&setup.grogu, &id, &token_id, &(100 * 10_000_000));
+    treasury_dao(&setup, &id, &kuiil);
+
+    let create_proposal = |title: &str| {
+        setup.contract.create_proposal(
+            &setup.grogu,
+            &id,
+            &String::from_str(&setup.env, title),
+            &String::from_str(
+                &setup.env,
+                "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+            ),
+            &(setup.env.ledger().timestamp() + 3600 * 24 * 10),
+            &true,
+            &None,
+        )
+    };
+    let proposal_id = create_proposal("Rewarded proposal");
+    let voting_ends_at = setup
+        .contract
+        .get_proposal(&id, &proposal_id)
+        .vote_data
+        .voting_ends_at;
+
+    // pools are only funded by the DAO
+    let err = setup
+        .contract
+        .try_create_action_proposal(
+            &setup.grogu,
+            &id,
+            &String::from_str(&setup.env, "Reward nothing"),
+            &String::from_str(
+                &setup.env,
+                "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+            ),
+            &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+            &true,
+            &types::DaoAction::FundReward(proposal_id, token_id.clone(), 0),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::InvalidAmount.into());
+
+    // the remainder of the division returns to the treasury
+    let fund = approved_action(
+        &setup,
+        &id,
+        &kuiil,
+        &types::DaoAction::FundReward(proposal_id, token_id.clone(), 30 * 10_000_000 + 2),
+    );
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &fund, &None, &None);
+    let pool = setup
+        .governance
+        .get_proposal_reward(&id, &proposal_id)
+        .unwrap();
+    assert_eq!(pool.amount, 30 * 10_000_000 + 2);
+    assert_eq!(pool.claimed, 0);
+    assert_eq!(
+        setup.contract.get_treasury(&id).get(token_id.clone()),
+        Some(70 * 10_000_000 - 2)
+    );
+
+    // bossk casts twice the weight of kuiil
+    for (voter, weight, vote_choice) in [
+        (&kuiil, 1_000_000, types::VoteChoice::Approve),
+        (&bossk, 2_000_000, types::VoteChoice::Reject),
+    ] {
+        setup.contract.vote(
+            voter,
+            &id,
+            &proposal_id,
+            &types::Vote::PublicVote(types::PublicVote {
+                address: voter.clone(),
+                weight,
+                vote_choice,
+            }),
+        );
+    }
+
+    let err = setup
+        .governance
+        .try_claim_proposal_reward(&kuiil, &id, &proposal_id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProposalActive.into());
+
+    setup.env.ledger().set_timestamp(voting_ends_at + 1);
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+
+    let balance = token_client.balance(&kuiil);
+    assert_eq!(
+        setup
+            .governance
+            .claim_proposal_reward(&kuiil, &id, &proposal_id),
+        10 * 10_000_000
+    );
+    assert_eq!(token_client.balance(&kuiil), balance + 10 * 10_000_000);
+
+    let err = setup
+        .governance
+        .try_claim_proposal_reward(&kuiil, &id, &proposal_id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::RewardAlreadyClaimed.into());
+
+    // the abstain vote of the proposer is not rewarded
+    let err = setup
+        .governance
+        .try_claim_proposal_reward(&setup.grogu, &id, &proposal_id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::UnknownVote.into());
+
+    // the last claim closes the pool
+    assert_eq!(
+        setup
+            .governance
+            .claim_proposal_reward(&bossk, &id, &proposal_id),
+        20 * 10_000_000 + 1
+    );
+    assert_eq!(
+        setup.governance.get_proposal_reward(&id, &proposal_id),
+        None
+    );
+    assert_eq!(
+        setup.contract.get_treasury(&id).get(token_id.clone()),
+        Some(70 * 10_000_000 - 1)
+    );
+
+    // rewards not claimed in time return to the treasury
+    let proposal_id = create_proposal("Unclaimed proposal");
+    let voting_ends_at = setup
+        .contract
+        .get_proposal(&id, &proposal_id)
+        .vote_data
+        .voting_ends_at;
+    let fund = approved_action(
+        &setup,
+        &id,
+        &kuiil,
+        &types::DaoAction::FundReward(proposal_id, token_id.clone(), 30 * 10_000_000),
+    );
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &fund, &None, &None);
+    for voter in [&kuiil, &bossk] {
+        setup.contract.vote(
+            voter,
+            &id,
+            &proposal_id,
+            &types::Vote::PublicVote(types::PublicVote {
+                address: voter.clone(),
+                weight: 1_000_000,
+                vote_choice: types::VoteChoice::Approve,
+            }),
+        );
+    }
+    setup.env.ledger().set_timestamp(voting_ends_at + 1);
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+    setup
+        .governance
+        .claim_proposal_reward(&kuiil, &id, &proposal_id);
+
+    let err = setup
+        .governance
+        .try_close_proposal_reward(&id, &proposal_id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, AdditionalErrors::RewardClaimPeriod.into());
+
+    let claim_ends_at = setup
+        .governance
+        .get_proposal_reward(&id, &proposal_id)
+        .unwrap()
+        .claim_ends_at;
+    setup.env.ledger().set_timestamp(claim_ends_at);
+    let err = setup
+        .governance
+        .try_claim_proposal_reward(&bossk, &id, &proposal_id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, AdditionalErrors::RewardClaimPeriod.into());
+
+    setup.governance.close_proposal_reward(&id, &proposal_id);
+    assert_eq!(
+        setup.governance.get_proposal_reward(&id, &proposal_id),
+        None
+    );
+    assert_eq!(
+        setup.contract.get_treasury(&id).get(token_id.clone()),
+        Some(55 * 10_000_000 - 1)
+    );
+
+    // the pool of a cancelled proposal returns to the treasury
+    let proposal_id = create_proposal("Cancelled proposal");
+    let fund = approved_action(
+        &setup,
+        &id,
+        &kuiil,
+        &types::DaoAction::FundReward(proposal_id, token_id.clone(), 10 * 10_000_000),
+    );
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &fund, &None, &None);
+    setup
+        .contract
+        .cancel_proposal(&setup.grogu, &id, &proposal_id);
+    assert_eq!(
+        setup.governance.get_proposal_reward(&id, &proposal_id),
+        None
+    );
+    assert_eq!(
+        setup.contract.get_treasury(&id).get(token_id.clone()),
+        Some(55 * 10_000_000 - 1)
+    );
+
+    let err = setup
+        .governance
+        .try_claim_proposal_reward(&kuiil, &id, &proposal_id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::InvalidRewardPool.into());
+}
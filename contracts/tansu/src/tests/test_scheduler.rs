@@ -0,0 +1,322 @@
+use super::test_utils::{
+    approved_action, community_member, create_test_data, init_contract, treasury_dao,
+};
+use crate::{
+    errors::{ContractErrors, ExtendedErrors},
+    types,
+};
+use scheduler::types::ScheduledRun;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::testutils::{Events, Ledger};
+use soroban_sdk::{Address, IntoVal, Map, String, Symbol, Val, vec};
+
+#[test]
+fn scheduler_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let kuiil = community_member(&setup, &id);
+    let day = 3600 * 24;
+
+    let config = types::GovernanceConfig {
+        veto_period: day,
+        ..setup.contract.get_governance_config(&id)
+    };
+    let proposal_id = approved_action(
+        &setup,
+        &id,
+        &kuiil,
+        &types::DaoAction::SetGovernanceConfig(config.clone()),
+    );
+    setup
+        .env
+        .ledger()
+        .set_timestamp(setup.env.ledger().timestamp() + day);
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+
+    // only approved proposals whose vote is over can be scheduled
+    let voting_ends_at = setup.env.ledger().timestamp() + 2 * day;
+    let rejected_id = setup.contract.create_action_proposal(
+        &setup.grogu,
+        &id,
+        &String::from_str(&setup.env, "Rejected action"),
+        &String::from_str(
+            &setup.env,
+            "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        ),
+        &voting_ends_at,
+        &true,
+        &types::DaoAction::SetGovernanceConfig(config.clone()),
+    );
+    setup.contract.vote(
+        &kuiil,
+        &id,
+        &rejected_id,
+        &types::Vote::PublicVote(types::PublicVote {
+            address: kuiil.clone(),
+            weight: types::Badge::Community as u32,
+            vote_choice: types::VoteChoice::Reject,
+        }),
+    );
+    let err = setup
+        .scheduler
+        .try_schedule_execution(&setup.grogu, &id, &rejected_id, &0)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::ProposalVotingTime.into());
+
+    setup.env.ledger().set_timestamp(voting_ends_at + 1);
+    let err = setup
+        .scheduler
+        .try_schedule_execution(&setup.grogu, &id, &rejected_id, &0)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::ProposalNotApproved.into());
+
+    let new_config = types::GovernanceConfig {
+        quorum: 1,
+        ..config.clone()
+    };
+    let proposal_id = approved_action(
+        &setup,
+        &id,
+        &kuiil,
+        &types::DaoAction::SetGovernanceConfig(new_config.clone()),
+    );
+    let err = setup
+        .scheduler
+        .try_schedule_execution(&kuiil, &id, &proposal_id, &0)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::UnauthorizedSigner.into());
+
+    let ledger = setup.env.ledger().sequence() + 10;
+    setup
+        .scheduler
+        .schedule_execution(&setup.grogu, &id, &proposal_id, &ledger);
+    assert_eq!(
+        setup.scheduler.get_schedule(&id),
+        Map::from_array(&setup.env, [(proposal_id, ledger)])
+    );
+
+    // nothing is due before the ledger
+    assert!(setup.scheduler.run_due(&id).is_empty());
+
+    // proposals in their veto period stay scheduled
+    setup.env.ledger().set_sequence_number(ledger);
+    assert_eq!(
+        setup.scheduler.run_due(&id),
+        Map::from_array(&setup.env, [(proposal_id, ScheduledRun::Pending)])
+    );
+    assert_eq!(setup.scheduler.get_schedule(&id).len(), 1);
+
+    setup
+        .env
+        .ledger()
+        .set_timestamp(setup.env.ledger().timestamp() + day);
+    assert_eq!(
+        setup.scheduler.run_due(&id),
+        Map::from_array(
+            &setup.env,
+            [(
+                proposal_id,
+                ScheduledRun::Executed(scheduler::types::ProposalStatus::Approved)
+            )]
+        )
+    );
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        vec![&setup.env, all_events.last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                setup.scheduler_id.clone(),
+                (
+                    Symbol::new(&setup.env, "scheduled_proposal_run"),
+                    id.clone(),
+                    proposal_id,
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [(
+                        Symbol::new(&setup.env, "result"),
+                        ScheduledRun::Executed(scheduler::types::ProposalStatus::Approved)
+                            .into_val(&setup.env)
+                    )],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+    assert_eq!(setup.contract.get_governance_config(&id), new_config);
+    assert!(setup.scheduler.get_schedule(&id).is_empty());
+    assert_eq!(
+        setup.contract.get_proposal(&id, &proposal_id).status,
+        types::ProposalStatus::Approved
+    );
+
+    // revoked proposals leave the queue
+    let proposal_id = approved_action(
+        &setup,
+        &id,
+        &kuiil,
+        &types::DaoAction::SetGovernanceConfig(config),
+    );
+    setup
+        .scheduler
+        .schedule_execution(&setup.grogu, &id, &proposal_id, &ledger);
+    setup
+        .contract
+        .revoke_proposal(&setup.grogu, &id, &proposal_id);
+    assert!(setup.scheduler.get_schedule(&id).is_empty());
+}
+
+#[test]
+fn scheduler_failure_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let kuiil = community_member(&setup, &id);
+    let recipient = Address::generate(&setup.env);
+    let token_id = setup.token_stellar.address.clone();
+    setup
+        .contract
+        .fund_treasury(&setup.grogu, &id, &token_id, &(15 * 10_000_000));
+    treasury_dao(&setup, &id, &kuiil);
+
+    // both transfers are approved but the treasury only covers one
+    let transfer = types::DaoAction::Transfer(token_id.clone(), recipient, 10 * 10_000_000);
+    let first_id = approved_action(&setup, &id, &kuiil, &transfer);
+    let second_id = approved_action(&setup, &id, &kuiil, &transfer);
+    let ledger = setup.env.ledger().sequence();
+    for proposal_id in [first_id, second_id] {
+        setup
+            .scheduler
+            .schedule_execution(&setup.grogu, &id, &proposal_id, &ledger);
+    }
+
+    // the failing proposal is unscheduled without stopping the run
+    assert_eq!(
+        setup.scheduler.run_due(&id),
+        Map::from_array(
+            &setup.env,
+            [
+                (
+                    first_id,
+                    ScheduledRun::Executed(scheduler::types::ProposalStatus::Approved)
+                ),
+                (
+                    second_id,
+                    ScheduledRun::Failed(ExtendedErrors::InsufficientTreasury as u32)
+                ),
+            ]
+        )
+    );
+    assert!(setup.scheduler.get_schedule(&id).is_empty());
+    assert_eq!(
+        setup.contract.get_proposal(&id, &second_id).status,
+        types::ProposalStatus::Active
+    );
+    assert_eq!(
+        setup.contract.get_treasury(&id).get(token_id),
+        Some(5 * 10_000_000)
+    );
+}
+
+#[test]
+fn scheduler_outcomes_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let kuiil = community_member(&setup, &id);
+
+    // the outcomes contract fails, no contract is deployed at its address
+    let outcomes_contract = Address::generate(&setup.env);
+    let voting_ends_at = setup.env.ledger().timestamp() + 3600 * 24 * 2;
+    let proposal_id = setup.contract.create_proposal(
+        &setup.grogu,
+        &id,
+        &String::from_str(&setup.env, "Notify the outcome"),
+        &String::from_str(
+            &setup.env,
+            "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        ),
+        &voting_ends_at,
+        &true,
+        &Some(outcomes_contract.clone()),
+    );
+    setup.contract.vote(
+        &kuiil,
+        &id,
+        &proposal_id,
+        &types::Vote::PublicVote(types::PublicVote {
+            address: kuiil.clone(),
+            weight: types::Badge::Community as u32,
+            vote_choice: types::VoteChoice::Approve,
+        }),
+    );
+    setup.env.ledger().set_timestamp(voting_ends_at + 1);
+
+    // maintainers cannot execute the proposal
+    assert!(
+        setup
+            .contract
+            .try_execute(&setup.grogu, &id, &proposal_id, &None, &None)
+            .is_err()
+    );
+    assert_eq!(
+        setup.contract.get_proposal(&id, &proposal_id).status,
+        types::ProposalStatus::Active
+    );
+
+    // the scheduler executes it and reports the failing outcomes contract
+    let ledger = setup.env.ledger().sequence();
+    setup
+        .scheduler
+        .schedule_execution(&setup.grogu, &id, &proposal_id, &ledger);
+    assert_eq!(
+        setup.scheduler.run_due(&id),
+        Map::from_array(
+            &setup.env,
+            [(
+                proposal_id,
+                ScheduledRun::Executed(scheduler::types::ProposalStatus::Approved)
+            )]
+        )
+    );
+    let all_events = setup.env.events().all();
+    let events_count = all_events.len();
+    assert_eq!(
+        all_events.slice(events_count - 2..events_count - 1),
+        vec![
+            &setup.env,
+            (
+                setup.contract_id.clone(),
+                (
+                    Symbol::new(&setup.env, "outcomes_contract_invoked"),
+                    id.clone(),
+                    proposal_id,
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "contract"),
+                            outcomes_contract.into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "function"),
+                            Symbol::new(&setup.env, "approve_outcome").into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "success"),
+                            false.into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+}
@@ -0,0 +1,158 @@
+use super::test_utils::{approved_action, community_member, create_test_data, init_contract};
+use crate::{
+    errors::{AdditionalErrors, ContractErrors},
+    types,
+};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{Address, IntoVal, Map, String, Symbol, Val, vec};
+
+#[test]
+fn sponsorship_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let kuiil = community_member(&setup, &id);
+    let bossk = community_member(&setup, &id);
+    let cara = community_member(&setup, &id);
+    let rex = Address::generate(&setup.env);
+    setup
+        .contract
+        .add_member(&rex, &String::from_str(&setup.env, "test"));
+    let title = String::from_str(&setup.env, "Sponsored proposal");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+    );
+
+    let config = setup.contract.get_governance_config(&id);
+    let err = setup
+        .contract
+        .try_create_action_proposal(
+            &setup.grogu,
+            &id,
+            &title,
+            &ipfs,
+            &(setup.env.ledger().timestamp() + 3600 * 24 * 2),
+            &true,
+            &types::DaoAction::SetGovernanceConfig(types::GovernanceConfig {
+                sponsors_required: 11,
+                ..config.clone()
+            }),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidGovernanceConfig.into());
+
+    let proposal_id = approved_action(
+        &setup,
+        &id,
+        &kuiil,
+        &types::DaoAction::SetGovernanceConfig(types::GovernanceConfig {
+            sponsors_required: 2,
+            ..config
+        }),
+    );
+    assert_eq!(setup.governance.get_sponsorship(&id, &proposal_id), None);
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+
+    let voting_ends_at = setup.env.ledger().timestamp() + 3600 * 24 * 2;
+    let proposal_id = setup.contract.create_proposal(
+        &setup.grogu,
+        &id,
+        &title,
+        &ipfs,
+        &voting_ends_at,
+        &true,
+        &None,
+    );
+    assert_eq!(
+        setup.governance.get_sponsorship(&id, &proposal_id),
+        Some(governance::types::Sponsorship {
+            required: 2,
+            sponsors: vec![&setup.env],
+        })
+    );
+
+    let vote = types::Vote::PublicVote(types::PublicVote {
+        address: kuiil.clone(),
+        weight: types::Badge::Community as u32,
+        vote_choice: types::VoteChoice::Approve,
+    });
+    let err = setup
+        .contract
+        .try_vote(&kuiil, &id, &proposal_id, &vote)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, AdditionalErrors::ProposalNotSponsored.into());
+
+    // the proposer and members without badges cannot sponsor
+    for sponsor in [&setup.grogu, &rex] {
+        let err = setup
+            .governance
+            .try_sponsor(sponsor, &id, &proposal_id)
+            .unwrap_err()
+            .unwrap();
+        assert_eq!(err, AdditionalErrors::InvalidSponsor.into());
+    }
+
+    setup.governance.sponsor(&kuiil, &id, &proposal_id);
+    let err = setup
+        .governance
+        .try_sponsor(&kuiil, &id, &proposal_id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, AdditionalErrors::InvalidSponsor.into());
+
+    setup.governance.sponsor(&bossk, &id, &proposal_id);
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        vec![&setup.env, all_events.last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                setup.governance_id.clone(),
+                (
+                    Symbol::new(&setup.env, "proposal_sponsored"),
+                    id.clone(),
+                    proposal_id,
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "required"),
+                            2u32.into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "sponsor"),
+                            bossk.clone().into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "sponsors"),
+                            2u32.into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+
+    // sponsored proposals open for voting
+    let err = setup
+        .governance
+        .try_sponsor(&cara, &id, &proposal_id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, AdditionalErrors::InvalidSponsor.into());
+    setup.contract.vote(&kuiil, &id, &proposal_id, &vote);
+
+    setup.env.ledger().set_timestamp(voting_ends_at + 1);
+    let vote_result = setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+    assert_eq!(vote_result, types::ProposalStatus::Approved);
+    assert_eq!(setup.governance.get_sponsorship(&id, &proposal_id), None);
+}
@@ -0,0 +1,194 @@
+use super::test_utils::{approved_action, community_member, create_test_data, init_contract};
+use crate::{
+    errors::{ContractErrors, ExtendedErrors},
+    types,
+};
+use soroban_sdk::testutils::{Address as _, Events, Ledger};
+use soroban_sdk::{Address, IntoVal, Map, String, Symbol, Val, token, vec};
+
+#[test]
+fn staking_flow() {
+    let setup = create_test_data();
+    let id = init_contract(&setup);
+    let kuiil = community_member(&setup, &id);
+
+    // a staker does not need a badge
+    let staker = Address::generate(&setup.env);
+    let sac = setup
+        .env
+        .register_stellar_asset_contract_v2(Address::generate(&setup.env));
+    let voting_token = token::TokenClient::new(&setup.env, &sac.address());
+    token::StellarAssetClient::new(&setup.env, &sac.address()).mint(&staker, &(10 * 10_000_000));
+
+    let err = setup
+        .staking
+        .try_stake(&staker, &id, &(4 * 10_000_000))
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::StakingDisabled.into());
+
+    let day = 3600 * 24;
+    let config = types::GovernanceConfig {
+        voting_weight: types::VotingWeight::Token(sac.address()),
+        unbonding_period: day,
+        ..setup.contract.get_governance_config(&id)
+    };
+    let proposal_id = approved_action(
+        &setup,
+        &id,
+        &kuiil,
+        &types::DaoAction::SetGovernanceConfig(config),
+    );
+    setup
+        .contract
+        .execute(&setup.grogu, &id, &proposal_id, &None, &None);
+
+    let err = setup
+        .staking
+        .try_stake(&staker, &id, &0)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::InvalidAmount.into());
+
+    // a stake is at least one whole token
+    let err = setup
+        .staking
+        .try_stake(&staker, &id, &(10_000_000 - 1))
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::InvalidAmount.into());
+
+    setup.staking.stake(&staker, &id, &(4 * 10_000_000));
+    assert_eq!(voting_token.balance(&staker), 6 * 10_000_000);
+    assert_eq!(
+        setup.staking.get_stake(&id, &staker),
+        Some(staking::types::Stake {
+            token: sac.address(),
+            amount: 4 * 10_000_000,
+            unbonding: 0,
+            unbonding_ends_at: 0,
+            staked_at: setup.env.ledger().timestamp(),
+            epoch: 0,
+        })
+    );
+    assert_eq!(setup.staking.get_total_stake(&id), 4 * 10_000_000);
+
+    let err = setup
+        .staking
+        .try_unstake(&staker, &id, &(4 * 10_000_000 - 1))
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::InvalidAmount.into());
+
+    let create_proposal = || {
+        setup.contract.create_proposal(
+            &setup.grogu,
+            &id,
+            &String::from_str(&setup.env, "Vote with staked tokens"),
+            &String::from_str(
+                &setup.env,
+                "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+            ),
+            &(setup.env.ledger().timestamp() + 2 * day),
+            &true,
+            &None,
+        )
+    };
+
+    // only the staked tokens of a staker without a badge count
+    let proposal_id = create_proposal();
+    assert_eq!(
+        setup.contract.get_voting_weight(&id, &proposal_id, &staker),
+        4
+    );
+
+    // unstaked tokens stop granting weight while unbonding
+    setup.staking.unstake(&staker, &id, &(4 * 10_000_000));
+    let unbonding_ends_at = setup.env.ledger().timestamp() + day;
+    let all_events = setup.env.events().all();
+    assert_eq!(
+        vec![&setup.env, all_events.last().unwrap()],
+        vec![
+            &setup.env,
+            (
+                setup.staking_id.clone(),
+                (
+                    Symbol::new(&setup.env, "stake_changed"),
+                    id.clone(),
+                    staker.clone()
+                )
+                    .into_val(&setup.env),
+                Map::<Symbol, Val>::from_array(
+                    &setup.env,
+                    [
+                        (
+                            Symbol::new(&setup.env, "amount"),
+                            0i128.into_val(&setup.env)
+                        ),
+                        (
+                            Symbol::new(&setup.env, "unbonding"),
+                            (4 * 10_000_000i128).into_val(&setup.env)
+                        ),
+                    ],
+                )
+                .into_val(&setup.env),
+            ),
+        ]
+    );
+
+    assert_eq!(setup.staking.get_total_stake(&id), 0);
+    let proposal_id = create_proposal();
+    assert_eq!(
+        setup.contract.get_voting_weight(&id, &proposal_id, &staker),
+        types::Badge::Default as u32
+    );
+
+    let err = setup
+        .staking
+        .try_unstake(&staker, &id, &1)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::InvalidAmount.into());
+
+    let err = setup
+        .staking
+        .try_withdraw_stake(&staker, &id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::StakeUnbonding.into());
+
+    setup.env.ledger().set_timestamp(unbonding_ends_at);
+    assert_eq!(setup.staking.withdraw_stake(&staker, &id), 4 * 10_000_000);
+    assert_eq!(voting_token.balance(&staker), 10 * 10_000_000);
+    assert_eq!(setup.staking.get_stake(&id, &staker), None);
+
+    let err = setup
+        .staking
+        .try_withdraw_stake(&staker, &id)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ExtendedErrors::InvalidStake.into());
+
+    // the unbonding period is bounded
+    let config = types::GovernanceConfig {
+        unbonding_period: 366 * day,
+        ..setup.contract.get_governance_config(&id)
+    };
+    let err = setup
+        .contract
+        .try_create_action_proposal(
+            &setup.grogu,
+            &id,
+            &String::from_str(&setup.env, "Lock stakes forever"),
+            &String::from_str(
+                &setup.env,
+                "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+            ),
+            &(setup.env.ledger().timestamp() + 2 * day),
+            &true,
+            &types::DaoAction::SetGovernanceConfig(config),
+        )
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(err, ContractErrors::InvalidGovernanceConfig.into());
+}
@@ -1,11 +1,23 @@
 use crate::{Tansu, TansuClient, domain_contract, outcomes_contract, types};
-use soroban_sdk::testutils::Address as _;
-use soroban_sdk::{Address, Bytes, Env, Executable, String, Vec, token, vec};
+use governance::{Governance, GovernanceClient};
+use registry::{Registry, RegistryClient};
+use scheduler::{Scheduler, SchedulerClient};
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{Address, Bytes, Env, Executable, String, U256, Vec, token, vec};
+use staking::{Staking, StakingClient};
 
 pub struct TestSetup {
     pub env: Env,
     pub contract: TansuClient<'static>,
     pub contract_id: Address,
+    pub registry: RegistryClient<'static>,
+    pub registry_id: Address,
+    pub staking: StakingClient<'static>,
+    pub staking_id: Address,
+    pub scheduler: SchedulerClient<'static>,
+    pub scheduler_id: Address,
+    pub governance: GovernanceClient<'static>,
+    pub governance_id: Address,
     pub domain_id: Address,
     pub outcomes_id: Address,
     pub token_stellar: token::StellarAssetClient<'static>,
@@ -77,6 +89,38 @@ pub fn create_test_data() -> TestSetup {
     };
     contract.set_collateral_contract(&contract_admin, &new_collateral);
 
+    let registry_id = env.register(Registry, (&contract_id,));
+    let registry = RegistryClient::new(&env, &registry_id);
+    let new_registry = types::Contract {
+        address: registry_id.clone(),
+        wasm_hash: None,
+    };
+    contract.set_registry_contract(&contract_admin, &new_registry);
+
+    let staking_id = env.register(Staking, (&contract_id,));
+    let staking = StakingClient::new(&env, &staking_id);
+    let new_staking = types::Contract {
+        address: staking_id.clone(),
+        wasm_hash: None,
+    };
+    contract.set_staking_contract(&contract_admin, &new_staking);
+
+    let scheduler_id = env.register(Scheduler, (&contract_id,));
+    let scheduler = SchedulerClient::new(&env, &scheduler_id);
+    let new_scheduler = types::Contract {
+        address: scheduler_id.clone(),
+        wasm_hash: None,
+    };
+    contract.set_scheduler_contract(&contract_admin, &new_scheduler);
+
+    let governance_id = env.register(Governance, (&contract_id,));
+    let governance = GovernanceClient::new(&env, &governance_id);
+    let new_governance = types::Contract {
+        address: governance_id.clone(),
+        wasm_hash: None,
+    };
+    contract.set_governance_contract(&contract_admin, &new_governance);
+
     let grogu = Address::generate(&env);
     let mando = Address::generate(&env);
 
@@ -84,6 +128,14 @@ pub fn create_test_data() -> TestSetup {
         env,
         contract,
         contract_id,
+        registry,
+        registry_id,
+        staking,
+        staking_id,
+        scheduler,
+        scheduler_id,
+        governance,
+        governance_id,
         domain_id,
         outcomes_id,
         token_stellar,
@@ -95,8 +147,11 @@ pub fn create_test_data() -> TestSetup {
 
 pub fn init_contract(setup: &TestSetup) -> Bytes {
     let name = String::from_str(&setup.env, "tansu");
-    let url = String::from_str(&setup.env, "github.com/tansu");
-    let ipfs = String::from_str(&setup.env, "2ef4f49fdd8fa9dc463f1f06a094c26b88710990");
+    let url = String::from_str(&setup.env, "https://github.com/tansu");
+    let ipfs = String::from_str(
+        &setup.env,
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi",
+    );
     let maintainers = vec![&setup.env, setup.grogu.clone(), setup.mando.clone()];
 
     let genesis_amount: i128 = 1_000_000_000 * 10_000_000;
@@ -105,5 +160,132 @@ pub fn init_contract(setup: &TestSetup) -> Bytes {
 
     setup
         .contract
-        .register(&setup.grogu, &name, &maintainers, &url, &ipfs)
+        .register(&setup.grogu, &name, &maintainers, &url, &ipfs, &None)
+}
+
+/// Create a proposal on the project and get it approved by a vote.
+pub fn approved_proposal(setup: &TestSetup, id: &Bytes) -> u32 {
+    let voting_ends_at = setup.env.ledger().timestamp() + 3600 * 24 * 2;
+    let kuiil = Address::generate(&setup.env);
+    setup.token_stellar.mint(&kuiil, &(10 * 10_000_000));
+    setup
+        .contract
+        .add_member(&kuiil, &String::from_str(&setup.env, "test"));
+    setup.contract.set_badges(
+        &setup.mando,
+        id,
+        &kuiil,
+        &vec![&setup.env, types::Badge::Community],
+    );
+
+    let proposal_id = setup.contract.create_proposal(
+        &setup.grogu,
+        id,
+        &String::from_str(&setup.env, "Approved proposal"),
+        &String::from_str(
+            &setup.env,
+            "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        ),
+        &voting_ends_at,
+        &true,
+        &None,
+    );
+
+    for (voter, weight) in [(&setup.mando, 1), (&kuiil, types::Badge::Community as u32)] {
+        setup.contract.vote(
+            voter,
+            id,
+            &proposal_id,
+            &types::Vote::PublicVote(types::PublicVote {
+                address: voter.clone(),
+                weight,
+                vote_choice: types::VoteChoice::Approve,
+            }),
+        );
+    }
+    setup.env.ledger().set_timestamp(voting_ends_at + 1);
+    setup
+        .contract
+        .execute(&setup.mando, id, &proposal_id, &None, &None);
+    proposal_id
+}
+
+/// Add a member with the community badge on the project.
+pub fn community_member(setup: &TestSetup, id: &Bytes) -> Address {
+    let member = Address::generate(&setup.env);
+    setup.token_stellar.mint(&member, &(100 * 10_000_000));
+    setup
+        .contract
+        .add_member(&member, &String::from_str(&setup.env, "test"));
+    setup.contract.set_badges(
+        &setup.mando,
+        id,
+        &member,
+        &vec![&setup.env, types::Badge::Community],
+    );
+    member
+}
+
+/// Seeds of anonymous votes as scalars of the BLS12-381 field.
+pub fn seeds(env: &Env, seeds: &[u128]) -> Vec<U256> {
+    let mut scalars = Vec::new(env);
+    for seed in seeds {
+        scalars.push_back(U256::from_u128(env, *seed));
+    }
+    scalars
+}
+
+/// Create a proposal applying an action and get it approved by a member.
+pub fn approved_action(
+    setup: &TestSetup,
+    id: &Bytes,
+    voter: &Address,
+    action: &types::DaoAction,
+) -> u32 {
+    let voting_ends_at = setup.env.ledger().timestamp() + 3600 * 24 * 2;
+    let proposal_id = setup.contract.create_action_proposal(
+        &setup.grogu,
+        id,
+        &String::from_str(&setup.env, "Apply an action"),
+        &String::from_str(
+            &setup.env,
+            "bafybeib6ioupho3p3pliusx7tgs7dvi6mpu2bwfhayj6w6ie44lo3vvc4i",
+        ),
+        &voting_ends_at,
+        &true,
+        action,
+    );
+    setup.contract.vote(
+        voter,
+        id,
+        &proposal_id,
+        &types::Vote::PublicVote(types::PublicVote {
+            address: voter.clone(),
+            weight: types::Badge::Community as u32,
+            vote_choice: types::VoteChoice::Approve,
+        }),
+    );
+    setup.env.ledger().set_timestamp(voting_ends_at + 1);
+    proposal_id
+}
+
+/// Renounce to the DAO of the project and require a quorum so that
+/// proposals can spend its treasury.
+pub fn treasury_dao(setup: &TestSetup, id: &Bytes, voter: &Address) {
+    setup.contract.renounce_to_dao(&setup.grogu, id);
+    setup.contract.renounce_to_dao(&setup.mando, id);
+
+    let config = setup.contract.get_governance_config(id);
+    let proposal_id = approved_action(
+        setup,
+        id,
+        voter,
+        &types::DaoAction::SetGovernanceConfig(types::GovernanceConfig {
+            quorum: types::Badge::Community as u32,
+            ..config
+        }),
+    );
+    setup
+        .contract
+        .execute(&setup.grogu, id, &proposal_id, &None, &None);
 }
@@ -1,7 +1,37 @@
-use soroban_sdk::{Address, Bytes, BytesN, String, Vec, contracttype};
+use soroban_sdk::{Address, Bytes, BytesN, Map, String, Symbol, Val, Vec, contracttype};
 
 // Constants
 pub const TIMELOCK_DELAY: u64 = 24 * 3600; // 24 hours in seconds
+pub const DEREGISTRATION_DELAY: u64 = 7 * 24 * 3600; // 7 days in seconds
+pub const MAINTAINERS_APPROVAL_PERIOD: u64 = 7 * 24 * 3600; // 7 days in seconds
+pub const MAX_PROJECT_NAME_LENGTH: u32 = 15;
+pub const MAX_MIRRORS: u32 = 5;
+pub const MAX_MAINTAINERS: u32 = 20;
+pub const MAX_PROJECTS_PER_PAGE: u32 = 20;
+pub const MAX_PACKAGES: u32 = 20;
+pub const MAX_PACKAGE_NAME_LENGTH: u32 = 32;
+pub const MAX_PACKAGE_RELEASES: u32 = 100;
+pub const MAX_ORGANIZATION_PROJECTS: u32 = 50;
+pub const MAX_URL_LENGTH: u32 = 256;
+pub const MIN_CID_LENGTH: u32 = 46;
+pub const MAX_CID_LENGTH: u32 = 128;
+pub const MAX_TAGS: u32 = 5;
+pub const MAX_TAG_LENGTH: u32 = 32;
+pub const MAX_FORKS: u32 = 50;
+pub const MAX_LICENSE_LENGTH: u32 = 64;
+pub const MAX_REGISTER_BATCH: u32 = 20;
+pub const MAX_METADATA_HISTORY: u32 = 10;
+pub const MAX_COMMIT_HISTORY: u32 = 50;
+pub const MAX_COMMITS_PER_PAGE: u32 = 10;
+pub const MAX_COMMIT_AUTHOR_LENGTH: u32 = 128;
+pub const MAX_PROPOSALS_PER_QUERY: u32 = 10;
+pub const MAX_PROPOSALS_SCANNED: u32 = 90;
+pub const MAX_COUNCIL_MEMBERS: u32 = 20;
+pub const MAX_RELEASE_TAG_LENGTH: u32 = 64;
+pub const MAX_REFS: u32 = 20;
+pub const MAX_REF_LENGTH: u32 = 64;
+pub const DOMAIN_GRACE_PERIOD: u64 = 30 * 24 * 3600; // 30 days in seconds
+pub const DOMAIN_PERIOD: u64 = 365 * 24 * 3600; // 1 year in seconds
 
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
@@ -14,14 +44,243 @@ pub struct Contract {
 pub enum ContractKey {
     DomainContract,     // Address and wasm hash of the SorobanDomain contract
     CollateralContract, // Collateral asset contract address
+    DomainProvider,     // Name service binding project names to owners
+    RegistryContract,   // Address and wasm hash of the registry contract
+    StakingContract,    // Address and wasm hash of the staking contract
+    SchedulerContract,  // Address and wasm hash of the scheduler contract
+    GovernanceContract, // Address and wasm hash of the governance contract
+}
+
+/// Name service binding project names to their owner.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DomainProvider {
+    SorobanDomains,        // the domain contract, see https://sorobandomains.org
+    NameService(Contract), // contract implementing `domain::NameServiceInterface`
+}
+
+/// Status of the domain bound to a project.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DomainStatus {
+    Unregistered,                 // no domain for the project name
+    Active(Address, Option<u64>), // owner, expiration timestamp if any
+    Grace(u64),                   // expired, still bound to the project until this timestamp
+    Expired,                      // expired and the grace period is over
+}
+
+/// Configuration field of a project, see `events::ProjectConfigUpdated`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ConfigField {
+    Url,
+    Ipfs,
+    Maintainers,
+    MaintainersConfig,
+    MaintainerRole,
+    HashAlgorithm,
+    CommitCooldown,
+}
+
+/// Project registered by the contract admins, see `register_batch`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProjectRegistration {
+    pub name: String,
+    pub maintainers: Vec<Address>,
+    pub url: String,
+    pub ipfs: String,
+    pub license: Option<String>,
+    pub owner: Address, // maintainer owning the domain of the project
+}
+
+/// Deprecation of a project in favor of its replacement.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Deprecation {
+    pub successor: Option<Bytes>, // key of the project replacing it
+    pub deprecated_at: u64,
+}
+
+/// Result of the last domain ownership verification of a project.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DomainVerification {
+    pub owner: Option<Address>, // owner of the domain, if it could be resolved
+    pub verified: bool,         // the domain is owned by a maintainer
+    pub checked_at: u64,
+}
+
+#[contracttype]
+pub enum DomainKey {
+    Owner(Bytes),    // last known owner of the project's domain
+    Provider(Bytes), // name service the project's domain was registered with
+}
+
+#[contracttype]
+pub enum AnonymousKeyKey {
+    History(Bytes),            // public keys used to encrypt anonymous votes, oldest first
+    Proposal(Bytes, u32),      // public key active when an anonymous proposal was created
+    References(Bytes, String), // active anonymous proposals encrypted with a public key
+}
+
+#[contracttype]
+pub enum DaoIndexKey {
+    Active(Bytes), // ids of the active proposals of a project, oldest first
+}
+
+/// Channel of a release, releases are promoted from one channel to the next.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum ReleaseChannel {
+    Nightly = 0,
+    Rc = 1,
+    Stable = 2,
+}
+
+/// Release change of an approved proposal applied by the registry, see
+/// `registry::RegistryInterface`.
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReleaseAction {
+    // tag, commit hash, artifact CID, changelog CID, channel
+    Create(String, String, String, String, ReleaseChannel),
+    Promote(String, ReleaseChannel), // tag, channel
+    Yank(String, String),            // tag, reason CID
+    Archive(String),                 // tag
+}
+
+#[contracttype]
+pub enum OrganizationKey {
+    Organization(Bytes),                  // organization key
+    Approvals(Bytes, OrganizationAction), // pending admins approvals
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum OrganizationAction {
+    // replace a maintainer in all the projects of the organization: old, new
+    RotateMaintainer(Address, Address),
+    SetAdmins(Vec<Address>),
+}
+
+/// Group of projects managed by a common set of admins.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Organization {
+    pub name: String,
+    pub admins: Vec<Address>,
+    pub projects: Vec<Bytes>,
 }
 
 #[contracttype]
 pub enum DataKey {
-    Member(Address), // Member of the DAO, address
-    Paused,          // Contract pause state
-    UpgradeProposal, // Pending upgrade proposal
-    AdminsConfig,    // Admin configuration for upgrades and other admin operations
+    Member(Address),               // Member of the DAO, address
+    Paused,                        // Contract pause state
+    UpgradeProposal,               // Pending upgrade proposal
+    AdminsConfig,                  // Admin configuration for upgrades and other admin operations
+    BondConfig,                    // Registration bond required to register a project
+    Stats,                         // Global counters
+    Projects(u32),                 // Page of registered project keys
+    TagProjects(String, u32),      // Page of project keys with a tag
+    TagCount(String),              // Number of projects with a tag
+    SigningKey(Address),           // ed25519 key signing the commits of an address
+    Attestor(Address),             // registered build attestor
+    InvokeTarget(Address, Symbol), // contract function DAOs can call, see `DaoAction::Invoke`
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Stats {
+    pub projects: u32,  // currently registered projects
+    pub proposals: u32, // proposals ever created
+    pub members: u32,   // registered members
+    pub commits: u32,   // commits ever recorded
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ProjectStats {
+    pub proposals: u32,          // proposals ever created
+    pub last_commit_ledger: u32, // ledger of the last commit, 0 if none
+    pub members: u32,            // members holding a badge
+    pub voting_weight: u32,      // sum of the badge weights of the members
+    pub executed_proposals: u32, // proposals executed with a tally
+    pub weight_cast: u128,       // weight cast on the executed proposals
+    pub eligible_weight: u128,   // weight eligible to vote on the executed proposals
+}
+
+/// Object format of the git repository of a project.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum HashAlgorithm {
+    #[default]
+    Sha1, // 40 hexadecimal characters, git's default
+    Sha256, // 64 hexadecimal characters
+}
+
+/// Commit recorded for a project, see `get_commit_history`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitRecord {
+    pub hash: String,
+    pub timestamp: u64, // ledger timestamp of the commit
+    pub ledger: u32,
+    pub signing_key: Option<BytesN<32>>, // ed25519 key which signed the commit
+    pub reverts: Option<String>,         // hash reverted, set by `revert_commit`
+    pub proposal_id: Option<u32>,        // approved proposal authorizing the commit
+}
+
+/// Page of commits returned by `get_commits`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitPage {
+    pub commits: Vec<CommitRecord>, // most recent first
+    pub next_cursor: Option<u32>,   // cursor of the next page, None at the end
+}
+
+/// Participation in the vote of a proposal, see `get_turnout`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Turnout {
+    pub weight_cast: u128, // weight of all the votes, the abstain vote of the proposer included
+    pub eligible_weight: u128, // weight of the members when the proposal was created
+    pub turnout_bps: u32,  // share of the eligible weight cast in basis points
+}
+
+/// Current weights cast on a public proposal, see `tally`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Tally {
+    pub approve: u128, // including the votes for an option of a multiple-choice proposal
+    pub reject: u128,
+    pub abstain: u128,
+}
+
+/// Page of proposals returned by `get_proposals`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProposalPage {
+    pub proposals: Vec<Proposal>, // most recent first
+    pub next_cursor: Option<u32>, // cursor of the next page, None at the end
+}
+
+/// Attested details of a commit, see `commit`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitMeta {
+    pub author: String,           // author identifier, e.g. a handle or an email hash
+    pub message_hash: BytesN<32>, // sha256 of the commit message
+    pub tree_hash: String,        // hash of the git tree, SHA-1 or SHA-256 in hex
+    pub timestamp: u64,           // author timestamp of the commit
+}
+
+/// tansu.toml file claimed by a project at a given ledger.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MetadataCommitment {
+    pub ipfs: String, // CID of the tansu.toml file
+    pub ledger: u32,  // ledger at which the file was set
 }
 
 #[contracttype]
@@ -31,6 +290,7 @@ pub struct Badges {
     pub triage: Vec<Address>,
     pub community: Vec<Address>,
     pub verified: Vec<Address>,
+    pub reviewer: Vec<Address>,
 }
 
 #[contracttype]
@@ -38,11 +298,21 @@ pub struct Badges {
 pub enum Badge {
     Developer = 10_000_000,
     Triage = 5_000_000,
+    Reviewer = 2_000_000, // can attest code reviews
     Community = 1_000_000,
     Verified = 500_000, // have a soroban domain
     Default = 1,
 }
 
+/// Permissions of a maintainer on a project, each role includes the previous ones.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum MaintainerRole {
+    Committer = 0, // can commit
+    Releaser = 1,  // can commit and release
+    Admin = 2,     // can also execute proposals
+}
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct ProjectBadges {
@@ -58,13 +328,24 @@ pub struct Member {
 }
 
 #[contracttype]
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ProposalStatus {
     Active,
     Approved,
     Rejected,
     Cancelled,
     Malicious,
+    Vetoed,
+    Expired, // approved but executed after the execution window, or anonymous tallies not revealed in time
+}
+
+/// Result of a scheduled proposal due, see `run_scheduled`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ScheduledRun {
+    Pending,                  // not ready, stays scheduled
+    Executed(ProposalStatus), // executed with this status
+    Failed(u32),              // code of the error preventing the execution, unscheduled
 }
 
 #[contracttype]
@@ -80,6 +361,7 @@ pub enum VoteChoice {
     Approve,
     Reject,
     Abstain,
+    Choice(u32), // index of an option of a multiple-choice proposal
 }
 
 #[contracttype]
@@ -106,6 +388,7 @@ pub struct VoteData {
     pub voting_ends_at: u64,
     pub public_voting: bool,
     pub votes: Vec<Vote>,
+    pub eligible_weight: u128, // weight of the badges of the project when the proposal was created
 }
 
 #[contracttype]
@@ -116,6 +399,14 @@ pub struct AnonymousVoteConfig {
     pub public_key: String,
 }
 
+/// Public key encrypting the anonymous votes of a project from a given time.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AnonymousKey {
+    pub public_key: String,
+    pub active_from: u64, // UNIX timestamp
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct AdminsConfig {
@@ -123,6 +414,21 @@ pub struct AdminsConfig {
     pub admins: Vec<Address>, // List of authorized admins
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BondConfig {
+    pub amount: i128,      // deposit in collateral asset, 0 to disable
+    pub lock_ledgers: u32, // ledgers after which a commit allows to claim the bond back
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Bond {
+    pub depositor: Address,
+    pub amount: i128,
+    pub refundable_at: u32, // ledger sequence from which commits unlock the bond
+}
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct UpgradeProposal {
@@ -132,6 +438,18 @@ pub struct UpgradeProposal {
     pub admins_config: AdminsConfig,
 }
 
+/// Kind of a proposal, deciding what is applied when it is approved.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProposalKind {
+    Text,             // advisory, nothing is applied
+    ConfigChange,     // configuration, governance rules, license or badges
+    Treasury,         // spending from the treasury of the project
+    Upgrade,          // new commit or release promotion
+    MaintainerChange, // maintainers of a DAO controlled project
+    ContractCall,     // call of another contract
+}
+
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct Proposal {
@@ -142,6 +460,30 @@ pub struct Proposal {
     pub vote_data: VoteData,
     pub status: ProposalStatus,
     pub outcomes_contract: Option<Address>,
+    pub kind: ProposalKind,
+    pub created_at: u64,             // UNIX timestamp
+    pub deposit: i128,               // bond of the proposer in collateral asset
+    pub depends_on: Option<u32>,     // proposal which must be approved first
+    pub options: Vec<String>,        // labels of a multiple-choice proposal, empty otherwise
+    pub winning_option: Option<u32>, // option with the most weight once approved
+    pub cosigner: Option<Address>,   // maintainer co-signing an emergency proposal
+    pub reveal_ends_at: Option<u64>, // end of the reveal of hashed public votes or anonymous tallies, None without deadline
+}
+
+/// Revision of the description of a proposal.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Revision {
+    pub ipfs: String,
+    pub amended_at: u64, // UNIX timestamp
+}
+
+/// Veto of a maintainer on an approved proposal.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Veto {
+    pub maintainer: Address,
+    pub reason: String,
 }
 
 #[contracttype]
@@ -159,13 +501,226 @@ pub enum ProjectKey {
     Dao(Bytes, u32), // Decentralized organization, pagination
     DaoTotalProposals(Bytes),
     AnonymousVoteConfig(Bytes),
+    Archived(Bytes),       // project is frozen, history stays readable
+    Deregistration(Bytes), // time from which the approved removal can be executed
+    Bond(Bytes),           // registration bond held for the project
+    MaintainersApprovals(Bytes, MaintainersAction), // pending maintainers approvals
+    MaintainerRole(Bytes, Address), // role of a maintainer, Committer if not set
+    ProjectIndex(Bytes),   // position of the project in the projects listing
+    Packages(Bytes),       // names of the packages of a project
+    Package(Bytes, String), // package of a project
+    Organization(Bytes),   // organization the project belongs to
+    DaoControlled(Bytes),  // maintainers renounced, changes go through proposals
+    ProposalAction(Bytes, u32), // action applied when the proposal is approved
+    Paused(Bytes),         // proposals, votes and commits are blocked
+    GovernanceConfig(Bytes), // voting rules of the DAO of the project
+    DomainExpiry(Bytes),   // last known expiration timestamp of the project's domain
+    Tags(Bytes),           // discovery tags of the project
+    TagIndex(Bytes, String), // position of the project in the tag listing
+    ForkOf(Bytes),         // project this project was forked from
+    Forks(Bytes),          // projects forked from this project
+    DomainVerification(Bytes), // last domain ownership verification
+    Deprecated(Bytes),     // project is deprecated, with its successor
+    Stats(Bytes),          // counters of the project
+    MetadataHistory(Bytes), // last tansu.toml commitments of the project
+    CommitCount(Bytes),    // number of commits ever recorded for the project
+    CommitHistory(Bytes, u32), // slot of the ring buffer of the last commits
+    CommitMeta(Bytes, u32), // attested details of the commit in the same slot
+    Refs(Bytes),           // names of the refs of the project
+    Ref(Bytes, String),    // latest commit hash of a ref
+    HashAlgorithm(Bytes),  // object format of the repository, SHA-1 if not set
+    CommitCooldown(Bytes), // ledgers between two commits of a maintainer
+    LastCommit(Bytes, Address), // ledger of the last commit of a maintainer
+    CommitProposal(Bytes, u32), // hash approved by a proposal and whether it was committed
+    Treasury(Bytes),       // balances per token held for the project
+    Delegations(Bytes),    // delegate of each member delegating its voting weight
+    DelegatedVotes(Bytes, u32), // members whose weight was exercised by their delegate
+    Vetoes(Bytes, u32),    // vetoes of the maintainers on a proposal
+    LastProposal(Bytes, Address), // ledger of the last proposal of an address
+    Revisions(Bytes, u32), // descriptions of an amended proposal, oldest first
+    ProposalTemplates(Bytes), // recurring proposals of the project by template ID
+    WeightSnapshot(Bytes, u32), // max weight of the members when a proposal was created
+    Reputation(Bytes, Address), // participation of a member in a project
+}
+
+/// Approvals of maintainers for an action, dropped once they expire.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PendingApprovals {
+    pub approvals: Vec<Address>,
+    pub expires_at: u64, // MAINTAINERS_APPROVAL_PERIOD after the first approval
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum MaintainersAction {
+    AddMaintainer(Address),
+    RemoveMaintainer(Address),
+    RotateMaintainer(Address, Address),         // old key, new key
+    UpdateConfig(Vec<Address>, String, String), // maintainers, url, ipfs
+    SetMaintainersConfig(u32, u32),             // threshold, max maintainers
+    SetMaintainerRole(Address, MaintainerRole),
+    RenounceToDao,
+    PauseProject,
+    ResumeProject,
+    RotateAnonymousKey(String), // public key encrypting the anonymous votes
+    Deregister,
+    Deprecate(Option<Bytes>), // successor
+    Undeprecate,
+    TransferProject(Address, Vec<Address>), // new lead maintainer, maintainers
+    ArchiveProject,
+    UnarchiveProject,
+}
+
+/// Reward split among the voters of a proposal, see `DaoAction::FundReward`.
+/// Pools are held by the governance contract, see
+/// `governance::GovernanceInterface`.
+#[contracttype(export = false)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RewardPool {
+    pub token: Address,
+    pub amount: i128,
+    pub claimed: i128,      // amount already paid to voters
+    pub claims: u32,        // number of voters who claimed their share
+    pub claim_ends_at: u64, // unclaimed rewards return to the treasury afterwards
+}
+
+/// Members elected to act for the DAO of a project, see `council_execute` of
+/// the governance contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Council {
+    pub members: Vec<Address>, // empty to dissolve the council
+    pub threshold: u32,        // signatures of members needed to act
+    pub spend_limit: i128,     // largest treasury transfer the council can make
+}
+
+/// Participation of a member in a project, see `get_reputation`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Reputation {
+    pub score: u32,
+    pub updated_at: u32, // ledger of the last update of the score
+}
+
+/// Proposal created again on a schedule, see `tick`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProposalTemplate {
+    pub title: String,
+    pub ipfs: String,
+    pub voting_period: u64, // in seconds
+    pub public_voting: bool,
+    pub action: TemplateAction,
+    pub interval: u64, // in seconds between two proposals
+    pub next_at: u64,  // UNIX timestamp of the next proposal
+}
+
+/// Content of the proposals created from a template.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+#[allow(clippy::large_enum_variant)]
+pub enum TemplateAction {
+    Text,
+    Action(DaoAction),
+}
+
+/// Source of the voting weight of the members of a project.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VotingWeight {
+    Badges,
+    Token(Address),          // whole tokens held, badges only grant membership
+    BadgesAndToken(Address), // sum of the badges and of the whole tokens held
+}
+
+/// Change applied to a DAO controlled project when a proposal is approved.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub enum DaoAction {
+    Commit(String),                 // new commit hash
+    UpdateConfig(String, String),   // url, ipfs
+    SetBadges(Address, Vec<Badge>), // member, badges
+    SetGovernanceConfig(GovernanceConfig),
+    SetLicense(String),                     // SPDX license identifier
+    PromoteRelease(String, ReleaseChannel), // tag, channel
+    YankRelease(String, String),            // tag, CID of the reason
+    ArchiveRelease(String),                 // tag
+    // tag, commit hash, artifact CID, changelog CID, channel
+    CreateRelease(String, String, String, String, ReleaseChannel),
+    Transfer(Address, Address, i128), // token, recipient, amount from the treasury
+    SetMaintainers(Vec<Address>),
+    Invoke(Address, Symbol, Vec<Val>), // contract, function, arguments
+    SetCouncil(Council),
+    FundReward(u32, Address, i128), // proposal id, token, amount from the treasury
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct RepoRef {
+    pub name: String, // label of the mirror, e.g. "gitlab"
+    pub url: String,  // link to the mirror VCS
+}
+
+/// Package hosted by a project, e.g. `tansu/contracts` for a monorepo.
+///
+/// Packages share the maintainers and the DAO of their project.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Package {
+    pub name: String,
+    pub hash: Option<String>, // latest commit hash of the package
+    pub releases: Vec<String>,
 }
 
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
 pub struct Config {
-    pub url: String,  // link to VCS
-    pub ipfs: String, // CID of the tansu.toml file with metadata
+    pub url: String,                // link to VCS
+    pub ipfs: String,               // CID of the tansu.toml file with metadata
+    pub mirrors: Vec<RepoRef>,      // additional links to VCS mirrors
+    pub maintainers_threshold: u32, // approvals required to change maintainers
+    pub max_maintainers: u32,       // cap on the number of maintainers
+    pub license: Option<String>,    // SPDX license identifier
+}
+
+/// Voting rules of the DAO of a project.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceConfig {
+    pub min_voting_period: u64, // in seconds
+    pub max_voting_period: u64, // in seconds
+    pub max_title_length: u32,
+    pub quorum: u32, // minimal total weight cast, otherwise the proposal is cancelled
+    pub approval_thresholds: Map<ProposalKind, u32>, // share of the weight cast in basis points, simple majority if not set
+    pub abstain_in_quorum: bool,                     // abstain weight counts toward the quorum
+    pub abstain_in_approval: bool,                   // abstain weight counts in the approval ratio
+    pub veto_period: u64, // in seconds after the vote, maintainers can veto approved proposals, 0 to disable
+    pub proposal_deposit: i128, // bond of proposers in collateral asset, 0 to disable
+    pub spam_quorum_bps: u32, // share of the quorum in basis points under which the deposit is slashed
+    pub proposal_cooldown: u32, // ledgers between two proposals of an address, 0 to disable
+    pub quadratic_voting: bool, // public votes weigh the square root of their weight
+    pub conviction_half_life: u32, // ledgers for conviction to move halfway to the support, 0 to disable
+    pub conviction_min_support_bps: u32, // share of the total weight funding a negligible amount
+    pub voting_weight: VotingWeight, // read for each member holding a badge when a proposal is created
+    pub unbonding_period: u64,       // in seconds before unstaked tokens can be withdrawn
+    pub reputation_half_life: u32,   // ledgers for reputation to halve, 0 to disable
+    pub reputation_weight: u32, // voting weight per reputation point, 0 to not count reputation
+    pub execution_window: u64, // in seconds after the vote and veto period to execute, 0 to disable
+    pub emergency_voting_period: u64, // minimal voting period of emergency proposals in seconds, 0 to disable
+    pub emergency_threshold: u32,     // approval share of emergency proposals in basis points
+    pub sponsors_required: u32, // badge holders sponsoring a proposal before it opens for voting, 0 to disable
+    pub max_voter_weight_bps: u32, // share of the eligible weight each voter or delegator casts at most, 0 to disable
+    pub reveal_period: u64, // in seconds after the vote to reveal hashed public votes, 0 to vote directly
+    pub tally_reveal_period: u64, // in seconds after the vote to reveal anonymous tallies, 0 for no deadline
+}
+
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Metadata {
+    pub description: Option<String>, // CID of the project description
+    pub website: Option<String>,     // link to the project website
+    pub logo: Option<String>,        // CID of the project logo
 }
 
 #[contracttype]
@@ -174,4 +729,65 @@ pub struct Project {
     pub name: String,
     pub config: Config,
     pub maintainers: Vec<Address>,
+    pub metadata: Metadata,
+}
+
+/// Layout of `Config` before mirrors, maintainer settings and licenses,
+/// read by `migrate_projects`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConfigV1 {
+    pub url: String,
+    pub ipfs: String,
+}
+
+/// Layout of `Project` before its metadata, read by `migrate_projects`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProjectV1 {
+    pub name: String,
+    pub config: ConfigV1,
+    pub maintainers: Vec<Address>,
+}
+
+/// Layout of `Badges` before reviewers, read by `migrate_projects`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct BadgesV1 {
+    pub developer: Vec<Address>,
+    pub triage: Vec<Address>,
+    pub community: Vec<Address>,
+    pub verified: Vec<Address>,
+}
+
+/// Layout of `VoteData` before the eligible weight, read by
+/// `migrate_proposals`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct VoteDataV1 {
+    pub voting_ends_at: u64,
+    pub public_voting: bool,
+    pub votes: Vec<Vote>,
+}
+
+/// Layout of `Proposal` before proposal kinds, deposits, dependencies,
+/// options and reveals, read by `migrate_proposals`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProposalV1 {
+    pub id: u32,
+    pub title: String,
+    pub proposer: Address,
+    pub ipfs: String,
+    pub vote_data: VoteDataV1,
+    pub status: ProposalStatus,
+    pub outcomes_contract: Option<Address>,
+}
+
+/// Layout of a page of `Dao` holding `ProposalV1`, read by
+/// `migrate_proposals`.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct DaoV1 {
+    pub proposals: Vec<ProposalV1>,
 }
@@ -0,0 +1,305 @@
+//! Validation of user inputs.
+
+use soroban_sdk::{Bytes, Env, String, panic_with_error};
+
+use crate::{errors, types};
+
+/// Normalize and validate a project name.
+///
+/// Project names are case insensitive and are normalized to lowercase.
+/// Only ASCII letters are accepted: this matches the charset of Soroban
+/// Domains and rejects Unicode lookalikes (e.g. a Cyrillic "а" in place of
+/// a Latin "a") which would allow impersonating another project.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `name` - The project name
+///
+/// # Returns
+/// * `Bytes` - The normalized project name
+///
+/// # Panics
+/// * If the name is empty or longer than `MAX_PROJECT_NAME_LENGTH`
+/// * If the name contains anything else than ASCII letters
+pub fn normalize_project_name(env: &Env, name: &String) -> Bytes {
+    let str_len = name.len() as usize;
+    if str_len == 0 || str_len > types::MAX_PROJECT_NAME_LENGTH as usize {
+        panic_with_error!(&env, &errors::ContractErrors::InvalidProjectName);
+    }
+
+    let mut slice = [0u8; types::MAX_PROJECT_NAME_LENGTH as usize];
+    let name_slice = &mut slice[..str_len];
+    name.copy_into_slice(name_slice);
+
+    if !name_slice.iter().all(|c| c.is_ascii_alphabetic()) {
+        panic_with_error!(&env, &errors::ContractErrors::InvalidProjectName);
+    }
+    name_slice.make_ascii_lowercase();
+
+    Bytes::from_slice(env, name_slice)
+}
+
+/// Normalize and validate a package name.
+///
+/// Package names are case insensitive and are normalized to lowercase.
+/// ASCII letters, digits and `-` are accepted.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `name` - The package name
+///
+/// # Returns
+/// * `String` - The normalized package name
+///
+/// # Panics
+/// * If the name is empty or longer than `MAX_PACKAGE_NAME_LENGTH`
+/// * If the name contains other characters
+pub fn normalize_package_name(env: &Env, name: &String) -> String {
+    let str_len = name.len() as usize;
+    if str_len == 0 || str_len > types::MAX_PACKAGE_NAME_LENGTH as usize {
+        panic_with_error!(&env, &errors::ContractErrors::InvalidPackage);
+    }
+
+    let mut slice = [0u8; types::MAX_PACKAGE_NAME_LENGTH as usize];
+    let name_slice = &mut slice[..str_len];
+    name.copy_into_slice(name_slice);
+
+    if !name_slice
+        .iter()
+        .all(|c| c.is_ascii_alphanumeric() || *c == b'-')
+    {
+        panic_with_error!(&env, &errors::ContractErrors::InvalidPackage);
+    }
+    name_slice.make_ascii_lowercase();
+
+    String::from_bytes(env, name_slice)
+}
+
+/// Normalize and validate a project tag.
+///
+/// Tags are case insensitive and are normalized to lowercase.
+/// ASCII letters, digits and `-` are accepted.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `tag` - The tag
+///
+/// # Returns
+/// * `String` - The normalized tag
+///
+/// # Panics
+/// * If the tag is empty or longer than `MAX_TAG_LENGTH`
+/// * If the tag contains other characters
+pub fn normalize_tag(env: &Env, tag: &String) -> String {
+    let str_len = tag.len() as usize;
+    if str_len == 0 || str_len > types::MAX_TAG_LENGTH as usize {
+        panic_with_error!(&env, &errors::ContractErrors::InvalidTag);
+    }
+
+    let mut slice = [0u8; types::MAX_TAG_LENGTH as usize];
+    let tag_slice = &mut slice[..str_len];
+    tag.copy_into_slice(tag_slice);
+
+    if !tag_slice
+        .iter()
+        .all(|c| c.is_ascii_alphanumeric() || *c == b'-')
+    {
+        panic_with_error!(&env, &errors::ContractErrors::InvalidTag);
+    }
+    tag_slice.make_ascii_lowercase();
+
+    String::from_bytes(env, tag_slice)
+}
+
+/// Validate the name of a ref, e.g. `main` or `release/1.x`.
+///
+/// Names follow git conventions: ASCII alphanumerics, `-`, `_`, `.` and
+/// `/` separated components which cannot be empty or start with `.`.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `name` - The ref name
+///
+/// # Panics
+/// * If the name is empty or longer than `MAX_REF_LENGTH`
+/// * If the name contains other characters or an invalid component
+pub fn validate_ref_name(env: &Env, name: &String) {
+    let str_len = name.len() as usize;
+    if str_len == 0 || str_len > types::MAX_REF_LENGTH as usize {
+        panic_with_error!(&env, &errors::ExtendedErrors::InvalidRef);
+    }
+
+    let mut slice = [0u8; types::MAX_REF_LENGTH as usize];
+    let name_slice = &mut slice[..str_len];
+    name.copy_into_slice(name_slice);
+
+    let valid = name_slice.split(|c| *c == b'/').all(|component| {
+        !component.is_empty()
+            && component[0] != b'.'
+            && !component.ends_with(b".lock")
+            && !component.windows(2).any(|pair| pair == b"..")
+            && component
+                .iter()
+                .all(|c| c.is_ascii_alphanumeric() || b"-_.".contains(c))
+    });
+    if !valid {
+        panic_with_error!(&env, &errors::ExtendedErrors::InvalidRef);
+    }
+}
+
+/// Validate an SPDX license identifier or expression.
+///
+/// Identifiers are made of ASCII letters, digits, `-`, `.` and `+`.
+/// Expressions such as `MIT OR Apache-2.0` also use spaces and parentheses.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `license` - The license
+///
+/// # Panics
+/// * If the license is empty or longer than `MAX_LICENSE_LENGTH`
+/// * If the license contains other characters
+pub fn validate_license(env: &Env, license: &String) {
+    let str_len = license.len() as usize;
+    if str_len == 0 || str_len > types::MAX_LICENSE_LENGTH as usize {
+        panic_with_error!(&env, &errors::ContractErrors::InvalidLicense);
+    }
+
+    let mut slice = [0u8; types::MAX_LICENSE_LENGTH as usize];
+    let license_slice = &mut slice[..str_len];
+    license.copy_into_slice(license_slice);
+
+    if !license_slice
+        .iter()
+        .all(|c| c.is_ascii_alphanumeric() || b"-.+ ()".contains(c))
+    {
+        panic_with_error!(&env, &errors::ContractErrors::InvalidLicense);
+    }
+}
+
+/// Validate the metadata of a commit.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `meta` - The commit metadata
+/// * `algorithm` - The object format of the repository
+///
+/// # Panics
+/// * If the author is empty or longer than `MAX_COMMIT_AUTHOR_LENGTH`
+/// * If the tree hash is not a hexadecimal hash of the algorithm
+pub fn validate_commit_meta(env: &Env, meta: &types::CommitMeta, algorithm: types::HashAlgorithm) {
+    let author_len = meta.author.len();
+    if author_len == 0 || author_len > types::MAX_COMMIT_AUTHOR_LENGTH {
+        panic_with_error!(&env, &errors::ContractErrors::InvalidCommitMeta);
+    }
+
+    if !is_git_hash(&meta.tree_hash, algorithm) {
+        panic_with_error!(&env, &errors::ContractErrors::InvalidCommitMeta);
+    }
+}
+
+/// Validate a commit hash.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `hash` - The commit hash
+/// * `algorithm` - The object format of the repository
+///
+/// # Panics
+/// * If the hash is not a 40 (SHA-1) or 64 (SHA-256) characters
+///   hexadecimal string, depending on the algorithm
+pub fn validate_commit_hash(env: &Env, hash: &String, algorithm: types::HashAlgorithm) {
+    if !is_git_hash(hash, algorithm) {
+        panic_with_error!(&env, &errors::ExtendedErrors::InvalidCommitHash);
+    }
+}
+
+/// Whether a string is a git object hash of the algorithm in hexadecimal.
+fn is_git_hash(hash: &String, algorithm: types::HashAlgorithm) -> bool {
+    let str_len = hash.len() as usize;
+    let expected_len = match algorithm {
+        types::HashAlgorithm::Sha1 => 40,
+        types::HashAlgorithm::Sha256 => 64,
+    };
+    if str_len != expected_len {
+        return false;
+    }
+    let mut slice = [0u8; 64];
+    let hash_slice = &mut slice[..str_len];
+    hash.copy_into_slice(hash_slice);
+    hash_slice.iter().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Validate a repository URL.
+///
+/// The URL must use HTTPS, fit within `MAX_URL_LENGTH` and only contain
+/// printable ASCII characters without whitespace.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `url` - The URL to validate
+///
+/// # Panics
+/// * If the URL is invalid
+pub fn validate_url(env: &Env, url: &String) {
+    let scheme = b"https://";
+    let str_len = url.len() as usize;
+    if str_len <= scheme.len() || str_len > types::MAX_URL_LENGTH as usize {
+        panic_with_error!(&env, &errors::ContractErrors::InvalidUrl);
+    }
+
+    let mut slice = [0u8; types::MAX_URL_LENGTH as usize];
+    url.copy_into_slice(&mut slice[..str_len]);
+    let url_b = &slice[..str_len];
+
+    if !url_b.starts_with(scheme) || !url_b.iter().all(|c| c.is_ascii_graphic()) {
+        panic_with_error!(&env, &errors::ContractErrors::InvalidUrl);
+    }
+}
+
+/// Validate an IPFS CID.
+///
+/// CIDv0 are 46 base58btc characters starting with `Qm`. CIDv1 are
+/// multibase encoded, the first character being the base: `b` for
+/// lowercase base32, `k` for base36 or `z` for base58btc.
+///
+/// # Arguments
+/// * `env` - The environment object
+/// * `cid` - The CID to validate
+///
+/// # Panics
+/// * If the CID is shorter than `MIN_CID_LENGTH` or longer than
+///   `MAX_CID_LENGTH`
+/// * If the multibase prefix is unknown or the CID contains characters
+///   outside of its base alphabet
+pub fn validate_cid(env: &Env, cid: &String) {
+    let str_len = cid.len() as usize;
+    if str_len < types::MIN_CID_LENGTH as usize || str_len > types::MAX_CID_LENGTH as usize {
+        panic_with_error!(&env, &errors::ExtendedErrors::InvalidCid);
+    }
+
+    let mut slice = [0u8; types::MAX_CID_LENGTH as usize];
+    cid.copy_into_slice(&mut slice[..str_len]);
+    let cid_b = &slice[..str_len];
+
+    let valid = match cid_b {
+        [b'Q', b'm', ..] if str_len == 46 => cid_b.iter().all(is_base58),
+        [b'b', encoded @ ..] => encoded
+            .iter()
+            .all(|c| c.is_ascii_lowercase() || (b'2'..=b'7').contains(c)),
+        [b'k', encoded @ ..] => encoded
+            .iter()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()),
+        [b'z', encoded @ ..] => encoded.iter().all(is_base58),
+        _ => false,
+    };
+    if !valid {
+        panic_with_error!(&env, &errors::ExtendedErrors::InvalidCid);
+    }
+}
+
+/// Whether a character is in the base58btc alphabet, which excludes `0`,
+/// `O`, `I` and `l`.
+fn is_base58(c: &u8) -> bool {
+    c.is_ascii_alphanumeric() && !matches!(c, b'0' | b'O' | b'I' | b'l')
+}
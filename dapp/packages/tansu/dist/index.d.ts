@@ -122,12 +122,14 @@ export type DataKey =
 export interface Badges {
   community: Array<string>;
   developer: Array<string>;
+  reviewer: Array<string>;
   triage: Array<string>;
   verified: Array<string>;
 }
 export declare enum Badge {
   Developer = 10000000,
   Triage = 5000000,
+  Reviewer = 2000000,
   Community = 1000000,
   Verified = 500000,
   Default = 1,
@@ -160,6 +162,42 @@ export type ProposalStatus =
   | {
       tag: "Malicious";
       values: void;
+    }
+  | {
+      tag: "Vetoed";
+      values: void;
+    }
+  | {
+      tag: "Expired";
+      values: void;
+    };
+/**
+ * Kind of a proposal, deciding what is applied when it is approved.
+ */
+export type ProposalKind =
+  | {
+      tag: "Text";
+      values: void;
+    }
+  | {
+      tag: "ConfigChange";
+      values: void;
+    }
+  | {
+      tag: "Treasury";
+      values: void;
+    }
+  | {
+      tag: "Upgrade";
+      values: void;
+    }
+  | {
+      tag: "MaintainerChange";
+      values: void;
+    }
+  | {
+      tag: "ContractCall";
+      values: void;
     };
 export type Vote =
   | {
@@ -182,6 +220,10 @@ export type VoteChoice =
   | {
       tag: "Abstain";
       values: void;
+    }
+  | {
+      tag: "Choice";
+      values: readonly [u32];
     };
 export interface PublicVote {
   address: string;
@@ -196,6 +238,7 @@ export interface AnonymousVote {
   weight: u32;
 }
 export interface VoteData {
+  eligible_weight: u128;
   public_voting: boolean;
   votes: Array<Vote>;
   voting_ends_at: u64;
@@ -216,13 +259,21 @@ export interface UpgradeProposal {
   wasm_hash: Buffer;
 }
 export interface Proposal {
+  cosigner: Option<string>;
+  created_at: u64;
+  depends_on: Option<u32>;
+  deposit: i128;
   id: u32;
   ipfs: string;
+  kind: ProposalKind;
+  options: Array<string>;
   outcomes_contract: Option<string>;
   proposer: string;
+  reveal_ends_at: Option<u64>;
   status: ProposalStatus;
   title: string;
   vote_data: VoteData;
+  winning_option: Option<u32>;
 }
 export interface Dao {
   proposals: Array<Proposal>;
@@ -252,13 +303,27 @@ export type ProjectKey =
       tag: "AnonymousVoteConfig";
       values: readonly [Buffer];
     };
+export interface RepoRef {
+  name: string;
+  url: string;
+}
 export interface Config {
   ipfs: string;
+  license: Option<string>;
+  maintainers_threshold: u32;
+  max_maintainers: u32;
+  mirrors: Array<RepoRef>;
   url: string;
 }
+export interface Metadata {
+  description: Option<string>;
+  logo: Option<string>;
+  website: Option<string>;
+}
 export interface Project {
   config: Config;
   maintainers: Array<string>;
+  metadata: Metadata;
   name: string;
 }
 export interface Client {
@@ -267,15 +332,19 @@ export interface Client {
    * Setup anonymous voting for a project.
    *
    * Configures BLS12-381 cryptographic primitives for anonymous voting.
-   * Only the contract admin can call this function.
+   * Any maintainer of the project can set it up once, the public key is
+   * active right away. Later changes of the key are approved by the
+   * maintainers with `rotate_anonymous_key`.
    *
    * # Arguments
    * * `env` - The environment object
+   * * `maintainer` - The address of the maintainer
    * * `project_key` - Unique identifier for the project
    * * `public_key` - Asymmetric public key to be used for vote encryption
    *
    * # Panics
-   * * If the caller is not the contract admin
+   * * If the caller is not a maintainer of the project
+   * * If anonymous voting is already set up for the project
    */
   anonymous_voting_setup: (
     {
@@ -1221,25 +1290,28 @@ export interface Client {
    *
    * Creates a new project entry with maintainers, URL, and commit hash.
    * Also registers the project name in the domain contract if not already registered.
-   * The project key is generated using keccak256 hash of the project name.
+   * The project name is normalized to lowercase and the project key is
+   * generated using keccak256 hash of the normalized project name.
    *
    * # Arguments
    * * `env` - The environment object
    * * `maintainer` - The address of the maintainer calling this function
-   * * `name` - The project name (max 15 characters)
+   * * `name` - The project name (max 15 ASCII letters)
    * * `maintainers` - List of maintainer addresses for the project
    * * `url` - The project's Git repository URL
    * * `ipfs` - CID of the tansu.toml file with associated metadata
+   * * `license` - SPDX license identifier of the project, if any
    *
    * # Returns
    * * `Bytes` - The project key (keccak256 hash of the name)
    *
    * # Panics
-   * * If the project name is longer than 15 characters
+   * * If the project name is not 1 to 15 ASCII letters
    * * If the project already exists
    * * If the maintainer is not authorized
    * * If the domain registration fails
    * * If the maintainer doesn't own an existing domain
+   * * If the existing domain expired
    */
   register: (
     {
@@ -1248,12 +1320,14 @@ export interface Client {
       maintainers,
       url,
       ipfs,
+      license,
     }: {
       maintainer: string;
       name: string;
       maintainers: Array<string>;
       url: string;
       ipfs: string;
+      license: Option<string>;
     },
     options?: {
       /**
@@ -77,6 +77,7 @@ export type DataKey =
 export interface Badges {
   community: Array<string>;
   developer: Array<string>;
+  reviewer: Array<string>;
   triage: Array<string>;
   verified: Array<string>;
 }
@@ -84,6 +85,7 @@ export interface Badges {
 export enum Badge {
   Developer = 10000000,
   Triage = 5000000,
+  Reviewer = 2000000,
   Community = 1000000,
   Verified = 500000,
   Default = 1,
@@ -104,7 +106,20 @@ export type ProposalStatus =
   | { tag: "Approved"; values: void }
   | { tag: "Rejected"; values: void }
   | { tag: "Cancelled"; values: void }
-  | { tag: "Malicious"; values: void };
+  | { tag: "Malicious"; values: void }
+  | { tag: "Vetoed"; values: void }
+  | { tag: "Expired"; values: void };
+
+/**
+ * Kind of a proposal, deciding what is applied when it is approved.
+ */
+export type ProposalKind =
+  | { tag: "Text"; values: void }
+  | { tag: "ConfigChange"; values: void }
+  | { tag: "Treasury"; values: void }
+  | { tag: "Upgrade"; values: void }
+  | { tag: "MaintainerChange"; values: void }
+  | { tag: "ContractCall"; values: void };
 
 export type Vote =
   | { tag: "PublicVote"; values: readonly [PublicVote] }
@@ -113,7 +128,8 @@ export type Vote =
 export type VoteChoice =
   | { tag: "Approve"; values: void }
   | { tag: "Reject"; values: void }
-  | { tag: "Abstain"; values: void };
+  | { tag: "Abstain"; values: void }
+  | { tag: "Choice"; values: readonly [u32] };
 
 export interface PublicVote {
   address: string;
@@ -130,6 +146,7 @@ export interface AnonymousVote {
 }
 
 export interface VoteData {
+  eligible_weight: u128;
   public_voting: boolean;
   votes: Array<Vote>;
   voting_ends_at: u64;
@@ -154,13 +171,21 @@ export interface UpgradeProposal {
 }
 
 export interface Proposal {
+  cosigner: Option<string>;
+  created_at: u64;
+  depends_on: Option<u32>;
+  deposit: i128;
   id: u32;
   ipfs: string;
+  kind: ProposalKind;
+  options: Array<string>;
   outcomes_contract: Option<string>;
   proposer: string;
+  reveal_ends_at: Option<u64>;
   status: ProposalStatus;
   title: string;
   vote_data: VoteData;
+  winning_option: Option<u32>;
 }
 
 export interface Dao {
@@ -175,14 +200,30 @@ export type ProjectKey =
   | { tag: "DaoTotalProposals"; values: readonly [Buffer] }
   | { tag: "AnonymousVoteConfig"; values: readonly [Buffer] };
 
+export interface RepoRef {
+  name: string;
+  url: string;
+}
+
 export interface Config {
   ipfs: string;
+  license: Option<string>;
+  maintainers_threshold: u32;
+  max_maintainers: u32;
+  mirrors: Array<RepoRef>;
   url: string;
 }
 
+export interface Metadata {
+  description: Option<string>;
+  logo: Option<string>;
+  website: Option<string>;
+}
+
 export interface Project {
   config: Config;
   maintainers: Array<string>;
+  metadata: Metadata;
   name: string;
 }
 
@@ -192,15 +233,19 @@ export interface Client {
    * Setup anonymous voting for a project.
    *
    * Configures BLS12-381 cryptographic primitives for anonymous voting.
-   * Only the contract admin can call this function.
+   * Any maintainer of the project can set it up once, the public key is
+   * active right away. Later changes of the key are approved by the
+   * maintainers with `rotate_anonymous_key`.
    *
    * # Arguments
    * * `env` - The environment object
+   * * `maintainer` - The address of the maintainer
    * * `project_key` - Unique identifier for the project
    * * `public_key` - Asymmetric public key to be used for vote encryption
    *
    * # Panics
-   * * If the caller is not the contract admin
+   * * If the caller is not a maintainer of the project
+   * * If anonymous voting is already set up for the project
    */
   anonymous_voting_setup: (
     {
@@ -288,7 +333,7 @@ export interface Client {
       project_key,
       votes,
       seeds,
-    }: { project_key: Buffer; votes: Array<u128>; seeds: Array<u128> },
+    }: { project_key: Buffer; votes: Array<u128>; seeds: Array<u256> },
     options?: {
       /**
        * The fee to pay for the transaction. Default: BASE_FEE
@@ -499,7 +544,7 @@ export interface Client {
       project_key: Buffer;
       proposal_id: u32;
       tallies: Option<Array<u128>>;
-      seeds: Option<Array<u128>>;
+      seeds: Option<Array<u256>>;
     },
     options?: {
       /**
@@ -557,7 +602,7 @@ export interface Client {
       project_key: Buffer;
       proposal: Proposal;
       tallies: Array<u128>;
-      seeds: Array<u128>;
+      seeds: Array<u256>;
     },
     options?: {
       /**
@@ -1146,25 +1191,28 @@ export interface Client {
    *
    * Creates a new project entry with maintainers, URL, and commit hash.
    * Also registers the project name in the domain contract if not already registered.
-   * The project key is generated using keccak256 hash of the project name.
+   * The project name is normalized to lowercase and the project key is
+   * generated using keccak256 hash of the normalized project name.
    *
    * # Arguments
    * * `env` - The environment object
    * * `maintainer` - The address of the maintainer calling this function
-   * * `name` - The project name (max 15 characters)
+   * * `name` - The project name (max 15 ASCII letters)
    * * `maintainers` - List of maintainer addresses for the project
    * * `url` - The project's Git repository URL
    * * `ipfs` - CID of the tansu.toml file with associated metadata
+   * * `license` - SPDX license identifier of the project, if any
    *
    * # Returns
    * * `Bytes` - The project key (keccak256 hash of the name)
    *
    * # Panics
-   * * If the project name is longer than 15 characters
+   * * If the project name is not 1 to 15 ASCII letters
    * * If the project already exists
    * * If the maintainer is not authorized
    * * If the domain registration fails
    * * If the maintainer doesn't own an existing domain
+   * * If the existing domain expired
    */
   register: (
     {
@@ -1173,12 +1221,14 @@ export interface Client {
       maintainers,
       url,
       ipfs,
+      license,
     }: {
       maintainer: string;
       name: string;
       maintainers: Array<string>;
       url: string;
       ipfs: string;
+      license: Option<string>;
     },
     options?: {
       /**
@@ -1377,14 +1427,14 @@ export class Client extends ContractClient {
   constructor(public readonly options: ContractClientOptions) {
     super(
       new ContractSpec([
-        "AAAAAAAAAXZTZXR1cCBhbm9ueW1vdXMgdm90aW5nIGZvciBhIHByb2plY3QuCgpDb25maWd1cmVzIEJMUzEyLTM4MSBjcnlwdG9ncmFwaGljIHByaW1pdGl2ZXMgZm9yIGFub255bW91cyB2b3RpbmcuCk9ubHkgdGhlIGNvbnRyYWN0IGFkbWluIGNhbiBjYWxsIHRoaXMgZnVuY3Rpb24uCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBwcm9qZWN0X2tleWAgLSBVbmlxdWUgaWRlbnRpZmllciBmb3IgdGhlIHByb2plY3QKKiBgcHVibGljX2tleWAgLSBBc3ltbWV0cmljIHB1YmxpYyBrZXkgdG8gYmUgdXNlZCBmb3Igdm90ZSBlbmNyeXB0aW9uCgojIFBhbmljcwoqIElmIHRoZSBjYWxsZXIgaXMgbm90IHRoZSBjb250cmFjdCBhZG1pbgAAAAAAFmFub255bW91c192b3Rpbmdfc2V0dXAAAAAAAAMAAAAAAAAACm1haW50YWluZXIAAAAAABMAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAAAAAAACnB1YmxpY19rZXkAAAAAABAAAAAA",
+        "AAAAAAAAAmNTZXR1cCBhbm9ueW1vdXMgdm90aW5nIGZvciBhIHByb2plY3QuCgpDb25maWd1cmVzIEJMUzEyLTM4MSBjcnlwdG9ncmFwaGljIHByaW1pdGl2ZXMgZm9yIGFub255bW91cyB2b3RpbmcuCkFueSBtYWludGFpbmVyIG9mIHRoZSBwcm9qZWN0IGNhbiBzZXQgaXQgdXAgb25jZSwgdGhlIHB1YmxpYyBrZXkgaXMKYWN0aXZlIHJpZ2h0IGF3YXkuIExhdGVyIGNoYW5nZXMgb2YgdGhlIGtleSBhcmUgYXBwcm92ZWQgYnkgdGhlCm1haW50YWluZXJzIHdpdGggYHJvdGF0ZV9hbm9ueW1vdXNfa2V5YC4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYG1haW50YWluZXJgIC0gVGhlIGFkZHJlc3Mgb2YgdGhlIG1haW50YWluZXIKKiBgcHJvamVjdF9rZXlgIC0gVW5pcXVlIGlkZW50aWZpZXIgZm9yIHRoZSBwcm9qZWN0CiogYHB1YmxpY19rZXlgIC0gQXN5bW1ldHJpYyBwdWJsaWMga2V5IHRvIGJlIHVzZWQgZm9yIHZvdGUgZW5jcnlwdGlvbgoKIyBQYW5pY3MKKiBJZiB0aGUgY2FsbGVyIGlzIG5vdCBhIG1haW50YWluZXIgb2YgdGhlIHByb2plY3QKKiBJZiBhbm9ueW1vdXMgdm90aW5nIGlzIGFscmVhZHkgc2V0IHVwIGZvciB0aGUgcHJvamVjdAAAAAAWYW5vbnltb3VzX3ZvdGluZ19zZXR1cAAAAAAAAwAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAAAAAAKcHVibGljX2tleQAAAAAAEAAAAAA=",
         "AAAAAAAAASdHZXQgdGhlIGFub255bW91cyB2b3RpbmcgY29uZmlndXJhdGlvbiBmb3IgYSBwcm9qZWN0LgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgcHJvamVjdF9rZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKCiMgUmV0dXJucwoqIGB0eXBlczo6QW5vbnltb3VzVm90ZUNvbmZpZ2AgLSBUaGUgYW5vbnltb3VzIHZvdGluZyBjb25maWd1cmF0aW9uCgojIFBhbmljcwoqIElmIG5vIGFub255bW91cyB2b3RpbmcgY29uZmlndXJhdGlvbiBleGlzdHMgZm9yIHRoZSBwcm9qZWN0AAAAABtnZXRfYW5vbnltb3VzX3ZvdGluZ19jb25maWcAAAAAAQAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAEAAAfQAAAAE0Fub255bW91c1ZvdGVDb25maWcA",
-        "AAAAAAAAAwNCdWlsZCB2b3RlIGNvbW1pdG1lbnRzIGZyb20gdm90ZXMgYW5kIHNlZWRzIGZvciBhbm9ueW1vdXMgdm90aW5nLgoKQ3JlYXRlcyBCTFMxMi0zODEgY29tbWl0bWVudHMgZm9yIGVhY2ggdm90ZSB1c2luZyB0aGUgZm9ybXVsYToKQyA9IGdedm90ZSAqIGhec2VlZCB3aGVyZSBnIGFuZCBoIGFyZSBnZW5lcmF0b3IgcG9pbnRzLgoKTm90ZTogVGhpcyBmdW5jdGlvbiBkb2VzIG5vdCBjb25zaWRlciB2b3Rpbmcgd2VpZ2h0cywgd2hpY2ggYXJlIGFwcGxpZWQKZHVyaW5nIHRoZSB0YWxseWluZyBwaGFzZS4gQ2FsbGluZyB0aGlzIG9uIHRoZSBzbWFydCBjb250cmFjdCB3b3VsZCByZXZlYWwKdGhlIHZvdGVzIGFuZCBzZWVkcywgc28gaXQgbXVzdCBiZSBydW4gZWl0aGVyIGluIHNpbXVsYXRpb24gb3IgY2xpZW50LXNpZGUuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBwcm9qZWN0X2tleWAgLSBVbmlxdWUgaWRlbnRpZmllciBmb3IgdGhlIHByb2plY3QKKiBgdm90ZXNgIC0gVmVjdG9yIG9mIHZvdGUgY2hvaWNlcyAoMD1hYnN0YWluLCAxPWFwcHJvdmUsIDI9cmVqZWN0KQoqIGBzZWVkc2AgLSBWZWN0b3Igb2YgcmFuZG9tIHNlZWRzIGZvciBlYWNoIHZvdGUKCiMgUmV0dXJucwoqIGBWZWM8Qnl0ZXNOPDk2Pj5gIC0gVmVjdG9yIG9mIHZvdGUgY29tbWl0bWVudHMgKG9uZSBwZXIgdm90ZSkKCiMgUGFuaWNzCiogSWYgbm8gYW5vbnltb3VzIHZvdGluZyBjb25maWd1cmF0aW9uIGV4aXN0cyBmb3IgdGhlIHByb2plY3QAAAAAHGJ1aWxkX2NvbW1pdG1lbnRzX2Zyb21fdm90ZXMAAAADAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAAV2b3RlcwAAAAAAA+oAAAAKAAAAAAAAAAVzZWVkcwAAAAAAA+oAAAAKAAAAAQAAA+oAAAPuAAAAYA==",
+        "AAAAAAAAAwNCdWlsZCB2b3RlIGNvbW1pdG1lbnRzIGZyb20gdm90ZXMgYW5kIHNlZWRzIGZvciBhbm9ueW1vdXMgdm90aW5nLgoKQ3JlYXRlcyBCTFMxMi0zODEgY29tbWl0bWVudHMgZm9yIGVhY2ggdm90ZSB1c2luZyB0aGUgZm9ybXVsYToKQyA9IGdedm90ZSAqIGhec2VlZCB3aGVyZSBnIGFuZCBoIGFyZSBnZW5lcmF0b3IgcG9pbnRzLgoKTm90ZTogVGhpcyBmdW5jdGlvbiBkb2VzIG5vdCBjb25zaWRlciB2b3Rpbmcgd2VpZ2h0cywgd2hpY2ggYXJlIGFwcGxpZWQKZHVyaW5nIHRoZSB0YWxseWluZyBwaGFzZS4gQ2FsbGluZyB0aGlzIG9uIHRoZSBzbWFydCBjb250cmFjdCB3b3VsZCByZXZlYWwKdGhlIHZvdGVzIGFuZCBzZWVkcywgc28gaXQgbXVzdCBiZSBydW4gZWl0aGVyIGluIHNpbXVsYXRpb24gb3IgY2xpZW50LXNpZGUuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBwcm9qZWN0X2tleWAgLSBVbmlxdWUgaWRlbnRpZmllciBmb3IgdGhlIHByb2plY3QKKiBgdm90ZXNgIC0gVmVjdG9yIG9mIHZvdGUgY2hvaWNlcyAoMD1hYnN0YWluLCAxPWFwcHJvdmUsIDI9cmVqZWN0KQoqIGBzZWVkc2AgLSBWZWN0b3Igb2YgcmFuZG9tIHNlZWRzIGZvciBlYWNoIHZvdGUKCiMgUmV0dXJucwoqIGBWZWM8Qnl0ZXNOPDk2Pj5gIC0gVmVjdG9yIG9mIHZvdGUgY29tbWl0bWVudHMgKG9uZSBwZXIgdm90ZSkKCiMgUGFuaWNzCiogSWYgbm8gYW5vbnltb3VzIHZvdGluZyBjb25maWd1cmF0aW9uIGV4aXN0cyBmb3IgdGhlIHByb2plY3QAAAAAHGJ1aWxkX2NvbW1pdG1lbnRzX2Zyb21fdm90ZXMAAAADAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAAV2b3RlcwAAAAAAA+oAAAAKAAAAAAAAAAVzZWVkcwAAAAAAA+oAAAAMAAAAAQAAA+oAAAPuAAAAYA==",
         "AAAAAAAAAzhDcmVhdGUgYSBuZXcgcHJvcG9zYWwgZm9yIGEgcHJvamVjdC4KClRoZSBwcm9wb3NlciBpcyBhdXRvbWF0aWNhbGx5IGFkZGVkIHRvIHRoZSBhYnN0YWluIGdyb3VwLgpCeSBjcmVhdGluZyBhIHByb3Bvc2FsLCB0aGUgcHJvcG9zZXIgaW5jdXIgYSBjb2xsYXRlcmFsIHdoaWNoIGlzCnJlcGFpZCB1cG9uIGV4ZWN1dGlvbiBvZiB0aGUgcHJvcG9zYWwgdW5sZXNzIHRoZSBwcm9wb3NhbCBpcyByZXZva2VkLgpUaGlzIGlzIGEgZGV0ZXJyZW50IG1lY2hhbmlzbS4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYHByb3Bvc2VyYCAtIEFkZHJlc3Mgb2YgdGhlIHByb3Bvc2FsIGNyZWF0b3IKKiBgcHJvamVjdF9rZXlgIC0gVW5pcXVlIGlkZW50aWZpZXIgZm9yIHRoZSBwcm9qZWN0CiogYHRpdGxlYCAtIFRpdGxlIG9mIHRoZSBwcm9wb3NhbAoqIGBpcGZzYCAtIElQRlMgY29udGVudCBpZGVudGlmaWVyIGRlc2NyaWJpbmcgdGhlIHByb3Bvc2FsCiogYHZvdGluZ19lbmRzX2F0YCAtIFVOSVggdGltZXN0YW1wIHdoZW4gdm90aW5nIGVuZHMKKiBgcHVibGljX3ZvdGluZ2AgLSBXaGV0aGVyIHZvdGluZyBpcyBwdWJsaWMgb3IgYW5vbnltb3VzCiogW2BPcHRpb248b3V0Y29tZXNfY29udHJhY3Q+YF0gLSBPdXRjb21lIGNvbnRyYWN0IGFkZHJlc3MKCiMgUmV0dXJucwoqIGB1MzJgIC0gVGhlIElEIG9mIHRoZSBjcmVhdGVkIHByb3Bvc2FsLgoKIyBQYW5pY3MKKiBJZiB0aGUgdGl0bGUgaXMgdG9vIGxvbmcKKiBJZiB0aGUgdm90aW5nIHBlcmlvZCBpcyBpbnZhbGlkCiogSWYgdGhlIHByb2plY3QgZG9lc24ndCBleGlzdAAAAA9jcmVhdGVfcHJvcG9zYWwAAAAABwAAAAAAAAAIcHJvcG9zZXIAAAATAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAAV0aXRsZQAAAAAAABAAAAAAAAAABGlwZnMAAAAQAAAAAAAAAA52b3RpbmdfZW5kc19hdAAAAAAABgAAAAAAAAANcHVibGljX3ZvdGluZwAAAAAAAAEAAAAAAAAAEW91dGNvbWVzX2NvbnRyYWN0AAAAAAAD6AAAABMAAAABAAAABA==",
         "AAAAAAAAAY1SZXZva2UgYSBwcm9wb3NhbC4KClVzZWZ1bCBpZiB0aGVyZSB3YXMgc29tZSBzcGFtIG9yIGJhZCBpbnRlbnQuIFRoYXQgd2lsbCBwcmV2ZW50IHRoZQpjb2xsYXRlcmFsIHRvIGJlIGNsYWltZWQgYmFjay4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYG1haW50YWluZXJgIC0gQWRkcmVzcyBvZiB0aGUgcHJvcG9zYWwgY3JlYXRvcgoqIGBwcm9qZWN0X2tleWAgLSBUaGUgcHJvamVjdCBrZXkgaWRlbnRpZmllcgoqIGBwcm9wb3NhbF9pZGAgLSBUaGUgSUQgb2YgdGhlIHByb3Bvc2FsIHRvIHZvdGUgb24KCiMgUGFuaWNzCiogSWYgdGhlIHByb3Bvc2FsIGlzIG5vdCBhY3RpdmUgYW55bW9yZQoqIElmIHRoZSBtYWludGFpbmVyIGlzIG5vdCBhdXRob3JpemVkAAAAAAAAD3Jldm9rZV9wcm9wb3NhbAAAAAADAAAAAAAAAAptYWludGFpbmVyAAAAAAATAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAEAAAAAA==",
         "AAAAAAAAAvtDYXN0IGEgdm90ZSBvbiBhIHByb3Bvc2FsLgoKQWxsb3dzIGEgbWVtYmVyIHRvIHZvdGUgb24gYSBwcm9wb3NhbC4KVGhlIHZvdGUgY2FuIGJlIGVpdGhlciBwdWJsaWMgb3IgYW5vbnltb3VzIGRlcGVuZGluZyBvbiB0aGUgcHJvcG9zYWwgY29uZmlndXJhdGlvbi4KRm9yIHB1YmxpYyB2b3RlcywgdGhlIGNob2ljZSBhbmQgd2VpZ2h0IGFyZSB2aXNpYmxlLiBGb3IgYW5vbnltb3VzIHZvdGVzLCBvbmx5CnRoZSB3ZWlnaHQgaXMgdmlzaWJsZSwgYW5kIHRoZSBjaG9pY2UgaXMgZW5jcnlwdGVkLgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgdm90ZXJgIC0gVGhlIGFkZHJlc3Mgb2YgdGhlIHZvdGVyCiogYHByb2plY3Rfa2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCiogYHByb3Bvc2FsX2lkYCAtIFRoZSBJRCBvZiB0aGUgcHJvcG9zYWwgdG8gdm90ZSBvbgoqIGB2b3RlYCAtIFRoZSB2b3RlIGRhdGEgKHB1YmxpYyBvciBhbm9ueW1vdXMpCgojIFBhbmljcwoqIElmIHRoZSB2b3RlciBoYXMgYWxyZWFkeSB2b3RlZAoqIElmIHRoZSB2b3RpbmcgcGVyaW9kIGhhcyBlbmRlZAoqIElmIHRoZSBwcm9wb3NhbCBpcyBub3QgYWN0aXZlIGFueW1vcmUKKiBJZiB0aGUgcHJvcG9zYWwgZG9lc24ndCBleGlzdAoqIElmIHRoZSB2b3RlcidzIHdlaWdodCBleGNlZWRzIHRoZWlyIG1heGltdW0gYWxsb3dlZCB3ZWlnaHQKKiBJZiB0aGUgdm90ZXIgaXMgbm90IGEgbWVtYmVyIG9mIHRoZSBwcm9qZWN0AAAAAAR2b3RlAAAABAAAAAAAAAAFdm90ZXIAAAAAAAATAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAAtwcm9wb3NhbF9pZAAAAAAEAAAAAAAAAAR2b3RlAAAH0AAAAARWb3RlAAAAAA==",
-        "AAAAAAAABABFeGVjdXRlIGEgdm90ZSBhZnRlciB0aGUgdm90aW5nIHBlcmlvZCBlbmRzLgoKUHJvY2Vzc2VzIHRoZSB2b3RpbmcgcmVzdWx0cyBhbmQgZGV0ZXJtaW5lcyB0aGUgZmluYWwgc3RhdHVzIG9mIHRoZSBwcm9wb3NhbC4KRm9yIHB1YmxpYyB2b3RlcywgdGhlIHJlc3VsdHMgYXJlIGNhbGN1bGF0ZWQgZGlyZWN0bHkgZnJvbSB2b3RlIGNvdW50cy4KRm9yIGFub255bW91cyB2b3RlcywgdGFsbGllcyBhbmQgc2VlZHMgYXJlIHZhbGlkYXRlZCBhZ2FpbnN0IHZvdGUgY29tbWl0bWVudHMKdG8gZW5zdXJlIHRoZSByZXN1bHRzIGFyZSBjb3JyZWN0LgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgbWFpbnRhaW5lcmAgLSBUaGUgYWRkcmVzcyBvZiB0aGUgbWFpbnRhaW5lciBleGVjdXRpbmcgdGhlIHByb3Bvc2FsCiogYHByb2plY3Rfa2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCiogYHByb3Bvc2FsX2lkYCAtIFRoZSBJRCBvZiB0aGUgcHJvcG9zYWwgdG8gZXhlY3V0ZQoqIFtgT3B0aW9uPHRhbGxpZXM+YF0gLSBkZWNvZGVkIHRhbGx5IHZhbHVlcyAoc2NhbGVkIGJ5IHdlaWdodHMpLCByZXNwZWN0aXZlbHkgQXBwcm92ZSwgcmVqZWN0IGFuZCBhYnN0YWluCiogW2BPcHRpb248c2VlZHM+YF0gLSBkZWNvZGVkIHNlZWQgdmFsdWVzIChzY2FsZWQgYnkgd2VpZ2h0cyksIHJlc3BlY3RpdmVseSBBcHByb3ZlLCByZWplY3QgYW5kIGFic3RhaW4KCiMgUmV0dXJucwoqIGB0eXBlczo6UHJvcG9zYWxTdGF0dXNgIC0gVGhlIGZpbmFsIHN0YXR1cyBvZiB0aGUgcHJvcG9zYWwgKEFwcHJvdmVkLCBSZWplY3RlZCwgb3IgQ2FuY2VsbGVkKQoKIyBQYW5pY3MKKiBJZiB0aGUgdm90aW5nIHBlcmlvZCBoYXNuJ3QgZW5kZWQKKiBJZiB0aGUgcHJvcG9zYWwgZG9lc24ndCBleGlzdAoqIElmIHRoZSBwcm9wb3NhbCBpcyBub3QgYWN0aXZlIGFueW1vcmUKKiBJZiB0YWxsaWVzL3NlZWRzIGFyZSBtaXNzaW5nIGZvciBhbm9ueW1vdXMgdm90ZXMKKiBJZiBjb21taXRtZW50AAAAB2V4ZWN1dGUAAAAABQAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAAAAAALcHJvcG9zYWxfaWQAAAAABAAAAAAAAAAHdGFsbGllcwAAAAPoAAAD6gAAAAoAAAAAAAAABXNlZWRzAAAAAAAD6AAAA+oAAAAKAAAAAQAAB9AAAAAOUHJvcG9zYWxTdGF0dXMAAA==",
-        "AAAAAAAAA3hWZXJpZnkgdm90ZSBjb21taXRtZW50IHByb29mIGZvciBhbm9ueW1vdXMgdm90aW5nLgoKVmFsaWRhdGVzIHRoYXQgdGhlIHByb3ZpZGVkIHRhbGxpZXMgYW5kIHNlZWRzIG1hdGNoIHRoZSB2b3RlIGNvbW1pdG1lbnRzCndpdGhvdXQgcmV2ZWFsaW5nIGluZGl2aWR1YWwgdm90ZXMuIFRoaXMgZW5zdXJlcyB0aGUgaW50ZWdyaXR5IG9mIGFub255bW91cwp2b3RpbmcgcmVzdWx0cy4KClRoZSBjb21taXRtZW50IGlzOgoKQyA9IGdediAqIGheciAoaW4gYWRkaXRpdmUgbm90YXRpb246IGcqdiArIGgqciksCgp3aGVyZSBnLCBoIGFyZSBCTFMxMi0zODEgZ2VuZXJhdG9yIHBvaW50cyBhbmQgdiBpcyB0aGUgdm90ZSBjaG9pY2UsCnIgaXMgdGhlIHNlZWQuIFZvdGluZyB3ZWlnaHQgaXMgaW50cm9kdWNlZCBkdXJpbmcgdGhlIHRhbGx5aW5nIHBoYXNlLgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgcHJvamVjdF9rZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKKiBgcHJvcG9zYWxgIC0gVGhlIHByb3Bvc2FsIGNvbnRhaW5pbmcgdm90ZSBjb21taXRtZW50cwoqIGB0YWxsaWVzYCAtIERlY29kZWQgdGFsbHkgdmFsdWVzIFthcHByb3ZlLCByZWplY3QsIGFic3RhaW5dIChzY2FsZWQgYnkgd2VpZ2h0cykKKiBgc2VlZHNgIC0gRGVjb2RlZCBzZWVkIHZhbHVlcyBbYXBwcm92ZSwgcmVqZWN0LCBhYnN0YWluXSAoc2NhbGVkIGJ5IHdlaWdodHMpCgojIFJldHVybnMKKiBgYm9vbGAgLSBUcnVlIGlmIGFsbCBjb21taXRtZW50cyBtYXRjaCB0aGUgcHJvdmlkZWQgdGFsbGllcyBhbmQgc2VlZHMKCiMgUGFuaWNzCiogSWYgbm8gYW5vbnltb3VzIHZvdGluZyBjb25maWd1cmF0aW9uIGV4aXN0cyBmb3IgdGhlIHByb2plY3QAAAAFcHJvb2YAAAAAAAAEAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAAhwcm9wb3NhbAAAB9AAAAAIUHJvcG9zYWwAAAAAAAAAB3RhbGxpZXMAAAAD6gAAAAoAAAAAAAAABXNlZWRzAAAAAAAD6gAAAAoAAAABAAAAAQ==",
+        "AAAAAAAABABFeGVjdXRlIGEgdm90ZSBhZnRlciB0aGUgdm90aW5nIHBlcmlvZCBlbmRzLgoKUHJvY2Vzc2VzIHRoZSB2b3RpbmcgcmVzdWx0cyBhbmQgZGV0ZXJtaW5lcyB0aGUgZmluYWwgc3RhdHVzIG9mIHRoZSBwcm9wb3NhbC4KRm9yIHB1YmxpYyB2b3RlcywgdGhlIHJlc3VsdHMgYXJlIGNhbGN1bGF0ZWQgZGlyZWN0bHkgZnJvbSB2b3RlIGNvdW50cy4KRm9yIGFub255bW91cyB2b3RlcywgdGFsbGllcyBhbmQgc2VlZHMgYXJlIHZhbGlkYXRlZCBhZ2FpbnN0IHZvdGUgY29tbWl0bWVudHMKdG8gZW5zdXJlIHRoZSByZXN1bHRzIGFyZSBjb3JyZWN0LgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgbWFpbnRhaW5lcmAgLSBUaGUgYWRkcmVzcyBvZiB0aGUgbWFpbnRhaW5lciBleGVjdXRpbmcgdGhlIHByb3Bvc2FsCiogYHByb2plY3Rfa2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCiogYHByb3Bvc2FsX2lkYCAtIFRoZSBJRCBvZiB0aGUgcHJvcG9zYWwgdG8gZXhlY3V0ZQoqIFtgT3B0aW9uPHRhbGxpZXM+YF0gLSBkZWNvZGVkIHRhbGx5IHZhbHVlcyAoc2NhbGVkIGJ5IHdlaWdodHMpLCByZXNwZWN0aXZlbHkgQXBwcm92ZSwgcmVqZWN0IGFuZCBhYnN0YWluCiogW2BPcHRpb248c2VlZHM+YF0gLSBkZWNvZGVkIHNlZWQgdmFsdWVzIChzY2FsZWQgYnkgd2VpZ2h0cyksIHJlc3BlY3RpdmVseSBBcHByb3ZlLCByZWplY3QgYW5kIGFic3RhaW4KCiMgUmV0dXJucwoqIGB0eXBlczo6UHJvcG9zYWxTdGF0dXNgIC0gVGhlIGZpbmFsIHN0YXR1cyBvZiB0aGUgcHJvcG9zYWwgKEFwcHJvdmVkLCBSZWplY3RlZCwgb3IgQ2FuY2VsbGVkKQoKIyBQYW5pY3MKKiBJZiB0aGUgdm90aW5nIHBlcmlvZCBoYXNuJ3QgZW5kZWQKKiBJZiB0aGUgcHJvcG9zYWwgZG9lc24ndCBleGlzdAoqIElmIHRoZSBwcm9wb3NhbCBpcyBub3QgYWN0aXZlIGFueW1vcmUKKiBJZiB0YWxsaWVzL3NlZWRzIGFyZSBtaXNzaW5nIGZvciBhbm9ueW1vdXMgdm90ZXMKKiBJZiBjb21taXRtZW50AAAAB2V4ZWN1dGUAAAAABQAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAAAAAALcHJvcG9zYWxfaWQAAAAABAAAAAAAAAAHdGFsbGllcwAAAAPoAAAD6gAAAAoAAAAAAAAABXNlZWRzAAAAAAAD6AAAA+oAAAAMAAAAAQAAB9AAAAAOUHJvcG9zYWxTdGF0dXMAAA==",
+        "AAAAAAAAA3hWZXJpZnkgdm90ZSBjb21taXRtZW50IHByb29mIGZvciBhbm9ueW1vdXMgdm90aW5nLgoKVmFsaWRhdGVzIHRoYXQgdGhlIHByb3ZpZGVkIHRhbGxpZXMgYW5kIHNlZWRzIG1hdGNoIHRoZSB2b3RlIGNvbW1pdG1lbnRzCndpdGhvdXQgcmV2ZWFsaW5nIGluZGl2aWR1YWwgdm90ZXMuIFRoaXMgZW5zdXJlcyB0aGUgaW50ZWdyaXR5IG9mIGFub255bW91cwp2b3RpbmcgcmVzdWx0cy4KClRoZSBjb21taXRtZW50IGlzOgoKQyA9IGdediAqIGheciAoaW4gYWRkaXRpdmUgbm90YXRpb246IGcqdiArIGgqciksCgp3aGVyZSBnLCBoIGFyZSBCTFMxMi0zODEgZ2VuZXJhdG9yIHBvaW50cyBhbmQgdiBpcyB0aGUgdm90ZSBjaG9pY2UsCnIgaXMgdGhlIHNlZWQuIFZvdGluZyB3ZWlnaHQgaXMgaW50cm9kdWNlZCBkdXJpbmcgdGhlIHRhbGx5aW5nIHBoYXNlLgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgcHJvamVjdF9rZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKKiBgcHJvcG9zYWxgIC0gVGhlIHByb3Bvc2FsIGNvbnRhaW5pbmcgdm90ZSBjb21taXRtZW50cwoqIGB0YWxsaWVzYCAtIERlY29kZWQgdGFsbHkgdmFsdWVzIFthcHByb3ZlLCByZWplY3QsIGFic3RhaW5dIChzY2FsZWQgYnkgd2VpZ2h0cykKKiBgc2VlZHNgIC0gRGVjb2RlZCBzZWVkIHZhbHVlcyBbYXBwcm92ZSwgcmVqZWN0LCBhYnN0YWluXSAoc2NhbGVkIGJ5IHdlaWdodHMpCgojIFJldHVybnMKKiBgYm9vbGAgLSBUcnVlIGlmIGFsbCBjb21taXRtZW50cyBtYXRjaCB0aGUgcHJvdmlkZWQgdGFsbGllcyBhbmQgc2VlZHMKCiMgUGFuaWNzCiogSWYgbm8gYW5vbnltb3VzIHZvdGluZyBjb25maWd1cmF0aW9uIGV4aXN0cyBmb3IgdGhlIHByb2plY3QAAAAFcHJvb2YAAAAAAAAEAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAAhwcm9wb3NhbAAAB9AAAAAIUHJvcG9zYWwAAAAAAAAAB3RhbGxpZXMAAAAD6gAAAAoAAAAAAAAABXNlZWRzAAAAAAAD6gAAAAwAAAABAAAAAQ==",
         "AAAAAAAAAUdSZXR1cm5zIGEgcGFnZSBvZiBwcm9wb3NhbHMgKDAgdG8gTUFYX1BST1BPU0FMU19QRVJfUEFHRSBwcm9wb3NhbHMgcGVyIHBhZ2UpLgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgcHJvamVjdF9rZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKKiBgcGFnZWAgLSBUaGUgcGFnZSBudW1iZXIgKDAtYmFzZWQpCgojIFJldHVybnMKKiBgdHlwZXM6OkRhb2AgLSBUaGUgREFPIG9iamVjdCBjb250YWluaW5nIGEgcGFnZSBvZiBwcm9wb3NhbHMKCiMgUGFuaWNzCiogSWYgdGhlIHBhZ2UgbnVtYmVyIGlzIG91dCBvZiBib3VuZHMAAAAAB2dldF9kYW8AAAAAAgAAAAAAAAALcHJvamVjdF9rZXkAAAAADgAAAAAAAAAEcGFnZQAAAAQAAAABAAAH0AAAAANEYW8A",
         "AAAAAAAAAQtHZXQgYSBzaW5nbGUgcHJvcG9zYWwgYnkgSUQuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBwcm9qZWN0X2tleWAgLSBUaGUgcHJvamVjdCBrZXkgaWRlbnRpZmllcgoqIGBwcm9wb3NhbF9pZGAgLSBUaGUgSUQgb2YgdGhlIHByb3Bvc2FsIHRvIHJldHJpZXZlCgojIFJldHVybnMKKiBgdHlwZXM6OlByb3Bvc2FsYCAtIFRoZSBwcm9wb3NhbCBvYmplY3QKCiMgUGFuaWNzCiogSWYgdGhlIHByb3Bvc2FsIGRvZXNuJ3QgZXhpc3QAAAAADGdldF9wcm9wb3NhbAAAAAIAAAAAAAAAC3Byb2plY3Rfa2V5AAAAAA4AAAAAAAAAC3Byb3Bvc2FsX2lkAAAAAAQAAAABAAAH0AAAAAhQcm9wb3NhbA==",
         "AAAAAAAAAQJBZGQgYSBuZXcgbWVtYmVyIHRvIHRoZSBzeXN0ZW0gd2l0aCBtZXRhZGF0YS4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYG1lbWJlcl9hZGRyZXNzYCAtIFRoZSBhZGRyZXNzIG9mIHRoZSBtZW1iZXIgdG8gYWRkCiogYG1ldGFgIC0gTWV0YWRhdGEgc3RyaW5nIGFzc29jaWF0ZWQgd2l0aCB0aGUgbWVtYmVyIChlLmcuLCBJUEZTIGhhc2gpCgojIFBhbmljcwoqIElmIHRoZSBtZW1iZXIgYWxyZWFkeSBleGlzdHMAAAAAAAphZGRfbWVtYmVyAAAAAAACAAAAAAAAAA5tZW1iZXJfYWRkcmVzcwAAAAAAEwAAAAAAAAAEbWV0YQAAABAAAAAA",
@@ -1403,7 +1453,7 @@ export class Client extends ContractClient {
         "AAAAAAAAAaRFeGVjdXRlIG9yIGNhbmNlbCB1cGdyYWRlIHByb3Bvc2FsCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBhZG1pbmAgLSBBbiBhZG1pbiBhZGRyZXNzCiogYGFjY2VwdGAgLSB0cnVlIHRvIGFjY2VwdCBhbmQgZmFsc2UgdG8gcmVqZWN0LgoKVXBncmFkZXMgY2FuIGFsd2F5cyBiZSBjYW5jZWxsZWQgYnV0IG9ubHkgZXhlY3V0ZWQgaWYgdGhlcmUgYXJlIGVub3VnaAphcHByb3ZhbHMgYW5kIHRoZSB0aW1lbG9jayBwZXJpb2QgaXMgb3Zlci4KCiMgUGFuaWNzCiogSWYgdGhlIGFkbWluIGlzIG5vdCBhdXRob3JpemVkCiogSWYgaXQgaXMgdG9vIGVhcmx5IHRvIGV4ZWN1dGUKKiBJZiB0aGVyZSBhcmUgbm90IGVub3VnaCBhcHByb3ZhbHMKKiBJZiB0aGVyZSBpcyBubyB1cGdyYWRlIHRvIGV4ZWN1dGUAAAAQZmluYWxpemVfdXBncmFkZQAAAAIAAAAAAAAABWFkbWluAAAAAAAAEwAAAAAAAAAGYWNjZXB0AAAAAAABAAAAAA==",
         "AAAAAAAAABxHZXQgdXBncmFkZSBwcm9wb3NhbCBkZXRhaWxzAAAAFGdldF91cGdyYWRlX3Byb3Bvc2FsAAAAAAAAAAEAAAfQAAAAD1VwZ3JhZGVQcm9wb3NhbAA=",
         "AAAAAAAAAFlHZXQgdGhlIGN1cnJlbnQgdmVyc2lvbiBvZiB0aGUgY29udHJhY3QuCgojIFJldHVybnMKKiBgdTMyYCAtIFRoZSBjb250cmFjdCB2ZXJzaW9uIG51bWJlcgAAAAAAAAd2ZXJzaW9uAAAAAAAAAAABAAAABA==",
-        "AAAAAAAAA15SZWdpc3RlciBhIG5ldyBwcm9qZWN0LgoKQ3JlYXRlcyBhIG5ldyBwcm9qZWN0IGVudHJ5IHdpdGggbWFpbnRhaW5lcnMsIFVSTCwgYW5kIGNvbW1pdCBoYXNoLgpBbHNvIHJlZ2lzdGVycyB0aGUgcHJvamVjdCBuYW1lIGluIHRoZSBkb21haW4gY29udHJhY3QgaWYgbm90IGFscmVhZHkgcmVnaXN0ZXJlZC4KVGhlIHByb2plY3Qga2V5IGlzIGdlbmVyYXRlZCB1c2luZyBrZWNjYWsyNTYgaGFzaCBvZiB0aGUgcHJvamVjdCBuYW1lLgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgbWFpbnRhaW5lcmAgLSBUaGUgYWRkcmVzcyBvZiB0aGUgbWFpbnRhaW5lciBjYWxsaW5nIHRoaXMgZnVuY3Rpb24KKiBgbmFtZWAgLSBUaGUgcHJvamVjdCBuYW1lIChtYXggMTUgY2hhcmFjdGVycykKKiBgbWFpbnRhaW5lcnNgIC0gTGlzdCBvZiBtYWludGFpbmVyIGFkZHJlc3NlcyBmb3IgdGhlIHByb2plY3QKKiBgdXJsYCAtIFRoZSBwcm9qZWN0J3MgR2l0IHJlcG9zaXRvcnkgVVJMCiogYGlwZnNgIC0gQ0lEIG9mIHRoZSB0YW5zdS50b21sIGZpbGUgd2l0aCBhc3NvY2lhdGVkIG1ldGFkYXRhCgojIFJldHVybnMKKiBgQnl0ZXNgIC0gVGhlIHByb2plY3Qga2V5IChrZWNjYWsyNTYgaGFzaCBvZiB0aGUgbmFtZSkKCiMgUGFuaWNzCiogSWYgdGhlIHByb2plY3QgbmFtZSBpcyBsb25nZXIgdGhhbiAxNSBjaGFyYWN0ZXJzCiogSWYgdGhlIHByb2plY3QgYWxyZWFkeSBleGlzdHMKKiBJZiB0aGUgbWFpbnRhaW5lciBpcyBub3QgYXV0aG9yaXplZAoqIElmIHRoZSBkb21haW4gcmVnaXN0cmF0aW9uIGZhaWxzCiogSWYgdGhlIG1haW50YWluZXIgZG9lc24ndCBvd24gYW4gZXhpc3RpbmcgZG9tYWluAAAAAAAIcmVnaXN0ZXIAAAAFAAAAAAAAAAptYWludGFpbmVyAAAAAAATAAAAAAAAAARuYW1lAAAAEAAAAAAAAAALbWFpbnRhaW5lcnMAAAAD6gAAABMAAAAAAAAAA3VybAAAAAAQAAAAAAAAAARpcGZzAAAAEAAAAAEAAAAO",
+        "AAAAAAAABABSZWdpc3RlciBhIG5ldyBwcm9qZWN0LgoKQ3JlYXRlcyBhIG5ldyBwcm9qZWN0IGVudHJ5IHdpdGggbWFpbnRhaW5lcnMsIFVSTCwgYW5kIGNvbW1pdCBoYXNoLgpBbHNvIHJlZ2lzdGVycyB0aGUgcHJvamVjdCBuYW1lIGluIHRoZSBkb21haW4gY29udHJhY3QgaWYgbm90IGFscmVhZHkgcmVnaXN0ZXJlZC4KVGhlIHByb2plY3QgbmFtZSBpcyBub3JtYWxpemVkIHRvIGxvd2VyY2FzZSBhbmQgdGhlIHByb2plY3Qga2V5IGlzCmdlbmVyYXRlZCB1c2luZyBrZWNjYWsyNTYgaGFzaCBvZiB0aGUgbm9ybWFsaXplZCBwcm9qZWN0IG5hbWUuCgojIEFyZ3VtZW50cwoqIGBlbnZgIC0gVGhlIGVudmlyb25tZW50IG9iamVjdAoqIGBtYWludGFpbmVyYCAtIFRoZSBhZGRyZXNzIG9mIHRoZSBtYWludGFpbmVyIGNhbGxpbmcgdGhpcyBmdW5jdGlvbgoqIGBuYW1lYCAtIFRoZSBwcm9qZWN0IG5hbWUgKG1heCAxNSBBU0NJSSBsZXR0ZXJzKQoqIGBtYWludGFpbmVyc2AgLSBMaXN0IG9mIG1haW50YWluZXIgYWRkcmVzc2VzIGZvciB0aGUgcHJvamVjdAoqIGB1cmxgIC0gVGhlIHByb2plY3QncyBHaXQgcmVwb3NpdG9yeSBVUkwKKiBgaXBmc2AgLSBDSUQgb2YgdGhlIHRhbnN1LnRvbWwgZmlsZSB3aXRoIGFzc29jaWF0ZWQgbWV0YWRhdGEKKiBgbGljZW5zZWAgLSBTUERYIGxpY2Vuc2UgaWRlbnRpZmllciBvZiB0aGUgcHJvamVjdCwgaWYgYW55CgojIFJldHVybnMKKiBgQnl0ZXNgIC0gVGhlIHByb2plY3Qga2V5IChrZWNjYWsyNTYgaGFzaCBvZiB0aGUgbmFtZSkKCiMgUGFuaWNzCiogSWYgdGhlIHByb2plY3QgbmFtZSBpcyBub3QgMSB0byAxNSBBU0NJSSBsZXR0ZXJzCiogSWYgdGhlIHByb2plY3QgYWxyZWFkeSBleGlzdHMKKiBJZiB0aGUgbWFpbnRhaW5lciBpcyBub3QgYXV0aG9yaXplZAoqIElmIHRoZSBkb21haW4gcmVnaXN0cmF0aW9uIGZhaWxzCiogSWYgdGhlIG1haW50YWluZXIgZG9lc24ndCBvd24gYW4gZXhpc3RpbmcgZG9tYWluCiogSWYgdGhlIGV4aXN0aW5nIGRvbWFpbiBleHBpcmVkCiogSWYgAAAACHJlZ2lzdGVyAAAABgAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAAEbmFtZQAAABAAAAAAAAAAC21haW50YWluZXJzAAAAA+oAAAATAAAAAAAAAAN1cmwAAAAAEAAAAAAAAAAEaXBmcwAAABAAAAAAAAAAB2xpY2Vuc2UAAAAD6AAAABAAAAABAAAADg==",
         "AAAAAAAAAdlVcGRhdGUgdGhlIGNvbmZpZ3VyYXRpb24gb2YgYW4gZXhpc3RpbmcgcHJvamVjdC4KCkFsbG93cyBtYWludGFpbmVycyB0byBjaGFuZ2UgdGhlIHByb2plY3QncyBVUkwsIGNvbW1pdCBoYXNoLCBhbmQgbWFpbnRhaW5lciBsaXN0LgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgbWFpbnRhaW5lcmAgLSBUaGUgYWRkcmVzcyBvZiB0aGUgbWFpbnRhaW5lciBjYWxsaW5nIHRoaXMgZnVuY3Rpb24KKiBga2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCiogYG1haW50YWluZXJzYCAtIE5ldyBsaXN0IG9mIG1haW50YWluZXIgYWRkcmVzc2VzCiogYHVybGAgLSBOZXcgR2l0IHJlcG9zaXRvcnkgVVJMCiogYGhhc2hgIC0gTmV3IGNvbW1pdCBoYXNoCgojIFBhbmljcwoqIElmIHRoZSBwcm9qZWN0IGRvZXNuJ3QgZXhpc3QKKiBJZiB0aGUgbWFpbnRhaW5lciBpcyBub3QgYXV0aG9yaXplZAAAAAAAAA11cGRhdGVfY29uZmlnAAAAAAAABQAAAAAAAAAKbWFpbnRhaW5lcgAAAAAAEwAAAAAAAAADa2V5AAAAAA4AAAAAAAAAC21haW50YWluZXJzAAAAA+oAAAATAAAAAAAAAAN1cmwAAAAAEAAAAAAAAAAEaXBmcwAAABAAAAAA",
         "AAAAAAAAAXNTZXQgdGhlIGxhdGVzdCBjb21taXQgaGFzaCBmb3IgYSBwcm9qZWN0LgoKVXBkYXRlcyB0aGUgY3VycmVudCBjb21taXQgaGFzaCBmb3IgdGhlIHNwZWNpZmllZCBwcm9qZWN0LgoKIyBBcmd1bWVudHMKKiBgZW52YCAtIFRoZSBlbnZpcm9ubWVudCBvYmplY3QKKiBgbWFpbnRhaW5lcmAgLSBUaGUgYWRkcmVzcyBvZiB0aGUgbWFpbnRhaW5lciBjYWxsaW5nIHRoaXMgZnVuY3Rpb24KKiBgcHJvamVjdF9rZXlgIC0gVGhlIHByb2plY3Qga2V5IGlkZW50aWZpZXIKKiBgaGFzaGAgLSBUaGUgbmV3IGNvbW1pdCBoYXNoCgojIFBhbmljcwoqIElmIHRoZSBwcm9qZWN0IGRvZXNuJ3QgZXhpc3QKKiBJZiB0aGUgbWFpbnRhaW5lciBpcyBub3QgYXV0aG9yaXplZAAAAAAGY29tbWl0AAAAAAADAAAAAAAAAAptYWludGFpbmVyAAAAAAATAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAAAAAARoYXNoAAAAEAAAAAA=",
         "AAAAAAAAAPZHZXQgdGhlIGxhc3QgY29tbWl0IGhhc2gKR2V0IHRoZSBsYXRlc3QgY29tbWl0IGhhc2ggZm9yIGEgcHJvamVjdC4KCiMgQXJndW1lbnRzCiogYGVudmAgLSBUaGUgZW52aXJvbm1lbnQgb2JqZWN0CiogYHByb2plY3Rfa2V5YCAtIFRoZSBwcm9qZWN0IGtleSBpZGVudGlmaWVyCgojIFJldHVybnMKKiBgU3RyaW5nYCAtIFRoZSBjdXJyZW50IGNvbW1pdCBoYXNoCgojIFBhbmljcwoqIElmIHRoZSBwcm9qZWN0IGRvZXNuJ3QgZXhpc3QAAAAAAApnZXRfY29tbWl0AAAAAAABAAAAAAAAAAtwcm9qZWN0X2tleQAAAAAOAAAAAQAAABA=",
@@ -1426,24 +1476,27 @@ export class Client extends ContractClient {
         "AAAAAQAAAAAAAAAAAAAACENvbnRyYWN0AAAAAgAAAAAAAAAHYWRkcmVzcwAAAAATAAAAAAAAAAl3YXNtX2hhc2gAAAAAAAPoAAAD7gAAACA=",
         "AAAAAgAAAAAAAAAAAAAAC0NvbnRyYWN0S2V5AAAAAAIAAAAAAAAAAAAAAA5Eb21haW5Db250cmFjdAAAAAAAAAAAAAAAAAASQ29sbGF0ZXJhbENvbnRyYWN0AAA=",
         "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAABAAAAAEAAAAAAAAABk1lbWJlcgAAAAAAAQAAABMAAAAAAAAAAAAAAAZQYXVzZWQAAAAAAAAAAAAAAAAAD1VwZ3JhZGVQcm9wb3NhbAAAAAAAAAAAAAAAAAxBZG1pbnNDb25maWc=",
-        "AAAAAQAAAAAAAAAAAAAABkJhZGdlcwAAAAAABAAAAAAAAAAJY29tbXVuaXR5AAAAAAAD6gAAABMAAAAAAAAACWRldmVsb3BlcgAAAAAAA+oAAAATAAAAAAAAAAZ0cmlhZ2UAAAAAA+oAAAATAAAAAAAAAAh2ZXJpZmllZAAAA+oAAAAT",
-        "AAAAAwAAAAAAAAAAAAAABUJhZGdlAAAAAAAABQAAAAAAAAAJRGV2ZWxvcGVyAAAAAJiWgAAAAAAAAAAGVHJpYWdlAAAATEtAAAAAAAAAAAlDb21tdW5pdHkAAAAAD0JAAAAAAAAAAAhWZXJpZmllZAAHoSAAAAAAAAAAB0RlZmF1bHQAAAAAAQ==",
+        "AAAAAQAAAAAAAAAAAAAABkJhZGdlcwAAAAAABQAAAAAAAAAJY29tbXVuaXR5AAAAAAAD6gAAABMAAAAAAAAACWRldmVsb3BlcgAAAAAAA+oAAAATAAAAAAAAAAhyZXZpZXdlcgAAA+oAAAATAAAAAAAAAAZ0cmlhZ2UAAAAAA+oAAAATAAAAAAAAAAh2ZXJpZmllZAAAA+oAAAAT",
+        "AAAAAwAAAAAAAAAAAAAABUJhZGdlAAAAAAAABgAAAAAAAAAJRGV2ZWxvcGVyAAAAAJiWgAAAAAAAAAAGVHJpYWdlAAAATEtAAAAAAAAAAAhSZXZpZXdlcgAehIAAAAAAAAAACUNvbW11bml0eQAAAAAPQkAAAAAAAAAACFZlcmlmaWVkAAehIAAAAAAAAAAHRGVmYXVsdAAAAAAB",
         "AAAAAQAAAAAAAAAAAAAADVByb2plY3RCYWRnZXMAAAAAAAACAAAAAAAAAAZiYWRnZXMAAAAAA+oAAAfQAAAABUJhZGdlAAAAAAAAAAAAAAdwcm9qZWN0AAAAAA4=",
         "AAAAAQAAAAAAAAAAAAAABk1lbWJlcgAAAAAAAgAAAAAAAAAEbWV0YQAAABAAAAAAAAAACHByb2plY3RzAAAD6gAAB9AAAAANUHJvamVjdEJhZGdlcwAAAA==",
-        "AAAAAgAAAAAAAAAAAAAADlByb3Bvc2FsU3RhdHVzAAAAAAAFAAAAAAAAAAAAAAAGQWN0aXZlAAAAAAAAAAAAAAAAAAhBcHByb3ZlZAAAAAAAAAAAAAAACFJlamVjdGVkAAAAAAAAAAAAAAAJQ2FuY2VsbGVkAAAAAAAAAAAAAAAAAAAJTWFsaWNpb3VzAAAA",
+        "AAAAAgAAAAAAAAAAAAAADlByb3Bvc2FsU3RhdHVzAAAAAAAHAAAAAAAAAAAAAAAGQWN0aXZlAAAAAAAAAAAAAAAAAAhBcHByb3ZlZAAAAAAAAAAAAAAACFJlamVjdGVkAAAAAAAAAAAAAAAJQ2FuY2VsbGVkAAAAAAAAAAAAAAAAAAAJTWFsaWNpb3VzAAAAAAAAAAAAAAAAAAAGVmV0b2VkAAAAAAAAAAAAAAAAAAdFeHBpcmVkAA==",
         "AAAAAgAAAAAAAAAAAAAABFZvdGUAAAACAAAAAQAAAAAAAAAKUHVibGljVm90ZQAAAAAAAQAAB9AAAAAKUHVibGljVm90ZQAAAAAAAQAAAAAAAAANQW5vbnltb3VzVm90ZQAAAAAAAAEAAAfQAAAADUFub255bW91c1ZvdGUAAAA=",
-        "AAAAAgAAAAAAAAAAAAAAClZvdGVDaG9pY2UAAAAAAAMAAAAAAAAAAAAAAAdBcHByb3ZlAAAAAAAAAAAAAAAABlJlamVjdAAAAAAAAAAAAAAAAAAHQWJzdGFpbgA=",
+        "AAAAAgAAAAAAAAAAAAAAClZvdGVDaG9pY2UAAAAAAAQAAAAAAAAAAAAAAAdBcHByb3ZlAAAAAAAAAAAAAAAABlJlamVjdAAAAAAAAAAAAAAAAAAHQWJzdGFpbgAAAAABAAAAAAAAAAZDaG9pY2UAAAAAAAEAAAAE",
         "AAAAAQAAAAAAAAAAAAAAClB1YmxpY1ZvdGUAAAAAAAMAAAAAAAAAB2FkZHJlc3MAAAAAEwAAAAAAAAALdm90ZV9jaG9pY2UAAAAH0AAAAApWb3RlQ2hvaWNlAAAAAAAAAAAABndlaWdodAAAAAAABA==",
         "AAAAAQAAAAAAAAAAAAAADUFub255bW91c1ZvdGUAAAAAAAAFAAAAAAAAAAdhZGRyZXNzAAAAABMAAAAAAAAAC2NvbW1pdG1lbnRzAAAAA+oAAAPuAAAAYAAAAAAAAAAPZW5jcnlwdGVkX3NlZWRzAAAAA+oAAAAQAAAAAAAAAA9lbmNyeXB0ZWRfdm90ZXMAAAAD6gAAABAAAAAAAAAABndlaWdodAAAAAAABA==",
-        "AAAAAQAAAAAAAAAAAAAACFZvdGVEYXRhAAAAAwAAAAAAAAANcHVibGljX3ZvdGluZwAAAAAAAAEAAAAAAAAABXZvdGVzAAAAAAAD6gAAB9AAAAAEVm90ZQAAAAAAAAAOdm90aW5nX2VuZHNfYXQAAAAAAAY=",
+        "AAAAAQAAAAAAAAAAAAAACFZvdGVEYXRhAAAABAAAAAAAAAAPZWxpZ2libGVfd2VpZ2h0AAAAAAoAAAAAAAAADXB1YmxpY192b3RpbmcAAAAAAAABAAAAAAAAAAV2b3RlcwAAAAAAA+oAAAfQAAAABFZvdGUAAAAAAAAADnZvdGluZ19lbmRzX2F0AAAAAAAG",
         "AAAAAQAAAAAAAAAAAAAAE0Fub255bW91c1ZvdGVDb25maWcAAAAAAwAAAAAAAAAKcHVibGljX2tleQAAAAAAEAAAAAAAAAAUc2VlZF9nZW5lcmF0b3JfcG9pbnQAAAPuAAAAYAAAAAAAAAAUdm90ZV9nZW5lcmF0b3JfcG9pbnQAAAPuAAAAYA==",
         "AAAAAQAAAAAAAAAAAAAADEFkbWluc0NvbmZpZwAAAAIAAAAAAAAABmFkbWlucwAAAAAD6gAAABMAAAAAAAAACXRocmVzaG9sZAAAAAAAAAQ=",
         "AAAAAQAAAAAAAAAAAAAAD1VwZ3JhZGVQcm9wb3NhbAAAAAAEAAAAAAAAAA1hZG1pbnNfY29uZmlnAAAAAAAH0AAAAAxBZG1pbnNDb25maWcAAAAAAAAACWFwcHJvdmFscwAAAAAAA+oAAAATAAAAAAAAAA1leGVjdXRhYmxlX2F0AAAAAAAABgAAAAAAAAAJd2FzbV9oYXNoAAAAAAAD7gAAACA=",
-        "AAAAAQAAAAAAAAAAAAAACFByb3Bvc2FsAAAABwAAAAAAAAACaWQAAAAAAAQAAAAAAAAABGlwZnMAAAAQAAAAAAAAABFvdXRjb21lc19jb250cmFjdAAAAAAAA+gAAAATAAAAAAAAAAhwcm9wb3NlcgAAABMAAAAAAAAABnN0YXR1cwAAAAAH0AAAAA5Qcm9wb3NhbFN0YXR1cwAAAAAAAAAAAAV0aXRsZQAAAAAAABAAAAAAAAAACXZvdGVfZGF0YQAAAAAAB9AAAAAIVm90ZURhdGE=",
+        "AAAAAQAAAAAAAAAAAAAACFByb3Bvc2FsAAAADwAAAAAAAAAIY29zaWduZXIAAAPoAAAAEwAAAAAAAAAKY3JlYXRlZF9hdAAAAAAABgAAAAAAAAAKZGVwZW5kc19vbgAAAAAD6AAAAAQAAAAAAAAAB2RlcG9zaXQAAAAACwAAAAAAAAACaWQAAAAAAAQAAAAAAAAABGlwZnMAAAAQAAAAAAAAAARraW5kAAAH0AAAAAxQcm9wb3NhbEtpbmQAAAAAAAAAB29wdGlvbnMAAAAD6gAAABAAAAAAAAAAEW91dGNvbWVzX2NvbnRyYWN0AAAAAAAD6AAAABMAAAAAAAAACHByb3Bvc2VyAAAAEwAAAAAAAAAOcmV2ZWFsX2VuZHNfYXQAAAAAA+gAAAAGAAAAAAAAAAZzdGF0dXMAAAAAB9AAAAAOUHJvcG9zYWxTdGF0dXMAAAAAAAAAAAAFdGl0bGUAAAAAAAAQAAAAAAAAAAl2b3RlX2RhdGEAAAAAAAfQAAAACFZvdGVEYXRhAAAAAAAAAA53aW5uaW5nX29wdGlvbgAAAAAD6AAAAAQ=",
         "AAAAAQAAAAAAAAAAAAAAA0RhbwAAAAABAAAAAAAAAAlwcm9wb3NhbHMAAAAAAAPqAAAH0AAAAAhQcm9wb3NhbA==",
         "AAAAAgAAAAAAAAAAAAAAClByb2plY3RLZXkAAAAAAAYAAAABAAAAAAAAAANLZXkAAAAAAQAAAA4AAAABAAAAAAAAAAZCYWRnZXMAAAAAAAEAAAAOAAAAAQAAAAAAAAAITGFzdEhhc2gAAAABAAAADgAAAAEAAAAAAAAAA0RhbwAAAAACAAAADgAAAAQAAAABAAAAAAAAABFEYW9Ub3RhbFByb3Bvc2FscwAAAAAAAAEAAAAOAAAAAQAAAAAAAAATQW5vbnltb3VzVm90ZUNvbmZpZwAAAAABAAAADg==",
-        "AAAAAQAAAAAAAAAAAAAABkNvbmZpZwAAAAAAAgAAAAAAAAAEaXBmcwAAABAAAAAAAAAAA3VybAAAAAAQ",
-        "AAAAAQAAAAAAAAAAAAAAB1Byb2plY3QAAAAAAwAAAAAAAAAGY29uZmlnAAAAAAfQAAAABkNvbmZpZwAAAAAAAAAAAAttYWludGFpbmVycwAAAAPqAAAAEwAAAAAAAAAEbmFtZQAAABA=",
+        "AAAAAQAAAAAAAAAAAAAABkNvbmZpZwAAAAAABgAAAAAAAAAEaXBmcwAAABAAAAAAAAAAB2xpY2Vuc2UAAAAD6AAAABAAAAAAAAAAFW1haW50YWluZXJzX3RocmVzaG9sZAAAAAAAAAQAAAAAAAAAD21heF9tYWludGFpbmVycwAAAAAEAAAAAAAAAAdtaXJyb3JzAAAAA+oAAAfQAAAAB1JlcG9SZWYAAAAAAAAAAAN1cmwAAAAAEA==",
+        "AAAAAQAAAAAAAAAAAAAAB1Byb2plY3QAAAAABAAAAAAAAAAGY29uZmlnAAAAAAfQAAAABkNvbmZpZwAAAAAAAAAAAAttYWludGFpbmVycwAAAAPqAAAAEwAAAAAAAAAIbWV0YWRhdGEAAAfQAAAACE1ldGFkYXRhAAAAAAAAAARuYW1lAAAAEA==",
+        "AAAAAQAAAAAAAAAAAAAAB1JlcG9SZWYAAAAAAgAAAAAAAAAEbmFtZQAAABAAAAAAAAAAA3VybAAAAAAQ",
+        "AAAAAQAAAAAAAAAAAAAACE1ldGFkYXRhAAAAAwAAAAAAAAALZGVzY3JpcHRpb24AAAAD6AAAABAAAAAAAAAABGxvZ28AAAPoAAAAEAAAAAAAAAAHd2Vic2l0ZQAAAAPoAAAAEA==",
+        "AAAAAgAAAEFLaW5kIG9mIGEgcHJvcG9zYWwsIGRlY2lkaW5nIHdoYXQgaXMgYXBwbGllZCB3aGVuIGl0IGlzIGFwcHJvdmVkLgAAAAAAAAAAAAAMUHJvcG9zYWxLaW5kAAAABgAAAAAAAAAAAAAABFRleHQAAAAAAAAAAAAAAAxDb25maWdDaGFuZ2UAAAAAAAAAAAAAAAhUcmVhc3VyeQAAAAAAAAAAAAAAB1VwZ3JhZGUAAAAAAAAAAAAAAAAQTWFpbnRhaW5lckNoYW5nZQAAAAAAAAAAAAAADENvbnRyYWN0Q2FsbA==",
       ]),
       options,
     );
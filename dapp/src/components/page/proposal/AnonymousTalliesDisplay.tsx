@@ -54,7 +54,7 @@ const AnonymousTalliesDisplay: React.FC<Props> = ({
                     <td className="p-1">{v.vote}</td>
                     <td className="p-1">{v.weight}</td>
                     <td className="p-1">{v.maxWeight}</td>
-                    <td className="p-1">{v.seed}</td>
+                    <td className="p-1">{v.seed.toString()}</td>
                   </tr>
                 ))}
               </tbody>
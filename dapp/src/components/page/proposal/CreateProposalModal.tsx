@@ -55,7 +55,6 @@ const CreateProposalModal = () => {
     privateKey: string;
   } | null>(null);
   const [existingAnonConfig, setExistingAnonConfig] = useState<boolean>(false);
-  const [resetAnonKeys, setResetAnonKeys] = useState<boolean>(false);
   const [keysDownloaded, setKeysDownloaded] = useState<boolean>(false);
   const [proposalNameError, setProposalNameError] = useState<string | null>(
     null,
@@ -229,7 +228,7 @@ const CreateProposalModal = () => {
       const files = prepareProposalFiles();
       setPreparedFiles(files);
 
-      if (isAnonymousVoting && (!existingAnonConfig || resetAnonKeys)) {
+      if (isAnonymousVoting && !existingAnonConfig) {
         // Show explicit config step
         setStep(5);
         return;
@@ -287,14 +286,12 @@ const CreateProposalModal = () => {
       );
       const exists = await hasAnonymousVotingConfig(projectName);
 
-      if (exists && generatedKeys) {
+      if (exists) {
         setExistingAnonConfig(true);
-        setResetAnonKeys(false);
       } else {
         const keys = await generateRSAKeyPair();
         setGeneratedKeys(keys);
         setExistingAnonConfig(false);
-        setResetAnonKeys(false);
 
         // Auto-download
         downloadKeys(keys);
@@ -303,7 +300,6 @@ const CreateProposalModal = () => {
       const keys = await generateRSAKeyPair();
       setGeneratedKeys(keys);
       setExistingAnonConfig(false);
-      setResetAnonKeys(false);
 
       downloadKeys(keys);
     }
@@ -400,24 +396,9 @@ const CreateProposalModal = () => {
                       )}
 
                     {isAnonymousVoting && existingAnonConfig && (
-                      <>
-                        <span className="text-sm text-green-600">
-                          Existing anonymous voting keys are already configured.
-                        </span>
-                        <button
-                          type="button"
-                          className="text-blue-500 underline text-sm w-fit"
-                          onClick={async () => {
-                            const keys = await generateRSAKeyPair();
-                            setGeneratedKeys(keys);
-                            setResetAnonKeys(true);
-                            setKeysDownloaded(false);
-                            downloadKeys(keys);
-                          }}
-                        >
-                          Reset Keys
-                        </button>
-                      </>
+                      <span className="text-sm text-green-600">
+                        Existing anonymous voting keys are already configured.
+                      </span>
                     )}
                   </div>
                 </div>
@@ -581,7 +562,7 @@ const CreateProposalModal = () => {
                     throw new Error("Invalid proposal name or description");
 
                   if (isAnonymousVoting) {
-                    if (!existingAnonConfig || resetAnonKeys) {
+                    if (!existingAnonConfig) {
                       if (!generatedKeys) {
                         throw new Error(
                           "Anonymous voting keys have not been generated yet.",
@@ -913,9 +894,7 @@ const CreateProposalModal = () => {
             </Button>
           </div>
         </div>
-      ) : step === 5 &&
-        isAnonymousVoting &&
-        (!existingAnonConfig || resetAnonKeys) ? (
+      ) : step === 5 && isAnonymousVoting && !existingAnonConfig ? (
         <div
           className="flex flex-col gap-10 md:gap-12"
           data-testid="anon-setup-step"
@@ -956,10 +935,8 @@ const CreateProposalModal = () => {
                       await setupAnonymousVoting(
                         projectName,
                         generatedKeys.publicKey,
-                        true,
                       );
                       setExistingAnonConfig(true);
-                      setResetAnonKeys(false);
                       // proceed to proposal creation
                       const files = preparedFiles || prepareProposalFiles();
                       await startProposalCreation(files);
@@ -173,9 +173,9 @@ export async function voteToProposal(
     const votesArr: number[] = [0, 0, 0];
     votesArr[voteIndex] = 1;
 
-    // Generate cryptographically secure 32-bit seeds.
-    const r = crypto.getRandomValues(new Uint32Array(3));
-    const seedsArr: number[] = [Number(r[0]), Number(r[1]), Number(r[2])];
+    // Generate cryptographically secure seeds, full scalars of the field.
+    const { randomSeed } = await import("../utils/anonymousVoting");
+    const seedsArr: bigint[] = [randomSeed(), randomSeed(), randomSeed()];
 
     // Get anonymous voting config
     const configTx = await client.get_anonymous_voting_config({
@@ -193,9 +193,8 @@ export async function voteToProposal(
     let encryptedVotes: string[];
     let commitmentsTx: any;
     try {
-      // Encode votes/seeds as u128 for contract bindings
+      // Encode votes as u128 and seeds as u256 for contract bindings
       const votesU128 = votesArr.map((v) => BigInt(v));
-      const seedsU128 = seedsArr.map((s) => BigInt(s));
 
       [encryptedSeeds, encryptedVotes, commitmentsTx] = await Promise.all([
         Promise.all(
@@ -211,7 +210,7 @@ export async function voteToProposal(
         client.build_commitments_from_votes({
           project_key: projectKey,
           votes: votesU128 as unknown as bigint[],
-          seeds: seedsU128 as unknown as bigint[],
+          seeds: seedsArr,
         }),
       ]);
       // Ensure the helper call did not surface a simulation error payload
@@ -318,22 +317,19 @@ export async function setBadges(
 export async function setupAnonymousVoting(
   project_name: string,
   public_key: string,
-  force = false,
 ): Promise<boolean> {
   const client = getClient();
   const projectKey = getProjectKey(project_name);
 
-  // Check if already configured
-  if (!force) {
-    try {
-      const configTx = await client.get_anonymous_voting_config({
-        project_key: projectKey,
-      });
-      checkSimulationError(configTx as any);
-      if (configTx.result) return true;
-    } catch {
-      // Fall-through to setup on network/simulation errors
-    }
+  // Check if already configured, keys are then rotated by the maintainers
+  try {
+    const configTx = await client.get_anonymous_voting_config({
+      project_key: projectKey,
+    });
+    checkSimulationError(configTx as any);
+    if (configTx.result) return true;
+  } catch {
+    // Fall-through to setup on network/simulation errors
   }
 
   const assembledTx = await client.anonymous_voting_setup({
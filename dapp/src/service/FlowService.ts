@@ -41,6 +41,7 @@ interface CreateProjectFlowParams {
   tomlFile: File;
   githubRepoUrl: string;
   maintainers: string[];
+  license?: string;
   onProgress?: (step: number) => void;
 }
 
@@ -321,6 +322,7 @@ export async function createProjectFlow({
   tomlFile,
   githubRepoUrl,
   maintainers,
+  license,
   onProgress,
 }: CreateProjectFlowParams): Promise<boolean> {
   // Step 1 – Calculate CID
@@ -344,6 +346,7 @@ export async function createProjectFlow({
     maintainers,
     url: githubRepoUrl,
     ipfs: expectedCid,
+    license,
   });
 
   // Check for simulation errors (contract errors) before signing
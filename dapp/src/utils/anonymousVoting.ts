@@ -19,6 +19,20 @@ async function getTansu() {
 // re-export local helper for consistency
 export { deriveProjectKey };
 
+// Order of the BLS12-381 scalar field, seeds and their weighted sums are
+// reduced modulo this value
+export const BLS12_381_ORDER =
+  0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001n;
+
+/**
+ * Generate a random seed, a full scalar of the BLS12-381 field.
+ */
+export function randomSeed(): bigint {
+  const bytes = crypto.getRandomValues(new Uint8Array(64));
+  const value = bytes.reduce((acc, byte) => (acc << 8n) + BigInt(byte), 0n);
+  return value % BLS12_381_ORDER;
+}
+
 /**
  * Validate that an uploaded key-file (optionally containing a publicKey)
  * matches the project's anonymous voting configuration. Throws a helpful
@@ -50,14 +64,14 @@ export async function validateAnonymousKeyForProject(
 export interface DecodedVote {
   address: string;
   vote: "approve" | "reject" | "abstain";
-  seed: number;
+  seed: bigint;
   weight: number;
   maxWeight: number;
 }
 
 export interface AnonymousVotingData {
   tallies: bigint[]; // length 3: approve/reject/abstain – weighted
-  seeds: bigint[]; // length 3 – sum of seeds per choice
+  seeds: bigint[]; // length 3 – sum of seeds per choice, modulo BLS12_381_ORDER
   voteCounts: number[]; // length 3 – un-weighted counts (needed for proof)
   voteStatus: VoteStatus;
   decodedVotes: DecodedVote[];
@@ -91,7 +105,7 @@ export async function computeAnonymousVotingData(
 
   // Init accumulators
   const talliesArr = [0, 0, 0];
-  const seedsArr = [0, 0, 0];
+  const seedsArr = [0n, 0n, 0n];
   const voteCounts = [0, 0, 0];
   const decodedPerVoter: DecodedVote[] = [];
 
@@ -107,7 +121,7 @@ export async function computeAnonymousVotingData(
       (data as { encrypted_seeds?: string[] }).encrypted_seeds ?? [];
 
     let voteChoiceIdx = -1;
-    let selectedSeedRaw = 0;
+    let selectedSeedRaw = 0n;
     for (let i = 0; i < 3; i++) {
       if (i >= encryptedVotes.length || i >= encryptedSeeds.length) continue;
       const vCipher = encryptedVotes[i] as string | undefined;
@@ -123,13 +137,13 @@ export async function computeAnonymousVotingData(
         const numStr = decStr.includes(":") ? decStr.split(":").pop()! : decStr;
         vDec = parseInt(numStr);
       }
-      let sDec: number;
+      let sDec: bigint;
       if (isPlainNumber(sCipher)) {
-        sDec = parseInt(sCipher);
+        sDec = BigInt(sCipher);
       } else {
         const decStr = await decryptWithPrivateKey(sCipher!, privateKey);
         const numStr = decStr.includes(":") ? decStr.split(":").pop()! : decStr;
-        sDec = parseInt(numStr);
+        sDec = BigInt(numStr);
       }
 
       if (vDec > 0) {
@@ -143,7 +157,7 @@ export async function computeAnonymousVotingData(
         voteCounts[i] !== undefined
       ) {
         talliesArr[i]! += vDec * weight;
-        seedsArr[i]! += sDec * weight;
+        seedsArr[i] = (seedsArr[i]! + sDec * BigInt(weight)) % BLS12_381_ORDER;
         if (vDec > 0) {
           voteCounts[i]! += 1;
         }
@@ -211,7 +225,7 @@ export async function computeAnonymousVotingData(
         project_key,
         proposal: rawProposal,
         tallies: talliesArr.map((n) => BigInt(n)),
-        seeds: seedsArr.map((n) => BigInt(n)),
+        seeds: seedsArr,
       });
       proofOk = !!proofRes.result;
     } catch (_) {
@@ -221,7 +235,7 @@ export async function computeAnonymousVotingData(
 
   return {
     tallies: talliesArr.map((n) => BigInt(n)),
-    seeds: seedsArr.map((n) => BigInt(n)),
+    seeds: seedsArr,
     voteCounts,
     voteStatus,
     decodedVotes: decodedPerVoter,